@@ -0,0 +1,103 @@
+//! Order-level book of one symbol, in price-time priority.
+
+use spot_sbe::book::Side;
+use spot_sbe::Decimal;
+use std::collections::{BTreeMap, VecDeque};
+
+/// An order waiting on the book.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Resting {
+    /// The account's order, or `None` for liquidity of other participants
+    pub order_id: Option<i64>,
+    pub qty: Decimal,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Book {
+    bids: BTreeMap<Decimal, VecDeque<Resting>>,
+    asks: BTreeMap<Decimal, VecDeque<Resting>>,
+}
+
+impl Book {
+    fn side_mut(&mut self, side: Side) -> &mut BTreeMap<Decimal, VecDeque<Resting>> {
+        match side {
+            Side::Bid => &mut self.bids,
+            Side::Ask => &mut self.asks,
+        }
+    }
+
+    /// Orders on `side`, best price first and oldest first within a price.
+    pub fn iter(&self, side: Side) -> Box<dyn Iterator<Item = (Decimal, &Resting)> + '_> {
+        match side {
+            Side::Bid => Box::new(
+                self.bids
+                    .iter()
+                    .rev()
+                    .flat_map(|(price, queue)| queue.iter().map(move |resting| (*price, resting))),
+            ),
+            Side::Ask => Box::new(
+                self.asks
+                    .iter()
+                    .flat_map(|(price, queue)| queue.iter().map(move |resting| (*price, resting))),
+            ),
+        }
+    }
+
+    #[inline]
+    pub fn best(&self, side: Side) -> Option<Decimal> {
+        self.iter(side).next().map(|(price, _)| price)
+    }
+
+    /// Queues an order behind the others at its price.
+    pub fn insert(&mut self, side: Side, price: Decimal, resting: Resting) {
+        self.side_mut(side)
+            .entry(price)
+            .or_default()
+            .push_back(resting);
+    }
+
+    /// Takes `qty` from the first order on `side`, removing it once nothing
+    /// is left, and returns it as it was.
+    pub fn fill_front(&mut self, side: Side, qty: Decimal) -> Option<Resting> {
+        let levels = self.side_mut(side);
+        let mut level = match side {
+            Side::Bid => levels.last_entry()?,
+            Side::Ask => levels.first_entry()?,
+        };
+        let front = level.get_mut().front_mut()?;
+        let before = *front;
+        front.qty = front.qty.checked_sub(qty)?;
+        if front.qty <= Decimal::ZERO {
+            level.get_mut().pop_front();
+            if level.get().is_empty() {
+                level.remove();
+            }
+        }
+        Some(before)
+    }
+
+    /// Removes the account's order `order_id` resting at `price`.
+    pub fn remove(&mut self, side: Side, price: Decimal, order_id: i64) -> Option<Resting> {
+        let levels = self.side_mut(side);
+        let queue = levels.get_mut(&price)?;
+        let index = queue
+            .iter()
+            .position(|resting| resting.order_id == Some(order_id))?;
+        let resting = queue.remove(index);
+        if queue.is_empty() {
+            levels.remove(&price);
+        }
+        resting
+    }
+
+    /// Removes the liquidity of other participants, leaving the account's
+    /// orders.
+    pub fn clear_external(&mut self) {
+        for levels in [&mut self.bids, &mut self.asks] {
+            for queue in levels.values_mut() {
+                queue.retain(|resting| resting.order_id.is_some());
+            }
+            levels.retain(|_, queue| !queue.is_empty());
+        }
+    }
+}
@@ -0,0 +1,2056 @@
+//! An in-process limit order matching simulator, for deterministic tests of
+//! strategies against realistic Spot API responses and user data streams.
+//!
+//! A [`Simulator`] holds the symbols of an exchangeInfo response, an order
+//! book per symbol and the balances of one account. New orders, OCOs,
+//! cancels and cancel-replaces are checked against the symbol's filters,
+//! matched in price-time priority and answered with the encoded response
+//! the exchange would send, `NewOrderFullResponse` included. Every change to
+//! the account's orders and balances is recorded as an [`Event`]: an
+//! `ExecutionReportEvent`, `ListStatusEvent` or
+//! `OutboundAccountPositionEvent`.
+//!
+//! Other participants are represented by [`Simulator::load_depth`] and
+//! [`Simulator::external_order`]. Self-trade prevention applies only when
+//! the account's orders meet each other, with the taker's mode deciding.
+//! Time only moves when told to, and order, trade and execution IDs count up
+//! from 1, so the same requests always produce the same bytes.
+//!
+//! Iceberg, trailing-stop, OTO and SOR orders are not simulated.
+
+use crate::book::{Book, Resting};
+use crate::symbol::{is_algo, is_market, OpenOrders};
+use spot_sbe::book::{BookError, OrderBook, Side};
+use spot_sbe::new_order_full_response_codec::owned::{Fills, PreventedMatches};
+use spot_sbe::new_order_list_full_response_codec::owned::{self as order_list, OrderReports};
+use spot_sbe::outbound_account_position_event_codec::owned::Balances;
+use spot_sbe::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+mod book;
+pub mod symbol;
+
+pub use symbol::{Filter, SymbolRules};
+
+/// An encoded response and the HTTP status it is sent with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reply {
+    pub status: u16,
+    /// The message, header included
+    pub body: Vec<u8>,
+}
+
+impl Reply {
+    fn encode(status: u16, message: &impl OwnedMessage) -> SbeResult<Self> {
+        Ok(Self {
+            status,
+            body: message.encode()?,
+        })
+    }
+
+    fn from_result<T: OwnedMessage>(result: Result<T, Rejection>) -> SbeResult<Self> {
+        match result {
+            Ok(response) => Self::encode(200, &response),
+            Err(rejection) => Self::encode(400, &rejection.to_error_response()),
+        }
+    }
+}
+
+/// A user data stream event.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    ExecutionReport(ExecutionReportEvent),
+    ListStatus(ListStatusEvent),
+    AccountPosition(OutboundAccountPositionEvent),
+}
+
+impl Event {
+    /// Encodes the event, header included.
+    pub fn encode(&self) -> SbeResult<Vec<u8>> {
+        match self {
+            Self::ExecutionReport(event) => event.encode(),
+            Self::ListStatus(event) => event.encode(),
+            Self::AccountPosition(event) => event.encode(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Sbe(SbeErr),
+    Book(BookError),
+    UnknownSymbol(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sbe(err) => write!(f, "{err}"),
+            Self::Book(err) => write!(f, "{err}"),
+            Self::UnknownSymbol(symbol) => write!(f, "unknown symbol {symbol:?}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<SbeErr> for Error {
+    fn from(err: SbeErr) -> Self {
+        Self::Sbe(err)
+    }
+}
+
+impl From<BookError> for Error {
+    fn from(err: BookError) -> Self {
+        Self::Book(err)
+    }
+}
+
+/// A request the exchange refuses, answered with an `ErrorResponse`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Rejection {
+    pub code: i16,
+    pub msg: String,
+}
+
+impl Rejection {
+    pub fn new(code: i16, msg: impl Into<String>) -> Self {
+        Self {
+            code,
+            msg: msg.into(),
+        }
+    }
+
+    fn to_error_response(&self) -> ErrorResponse {
+        ErrorResponse {
+            code: self.code,
+            msg: self.msg.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+fn invalid_symbol() -> Rejection {
+    Rejection::new(-1121, "Invalid symbol.")
+}
+
+fn insufficient_balance() -> Rejection {
+    Rejection::new(
+        -2010,
+        "Account has insufficient balance for requested action.",
+    )
+}
+
+/// The parameters of a new order, as sent to `POST /api/v3/order`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NewOrder {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+    pub qty: Option<Decimal>,
+    /// Quote amount to spend or receive, for `MARKET` orders only
+    pub quote_order_qty: Option<Decimal>,
+    pub price: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
+    /// Generated as `sim-<orderId>` when not given
+    pub client_order_id: Option<String>,
+    /// `NullVal` takes the symbol's default
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+}
+
+impl NewOrder {
+    /// A `GTC` `LIMIT` order.
+    pub fn limit(symbol: &str, side: OrderSide, price: Decimal, qty: Decimal) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            side,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::Gtc,
+            qty: Some(qty),
+            price: Some(price),
+            ..Default::default()
+        }
+    }
+
+    /// A `MARKET` order for a base quantity.
+    pub fn market(symbol: &str, side: OrderSide, qty: Decimal) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            side,
+            order_type: OrderType::Market,
+            qty: Some(qty),
+            ..Default::default()
+        }
+    }
+}
+
+/// The parameters of an OCO: a `LIMIT_MAKER` order at `price` and a
+/// `STOP_LOSS` order at `stop_price`, or a `GTC` `STOP_LOSS_LIMIT` order when
+/// `stop_limit_price` is given.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NewOco {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub qty: Decimal,
+    pub price: Decimal,
+    pub stop_price: Decimal,
+    pub stop_limit_price: Option<Decimal>,
+    /// Generated as `sim-list-<orderListId>` when not given
+    pub list_client_order_id: Option<String>,
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+}
+
+/// How a request names one of the account's orders.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderRef<'a> {
+    Id(i64),
+    ClientOrderId(&'a str),
+}
+
+/// `cancelReplaceMode` of a cancel-replace request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CancelReplaceMode {
+    /// The new order is not placed if the cancel fails
+    StopOnFailure,
+    /// The new order is placed whether or not the cancel succeeds
+    AllowFailure,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Balance {
+    pub free: Decimal,
+    pub locked: Decimal,
+}
+
+/// One of the account's orders, as of its last execution report.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Order {
+    pub symbol: String,
+    pub order_id: i64,
+    pub order_list_id: Option<i64>,
+    pub client_order_id: String,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+    /// Zero for market orders
+    pub price: Decimal,
+    pub stop_price: Option<Decimal>,
+    /// For quote quantity orders, the quantity the book allowed when placed
+    pub orig_qty: Decimal,
+    pub orig_quote_order_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
+    pub status: OrderStatus,
+    /// The mode it is matched with, after the symbol's default is applied
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+    pub prevented_qty: Decimal,
+    pub time: i64,
+    /// When it went on the book or started matching; `None` for stop
+    /// orders until they trigger
+    pub working_time: Option<i64>,
+    /// Balance held for it, in the base asset when selling and the quote
+    /// asset when buying
+    locked: Decimal,
+}
+
+impl Order {
+    #[inline]
+    pub fn is_open(&self) -> bool {
+        matches!(self.status, OrderStatus::New | OrderStatus::PartiallyFilled)
+    }
+
+    #[inline]
+    pub fn remaining_qty(&self) -> Decimal {
+        sub(self.orig_qty, self.executed_qty)
+    }
+}
+
+/// An OCO, whose legs end together.
+#[derive(Clone, Debug)]
+struct OrderList {
+    order_list_id: i64,
+    list_client_order_id: String,
+    symbol: String,
+    orders: [i64; 2],
+    all_done: bool,
+}
+
+#[derive(Clone, Debug)]
+struct Market {
+    rules: SymbolRules,
+    book: Book,
+    last_price: Option<Decimal>,
+}
+
+/// The last ID of each kind handed out.
+#[derive(Clone, Copy, Debug, Default)]
+struct Ids {
+    order: i64,
+    order_list: i64,
+    trade: i64,
+    execution: i64,
+    prevented_match: i64,
+}
+
+fn next(id: &mut i64) -> i64 {
+    *id += 1;
+    *id
+}
+
+/// A match the taker's sweep of the book will make, worked out before
+/// anything changes so that its cost is known up front.
+#[derive(Clone, Copy, Debug)]
+enum Step {
+    Trade {
+        price: Decimal,
+        qty: Decimal,
+        maker: Option<i64>,
+    },
+    /// A match between two of the account's orders that self-trade
+    /// prevention stops. `taker_qty` is the quantity the taker loses, if it
+    /// expires.
+    Prevent {
+        price: Decimal,
+        qty: Decimal,
+        maker: i64,
+        maker_qty: Decimal,
+        expire_maker: bool,
+        taker_qty: Option<Decimal>,
+    },
+}
+
+/// What a taker may sweep: up to `limit`, and until `qty` is filled or
+/// `budget` of quote asset is spent or received.
+#[derive(Clone, Copy, Debug)]
+struct Sweep {
+    side: OrderSide,
+    limit: Option<Decimal>,
+    qty: Option<Decimal>,
+    budget: Option<Decimal>,
+    step: Decimal,
+    /// `None` for other participants, whom self-trade prevention does not
+    /// concern
+    stp: Option<SelfTradePreventionMode>,
+    price_exponent: i8,
+}
+
+impl Sweep {
+    fn plan(&self, book: &Book) -> Vec<Step> {
+        let mut steps = Vec::new();
+        let (mut qty_left, mut budget_left) = (self.qty, self.budget);
+        for (price, resting) in book.iter(maker_side(self.side)) {
+            let crosses = self.limit.is_none_or(|limit| match self.side {
+                OrderSide::Buy => price <= limit,
+                _ => price >= limit,
+            });
+            if !crosses {
+                break;
+            }
+            let mut qty = resting.qty;
+            if let Some(left) = qty_left {
+                qty = qty.min(left);
+            }
+            if let Some(left) = budget_left {
+                qty = qty.min(floor_to_step(div_floor(left, price), self.step));
+            }
+            if qty <= Decimal::ZERO {
+                break;
+            }
+            let stp = self.stp.unwrap_or(SelfTradePreventionMode::None);
+            match resting.order_id {
+                Some(maker) if stp != SelfTradePreventionMode::None => {
+                    let expire_taker = stp != SelfTradePreventionMode::ExpireMaker;
+                    steps.push(Step::Prevent {
+                        price,
+                        qty,
+                        maker,
+                        maker_qty: resting.qty,
+                        expire_maker: stp != SelfTradePreventionMode::ExpireTaker,
+                        taker_qty: expire_taker.then(|| qty_left.unwrap_or(qty)),
+                    });
+                    if expire_taker {
+                        break;
+                    }
+                }
+                maker => {
+                    steps.push(Step::Trade { price, qty, maker });
+                    qty_left = qty_left.map(|left| sub(left, qty));
+                    budget_left = budget_left
+                        .map(|left| sub(left, floor_to(mul(price, qty), self.price_exponent)));
+                    if qty_left.is_some_and(|left| left.is_zero()) {
+                        break;
+                    }
+                }
+            }
+        }
+        steps
+    }
+}
+
+/// Traded quantity and quote amount of a plan.
+fn totals(steps: &[Step], price_exponent: i8) -> (Decimal, Decimal) {
+    steps.iter().fold(
+        (Decimal::ZERO, Decimal::ZERO),
+        |(qty, quote), step| match *step {
+            Step::Trade { price, qty: q, .. } => (
+                add(qty, q),
+                add(quote, floor_to(mul(price, q), price_exponent)),
+            ),
+            Step::Prevent { .. } => (qty, quote),
+        },
+    )
+}
+
+/// What a taker's sweep of the book did, for its `NewOrderFullResponse`.
+#[derive(Clone, Debug, Default)]
+struct Outcome {
+    fills: Vec<Fills>,
+    prevented_matches: Vec<PreventedMatches>,
+}
+
+#[inline]
+fn book_side(side: OrderSide) -> Side {
+    match side {
+        OrderSide::Buy => Side::Bid,
+        _ => Side::Ask,
+    }
+}
+
+#[inline]
+fn maker_side(taker: OrderSide) -> Side {
+    match taker {
+        OrderSide::Buy => Side::Ask,
+        _ => Side::Bid,
+    }
+}
+
+#[inline]
+fn bool_enum(value: bool) -> BoolEnum {
+    if value {
+        BoolEnum::True
+    } else {
+        BoolEnum::False
+    }
+}
+
+/// Whether a stop order triggers at `last` price.
+fn triggers(order_type: OrderType, side: OrderSide, stop_price: Decimal, last: Decimal) -> bool {
+    let stop_loss = matches!(order_type, OrderType::StopLoss | OrderType::StopLossLimit);
+    match (stop_loss, side) {
+        (true, OrderSide::Buy) | (false, OrderSide::Sell) => last >= stop_price,
+        _ => last <= stop_price,
+    }
+}
+
+/// The simulated exchange and account.
+#[derive(Clone, Debug)]
+pub struct Simulator {
+    markets: BTreeMap<String, Market>,
+    orders: BTreeMap<i64, Order>,
+    order_lists: BTreeMap<i64, OrderList>,
+    balances: BTreeMap<String, Balance>,
+    maker_commission: Decimal,
+    taker_commission: Decimal,
+    now: i64,
+    ids: Ids,
+    events: Vec<Event>,
+    /// Assets whose balance changed since the last account update event
+    changed: BTreeSet<String>,
+}
+
+impl Simulator {
+    pub fn new(symbols: Vec<SymbolRules>) -> Self {
+        let markets = symbols
+            .into_iter()
+            .map(|rules| {
+                let market = Market {
+                    rules,
+                    book: Book::default(),
+                    last_price: None,
+                };
+                (market.rules.symbol.clone(), market)
+            })
+            .collect();
+        Self {
+            markets,
+            orders: BTreeMap::new(),
+            order_lists: BTreeMap::new(),
+            balances: BTreeMap::new(),
+            maker_commission: Decimal::ZERO,
+            taker_commission: Decimal::ZERO,
+            now: 0,
+            ids: Ids::default(),
+            events: Vec::new(),
+            changed: BTreeSet::new(),
+        }
+    }
+
+    /// Trades the symbols of the `ExchangeInfoResponse`, header included,
+    /// at the start of `data`.
+    pub fn from_exchange_info(data: &[u8]) -> SbeResult<Self> {
+        let info: ExchangeInfoResponse = decode(data)?;
+        let symbols = info
+            .symbols
+            .iter()
+            .map(SymbolRules::from_owned)
+            .collect::<SbeResult<_>>()?;
+        Ok(Self::new(symbols))
+    }
+
+    /// Current time, in milliseconds since the Unix epoch.
+    #[inline]
+    pub fn now(&self) -> i64 {
+        self.now
+    }
+
+    #[inline]
+    pub fn set_time(&mut self, now: i64) {
+        self.now = now;
+    }
+
+    #[inline]
+    pub fn advance(&mut self, ms: i64) {
+        self.now += ms;
+    }
+
+    /// Sets the commission rates of the account, e.g. `0.001` for 0.1%.
+    pub fn set_commission(&mut self, maker: Decimal, taker: Decimal) {
+        self.maker_commission = maker;
+        self.taker_commission = taker;
+    }
+
+    /// Adds to the free balance of `asset`, without an event.
+    pub fn deposit(&mut self, asset: &str, amount: Decimal) {
+        let balance = self.balances.entry(asset.to_owned()).or_default();
+        balance.free = add(balance.free, amount);
+    }
+
+    pub fn balance(&self, asset: &str) -> Balance {
+        self.balances.get(asset).copied().unwrap_or_default()
+    }
+
+    pub fn rules(&self, symbol: &str) -> Option<&SymbolRules> {
+        self.markets.get(symbol).map(|market| &market.rules)
+    }
+
+    /// Price of the last trade on `symbol`, which stop orders trigger on.
+    pub fn last_price(&self, symbol: &str) -> Option<Decimal> {
+        self.markets.get(symbol)?.last_price
+    }
+
+    pub fn order(&self, order_id: i64) -> Option<&Order> {
+        self.orders.get(&order_id)
+    }
+
+    /// Open orders of the account on `symbol`, oldest first.
+    pub fn open_orders<'a>(&'a self, symbol: &'a str) -> impl Iterator<Item = &'a Order> + 'a {
+        self.orders
+            .values()
+            .filter(move |order| order.symbol == symbol && order.is_open())
+    }
+
+    /// Takes the user data stream events since the last call, oldest first.
+    pub fn take_events(&mut self) -> Vec<Event> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Replaces the liquidity of other participants on `symbol` with the
+    /// levels of the `DepthResponse`, header included, at the start of
+    /// `data`. Each level rests as one order behind the account's orders at
+    /// its price, and is not matched against them.
+    pub fn load_depth(&mut self, symbol: &str, data: &[u8]) -> Result<(), Error> {
+        let depth = OrderBook::decode(data)?;
+        let market = self.market_mut(symbol)?;
+        market.book.clear_external();
+        for side in [Side::Bid, Side::Ask] {
+            for level in depth.levels(side) {
+                let resting = Resting {
+                    order_id: None,
+                    qty: level.qty,
+                };
+                market.book.insert(side, level.price, resting);
+            }
+        }
+        Ok(())
+    }
+
+    /// An order of another participant: a limit order at `price`, whose
+    /// remainder rests on the book, or a market order when `price` is
+    /// `None`. Fills of the account's orders are reported as maker trades.
+    pub fn external_order(
+        &mut self,
+        symbol: &str,
+        side: OrderSide,
+        price: Option<Decimal>,
+        qty: Decimal,
+    ) -> Result<(), Error> {
+        let market = self.market_mut(symbol)?;
+        let sweep = Sweep {
+            side,
+            limit: price,
+            qty: Some(qty),
+            budget: None,
+            step: market.rules.qty_step(price.is_none()),
+            stp: None,
+            price_exponent: market.rules.price_exponent(),
+        };
+        let steps = sweep.plan(&market.book);
+        let (executed, _) = totals(&steps, sweep.price_exponent);
+        self.apply(symbol, None, side, &steps);
+        let remaining = sub(qty, executed);
+        if let Some(price) = price {
+            if remaining > Decimal::ZERO {
+                let resting = Resting {
+                    order_id: None,
+                    qty: remaining,
+                };
+                self.market_mut(symbol)?
+                    .book
+                    .insert(book_side(side), price, resting);
+            }
+        }
+        self.run_triggers(symbol);
+        self.flush_balances();
+        Ok(())
+    }
+
+    /// Places an order and answers with a `NewOrderFullResponse`, or an
+    /// `ErrorResponse` when it is rejected.
+    pub fn new_order(&mut self, order: &NewOrder) -> SbeResult<Reply> {
+        let result = self.place(order);
+        self.flush_balances();
+        Reply::from_result(result)
+    }
+
+    /// Places an OCO and answers with a `NewOrderListFullResponse`, or an
+    /// `ErrorResponse` when it is rejected.
+    pub fn new_oco(&mut self, oco: &NewOco) -> SbeResult<Reply> {
+        let result = self.place_oco(oco);
+        self.flush_balances();
+        Reply::from_result(result)
+    }
+
+    /// Cancels an open order and answers with a `CancelOrderResponse`, or
+    /// an `ErrorResponse` when there is no such order. Canceling a leg of
+    /// an OCO cancels the other leg too.
+    pub fn cancel(&mut self, symbol: &str, order: OrderRef<'_>) -> SbeResult<Reply> {
+        let result = self.cancel_order(symbol, order);
+        self.flush_balances();
+        Reply::from_result(result)
+    }
+
+    /// Cancels an open order and places `new` in its stead, answering with
+    /// a `CancelReplaceOrderResponse`. When either part fails the answer is
+    /// an `ErrorResponse` whose `data` holds it: `-2022` with status 400
+    /// when nothing succeeded, `-2021` with status 409 when one part did.
+    pub fn cancel_replace(
+        &mut self,
+        symbol: &str,
+        cancel: OrderRef<'_>,
+        new: &NewOrder,
+        mode: CancelReplaceMode,
+    ) -> SbeResult<Reply> {
+        let allowed = match self.markets.get(symbol) {
+            None => Err(invalid_symbol()),
+            Some(market) if !market.rules.cancel_replace_allowed => Err(Rejection::new(
+                -2010,
+                "Cancel-replace orders are not supported for this symbol.",
+            )),
+            Some(_) => Ok(()),
+        };
+        if let Err(rejection) = allowed {
+            return Reply::encode(400, &rejection.to_error_response());
+        }
+
+        let canceled = self.cancel_order(symbol, cancel);
+        let placed = match (&canceled, mode) {
+            (Err(_), CancelReplaceMode::StopOnFailure) => None,
+            _ => Some(self.place(new)),
+        };
+        self.flush_balances();
+
+        let status = |ok: bool| {
+            if ok {
+                CancelReplaceStatus::Success
+            } else {
+                CancelReplaceStatus::Failure
+            }
+        };
+        let response = CancelReplaceOrderResponse {
+            cancel_result: status(canceled.is_ok()),
+            new_order_result: placed
+                .as_ref()
+                .map_or(CancelReplaceStatus::NotAttempted, |placed| {
+                    status(placed.is_ok())
+                }),
+            cancel_response: Some(match &canceled {
+                Ok(response) => response.encode()?,
+                Err(rejection) => rejection.to_error_response().encode()?,
+            }),
+            new_order_response: match &placed {
+                Some(Ok(response)) => Some(response.encode()?),
+                Some(Err(rejection)) => Some(rejection.to_error_response().encode()?),
+                None => None,
+            },
+        };
+        let error = match (canceled.is_ok(), placed.map(|placed| placed.is_ok())) {
+            (true, Some(true)) => return Reply::encode(200, &response),
+            (false, None | Some(false)) => (400, -2022, "Order cancel-replace failed."),
+            _ => (409, -2021, "Order cancel-replace partially failed."),
+        };
+        let (status, code, msg) = error;
+        let error = ErrorResponse {
+            code,
+            msg: msg.to_owned(),
+            data: Some(response.encode()?),
+            ..Default::default()
+        };
+        Reply::encode(status, &error)
+    }
+
+    fn market_mut(&mut self, symbol: &str) -> Result<&mut Market, Error> {
+        self.markets
+            .get_mut(symbol)
+            .ok_or_else(|| Error::UnknownSymbol(symbol.to_owned()))
+    }
+
+    fn open_counts(&self, symbol: &str) -> OpenOrders {
+        self.open_orders(symbol)
+            .fold(OpenOrders::default(), |mut open, order| {
+                open.orders += 1;
+                if is_algo(order.order_type) {
+                    open.algo_orders += 1;
+                }
+                open
+            })
+    }
+
+    /// Price market orders are checked against for the notional filters.
+    fn reference_price(market: &Market, side: OrderSide) -> Option<Decimal> {
+        market
+            .last_price
+            .or_else(|| market.book.best(maker_side(side)))
+    }
+
+    fn find(&self, symbol: &str, which: OrderRef<'_>) -> Option<i64> {
+        let order = match which {
+            OrderRef::Id(order_id) => self.orders.get(&order_id)?,
+            OrderRef::ClientOrderId(client_order_id) => self
+                .open_orders(symbol)
+                .find(|order| order.client_order_id == client_order_id)?,
+        };
+        (order.symbol == symbol && order.is_open()).then_some(order.order_id)
+    }
+
+    fn lock_asset(&self, order_id: i64) -> String {
+        let order = &self.orders[&order_id];
+        let rules = &self.markets[&order.symbol].rules;
+        match order.side {
+            OrderSide::Buy => rules.quote_asset.clone(),
+            _ => rules.base_asset.clone(),
+        }
+    }
+
+    fn adjust(&mut self, asset: &str, free: Decimal, locked: Decimal) {
+        let balance = self.balances.entry(asset.to_owned()).or_default();
+        balance.free = add(balance.free, free);
+        balance.locked = add(balance.locked, locked);
+        self.changed.insert(asset.to_owned());
+    }
+
+    /// Moves `amount` of the free balance into the order's lock.
+    fn lock(&mut self, order_id: i64, amount: Decimal) {
+        if amount.is_zero() {
+            return;
+        }
+        let asset = self.lock_asset(order_id);
+        let order = self.orders.get_mut(&order_id).unwrap();
+        order.locked = add(order.locked, amount);
+        self.adjust(&asset, sub(Decimal::ZERO, amount), amount);
+    }
+
+    /// Returns what is left of the order's lock to the free balance.
+    fn release(&mut self, order_id: i64) {
+        let asset = self.lock_asset(order_id);
+        let order = self.orders.get_mut(&order_id).unwrap();
+        let amount = std::mem::take(&mut order.locked);
+        if !amount.is_zero() {
+            self.adjust(&asset, amount, sub(Decimal::ZERO, amount));
+        }
+    }
+
+    /// Spends `amount` of the order's lock on a trade.
+    fn spend(&mut self, order_id: i64, amount: Decimal) {
+        let asset = self.lock_asset(order_id);
+        let order = self.orders.get_mut(&order_id).unwrap();
+        order.locked = sub(order.locked, amount);
+        self.adjust(&asset, Decimal::ZERO, sub(Decimal::ZERO, amount));
+    }
+
+    /// Balance a new order needs locked: the base quantity when selling, and
+    /// the quote amount at its limit or stop price when buying. Market
+    /// orders lock what their sweep of the book costs.
+    fn required_lock(&self, order: &NewOrder, planned: (Decimal, Decimal)) -> Decimal {
+        let rules = &self.markets[&order.symbol].rules;
+        let qty = order.qty.unwrap_or(planned.0);
+        match (order.side, order.order_type) {
+            (OrderSide::Buy, OrderType::Market) => planned.1,
+            (OrderSide::Buy, _) => {
+                let price = order.price.or(order.stop_price).unwrap_or_default();
+                ceil_to(mul(price, qty), rules.price_exponent())
+            }
+            _ => qty,
+        }
+    }
+
+    /// Records a new order and reports it.
+    fn accept(&mut self, new: &NewOrder, order_list_id: Option<i64>, orig_qty: Decimal) -> i64 {
+        let rules = &self.markets[&new.symbol].rules;
+        let order_id = next(&mut self.ids.order);
+        let order = Order {
+            symbol: new.symbol.clone(),
+            order_id,
+            order_list_id,
+            client_order_id: new
+                .client_order_id
+                .clone()
+                .unwrap_or_else(|| format!("sim-{order_id}")),
+            side: new.side,
+            order_type: new.order_type,
+            time_in_force: new.time_in_force,
+            price: new.price.unwrap_or_default(),
+            stop_price: new.stop_price,
+            orig_qty,
+            orig_quote_order_qty: new.quote_order_qty.unwrap_or_default(),
+            executed_qty: Decimal::ZERO,
+            cummulative_quote_qty: Decimal::ZERO,
+            status: OrderStatus::New,
+            self_trade_prevention_mode: rules.effective_stp_mode(new.self_trade_prevention_mode),
+            prevented_qty: Decimal::ZERO,
+            time: self.now,
+            working_time: (!is_algo(new.order_type)).then_some(self.now),
+            locked: Decimal::ZERO,
+        };
+        self.orders.insert(order_id, order);
+        let report = self.execution_report(order_id, ExecutionType::New);
+        self.events.push(Event::ExecutionReport(report));
+        order_id
+    }
+
+    fn place(&mut self, new: &NewOrder) -> Result<NewOrderFullResponse, Rejection> {
+        let market = self.markets.get(&new.symbol).ok_or_else(invalid_symbol)?;
+        let rules = &market.rules;
+        let reference_price = Self::reference_price(market, new.side);
+        rules.check(new, reference_price, self.open_counts(&new.symbol))?;
+        if let Some(client_order_id) = &new.client_order_id {
+            if self
+                .open_orders(&new.symbol)
+                .any(|order| order.client_order_id == *client_order_id)
+            {
+                return Err(Rejection::new(-2010, "Duplicate order sent."));
+            }
+        }
+        match new.order_type {
+            OrderType::LimitMaker => {
+                let best = market.book.best(maker_side(new.side));
+                let price = new.price.unwrap_or_default();
+                let takes = best.is_some_and(|best| match new.side {
+                    OrderSide::Buy => best <= price,
+                    _ => best >= price,
+                });
+                if takes {
+                    return Err(Rejection::new(
+                        -2010,
+                        "Order would immediately match and take.",
+                    ));
+                }
+            }
+            order_type if is_algo(order_type) => {
+                let stop_price = new.stop_price.unwrap_or_default();
+                if market
+                    .last_price
+                    .is_some_and(|last| triggers(order_type, new.side, stop_price, last))
+                {
+                    return Err(Rejection::new(-2010, "Order would immediately trigger."));
+                }
+            }
+            _ => {}
+        }
+
+        // Market orders are swept in advance, to lock what they will cost
+        // and to size quote quantity orders
+        let (planned, orig_qty) = if new.order_type == OrderType::Market {
+            let sweep = Sweep {
+                side: new.side,
+                limit: None,
+                qty: new.qty,
+                budget: new.quote_order_qty,
+                step: rules.qty_step(true),
+                stp: Some(rules.effective_stp_mode(new.self_trade_prevention_mode)),
+                price_exponent: rules.price_exponent(),
+            };
+            let steps = sweep.plan(&market.book);
+            let planned = totals(&steps, sweep.price_exponent);
+            let taker_prevented = steps.iter().find_map(|step| match *step {
+                Step::Prevent { taker_qty, .. } => taker_qty,
+                Step::Trade { .. } => None,
+            });
+            let sized = add(planned.0, taker_prevented.unwrap_or_default());
+            (planned, new.qty.unwrap_or(sized))
+        } else {
+            ((Decimal::ZERO, Decimal::ZERO), new.qty.unwrap_or_default())
+        };
+        let lock = self.required_lock(new, planned);
+        let lock_asset = match new.side {
+            OrderSide::Buy => &rules.quote_asset,
+            _ => &rules.base_asset,
+        };
+        if self.balance(lock_asset).free < lock {
+            return Err(insufficient_balance());
+        }
+
+        let order_id = self.accept(new, None, orig_qty);
+        self.lock(order_id, lock);
+        let outcome = if is_algo(new.order_type) {
+            Outcome::default()
+        } else {
+            self.work(order_id)
+        };
+        let response = self.full_response(order_id, outcome);
+        self.run_triggers(&new.symbol);
+        Ok(response)
+    }
+
+    fn place_oco(&mut self, oco: &NewOco) -> Result<NewOrderListFullResponse, Rejection> {
+        let market = self.markets.get(&oco.symbol).ok_or_else(invalid_symbol)?;
+        let rules = &market.rules;
+        if !rules.oco_allowed {
+            return Err(Rejection::new(
+                -1013,
+                "OCO orders are not supported for this symbol.",
+            ));
+        }
+        let limit_maker = NewOrder {
+            symbol: oco.symbol.clone(),
+            side: oco.side,
+            order_type: OrderType::LimitMaker,
+            qty: Some(oco.qty),
+            price: Some(oco.price),
+            self_trade_prevention_mode: oco.self_trade_prevention_mode,
+            ..Default::default()
+        };
+        let stop = NewOrder {
+            order_type: match oco.stop_limit_price {
+                Some(_) => OrderType::StopLossLimit,
+                None => OrderType::StopLoss,
+            },
+            time_in_force: match oco.stop_limit_price {
+                Some(_) => TimeInForce::Gtc,
+                None => TimeInForce::NullVal,
+            },
+            price: oco.stop_limit_price,
+            stop_price: Some(oco.stop_price),
+            ..limit_maker.clone()
+        };
+        let reference_price = Self::reference_price(market, oco.side);
+        let mut open = self.open_counts(&oco.symbol);
+        rules.check(&limit_maker, reference_price, open)?;
+        open.orders += 1;
+        rules.check(&stop, reference_price, open)?;
+
+        // A sell OCO's limit leg is above the market and its stop below,
+        // and the other way around for a buy
+        let mut prices = vec![oco.price];
+        prices.extend(market.last_price);
+        prices.push(oco.stop_price);
+        let ordered = prices.windows(2).all(|pair| match oco.side {
+            OrderSide::Buy => pair[0] < pair[1],
+            _ => pair[0] > pair[1],
+        });
+        if !ordered {
+            return Err(Rejection::new(
+                -2010,
+                "The relationship of the prices for the orders is not correct.",
+            ));
+        }
+        let takes = market
+            .book
+            .best(maker_side(oco.side))
+            .is_some_and(|best| match oco.side {
+                OrderSide::Buy => best <= oco.price,
+                _ => best >= oco.price,
+            });
+        if takes {
+            return Err(Rejection::new(
+                -2010,
+                "Order would immediately match and take.",
+            ));
+        }
+
+        // Both legs share one lock, which is enough for either
+        let lock = self
+            .required_lock(&limit_maker, Default::default())
+            .max(self.required_lock(&stop, Default::default()));
+        let lock_asset = match oco.side {
+            OrderSide::Buy => &rules.quote_asset,
+            _ => &rules.base_asset,
+        };
+        if self.balance(lock_asset).free < lock {
+            return Err(insufficient_balance());
+        }
+
+        let order_list_id = next(&mut self.ids.order_list);
+        let list_client_order_id = oco
+            .list_client_order_id
+            .clone()
+            .unwrap_or_else(|| format!("sim-list-{order_list_id}"));
+        let stop_id = self.accept(&stop, Some(order_list_id), oco.qty);
+        let limit_id = self.accept(&limit_maker, Some(order_list_id), oco.qty);
+        self.lock(limit_id, lock);
+        let list = OrderList {
+            order_list_id,
+            list_client_order_id,
+            symbol: oco.symbol.clone(),
+            orders: [stop_id, limit_id],
+            all_done: false,
+        };
+        let event = self.list_status_event(&list);
+        self.events.push(Event::ListStatus(event));
+        self.order_lists.insert(order_list_id, list);
+        self.work(limit_id);
+
+        let list = &self.order_lists[&order_list_id];
+        let rules = &self.markets[&oco.symbol].rules;
+        Ok(NewOrderListFullResponse {
+            order_list_id,
+            contingency_type: ContingencyType::Oco,
+            list_status_type: ListStatusType::ExecStarted,
+            list_order_status: ListOrderStatus::Executing,
+            transaction_time: self.now,
+            price_exponent: rules.price_exponent(),
+            qty_exponent: rules.qty_exponent(),
+            orders: list
+                .orders
+                .iter()
+                .map(|order_id| order_list::Orders {
+                    order_id: *order_id,
+                    symbol: list.symbol.clone(),
+                    client_order_id: self.orders[order_id].client_order_id.clone(),
+                })
+                .collect(),
+            order_reports: list
+                .orders
+                .iter()
+                .map(|order_id| self.order_report(*order_id))
+                .collect(),
+            list_client_order_id: list.list_client_order_id.clone(),
+            symbol: list.symbol.clone(),
+        })
+    }
+
+    fn cancel_order(
+        &mut self,
+        symbol: &str,
+        which: OrderRef<'_>,
+    ) -> Result<CancelOrderResponse, Rejection> {
+        if !self.markets.contains_key(symbol) {
+            return Err(invalid_symbol());
+        }
+        let order_id = self
+            .find(symbol, which)
+            .ok_or_else(|| Rejection::new(-2011, "Unknown order sent."))?;
+        let cancel_client_order_id = format!("sim-cancel-{order_id}");
+        self.end(
+            order_id,
+            OrderStatus::Canceled,
+            Some(&cancel_client_order_id),
+        );
+
+        let order = &self.orders[&order_id];
+        let rules = &self.markets[symbol].rules;
+        let response = CancelOrderResponse {
+            price_exponent: rules.price_exponent(),
+            qty_exponent: rules.qty_exponent(),
+            order_id,
+            order_list_id: order.order_list_id,
+            transact_time: self.now,
+            price: order.price,
+            orig_qty: order.orig_qty,
+            executed_qty: order.executed_qty,
+            cummulative_quote_qty: order.cummulative_quote_qty,
+            status: order.status,
+            time_in_force: order.time_in_force,
+            order_type: order.order_type,
+            side: order.side,
+            stop_price: order.stop_price,
+            working_floor: Floor::Exchange,
+            self_trade_prevention_mode: order.self_trade_prevention_mode,
+            prevented_quantity: prevented_qty(order),
+            used_sor: BoolEnum::False,
+            orig_quote_order_qty: order.orig_quote_order_qty,
+            symbol: order.symbol.clone(),
+            orig_client_order_id: order.client_order_id.clone(),
+            client_order_id: cancel_client_order_id,
+            ..Default::default()
+        };
+        if let Some(order_list_id) = order.order_list_id {
+            self.close_list(order_list_id, order_id, OrderStatus::Canceled, false);
+        }
+        Ok(response)
+    }
+
+    /// Takes an open order off the book, ends it with `status` and reports
+    /// it. A cancel is reported under the cancel's client order ID.
+    fn end(&mut self, order_id: i64, status: OrderStatus, cancel_client_order_id: Option<&str>) {
+        let order = &self.orders[&order_id];
+        if order.working_time.is_some() && !is_market(order.order_type) {
+            let (side, price) = (book_side(order.side), order.price);
+            let market = self.markets.get_mut(&order.symbol).unwrap();
+            market.book.remove(side, price, order_id);
+        }
+        self.orders.get_mut(&order_id).unwrap().status = status;
+        self.release(order_id);
+        let execution_type = match status {
+            OrderStatus::Canceled => ExecutionType::Canceled,
+            _ => ExecutionType::Expired,
+        };
+        let mut report = self.execution_report(order_id, execution_type);
+        if let Some(cancel_client_order_id) = cancel_client_order_id {
+            report.orig_client_order_id = Some(report.client_order_id);
+            report.client_order_id = cancel_client_order_id.to_owned();
+        }
+        self.events.push(Event::ExecutionReport(report));
+    }
+
+    /// Ends an OCO once `leg` fills, triggers or is canceled: the other leg
+    /// ends with `status`, and hands its lock to `leg` if `hand_over`.
+    fn close_list(&mut self, order_list_id: i64, leg: i64, status: OrderStatus, hand_over: bool) {
+        let list = self.order_lists.get_mut(&order_list_id).unwrap();
+        if std::mem::replace(&mut list.all_done, true) {
+            return;
+        }
+        let others: Vec<i64> = list
+            .orders
+            .iter()
+            .copied()
+            .filter(|id| *id != leg)
+            .collect();
+        for other in others {
+            if !self.orders[&other].is_open() {
+                continue;
+            }
+            if hand_over {
+                let locked = std::mem::take(&mut self.orders.get_mut(&other).unwrap().locked);
+                let to = self.orders.get_mut(&leg).unwrap();
+                to.locked = add(to.locked, locked);
+            }
+            let cancel_client_order_id = format!("sim-cancel-{other}");
+            let cancel =
+                (status == OrderStatus::Canceled).then_some(cancel_client_order_id.as_str());
+            self.end(other, status, cancel);
+        }
+        let event = self.list_status_event(&self.order_lists[&order_list_id]);
+        self.events.push(Event::ListStatus(event));
+    }
+
+    /// Matches a working order against the book, then rests what is left
+    /// of a limit order or expires it.
+    fn work(&mut self, order_id: i64) -> Outcome {
+        let order = &self.orders[&order_id];
+        let market = &self.markets[&order.symbol];
+        let rules = &market.rules;
+        let market_order = is_market(order.order_type);
+        let remaining = order.remaining_qty();
+        let (qty, budget) = if order.orig_quote_order_qty > Decimal::ZERO {
+            let budget = sub(order.orig_quote_order_qty, order.cummulative_quote_qty);
+            (None, Some(budget))
+        } else if market_order && is_algo(order.order_type) && order.side == OrderSide::Buy {
+            // A triggered stop buys what its lock at the stop price affords
+            (Some(remaining), Some(order.locked))
+        } else {
+            (Some(remaining), None)
+        };
+        let sweep = Sweep {
+            side: order.side,
+            limit: (!market_order).then_some(order.price),
+            qty,
+            budget,
+            step: rules.qty_step(market_order),
+            stp: Some(order.self_trade_prevention_mode),
+            price_exponent: rules.price_exponent(),
+        };
+        let mut steps = sweep.plan(&market.book);
+        if order.time_in_force == TimeInForce::Fok
+            && totals(&steps, sweep.price_exponent).0 < remaining
+        {
+            steps.clear();
+        }
+        let (symbol, side) = (order.symbol.clone(), order.side);
+        let outcome = self.apply(&symbol, Some(order_id), side, &steps);
+
+        let order = &self.orders[&order_id];
+        let rests =
+            !market_order && matches!(order.time_in_force, TimeInForce::Gtc | TimeInForce::NullVal);
+        if order.status == OrderStatus::ExpiredInMatch {
+            self.release(order_id);
+        } else if order.is_open() && rests {
+            let (side, price) = (book_side(order.side), order.price);
+            let resting = Resting {
+                order_id: Some(order_id),
+                qty: order.remaining_qty(),
+            };
+            let market = self.markets.get_mut(&symbol).unwrap();
+            market.book.insert(side, price, resting);
+        } else if order.is_open() {
+            self.end(order_id, OrderStatus::Expired, None);
+        }
+        outcome
+    }
+
+    /// Carries out a planned sweep by `taker`, the account's order or
+    /// another participant's.
+    fn apply(
+        &mut self,
+        symbol: &str,
+        taker: Option<i64>,
+        side: OrderSide,
+        steps: &[Step],
+    ) -> Outcome {
+        let mut outcome = Outcome::default();
+        for step in steps {
+            match *step {
+                Step::Trade { price, qty, maker } => {
+                    let market = self.markets.get_mut(symbol).unwrap();
+                    market.book.fill_front(maker_side(side), qty);
+                    market.last_price = Some(price);
+                    let quote = floor_to(mul(price, qty), market.rules.price_exponent());
+                    let trade_id = next(&mut self.ids.trade);
+                    if let Some(taker) = taker {
+                        let fill = self.fill(taker, trade_id, price, qty, quote, false);
+                        outcome.fills.push(fill);
+                    }
+                    if let Some(maker) = maker {
+                        self.fill(maker, trade_id, price, qty, quote, true);
+                    }
+                }
+                Step::Prevent {
+                    price,
+                    qty,
+                    maker,
+                    maker_qty,
+                    expire_maker,
+                    taker_qty,
+                } => {
+                    let taker = taker.expect("only the account's orders prevent matches");
+                    let prevented_match_id = next(&mut self.ids.prevented_match);
+                    let quote = {
+                        let rules = &self.markets[symbol].rules;
+                        floor_to(mul(price, qty), rules.price_exponent())
+                    };
+                    let prevent =
+                        |this: &mut Self, order_id: i64, counter_order_id: i64, lost: Decimal| {
+                            let order = this.orders.get_mut(&order_id).unwrap();
+                            order.prevented_qty = add(order.prevented_qty, lost);
+                            order.status = OrderStatus::ExpiredInMatch;
+                            let mut report =
+                                this.execution_report(order_id, ExecutionType::TradePrevention);
+                            report.last_prevented_qty = Some(lost);
+                            report.prevented_match_id = Some(prevented_match_id);
+                            report.prevented_execution_qty = Some(qty);
+                            report.prevented_execution_price = Some(price);
+                            report.prevented_execution_quote_qty = Some(quote);
+                            report.counter_order_id = Some(counter_order_id);
+                            report.counter_symbol = Some(symbol.to_owned());
+                            this.events.push(Event::ExecutionReport(report));
+                        };
+                    if expire_maker {
+                        let market = self.markets.get_mut(symbol).unwrap();
+                        market.book.remove(maker_side(side), price, maker);
+                        prevent(self, maker, taker, maker_qty);
+                        self.release(maker);
+                        if let Some(order_list_id) = self.orders[&maker].order_list_id {
+                            self.close_list(order_list_id, maker, OrderStatus::Expired, false);
+                        }
+                    }
+                    if let Some(taker_qty) = taker_qty {
+                        prevent(self, taker, maker, taker_qty);
+                    }
+                    outcome.prevented_matches.push(PreventedMatches {
+                        prevented_match_id,
+                        maker_order_id: Some(maker),
+                        price: Some(price),
+                        taker_prevented_quantity: taker_qty,
+                        maker_prevented_quantity: expire_maker.then_some(maker_qty),
+                        maker_symbol: Some(symbol.to_owned()),
+                    });
+                }
+            }
+        }
+        outcome
+    }
+
+    /// Books a trade of the account's order: its balances, commission and
+    /// execution report.
+    fn fill(
+        &mut self,
+        order_id: i64,
+        trade_id: i64,
+        price: Decimal,
+        qty: Decimal,
+        quote: Decimal,
+        is_maker: bool,
+    ) -> Fills {
+        let order = self.orders.get_mut(&order_id).unwrap();
+        order.executed_qty = add(order.executed_qty, qty);
+        order.cummulative_quote_qty = add(order.cummulative_quote_qty, quote);
+        order.status = if order.executed_qty >= order.orig_qty {
+            OrderStatus::Filled
+        } else {
+            OrderStatus::PartiallyFilled
+        };
+        let (side, order_list_id, filled) = (
+            order.side,
+            order.order_list_id,
+            order.status == OrderStatus::Filled,
+        );
+        let rules = &self.markets[&order.symbol].rules;
+        let rate = if is_maker {
+            self.maker_commission
+        } else {
+            self.taker_commission
+        };
+        // Commission is taken from what the trade pays out
+        let (spent, received, commission, commission_asset) = match side {
+            OrderSide::Buy => {
+                let exponent = -(rules.base_commission_precision as i8);
+                let commission = floor_to(mul(qty, rate), exponent);
+                (quote, qty, commission, rules.base_asset.clone())
+            }
+            _ => {
+                let exponent = -(rules.quote_commission_precision as i8);
+                let commission = floor_to(mul(quote, rate), exponent);
+                (qty, quote, commission, rules.quote_asset.clone())
+            }
+        };
+        let commission_exponent = rules.commission_exponent();
+        self.spend(order_id, spent);
+        self.adjust(&commission_asset, sub(received, commission), Decimal::ZERO);
+
+        let mut report = self.execution_report(order_id, ExecutionType::Trade);
+        report.trade_id = Some(trade_id);
+        report.last_qty = qty;
+        report.last_price = price;
+        report.quote_qty = quote;
+        report.commission = commission;
+        report.commission_asset = Some(commission_asset.clone());
+        report.is_maker = bool_enum(is_maker);
+        report.is_best_match = BoolEnum::True;
+        self.events.push(Event::ExecutionReport(report));
+
+        if filled {
+            self.release(order_id);
+        }
+        if let Some(order_list_id) = order_list_id {
+            self.close_list(order_list_id, order_id, OrderStatus::Expired, false);
+        }
+        Fills {
+            commission_exponent,
+            match_type: MatchType::NullVal,
+            price,
+            qty,
+            commission,
+            trade_id: Some(trade_id),
+            alloc_id: None,
+            commission_asset: Some(commission_asset),
+        }
+    }
+
+    /// Triggers the stop orders on `symbol` that the last price has
+    /// reached, oldest first, until none are left. A triggered order may
+    /// trade and trigger others.
+    fn run_triggers(&mut self, symbol: &str) {
+        loop {
+            let Some(last) = self.markets[symbol].last_price else {
+                return;
+            };
+            let triggered = self.open_orders(symbol).find(|order| {
+                order.working_time.is_none()
+                    && triggers(
+                        order.order_type,
+                        order.side,
+                        order.stop_price.unwrap_or_default(),
+                        last,
+                    )
+            });
+            let Some(order_id) = triggered.map(|order| order.order_id) else {
+                return;
+            };
+            if let Some(order_list_id) = self.orders[&order_id].order_list_id {
+                self.close_list(order_list_id, order_id, OrderStatus::Expired, true);
+            }
+            self.orders.get_mut(&order_id).unwrap().working_time = Some(self.now);
+            let report = self.execution_report(order_id, ExecutionType::New);
+            self.events.push(Event::ExecutionReport(report));
+            self.work(order_id);
+        }
+    }
+
+    /// Reports the balances that changed since the last report.
+    fn flush_balances(&mut self) {
+        if self.changed.is_empty() {
+            return;
+        }
+        let balances = std::mem::take(&mut self.changed)
+            .into_iter()
+            .map(|asset| {
+                let Balance { free, locked } = self.balance(&asset);
+                let exponent = [free, locked]
+                    .iter()
+                    .map(|value| value.normalize().exponent())
+                    .fold(-8, i8::min);
+                Balances {
+                    exponent,
+                    free,
+                    locked,
+                    asset,
+                }
+            })
+            .collect();
+        self.events
+            .push(Event::AccountPosition(OutboundAccountPositionEvent {
+                event_time: self.now,
+                update_time: self.now,
+                balances,
+            }));
+    }
+
+    /// An execution report of the order as it stands, with a new
+    /// execution ID. Trade and prevention details are filled in by the
+    /// caller.
+    fn execution_report(
+        &mut self,
+        order_id: i64,
+        execution_type: ExecutionType,
+    ) -> ExecutionReportEvent {
+        let execution_id = next(&mut self.ids.execution);
+        let order = &self.orders[&order_id];
+        let rules = &self.markets[&order.symbol].rules;
+        ExecutionReportEvent {
+            event_time: self.now,
+            transact_time: self.now,
+            price_exponent: rules.price_exponent(),
+            qty_exponent: rules.qty_exponent(),
+            commission_exponent: rules.commission_exponent(),
+            order_creation_time: order.time,
+            working_time: order.working_time,
+            order_id,
+            order_list_id: order.order_list_id,
+            orig_qty: order.orig_qty,
+            price: order.price,
+            orig_quote_order_qty: order.orig_quote_order_qty,
+            stop_price: order.stop_price.unwrap_or_default(),
+            order_type: order.order_type,
+            side: order.side,
+            time_in_force: order.time_in_force,
+            execution_type,
+            order_status: order.status,
+            execution_id,
+            executed_qty: order.executed_qty,
+            cummulative_quote_qty: order.cummulative_quote_qty,
+            is_working: bool_enum(order.working_time.is_some()),
+            is_maker: BoolEnum::False,
+            is_best_match: BoolEnum::False,
+            self_trade_prevention_mode: order.self_trade_prevention_mode,
+            working_floor: Floor::Exchange,
+            used_sor: BoolEnum::False,
+            prevented_qty: prevented_qty(order),
+            symbol: order.symbol.clone(),
+            client_order_id: order.client_order_id.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn list_status_event(&self, list: &OrderList) -> ListStatusEvent {
+        let (list_status_type, list_order_status) = if list.all_done {
+            (ListStatusType::AllDone, ListOrderStatus::AllDone)
+        } else {
+            (ListStatusType::ExecStarted, ListOrderStatus::Executing)
+        };
+        ListStatusEvent {
+            event_time: self.now,
+            transact_time: self.now,
+            order_list_id: list.order_list_id,
+            contingency_type: ContingencyType::Oco,
+            list_status_type,
+            list_order_status,
+            orders: list
+                .orders
+                .iter()
+                .map(|order_id| list_status_event_codec::owned::Orders {
+                    order_id: *order_id,
+                    symbol: list.symbol.clone(),
+                    client_order_id: self.orders[order_id].client_order_id.clone(),
+                })
+                .collect(),
+            symbol: list.symbol.clone(),
+            list_client_order_id: list.list_client_order_id.clone(),
+            reject_reason: None,
+        }
+    }
+
+    fn full_response(&self, order_id: i64, outcome: Outcome) -> NewOrderFullResponse {
+        let order = &self.orders[&order_id];
+        let rules = &self.markets[&order.symbol].rules;
+        NewOrderFullResponse {
+            price_exponent: rules.price_exponent(),
+            qty_exponent: rules.qty_exponent(),
+            order_id,
+            order_list_id: order.order_list_id,
+            transact_time: order.time,
+            price: order.price,
+            orig_qty: order.orig_qty,
+            executed_qty: order.executed_qty,
+            cummulative_quote_qty: order.cummulative_quote_qty,
+            status: order.status,
+            time_in_force: order.time_in_force,
+            order_type: order.order_type,
+            side: order.side,
+            stop_price: order.stop_price,
+            working_time: order.working_time,
+            working_floor: Floor::Exchange,
+            self_trade_prevention_mode: order.self_trade_prevention_mode,
+            prevented_quantity: prevented_qty(order),
+            used_sor: BoolEnum::False,
+            orig_quote_order_qty: order.orig_quote_order_qty,
+            fills: outcome.fills,
+            prevented_matches: outcome.prevented_matches,
+            symbol: order.symbol.clone(),
+            client_order_id: order.client_order_id.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn order_report(&self, order_id: i64) -> OrderReports {
+        let order = &self.orders[&order_id];
+        OrderReports {
+            order_id,
+            order_list_id: order.order_list_id,
+            transact_time: order.time,
+            price: order.price,
+            orig_qty: order.orig_qty,
+            executed_qty: order.executed_qty,
+            cummulative_quote_qty: order.cummulative_quote_qty,
+            status: order.status,
+            time_in_force: order.time_in_force,
+            order_type: order.order_type,
+            side: order.side,
+            stop_price: order.stop_price,
+            working_time: order.working_time,
+            working_floor: Floor::Exchange,
+            self_trade_prevention_mode: order.self_trade_prevention_mode,
+            prevented_quantity: prevented_qty(order),
+            used_sor: BoolEnum::False,
+            orig_quote_order_qty: order.orig_quote_order_qty,
+            symbol: order.symbol.clone(),
+            client_order_id: order.client_order_id.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+#[inline]
+fn prevented_qty(order: &Order) -> Option<Decimal> {
+    (!order.prevented_qty.is_zero()).then_some(order.prevented_qty)
+}
+
+// Amounts are validated against the symbol's precisions, so their i64
+// mantissas cannot overflow the i128 arithmetic of `Decimal`.
+
+fn add(a: Decimal, b: Decimal) -> Decimal {
+    a.checked_add(b).expect("decimal overflow")
+}
+
+fn sub(a: Decimal, b: Decimal) -> Decimal {
+    a.checked_sub(b).expect("decimal overflow")
+}
+
+fn mul(a: Decimal, b: Decimal) -> Decimal {
+    a.checked_mul(b).expect("decimal overflow")
+}
+
+fn floor_to(value: Decimal, exponent: i8) -> Decimal {
+    value
+        .round_to_tick(Decimal::new(1, exponent), Rounding::Floor)
+        .expect("decimal overflow")
+}
+
+fn ceil_to(value: Decimal, exponent: i8) -> Decimal {
+    value
+        .round_to_tick(Decimal::new(1, exponent), Rounding::Ceil)
+        .expect("decimal overflow")
+}
+
+fn floor_to_step(value: Decimal, step: Decimal) -> Decimal {
+    value
+        .round_to_tick(step, Rounding::Floor)
+        .expect("decimal overflow")
+}
+
+/// `a / b` rounded down to 18 decimal places, for a positive `b`.
+fn div_floor(a: Decimal, b: Decimal) -> Decimal {
+    const EXPONENT: i8 = -18;
+    let shift = i32::from(a.exponent()) - i32::from(b.exponent()) - i32::from(EXPONENT);
+    let (numerator, denominator) = if shift >= 0 {
+        (mul_pow10(a.mantissa(), shift), b.mantissa())
+    } else {
+        (a.mantissa(), mul_pow10(b.mantissa(), -shift))
+    };
+    Decimal::new(numerator.div_euclid(denominator), EXPONENT)
+}
+
+fn mul_pow10(mantissa: i128, shift: i32) -> i128 {
+    10i128
+        .checked_pow(shift as u32)
+        .and_then(|factor| mantissa.checked_mul(factor))
+        .expect("decimal overflow")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spot_sbe::lot_size_filter_codec::owned::LotSizeFilter;
+    use spot_sbe::notional_filter_codec::owned::NotionalFilter;
+    use spot_sbe::price_filter_codec::owned::PriceFilter;
+
+    fn d(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn btcusdt() -> SymbolRules {
+        SymbolRules {
+            symbol: "BTCUSDT".into(),
+            base_asset: "BTC".into(),
+            quote_asset: "USDT".into(),
+            status: SymbolStatus::Trading,
+            base_asset_precision: 8,
+            quote_asset_precision: 8,
+            base_commission_precision: 8,
+            quote_commission_precision: 8,
+            order_types: OrderTypes::new(u16::MAX),
+            oco_allowed: true,
+            quote_order_qty_market_allowed: true,
+            cancel_replace_allowed: true,
+            default_self_trade_prevention_mode: SelfTradePreventionMode::None,
+            allowed_self_trade_prevention_modes: AllowedSelfTradePreventionModes::new(u8::MAX),
+            filters: vec![
+                Filter::Price(PriceFilter {
+                    price_exponent: -2,
+                    min_price: d("0.01"),
+                    max_price: d("1000000"),
+                    tick_size: d("0.01"),
+                }),
+                Filter::LotSize(LotSizeFilter {
+                    qty_exponent: -3,
+                    min_qty: d("0.001"),
+                    max_qty: d("1000"),
+                    step_size: d("0.001"),
+                }),
+                Filter::Notional(NotionalFilter {
+                    price_exponent: 0,
+                    min_notional: d("5"),
+                    apply_min_to_market: BoolEnum::True,
+                    max_notional: d("1000000"),
+                    apply_max_to_market: BoolEnum::False,
+                    avg_price_mins: 5,
+                }),
+            ],
+        }
+    }
+
+    /// A simulator with asks of other participants at `asks` and funds for
+    /// either side.
+    fn simulator(asks: &[(&str, &str)]) -> Simulator {
+        let mut sim = Simulator::new(vec![btcusdt()]);
+        for (price, qty) in asks {
+            sim.external_order("BTCUSDT", OrderSide::Sell, Some(d(price)), d(qty))
+                .unwrap();
+        }
+        sim.deposit("USDT", d("1000"));
+        sim.deposit("BTC", d("10"));
+        sim
+    }
+
+    fn placed(reply: &Reply) -> NewOrderFullResponse {
+        assert_eq!(reply.status, 200);
+        decode(&reply.body).unwrap()
+    }
+
+    fn rejected(reply: &Reply) -> ErrorResponse {
+        assert_ne!(reply.status, 200);
+        decode(&reply.body).unwrap()
+    }
+
+    fn execution_types(events: &[Event]) -> Vec<(i64, ExecutionType, OrderStatus)> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                Event::ExecutionReport(report) => {
+                    Some((report.order_id, report.execution_type, report.order_status))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn filter_failures_are_rejected_with_1013() {
+        let mut sim = simulator(&[]);
+        let off_tick = NewOrder::limit("BTCUSDT", OrderSide::Buy, d("100.005"), d("1"));
+        let reply = sim.new_order(&off_tick).unwrap();
+        assert_eq!(reply.status, 400);
+        let error = rejected(&reply);
+        assert_eq!(error.code, -1013);
+        assert_eq!(error.msg, "Filter failure: PRICE_FILTER");
+
+        let small = NewOrder::limit("BTCUSDT", OrderSide::Buy, d("100"), d("0.01"));
+        let error = rejected(&sim.new_order(&small).unwrap());
+        assert_eq!(error.msg, "Filter failure: NOTIONAL");
+
+        let unknown = NewOrder::limit("ETHUSDT", OrderSide::Buy, d("100"), d("1"));
+        assert_eq!(rejected(&sim.new_order(&unknown).unwrap()).code, -1121);
+        assert!(sim.take_events().is_empty());
+        assert_eq!(sim.open_orders("BTCUSDT").count(), 0);
+    }
+
+    #[test]
+    fn market_order_sweeps_levels() {
+        let mut sim = simulator(&[("100", "1"), ("101", "1"), ("102", "1")]);
+        let response = placed(
+            &sim.new_order(&NewOrder::market("BTCUSDT", OrderSide::Buy, d("2.5")))
+                .unwrap(),
+        );
+        assert_eq!(response.status, OrderStatus::Filled);
+        let fills: Vec<_> = response
+            .fills
+            .iter()
+            .map(|fill| (fill.price, fill.qty, fill.trade_id))
+            .collect();
+        assert_eq!(
+            fills,
+            [
+                (d("100"), d("1"), Some(1)),
+                (d("101"), d("1"), Some(2)),
+                (d("102"), d("0.5"), Some(3)),
+            ]
+        );
+        assert_eq!(response.executed_qty, d("2.5"));
+        assert_eq!(response.cummulative_quote_qty, d("252"));
+        assert_eq!(sim.last_price("BTCUSDT"), Some(d("102")));
+        assert_eq!(sim.balance("USDT").free, d("748"));
+        assert_eq!(sim.balance("USDT").locked, Decimal::ZERO);
+        assert_eq!(sim.balance("BTC").free, d("12.5"));
+
+        let events = sim.take_events();
+        assert_eq!(
+            execution_types(&events),
+            [
+                (1, ExecutionType::New, OrderStatus::New),
+                (1, ExecutionType::Trade, OrderStatus::PartiallyFilled),
+                (1, ExecutionType::Trade, OrderStatus::PartiallyFilled),
+                (1, ExecutionType::Trade, OrderStatus::Filled),
+            ]
+        );
+        let Some(Event::AccountPosition(position)) = events.last() else {
+            panic!("no account update after the trades");
+        };
+        let assets: Vec<_> = position.balances.iter().map(|b| b.asset.as_str()).collect();
+        assert_eq!(assets, ["BTC", "USDT"]);
+        for event in &events {
+            event.encode().unwrap();
+        }
+    }
+
+    #[test]
+    fn market_order_with_quote_quantity_spends_up_to_it() {
+        let mut sim = simulator(&[("100", "1"), ("101", "1")]);
+        let order = NewOrder {
+            qty: None,
+            quote_order_qty: Some(d("150")),
+            ..NewOrder::market("BTCUSDT", OrderSide::Buy, Decimal::ZERO)
+        };
+        let response = placed(&sim.new_order(&order).unwrap());
+        assert_eq!(response.status, OrderStatus::Filled);
+        assert_eq!(response.orig_quote_order_qty, d("150"));
+        // 50 left buys 0.495 at 101, floored to the lot step
+        assert_eq!(response.orig_qty, d("1.495"));
+        assert_eq!(response.executed_qty, d("1.495"));
+        assert_eq!(response.cummulative_quote_qty, d("149.995"));
+        assert_eq!(sim.balance("USDT").free, d("850.005"));
+        assert_eq!(sim.balance("USDT").locked, Decimal::ZERO);
+    }
+
+    #[test]
+    fn self_trade_prevention_follows_the_taker_mode() {
+        let cases = [
+            (
+                SelfTradePreventionMode::ExpireTaker,
+                OrderStatus::New,
+                OrderStatus::ExpiredInMatch,
+            ),
+            (
+                SelfTradePreventionMode::ExpireMaker,
+                OrderStatus::ExpiredInMatch,
+                OrderStatus::New,
+            ),
+            (
+                SelfTradePreventionMode::ExpireBoth,
+                OrderStatus::ExpiredInMatch,
+                OrderStatus::ExpiredInMatch,
+            ),
+        ];
+        for (mode, maker_status, taker_status) in cases {
+            let mut sim = simulator(&[]);
+            let maker = NewOrder::limit("BTCUSDT", OrderSide::Sell, d("100"), d("1"));
+            placed(&sim.new_order(&maker).unwrap());
+            let taker = NewOrder {
+                self_trade_prevention_mode: mode,
+                ..NewOrder::limit("BTCUSDT", OrderSide::Buy, d("100"), d("1"))
+            };
+            let response = placed(&sim.new_order(&taker).unwrap());
+            assert!(response.fills.is_empty(), "{mode:?}");
+            assert_eq!(response.status, taker_status, "{mode:?}");
+            assert_eq!(response.self_trade_prevention_mode, mode);
+            let [prevented] = &response.prevented_matches[..] else {
+                panic!("{mode:?}: {:?}", response.prevented_matches);
+            };
+            assert_eq!(prevented.maker_order_id, Some(1));
+            assert_eq!(prevented.price, Some(d("100")));
+            let expired = |status| (status == OrderStatus::ExpiredInMatch).then(|| d("1"));
+            assert_eq!(prevented.maker_prevented_quantity, expired(maker_status));
+            assert_eq!(prevented.taker_prevented_quantity, expired(taker_status));
+            assert_eq!(sim.order(1).unwrap().status, maker_status, "{mode:?}");
+            assert_eq!(sim.last_price("BTCUSDT"), None);
+            // Expired orders hand back their locks
+            let usdt_locked = if taker_status == OrderStatus::New {
+                "100"
+            } else {
+                "0"
+            };
+            let btc_locked = if maker_status == OrderStatus::New {
+                "1"
+            } else {
+                "0"
+            };
+            assert_eq!(sim.balance("USDT").locked, d(usdt_locked), "{mode:?}");
+            assert_eq!(sim.balance("BTC").locked, d(btc_locked), "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn self_trade_prevention_none_trades_with_itself() {
+        let mut sim = simulator(&[]);
+        let maker = NewOrder::limit("BTCUSDT", OrderSide::Sell, d("100"), d("1"));
+        placed(&sim.new_order(&maker).unwrap());
+        let taker = NewOrder::limit("BTCUSDT", OrderSide::Buy, d("100"), d("1"));
+        let response = placed(&sim.new_order(&taker).unwrap());
+        assert_eq!(response.status, OrderStatus::Filled);
+        assert!(response.prevented_matches.is_empty());
+        assert_eq!(sim.order(1).unwrap().status, OrderStatus::Filled);
+    }
+
+    #[test]
+    fn fill_or_kill_expires_without_trading() {
+        let mut sim = simulator(&[("100", "1")]);
+        let order = NewOrder {
+            time_in_force: TimeInForce::Fok,
+            ..NewOrder::limit("BTCUSDT", OrderSide::Buy, d("100"), d("2"))
+        };
+        let response = placed(&sim.new_order(&order).unwrap());
+        assert_eq!(response.status, OrderStatus::Expired);
+        assert!(response.fills.is_empty());
+        assert_eq!(sim.balance("USDT").free, d("1000"));
+        assert_eq!(sim.balance("USDT").locked, Decimal::ZERO);
+
+        // The ask is still there
+        let order = NewOrder {
+            qty: Some(d("1")),
+            ..order
+        };
+        let response = placed(&sim.new_order(&order).unwrap());
+        assert_eq!(response.status, OrderStatus::Filled);
+    }
+
+    #[test]
+    fn limit_maker_that_would_take_is_rejected() {
+        let mut sim = simulator(&[("100", "1")]);
+        let order = NewOrder {
+            order_type: OrderType::LimitMaker,
+            time_in_force: TimeInForce::NullVal,
+            ..NewOrder::limit("BTCUSDT", OrderSide::Buy, d("100"), d("1"))
+        };
+        let error = rejected(&sim.new_order(&order).unwrap());
+        assert_eq!(error.code, -2010);
+        assert_eq!(error.msg, "Order would immediately match and take.");
+        let order = NewOrder {
+            price: Some(d("99.99")),
+            ..order
+        };
+        assert_eq!(
+            placed(&sim.new_order(&order).unwrap()).status,
+            OrderStatus::New
+        );
+    }
+
+    #[test]
+    fn oco_legs_end_together() {
+        let mut sim = simulator(&[]);
+        let oco = NewOco {
+            symbol: "BTCUSDT".into(),
+            side: OrderSide::Sell,
+            qty: d("1"),
+            price: d("110"),
+            stop_price: d("90"),
+            ..Default::default()
+        };
+        let reply = sim.new_oco(&oco).unwrap();
+        assert_eq!(reply.status, 200);
+        let response: NewOrderListFullResponse = decode(&reply.body).unwrap();
+        assert_eq!(response.list_client_order_id, "sim-list-1");
+        let types: Vec<_> = response
+            .order_reports
+            .iter()
+            .map(|report| (report.order_id, report.order_type))
+            .collect();
+        assert_eq!(
+            types,
+            [(1, OrderType::StopLoss), (2, OrderType::LimitMaker)]
+        );
+        // One lock covers both legs
+        assert_eq!(sim.balance("BTC").locked, d("1"));
+        sim.take_events();
+
+        sim.external_order("BTCUSDT", OrderSide::Buy, Some(d("110")), d("1"))
+            .unwrap();
+        assert_eq!(sim.order(2).unwrap().status, OrderStatus::Filled);
+        assert_eq!(sim.order(1).unwrap().status, OrderStatus::Expired);
+        assert_eq!(
+            sim.balance("BTC"),
+            Balance {
+                free: d("9"),
+                locked: Decimal::ZERO
+            }
+        );
+        assert_eq!(sim.balance("USDT").free, d("1110"));
+        let events = sim.take_events();
+        assert_eq!(
+            execution_types(&events),
+            [
+                (2, ExecutionType::Trade, OrderStatus::Filled),
+                (1, ExecutionType::Expired, OrderStatus::Expired),
+            ]
+        );
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::ListStatus(status) if status.list_order_status == ListOrderStatus::AllDone
+        )));
+    }
+
+    #[test]
+    fn oco_prices_must_straddle_the_market() {
+        let mut sim = simulator(&[]);
+        let oco = NewOco {
+            symbol: "BTCUSDT".into(),
+            side: OrderSide::Sell,
+            qty: d("1"),
+            price: d("90"),
+            stop_price: d("110"),
+            ..Default::default()
+        };
+        let error = rejected(&sim.new_oco(&oco).unwrap());
+        assert_eq!(error.code, -2010);
+        assert!(sim.take_events().is_empty());
+    }
+
+    #[test]
+    fn cancel_replace_reports_each_part() {
+        let mut sim = simulator(&[]);
+        placed(
+            &sim.new_order(&NewOrder::limit("BTCUSDT", OrderSide::Buy, d("90"), d("1")))
+                .unwrap(),
+        );
+        let new = NewOrder::limit("BTCUSDT", OrderSide::Buy, d("95"), d("1"));
+
+        let reply = sim
+            .cancel_replace(
+                "BTCUSDT",
+                OrderRef::Id(1),
+                &new,
+                CancelReplaceMode::StopOnFailure,
+            )
+            .unwrap();
+        assert_eq!(reply.status, 200);
+        let response: CancelReplaceOrderResponse = decode(&reply.body).unwrap();
+        assert_eq!(response.cancel_result, CancelReplaceStatus::Success);
+        assert_eq!(response.new_order_result, CancelReplaceStatus::Success);
+        let canceled: CancelOrderResponse = decode(&response.cancel_response.unwrap()).unwrap();
+        assert_eq!(canceled.status, OrderStatus::Canceled);
+        let placed_order: NewOrderFullResponse =
+            decode(&response.new_order_response.unwrap()).unwrap();
+        assert_eq!(placed_order.order_id, 2);
+        assert_eq!(sim.balance("USDT").locked, d("95"));
+
+        // Order 1 is gone, so only the new order succeeds
+        let reply = sim
+            .cancel_replace(
+                "BTCUSDT",
+                OrderRef::Id(1),
+                &new,
+                CancelReplaceMode::AllowFailure,
+            )
+            .unwrap();
+        assert_eq!(reply.status, 409);
+        let error = rejected(&reply);
+        assert_eq!(error.code, -2021);
+        let response: CancelReplaceOrderResponse = decode(&error.data.unwrap()).unwrap();
+        assert_eq!(response.cancel_result, CancelReplaceStatus::Failure);
+        assert_eq!(response.new_order_result, CancelReplaceStatus::Success);
+        let cancel_error: ErrorResponse = decode(&response.cancel_response.unwrap()).unwrap();
+        assert_eq!(cancel_error.code, -2011);
+
+        let reply = sim
+            .cancel_replace(
+                "BTCUSDT",
+                OrderRef::Id(1),
+                &new,
+                CancelReplaceMode::StopOnFailure,
+            )
+            .unwrap();
+        assert_eq!(reply.status, 400);
+        let error = rejected(&reply);
+        assert_eq!(error.code, -2022);
+        let response: CancelReplaceOrderResponse = decode(&error.data.unwrap()).unwrap();
+        assert_eq!(response.new_order_result, CancelReplaceStatus::NotAttempted);
+        assert_eq!(response.new_order_response, None);
+        assert_eq!(sim.open_orders("BTCUSDT").count(), 2);
+    }
+
+    #[test]
+    fn same_requests_give_the_same_bytes() {
+        let run = || {
+            let mut sim = simulator(&[("100", "1"), ("101", "1")]);
+            let reply = sim
+                .new_order(&NewOrder::market("BTCUSDT", OrderSide::Buy, d("1.5")))
+                .unwrap();
+            let events: Vec<_> = sim
+                .take_events()
+                .iter()
+                .map(|event| event.encode().unwrap())
+                .collect();
+            (reply, events)
+        };
+        assert_eq!(run(), run());
+    }
+}
@@ -0,0 +1,373 @@
+//! The trading rules of a symbol, as listed in an exchangeInfo response.
+//!
+//! Filters that depend on order book averages or account history
+//! (`PERCENT_PRICE`, `PERCENT_PRICE_BY_SIDE`, `MAX_POSITION`, `T_PLUS_SELL`)
+//! and iceberg and trailing-stop filters are kept as [`Filter::Other`] and
+//! not checked. Notional filters are checked for market orders against the
+//! last trade price, or the best opposite price before the first trade.
+
+use crate::{NewOrder, Rejection};
+use spot_sbe::exchange_info_response_codec::owned::Symbols;
+use spot_sbe::*;
+
+/// A symbol filter the simulator checks orders against.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Filter {
+    Price(PriceFilter),
+    LotSize(LotSizeFilter),
+    MarketLotSize(MarketLotSizeFilter),
+    MinNotional(MinNotionalFilter),
+    Notional(NotionalFilter),
+    MaxNumOrders(MaxNumOrdersFilter),
+    MaxNumAlgoOrders(MaxNumAlgoOrdersFilter),
+    /// Any other filter, by message name, which is not simulated
+    Other(&'static str),
+}
+
+impl Filter {
+    /// Decodes a filter embedded in an exchangeInfo response.
+    pub fn decode(data: &[u8]) -> SbeResult<Self> {
+        Ok(match decode_any(data)? {
+            AnyMessage::PriceFilter(mut decoder) => {
+                Self::Price(PriceFilter::from_decoder(&mut decoder)?)
+            }
+            AnyMessage::LotSizeFilter(mut decoder) => {
+                Self::LotSize(LotSizeFilter::from_decoder(&mut decoder)?)
+            }
+            AnyMessage::MarketLotSizeFilter(mut decoder) => {
+                Self::MarketLotSize(MarketLotSizeFilter::from_decoder(&mut decoder)?)
+            }
+            AnyMessage::MinNotionalFilter(mut decoder) => {
+                Self::MinNotional(MinNotionalFilter::from_decoder(&mut decoder)?)
+            }
+            AnyMessage::NotionalFilter(mut decoder) => {
+                Self::Notional(NotionalFilter::from_decoder(&mut decoder)?)
+            }
+            AnyMessage::MaxNumOrdersFilter(mut decoder) => {
+                Self::MaxNumOrders(MaxNumOrdersFilter::from_decoder(&mut decoder)?)
+            }
+            AnyMessage::MaxNumAlgoOrdersFilter(mut decoder) => {
+                Self::MaxNumAlgoOrders(MaxNumAlgoOrdersFilter::from_decoder(&mut decoder)?)
+            }
+            message => Self::Other(message.name()),
+        })
+    }
+
+    /// The `filterType` the exchange names in its filter failures.
+    fn filter_type(&self) -> &'static str {
+        match self {
+            Self::Price(_) => "PRICE_FILTER",
+            Self::LotSize(_) => "LOT_SIZE",
+            Self::MarketLotSize(_) => "MARKET_LOT_SIZE",
+            Self::MinNotional(_) => "MIN_NOTIONAL",
+            Self::Notional(_) => "NOTIONAL",
+            Self::MaxNumOrders(_) => "MAX_NUM_ORDERS",
+            Self::MaxNumAlgoOrders(_) => "MAX_NUM_ALGO_ORDERS",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+/// A symbol's settings and filters.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymbolRules {
+    pub symbol: String,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub status: SymbolStatus,
+    pub base_asset_precision: u8,
+    pub quote_asset_precision: u8,
+    pub base_commission_precision: u8,
+    pub quote_commission_precision: u8,
+    pub order_types: OrderTypes,
+    pub oco_allowed: bool,
+    pub quote_order_qty_market_allowed: bool,
+    pub cancel_replace_allowed: bool,
+    pub default_self_trade_prevention_mode: SelfTradePreventionMode,
+    pub allowed_self_trade_prevention_modes: AllowedSelfTradePreventionModes,
+    pub filters: Vec<Filter>,
+}
+
+/// Open orders of the account on one symbol, for the `MAX_NUM_*` filters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct OpenOrders {
+    pub orders: i64,
+    pub algo_orders: i64,
+}
+
+impl SymbolRules {
+    pub fn from_owned(symbol: &Symbols) -> SbeResult<Self> {
+        Ok(Self {
+            symbol: symbol.symbol.clone(),
+            base_asset: symbol.base_asset.clone(),
+            quote_asset: symbol.quote_asset.clone(),
+            status: symbol.status,
+            base_asset_precision: symbol.base_asset_precision,
+            quote_asset_precision: symbol.quote_asset_precision,
+            base_commission_precision: symbol.base_commission_precision,
+            quote_commission_precision: symbol.quote_commission_precision,
+            order_types: symbol.order_types,
+            oco_allowed: symbol.oco_allowed == BoolEnum::True,
+            quote_order_qty_market_allowed: symbol.quote_order_qty_market_allowed == BoolEnum::True,
+            cancel_replace_allowed: symbol.cancel_replace_allowed == BoolEnum::True,
+            default_self_trade_prevention_mode: symbol.default_self_trade_prevention_mode,
+            allowed_self_trade_prevention_modes: symbol.allowed_self_trade_prevention_modes,
+            filters: symbol
+                .filters
+                .iter()
+                .map(|filter| Filter::decode(&filter.filter))
+                .collect::<SbeResult<_>>()?,
+        })
+    }
+
+    /// Exponent of prices and quote quantities in encoded messages.
+    #[inline]
+    pub fn price_exponent(&self) -> i8 {
+        -(self.quote_asset_precision as i8)
+    }
+
+    /// Exponent of base quantities in encoded messages.
+    #[inline]
+    pub fn qty_exponent(&self) -> i8 {
+        -(self.base_asset_precision as i8)
+    }
+
+    /// Exponent of commissions in encoded messages, which may be in either
+    /// asset.
+    #[inline]
+    pub fn commission_exponent(&self) -> i8 {
+        -(self
+            .base_commission_precision
+            .max(self.quote_commission_precision) as i8)
+    }
+
+    /// The mode an order placed with `mode` is matched with.
+    pub fn effective_stp_mode(&self, mode: SelfTradePreventionMode) -> SelfTradePreventionMode {
+        match mode {
+            SelfTradePreventionMode::NullVal => match self.default_self_trade_prevention_mode {
+                SelfTradePreventionMode::NullVal => SelfTradePreventionMode::None,
+                default => default,
+            },
+            mode => mode,
+        }
+    }
+
+    /// Smallest quantity increment of an order, from the lot size filters.
+    pub fn qty_step(&self, market: bool) -> Decimal {
+        let mut step = None;
+        for filter in &self.filters {
+            match filter {
+                Filter::MarketLotSize(filter) if market && !filter.step_size.is_zero() => {
+                    return filter.step_size;
+                }
+                Filter::LotSize(filter) if !filter.step_size.is_zero() => {
+                    step = Some(filter.step_size);
+                }
+                _ => {}
+            }
+        }
+        step.unwrap_or(Decimal::new(1, self.qty_exponent()))
+    }
+
+    /// Checks an order the way the exchange does before accepting it, and
+    /// returns the first rule it breaks. `reference_price` stands in for the
+    /// price of market orders.
+    pub(crate) fn check(
+        &self,
+        order: &NewOrder,
+        reference_price: Option<Decimal>,
+        open: OpenOrders,
+    ) -> Result<(), Rejection> {
+        if self.status != SymbolStatus::Trading {
+            return Err(Rejection::new(-2010, "Market is closed."));
+        }
+        let types = self.order_types;
+        let allowed = match order.order_type {
+            OrderType::Market => types.get_market(),
+            OrderType::Limit => types.get_limit(),
+            OrderType::StopLoss => types.get_stop_loss(),
+            OrderType::StopLossLimit => types.get_stop_loss_limit(),
+            OrderType::TakeProfit => types.get_take_profit(),
+            OrderType::TakeProfitLimit => types.get_take_profit_limit(),
+            OrderType::LimitMaker => types.get_limit_maker(),
+            OrderType::NullVal => false,
+        };
+        if !allowed || order.side == OrderSide::NullVal {
+            return Err(Rejection::new(-1116, "Invalid orderType."));
+        }
+        check_parameters(order)?;
+        if order.quote_order_qty.is_some() && !self.quote_order_qty_market_allowed {
+            return Err(Rejection::new(
+                -2010,
+                "Quote order qty market orders are not supported for this symbol.",
+            ));
+        }
+        let modes = self.allowed_self_trade_prevention_modes;
+        let allowed = match order.self_trade_prevention_mode {
+            SelfTradePreventionMode::NullVal => true,
+            SelfTradePreventionMode::None => modes.get_none(),
+            SelfTradePreventionMode::ExpireTaker => modes.get_expire_taker(),
+            SelfTradePreventionMode::ExpireMaker => modes.get_expire_maker(),
+            SelfTradePreventionMode::ExpireBoth => modes.get_expire_both(),
+        };
+        if !allowed {
+            return Err(Rejection::new(
+                -1013,
+                "This symbol does not allow the specified self-trade prevention mode.",
+            ));
+        }
+        let prices = [order.price, order.stop_price];
+        let fits =
+            |value: Option<Decimal>, exponent| value.is_none_or(|v| v.rescale(exponent).is_some());
+        if !prices
+            .iter()
+            .all(|&price| fits(price, self.price_exponent()))
+            || !fits(order.qty, self.qty_exponent())
+            || !fits(order.quote_order_qty, self.price_exponent())
+        {
+            return Err(Rejection::new(
+                -1111,
+                "Precision is over the maximum defined for this asset.",
+            ));
+        }
+        for filter in &self.filters {
+            if !passes(filter, order, reference_price, open) {
+                return Err(Rejection::new(
+                    -1013,
+                    format!("Filter failure: {}", filter.filter_type()),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Orders that execute at the market price, immediately or once triggered.
+pub(crate) fn is_market(order_type: OrderType) -> bool {
+    matches!(
+        order_type,
+        OrderType::Market | OrderType::StopLoss | OrderType::TakeProfit
+    )
+}
+
+/// Orders that wait for their `stopPrice`.
+pub(crate) fn is_algo(order_type: OrderType) -> bool {
+    matches!(
+        order_type,
+        OrderType::StopLoss
+            | OrderType::StopLossLimit
+            | OrderType::TakeProfit
+            | OrderType::TakeProfitLimit
+    )
+}
+
+fn check_parameters(order: &NewOrder) -> Result<(), Rejection> {
+    let require = |present: bool, name: &str| {
+        if present {
+            Ok(())
+        } else {
+            Err(Rejection::new(
+                -1102,
+                format!("Mandatory parameter '{name}' was not sent, was empty/null, or malformed."),
+            ))
+        }
+    };
+    let positive = |value: Option<Decimal>| value.is_some_and(|v| v > Decimal::ZERO);
+    match order.order_type {
+        OrderType::Market => {
+            if order.qty.is_some() == order.quote_order_qty.is_some() {
+                return Err(Rejection::new(
+                    -1102,
+                    "Param 'quantity' or 'quoteOrderQty' must be sent, but both were empty/null!",
+                ));
+            }
+            require(
+                positive(order.qty) || positive(order.quote_order_qty),
+                "quantity",
+            )?;
+        }
+        OrderType::Limit | OrderType::StopLossLimit | OrderType::TakeProfitLimit => {
+            require(order.time_in_force != TimeInForce::NullVal, "timeInForce")?;
+            require(positive(order.price), "price")?;
+            require(positive(order.qty), "quantity")?;
+        }
+        OrderType::LimitMaker => {
+            require(positive(order.price), "price")?;
+            require(positive(order.qty), "quantity")?;
+        }
+        OrderType::StopLoss | OrderType::TakeProfit | OrderType::NullVal => {
+            require(positive(order.qty), "quantity")?;
+        }
+    }
+    if is_algo(order.order_type) {
+        require(positive(order.stop_price), "stopPrice")?;
+    }
+    if order.order_type != OrderType::Market && order.quote_order_qty.is_some() {
+        return Err(Rejection::new(
+            -1106,
+            "Parameter 'quoteOrderQty' sent when not required.",
+        ));
+    }
+    Ok(())
+}
+
+fn passes(
+    filter: &Filter,
+    order: &NewOrder,
+    reference_price: Option<Decimal>,
+    open: OpenOrders,
+) -> bool {
+    let market = is_market(order.order_type);
+    let notional = || {
+        let price = if market { reference_price } else { order.price }?;
+        match (order.quote_order_qty, order.qty) {
+            (Some(quote_order_qty), _) => Some(quote_order_qty),
+            (None, Some(qty)) => price.checked_mul(qty),
+            (None, None) => None,
+        }
+    };
+    match filter {
+        Filter::Price(filter) => [order.price, order.stop_price]
+            .into_iter()
+            .flatten()
+            .all(|price| in_range(price, filter.min_price, filter.max_price, filter.tick_size)),
+        Filter::LotSize(filter) => order
+            .qty
+            .is_none_or(|qty| in_range(qty, filter.min_qty, filter.max_qty, filter.step_size)),
+        Filter::MarketLotSize(filter) => {
+            !market
+                || order.qty.is_none_or(|qty| {
+                    in_range(qty, filter.min_qty, filter.max_qty, filter.step_size)
+                })
+        }
+        Filter::MinNotional(filter) => {
+            (market && filter.apply_to_market != BoolEnum::True)
+                || notional().is_none_or(|notional| notional >= filter.min_notional)
+        }
+        Filter::Notional(filter) => notional().is_none_or(|notional| {
+            let min_applies = !market || filter.apply_min_to_market == BoolEnum::True;
+            let max_applies = !market || filter.apply_max_to_market == BoolEnum::True;
+            (!min_applies || notional >= filter.min_notional)
+                && (!max_applies || notional <= filter.max_notional)
+        }),
+        Filter::MaxNumOrders(filter) => open.orders < filter.max_num_orders,
+        Filter::MaxNumAlgoOrders(filter) => {
+            !is_algo(order.order_type) || open.algo_orders < filter.max_num_algo_orders
+        }
+        Filter::Other(_) => true,
+    }
+}
+
+/// `min <= value <= max` and `value - min` a multiple of `step`; a zero
+/// `min`, `max` or `step` disables that check.
+fn in_range(value: Decimal, min: Decimal, max: Decimal, step: Decimal) -> bool {
+    let on_step = || {
+        value
+            .checked_sub(min)
+            .and_then(|offset| Some(offset.round_to_tick(step, Rounding::Floor)? == offset))
+            .unwrap_or(false)
+    };
+    (min.is_zero() || value >= min)
+        && (max.is_zero() || value <= max)
+        && (step.is_zero() || on_step())
+}
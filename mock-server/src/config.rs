@@ -0,0 +1,435 @@
+//! The JSON configuration of a mock server.
+//!
+//! ```json
+//! {
+//!   "rateLimits": [
+//!     {"rateLimitType": "REQUEST_WEIGHT", "interval": "MINUTE", "intervalNum": 1, "limit": 6000}
+//!   ],
+//!   "deprecatedSchemas": ["2:1"],
+//!   "routes": [
+//!     {
+//!       "rest": "GET /api/v3/depth",
+//!       "ws": "depth",
+//!       "params": {"symbol": "BTCUSDT"},
+//!       "weight": 5,
+//!       "responses": [
+//!         {"template": "DepthResponse", "json": {"lastUpdateId": 1, "bids": [], "asks": []}},
+//!         {"file": "depth.sbe"},
+//!         {"status": 400, "error": {"code": -1121, "msg": "Invalid symbol."}}
+//!       ]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! A route answers REST requests for its `rest` method and path, and
+//! WebSocket API requests for its `ws` method, as long as every one of its
+//! `params` is given with that value. The first route that matches answers.
+//! Each request it answers takes the next of its `responses`, and the last
+//! one is repeated from then on.
+
+use anyhow::{bail, Context};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use spot_sbe::{RateLimitInterval, RateLimitType, SchemaVersion, SCHEMAS};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Debug)]
+pub struct Config {
+    pub rate_limits: Vec<RateLimit>,
+    /// Schemas whose responses are flagged as deprecated
+    pub deprecated_schemas: Vec<&'static SchemaVersion>,
+    pub routes: Vec<Route>,
+}
+
+impl Config {
+    /// Reads a configuration file. Response files are found relative to the
+    /// directory it is in.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+        Self::from_json(&text, base_dir).with_context(|| format!("Invalid {}", path.display()))
+    }
+
+    /// Parses a configuration, reading response files relative to
+    /// `base_dir`. Every response is encoded up front, so that a mistake is
+    /// reported before the server starts.
+    pub fn from_json(text: &str, base_dir: &Path) -> anyhow::Result<Self> {
+        let raw: RawConfig = serde_json::from_str(text)?;
+        for (index, limit) in raw.rate_limits.iter().enumerate() {
+            if limit.interval_num == 0 {
+                bail!("rateLimits[{index}]: intervalNum must be at least 1");
+            }
+            if limit.limit < 0 {
+                bail!("rateLimits[{index}]: limit must not be negative");
+            }
+        }
+        let deprecated_schemas = raw
+            .deprecated_schemas
+            .iter()
+            .map(|name| parse_schema(name).with_context(|| format!("deprecatedSchemas: {name:?}")))
+            .collect::<anyhow::Result<_>>()?;
+        let routes = raw
+            .routes
+            .into_iter()
+            .enumerate()
+            .map(|(index, route)| {
+                Route::from_raw(route, base_dir).with_context(|| format!("routes[{index}]"))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self {
+            rate_limits: raw.rate_limits,
+            deprecated_schemas,
+            routes,
+        })
+    }
+
+    /// The first route that answers `request`.
+    pub fn route(
+        &self,
+        request: &Endpoint<'_>,
+        params: &HashMap<String, String>,
+    ) -> Option<&Route> {
+        self.routes
+            .iter()
+            .find(|route| route.matches(request, params))
+    }
+
+    #[inline]
+    pub fn is_deprecated(&self, schema: &SchemaVersion) -> bool {
+        self.deprecated_schemas
+            .iter()
+            .any(|deprecated| (deprecated.id, deprecated.version) == (schema.id, schema.version))
+    }
+}
+
+/// What a request asks for, before its parameters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endpoint<'a> {
+    Rest { method: &'a str, path: &'a str },
+    Ws { method: &'a str },
+}
+
+#[derive(Debug)]
+pub struct Route {
+    /// HTTP method and path, e.g. `("GET", "/api/v3/depth")`
+    pub rest: Option<(String, String)>,
+    /// WebSocket API method, e.g. `"depth"`
+    pub ws: Option<String>,
+    pub params: HashMap<String, String>,
+    /// Added to `REQUEST_WEIGHT` limits for each request
+    pub weight: i64,
+    /// Whether each request counts towards `ORDERS` limits
+    pub orders: bool,
+    pub responses: Vec<Reply>,
+    next: AtomicUsize,
+}
+
+impl Route {
+    fn from_raw(raw: RawRoute, base_dir: &Path) -> anyhow::Result<Self> {
+        let rest = match raw.rest {
+            Some(rest) => match rest.split_once(' ') {
+                Some((method, path)) if path.starts_with('/') => {
+                    Some((method.to_ascii_uppercase(), path.to_owned()))
+                }
+                _ => bail!("rest: expected a method and a path, e.g. \"GET /api/v3/depth\""),
+            },
+            None => None,
+        };
+        if rest.is_none() && raw.ws.is_none() {
+            bail!("a route needs a \"rest\" path, a \"ws\" method or both");
+        }
+        if raw.responses.is_empty() {
+            bail!("a route needs at least one response");
+        }
+        let params = raw
+            .params
+            .into_iter()
+            .map(|(name, value)| (name, param_string(value)))
+            .collect();
+        let responses = raw
+            .responses
+            .into_iter()
+            .enumerate()
+            .map(|(index, reply)| {
+                Reply::from_raw(reply, base_dir).with_context(|| format!("responses[{index}]"))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self {
+            rest,
+            ws: raw.ws,
+            params,
+            weight: raw.weight,
+            orders: raw.orders,
+            responses,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    fn matches(&self, endpoint: &Endpoint<'_>, params: &HashMap<String, String>) -> bool {
+        let endpoint_matches = match *endpoint {
+            Endpoint::Rest { method, path } => self
+                .rest
+                .as_ref()
+                .is_some_and(|(m, p)| m.eq_ignore_ascii_case(method) && p == path),
+            Endpoint::Ws { method } => self.ws.as_deref() == Some(method),
+        };
+        endpoint_matches
+            && self
+                .params
+                .iter()
+                .all(|(name, value)| params.get(name) == Some(value))
+    }
+
+    /// The response to the next request, which is the last one once the
+    /// script has run out.
+    pub fn next_response(&self) -> &Reply {
+        let index = self.next.fetch_add(1, Ordering::Relaxed);
+        &self.responses[index.min(self.responses.len() - 1)]
+    }
+}
+
+/// A canned response.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Reply {
+    /// An encoded message, header included
+    Message { status: u16, body: Vec<u8> },
+    /// An `ErrorResponse`, which is encoded when sent so that its
+    /// `serverTime` and `retryAfter` are current
+    Error {
+        status: u16,
+        code: i16,
+        msg: String,
+        /// Milliseconds from when it is sent to its `retryAfter`
+        retry_in: Option<i64>,
+    },
+}
+
+impl Reply {
+    fn from_raw(raw: RawReply, base_dir: &Path) -> anyhow::Result<Self> {
+        match raw {
+            RawReply {
+                status,
+                template: Some(template),
+                json: Some(json),
+                file: None,
+                error: None,
+            } => Ok(Self::Message {
+                status: status.unwrap_or(200),
+                body: spot_sbe::json::from_json(&template, &json)
+                    .with_context(|| format!("Failed to encode {template}"))?,
+            }),
+            RawReply {
+                status,
+                template: None,
+                json: None,
+                file: Some(file),
+                error: None,
+            } => {
+                let path = base_dir.join(file);
+                let body = std::fs::read(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                Ok(Self::Message {
+                    status: status.unwrap_or(200),
+                    body,
+                })
+            }
+            RawReply {
+                status,
+                template: None,
+                json: None,
+                file: None,
+                error: Some(error),
+            } => Ok(Self::Error {
+                status: status.unwrap_or(400),
+                code: error.code,
+                msg: error.msg,
+                retry_in: error.retry_in,
+            }),
+            _ => bail!("expected either \"template\" and \"json\", \"file\" or \"error\""),
+        }
+    }
+}
+
+/// A limit the server counts requests against, in the shape of the
+/// `rateLimits` of an exchangeInfo response.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RateLimit {
+    #[serde(deserialize_with = "rate_limit_type")]
+    pub rate_limit_type: RateLimitType,
+    #[serde(deserialize_with = "rate_limit_interval")]
+    pub interval: RateLimitInterval,
+    pub interval_num: u8,
+    pub limit: i64,
+}
+
+impl RateLimit {
+    /// Length of the window requests are counted over.
+    pub fn window_ms(&self) -> i64 {
+        let unit = match self.interval {
+            RateLimitInterval::Second => 1_000,
+            RateLimitInterval::Minute => 60_000,
+            RateLimitInterval::Hour => 3_600_000,
+            RateLimitInterval::Day | RateLimitInterval::NullVal => 86_400_000,
+        };
+        unit * i64::from(self.interval_num)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct RawConfig {
+    #[serde(default)]
+    rate_limits: Vec<RateLimit>,
+    #[serde(default)]
+    deprecated_schemas: Vec<String>,
+    routes: Vec<RawRoute>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct RawRoute {
+    rest: Option<String>,
+    ws: Option<String>,
+    #[serde(default)]
+    params: Map<String, Value>,
+    #[serde(default = "default_weight")]
+    weight: i64,
+    #[serde(default)]
+    orders: bool,
+    responses: Vec<RawReply>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct RawReply {
+    status: Option<u16>,
+    template: Option<String>,
+    json: Option<Value>,
+    file: Option<PathBuf>,
+    error: Option<RawError>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct RawError {
+    code: i16,
+    msg: String,
+    retry_in: Option<i64>,
+}
+
+fn default_weight() -> i64 {
+    1
+}
+
+/// Parameters compare as the strings a query string would carry.
+pub(crate) fn param_string(value: Value) -> String {
+    match value {
+        Value::String(value) => value,
+        value => value.to_string(),
+    }
+}
+
+/// A compiled-in schema named as in the `X-MBX-SBE` header, e.g. `"2:1"`.
+pub fn parse_schema(name: &str) -> anyhow::Result<&'static SchemaVersion> {
+    let Some((id, version)) = name.split_once(':') else {
+        bail!("expected <schema ID>:<version>");
+    };
+    let (id, version): (u16, u16) = (id.trim().parse()?, version.trim().parse()?);
+    match SCHEMAS.iter().find(|s| (s.id, s.version) == (id, version)) {
+        Some(schema) => Ok(schema),
+        None => bail!("schema {id}:{version} is not compiled in"),
+    }
+}
+
+fn rate_limit_type<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<RateLimitType, D::Error> {
+    match String::deserialize(deserializer)?.as_str() {
+        "RAW_REQUESTS" => Ok(RateLimitType::RawRequests),
+        "CONNECTIONS" => Ok(RateLimitType::Connections),
+        "REQUEST_WEIGHT" => Ok(RateLimitType::RequestWeight),
+        "ORDERS" => Ok(RateLimitType::Orders),
+        other => Err(serde::de::Error::custom(format!(
+            "unknown rateLimitType {other:?}"
+        ))),
+    }
+}
+
+fn rate_limit_interval<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<RateLimitInterval, D::Error> {
+    match String::deserialize(deserializer)?.as_str() {
+        "SECOND" => Ok(RateLimitInterval::Second),
+        "MINUTE" => Ok(RateLimitInterval::Minute),
+        "HOUR" => Ok(RateLimitInterval::Hour),
+        "DAY" => Ok(RateLimitInterval::Day),
+        other => Err(serde::de::Error::custom(format!(
+            "unknown interval {other:?}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parse(config: Value) -> anyhow::Result<Config> {
+        Config::from_json(&config.to_string(), Path::new("."))
+    }
+
+    fn with_limit(interval_num: u8, limit: i64) -> anyhow::Result<Config> {
+        parse(json!({
+            "rateLimits": [{
+                "rateLimitType": "REQUEST_WEIGHT",
+                "interval": "MINUTE",
+                "intervalNum": interval_num,
+                "limit": limit,
+            }],
+            "routes": [],
+        }))
+    }
+
+    #[test]
+    fn rate_limits_need_an_interval_and_a_limit() {
+        let config = with_limit(1, 6000).unwrap();
+        assert_eq!(config.rate_limits[0].window_ms(), 60_000);
+        let err = with_limit(0, 6000).unwrap_err();
+        assert!(err.to_string().contains("intervalNum"));
+        let err = with_limit(1, -1).unwrap_err();
+        assert!(err.to_string().contains("limit must not be negative"));
+    }
+
+    #[test]
+    fn scripted_responses_repeat_the_last() {
+        let config = parse(json!({
+            "routes": [{
+                "ws": "ping",
+                "responses": [
+                    {"template": "PingResponse", "json": {}},
+                    {"status": 503, "error": {"code": -1000, "msg": "Down."}},
+                ],
+            }],
+        }))
+        .unwrap();
+        let route = config
+            .route(&Endpoint::Ws { method: "ping" }, &HashMap::new())
+            .unwrap();
+        assert!(matches!(
+            route.next_response(),
+            Reply::Message { status: 200, .. }
+        ));
+        for _ in 0..2 {
+            assert!(matches!(
+                route.next_response(),
+                Reply::Error { status: 503, .. }
+            ));
+        }
+    }
+}
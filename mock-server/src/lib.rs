@@ -0,0 +1,716 @@
+//! A local stand-in for the Spot REST API and WebSocket API, answering in
+//! SBE with the canned or scripted responses of a [`Config`].
+//!
+//! Both APIs are served on one port. REST requests must accept
+//! `application/sbe` and may name their schema in the `X-MBX-SBE` header;
+//! WebSocket API connections are made to `/ws-api/v3?responseFormat=sbe`,
+//! with `sbeSchemaId` and `sbeSchemaVersion` optional. Either way, the
+//! newest compiled-in schema is used when none is named, and one that is not
+//! compiled in is refused.
+//!
+//! Every request counts towards the configured rate limits, and is refused
+//! with a `429` `ErrorResponse` carrying `retryAfter` once one of them is
+//! exceeded. Usage is reported in `X-MBX-USED-WEIGHT-*` and
+//! `X-MBX-ORDER-COUNT-*` headers, or in a `WebSocketResponse`'s
+//! `rateLimits`.
+
+use crate::config::{param_string, Endpoint, Reply};
+use crate::limits::{Counted, RateLimiter};
+use anyhow::{bail, Context};
+use serde_json::Value;
+use spot_sbe::web_socket_response_codec::owned::RateLimits;
+use spot_sbe::*;
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tungstenite::handshake::server::{ErrorResponse as Rejection, Request as Handshake, Response};
+use tungstenite::http::StatusCode;
+use tungstenite::Message;
+
+pub mod config;
+pub mod limits;
+
+pub use config::Config;
+
+/// `ErrorResponse` code for requests the configuration has no answer for.
+pub const UNKNOWN_CODE: i16 = -1000;
+/// `ErrorResponse` code for going over a request weight limit.
+pub const TOO_MANY_REQUESTS_CODE: i16 = -1003;
+/// `ErrorResponse` code for going over an order count limit.
+pub const TOO_MANY_ORDERS_CODE: i16 = -1015;
+
+/// Listener for REST and WebSocket API connections.
+#[derive(Debug)]
+pub struct MockServer {
+    listener: TcpListener,
+    state: Arc<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    config: Config,
+    limiter: RateLimiter,
+}
+
+impl MockServer {
+    /// Listens on `addr`, e.g. `"127.0.0.1:0"` for any free port.
+    pub fn bind(addr: &str, config: Config) -> io::Result<Self> {
+        let limiter = RateLimiter::new(config.rate_limits.clone());
+        Ok(Self {
+            listener: TcpListener::bind(addr)?,
+            state: Arc::new(State { config, limiter }),
+        })
+    }
+
+    #[inline]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Base URL of the REST API, e.g. `http://127.0.0.1:8080`.
+    pub fn rest_url(&self) -> io::Result<String> {
+        Ok(format!("http://{}", self.local_addr()?))
+    }
+
+    /// Base URL of the WebSocket API, to pass to `ws_api_client::Client::connect`.
+    pub fn ws_url(&self) -> io::Result<String> {
+        Ok(format!("ws://{}", self.local_addr()?))
+    }
+
+    /// Serves connections until the listener fails, each on its own thread.
+    /// Errors on a connection only end that connection, and are printed to
+    /// stderr.
+    pub fn run(self) -> io::Result<()> {
+        loop {
+            let (stream, peer) = self.listener.accept()?;
+            let state = Arc::clone(&self.state);
+            std::thread::spawn(move || {
+                if let Err(err) = state.serve_connection(stream) {
+                    eprintln!("{peer}: {err:#}");
+                }
+            });
+        }
+    }
+
+    /// Serves connections on a background thread, for use within a test.
+    pub fn spawn(self) -> std::thread::JoinHandle<io::Result<()>> {
+        std::thread::spawn(move || self.run())
+    }
+}
+
+/// An HTTP request head.
+#[derive(Debug)]
+struct HttpRequest {
+    method: String,
+    path: String,
+    query: String,
+    /// Names in lowercase
+    headers: Vec<(String, String)>,
+}
+
+impl HttpRequest {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+#[derive(Debug)]
+struct HttpResponse {
+    status: u16,
+    content_type: &'static str,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+/// The answer to one request, in either API.
+#[derive(Debug)]
+struct Answer {
+    status: u16,
+    /// An SBE message, header included
+    body: Vec<u8>,
+    counted: Counted,
+}
+
+impl State {
+    fn serve_connection(&self, mut stream: TcpStream) -> anyhow::Result<()> {
+        let mut buf = Vec::new();
+        loop {
+            let Some(head_length) = read_head(&mut stream, &mut buf)? else {
+                return Ok(());
+            };
+            let request = parse_head(&buf)?;
+            let upgrade = request.header("upgrade");
+            if upgrade.is_some_and(|value| value.eq_ignore_ascii_case("websocket")) {
+                // The handshake is read again by tungstenite.
+                return self.serve_ws(Rewound {
+                    pending: buf,
+                    position: 0,
+                    stream,
+                });
+            }
+            let content_length = match request.header("content-length") {
+                Some(value) => value.trim().parse().context("Invalid Content-Length")?,
+                None => 0,
+            };
+            while buf.len() < head_length + content_length {
+                let mut chunk = [0; 4096];
+                match stream.read(&mut chunk)? {
+                    0 => bail!("connection closed within a request body"),
+                    n => buf.extend_from_slice(&chunk[..n]),
+                }
+            }
+            let body: Vec<u8> = buf
+                .drain(..head_length + content_length)
+                .skip(head_length)
+                .collect();
+            let response = self.rest(&request, &body)?;
+            eprintln!("{} {} -> {}", request.method, request.path, response.status);
+            write_response(&mut stream, &response)?;
+            if request
+                .header("connection")
+                .is_some_and(|value| value.eq_ignore_ascii_case("close"))
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    fn rest(&self, request: &HttpRequest, body: &[u8]) -> anyhow::Result<HttpResponse> {
+        let accepts_sbe = request
+            .header("accept")
+            .is_some_and(|value| value.contains("application/sbe"));
+        if !accepts_sbe {
+            return Ok(json_error(406, "only Accept: application/sbe is served"));
+        }
+        let schema = match request.header("x-mbx-sbe") {
+            Some(name) => match config::parse_schema(name) {
+                Ok(schema) => schema,
+                Err(err) => return Ok(json_error(400, &format!("X-MBX-SBE: {err}"))),
+            },
+            None => SCHEMAS[SCHEMAS.len() - 1],
+        };
+        let mut params = parse_query(&request.query);
+        params.extend(parse_query(&String::from_utf8_lossy(body)));
+        let endpoint = Endpoint::Rest {
+            method: &request.method,
+            path: &request.path,
+        };
+        let answer = self.answer(&endpoint, &params, 404)?;
+
+        let mut headers = Vec::new();
+        for usage in &answer.counted.usage {
+            let name = match usage.limit.rate_limit_type {
+                RateLimitType::RequestWeight => "X-MBX-USED-WEIGHT",
+                RateLimitType::Orders => "X-MBX-ORDER-COUNT",
+                _ => continue,
+            };
+            let interval = match usage.limit.interval {
+                RateLimitInterval::Second => 'S',
+                RateLimitInterval::Minute => 'M',
+                RateLimitInterval::Hour => 'H',
+                RateLimitInterval::Day | RateLimitInterval::NullVal => 'D',
+            };
+            headers.push((
+                format!("{name}-{}{interval}", usage.limit.interval_num),
+                usage.used.to_string(),
+            ));
+        }
+        if let Some(retry_after) = answer.counted.retry_after {
+            let seconds = ((retry_after - now_ms()).max(0) + 999) / 1000;
+            headers.push(("Retry-After".into(), seconds.to_string()));
+        }
+        if self.config.is_deprecated(schema) {
+            headers.push(("X-MBX-SBE-DEPRECATED".into(), "true".into()));
+        }
+        Ok(HttpResponse {
+            status: answer.status,
+            content_type: "application/sbe",
+            headers,
+            body: answer.body,
+        })
+    }
+
+    fn serve_ws(&self, stream: Rewound) -> anyhow::Result<()> {
+        let mut schema = None;
+        let callback = |request: &Handshake, response: Response| {
+            let query = parse_query(request.uri().query().unwrap_or_default());
+            let reject = |msg: String| {
+                let mut rejection = Rejection::new(Some(msg));
+                *rejection.status_mut() = StatusCode::BAD_REQUEST;
+                Err(rejection)
+            };
+            if request.uri().path() != "/ws-api/v3"
+                || query.get("responseFormat").map(String::as_str) != Some("sbe")
+            {
+                return reject("expected /ws-api/v3?responseFormat=sbe".into());
+            }
+            schema = match (query.get("sbeSchemaId"), query.get("sbeSchemaVersion")) {
+                (Some(id), Some(version)) => match config::parse_schema(&format!("{id}:{version}"))
+                {
+                    Ok(schema) => Some(schema),
+                    Err(err) => return reject(err.to_string()),
+                },
+                _ => Some(SCHEMAS[SCHEMAS.len() - 1]),
+            };
+            Ok(response)
+        };
+        let mut socket = tungstenite::accept_hdr(stream, callback).map_err(|err| match err {
+            tungstenite::HandshakeError::Failure(err) => anyhow::Error::from(err),
+            tungstenite::HandshakeError::Interrupted(_) => anyhow::anyhow!("handshake interrupted"),
+        })?;
+        let deprecated = match schema {
+            Some(schema) if self.config.is_deprecated(schema) => BoolEnum::True,
+            _ => BoolEnum::False,
+        };
+
+        loop {
+            let text = match socket.read() {
+                Ok(Message::Text(text)) => text,
+                Ok(Message::Close(_)) => {
+                    // Sends the reply to the close frame that tungstenite
+                    // has queued.
+                    let _ = socket.flush();
+                    return Ok(());
+                }
+                Ok(_) => continue,
+                Err(tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed) => {
+                    return Ok(())
+                }
+                Err(err) => return Err(err.into()),
+            };
+            let request: Value = match serde_json::from_str(text.as_str()) {
+                Ok(request) => request,
+                Err(err) => {
+                    let msg = format!("Invalid JSON request: {err}");
+                    let result = error_response(UNKNOWN_CODE, msg, now_ms(), None)?;
+                    let response = WebSocketResponse {
+                        sbe_schema_id_version_deprecated: deprecated,
+                        status: 400,
+                        rate_limits: Vec::new(),
+                        id: String::new(),
+                        result,
+                    };
+                    socket.send(Message::binary(response.encode()?))?;
+                    continue;
+                }
+            };
+            let id = match request.get("id") {
+                Some(Value::String(id)) => id.clone(),
+                Some(Value::Number(id)) => id.to_string(),
+                _ => String::new(),
+            };
+            let method = match request.get("method") {
+                Some(Value::String(method)) => method.as_str(),
+                _ => "",
+            };
+            let params = match request.get("params") {
+                Some(Value::Object(params)) => params
+                    .iter()
+                    .map(|(name, value)| (name.clone(), param_string(value.clone())))
+                    .collect(),
+                _ => HashMap::new(),
+            };
+            let answer = self.answer(&Endpoint::Ws { method }, &params, 400)?;
+            eprintln!("ws {method} {id:?} -> {}", answer.status);
+            let response = WebSocketResponse {
+                sbe_schema_id_version_deprecated: deprecated,
+                status: answer.status,
+                rate_limits: answer
+                    .counted
+                    .usage
+                    .iter()
+                    .map(|usage| RateLimits {
+                        rate_limit_type: usage.limit.rate_limit_type,
+                        interval: usage.limit.interval,
+                        interval_num: usage.limit.interval_num,
+                        rate_limit: usage.limit.limit,
+                        current: usage.used,
+                    })
+                    .collect(),
+                id,
+                result: answer.body,
+            };
+            socket.send(Message::binary(response.encode()?))?;
+        }
+    }
+
+    /// Counts the request, then answers it from the first matching route;
+    /// `unrouted_status` is the status of the error when none matches.
+    fn answer(
+        &self,
+        endpoint: &Endpoint<'_>,
+        params: &HashMap<String, String>,
+        unrouted_status: u16,
+    ) -> SbeResult<Answer> {
+        let now = now_ms();
+        let route = self.config.route(endpoint, params);
+        let (weight, orders) = route.map_or((1, false), |route| (route.weight, route.orders));
+        let counted = self.limiter.count(weight, orders, now);
+        let (status, body) = if let Some(usage) = counted.exceeded() {
+            let limit = &usage.limit;
+            let (code, what) = match limit.rate_limit_type {
+                RateLimitType::Orders => (TOO_MANY_ORDERS_CODE, "Too many new orders"),
+                _ => (TOO_MANY_REQUESTS_CODE, "Too many requests"),
+            };
+            let msg = format!(
+                "{what}; current limit is {} per {} {}.",
+                limit.limit,
+                limit.interval_num,
+                interval_name(limit.interval)
+            );
+            (429, error_response(code, msg, now, counted.retry_after)?)
+        } else {
+            match route.map(|route| route.next_response()) {
+                Some(Reply::Message { status, body }) => (*status, body.clone()),
+                Some(Reply::Error {
+                    status,
+                    code,
+                    msg,
+                    retry_in,
+                }) => {
+                    let retry_after = retry_in.map(|ms| now + ms);
+                    (
+                        *status,
+                        error_response(*code, msg.clone(), now, retry_after)?,
+                    )
+                }
+                None => {
+                    let msg = format!("No response is configured for {endpoint}");
+                    let body = error_response(UNKNOWN_CODE, msg, now, None)?;
+                    (unrouted_status, body)
+                }
+            }
+        };
+        Ok(Answer {
+            status,
+            body,
+            counted,
+        })
+    }
+}
+
+impl core::fmt::Display for Endpoint<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Rest { method, path } => write!(f, "{method} {path}"),
+            Self::Ws { method } => write!(f, "method {method:?}"),
+        }
+    }
+}
+
+/// A stream that yields the bytes already read from it before reading on.
+#[derive(Debug)]
+struct Rewound {
+    pending: Vec<u8>,
+    position: usize,
+    stream: TcpStream,
+}
+
+impl Read for Rewound {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position < self.pending.len() {
+            let n = (&self.pending[self.position..]).read(buf)?;
+            self.position += n;
+            return Ok(n);
+        }
+        self.stream.read(buf)
+    }
+}
+
+impl Write for Rewound {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stream.write(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+/// Reads until `buf` starts with a whole request head, and returns its
+/// length; `None` when the connection closes between requests.
+fn read_head(stream: &mut TcpStream, buf: &mut Vec<u8>) -> anyhow::Result<Option<usize>> {
+    loop {
+        let mut headers = [httparse::EMPTY_HEADER; 64];
+        if let httparse::Status::Complete(length) =
+            httparse::Request::new(&mut headers).parse(buf)?
+        {
+            return Ok(Some(length));
+        }
+        let mut chunk = [0; 4096];
+        match stream.read(&mut chunk)? {
+            0 if buf.is_empty() => return Ok(None),
+            0 => bail!("connection closed within a request head"),
+            n => buf.extend_from_slice(&chunk[..n]),
+        }
+    }
+}
+
+fn parse_head(buf: &[u8]) -> anyhow::Result<HttpRequest> {
+    let mut headers = [httparse::EMPTY_HEADER; 64];
+    let mut request = httparse::Request::new(&mut headers);
+    request.parse(buf)?;
+    let target = request.path.unwrap_or("/");
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    Ok(HttpRequest {
+        method: request.method.unwrap_or_default().to_owned(),
+        path: path.to_owned(),
+        query: query.to_owned(),
+        headers: request
+            .headers
+            .iter()
+            .map(|header| {
+                let value = String::from_utf8_lossy(header.value).into_owned();
+                (header.name.to_ascii_lowercase(), value)
+            })
+            .collect(),
+    })
+}
+
+fn write_response(stream: &mut TcpStream, response: &HttpResponse) -> io::Result<()> {
+    let reason = StatusCode::from_u16(response.status)
+        .ok()
+        .and_then(|status| status.canonical_reason())
+        .unwrap_or_default();
+    let mut head = format!(
+        "HTTP/1.1 {} {reason}\r\nContent-Type: {}\r\nContent-Length: {}\r\n",
+        response.status,
+        response.content_type,
+        response.body.len()
+    );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str("\r\n");
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(&response.body)?;
+    stream.flush()
+}
+
+/// A JSON error, for requests that cannot be answered in SBE.
+fn json_error(status: u16, msg: &str) -> HttpResponse {
+    let body = serde_json::json!({ "code": UNKNOWN_CODE, "msg": msg });
+    HttpResponse {
+        status,
+        content_type: "application/json",
+        headers: Vec::new(),
+        body: body.to_string().into_bytes(),
+    }
+}
+
+fn error_response(
+    code: i16,
+    msg: String,
+    server_time: i64,
+    retry_after: Option<i64>,
+) -> SbeResult<Vec<u8>> {
+    ErrorResponse {
+        code,
+        server_time: Some(server_time),
+        retry_after,
+        msg,
+        data: None,
+    }
+    .encode()
+}
+
+fn interval_name(interval: RateLimitInterval) -> &'static str {
+    match interval {
+        RateLimitInterval::Second => "SECOND",
+        RateLimitInterval::Minute => "MINUTE",
+        RateLimitInterval::Hour => "HOUR",
+        RateLimitInterval::Day | RateLimitInterval::NullVal => "DAY",
+    }
+}
+
+/// Parameters of a query string or form body, percent-decoded.
+fn parse_query(query: &str) -> HashMap<String, String> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(name), percent_decode(value))
+        })
+        .collect()
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let hex = bytes
+            .get(index + 1..index + 3)
+            .and_then(|hex| core::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[index], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            (b'+', _) => {
+                decoded.push(b' ');
+                index += 1;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Milliseconds since the Unix epoch.
+fn now_ms() -> i64 {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    i64::try_from(elapsed.as_millis()).unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use ws_api_client::Client;
+
+    const CONFIG: &str = r#"{
+        "rateLimits": [
+            {"rateLimitType": "REQUEST_WEIGHT", "interval": "DAY", "intervalNum": 1, "limit": 10}
+        ],
+        "deprecatedSchemas": ["2:1"],
+        "routes": [
+            {
+                "rest": "GET /api/v3/depth",
+                "ws": "depth",
+                "params": {"symbol": "BTCUSDT"},
+                "weight": 4,
+                "responses": [
+                    {"template": "DepthResponse", "json": {"lastUpdateId": 1, "bids": [], "asks": []}}
+                ]
+            },
+            {
+                "ws": "order.place",
+                "responses": [
+                    {"template": "NewOrderAckResponse", "json": {
+                        "symbol": "BTCUSDT", "orderId": 28, "orderListId": -1,
+                        "clientOrderId": "x", "transactTime": 1507725176595
+                    }},
+                    {"status": 400, "error": {"code": -2010, "msg": "Account has insufficient balance."}}
+                ]
+            }
+        ]
+    }"#;
+
+    /// Serves `CONFIG` on a free port, and returns its address.
+    fn serve() -> SocketAddr {
+        let config = Config::from_json(CONFIG, Path::new(".")).unwrap();
+        let server = MockServer::bind("127.0.0.1:0", config).unwrap();
+        let addr = server.local_addr().unwrap();
+        server.spawn();
+        addr
+    }
+
+    /// Sends a REST request and returns the status, headers and body.
+    fn get(addr: SocketAddr, target: &str) -> (u16, Vec<(String, String)>, Vec<u8>) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        let request = format!(
+            "GET {target} HTTP/1.1\r\nHost: {addr}\r\nAccept: application/sbe\r\nConnection: close\r\n\r\n"
+        );
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut raw = Vec::new();
+        stream.read_to_end(&mut raw).unwrap();
+        let mut headers = [httparse::EMPTY_HEADER; 16];
+        let mut response = httparse::Response::new(&mut headers);
+        let httparse::Status::Complete(length) = response.parse(&raw).unwrap() else {
+            panic!("incomplete response head");
+        };
+        let headers = response
+            .headers
+            .iter()
+            .map(|header| {
+                let value = String::from_utf8_lossy(header.value).into_owned();
+                (header.name.to_owned(), value)
+            })
+            .collect();
+        (response.code.unwrap(), headers, raw[length..].to_vec())
+    }
+
+    fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+        headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[test]
+    fn rest_reports_usage_and_refuses_over_the_limit() {
+        let addr = serve();
+        let (status, headers, body) = get(addr, "/api/v3/depth?symbol=BTCUSDT");
+        assert_eq!(status, 200);
+        assert_eq!(header(&headers, "X-MBX-USED-WEIGHT-1D"), Some("4"));
+        assert_eq!(header(&headers, "X-MBX-SBE-DEPRECATED"), Some("true"));
+        let depth: DepthResponse = decode(&body).unwrap();
+        assert_eq!(depth.last_update_id, 1);
+
+        get(addr, "/api/v3/depth?symbol=BTCUSDT");
+        let (status, headers, body) = get(addr, "/api/v3/depth?symbol=BTCUSDT");
+        assert_eq!(status, 429);
+        assert_eq!(header(&headers, "X-MBX-USED-WEIGHT-1D"), Some("12"));
+        assert!(header(&headers, "Retry-After").is_some());
+        let error: ErrorResponse = decode(&body).unwrap();
+        assert_eq!(error.code, TOO_MANY_REQUESTS_CODE);
+        assert!(error
+            .retry_after
+            .is_some_and(|retry_after| retry_after > now_ms()));
+    }
+
+    #[test]
+    fn ws_api_answers_with_rate_limits_and_scripted_replies() {
+        let addr = serve();
+        let mut client = Client::connect(&format!("ws://{addr}")).unwrap();
+
+        let response = client.request("order.place", Value::Null).unwrap();
+        assert_eq!(response.status, 200);
+        assert!(response.sbe_schema_id_version_deprecated);
+        assert_eq!(response.rate_limits.len(), 1);
+        assert_eq!(response.rate_limits[0].current, 1);
+        let ack: NewOrderAckResponse = response.decode_result().unwrap();
+        assert_eq!(ack.order_id, 28);
+
+        for _ in 0..2 {
+            let response = client.request("order.place", Value::Null).unwrap();
+            assert_eq!(response.status, 400);
+            assert_eq!(response.error().unwrap().unwrap().code, -2010);
+        }
+
+        let params = serde_json::json!({"symbol": "BTCUSDT"});
+        let response = client.request("depth", params.clone()).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.rate_limits[0].current, 7);
+        let response = client.request("depth", params).unwrap();
+        assert_eq!(response.status, 429);
+        let error = response.error().unwrap().unwrap();
+        assert_eq!(error.code, TOO_MANY_REQUESTS_CODE);
+        assert!(error.retry_after.is_some());
+        client.close().unwrap();
+    }
+
+    #[test]
+    fn unrouted_ws_request_is_an_error() {
+        let addr = serve();
+        let mut client = Client::connect(&format!("ws://{addr}")).unwrap();
+        let response = client.request("time", Value::Null).unwrap();
+        assert_eq!(response.status, 400);
+        assert_eq!(response.error().unwrap().unwrap().code, UNKNOWN_CODE);
+    }
+}
@@ -0,0 +1,88 @@
+//! Rate-limit counters shared by every connection, as the exchange counts
+//! per IP address.
+//!
+//! Requests are counted over fixed windows aligned to the Unix epoch, so a
+//! `MINUTE` limit resets on the minute. A request is counted even when it
+//! goes over a limit, and is then refused until its window ends.
+
+use crate::config::RateLimit;
+use spot_sbe::RateLimitType;
+use std::sync::Mutex;
+
+/// How much of a limit is used, this request included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Usage {
+    pub limit: RateLimit,
+    pub used: i64,
+}
+
+impl Usage {
+    #[inline]
+    pub fn exceeded(&self) -> bool {
+        self.used > self.limit.limit
+    }
+}
+
+/// The outcome of counting one request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Counted {
+    /// Every limit the request counted towards
+    pub usage: Vec<Usage>,
+    /// When the first limit it went over resets, in milliseconds since the
+    /// Unix epoch
+    pub retry_after: Option<i64>,
+}
+
+impl Counted {
+    /// The first limit the request went over.
+    pub fn exceeded(&self) -> Option<&Usage> {
+        self.usage.iter().find(|usage| usage.exceeded())
+    }
+}
+
+#[derive(Debug)]
+pub struct RateLimiter {
+    limits: Vec<RateLimit>,
+    /// Start of the current window and how much of it is used, by limit
+    windows: Mutex<Vec<(i64, i64)>>,
+}
+
+impl RateLimiter {
+    pub fn new(limits: Vec<RateLimit>) -> Self {
+        let windows = Mutex::new(vec![(0, 0); limits.len()]);
+        Self { limits, windows }
+    }
+
+    /// Counts a request of `weight` at `now`, in milliseconds since the Unix
+    /// epoch. `CONNECTIONS` limits are not counted.
+    pub fn count(&self, weight: i64, orders: bool, now: i64) -> Counted {
+        let mut windows = self.windows.lock().unwrap();
+        let mut counted = Counted {
+            usage: Vec::new(),
+            retry_after: None,
+        };
+        for (limit, (start, used)) in self.limits.iter().zip(windows.iter_mut()) {
+            let cost = match limit.rate_limit_type {
+                RateLimitType::RawRequests => 1,
+                RateLimitType::RequestWeight => weight,
+                RateLimitType::Orders if orders => 1,
+                _ => continue,
+            };
+            let window = limit.window_ms();
+            let window_start = now - now.rem_euclid(window);
+            if *start != window_start {
+                (*start, *used) = (window_start, 0);
+            }
+            *used += cost;
+            let usage = Usage {
+                limit: *limit,
+                used: *used,
+            };
+            if usage.exceeded() && counted.retry_after.is_none() {
+                counted.retry_after = Some(window_start + window);
+            }
+            counted.usage.push(usage);
+        }
+        counted
+    }
+}
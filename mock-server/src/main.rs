@@ -0,0 +1,39 @@
+//! Serves the canned or scripted SBE responses of a configuration file on
+//! the REST API and WebSocket API paths, for testing clients offline.
+//!
+//! ```shell
+//! mock-server --bind 127.0.0.1:8080 mock-server/mock.json
+//! ```
+//!
+//! The URL it listens on is printed to stdout once it is ready, so that a
+//! script can start it on any free port.
+
+use anyhow::{bail, Context};
+use mock_server::{Config, MockServer};
+
+fn main() -> anyhow::Result<()> {
+    const USAGE: &str = "Usage: mock-server [--bind <address>] <config.json>";
+    let (mut bind, mut config) = ("127.0.0.1:0".to_owned(), None);
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bind" => {
+                let Some(addr) = args.next() else {
+                    bail!("Missing value for --bind");
+                };
+                bind = addr;
+            }
+            _ if arg.starts_with('-') || config.is_some() => bail!(USAGE),
+            _ => config = Some(arg),
+        }
+    }
+    let Some(config) = config else {
+        bail!(USAGE);
+    };
+    let config = Config::load(&config)?;
+    let server =
+        MockServer::bind(&bind, config).with_context(|| format!("Failed to bind {bind}"))?;
+    println!("{}", server.rest_url()?);
+    server.run()?;
+    Ok(())
+}
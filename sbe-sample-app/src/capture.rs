@@ -0,0 +1,303 @@
+use crate::{
+    compat::{Compat, CompatPolicy, Rejected},
+    frame::{FrameReader, Framing},
+    render, OutputFormat,
+};
+use anyhow::{bail, Context};
+use serde::Serialize;
+use spot_sbe::capture::{now_us, Capture, CaptureWriter, Direction, Record};
+use std::fs::File;
+use std::io::{self, BufReader, Read, Write};
+use std::time::Duration;
+
+fn parse_connection(value: Option<String>) -> anyhow::Result<u32> {
+    let Some(value) = value else {
+        bail!("Missing value for --connection");
+    };
+    value
+        .parse()
+        .with_context(|| format!("Invalid connection ID {value:?}"))
+}
+
+fn open_capture(path: &str) -> anyhow::Result<Capture> {
+    let capture = Capture::open(path).with_context(|| format!("Failed to read {path}"))?;
+    if capture.truncated_bytes() > 0 {
+        eprintln!(
+            "Warning: {path} ends with a partial record of {} bytes, which is skipped",
+            capture.truncated_bytes()
+        );
+    }
+    Ok(capture)
+}
+
+/// Appends every message read from stdin or the given files to a capture,
+/// stamped with the time it was read. Messages are recorded as inbound, or
+/// as outbound requests with `--outbound`.
+pub fn record(mut args: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    const USAGE: &str = "Usage: sbe-sample-app record [--connection <id>] [--outbound] [--length-prefixed] <capture> [files...]";
+    let (mut connection_id, mut direction, mut framing, mut capture, mut paths) =
+        (0, Direction::Inbound, Framing::Sbe, None, Vec::new());
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--connection" => connection_id = parse_connection(args.next())?,
+            "--outbound" => direction = Direction::Outbound,
+            "--length-prefixed" => framing = Framing::LengthPrefixed,
+            "-" => paths.push(arg),
+            _ if arg.starts_with('-') => bail!(USAGE),
+            _ if capture.is_none() => capture = Some(arg),
+            _ => paths.push(arg),
+        }
+    }
+    let Some(capture) = capture else {
+        bail!(USAGE);
+    };
+    let mut writer = CaptureWriter::append(&capture)
+        .with_context(|| format!("Failed to open {capture} for appending"))?;
+    let mut count = 0;
+    let mut record_stream = |input: Box<dyn Read>| -> anyhow::Result<()> {
+        let mut frames = FrameReader::new(input, framing);
+        while let Some(frame) = frames.next_frame()? {
+            writer.write(&Record {
+                received_at: now_us(),
+                connection_id,
+                direction,
+                frame,
+            })?;
+            // Each record is complete on disk before the next is read.
+            writer.flush()?;
+            count += 1;
+        }
+        Ok(())
+    };
+    if paths.is_empty() {
+        record_stream(Box::new(io::stdin().lock()))?;
+    }
+    for path in &paths {
+        if path == "-" {
+            record_stream(Box::new(io::stdin().lock()))?;
+        } else {
+            let file = File::open(path).with_context(|| format!("Failed to open {path}"))?;
+            record_stream(Box::new(BufReader::new(file)))?;
+        }
+    }
+    eprintln!("Recorded {count} messages to {capture}");
+    Ok(())
+}
+
+/// Writes the inbound frames of a capture to stdout, optionally as far
+/// apart as they were received.
+pub fn replay(mut args: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    const USAGE: &str =
+        "Usage: sbe-sample-app replay [--paced] [--connection <id>] [--length-prefixed] <capture>";
+    let (mut paced, mut connection_id, mut framing, mut path) = (false, None, Framing::Sbe, None);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--paced" => paced = true,
+            "--connection" => connection_id = Some(parse_connection(args.next())?),
+            "--length-prefixed" => framing = Framing::LengthPrefixed,
+            _ if arg.starts_with('-') || path.is_some() => bail!(USAGE),
+            _ => path = Some(arg),
+        }
+    }
+    let Some(path) = path else {
+        bail!(USAGE);
+    };
+    let capture = open_capture(&path)?;
+    let mut stdout = io::stdout().lock();
+    let mut previous: Option<i64> = None;
+    let records = capture.iter().filter(|record| {
+        record.direction == Direction::Inbound
+            && connection_id.is_none_or(|id| id == record.connection_id)
+    });
+    for record in records {
+        if let (true, Some(previous)) = (paced, previous) {
+            let gap = record.received_at.saturating_sub(previous);
+            if gap > 0 {
+                stdout.flush()?;
+                std::thread::sleep(Duration::from_micros(gap as u64));
+            }
+        }
+        previous = Some(record.received_at);
+        if framing == Framing::LengthPrefixed {
+            let length = u32::try_from(record.frame.len())?;
+            stdout.write_all(&length.to_le_bytes())?;
+        }
+        stdout.write_all(record.frame)?;
+    }
+    stdout.flush()?;
+    Ok(())
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CatRecord<T> {
+    record: usize,
+    /// Microseconds since the Unix epoch
+    received_at: i64,
+    connection_id: u32,
+    direction: &'static str,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    is_error: bool,
+    message: T,
+}
+
+/// Decodes every record of a capture, printing each with its metadata.
+/// Outbound frames that are not SBE, such as JSON requests, are printed as
+/// they were sent. Records the compatibility policy rejects are reported
+/// and skipped, and make the exit status non-zero.
+pub fn cat(mut args: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    const USAGE: &str =
+        "Usage: sbe-sample-app cat [--json] [--compat <policy>] [--connection <id>] <capture>";
+    let (mut format, mut policy, mut connection_id, mut path) =
+        (OutputFormat::Yaml, CompatPolicy::default(), None, None);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--yaml" => format = OutputFormat::Yaml,
+            "--json" => format = OutputFormat::Json,
+            "--compat" => {
+                let Some(value) = args.next() else {
+                    bail!("Missing value for --compat");
+                };
+                policy = CompatPolicy::parse(&value)?;
+            }
+            "--connection" => connection_id = Some(parse_connection(args.next())?),
+            _ if arg.starts_with('-') || path.is_some() => bail!(USAGE),
+            _ => path = Some(arg),
+        }
+    }
+    let Some(path) = path else {
+        bail!(USAGE);
+    };
+    let capture = open_capture(&path)?;
+    let mut compat = Compat::new(policy, Box::new(io::stderr()));
+    let (mut stdout, mut stderr) = (io::stdout().lock(), io::stderr().lock());
+    let failed = cat_records(
+        &capture,
+        format,
+        connection_id,
+        &mut compat,
+        &mut stdout,
+        &mut stderr,
+    )?;
+    if failed {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Writes the records of `capture` to `out` and the ones the compatibility
+/// policy rejects to `errors`. Returns whether any were rejected.
+fn cat_records(
+    capture: &Capture,
+    format: OutputFormat,
+    connection_id: Option<u32>,
+    compat: &mut Compat,
+    out: &mut dyn Write,
+    errors: &mut dyn Write,
+) -> anyhow::Result<bool> {
+    let mut failed = false;
+    for (index, record) in capture.iter().enumerate() {
+        if connection_id.is_some_and(|id| id != record.connection_id) {
+            continue;
+        }
+        let direction = match record.direction {
+            Direction::Inbound => "inbound",
+            Direction::Outbound => "outbound",
+        };
+        let text = core::str::from_utf8(record.frame).ok();
+        let (message, is_error) = match text {
+            // Requests to the WebSocket API are JSON text.
+            Some(text) if record.direction == Direction::Outbound && text.starts_with('{') => {
+                (text.to_owned(), false)
+            }
+            _ => {
+                let document = match render(record.frame, format, compat) {
+                    Ok(document) => document,
+                    Err(err) if err.is::<Rejected>() => {
+                        writeln!(errors, "Error: record {index}: {err}")?;
+                        failed = true;
+                        continue;
+                    }
+                    Err(err) => return Err(err.context(format!("Failed to decode record {index}"))),
+                };
+                (document.text, document.is_error)
+            }
+        };
+        match format {
+            OutputFormat::Yaml => {
+                let message: serde_yaml::Value = serde_yaml::from_str(&message)?;
+                let record = CatRecord {
+                    record: index,
+                    received_at: record.received_at,
+                    connection_id: record.connection_id,
+                    direction,
+                    is_error,
+                    message,
+                };
+                writeln!(out, "{}", serde_yaml::to_string(&record)?)?;
+            }
+            OutputFormat::Json => {
+                let message: serde_json::Value = serde_json::from_str(&message)?;
+                let record = CatRecord {
+                    record: index,
+                    received_at: record.received_at,
+                    connection_id: record.connection_id,
+                    direction,
+                    is_error,
+                    message,
+                };
+                writeln!(out, "{}", serde_json::to_string_pretty(&record)?)?;
+            }
+        }
+    }
+    Ok(failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ping(version: u16) -> Vec<u8> {
+        [0, 101, 2, version].map(u16::to_le_bytes).concat()
+    }
+
+    #[test]
+    fn cat_skips_rejected_records() {
+        let frames = [ping(1), ping(0), ping(1)];
+        let mut writer = CaptureWriter::new(Vec::new()).unwrap();
+        for (received_at, frame) in (1..).zip(&frames) {
+            writer
+                .write(&Record {
+                    received_at,
+                    connection_id: 0,
+                    direction: Direction::Inbound,
+                    frame,
+                })
+                .unwrap();
+        }
+        let capture = Capture::from_bytes(writer.into_inner()).unwrap();
+        let mut compat = Compat::new(CompatPolicy::Strict, Box::new(io::sink()));
+        let (mut out, mut errors) = (Vec::new(), Vec::new());
+        let failed = cat_records(
+            &capture,
+            OutputFormat::Json,
+            None,
+            &mut compat,
+            &mut out,
+            &mut errors,
+        )
+        .unwrap();
+        assert!(failed);
+        let out = String::from_utf8(out).unwrap();
+        let records: Vec<u64> = serde_json::Deserializer::from_str(&out)
+            .into_iter::<serde_json::Value>()
+            .map(|record| record.unwrap()["record"].as_u64().unwrap())
+            .collect();
+        assert_eq!(records, [0, 2]);
+        let errors = String::from_utf8(errors).unwrap();
+        assert!(
+            errors.starts_with("Error: record 1: Schema 2:0 rejected by the strict policy"),
+            "{errors}"
+        );
+    }
+}
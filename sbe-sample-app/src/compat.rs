@@ -0,0 +1,279 @@
+//! Schema compatibility policy: whether to decode a message whose header
+//! names a schema version other than the compiled-in one, or that the server
+//! flags as using a deprecated schema. Every decision other than an exact
+//! match is reported as a JSON line, separately from the decoded output.
+//! A rejection is returned as a [`Rejected`] error, so that the caller can
+//! skip that message and carry on with the rest of the stream.
+
+use anyhow::bail;
+use serde::Serialize;
+use spot_sbe::{schema_for, MessageHeaderDecoder, ReadBuf};
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::Write;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CompatPolicy {
+    /// Only the exact compiled-in version; deprecated schemas are rejected.
+    Strict,
+    /// Older versions are decoded with their `acting_version`, so members
+    /// added since read as null or default; newer versions are rejected.
+    #[default]
+    Warn,
+    /// As `Warn`, and newer versions are decoded too, skipping the members
+    /// the compiled-in codecs do not know about.
+    AllowNewer,
+}
+
+impl CompatPolicy {
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        Ok(match value {
+            "strict" => Self::Strict,
+            "warn" => Self::Warn,
+            "allow-newer" => Self::AllowNewer,
+            _ => bail!(
+                "Unexpected compatibility policy {value:?}; expected strict, warn or allow-newer"
+            ),
+        })
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Strict => "strict",
+            Self::Warn => "warn",
+            Self::AllowNewer => "allow-newer",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Check {
+    SchemaVersion,
+    DeprecatedSchema,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Warn,
+    Reject,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Decision {
+    pub check: Check,
+    pub outcome: Outcome,
+    pub policy: CompatPolicy,
+    pub template_id: u16,
+    pub schema_id: u16,
+    /// Version in the message header
+    pub version: u16,
+    /// Version of the codecs the message is decoded with
+    pub decoder_version: u16,
+    pub detail: String,
+}
+
+/// A message the policy does not allow to be decoded.
+#[derive(Debug)]
+pub struct Rejected {
+    pub schema_id: u16,
+    pub version: u16,
+    pub policy: CompatPolicy,
+    pub detail: String,
+}
+
+impl fmt::Display for Rejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Schema {}:{} rejected by the {} policy: {}",
+            self.schema_id,
+            self.version,
+            self.policy.name(),
+            self.detail
+        )
+    }
+}
+
+impl std::error::Error for Rejected {}
+
+/// Applies the policy and writes its decisions to `report`.
+pub struct Compat {
+    policy: CompatPolicy,
+    report: Box<dyn Write>,
+}
+
+impl Compat {
+    pub fn new(policy: CompatPolicy, report: Box<dyn Write>) -> Self {
+        Self { policy, report }
+    }
+
+    /// Checks the header's version against the codecs `spot_sbe` decodes its
+    /// schema ID with. Returns a [`Rejected`] error if the policy rejects it.
+    pub fn check_version(
+        &mut self,
+        header: &MessageHeaderDecoder<ReadBuf<'_>>,
+    ) -> anyhow::Result<()> {
+        let schema_id = header.schema_id();
+        let version = header.version();
+        let Ok(schema) = schema_for(schema_id, version) else {
+            bail!("Unexpected schema ID. Got {schema_id}; spot_sbe has no codecs for it");
+        };
+        let (outcome, detail) = match (self.policy, version.cmp(&schema.version)) {
+            (_, Ordering::Equal) => return Ok(()),
+            (CompatPolicy::Strict, _) => (
+                Outcome::Reject,
+                format!("strict policy requires version {}", schema.version),
+            ),
+            (_, Ordering::Less) => (
+                Outcome::Warn,
+                format!("members added after version {version} read as null or default"),
+            ),
+            (CompatPolicy::Warn, Ordering::Greater) => (
+                Outcome::Reject,
+                format!("newer than the compiled-in version {}", schema.version),
+            ),
+            (CompatPolicy::AllowNewer, Ordering::Greater) => (
+                Outcome::Warn,
+                format!("members added after version {} are skipped", schema.version),
+            ),
+        };
+        self.decide(Decision {
+            check: Check::SchemaVersion,
+            outcome,
+            policy: self.policy,
+            template_id: header.template_id(),
+            schema_id,
+            version,
+            decoder_version: schema.version,
+            detail,
+        })
+    }
+
+    /// Handles the `sbeSchemaIdVersionDeprecated` flag of a WebSocket response
+    /// whose header named `schema_id` and `version`.
+    pub fn check_deprecated(
+        &mut self,
+        template_id: u16,
+        schema_id: u16,
+        version: u16,
+        deprecated: bool,
+    ) -> anyhow::Result<()> {
+        if !deprecated {
+            return Ok(());
+        }
+        let outcome = match self.policy {
+            CompatPolicy::Strict => Outcome::Reject,
+            CompatPolicy::Warn | CompatPolicy::AllowNewer => Outcome::Warn,
+        };
+        self.decide(Decision {
+            check: Check::DeprecatedSchema,
+            outcome,
+            policy: self.policy,
+            template_id,
+            schema_id,
+            version,
+            decoder_version: schema_for(schema_id, version).map_or(version, |s| s.version),
+            detail: format!("the server reports schema {schema_id}:{version} as deprecated"),
+        })
+    }
+
+    fn decide(&mut self, decision: Decision) -> anyhow::Result<()> {
+        serde_json::to_writer(&mut self.report, &decision)?;
+        writeln!(self.report)?;
+        self.report.flush()?;
+        if decision.outcome == Outcome::Reject {
+            return Err(Rejected {
+                schema_id: decision.schema_id,
+                version: decision.version,
+                policy: decision.policy,
+                detail: decision.detail,
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// A report whose contents stay readable after it is boxed.
+    #[derive(Clone, Default)]
+    struct Report(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Report {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn check(policy: CompatPolicy, version: u16) -> (anyhow::Result<()>, String) {
+        let report = Report::default();
+        let mut compat = Compat::new(policy, Box::new(report.clone()));
+        let header = [0u16, 101, 2, version].map(u16::to_le_bytes).concat();
+        let header = MessageHeaderDecoder::default()
+            .wrap(ReadBuf::new(&header), 0)
+            .unwrap();
+        let result = compat.check_version(&header);
+        let report = String::from_utf8(report.0.take()).unwrap();
+        (result, report)
+    }
+
+    #[test]
+    fn compiled_in_version_is_not_reported() {
+        for policy in [
+            CompatPolicy::Strict,
+            CompatPolicy::Warn,
+            CompatPolicy::AllowNewer,
+        ] {
+            let (result, report) = check(policy, 1);
+            assert!(result.is_ok());
+            assert_eq!(report, "");
+        }
+    }
+
+    #[test]
+    fn other_versions_are_warned_about_or_rejected() {
+        let cases = [
+            (CompatPolicy::Strict, 0, Outcome::Reject),
+            (CompatPolicy::Warn, 0, Outcome::Warn),
+            (CompatPolicy::Warn, 2, Outcome::Reject),
+            (CompatPolicy::AllowNewer, 2, Outcome::Warn),
+        ];
+        for (policy, version, outcome) in cases {
+            let (result, report) = check(policy, version);
+            let decision: serde_json::Value = serde_json::from_str(&report).unwrap();
+            assert_eq!(decision["check"], "schema-version");
+            assert_eq!(decision["outcome"], serde_json::to_value(outcome).unwrap());
+            assert_eq!(decision["version"], version);
+            match outcome {
+                Outcome::Warn => assert!(result.is_ok()),
+                Outcome::Reject => assert!(result.unwrap_err().is::<Rejected>()),
+            }
+        }
+    }
+
+    #[test]
+    fn deprecated_schema_is_rejected_only_when_strict() {
+        let mut compat = Compat::new(CompatPolicy::Strict, Box::new(std::io::sink()));
+        assert!(compat.check_deprecated(101, 2, 1, false).is_ok());
+        let err = compat.check_deprecated(101, 2, 1, true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema 2:1 rejected by the strict policy: the server reports schema 2:1 as deprecated"
+        );
+        let mut compat = Compat::new(CompatPolicy::Warn, Box::new(std::io::sink()));
+        assert!(compat.check_deprecated(101, 2, 1, true).is_ok());
+    }
+}
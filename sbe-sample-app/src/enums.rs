@@ -0,0 +1,153 @@
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+use spot_sbe::{
+    CancelReplaceStatus, ListOrderStatus, OrderSide, OrderStatus, OrderType, TimeInForce,
+};
+
+pub fn serialize_cancel_replace_status<S: Serializer>(
+    val: &CancelReplaceStatus,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use CancelReplaceStatus::*;
+    let str_val = match val {
+        Success => "SUCCESS",
+        Failure => "FAILURE",
+        NotAttempted => "NOT_ATTEMPTED",
+        NullVal => return serializer.serialize_none(),
+    };
+    serializer.serialize_str(str_val)
+}
+
+pub fn serialize_list_order_status<S: Serializer>(
+    val: &ListOrderStatus,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use ListOrderStatus::*;
+    let str_val = match val {
+        Canceling => "CANCELING",
+        Executing => "EXECUTING",
+        AllDone => "ALL_DONE",
+        Reject => "REJECT",
+        NullVal => return serializer.serialize_none(),
+    };
+    serializer.serialize_str(str_val)
+}
+
+pub fn serialize_order_side<S: Serializer>(
+    val: &OrderSide,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use OrderSide::*;
+    let str_val = match val {
+        Buy => "BUY",
+        Sell => "SELL",
+        NullVal => return serializer.serialize_none(),
+    };
+    serializer.serialize_str(str_val)
+}
+
+pub fn serialize_order_status<S: Serializer>(
+    val: &OrderStatus,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use OrderStatus::*;
+    let str_val = match val {
+        New => "NEW",
+        PartiallyFilled => "PARTIALLY_FILLED",
+        Filled => "FILLED",
+        Canceled => "CANCELED",
+        PendingCancel => "PENDING_CANCEL",
+        Rejected => "REJECTED",
+        Expired => "EXPIRED",
+        ExpiredInMatch => "EXPIRED_IN_MATCH",
+        PendingNew => "PENDING_NEW",
+        Unknown => "UNKNOWN",
+        NullVal => return serializer.serialize_none(),
+    };
+    serializer.serialize_str(str_val)
+}
+
+pub fn serialize_order_type<S: Serializer>(
+    val: &OrderType,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use OrderType::*;
+    let str_val = match val {
+        Market => "MARKET",
+        Limit => "LIMIT",
+        StopLoss => "STOP_LOSS",
+        StopLossLimit => "STOP_LOSS_LIMIT",
+        TakeProfit => "TAKE_PROFIT",
+        TakeProfitLimit => "TAKE_PROFIT_LIMIT",
+        LimitMaker => "LIMIT_MAKER",
+        NullVal => return serializer.serialize_none(),
+    };
+    serializer.serialize_str(str_val)
+}
+
+pub fn serialize_time_in_force<S: Serializer>(
+    val: &TimeInForce,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use TimeInForce::*;
+    let str_val = match val {
+        Gtc => "GTC",
+        Ioc => "IOC",
+        Fok => "FOK",
+        NullVal => return serializer.serialize_none(),
+    };
+    serializer.serialize_str(str_val)
+}
+
+pub fn deserialize_order_side<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<OrderSide, D::Error> {
+    use OrderSide::*;
+    let str_val = String::deserialize(deserializer)?;
+    Ok(match str_val.as_str() {
+        "BUY" => Buy,
+        "SELL" => Sell,
+        _ => return Err(D::Error::unknown_variant(&str_val, &["BUY", "SELL"])),
+    })
+}
+
+pub fn deserialize_order_type<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<OrderType, D::Error> {
+    use OrderType::*;
+    const VARIANTS: &[&str] = &[
+        "MARKET",
+        "LIMIT",
+        "STOP_LOSS",
+        "STOP_LOSS_LIMIT",
+        "TAKE_PROFIT",
+        "TAKE_PROFIT_LIMIT",
+        "LIMIT_MAKER",
+    ];
+    let str_val = String::deserialize(deserializer)?;
+    Ok(match str_val.as_str() {
+        "MARKET" => Market,
+        "LIMIT" => Limit,
+        "STOP_LOSS" => StopLoss,
+        "STOP_LOSS_LIMIT" => StopLossLimit,
+        "TAKE_PROFIT" => TakeProfit,
+        "TAKE_PROFIT_LIMIT" => TakeProfitLimit,
+        "LIMIT_MAKER" => LimitMaker,
+        _ => return Err(D::Error::unknown_variant(&str_val, VARIANTS)),
+    })
+}
+
+/// Absent or `null` deserializes as `NullVal`, as when decoded.
+pub fn deserialize_time_in_force<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<TimeInForce, D::Error> {
+    use TimeInForce::*;
+    let Some(str_val) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(NullVal);
+    };
+    Ok(match str_val.as_str() {
+        "GTC" => Gtc,
+        "IOC" => Ioc,
+        "FOK" => Fok,
+        _ => return Err(D::Error::unknown_variant(&str_val, &["GTC", "IOC", "FOK"])),
+    })
+}
@@ -1,7 +1,8 @@
+use crate::message::Message;
 use crate::rate_limit::RateLimit;
-use serde::{ser::SerializeSeq, Serialize, Serializer};
+use serde::{de::Error, ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer};
 use spot_sbe::{
-    AllowedSelfTradePreventionModes, OrderTypes, SelfTradePreventionMode, SymbolStatus,
+    AllowedSelfTradePreventionModes, Decimal, OrderTypes, SelfTradePreventionMode, SymbolStatus,
 };
 
 #[derive(Serialize)]
@@ -11,19 +12,8 @@ pub struct ErrorResponse {
     pub server_time: Option<i64>,
     pub retry_after: Option<i64>,
     pub msg: String,
-}
-
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Decimal {
-    mantissa: i64,
-    exponent: i8,
-}
-
-impl Decimal {
-    pub fn new(mantissa: i64, exponent: i8) -> Self {
-        Self { mantissa, exponent }
-    }
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Box<Message>>,
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -156,7 +146,7 @@ pub struct ExchangeInfo {
     pub sors: Vec<Sor>,
 }
 
-fn serialize_symbol_status<S: Serializer>(
+pub fn serialize_symbol_status<S: Serializer>(
     val: &SymbolStatus,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
@@ -174,7 +164,7 @@ fn serialize_symbol_status<S: Serializer>(
     serializer.serialize_str(str_val)
 }
 
-fn serialize_order_types<S: Serializer>(
+pub fn serialize_order_types<S: Serializer>(
     val: &OrderTypes,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
@@ -207,7 +197,7 @@ fn serialize_order_types<S: Serializer>(
     seq.end()
 }
 
-fn serialize_self_trade_prevention_mode<S: Serializer>(
+pub fn serialize_self_trade_prevention_mode<S: Serializer>(
     val: &SelfTradePreventionMode,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
@@ -222,7 +212,25 @@ fn serialize_self_trade_prevention_mode<S: Serializer>(
     serializer.serialize_str(str_val)
 }
 
-fn serialize_allowed_self_trade_prevention_modes<S: Serializer>(
+/// Absent or `null` deserializes as `NullVal`, as when decoded.
+pub fn deserialize_self_trade_prevention_mode<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<SelfTradePreventionMode, D::Error> {
+    use SelfTradePreventionMode::*;
+    const VARIANTS: &[&str] = &["NONE", "EXPIRE_TAKER", "EXPIRE_MAKER", "EXPIRE_BOTH"];
+    let Some(str_val) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(NullVal);
+    };
+    Ok(match str_val.as_str() {
+        "NONE" => None,
+        "EXPIRE_TAKER" => ExpireTaker,
+        "EXPIRE_MAKER" => ExpireMaker,
+        "EXPIRE_BOTH" => ExpireBoth,
+        _ => return Err(D::Error::unknown_variant(&str_val, VARIANTS)),
+    })
+}
+
+pub fn serialize_allowed_self_trade_prevention_modes<S: Serializer>(
     val: &AllowedSelfTradePreventionModes,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
@@ -0,0 +1,272 @@
+use anyhow::bail;
+use spot_sbe::SbeErr;
+use std::io::{self, Read};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Framing {
+    /// Concatenated SBE messages, split using each message's header, group
+    /// dimensions and var data lengths.
+    Sbe,
+    /// Each message is preceded by its length as a little-endian u32, as in
+    /// captures of WebSocket binary frames.
+    LengthPrefixed,
+}
+
+const LENGTH_PREFIX: usize = 4;
+
+/// Longest message accepted, so that a corrupt length cannot make the reader
+/// buffer the whole stream.
+const MAX_FRAME_LEN: usize = 16 * 1024 * 1024;
+
+/// Splits a byte stream into messages, reading only as much as each one needs.
+pub struct FrameReader<R> {
+    stream: R,
+    framing: Framing,
+    buffer: Vec<u8>,
+    /// Start of the unconsumed bytes in `buffer`
+    start: usize,
+    eof: bool,
+    max_frame_len: usize,
+}
+
+impl<R: Read> FrameReader<R> {
+    pub fn new(stream: R, framing: Framing) -> Self {
+        Self {
+            stream,
+            framing,
+            buffer: Vec::with_capacity(64 * 1024),
+            start: 0,
+            eof: false,
+            max_frame_len: MAX_FRAME_LEN,
+        }
+    }
+
+    /// Returns the next message, or `None` at the end of the stream.
+    pub fn next_frame(&mut self) -> anyhow::Result<Option<&[u8]>> {
+        loop {
+            let pending = &self.buffer[self.start..];
+            if pending.is_empty() && self.eof {
+                return Ok(None);
+            }
+            if let Some(length) = self.frame_length(pending)? {
+                self.check_length(length)?;
+                let (frame_start, frame_end) = match self.framing {
+                    Framing::Sbe => (self.start, self.start + length),
+                    Framing::LengthPrefixed => (
+                        self.start + LENGTH_PREFIX,
+                        self.start + LENGTH_PREFIX + length,
+                    ),
+                };
+                self.start = frame_end;
+                return Ok(Some(&self.buffer[frame_start..frame_end]));
+            }
+            if self.eof {
+                bail!(
+                    "Stream ends with a truncated message of {} bytes",
+                    pending.len()
+                );
+            }
+            self.fill()?;
+        }
+    }
+
+    /// Length of the message at the start of `pending` (excluding any length
+    /// prefix), or `None` if more bytes are needed to tell.
+    fn frame_length(&self, pending: &[u8]) -> anyhow::Result<Option<usize>> {
+        match self.framing {
+            Framing::Sbe => match spot_sbe::meta::message_length(pending) {
+                Ok(length) => Ok(Some(length)),
+                Err(SbeErr::BufferTooShort { .. }) => {
+                    // The length is only known once the whole message is in.
+                    self.check_length(pending.len())?;
+                    Ok(None)
+                }
+                Err(err) => Err(err.into()),
+            },
+            Framing::LengthPrefixed => {
+                let Some(prefix) = pending.get(..LENGTH_PREFIX) else {
+                    return Ok(None);
+                };
+                let length = u32::from_le_bytes(prefix.try_into()?) as usize;
+                self.check_length(length)?;
+                Ok((pending.len() >= LENGTH_PREFIX + length).then_some(length))
+            }
+        }
+    }
+
+    fn check_length(&self, length: usize) -> anyhow::Result<()> {
+        if length > self.max_frame_len {
+            bail!(
+                "Message of at least {length} bytes exceeds the limit of {} bytes",
+                self.max_frame_len
+            );
+        }
+        Ok(())
+    }
+
+    fn fill(&mut self) -> io::Result<()> {
+        // Drop consumed frames before growing the buffer.
+        if self.start > 0 {
+            self.buffer.drain(..self.start);
+            self.start = 0;
+        }
+        let len = self.buffer.len();
+        self.buffer.resize(len.max(32 * 1024) * 2, 0);
+        let read = loop {
+            match self.stream.read(&mut self.buffer[len..]) {
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                result => break result,
+            }
+        };
+        let read = read.inspect_err(|_| self.buffer.truncate(len))?;
+        self.buffer.truncate(len + read);
+        self.eof = read == 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spot_sbe::depth_response_codec::owned::{Asks, Bids, DepthResponse};
+    use spot_sbe::OwnedMessage;
+
+    /// Returns at most `chunk` bytes per read, to split frames across reads.
+    struct Chunked<'a> {
+        data: &'a [u8],
+        chunk: usize,
+    }
+
+    impl Read for Chunked<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let length = self.data.len().min(self.chunk).min(buf.len());
+            buf[..length].copy_from_slice(&self.data[..length]);
+            self.data = &self.data[length..];
+            Ok(length)
+        }
+    }
+
+    fn frames() -> Vec<Vec<u8>> {
+        let depth = |last_update_id| {
+            DepthResponse {
+                last_update_id,
+                bids: vec![Bids {
+                    price: "100".parse().unwrap(),
+                    qty: "1".parse().unwrap(),
+                }],
+                asks: vec![Asks::default(); 2],
+                ..Default::default()
+            }
+            .encode()
+            .unwrap()
+        };
+        let ping = [0, 101, 2, 1].map(u16::to_le_bytes).concat();
+        vec![depth(1), ping, depth(2)]
+    }
+
+    fn stream(frames: &[Vec<u8>], framing: Framing) -> Vec<u8> {
+        frames
+            .iter()
+            .flat_map(|frame| {
+                let prefix = match framing {
+                    Framing::Sbe => vec![],
+                    Framing::LengthPrefixed => (frame.len() as u32).to_le_bytes().to_vec(),
+                };
+                [prefix, frame.clone()].concat()
+            })
+            .collect()
+    }
+
+    fn read_all(data: &[u8], chunk: usize, framing: Framing) -> anyhow::Result<Vec<Vec<u8>>> {
+        read_all_up_to(data, chunk, framing, MAX_FRAME_LEN)
+    }
+
+    fn read_all_up_to(
+        data: &[u8],
+        chunk: usize,
+        framing: Framing,
+        max_frame_len: usize,
+    ) -> anyhow::Result<Vec<Vec<u8>>> {
+        let mut reader = FrameReader::new(Chunked { data, chunk }, framing);
+        reader.max_frame_len = max_frame_len;
+        let mut frames = Vec::new();
+        while let Some(frame) = reader.next_frame()? {
+            frames.push(frame.to_vec());
+        }
+        // the end of the stream stays the end
+        assert!(reader.next_frame()?.is_none());
+        Ok(frames)
+    }
+
+    #[test]
+    fn reads_frames_back_to_back() {
+        for framing in [Framing::Sbe, Framing::LengthPrefixed] {
+            let data = stream(&frames(), framing);
+            assert_eq!(read_all(&data, usize::MAX, framing).unwrap(), frames());
+        }
+    }
+
+    #[test]
+    fn reads_frames_split_across_reads() {
+        for framing in [Framing::Sbe, Framing::LengthPrefixed] {
+            let data = stream(&frames(), framing);
+            for chunk in [1, 3, 7] {
+                assert_eq!(read_all(&data, chunk, framing).unwrap(), frames());
+            }
+        }
+    }
+
+    #[test]
+    fn empty_stream_has_no_frames() {
+        for framing in [Framing::Sbe, Framing::LengthPrefixed] {
+            assert!(read_all(&[], 1, framing).unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn eof_inside_a_frame_is_an_error() {
+        for framing in [Framing::Sbe, Framing::LengthPrefixed] {
+            let data = stream(&frames(), framing);
+            // inside the header, and inside the groups of the last frame
+            for cut in [3, data.len() - 5] {
+                let err = read_all(&data[..cut], 4, framing).unwrap_err();
+                assert!(
+                    err.to_string()
+                        .starts_with("Stream ends with a truncated message"),
+                    "{err}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn oversized_frame_is_an_error() {
+        let is_oversized =
+            |err: &anyhow::Error| err.to_string().ends_with("exceeds the limit of 40 bytes");
+        // a length prefix past the limit fails before the message is read
+        let data = [(MAX_FRAME_LEN as u32 + 1).to_le_bytes().as_slice(), &[0; 8]].concat();
+        let err = read_all(&data, usize::MAX, Framing::LengthPrefixed).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Message of at least {} bytes exceeds the limit of {MAX_FRAME_LEN} bytes",
+                MAX_FRAME_LEN + 1
+            )
+        );
+        let frames = frames();
+        assert!(frames[0].len() > 40 && frames[1].len() <= 40);
+        for framing in [Framing::Sbe, Framing::LengthPrefixed] {
+            let data = stream(&frames, framing);
+            // whole or still arriving, the depth frame is too long
+            for chunk in [usize::MAX, 4] {
+                let err = read_all_up_to(&data, chunk, framing, 40).unwrap_err();
+                assert!(is_oversized(&err), "{err}");
+            }
+            let ping = stream(&frames[1..2], framing);
+            assert_eq!(
+                read_all_up_to(&ping, 4, framing, 40).unwrap(),
+                &frames[1..2]
+            );
+        }
+    }
+}
@@ -1,34 +1,84 @@
 use crate::{
-    exchange_info::{
-        Decimal, ErrorResponse, ExchangeFilter, ExchangeInfo, Sor, SymbolFilter, SymbolInfo,
-    },
+    compat::{Compat, CompatPolicy, Rejected},
+    exchange_info::{ErrorResponse, ExchangeFilter, ExchangeInfo, Sor, SymbolFilter, SymbolInfo},
+    frame::{FrameReader, Framing},
+    message::{decode_message, decode_optional_message_data, Message},
     rate_limit::RateLimit,
+    validate::{MarketState, Order, Violation},
     websocket::WebSocketMetadata,
 };
-use anyhow::bail;
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
 use spot_sbe::{
-    error_response_codec, exchange_info_response_codec, exchange_max_num_algo_orders_filter_codec,
-    exchange_max_num_iceberg_orders_filter_codec, exchange_max_num_orders_filter_codec,
-    iceberg_parts_filter_codec, lot_size_filter_codec, market_lot_size_filter_codec,
-    max_num_algo_orders_filter_codec, max_num_iceberg_orders_filter_codec,
-    max_num_orders_filter_codec, max_position_filter_codec, min_notional_filter_codec,
-    notional_filter_codec, percent_price_by_side_filter_codec, percent_price_filter_codec,
-    price_filter_codec, tp_lus_sell_filter_codec, trailing_delta_filter_codec,
-    web_socket_response_codec, BoolEnum, ErrorResponseDecoder, ExchangeInfoResponseDecoder,
-    ExchangeMaxNumAlgoOrdersFilterDecoder, ExchangeMaxNumIcebergOrdersFilterDecoder,
-    ExchangeMaxNumOrdersFilterDecoder, IcebergPartsFilterDecoder, LotSizeFilterDecoder,
-    MarketLotSizeFilterDecoder, MaxNumAlgoOrdersFilterDecoder, MaxNumIcebergOrdersFilterDecoder,
-    MaxNumOrdersFilterDecoder, MaxPositionFilterDecoder, MessageHeaderDecoder,
-    MinNotionalFilterDecoder, NotionalFilterDecoder, PercentPriceBySideFilterDecoder,
-    PercentPriceFilterDecoder, PriceFilterDecoder, ReadBuf, TPlusSellFilterDecoder,
-    TrailingDeltaFilterDecoder, WebSocketResponseDecoder,
+    cancel_replace::ErrorReply, decode_any, decode_versioned, error_response_codec,
+    exchange_info_response_codec, web_socket_response_codec, AnyMessage, BoolEnum,
+    ErrorResponseDecoder, ExchangeInfoResponseDecoder, MessageHeaderDecoder, ReadBuf,
+    WebSocketResponseDecoder,
 };
-use std::io::{self, Read};
+use std::fs::File;
+use std::io::{self, BufReader, Read, Write};
 
+mod capture;
+mod compat;
+mod enums;
 mod exchange_info;
+mod frame;
+mod message;
 mod rate_limit;
+mod trading;
+mod validate;
 mod websocket;
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Yaml,
+    /// The JSON the non-SBE API would have returned
+    Json,
+}
+
+struct Options {
+    format: OutputFormat,
+    framing: Framing,
+    compat: CompatPolicy,
+    /// Where to write compatibility decisions as JSON lines; stderr if unset
+    compat_report: Option<String>,
+    /// Files to decode in order; stdin if empty or "-"
+    paths: Vec<String>,
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> anyhow::Result<Options> {
+    let mut options = Options {
+        format: OutputFormat::Yaml,
+        framing: Framing::Sbe,
+        compat: CompatPolicy::default(),
+        compat_report: None,
+        paths: Vec::new(),
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--yaml" => options.format = OutputFormat::Yaml,
+            "--json" => options.format = OutputFormat::Json,
+            "--length-prefixed" => options.framing = Framing::LengthPrefixed,
+            "--compat" | "--compat-report" => {
+                let Some(value) = args.next() else {
+                    bail!("Missing value for {arg}");
+                };
+                if arg == "--compat" {
+                    options.compat = CompatPolicy::parse(&value)?;
+                } else {
+                    options.compat_report = Some(value);
+                }
+            }
+            "-" => options.paths.push(arg),
+            _ if arg.starts_with('-') => bail!(
+                "Unexpected argument {arg:?}; expected --yaml, --json, --length-prefixed, --compat, --compat-report or file paths"
+            ),
+            _ => options.paths.push(arg),
+        }
+    }
+    Ok(options)
+}
+
 fn read_payload(mut stream: impl Read) -> io::Result<Vec<u8>> {
     let mut payload = Vec::with_capacity(64 * 1024);
     stream.read_to_end(&mut payload)?;
@@ -45,182 +95,136 @@ fn into_bool(value: BoolEnum) -> anyhow::Result<bool> {
     })
 }
 
-fn decode_error(header: MessageHeaderDecoder<ReadBuf<'_>>) -> anyhow::Result<ErrorResponse> {
-    let mut decoder = ErrorResponseDecoder::default().header(header);
+fn decode_error(mut decoder: ErrorResponseDecoder<'_>) -> anyhow::Result<ErrorResponse> {
+    // Rejects `data` other than a `CancelReplaceOrderResponse` whose embedded
+    // errors carry no `data` of their own, which bounds the recursion below.
+    ErrorReply::from_decoder(decoder)?;
     let response = ErrorResponse {
         code: decoder.code(),
         server_time: decoder.server_time(),
         retry_after: decoder.retry_after(),
         msg: {
-            let (offset, length) = decoder.msg_decoder();
-            let slice = decoder.msg_slice((offset, length));
+            let (offset, length) = decoder.msg_decoder()?;
+            let slice = decoder.msg_slice((offset, length))?;
             String::from_utf8(slice.into())?
         },
+        data: {
+            let coordinates = decoder.data_decoder()?;
+            let slice = decoder.data_slice(coordinates)?;
+            decode_optional_message_data(slice)?
+        },
     };
     Ok(response)
 }
 
-fn decode_exchange_filter(
-    header: MessageHeaderDecoder<ReadBuf<'_>>,
-) -> anyhow::Result<ExchangeFilter> {
-    Ok(match header.template_id() {
-        exchange_max_num_orders_filter_codec::SBE_TEMPLATE_ID => {
-            let decoder = ExchangeMaxNumOrdersFilterDecoder::default().header(header);
-            ExchangeFilter::MaxNumOrders {
-                max_num_orders: decoder.max_num_orders(),
-            }
-        }
-        exchange_max_num_algo_orders_filter_codec::SBE_TEMPLATE_ID => {
-            let decoder = ExchangeMaxNumAlgoOrdersFilterDecoder::default().header(header);
-            ExchangeFilter::MaxNumAlgoOrders {
-                max_num_algo_orders: decoder.max_num_algo_orders(),
-            }
-        }
+fn decode_exchange_filter(message: AnyMessage<'_>) -> anyhow::Result<ExchangeFilter> {
+    Ok(match message {
+        AnyMessage::ExchangeMaxNumOrdersFilter(decoder) => ExchangeFilter::MaxNumOrders {
+            max_num_orders: decoder.max_num_orders(),
+        },
+        AnyMessage::ExchangeMaxNumAlgoOrdersFilter(decoder) => ExchangeFilter::MaxNumAlgoOrders {
+            max_num_algo_orders: decoder.max_num_algo_orders(),
+        },
 
-        exchange_max_num_iceberg_orders_filter_codec::SBE_TEMPLATE_ID => {
-            let decoder = ExchangeMaxNumIcebergOrdersFilterDecoder::default().header(header);
+        AnyMessage::ExchangeMaxNumIcebergOrdersFilter(decoder) => {
             ExchangeFilter::MaxNumIcebergOrders {
                 max_num_iceberg_orders: decoder.max_num_iceberg_orders(),
             }
         }
-        template_id => {
-            bail!("Unexpected exchange filter template ID: {template_id}");
+        message => {
+            bail!("Unexpected exchange filter message {}", message.name());
         }
     })
 }
 
-fn decode_symbol_filter(header: MessageHeaderDecoder<ReadBuf<'_>>) -> anyhow::Result<SymbolFilter> {
-    Ok(match header.template_id() {
-        price_filter_codec::SBE_TEMPLATE_ID => {
-            let filter = PriceFilterDecoder::default().header(header);
-            let exponent = filter.price_exponent();
-            SymbolFilter::Price {
-                min_price: Decimal::new(filter.min_price(), exponent),
-                max_price: Decimal::new(filter.max_price(), exponent),
-                tick_size: Decimal::new(filter.tick_size(), exponent),
-            }
-        }
-        percent_price_filter_codec::SBE_TEMPLATE_ID => {
-            let filter = PercentPriceFilterDecoder::default().header(header);
-            let exponent = filter.multiplier_exponent();
-            SymbolFilter::PercentPrice {
-                multiplier_up: Decimal::new(filter.multiplier_up(), exponent),
-                multiplier_down: Decimal::new(filter.multiplier_down(), exponent),
-                avg_price_mins: filter.avg_price_mins(),
-            }
-        }
-        percent_price_by_side_filter_codec::SBE_TEMPLATE_ID => {
-            let filter = PercentPriceBySideFilterDecoder::default().header(header);
-            let exponent = filter.multiplier_exponent();
-            SymbolFilter::PercentPriceBySide {
-                bid_multiplier_up: Decimal::new(filter.bid_multiplier_up(), exponent),
-                bid_multiplier_down: Decimal::new(filter.bid_multiplier_down(), exponent),
-                ask_multiplier_up: Decimal::new(filter.ask_multiplier_up(), exponent),
-                ask_multiplier_down: Decimal::new(filter.ask_multiplier_down(), exponent),
-                avg_price_mins: filter.avg_price_mins(),
-            }
-        }
-        lot_size_filter_codec::SBE_TEMPLATE_ID => {
-            let filter = LotSizeFilterDecoder::default().header(header);
-            let exponent = filter.qty_exponent();
-            SymbolFilter::LotSize {
-                min_qty: Decimal::new(filter.min_qty(), exponent),
-                max_qty: Decimal::new(filter.max_qty(), exponent),
-                step_size: Decimal::new(filter.step_size(), exponent),
-            }
-        }
-        min_notional_filter_codec::SBE_TEMPLATE_ID => {
-            let filter = MinNotionalFilterDecoder::default().header(header);
-            let exponent = filter.price_exponent();
-            SymbolFilter::MinNotional {
-                min_notional: Decimal::new(filter.min_notional(), exponent),
-                apply_to_market: into_bool(filter.apply_to_market())?,
-                avg_price_mins: filter.avg_price_mins(),
-            }
-        }
-        notional_filter_codec::SBE_TEMPLATE_ID => {
-            let filter = NotionalFilterDecoder::default().header(header);
-            let exponent = filter.price_exponent();
-            SymbolFilter::Notional {
-                min_notional: Decimal::new(filter.min_notional(), exponent),
-                apply_min_to_market: into_bool(filter.apply_min_to_market())?,
-                max_notional: Decimal::new(filter.max_notional(), exponent),
-                apply_max_to_market: into_bool(filter.apply_max_to_market())?,
-                avg_price_mins: filter.avg_price_mins(),
-            }
-        }
-        iceberg_parts_filter_codec::SBE_TEMPLATE_ID => {
-            let filter = IcebergPartsFilterDecoder::default().header(header);
-            SymbolFilter::IcebergParts {
-                filter_limit: filter.filter_limit(),
-            }
-        }
-        market_lot_size_filter_codec::SBE_TEMPLATE_ID => {
-            let filter = MarketLotSizeFilterDecoder::default().header(header);
-            let exponent = filter.qty_exponent();
-            SymbolFilter::MarketLotSize {
-                min_qty: Decimal::new(filter.min_qty(), exponent),
-                max_qty: Decimal::new(filter.max_qty(), exponent),
-                step_size: Decimal::new(filter.step_size(), exponent),
-            }
-        }
-        max_num_orders_filter_codec::SBE_TEMPLATE_ID => {
-            let filter = MaxNumOrdersFilterDecoder::default().header(header);
-            SymbolFilter::MaxNumOrders {
-                max_num_orders: filter.max_num_orders(),
-            }
-        }
-        max_num_algo_orders_filter_codec::SBE_TEMPLATE_ID => {
-            let filter = MaxNumAlgoOrdersFilterDecoder::default().header(header);
-            SymbolFilter::MaxNumAlgoOrders {
-                max_num_algo_orders: filter.max_num_algo_orders(),
-            }
-        }
-        max_num_iceberg_orders_filter_codec::SBE_TEMPLATE_ID => {
-            let filter = MaxNumIcebergOrdersFilterDecoder::default().header(header);
-            SymbolFilter::MaxNumIcebergOrders {
-                max_num_iceberg_orders: filter.max_num_iceberg_orders(),
-            }
-        }
-        max_position_filter_codec::SBE_TEMPLATE_ID => {
-            let filter = MaxPositionFilterDecoder::default().header(header);
-            let exponent = filter.qty_exponent();
-            SymbolFilter::MaxPosition {
-                max_position: Decimal::new(filter.max_position(), exponent),
-            }
-        }
-        trailing_delta_filter_codec::SBE_TEMPLATE_ID => {
-            let filter = TrailingDeltaFilterDecoder::default().header(header);
-            SymbolFilter::TrailingDelta {
-                min_trailing_above_delta: filter.min_trailing_above_delta(),
-                max_trailing_above_delta: filter.max_trailing_above_delta(),
-                min_trailing_below_delta: filter.min_trailing_below_delta(),
-                max_trailing_below_delta: filter.max_trailing_below_delta(),
-            }
-        }
-        tp_lus_sell_filter_codec::SBE_TEMPLATE_ID => {
-            let filter = TPlusSellFilterDecoder::default().header(header);
-            SymbolFilter::TPlusSell {
-                end_time: filter.end_time(),
-            }
-        }
-        template_id => {
-            bail!("Unexpected symbol filter message ID: {template_id}");
+fn decode_symbol_filter(message: AnyMessage<'_>) -> anyhow::Result<SymbolFilter> {
+    Ok(match message {
+        AnyMessage::PriceFilter(filter) => SymbolFilter::Price {
+            min_price: filter.min_price_decimal(),
+            max_price: filter.max_price_decimal(),
+            tick_size: filter.tick_size_decimal(),
+        },
+        AnyMessage::PercentPriceFilter(filter) => SymbolFilter::PercentPrice {
+            multiplier_up: filter.multiplier_up_decimal(),
+            multiplier_down: filter.multiplier_down_decimal(),
+            avg_price_mins: filter.avg_price_mins(),
+        },
+        AnyMessage::PercentPriceBySideFilter(filter) => SymbolFilter::PercentPriceBySide {
+            bid_multiplier_up: filter.bid_multiplier_up_decimal(),
+            bid_multiplier_down: filter.bid_multiplier_down_decimal(),
+            ask_multiplier_up: filter.ask_multiplier_up_decimal(),
+            ask_multiplier_down: filter.ask_multiplier_down_decimal(),
+            avg_price_mins: filter.avg_price_mins(),
+        },
+        AnyMessage::LotSizeFilter(filter) => SymbolFilter::LotSize {
+            min_qty: filter.min_qty_decimal(),
+            max_qty: filter.max_qty_decimal(),
+            step_size: filter.step_size_decimal(),
+        },
+        AnyMessage::MinNotionalFilter(filter) => SymbolFilter::MinNotional {
+            min_notional: filter.min_notional_decimal(),
+            apply_to_market: into_bool(filter.apply_to_market())?,
+            avg_price_mins: filter.avg_price_mins(),
+        },
+        AnyMessage::NotionalFilter(filter) => SymbolFilter::Notional {
+            min_notional: filter.min_notional_decimal(),
+            apply_min_to_market: into_bool(filter.apply_min_to_market())?,
+            max_notional: filter.max_notional_decimal(),
+            apply_max_to_market: into_bool(filter.apply_max_to_market())?,
+            avg_price_mins: filter.avg_price_mins(),
+        },
+        AnyMessage::IcebergPartsFilter(filter) => SymbolFilter::IcebergParts {
+            filter_limit: filter.filter_limit(),
+        },
+        AnyMessage::MarketLotSizeFilter(filter) => SymbolFilter::MarketLotSize {
+            min_qty: filter.min_qty_decimal(),
+            max_qty: filter.max_qty_decimal(),
+            step_size: filter.step_size_decimal(),
+        },
+        AnyMessage::MaxNumOrdersFilter(filter) => SymbolFilter::MaxNumOrders {
+            max_num_orders: filter.max_num_orders(),
+        },
+        AnyMessage::MaxNumAlgoOrdersFilter(filter) => SymbolFilter::MaxNumAlgoOrders {
+            max_num_algo_orders: filter.max_num_algo_orders(),
+        },
+        AnyMessage::MaxNumIcebergOrdersFilter(filter) => SymbolFilter::MaxNumIcebergOrders {
+            max_num_iceberg_orders: filter.max_num_iceberg_orders(),
+        },
+        AnyMessage::MaxPositionFilter(filter) => SymbolFilter::MaxPosition {
+            max_position: filter.max_position_decimal(),
+        },
+        AnyMessage::TrailingDeltaFilter(filter) => SymbolFilter::TrailingDelta {
+            min_trailing_above_delta: filter.min_trailing_above_delta(),
+            max_trailing_above_delta: filter.max_trailing_above_delta(),
+            min_trailing_below_delta: filter.min_trailing_below_delta(),
+            max_trailing_below_delta: filter.max_trailing_below_delta(),
+        },
+        AnyMessage::TPlusSellFilter(filter) => SymbolFilter::TPlusSell {
+            end_time: filter.end_time(),
+        },
+        message => {
+            bail!("Unexpected symbol filter message {}", message.name());
         }
     })
 }
 
 fn decode_websocket_metadata(
     header: MessageHeaderDecoder<ReadBuf<'_>>,
+    compat: &mut Compat,
 ) -> anyhow::Result<(WebSocketMetadata, usize)> {
-    let decoder = WebSocketResponseDecoder::default().header(header);
-    if into_bool(decoder.sbe_schema_id_version_deprecated())? {
-        println!("Warning: sbe-sample-app is using a deprecated schema");
-    }
+    let (template_id, schema_id, version) =
+        (header.template_id(), header.schema_id(), header.version());
+    let decoder = WebSocketResponseDecoder::default().header(header)?;
+    compat.check_deprecated(
+        template_id,
+        schema_id,
+        version,
+        into_bool(decoder.sbe_schema_id_version_deprecated())?,
+    )?;
     let status = decoder.status();
-    let mut decoder = decoder.rate_limits_decoder();
+    let mut decoder = decoder.rate_limits_decoder()?;
     let count = decoder.count();
-    let mut rate_limits = Vec::with_capacity(count.try_into()?);
+    let mut rate_limits = Vec::new();
     for _ in 0..count {
         decoder.advance()?;
         let rate_limit = RateLimit {
@@ -233,62 +237,18 @@ fn decode_websocket_metadata(
         rate_limits.push(rate_limit);
     }
     let mut decoder = decoder.parent()?;
-    let coordinates = decoder.id_decoder();
-    let id = decoder.id_slice(coordinates);
+    let coordinates = decoder.id_decoder()?;
+    let id = decoder.id_slice(coordinates)?;
     let id = String::from_utf8(id.to_vec())?;
     let response = WebSocketMetadata::new(status, rate_limits, id);
-    let coordinates = decoder.result_decoder();
+    let coordinates = decoder.result_decoder()?;
     Ok((response, coordinates.0))
 }
 
-fn main() -> anyhow::Result<()> {
-    let payload = read_payload(io::stdin())?;
-    let mut decoder = MessageHeaderDecoder::default().wrap(ReadBuf::new(&payload), 0);
-    // A separate "ErrorResponse" message is returned for errors and its format
-    // is expected to be backwards compatible across all schema IDs.
-    if decoder.template_id() == error_response_codec::SBE_TEMPLATE_ID {
-        let response = decode_error(decoder)?;
-        let yaml = serde_yaml::to_string(&response)?;
-        bail!(yaml);
-    }
-    let mut websocket_meta = None;
-    let schema_id = decoder.schema_id();
-    if schema_id != exchange_info_response_codec::SBE_SCHEMA_ID {
-        bail!(
-            "Unexpected schema ID. Got {schema_id}; expected {}",
-            exchange_info_response_codec::SBE_SCHEMA_ID
-        );
-    }
-    let version = decoder.version();
-    if version != exchange_info_response_codec::SBE_SCHEMA_VERSION {
-        println!(
-            "Warning: Unexpected schema version. Got {version}; expected {}",
-            exchange_info_response_codec::SBE_SCHEMA_VERSION,
-        );
-        // Schemas with the same ID are expected to be backwards compatible.
-    }
-    if decoder.template_id() == web_socket_response_codec::SBE_TEMPLATE_ID {
-        let (websocket, offset) = decode_websocket_metadata(decoder)?;
-        websocket_meta = Some(websocket);
-        decoder = MessageHeaderDecoder::default().wrap(ReadBuf::new(&payload[offset..]), 0);
-        if decoder.template_id() == error_response_codec::SBE_TEMPLATE_ID {
-            let response = decode_error(decoder)?;
-            let yaml = if let Some(websocket_meta) = websocket_meta.as_mut() {
-                websocket_meta.set_error(response);
-                serde_yaml::to_string(&websocket_meta)?
-            } else {
-                serde_yaml::to_string(&response)?
-            };
-            bail!(yaml);
-        }
-    }
-    if decoder.template_id() != exchange_info_response_codec::SBE_TEMPLATE_ID {
-        bail!("Unexpected template ID {}", decoder.template_id());
-    }
-    let decoder = ExchangeInfoResponseDecoder::default().header(decoder);
-    let mut decoder = decoder.rate_limits_decoder();
+fn decode_exchange_info(decoder: ExchangeInfoResponseDecoder<'_>) -> anyhow::Result<ExchangeInfo> {
+    let mut decoder = decoder.rate_limits_decoder()?;
     let count = decoder.count();
-    let mut rate_limits = Vec::with_capacity(count.try_into()?);
+    let mut rate_limits = Vec::new();
     for _ in 0..count {
         decoder.advance()?;
         let rate_limit = RateLimit {
@@ -302,22 +262,21 @@ fn main() -> anyhow::Result<()> {
     }
     let decoder = decoder.parent()?;
 
-    let mut decoder = decoder.exchange_filters_decoder();
+    let mut decoder = decoder.exchange_filters_decoder()?;
     let count = decoder.count();
-    let mut exchange_filters = Vec::with_capacity(count.try_into()?);
+    let mut exchange_filters = Vec::new();
     for _ in 0..count {
         decoder.advance()?;
-        let coordinates = decoder.filter_decoder();
-        let slice = decoder.filter_slice(coordinates);
-        let decoder = MessageHeaderDecoder::default().wrap(ReadBuf::new(slice), 0);
-        let filter = decode_exchange_filter(decoder)?;
+        let coordinates = decoder.filter_decoder()?;
+        let slice = decoder.filter_slice(coordinates)?;
+        let filter = decode_exchange_filter(decode_any(slice)?)?;
         exchange_filters.push(filter);
     }
     let decoder = decoder.parent()?;
 
-    let mut decoder = decoder.symbols_decoder();
+    let mut decoder = decoder.symbols_decoder()?;
     let count = decoder.count().try_into()?;
-    let mut symbols = Vec::with_capacity(count);
+    let mut symbols = Vec::new();
     for _ in 0..count {
         decoder.advance()?;
         symbols.push(SymbolInfo {
@@ -337,30 +296,29 @@ fn main() -> anyhow::Result<()> {
             default_self_trade_prevention_mode: decoder.default_self_trade_prevention_mode(),
             allowed_self_trade_prevention_modes: decoder.allowed_self_trade_prevention_modes(),
             filters: {
-                let mut filters_decoder = decoder.filters_decoder();
+                let mut filters_decoder = decoder.filters_decoder()?;
                 let count = filters_decoder.count().try_into()?;
-                let mut filters = Vec::with_capacity(count);
+                let mut filters = Vec::new();
                 for _ in 0..count {
                     filters_decoder.advance()?;
-                    let coordinates = filters_decoder.filter_decoder();
-                    let slice = filters_decoder.filter_slice(coordinates);
-                    let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(slice), 0);
-                    let filter = decode_symbol_filter(header)?;
+                    let coordinates = filters_decoder.filter_decoder()?;
+                    let slice = filters_decoder.filter_slice(coordinates)?;
+                    let filter = decode_symbol_filter(decode_any(slice)?)?;
                     filters.push(filter);
                 }
                 decoder = filters_decoder.parent()?;
                 filters
             },
             permissions: {
-                let permission_sets_decoder = decoder.permission_sets_decoder();
+                let permission_sets_decoder = decoder.permission_sets_decoder()?;
 
-                let mut permissions_decoder = permission_sets_decoder.permissions_decoder();
+                let mut permissions_decoder = permission_sets_decoder.permissions_decoder()?;
                 let count = permissions_decoder.count().try_into()?;
-                let mut permissions = Vec::with_capacity(count);
+                let mut permissions = Vec::new();
                 for _ in 0..count {
                     permissions_decoder.advance()?;
-                    let coordinates = permissions_decoder.permission_decoder();
-                    let slice = permissions_decoder.permission_slice(coordinates);
+                    let coordinates = permissions_decoder.permission_decoder()?;
+                    let slice = permissions_decoder.permission_slice(coordinates)?;
                     permissions.push(String::from_utf8(slice.into())?);
                 }
 
@@ -369,41 +327,41 @@ fn main() -> anyhow::Result<()> {
                 permissions
             },
             symbol: {
-                let coordinates = decoder.symbol_decoder();
-                let slice = decoder.symbol_slice(coordinates);
+                let coordinates = decoder.symbol_decoder()?;
+                let slice = decoder.symbol_slice(coordinates)?;
                 String::from_utf8(slice.into())?
             },
             base_asset: {
-                let coordinates = decoder.base_asset_decoder();
-                let slice = decoder.base_asset_slice(coordinates);
+                let coordinates = decoder.base_asset_decoder()?;
+                let slice = decoder.base_asset_slice(coordinates)?;
                 String::from_utf8(slice.into())?
             },
             quote_asset: {
-                let coordinates = decoder.quote_asset_decoder();
-                let slice = decoder.quote_asset_slice(coordinates);
+                let coordinates = decoder.quote_asset_decoder()?;
+                let slice = decoder.quote_asset_slice(coordinates)?;
                 String::from_utf8(slice.into())?
             },
         });
     }
     let decoder = decoder.parent()?;
 
-    let mut decoder = decoder.sors_decoder();
+    let mut decoder = decoder.sors_decoder()?;
     let count = decoder.count().try_into()?;
-    let mut sors = Vec::with_capacity(count);
+    let mut sors = Vec::new();
     for _ in 0..count {
         decoder.advance()?;
-        let mut symbols_decoder = decoder.sor_symbols_decoder();
+        let mut symbols_decoder = decoder.sor_symbols_decoder()?;
         let count = symbols_decoder.count().try_into()?;
-        let mut symbols = Vec::with_capacity(count);
+        let mut symbols = Vec::new();
         for _ in 0..count {
             symbols_decoder.advance()?;
-            let coordinates = symbols_decoder.symbol_decoder();
-            let slice = symbols_decoder.symbol_slice(coordinates);
+            let coordinates = symbols_decoder.symbol_decoder()?;
+            let slice = symbols_decoder.symbol_slice(coordinates)?;
             symbols.push(String::from_utf8(slice.into())?);
         }
         decoder = symbols_decoder.parent()?;
-        let coordinates = decoder.base_asset_decoder();
-        let slice = decoder.base_asset_slice(coordinates);
+        let coordinates = decoder.base_asset_decoder()?;
+        let slice = decoder.base_asset_slice(coordinates)?;
         let base_asset = String::from_utf8(slice.into())?;
         sors.push(Sor {
             symbols,
@@ -416,12 +374,303 @@ fn main() -> anyhow::Result<()> {
         symbols,
         sors,
     };
-    let yaml = if let Some(websocket_meta) = websocket_meta.as_mut() {
-        websocket_meta.set_exchange_info(response);
-        serde_yaml::to_string(&websocket_meta)?
+    Ok(response)
+}
+
+/// Reads a message's JSON from stdin and writes its SBE encoding to stdout.
+fn encode(mut args: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    let (Some(template), None) = (args.next(), args.next()) else {
+        bail!("Usage: sbe-sample-app encode <TemplateName>");
+    };
+    let json = String::from_utf8(read_payload(io::stdin())?)?;
+    let payload = spot_sbe::json::from_json_str(&template, &json)?;
+    io::stdout().write_all(&payload)?;
+    Ok(())
+}
+
+/// An order to validate, with the account and market state its filters need
+#[derive(Deserialize)]
+struct ValidateRequest {
+    #[serde(flatten)]
+    order: Order,
+    #[serde(default)]
+    market: MarketState,
+}
+
+#[derive(Serialize)]
+struct ValidateReport {
+    order: Order,
+    violations: Vec<Violation>,
+}
+
+/// Reads an order's JSON from stdin and checks it against its symbol in an
+/// exchangeInfo response, REST or WebSocket. Exits with 1 if it breaks any rule.
+fn validate(args: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    const USAGE: &str = "Usage: sbe-sample-app validate [--round] [--json] <exchangeInfo.sbe>";
+    let (mut round, mut format, mut path) = (false, OutputFormat::Yaml, None);
+    for arg in args {
+        match arg.as_str() {
+            "--round" => round = true,
+            "--json" => format = OutputFormat::Json,
+            _ if arg.starts_with('-') || path.is_some() => bail!(USAGE),
+            _ => path = Some(arg),
+        }
+    }
+    let Some(path) = path else {
+        bail!(USAGE);
+    };
+    let payload = std::fs::read(&path).with_context(|| format!("Failed to read {path}"))?;
+    let exchange_info = load_exchange_info(&payload)?;
+    let request: ValidateRequest = serde_json::from_slice(&read_payload(io::stdin())?)?;
+    let Some(symbol) = exchange_info
+        .symbols
+        .iter()
+        .find(|symbol| symbol.symbol == request.order.symbol)
+    else {
+        bail!("Symbol {} not found in {path}", request.order.symbol);
+    };
+    let order = if round {
+        validate::round(&request.order, symbol)
     } else {
-        serde_yaml::to_string(&response)?
+        request.order
     };
-    println!("{}", yaml);
+    let violations = validate::validate(&order, symbol, &request.market);
+    let failed = !violations.is_empty();
+    let report = ValidateReport { order, violations };
+    match format {
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&report)?),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+    }
+    if failed {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Decodes an exchangeInfo response, unwrapping it from a WebSocket response.
+fn load_exchange_info(payload: &[u8]) -> anyhow::Result<ExchangeInfo> {
+    let mut header = MessageHeaderDecoder::default().wrap(ReadBuf::new(payload), 0)?;
+    if header.template_id() == web_socket_response_codec::SBE_TEMPLATE_ID {
+        let mut compat = Compat::new(CompatPolicy::default(), Box::new(io::stderr()));
+        compat.check_version(&header)?;
+        let (_, offset) = decode_websocket_metadata(header, &mut compat)?;
+        header = MessageHeaderDecoder::default().wrap(ReadBuf::new(&payload[offset..]), 0)?;
+    }
+    if header.template_id() == error_response_codec::SBE_TEMPLATE_ID {
+        let response = decode_error(ErrorResponseDecoder::default().header(header)?)?;
+        bail!("Got an ErrorResponse: {}", response.msg);
+    }
+    if header.template_id() != exchange_info_response_codec::SBE_TEMPLATE_ID {
+        bail!(
+            "Expected an exchangeInfo response. Got template ID {}",
+            header.template_id()
+        );
+    }
+    decode_exchange_info(ExchangeInfoResponseDecoder::default().header(header)?)
+}
+
+fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1).peekable();
+    if args.next_if(|arg| arg == "encode").is_some() {
+        return encode(args);
+    }
+    if args.next_if(|arg| arg == "validate").is_some() {
+        return validate(args);
+    }
+    if args.next_if(|arg| arg == "record").is_some() {
+        return capture::record(args);
+    }
+    if args.next_if(|arg| arg == "replay").is_some() {
+        return capture::replay(args);
+    }
+    if args.next_if(|arg| arg == "cat").is_some() {
+        return capture::cat(args);
+    }
+    let options = parse_options(args)?;
+    let report: Box<dyn Write> = match &options.compat_report {
+        Some(path) => {
+            Box::new(File::create(path).with_context(|| format!("Failed to create {path}"))?)
+        }
+        None => Box::new(io::stderr()),
+    };
+    let mut compat = Compat::new(options.compat, report);
+    let mut failed = false;
+    let mut decode = |input: Box<dyn Read>| -> anyhow::Result<()> {
+        let (mut stdout, mut stderr) = (io::stdout().lock(), io::stderr().lock());
+        failed |= decode_stream(input, &options, &mut compat, &mut stdout, &mut stderr)?;
+        Ok(())
+    };
+    if options.paths.is_empty() {
+        decode(Box::new(io::stdin().lock()))?;
+    }
+    for path in &options.paths {
+        if path == "-" {
+            decode(Box::new(io::stdin().lock()))?;
+        } else {
+            let file = File::open(path).with_context(|| format!("Failed to open {path}"))?;
+            decode(Box::new(BufReader::new(file)))?;
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
     Ok(())
 }
+
+/// Decodes every frame of `input`, writing documents to `out` and error
+/// responses and messages rejected by the compatibility policy to `errors`.
+/// A rejected message is skipped. Returns whether there were any errors.
+fn decode_stream(
+    input: Box<dyn Read>,
+    options: &Options,
+    compat: &mut Compat,
+    out: &mut dyn Write,
+    errors: &mut dyn Write,
+) -> anyhow::Result<bool> {
+    let mut failed = false;
+    let mut frames = FrameReader::new(input, options.framing);
+    while let Some(payload) = frames.next_frame()? {
+        let document = match render(payload, options.format, compat) {
+            Ok(document) => document,
+            Err(err) if err.is::<Rejected>() => {
+                writeln!(errors, "Error: {err}")?;
+                failed = true;
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+        if document.is_error {
+            writeln!(errors, "Error: {}", document.text)?;
+            failed = true;
+        } else {
+            writeln!(out, "{}", document.text)?;
+        }
+    }
+    Ok(failed)
+}
+
+/// A message rendered in the requested output format
+struct Document {
+    text: String,
+    /// The message is, or wraps, an ErrorResponse
+    is_error: bool,
+}
+
+impl Document {
+    fn ok(text: String) -> Self {
+        Self {
+            text,
+            is_error: false,
+        }
+    }
+
+    fn error(text: String) -> Self {
+        Self {
+            text,
+            is_error: true,
+        }
+    }
+}
+
+fn render(payload: &[u8], format: OutputFormat, compat: &mut Compat) -> anyhow::Result<Document> {
+    let decoder = MessageHeaderDecoder::default().wrap(ReadBuf::new(payload), 0)?;
+    // Both formats pick the codecs from the header's schema ID and version, so
+    // a schema without codecs is reported alike whichever is asked for.
+    let message = decode_versioned(payload)?;
+    // A separate "ErrorResponse" message is returned for errors and its format
+    // is expected to be backwards compatible across all schema versions.
+    if message.template_id() == error_response_codec::SBE_TEMPLATE_ID {
+        if format == OutputFormat::Json {
+            let json = spot_sbe::json::to_json_string_pretty(payload)?;
+            return Ok(Document::error(json));
+        }
+        let response = decode_message(decoder)?;
+        let yaml = serde_yaml::to_string(&response)?;
+        return Ok(Document::error(yaml));
+    }
+    compat.check_version(&decoder)?;
+    if decoder.template_id() == web_socket_response_codec::SBE_TEMPLATE_ID {
+        let (mut websocket_meta, offset) = decode_websocket_metadata(decoder, compat)?;
+        let decoder = MessageHeaderDecoder::default().wrap(ReadBuf::new(&payload[offset..]), 0)?;
+        if decoder.template_id() != error_response_codec::SBE_TEMPLATE_ID {
+            compat.check_version(&decoder)?;
+        }
+        if format == OutputFormat::Json {
+            let is_error = decoder.template_id() == error_response_codec::SBE_TEMPLATE_ID;
+            let json = spot_sbe::json::to_json_string_pretty(payload)?;
+            return Ok(if is_error {
+                Document::error(json)
+            } else {
+                Document::ok(json)
+            });
+        }
+        let result = decode_message(decoder)?;
+        let is_error = matches!(result, Message::Error(_));
+        websocket_meta.set_result(result);
+        let yaml = serde_yaml::to_string(&websocket_meta)?;
+        return Ok(if is_error {
+            Document::error(yaml)
+        } else {
+            Document::ok(yaml)
+        });
+    }
+    if format == OutputFormat::Json {
+        let json = spot_sbe::json::to_json_string_pretty(payload)?;
+        return Ok(Document::ok(json));
+    }
+    let response = decode_message(decoder)?;
+    let yaml = serde_yaml::to_string(&response)?;
+    Ok(Document::ok(yaml))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ping(version: u16) -> Vec<u8> {
+        [0, 101, 2, version].map(u16::to_le_bytes).concat()
+    }
+
+    #[test]
+    fn schema_without_codecs_is_an_error_in_both_formats() {
+        let mut compat = Compat::new(CompatPolicy::default(), Box::new(io::sink()));
+        for template_id in [101, error_response_codec::SBE_TEMPLATE_ID] {
+            let frame = [0, template_id, 1, 0].map(u16::to_le_bytes).concat();
+            let yaml = render(&frame, OutputFormat::Yaml, &mut compat)
+                .err()
+                .unwrap();
+            let json = render(&frame, OutputFormat::Json, &mut compat)
+                .err()
+                .unwrap();
+            assert_eq!(yaml.to_string(), "unknown schema 1:0");
+            assert_eq!(json.to_string(), yaml.to_string());
+        }
+    }
+
+    #[test]
+    fn rejected_messages_are_skipped() {
+        let options =
+            parse_options(["--compat".to_string(), "strict".to_string()].into_iter()).unwrap();
+        let mut compat = Compat::new(options.compat, Box::new(io::sink()));
+        let input = [ping(0), ping(1), ping(0), ping(1)].concat();
+        let (mut out, mut errors) = (Vec::new(), Vec::new());
+        let failed = decode_stream(
+            Box::new(io::Cursor::new(input)),
+            &options,
+            &mut compat,
+            &mut out,
+            &mut errors,
+        )
+        .unwrap();
+        assert!(failed);
+        let out = String::from_utf8(out).unwrap();
+        let errors = String::from_utf8(errors).unwrap();
+        // a ping response renders as an empty mapping
+        assert_eq!(out.matches("{}").count(), 2, "{out}");
+        assert_eq!(
+            errors.matches("rejected by the strict policy").count(),
+            2,
+            "{errors}"
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    decode_error, decode_exchange_info,
+    exchange_info::{ErrorResponse, ExchangeInfo},
+    trading::{
+        decode_cancel_open_orders, decode_cancel_replace_order, CancelOpenOrders,
+        CancelReplaceOrder,
+    },
+};
+use anyhow::bail;
+use serde::Serialize;
+use spot_sbe::{AnyMessage, MessageHeaderDecoder, OwnedAnyMessage, ReadBuf, VersionedMessage};
+
+/// A decoded message. Most are the owned structs from `spot_sbe`; the ones
+/// carrying other messages or filters as var data keep their own types so
+/// those are decoded too rather than shown as bytes.
+#[allow(clippy::large_enum_variant)]
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum Message {
+    Error(ErrorResponse),
+    ExchangeInfo(ExchangeInfo),
+    CancelOpenOrders(CancelOpenOrders),
+    CancelReplaceOrder(CancelReplaceOrder),
+    Owned(OwnedAnyMessage),
+}
+
+impl Message {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Message::Error(_) => "Error",
+            Message::ExchangeInfo(_) => "ExchangeInfo",
+            Message::CancelOpenOrders(_) => "CancelOpenOrders",
+            Message::CancelReplaceOrder(_) => "CancelReplaceOrder",
+            Message::Owned(message) => message.name(),
+        }
+    }
+}
+
+pub fn decode_message(header: MessageHeaderDecoder<ReadBuf<'_>>) -> anyhow::Result<Message> {
+    let message = match VersionedMessage::from_header(header)? {
+        VersionedMessage::V2_1(message) => message,
+        message => {
+            let schema = message.schema();
+            bail!("No decoder for schema {}:{}", schema.id, schema.version);
+        }
+    };
+    Ok(match message {
+        AnyMessage::ErrorResponse(decoder) => Message::Error(decode_error(decoder)?),
+        AnyMessage::ExchangeInfoResponse(decoder) => {
+            Message::ExchangeInfo(decode_exchange_info(decoder)?)
+        }
+        AnyMessage::CancelOpenOrdersResponse(decoder) => {
+            Message::CancelOpenOrders(decode_cancel_open_orders(decoder)?)
+        }
+        AnyMessage::CancelReplaceOrderResponse(decoder) => {
+            Message::CancelReplaceOrder(decode_cancel_replace_order(decoder)?)
+        }
+        message => Message::Owned(message.to_owned_message()?),
+    })
+}
+
+pub fn decode_message_data(slice: &[u8]) -> anyhow::Result<Message> {
+    let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(slice), 0)?;
+    decode_message(header)
+}
+
+pub fn decode_optional_message_data(slice: &[u8]) -> anyhow::Result<Option<Box<Message>>> {
+    if slice.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Box::new(decode_message_data(slice)?)))
+}
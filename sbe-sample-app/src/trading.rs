@@ -0,0 +1,387 @@
+use crate::{
+    enums::{serialize_cancel_replace_status, serialize_list_order_status, serialize_order_status},
+    message::{decode_message_data, decode_optional_message_data, Message},
+};
+use serde::Serialize;
+use spot_sbe::cancel_open_orders::{self, CanceledIter};
+use spot_sbe::cancel_replace::{CancelOutcome, CancelReplaceResponse, ErrorReply, NewOrderOutcome};
+use spot_sbe::{
+    CancelOpenOrdersResponseDecoder, CancelReplaceOrderResponseDecoder, CancelReplaceStatus,
+    ListOrderStatus, OrderStatus,
+};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOpenOrders {
+    pub responses: Vec<Message>,
+    /// What each response canceled, with its final status
+    pub canceled: Vec<Canceled>,
+}
+
+#[derive(Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "SCREAMING_SNAKE_CASE",
+    rename_all_fields = "camelCase"
+)]
+pub enum Canceled {
+    Order(CanceledOrder),
+    OrderList {
+        symbol: String,
+        order_list_id: i64,
+        list_client_order_id: String,
+        #[serde(serialize_with = "serialize_list_order_status")]
+        list_order_status: ListOrderStatus,
+        orders: Vec<CanceledOrder>,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CanceledOrder {
+    pub symbol: String,
+    pub order_id: i64,
+    /// The `clientOrderId` the order was placed with
+    pub client_order_id: String,
+    #[serde(serialize_with = "serialize_order_status")]
+    pub status: OrderStatus,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceOrder {
+    #[serde(serialize_with = "serialize_cancel_replace_status")]
+    pub cancel_result: CancelReplaceStatus,
+    #[serde(serialize_with = "serialize_cancel_replace_status")]
+    pub new_order_result: CancelReplaceStatus,
+    pub cancel_response: Option<Box<Message>>,
+    pub new_order_response: Option<Box<Message>>,
+    pub outcome: CancelReplaceOutcome,
+}
+
+/// What became of both halves of a cancel-replace, e.g. `summary: order 12
+/// canceled, new order 13 placed`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceOutcome {
+    pub summary: String,
+    pub cancel: OrderOutcome,
+    pub new_order: OrderOutcome,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderOutcome {
+    #[serde(serialize_with = "serialize_cancel_replace_status")]
+    pub result: CancelReplaceStatus,
+    pub order_id: Option<i64>,
+    /// Null for a new order placed with `newOrderRespType=ACK`
+    #[serde(serialize_with = "serialize_order_status")]
+    pub status: OrderStatus,
+    pub error_code: Option<i16>,
+    pub error_msg: Option<String>,
+}
+
+pub fn decode_cancel_open_orders(
+    decoder: CancelOpenOrdersResponseDecoder<'_>,
+) -> anyhow::Result<CancelOpenOrders> {
+    let response = CancelOpenOrders {
+        responses: {
+            let mut responses_decoder = decoder.responses_decoder()?;
+            let count = responses_decoder.count().try_into()?;
+            let mut responses = Vec::new();
+            for _ in 0..count {
+                responses_decoder.advance()?;
+                let coordinates = responses_decoder.response_decoder()?;
+                let slice = responses_decoder.response_slice(coordinates)?;
+                responses.push(decode_message_data(slice)?);
+            }
+            responses
+        },
+        canceled: CanceledIter::from_decoder(decoder)?
+            .map(|canceled| decode_canceled(canceled?))
+            .collect::<anyhow::Result<_>>()?,
+    };
+    Ok(response)
+}
+
+fn decode_canceled(canceled: cancel_open_orders::Canceled<'_>) -> anyhow::Result<Canceled> {
+    Ok(match canceled {
+        cancel_open_orders::Canceled::Order(mut decoder) => Canceled::Order(CanceledOrder {
+            order_id: decoder.order_id(),
+            status: decoder.status(),
+            symbol: {
+                let coordinates = decoder.symbol_decoder()?;
+                String::from_utf8(decoder.symbol_slice(coordinates)?.into())?
+            },
+            client_order_id: {
+                let coordinates = decoder.orig_client_order_id_decoder()?;
+                String::from_utf8(decoder.orig_client_order_id_slice(coordinates)?.into())?
+            },
+        }),
+        cancel_open_orders::Canceled::OrderList(decoder) => {
+            let order_list_id = decoder.order_list_id();
+            let list_order_status = decoder.list_order_status();
+            // The reports carry each order's status; the var data of
+            // `orders` still has to be read to reach them.
+            let mut orders_decoder = decoder.orders_decoder()?;
+            while orders_decoder.advance()?.is_some() {
+                orders_decoder.symbol_decoder()?;
+                orders_decoder.client_order_id_decoder()?;
+            }
+            let mut order_reports_decoder = orders_decoder.parent()?.order_reports_decoder()?;
+            let mut orders = Vec::new();
+            while order_reports_decoder.advance()?.is_some() {
+                orders.push(CanceledOrder {
+                    order_id: order_reports_decoder.order_id(),
+                    status: order_reports_decoder.status(),
+                    symbol: {
+                        let coordinates = order_reports_decoder.symbol_decoder()?;
+                        let slice = order_reports_decoder.symbol_slice(coordinates)?;
+                        String::from_utf8(slice.into())?
+                    },
+                    client_order_id: {
+                        let coordinates = order_reports_decoder.orig_client_order_id_decoder()?;
+                        let slice =
+                            order_reports_decoder.orig_client_order_id_slice(coordinates)?;
+                        String::from_utf8(slice.into())?
+                    },
+                });
+                order_reports_decoder.client_order_id_decoder()?;
+            }
+            let mut decoder = order_reports_decoder.parent()?;
+            let coordinates = decoder.list_client_order_id_decoder()?;
+            let list_client_order_id =
+                String::from_utf8(decoder.list_client_order_id_slice(coordinates)?.into())?;
+            let coordinates = decoder.symbol_decoder()?;
+            let symbol = String::from_utf8(decoder.symbol_slice(coordinates)?.into())?;
+            Canceled::OrderList {
+                symbol,
+                order_list_id,
+                list_client_order_id,
+                list_order_status,
+                orders,
+            }
+        }
+    })
+}
+
+pub fn decode_cancel_replace_order(
+    mut decoder: CancelReplaceOrderResponseDecoder<'_>,
+) -> anyhow::Result<CancelReplaceOrder> {
+    let outcome = decode_cancel_replace_outcome(CancelReplaceResponse::from_decoder(decoder)?)?;
+    let response = CancelReplaceOrder {
+        cancel_result: decoder.cancel_result(),
+        new_order_result: decoder.new_order_result(),
+        cancel_response: {
+            let coordinates = decoder.cancel_response_decoder()?;
+            let slice = decoder.cancel_response_slice(coordinates)?;
+            decode_optional_message_data(slice)?
+        },
+        new_order_response: {
+            let coordinates = decoder.new_order_response_decoder()?;
+            let slice = decoder.new_order_response_slice(coordinates)?;
+            decode_optional_message_data(slice)?
+        },
+        outcome,
+    };
+    Ok(response)
+}
+
+fn decode_cancel_replace_outcome(
+    response: CancelReplaceResponse<'_>,
+) -> anyhow::Result<CancelReplaceOutcome> {
+    let mut cancel = OrderOutcome::new(response.cancel_result);
+    match response.cancel {
+        CancelOutcome::Success(Some(decoder)) => {
+            cancel.order_id = Some(decoder.order_id());
+            cancel.status = decoder.status();
+        }
+        CancelOutcome::Failure(Some(error)) => cancel.set_error(&error)?,
+        CancelOutcome::Success(None) | CancelOutcome::Failure(None) => {}
+        CancelOutcome::NotAttempted => {}
+    }
+    let mut new_order = OrderOutcome::new(response.new_order_result);
+    match response.new_order {
+        NewOrderOutcome::Success(Some(placed)) => {
+            new_order.order_id = Some(placed.order_id());
+            new_order.status = placed.status().unwrap_or(OrderStatus::NullVal);
+        }
+        NewOrderOutcome::Failure(Some(error)) => new_order.set_error(&error)?,
+        NewOrderOutcome::Success(None) | NewOrderOutcome::Failure(None) => {}
+        NewOrderOutcome::NotAttempted => {}
+    }
+    let order = |order_id: Option<i64>| match order_id {
+        Some(order_id) => format!("order {order_id}"),
+        None => "order".into(),
+    };
+    let summary = format!(
+        "{} {}, new {} {}",
+        order(cancel.order_id),
+        match cancel.result {
+            CancelReplaceStatus::Success => "canceled",
+            CancelReplaceStatus::Failure => "cancel failed",
+            _ => "cancel not attempted",
+        },
+        order(new_order.order_id),
+        match new_order.result {
+            CancelReplaceStatus::Success => "placed",
+            CancelReplaceStatus::Failure => "failed",
+            _ => "not attempted",
+        },
+    );
+    Ok(CancelReplaceOutcome {
+        summary,
+        cancel,
+        new_order,
+    })
+}
+
+impl OrderOutcome {
+    fn new(result: CancelReplaceStatus) -> Self {
+        Self {
+            result,
+            order_id: None,
+            status: OrderStatus::NullVal,
+            error_code: None,
+            error_msg: None,
+        }
+    }
+
+    fn set_error(&mut self, error: &ErrorReply<'_>) -> anyhow::Result<()> {
+        self.error_code = Some(error.decoder.code());
+        self.error_msg = Some(error.msg()?.to_owned());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use spot_sbe::cancel_open_orders_response_codec::owned as cancel_open_orders;
+    use spot_sbe::cancel_order_list_response_codec::owned as cancel_order_list;
+    use spot_sbe::cancel_order_response_codec::owned::CancelOrderResponse;
+    use spot_sbe::OwnedMessage;
+    use spot_sbe::{
+        ContingencyType, ListStatusType, MessageHeaderDecoder, OrderSide, OrderType, ReadBuf,
+        SelfTradePreventionMode, TimeInForce,
+    };
+
+    fn order(order_id: i64, client_order_id: &str) -> Vec<u8> {
+        CancelOrderResponse {
+            order_id,
+            status: OrderStatus::Canceled,
+            time_in_force: TimeInForce::Gtc,
+            order_type: OrderType::Limit,
+            side: OrderSide::Buy,
+            self_trade_prevention_mode: SelfTradePreventionMode::None,
+            symbol: "BTCUSDT".into(),
+            orig_client_order_id: client_order_id.into(),
+            client_order_id: "cancel".into(),
+            ..Default::default()
+        }
+        .encode()
+        .unwrap()
+    }
+
+    fn order_list(order_list_id: i64, order_ids: [i64; 2]) -> Vec<u8> {
+        cancel_order_list::CancelOrderListResponse {
+            order_list_id,
+            contingency_type: ContingencyType::Oco,
+            list_status_type: ListStatusType::AllDone,
+            list_order_status: ListOrderStatus::AllDone,
+            orders: order_ids
+                .map(|order_id| cancel_order_list::Orders {
+                    order_id,
+                    symbol: "BTCUSDT".into(),
+                    client_order_id: format!("leg-{order_id}"),
+                })
+                .into(),
+            order_reports: order_ids
+                .map(|order_id| cancel_order_list::OrderReports {
+                    order_id,
+                    order_list_id: Some(order_list_id),
+                    status: OrderStatus::Canceled,
+                    time_in_force: TimeInForce::Gtc,
+                    order_type: OrderType::LimitMaker,
+                    side: OrderSide::Sell,
+                    self_trade_prevention_mode: SelfTradePreventionMode::None,
+                    symbol: "BTCUSDT".into(),
+                    orig_client_order_id: format!("leg-{order_id}"),
+                    client_order_id: "cancel".into(),
+                    ..Default::default()
+                })
+                .into(),
+            list_client_order_id: "list".into(),
+            symbol: "BTCUSDT".into(),
+            ..Default::default()
+        }
+        .encode()
+        .unwrap()
+    }
+
+    #[test]
+    fn cancel_open_orders_lists_what_was_canceled() {
+        let frame = cancel_open_orders::CancelOpenOrdersResponse {
+            responses: [order(1, "first"), order_list(7, [2, 3])]
+                .map(|response| cancel_open_orders::Responses { response })
+                .into(),
+        }
+        .encode()
+        .unwrap();
+        let header = MessageHeaderDecoder::default()
+            .wrap(ReadBuf::new(&frame), 0)
+            .unwrap();
+        let decoder = CancelOpenOrdersResponseDecoder::default()
+            .header(header)
+            .unwrap();
+        let response = decode_cancel_open_orders(decoder).unwrap();
+        assert_eq!(response.responses.len(), 2);
+        let canceled = serde_json::to_value(&response.canceled).unwrap();
+        let report = |order_id: i64, client_order_id: &str| {
+            json!({
+                "symbol": "BTCUSDT",
+                "orderId": order_id,
+                "clientOrderId": client_order_id,
+                "status": "CANCELED",
+            })
+        };
+        let mut first = report(1, "first");
+        first["type"] = json!("ORDER");
+        assert_eq!(
+            canceled,
+            json!([
+                first,
+                {
+                    "type": "ORDER_LIST",
+                    "symbol": "BTCUSDT",
+                    "orderListId": 7,
+                    "listClientOrderId": "list",
+                    "listOrderStatus": "ALL_DONE",
+                    "orders": [report(2, "leg-2"), report(3, "leg-3")],
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn cancel_open_orders_with_an_unexpected_entry_is_an_error() {
+        let frame = cancel_open_orders::CancelOpenOrdersResponse {
+            responses: vec![cancel_open_orders::Responses {
+                response: spot_sbe::ping_response_codec::owned::PingResponse {}
+                    .encode()
+                    .unwrap(),
+            }],
+        }
+        .encode()
+        .unwrap();
+        let header = MessageHeaderDecoder::default()
+            .wrap(ReadBuf::new(&frame), 0)
+            .unwrap();
+        let decoder = CancelOpenOrdersResponseDecoder::default()
+            .header(header)
+            .unwrap();
+        assert!(decode_cancel_open_orders(decoder).is_err());
+    }
+}
@@ -0,0 +1,1058 @@
+//! Pre-trade validation of an order against a symbol's `exchangeInfo`: its
+//! filters, and the order types, iceberg, OCO, trailing stop and
+//! self-trade prevention settings of the symbol.
+
+use crate::enums::{
+    deserialize_order_side, deserialize_order_type, deserialize_time_in_force,
+    serialize_order_side, serialize_order_type, serialize_time_in_force,
+};
+use crate::exchange_info::{
+    deserialize_self_trade_prevention_mode, serialize_self_trade_prevention_mode,
+    serialize_symbol_status, SymbolFilter, SymbolInfo,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::value::Serializer as Value;
+use spot_sbe::{
+    Decimal, OrderSide, OrderType, Rounding, SelfTradePreventionMode, SymbolStatus, TimeInForce,
+};
+use std::fmt::Display;
+
+/// A proposed order, with the parameters of the `order.place` request.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub symbol: String,
+    #[serde(
+        serialize_with = "serialize_order_side",
+        deserialize_with = "deserialize_order_side"
+    )]
+    pub side: OrderSide,
+    #[serde(
+        rename = "type",
+        serialize_with = "serialize_order_type",
+        deserialize_with = "deserialize_order_type"
+    )]
+    pub order_type: OrderType,
+    #[serde(
+        default,
+        serialize_with = "serialize_time_in_force",
+        deserialize_with = "deserialize_time_in_force"
+    )]
+    pub time_in_force: TimeInForce,
+    pub price: Option<Decimal>,
+    #[serde(rename = "quantity")]
+    pub qty: Option<Decimal>,
+    pub quote_order_qty: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
+    pub iceberg_qty: Option<Decimal>,
+    pub trailing_delta: Option<i64>,
+    #[serde(
+        default,
+        serialize_with = "serialize_self_trade_prevention_mode",
+        deserialize_with = "deserialize_self_trade_prevention_mode"
+    )]
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+    /// The order is a leg of an OCO order list
+    #[serde(default)]
+    pub oco: bool,
+}
+
+impl Order {
+    /// Orders that execute at the market price, immediately or once triggered
+    fn is_market(&self) -> bool {
+        matches!(
+            self.order_type,
+            OrderType::Market | OrderType::StopLoss | OrderType::TakeProfit
+        )
+    }
+
+    fn is_algo(&self) -> bool {
+        matches!(
+            self.order_type,
+            OrderType::StopLoss
+                | OrderType::StopLossLimit
+                | OrderType::TakeProfit
+                | OrderType::TakeProfitLimit
+        )
+    }
+}
+
+/// Account and market state that some filters depend on. A filter whose
+/// input is unknown is not checked.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketState {
+    /// Average price, as returned by `avgPrice`. Used for every filter's
+    /// `avgPriceMins`, so it should cover the longest of them.
+    pub avg_price: Option<Decimal>,
+    pub open_orders: Option<i64>,
+    pub open_algo_orders: Option<i64>,
+    pub open_iceberg_orders: Option<i64>,
+    /// Balance of the base asset plus the quantity of open BUY orders
+    pub position: Option<Decimal>,
+}
+
+/// A rule the order breaks.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Violation {
+    /// `filterType` of the filter, or the `SymbolInfo` or order field checked
+    pub filter: &'static str,
+    /// The rule that failed, in terms of the order's and filter's field names
+    pub rule: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<String>,
+}
+
+struct Violations(Vec<Violation>);
+
+impl Violations {
+    fn rule(&mut self, filter: &'static str, rule: impl Into<String>) {
+        self.0.push(Violation {
+            filter,
+            rule: rule.into(),
+            value: None,
+            limit: None,
+        });
+    }
+
+    fn limit(
+        &mut self,
+        filter: &'static str,
+        rule: impl Into<String>,
+        value: impl Display,
+        limit: impl Display,
+    ) {
+        self.0.push(Violation {
+            filter,
+            rule: rule.into(),
+            value: Some(value.to_string()),
+            limit: Some(limit.to_string()),
+        });
+    }
+
+    /// Checks `min <= value <= max` and that `value - min` is a multiple of
+    /// `step`. A zero `min`, `max` or `step` disables that check.
+    fn range(
+        &mut self,
+        filter: &'static str,
+        field: &str,
+        value: Decimal,
+        (min_name, min): (&str, Decimal),
+        (max_name, max): (&str, Decimal),
+        (step_name, step): (&str, Decimal),
+    ) {
+        if !min.is_zero() && value < min {
+            self.limit(filter, format!("{field} >= {min_name}"), value, min);
+        }
+        if !max.is_zero() && value > max {
+            self.limit(filter, format!("{field} <= {max_name}"), value, max);
+        }
+        if !step.is_zero() && !on_step(value, min, step) {
+            self.limit(
+                filter,
+                format!("({field} - {min_name}) % {step_name} == 0"),
+                value,
+                step,
+            );
+        }
+    }
+}
+
+/// Checks `order` against `symbol` and returns every rule it breaks, in the
+/// order the symbol's settings and filters are listed.
+pub fn validate(order: &Order, symbol: &SymbolInfo, market: &MarketState) -> Vec<Violation> {
+    let mut violations = Violations(Vec::new());
+    check_symbol(order, symbol, &mut violations);
+    check_parameters(order, &mut violations);
+    for filter in &symbol.filters {
+        check_filter(order, filter, market, &mut violations);
+    }
+    violations.0
+}
+
+fn check_symbol(order: &Order, symbol: &SymbolInfo, violations: &mut Violations) {
+    if order.symbol != symbol.symbol {
+        violations.limit("symbol", "symbol == symbol", &order.symbol, &symbol.symbol);
+    }
+    if symbol.status != SymbolStatus::Trading {
+        violations.limit(
+            "status",
+            "status == TRADING",
+            api_name(serialize_symbol_status(&symbol.status, Value)),
+            "TRADING",
+        );
+    }
+    let types = symbol.order_types;
+    let allowed = match order.order_type {
+        OrderType::Market => types.get_market(),
+        OrderType::Limit => types.get_limit(),
+        OrderType::StopLoss => types.get_stop_loss(),
+        OrderType::StopLossLimit => types.get_stop_loss_limit(),
+        OrderType::TakeProfit => types.get_take_profit(),
+        OrderType::TakeProfitLimit => types.get_take_profit_limit(),
+        OrderType::LimitMaker => types.get_limit_maker(),
+        OrderType::NullVal => false,
+    };
+    if !allowed {
+        violations.rule(
+            "orderTypes",
+            format!(
+                "orderTypes contains {}",
+                api_name(serialize_order_type(&order.order_type, Value))
+            ),
+        );
+    }
+    if order.iceberg_qty.is_some() && !symbol.iceberg_allowed {
+        violations.rule("icebergAllowed", "icebergQty requires icebergAllowed");
+    }
+    if order.oco && !symbol.oco_allowed {
+        violations.rule("ocoAllowed", "OCO order lists require ocoAllowed");
+    }
+    if order.order_type == OrderType::Market
+        && order.quote_order_qty.is_some()
+        && !symbol.quote_order_qty_market_allowed
+    {
+        violations.rule(
+            "quoteOrderQtyMarketAllowed",
+            "quoteOrderQty requires quoteOrderQtyMarketAllowed",
+        );
+    }
+    if order.trailing_delta.is_some() && !symbol.allow_trailing_stop {
+        violations.rule(
+            "allowTrailingStop",
+            "trailingDelta requires allowTrailingStop",
+        );
+    }
+    let modes = symbol.allowed_self_trade_prevention_modes;
+    let allowed = match order.self_trade_prevention_mode {
+        // The symbol's default mode applies
+        SelfTradePreventionMode::NullVal => true,
+        SelfTradePreventionMode::None => modes.get_none(),
+        SelfTradePreventionMode::ExpireTaker => modes.get_expire_taker(),
+        SelfTradePreventionMode::ExpireMaker => modes.get_expire_maker(),
+        SelfTradePreventionMode::ExpireBoth => modes.get_expire_both(),
+    };
+    if !allowed {
+        violations.rule(
+            "allowedSelfTradePreventionModes",
+            format!(
+                "allowedSelfTradePreventionModes contains {}",
+                api_name(serialize_self_trade_prevention_mode(
+                    &order.self_trade_prevention_mode,
+                    Value
+                ))
+            ),
+        );
+    }
+}
+
+/// Checks the parameters each order type requires.
+fn check_parameters(order: &Order, violations: &mut Violations) {
+    let order_type = order.order_type;
+    let type_name = api_name(serialize_order_type(&order_type, Value));
+    let mut require = |present: bool, name: &str| {
+        if !present {
+            violations.rule("type", format!("{type_name} requires {name}"));
+        }
+    };
+    let has_trigger = order.stop_price.is_some() || order.trailing_delta.is_some();
+    match order_type {
+        OrderType::Market => require(
+            order.qty.is_some() || order.quote_order_qty.is_some(),
+            "quantity or quoteOrderQty",
+        ),
+        OrderType::Limit | OrderType::StopLossLimit | OrderType::TakeProfitLimit => {
+            require(order.time_in_force != TimeInForce::NullVal, "timeInForce");
+            require(order.price.is_some(), "price");
+            require(order.qty.is_some(), "quantity");
+            if order_type != OrderType::Limit {
+                require(has_trigger, "stopPrice or trailingDelta");
+            }
+        }
+        OrderType::StopLoss | OrderType::TakeProfit => {
+            require(order.qty.is_some(), "quantity");
+            require(has_trigger, "stopPrice or trailingDelta");
+        }
+        OrderType::LimitMaker => {
+            require(order.price.is_some(), "price");
+            require(order.qty.is_some(), "quantity");
+        }
+        OrderType::NullVal => require(false, "a known type"),
+    }
+}
+
+fn check_filter(
+    order: &Order,
+    filter: &SymbolFilter,
+    market: &MarketState,
+    violations: &mut Violations,
+) {
+    let prices = [("price", order.price), ("stopPrice", order.stop_price)];
+    match *filter {
+        SymbolFilter::Price {
+            min_price,
+            max_price,
+            tick_size,
+        } => {
+            for (field, price) in prices {
+                let Some(price) = price else { continue };
+                violations.range(
+                    "PRICE_FILTER",
+                    field,
+                    price,
+                    ("minPrice", min_price),
+                    ("maxPrice", max_price),
+                    ("tickSize", tick_size),
+                );
+            }
+        }
+        SymbolFilter::PercentPrice {
+            multiplier_up,
+            multiplier_down,
+            ..
+        } => {
+            if let (Some(price), Some(avg_price)) = (order.price, market.avg_price) {
+                check_percent_price(
+                    violations,
+                    "PERCENT_PRICE",
+                    price,
+                    avg_price,
+                    ("multiplierUp", multiplier_up),
+                    ("multiplierDown", multiplier_down),
+                );
+            }
+        }
+        SymbolFilter::PercentPriceBySide {
+            bid_multiplier_up,
+            bid_multiplier_down,
+            ask_multiplier_up,
+            ask_multiplier_down,
+            ..
+        } => {
+            let (up, down) = match order.side {
+                OrderSide::Buy => (
+                    ("bidMultiplierUp", bid_multiplier_up),
+                    ("bidMultiplierDown", bid_multiplier_down),
+                ),
+                OrderSide::Sell | OrderSide::NullVal => (
+                    ("askMultiplierUp", ask_multiplier_up),
+                    ("askMultiplierDown", ask_multiplier_down),
+                ),
+            };
+            if let (Some(price), Some(avg_price)) = (order.price, market.avg_price) {
+                check_percent_price(
+                    violations,
+                    "PERCENT_PRICE_BY_SIDE",
+                    price,
+                    avg_price,
+                    up,
+                    down,
+                );
+            }
+        }
+        SymbolFilter::LotSize {
+            min_qty,
+            max_qty,
+            step_size,
+        } => {
+            if let Some(qty) = order.qty {
+                violations.range(
+                    "LOT_SIZE",
+                    "quantity",
+                    qty,
+                    ("minQty", min_qty),
+                    ("maxQty", max_qty),
+                    ("stepSize", step_size),
+                );
+            }
+        }
+        SymbolFilter::MarketLotSize {
+            min_qty,
+            max_qty,
+            step_size,
+        } => {
+            if let (true, Some(qty)) = (order.is_market(), order.qty) {
+                violations.range(
+                    "MARKET_LOT_SIZE",
+                    "quantity",
+                    qty,
+                    ("minQty", min_qty),
+                    ("maxQty", max_qty),
+                    ("stepSize", step_size),
+                );
+            }
+        }
+        SymbolFilter::MinNotional {
+            min_notional,
+            apply_to_market,
+            ..
+        } => {
+            if order.is_market() && !apply_to_market {
+                return;
+            }
+            if let Some(notional) = notional(order, market) {
+                if notional < min_notional {
+                    violations.limit(
+                        "MIN_NOTIONAL",
+                        "price * quantity >= minNotional",
+                        notional,
+                        min_notional,
+                    );
+                }
+            }
+        }
+        SymbolFilter::Notional {
+            min_notional,
+            apply_min_to_market,
+            max_notional,
+            apply_max_to_market,
+            ..
+        } => {
+            let Some(notional) = notional(order, market) else {
+                return;
+            };
+            if (!order.is_market() || apply_min_to_market) && notional < min_notional {
+                violations.limit(
+                    "NOTIONAL",
+                    "price * quantity >= minNotional",
+                    notional,
+                    min_notional,
+                );
+            }
+            if (!order.is_market() || apply_max_to_market) && notional > max_notional {
+                violations.limit(
+                    "NOTIONAL",
+                    "price * quantity <= maxNotional",
+                    notional,
+                    max_notional,
+                );
+            }
+        }
+        SymbolFilter::IcebergParts { filter_limit } => {
+            let (Some(qty), Some(iceberg_qty)) = (order.qty, order.iceberg_qty) else {
+                return;
+            };
+            if let Some(parts) = ceil_div(qty, iceberg_qty) {
+                if parts > i128::from(filter_limit) {
+                    violations.limit(
+                        "ICEBERG_PARTS",
+                        "ceil(quantity / icebergQty) <= limit",
+                        parts,
+                        filter_limit,
+                    );
+                }
+            }
+        }
+        SymbolFilter::MaxNumOrders { max_num_orders } => {
+            if let Some(open) = market.open_orders {
+                check_count(violations, "MAX_NUM_ORDERS", open, max_num_orders);
+            }
+        }
+        SymbolFilter::MaxNumAlgoOrders {
+            max_num_algo_orders,
+        } => {
+            if let (true, Some(open)) = (order.is_algo(), market.open_algo_orders) {
+                check_count(violations, "MAX_NUM_ALGO_ORDERS", open, max_num_algo_orders);
+            }
+        }
+        SymbolFilter::MaxNumIcebergOrders {
+            max_num_iceberg_orders,
+        } => {
+            if let (Some(_), Some(open)) = (order.iceberg_qty, market.open_iceberg_orders) {
+                check_count(
+                    violations,
+                    "MAX_NUM_ICEBERG_ORDERS",
+                    open,
+                    max_num_iceberg_orders,
+                );
+            }
+        }
+        SymbolFilter::MaxPosition { max_position } => {
+            if order.side != OrderSide::Buy {
+                return;
+            }
+            let (Some(position), Some(qty)) = (market.position, order.qty) else {
+                return;
+            };
+            if let Some(total) = position.checked_add(qty) {
+                if total > max_position {
+                    violations.limit(
+                        "MAX_POSITION",
+                        "position + quantity <= maxPosition",
+                        total,
+                        max_position,
+                    );
+                }
+            }
+        }
+        SymbolFilter::TrailingDelta {
+            min_trailing_above_delta,
+            max_trailing_above_delta,
+            min_trailing_below_delta,
+            max_trailing_below_delta,
+        } => {
+            let Some(delta) = order.trailing_delta else {
+                return;
+            };
+            // BUY stop-loss and SELL take-profit orders trigger above the
+            // market price, the other two below it.
+            let above = matches!(
+                (order.side, order.order_type),
+                (
+                    OrderSide::Buy,
+                    OrderType::StopLoss | OrderType::StopLossLimit
+                ) | (
+                    OrderSide::Sell,
+                    OrderType::TakeProfit | OrderType::TakeProfitLimit
+                )
+            );
+            let (min, max, min_name, max_name) = if above {
+                (
+                    min_trailing_above_delta,
+                    max_trailing_above_delta,
+                    "minTrailingAboveDelta",
+                    "maxTrailingAboveDelta",
+                )
+            } else {
+                (
+                    min_trailing_below_delta,
+                    max_trailing_below_delta,
+                    "minTrailingBelowDelta",
+                    "maxTrailingBelowDelta",
+                )
+            };
+            if delta < min {
+                violations.limit(
+                    "TRAILING_DELTA",
+                    format!("trailingDelta >= {min_name}"),
+                    delta,
+                    min,
+                );
+            }
+            if delta > max {
+                violations.limit(
+                    "TRAILING_DELTA",
+                    format!("trailingDelta <= {max_name}"),
+                    delta,
+                    max,
+                );
+            }
+        }
+        // Depends on when the assets being sold were bought, which the order
+        // does not say.
+        SymbolFilter::TPlusSell { .. } => {}
+    }
+}
+
+/// The API's name for an enum value, from its serializer.
+fn api_name(value: Result<serde_json::Value, serde_json::Error>) -> String {
+    match value {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => "null".into(),
+    }
+}
+
+fn check_percent_price(
+    violations: &mut Violations,
+    filter: &'static str,
+    price: Decimal,
+    avg_price: Decimal,
+    (up_name, up): (&str, Decimal),
+    (down_name, down): (&str, Decimal),
+) {
+    if let Some(limit) = avg_price.checked_mul(up) {
+        if price > limit {
+            violations.limit(
+                filter,
+                format!("price <= avgPrice * {up_name}"),
+                price,
+                limit.normalize(),
+            );
+        }
+    }
+    if let Some(limit) = avg_price.checked_mul(down) {
+        if price < limit {
+            violations.limit(
+                filter,
+                format!("price >= avgPrice * {down_name}"),
+                price,
+                limit.normalize(),
+            );
+        }
+    }
+}
+
+fn check_count(violations: &mut Violations, filter: &'static str, open: i64, max: i64) {
+    if open >= max {
+        violations.limit(filter, "open orders + 1 <= limit", open + 1, max);
+    }
+}
+
+/// The order's value in the quote asset: `quoteOrderQty` if given, otherwise
+/// the quantity at the order's price, or at the average price for market
+/// orders.
+fn notional(order: &Order, market: &MarketState) -> Option<Decimal> {
+    if let Some(quote_order_qty) = order.quote_order_qty {
+        return Some(quote_order_qty);
+    }
+    let price = if order.is_market() {
+        market.avg_price?
+    } else {
+        order.price?
+    };
+    Some(price.checked_mul(order.qty?)?.normalize())
+}
+
+fn on_step(value: Decimal, min: Decimal, step: Decimal) -> bool {
+    value
+        .checked_sub(min)
+        .and_then(|offset| Some(offset.round_to_tick(step, Rounding::Floor)? == offset))
+        .unwrap_or(false)
+}
+
+fn ceil_div(value: Decimal, divisor: Decimal) -> Option<i128> {
+    let exponent = value.exponent().min(divisor.exponent());
+    let value = value.rescale(exponent)?.mantissa();
+    let divisor = divisor.rescale(exponent)?.mantissa();
+    if divisor <= 0 {
+        return None;
+    }
+    value
+        .checked_add(divisor - 1)
+        .map(|sum| sum.div_euclid(divisor))
+}
+
+/// Rounds `value` to `min` plus a multiple of `step`, leaving it unchanged if
+/// `step` is zero or the result would overflow.
+fn round_to_step(value: Decimal, min: Decimal, step: Decimal, rounding: Rounding) -> Decimal {
+    if step.is_zero() {
+        return value;
+    }
+    value
+        .checked_sub(min)
+        .and_then(|offset| offset.round_to_tick(step, rounding))
+        .and_then(|offset| offset.checked_add(min))
+        .unwrap_or(value)
+}
+
+/// Returns `order` with `price` and `stopPrice` rounded to the nearest tick
+/// of the PRICE_FILTER, and `quantity` and `icebergQty` rounded down to the
+/// step of the LOT_SIZE filter, or of MARKET_LOT_SIZE for market orders.
+/// Rounding does not bring a value within the filters' min and max, which
+/// [`validate`] still reports.
+pub fn round(order: &Order, symbol: &SymbolInfo) -> Order {
+    let mut rounded = order.clone();
+    let mut lot_size = None;
+    let mut market_lot_size = None;
+    for filter in &symbol.filters {
+        match *filter {
+            SymbolFilter::Price {
+                min_price,
+                tick_size,
+                ..
+            } => {
+                for price in [&mut rounded.price, &mut rounded.stop_price]
+                    .into_iter()
+                    .flatten()
+                {
+                    *price = round_to_step(*price, min_price, tick_size, Rounding::Nearest);
+                }
+            }
+            SymbolFilter::LotSize {
+                min_qty, step_size, ..
+            } => lot_size = Some((min_qty, step_size)),
+            SymbolFilter::MarketLotSize {
+                min_qty, step_size, ..
+            } if !step_size.is_zero() => market_lot_size = Some((min_qty, step_size)),
+            _ => {}
+        }
+    }
+    let step = match market_lot_size {
+        Some(step) if order.is_market() => Some(step),
+        _ => lot_size,
+    };
+    if let Some((min_qty, step_size)) = step {
+        for qty in [&mut rounded.qty, &mut rounded.iceberg_qty]
+            .into_iter()
+            .flatten()
+        {
+            *qty = round_to_step(*qty, min_qty, step_size, Rounding::Floor);
+        }
+    }
+    rounded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spot_sbe::{AllowedSelfTradePreventionModes, OrderTypes};
+
+    fn dec(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn symbol(filters: Vec<SymbolFilter>) -> SymbolInfo {
+        SymbolInfo {
+            status: SymbolStatus::Trading,
+            base_asset_precision: 8,
+            quote_asset_precision: 8,
+            base_commission_precision: 8,
+            quote_commission_precision: 8,
+            order_types: OrderTypes::new(u16::MAX),
+            iceberg_allowed: true,
+            oco_allowed: true,
+            quote_order_qty_market_allowed: true,
+            allow_trailing_stop: true,
+            cancel_replace_allowed: true,
+            is_spot_trading_allowed: true,
+            is_margin_trading_allowed: false,
+            default_self_trade_prevention_mode: SelfTradePreventionMode::ExpireMaker,
+            allowed_self_trade_prevention_modes: AllowedSelfTradePreventionModes::new(u8::MAX),
+            filters,
+            permissions: vec!["SPOT".into()],
+            symbol: "BTCUSDT".into(),
+            base_asset: "BTC".into(),
+            quote_asset: "USDT".into(),
+        }
+    }
+
+    fn order(json: serde_json::Value) -> Order {
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn limit_buy(price: &str, qty: &str) -> Order {
+        order(serde_json::json!({
+            "symbol": "BTCUSDT",
+            "side": "BUY",
+            "type": "LIMIT",
+            "timeInForce": "GTC",
+            "price": price,
+            "quantity": qty,
+        }))
+    }
+
+    fn price_filter() -> SymbolFilter {
+        SymbolFilter::Price {
+            min_price: dec("0.01"),
+            max_price: dec("1000000"),
+            tick_size: dec("0.01"),
+        }
+    }
+
+    fn lot_size() -> SymbolFilter {
+        SymbolFilter::LotSize {
+            min_qty: dec("0.001"),
+            max_qty: dec("100"),
+            step_size: dec("0.001"),
+        }
+    }
+
+    fn rules(violations: &[Violation]) -> Vec<(&str, &str)> {
+        violations
+            .iter()
+            .map(|violation| (violation.filter, violation.rule.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn valid_order_has_no_violations() {
+        let symbol = symbol(vec![price_filter(), lot_size()]);
+        let order = limit_buy("30000.01", "0.015");
+        assert!(validate(&order, &symbol, &MarketState::default()).is_empty());
+    }
+
+    #[test]
+    fn symbol_settings_are_checked() {
+        let mut symbol = symbol(Vec::new());
+        symbol.status = SymbolStatus::Halt;
+        symbol.order_types = OrderTypes::new(0);
+        symbol.iceberg_allowed = false;
+        let mut order = limit_buy("1", "1");
+        order.symbol = "ETHUSDT".into();
+        order.iceberg_qty = Some(dec("0.5"));
+        let violations = validate(&order, &symbol, &MarketState::default());
+        assert_eq!(
+            rules(&violations),
+            [
+                ("symbol", "symbol == symbol"),
+                ("status", "status == TRADING"),
+                ("orderTypes", "orderTypes contains LIMIT"),
+                ("icebergAllowed", "icebergQty requires icebergAllowed"),
+            ]
+        );
+        assert_eq!(violations[1].value.as_deref(), Some("HALT"));
+    }
+
+    #[test]
+    fn order_types_require_their_parameters() {
+        let symbol = symbol(Vec::new());
+        let order = order(serde_json::json!({
+            "symbol": "BTCUSDT",
+            "side": "SELL",
+            "type": "STOP_LOSS_LIMIT",
+        }));
+        assert_eq!(
+            rules(&validate(&order, &symbol, &MarketState::default())),
+            [
+                ("type", "STOP_LOSS_LIMIT requires timeInForce"),
+                ("type", "STOP_LOSS_LIMIT requires price"),
+                ("type", "STOP_LOSS_LIMIT requires quantity"),
+                (
+                    "type",
+                    "STOP_LOSS_LIMIT requires stopPrice or trailingDelta"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn price_and_quantity_must_be_in_range_and_on_step() {
+        let symbol = symbol(vec![price_filter(), lot_size()]);
+        let violations = validate(
+            &limit_buy("30000.005", "100.0005"),
+            &symbol,
+            &MarketState::default(),
+        );
+        assert_eq!(
+            rules(&violations),
+            [
+                ("PRICE_FILTER", "(price - minPrice) % tickSize == 0"),
+                ("LOT_SIZE", "quantity <= maxQty"),
+                ("LOT_SIZE", "(quantity - minQty) % stepSize == 0"),
+            ]
+        );
+        assert_eq!(violations[0].value.as_deref(), Some("30000.005"));
+        assert_eq!(violations[0].limit.as_deref(), Some("0.01"));
+    }
+
+    #[test]
+    fn step_is_counted_from_the_minimum() {
+        let symbol = symbol(vec![SymbolFilter::LotSize {
+            min_qty: dec("0.5"),
+            max_qty: dec("0"),
+            step_size: dec("2"),
+        }]);
+        let market = MarketState::default();
+        assert!(validate(&limit_buy("1", "4.5"), &symbol, &market).is_empty());
+        assert_eq!(
+            rules(&validate(&limit_buy("1", "4"), &symbol, &market)),
+            [("LOT_SIZE", "(quantity - minQty) % stepSize == 0")]
+        );
+    }
+
+    #[test]
+    fn percent_price_by_side_uses_the_order_side() {
+        let symbol = symbol(vec![SymbolFilter::PercentPriceBySide {
+            bid_multiplier_up: dec("1.1"),
+            bid_multiplier_down: dec("0.2"),
+            ask_multiplier_up: dec("5"),
+            ask_multiplier_down: dec("0.9"),
+            avg_price_mins: 5,
+        }]);
+        let market = MarketState {
+            avg_price: Some(dec("100")),
+            ..MarketState::default()
+        };
+        let violations = validate(&limit_buy("120", "1"), &symbol, &market);
+        assert_eq!(
+            rules(&violations),
+            [(
+                "PERCENT_PRICE_BY_SIDE",
+                "price <= avgPrice * bidMultiplierUp"
+            )]
+        );
+        assert_eq!(violations[0].limit.as_deref(), Some("110"));
+        let mut sell = limit_buy("120", "1");
+        sell.side = OrderSide::Sell;
+        assert!(validate(&sell, &symbol, &market).is_empty());
+        sell.price = Some(dec("80"));
+        assert_eq!(
+            rules(&validate(&sell, &symbol, &market)),
+            [(
+                "PERCENT_PRICE_BY_SIDE",
+                "price >= avgPrice * askMultiplierDown"
+            )]
+        );
+        // Not checked without an average price
+        assert!(validate(&sell, &symbol, &MarketState::default()).is_empty());
+    }
+
+    #[test]
+    fn notional_uses_the_average_price_for_market_orders() {
+        let symbol = symbol(vec![SymbolFilter::Notional {
+            min_notional: dec("10"),
+            apply_min_to_market: true,
+            max_notional: dec("1000"),
+            apply_max_to_market: false,
+            avg_price_mins: 5,
+        }]);
+        let market = MarketState {
+            avg_price: Some(dec("100")),
+            ..MarketState::default()
+        };
+        let violations = validate(&limit_buy("100", "0.05"), &symbol, &market);
+        assert_eq!(
+            rules(&violations),
+            [("NOTIONAL", "price * quantity >= minNotional")]
+        );
+        assert_eq!(violations[0].value.as_deref(), Some("5"));
+        assert_eq!(
+            rules(&validate(&limit_buy("100", "20"), &symbol, &market)),
+            [("NOTIONAL", "price * quantity <= maxNotional")]
+        );
+        let mut market_order = order(serde_json::json!({
+            "symbol": "BTCUSDT",
+            "side": "BUY",
+            "type": "MARKET",
+            "quantity": "0.05",
+        }));
+        assert_eq!(
+            rules(&validate(&market_order, &symbol, &market)),
+            [("NOTIONAL", "price * quantity >= minNotional")]
+        );
+        // apply_max_to_market is false
+        market_order.qty = Some(dec("20"));
+        assert!(validate(&market_order, &symbol, &market).is_empty());
+        market_order.qty = None;
+        market_order.quote_order_qty = Some(dec("9.99"));
+        assert_eq!(
+            rules(&validate(&market_order, &symbol, &market)),
+            [("NOTIONAL", "price * quantity >= minNotional")]
+        );
+    }
+
+    #[test]
+    fn min_notional_can_skip_market_orders() {
+        let symbol = symbol(vec![SymbolFilter::MinNotional {
+            min_notional: dec("10"),
+            apply_to_market: false,
+            avg_price_mins: 5,
+        }]);
+        let market = MarketState {
+            avg_price: Some(dec("100")),
+            ..MarketState::default()
+        };
+        assert_eq!(
+            rules(&validate(&limit_buy("100", "0.05"), &symbol, &market)),
+            [("MIN_NOTIONAL", "price * quantity >= minNotional")]
+        );
+        let market_order = order(serde_json::json!({
+            "symbol": "BTCUSDT",
+            "side": "BUY",
+            "type": "MARKET",
+            "quantity": "0.05",
+        }));
+        assert!(validate(&market_order, &symbol, &market).is_empty());
+    }
+
+    #[test]
+    fn open_order_counts_include_the_new_order() {
+        let symbol = symbol(vec![
+            SymbolFilter::MaxNumOrders {
+                max_num_orders: 200,
+            },
+            SymbolFilter::MaxNumAlgoOrders {
+                max_num_algo_orders: 5,
+            },
+            SymbolFilter::IcebergParts { filter_limit: 10 },
+        ]);
+        let mut market = MarketState {
+            open_orders: Some(199),
+            open_algo_orders: Some(5),
+            ..MarketState::default()
+        };
+        let mut order = limit_buy("1", "1");
+        // Not an algo order
+        assert!(validate(&order, &symbol, &market).is_empty());
+        market.open_orders = Some(200);
+        let violations = validate(&order, &symbol, &market);
+        assert_eq!(
+            rules(&violations),
+            [("MAX_NUM_ORDERS", "open orders + 1 <= limit")]
+        );
+        assert_eq!(violations[0].value.as_deref(), Some("201"));
+        order.iceberg_qty = Some(dec("0.09"));
+        let violations = validate(&order, &symbol, &market);
+        assert_eq!(violations[1].filter, "ICEBERG_PARTS");
+        assert_eq!(violations[1].value.as_deref(), Some("12"));
+        order.iceberg_qty = Some(dec("0.1"));
+        assert_eq!(validate(&order, &symbol, &market).len(), 1);
+    }
+
+    #[test]
+    fn trailing_delta_direction_depends_on_side_and_type() {
+        let symbol = symbol(vec![SymbolFilter::TrailingDelta {
+            min_trailing_above_delta: 10,
+            max_trailing_above_delta: 2000,
+            min_trailing_below_delta: 50,
+            max_trailing_below_delta: 1000,
+        }]);
+        let mut order = order(serde_json::json!({
+            "symbol": "BTCUSDT",
+            "side": "BUY",
+            "type": "STOP_LOSS",
+            "quantity": "1",
+            "trailingDelta": 1500,
+        }));
+        let market = MarketState::default();
+        assert!(validate(&order, &symbol, &market).is_empty());
+        order.side = OrderSide::Sell;
+        assert_eq!(
+            rules(&validate(&order, &symbol, &market)),
+            [("TRAILING_DELTA", "trailingDelta <= maxTrailingBelowDelta")]
+        );
+    }
+
+    #[test]
+    fn round_puts_prices_and_quantities_on_step() {
+        let symbol = symbol(vec![price_filter(), lot_size()]);
+        let mut order = limit_buy("30000.006", "0.0159");
+        order.iceberg_qty = Some(dec("0.0051"));
+        let rounded = round(&order, &symbol);
+        assert_eq!(rounded.price, Some(dec("30000.01")));
+        assert_eq!(rounded.qty, Some(dec("0.015")));
+        assert_eq!(rounded.iceberg_qty, Some(dec("0.005")));
+        assert!(validate(&rounded, &symbol, &MarketState::default()).is_empty());
+    }
+
+    #[test]
+    fn round_uses_market_lot_size_for_market_orders() {
+        let symbol = symbol(vec![
+            lot_size(),
+            SymbolFilter::MarketLotSize {
+                min_qty: dec("0"),
+                max_qty: dec("50"),
+                step_size: dec("0.1"),
+            },
+        ]);
+        let mut order = order(serde_json::json!({
+            "symbol": "BTCUSDT",
+            "side": "SELL",
+            "type": "MARKET",
+            "quantity": "1.2345",
+        }));
+        assert_eq!(round(&order, &symbol).qty, Some(dec("1.2")));
+        order.order_type = OrderType::Limit;
+        assert_eq!(round(&order, &symbol).qty, Some(dec("1.234")));
+    }
+
+    #[test]
+    fn round_leaves_values_below_the_minimum() {
+        let symbol = symbol(vec![lot_size()]);
+        let rounded = round(&limit_buy("1", "0.0005"), &symbol);
+        assert_eq!(
+            rules(&validate(&rounded, &symbol, &MarketState::default())),
+            [("LOT_SIZE", "quantity >= minQty")]
+        );
+    }
+
+    #[test]
+    fn ceil_div_counts_partial_parts() {
+        assert_eq!(ceil_div(dec("1"), dec("0.3")), Some(4));
+        assert_eq!(ceil_div(dec("0.9"), dec("0.3")), Some(3));
+        assert_eq!(ceil_div(dec("1"), dec("0")), None);
+    }
+}
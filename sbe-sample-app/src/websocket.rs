@@ -1,13 +1,6 @@
-use crate::exchange_info::{ErrorResponse, ExchangeInfo};
+use crate::message::Message;
 use crate::rate_limit::RateLimit;
-use serde::Serialize;
-
-#[derive(Serialize)]
-enum WebSocketResult {
-    Unset,
-    Error(ErrorResponse),
-    ExchangeInfo(ExchangeInfo),
-}
+use serde::{ser::SerializeMap, Serialize, Serializer};
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -15,7 +8,8 @@ pub struct WebSocketMetadata {
     status: u16,
     rate_limits: Vec<RateLimit>,
     id: String,
-    result: WebSocketResult,
+    #[serde(serialize_with = "serialize_result")]
+    result: Option<Message>,
 }
 
 impl WebSocketMetadata {
@@ -24,15 +18,24 @@ impl WebSocketMetadata {
             status,
             rate_limits,
             id,
-            result: WebSocketResult::Unset,
+            result: None,
         }
     }
 
-    pub fn set_error(&mut self, error: ErrorResponse) {
-        self.result = WebSocketResult::Error(error);
+    pub fn set_result(&mut self, result: Message) {
+        self.result = Some(result);
     }
+}
 
-    pub fn set_exchange_info(&mut self, exchange_info: ExchangeInfo) {
-        self.result = WebSocketResult::ExchangeInfo(exchange_info);
-    }
+/// Serializes the result keyed by its message name, e.g. `ExchangeInfo: ...`.
+fn serialize_result<S: Serializer>(
+    val: &Option<Message>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let Some(message) = val else {
+        return serializer.serialize_none();
+    };
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry(message.name(), message)?;
+    map.end()
 }
@@ -0,0 +1,489 @@
+//! Account balances folded from an `AccountResponse` and the user data
+//! stream's position, balance and external lock events.
+//!
+//! `outboundAccountPosition` carries the full free and locked values of the
+//! assets that changed, while `balanceUpdate` and `externalLockUpdate` carry
+//! deltas. Each delta is applied as it arrives and checked against the next
+//! full position of its asset, which is what the exchange says the balance is.
+
+use crate::*;
+use std::collections::BTreeMap;
+
+/// Free and locked quantity of one asset.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Balance {
+    pub free: Decimal,
+    pub locked: Decimal,
+}
+
+/// Which part of a [`Balance`] an [`Inconsistency`] is about.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    Free,
+    Locked,
+}
+
+/// Something the events disagree about. The state always follows the
+/// exchange: full positions overwrite, and out-of-order events are dropped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Inconsistency {
+    /// A full position differs from the previous one plus the deltas applied
+    /// since.
+    DeltaMismatch {
+        event_time: i64,
+        asset: String,
+        part: Part,
+        expected: Decimal,
+        actual: Decimal,
+    },
+    /// A delta took a balance below zero.
+    NegativeBalance {
+        event_time: i64,
+        asset: String,
+        part: Part,
+        value: Decimal,
+    },
+    /// An event or `AccountResponse` older than the last one applied, which
+    /// was dropped.
+    OutOfOrder {
+        event_time: i64,
+        last_event_time: i64,
+    },
+}
+
+#[derive(Clone, Debug, Default)]
+struct AssetState {
+    balance: Balance,
+    /// Which parts have had deltas applied since the last full position
+    free_delta: bool,
+    locked_delta: bool,
+}
+
+/// Balances of one account, as of the last event applied.
+#[derive(Clone, Debug, Default)]
+pub struct AccountState {
+    assets: BTreeMap<String, AssetState>,
+    /// `updateTime` of the `AccountResponse` the state was seeded from, if
+    /// any
+    seed_time: Option<i64>,
+    last_event_time: Option<i64>,
+    inconsistencies: Vec<Inconsistency>,
+}
+
+impl AccountState {
+    /// Seeds the balances from an `AccountResponse`, header included.
+    pub fn decode(data: &[u8]) -> SbeResult<Self> {
+        let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+        Self::from_decoder(AccountResponseDecoder::default().header(header)?)
+    }
+
+    /// Seeds the balances from a decoder positioned after its header.
+    pub fn from_decoder(decoder: AccountResponseDecoder<'_>) -> SbeResult<Self> {
+        let mut state = Self::default();
+        state.seed(decoder)?;
+        Ok(state)
+    }
+
+    /// Replaces every balance with those of an `AccountResponse`. Events
+    /// older than its `updateTime` are already reflected in it, and are
+    /// skipped from then on; events at that time are still applied.
+    pub fn seed(&mut self, decoder: AccountResponseDecoder<'_>) -> SbeResult<()> {
+        let update_time = decoder.update_time();
+        let mut assets = BTreeMap::new();
+        let mut balances_decoder = decoder.balances_decoder()?;
+        while balances_decoder.advance()?.is_some() {
+            let balance = Balance {
+                free: balances_decoder.free_decimal(),
+                locked: balances_decoder.locked_decimal(),
+            };
+            let coordinates = balances_decoder.asset_decoder()?;
+            let asset = utf8(balances_decoder.asset_slice(coordinates)?)?;
+            assets.insert(
+                asset,
+                AssetState {
+                    balance,
+                    ..AssetState::default()
+                },
+            );
+        }
+        self.assets = assets;
+        self.seed_time = Some(update_time);
+        self.last_event_time = Some(update_time);
+        Ok(())
+    }
+
+    /// Applies the message at the start of `data` with [`apply`](Self::apply).
+    pub fn apply_bytes(&mut self, data: &[u8]) -> SbeResult<bool> {
+        self.apply(decode_any(data)?)
+    }
+
+    /// Applies an `AccountResponse`, `outboundAccountPosition`,
+    /// `balanceUpdate` or `externalLockUpdate`. Returns `false` for any other
+    /// message, and for events the state already reflects. An
+    /// `AccountResponse` older than the last event applied is dropped as out
+    /// of order rather than seeding the state back in time.
+    pub fn apply(&mut self, message: AnyMessage<'_>) -> SbeResult<bool> {
+        match message {
+            AnyMessage::AccountResponse(decoder) => {
+                let update_time = decoder.update_time();
+                if let Some(last_event_time) = self.last_event_time {
+                    if update_time < last_event_time {
+                        self.inconsistencies.push(Inconsistency::OutOfOrder {
+                            event_time: update_time,
+                            last_event_time,
+                        });
+                        return Ok(false);
+                    }
+                }
+                self.seed(decoder)?;
+                Ok(true)
+            }
+            AnyMessage::OutboundAccountPositionEvent(decoder) => self.apply_position(decoder),
+            AnyMessage::BalanceUpdateEvent(mut decoder) => {
+                let event_time = decoder.event_time();
+                let delta = decoder.free_qty_delta_decimal();
+                let coordinates = decoder.asset_decoder()?;
+                let asset = utf8(decoder.asset_slice(coordinates)?)?;
+                Ok(self.apply_delta(event_time, asset, Part::Free, delta))
+            }
+            AnyMessage::ExternalLockUpdateEvent(mut decoder) => {
+                let event_time = decoder.event_time();
+                let delta = decoder.locked_qty_delta_decimal();
+                let coordinates = decoder.asset_decoder()?;
+                let asset = utf8(decoder.asset_slice(coordinates)?)?;
+                Ok(self.apply_delta(event_time, asset, Part::Locked, delta))
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Applies a batch of messages sorted by `eventTime`, the
+    /// `AccountResponse` by its `updateTime`. Messages with the same time
+    /// keep their order.
+    pub fn apply_in_order<'a>(
+        &mut self,
+        messages: impl IntoIterator<Item = AnyMessage<'a>>,
+    ) -> SbeResult<()> {
+        let mut messages: Vec<_> = messages.into_iter().collect();
+        messages.sort_by_key(|message| match message {
+            AnyMessage::AccountResponse(decoder) => decoder.update_time(),
+            AnyMessage::OutboundAccountPositionEvent(decoder) => decoder.event_time(),
+            AnyMessage::BalanceUpdateEvent(decoder) => decoder.event_time(),
+            AnyMessage::ExternalLockUpdateEvent(decoder) => decoder.event_time(),
+            _ => i64::MAX,
+        });
+        for message in messages {
+            self.apply(message)?;
+        }
+        Ok(())
+    }
+
+    fn apply_position(
+        &mut self,
+        decoder: OutboundAccountPositionEventDecoder<'_>,
+    ) -> SbeResult<bool> {
+        let event_time = decoder.event_time();
+        if !self.accept(event_time) {
+            return Ok(false);
+        }
+        let mut balances_decoder = decoder.balances_decoder()?;
+        while balances_decoder.advance()?.is_some() {
+            let actual = Balance {
+                free: balances_decoder.free_decimal(),
+                locked: balances_decoder.locked_decimal(),
+            };
+            let coordinates = balances_decoder.asset_decoder()?;
+            let asset = utf8(balances_decoder.asset_slice(coordinates)?)?;
+            let state = self.assets.entry(asset.clone()).or_default();
+            let expected = state.balance;
+            let checks = [
+                (state.free_delta, Part::Free, expected.free, actual.free),
+                (
+                    state.locked_delta,
+                    Part::Locked,
+                    expected.locked,
+                    actual.locked,
+                ),
+            ];
+            *state = AssetState {
+                balance: actual,
+                ..AssetState::default()
+            };
+            for (had_delta, part, expected, actual) in checks {
+                if had_delta && expected != actual {
+                    self.inconsistencies.push(Inconsistency::DeltaMismatch {
+                        event_time,
+                        asset: asset.clone(),
+                        part,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn apply_delta(&mut self, event_time: i64, asset: String, part: Part, delta: Decimal) -> bool {
+        if !self.accept(event_time) {
+            return false;
+        }
+        let state = self.assets.entry(asset.clone()).or_default();
+        let (value, had_delta) = match part {
+            Part::Free => (&mut state.balance.free, &mut state.free_delta),
+            Part::Locked => (&mut state.balance.locked, &mut state.locked_delta),
+        };
+        // An unrepresentable sum can only disagree with the next position,
+        // which then overwrites it.
+        *value = value.checked_add(delta).unwrap_or(*value);
+        *had_delta = true;
+        if value.is_sign_negative() {
+            let value = *value;
+            self.inconsistencies.push(Inconsistency::NegativeBalance {
+                event_time,
+                asset,
+                part,
+                value,
+            });
+        }
+        true
+    }
+
+    /// Whether an event at `event_time` should be applied, recording it as
+    /// the last one if so. Events at the same time as the last one are
+    /// applied in the order they arrive.
+    fn accept(&mut self, event_time: i64) -> bool {
+        if self
+            .seed_time
+            .is_some_and(|seed_time| event_time < seed_time)
+        {
+            return false;
+        }
+        if let Some(last_event_time) = self.last_event_time {
+            if event_time < last_event_time {
+                self.inconsistencies.push(Inconsistency::OutOfOrder {
+                    event_time,
+                    last_event_time,
+                });
+                return false;
+            }
+        }
+        self.last_event_time = Some(event_time);
+        true
+    }
+
+    /// Free and locked quantity of `asset`, or `None` if no message has
+    /// mentioned it.
+    #[inline]
+    pub fn balance(&self, asset: &str) -> Option<Balance> {
+        self.assets.get(asset).map(|state| state.balance)
+    }
+
+    #[inline]
+    pub fn free(&self, asset: &str) -> Option<Decimal> {
+        self.balance(asset).map(|balance| balance.free)
+    }
+
+    #[inline]
+    pub fn locked(&self, asset: &str) -> Option<Decimal> {
+        self.balance(asset).map(|balance| balance.locked)
+    }
+
+    /// Every asset and its balance, by asset name.
+    pub fn balances(&self) -> impl Iterator<Item = (&str, Balance)> + '_ {
+        self.assets
+            .iter()
+            .map(|(asset, state)| (asset.as_str(), state.balance))
+    }
+
+    /// `eventTime` of the last event applied, or the seed's `updateTime`;
+    /// `None` if nothing has been applied.
+    #[inline]
+    pub fn last_event_time(&self) -> Option<i64> {
+        self.last_event_time
+    }
+
+    /// Inconsistencies found since they were last taken.
+    #[inline]
+    pub fn inconsistencies(&self) -> &[Inconsistency] {
+        &self.inconsistencies
+    }
+
+    pub fn take_inconsistencies(&mut self) -> Vec<Inconsistency> {
+        core::mem::take(&mut self.inconsistencies)
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::account_response_codec::owned as account;
+    use crate::balance_update_event_codec::owned::BalanceUpdateEvent;
+    use crate::external_lock_update_event_codec::owned::ExternalLockUpdateEvent;
+    use crate::outbound_account_position_event_codec::owned as position;
+
+    fn d(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    fn seed(update_time: i64, free: &str) -> Vec<u8> {
+        account::AccountResponse {
+            update_time,
+            balances: vec![account::Balances {
+                free: d(free),
+                asset: "BTC".into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+        .encode()
+        .unwrap()
+    }
+
+    fn position(event_time: i64, free: &str, locked: &str) -> Vec<u8> {
+        position::OutboundAccountPositionEvent {
+            event_time,
+            update_time: event_time,
+            balances: vec![position::Balances {
+                free: d(free),
+                locked: d(locked),
+                asset: "BTC".into(),
+                ..Default::default()
+            }],
+        }
+        .encode()
+        .unwrap()
+    }
+
+    fn balance_update(event_time: i64, delta: &str) -> Vec<u8> {
+        BalanceUpdateEvent {
+            event_time,
+            free_qty_delta: d(delta),
+            asset: "BTC".into(),
+            ..Default::default()
+        }
+        .encode()
+        .unwrap()
+    }
+
+    fn lock_update(event_time: i64, delta: &str) -> Vec<u8> {
+        ExternalLockUpdateEvent {
+            event_time,
+            locked_qty_delta: d(delta),
+            asset: "BTC".into(),
+            ..Default::default()
+        }
+        .encode()
+        .unwrap()
+    }
+
+    #[test]
+    fn applies_events_from_the_seed_time_on() {
+        let mut state = AccountState::decode(&seed(100, "1")).unwrap();
+        assert_eq!(state.last_event_time(), Some(100));
+        assert_eq!(state.apply_bytes(&balance_update(99, "5")), Ok(false));
+        assert_eq!(state.apply_bytes(&balance_update(100, "0.5")), Ok(true));
+        assert_eq!(state.free("BTC"), Some(d("1.5")));
+        assert_eq!(state.apply_bytes(&position(110, "1.5", "0")), Ok(true));
+        assert_eq!(state.inconsistencies(), []);
+        assert_eq!(state.last_event_time(), Some(110));
+    }
+
+    #[test]
+    fn unseeded_state_accepts_any_time() {
+        let mut state = AccountState::default();
+        assert_eq!(state.last_event_time(), None);
+        assert_eq!(state.apply_bytes(&balance_update(0, "2")), Ok(true));
+        assert_eq!(state.free("BTC"), Some(d("2")));
+        assert_eq!(state.last_event_time(), Some(0));
+    }
+
+    #[test]
+    fn reports_inconsistencies() {
+        let mut state = AccountState::decode(&seed(100, "1")).unwrap();
+        state.apply_bytes(&balance_update(101, "0.5")).unwrap();
+        state.apply_bytes(&lock_update(102, "-0.25")).unwrap();
+        state.apply_bytes(&position(103, "1.2", "0")).unwrap();
+        assert_eq!(state.apply_bytes(&balance_update(102, "1")), Ok(false));
+        let event_time = 103;
+        let asset = String::from("BTC");
+        assert_eq!(
+            state.take_inconsistencies(),
+            [
+                Inconsistency::NegativeBalance {
+                    event_time: 102,
+                    asset: asset.clone(),
+                    part: Part::Locked,
+                    value: d("-0.25"),
+                },
+                Inconsistency::DeltaMismatch {
+                    event_time,
+                    asset: asset.clone(),
+                    part: Part::Free,
+                    expected: d("1.5"),
+                    actual: d("1.2"),
+                },
+                Inconsistency::DeltaMismatch {
+                    event_time,
+                    asset,
+                    part: Part::Locked,
+                    expected: d("-0.25"),
+                    actual: d("0"),
+                },
+                Inconsistency::OutOfOrder {
+                    event_time: 102,
+                    last_event_time: 103,
+                },
+            ]
+        );
+        // the position is what the exchange says
+        assert_eq!(
+            state.balance("BTC"),
+            Some(Balance {
+                free: d("1.2"),
+                locked: d("0")
+            })
+        );
+    }
+
+    #[test]
+    fn stale_snapshot_is_dropped() {
+        let mut state = AccountState::decode(&seed(100, "1")).unwrap();
+        assert_eq!(state.apply_bytes(&position(110, "3", "0.5")), Ok(true));
+        assert_eq!(state.apply_bytes(&seed(105, "1")), Ok(false));
+        assert_eq!(
+            state.balance("BTC"),
+            Some(Balance {
+                free: d("3"),
+                locked: d("0.5")
+            })
+        );
+        assert_eq!(state.last_event_time(), Some(110));
+        assert_eq!(
+            state.inconsistencies(),
+            [Inconsistency::OutOfOrder {
+                event_time: 105,
+                last_event_time: 110,
+            }]
+        );
+        // a snapshot at the last event time still applies, and later events
+        // older than it are skipped
+        assert_eq!(state.apply_bytes(&seed(110, "4")), Ok(true));
+        assert_eq!(state.apply_bytes(&balance_update(109, "1")), Ok(false));
+        assert_eq!(state.free("BTC"), Some(d("4")));
+    }
+
+    #[test]
+    fn applies_batches_in_time_order() {
+        let frames = [
+            position(20, "3", "0"),
+            balance_update(10, "1"),
+            seed(5, "2"),
+        ];
+        let messages = frames.iter().map(|frame| decode_any(frame).unwrap());
+        let mut state = AccountState::default();
+        state.apply_in_order(messages).unwrap();
+        assert_eq!(state.free("BTC"), Some(d("3")));
+        assert_eq!(state.inconsistencies(), []);
+    }
+}
@@ -0,0 +1,385 @@
+//! Local price-level order book built from `DepthResponse` snapshots.
+//!
+//! Levels are keyed by their exact [`Decimal`] price, so no precision is lost
+//! between decoding a snapshot and encoding the book back into one.
+
+use crate::depth_response_codec::encoder::{AsksEncoder, BidsEncoder};
+use crate::*;
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BookError {
+    Decode(SbeErr),
+    /// A group lists the same price twice, e.g. the `bids` of a
+    /// `DepthResponse`, which has one entry per price level.
+    DuplicatePriceLevel {
+        group: &'static str,
+        price: Decimal,
+    },
+}
+
+impl core::fmt::Display for BookError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Decode(err) => write!(f, "{err}"),
+            Self::DuplicatePriceLevel { group, price } => {
+                write!(f, "{group} lists price {price} more than once")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BookError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Decode(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<SbeErr> for BookError {
+    fn from(err: SbeErr) -> Self {
+        Self::Decode(err)
+    }
+}
+
+/// Side of the book.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Side {
+    Bid,
+    Ask,
+}
+
+/// A price level: the total quantity resting at one price.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Level {
+    pub price: Decimal,
+    pub qty: Decimal,
+}
+
+/// Price-level order book, as of the `lastUpdateId` of the snapshot it was
+/// last built from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OrderBook {
+    /// `None` until a snapshot has been applied
+    last_update_id: Option<i64>,
+    /// Exponents of the last snapshot, kept so that encoding the book back
+    /// reproduces its scale
+    price_exponent: i8,
+    qty_exponent: i8,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+}
+
+impl OrderBook {
+    /// Builds a book from the `DepthResponse`, header included, at the start
+    /// of `data`.
+    pub fn decode(data: &[u8]) -> Result<Self, BookError> {
+        let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+        Self::from_decoder(DepthResponseDecoder::default().header(header)?)
+    }
+
+    /// Builds a book from a decoder positioned after its header.
+    pub fn from_decoder(decoder: DepthResponseDecoder<'_>) -> Result<Self, BookError> {
+        let mut book = Self::default();
+        book.replace(decoder)?;
+        Ok(book)
+    }
+
+    /// Replaces the book with the `DepthResponse` at the start of `data` if
+    /// it is newer than the book. Returns whether it was applied; an older or
+    /// repeated snapshot leaves the book unchanged, while the first snapshot
+    /// an empty book gets is always applied.
+    pub fn apply_snapshot(&mut self, data: &[u8]) -> Result<bool, BookError> {
+        let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+        let decoder = DepthResponseDecoder::default().header(header)?;
+        self.apply_decoder(decoder)
+    }
+
+    /// As [`apply_snapshot`](Self::apply_snapshot), from a decoder positioned
+    /// after its header.
+    pub fn apply_decoder(&mut self, decoder: DepthResponseDecoder<'_>) -> Result<bool, BookError> {
+        if self
+            .last_update_id
+            .is_some_and(|last_update_id| decoder.last_update_id() <= last_update_id)
+        {
+            return Ok(false);
+        }
+        self.replace(decoder)?;
+        Ok(true)
+    }
+
+    fn replace(&mut self, decoder: DepthResponseDecoder<'_>) -> Result<(), BookError> {
+        let last_update_id = decoder.last_update_id();
+        let price_exponent = decoder.price_exponent();
+        let qty_exponent = decoder.qty_exponent();
+
+        let mut bids = BTreeMap::new();
+        let mut bids_decoder = decoder.bids_decoder()?;
+        while bids_decoder.advance()?.is_some() {
+            insert_level(
+                &mut bids,
+                "bids",
+                bids_decoder.price_decimal()?,
+                bids_decoder.qty_decimal()?,
+            )?;
+        }
+        let decoder = bids_decoder.parent()?;
+
+        let mut asks = BTreeMap::new();
+        let mut asks_decoder = decoder.asks_decoder()?;
+        while asks_decoder.advance()?.is_some() {
+            insert_level(
+                &mut asks,
+                "asks",
+                asks_decoder.price_decimal()?,
+                asks_decoder.qty_decimal()?,
+            )?;
+        }
+
+        *self = Self {
+            last_update_id: Some(last_update_id),
+            price_exponent,
+            qty_exponent,
+            bids,
+            asks,
+        };
+        Ok(())
+    }
+
+    /// `lastUpdateId` of the snapshot the book was built from, if any.
+    #[inline]
+    pub fn last_update_id(&self) -> Option<i64> {
+        self.last_update_id
+    }
+
+    /// Levels of one side, best first: highest bid or lowest ask.
+    pub fn levels(&self, side: Side) -> Box<dyn Iterator<Item = Level> + '_> {
+        let level = |(&price, &qty)| Level { price, qty };
+        match side {
+            Side::Bid => Box::new(self.bids.iter().rev().map(level)),
+            Side::Ask => Box::new(self.asks.iter().map(level)),
+        }
+    }
+
+    #[inline]
+    pub fn best_bid(&self) -> Option<Level> {
+        self.levels(Side::Bid).next()
+    }
+
+    #[inline]
+    pub fn best_ask(&self) -> Option<Level> {
+        self.levels(Side::Ask).next()
+    }
+
+    /// Best ask minus best bid, or `None` if either side is empty.
+    pub fn spread(&self) -> Option<Decimal> {
+        self.best_ask()?.price.checked_sub(self.best_bid()?.price)
+    }
+
+    /// Halfway between the best bid and ask, or `None` if either side is
+    /// empty.
+    pub fn mid(&self) -> Option<Decimal> {
+        let sum = self.best_bid()?.price.checked_add(self.best_ask()?.price)?;
+        Some(sum.checked_mul(Decimal::new(5, -1))?.normalize())
+    }
+
+    /// The `n` best levels of one side.
+    pub fn depth(&self, side: Side, n: usize) -> Vec<Level> {
+        self.levels(side).take(n).collect()
+    }
+
+    /// Total quantity of the levels at `price` or better: bids at or above
+    /// it, asks at or below it. This is what an order on the other side with
+    /// `price` as its limit could fill against.
+    pub fn qty_to_price(&self, side: Side, price: Decimal) -> Option<Decimal> {
+        let mut total = Decimal::ZERO;
+        for level in self.levels(side) {
+            let better = match side {
+                Side::Bid => level.price >= price,
+                Side::Ask => level.price <= price,
+            };
+            if !better {
+                break;
+            }
+            total = total.checked_add(level.qty)?;
+        }
+        Some(total)
+    }
+
+    /// Length of the encoded snapshot excluding its header
+    pub fn encoded_length(&self) -> usize {
+        let entry = usize::from(BidsEncoder::<DepthResponseEncoder<'_>>::block_length());
+        usize::from(depth_response_codec::SBE_BLOCK_LENGTH)
+            + 2 * group_size_encoding_codec::ENCODED_LENGTH
+            + entry * (self.bids.len() + self.asks.len())
+    }
+
+    /// Writes the book as a snapshot, bids best first then asks best first.
+    /// Each exponent is the finest scale among the snapshot it was built from
+    /// and its levels.
+    pub fn to_encoder(&self, encoder: &mut DepthResponseEncoder<'_>) -> SbeResult<()> {
+        let price_exponent = self
+            .bids
+            .keys()
+            .chain(self.asks.keys())
+            .map(Decimal::exponent)
+            .fold(self.price_exponent, i8::min);
+        let qty_exponent = self
+            .bids
+            .values()
+            .chain(self.asks.values())
+            .map(Decimal::exponent)
+            .fold(self.qty_exponent, i8::min);
+        encoder.last_update_id(self.last_update_id.unwrap_or_default());
+        encoder.price_exponent(price_exponent);
+        encoder.qty_exponent(qty_exponent);
+
+        let count = checked_length::<u32>(self.bids.len())?;
+        let mut bids_encoder = core::mem::take(encoder).bids_encoder(count, BidsEncoder::default());
+        for level in self.levels(Side::Bid) {
+            bids_encoder.advance()?;
+            bids_encoder.price_decimal(level.price)?;
+            bids_encoder.qty_decimal(level.qty)?;
+        }
+        *encoder = bids_encoder.parent()?;
+
+        let count = checked_length::<u32>(self.asks.len())?;
+        let mut asks_encoder = core::mem::take(encoder).asks_encoder(count, AsksEncoder::default());
+        for level in self.levels(Side::Ask) {
+            asks_encoder.advance()?;
+            asks_encoder.price_decimal(level.price)?;
+            asks_encoder.qty_decimal(level.qty)?;
+        }
+        *encoder = asks_encoder.parent()?;
+        Ok(())
+    }
+
+    /// Encodes the book as a `DepthResponse`, header included.
+    pub fn encode(&self) -> SbeResult<Vec<u8>> {
+        let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+        let encoder = DepthResponseEncoder::default().wrap(
+            WriteBuf::new(&mut data),
+            message_header_codec::ENCODED_LENGTH,
+        );
+        let mut encoder = encoder.header(0).parent()?;
+        self.to_encoder(&mut encoder)?;
+        Ok(data)
+    }
+}
+
+/// Empty levels are left out. A price listed twice is an error rather than
+/// either quantity winning, as the snapshot cannot be trusted.
+fn insert_level(
+    levels: &mut BTreeMap<Decimal, Decimal>,
+    group: &'static str,
+    price: Decimal,
+    qty: Decimal,
+) -> Result<(), BookError> {
+    if qty.is_zero() {
+        return Ok(());
+    }
+    if levels.insert(price, qty).is_some() {
+        return Err(BookError::DuplicatePriceLevel { group, price });
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::depth_response_codec::owned::{Asks, Bids, DepthResponse};
+
+    fn d(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    fn snapshot(last_update_id: i64, bids: &[(&str, &str)], asks: &[(&str, &str)]) -> Vec<u8> {
+        DepthResponse {
+            last_update_id,
+            bids: bids
+                .iter()
+                .map(|&(price, qty)| Bids {
+                    price: d(price),
+                    qty: d(qty),
+                })
+                .collect(),
+            asks: asks
+                .iter()
+                .map(|&(price, qty)| Asks {
+                    price: d(price),
+                    qty: d(qty),
+                })
+                .collect(),
+            ..Default::default()
+        }
+        .encode()
+        .unwrap()
+    }
+
+    #[test]
+    fn first_snapshot_is_always_applied() {
+        let mut book = OrderBook::default();
+        assert_eq!(book.last_update_id(), None);
+        assert_eq!(
+            book.apply_snapshot(&snapshot(0, &[("1", "1")], &[])),
+            Ok(true)
+        );
+        assert_eq!(book.last_update_id(), Some(0));
+        assert_eq!(book.apply_snapshot(&snapshot(0, &[], &[])), Ok(false));
+        assert_eq!(
+            book.apply_snapshot(&snapshot(5, &[("2", "1")], &[])),
+            Ok(true)
+        );
+        assert_eq!(book.apply_snapshot(&snapshot(3, &[], &[])), Ok(false));
+        assert_eq!(book.best_bid().map(|level| level.price), Some(d("2")));
+    }
+
+    #[test]
+    fn levels_are_ordered_best_first() {
+        let bids = [("99.5", "1"), ("100", "2"), ("98", "0")];
+        let asks = [("101.25", "3"), ("100.5", "0.5")];
+        let book = OrderBook::decode(&snapshot(1, &bids, &asks)).unwrap();
+        let prices: Vec<_> = book.levels(Side::Bid).map(|level| level.price).collect();
+        // the empty level at 98 is left out
+        assert_eq!(prices, [d("100"), d("99.5")]);
+        assert_eq!(book.best_ask().map(|level| level.price), Some(d("100.5")));
+        assert_eq!(book.spread(), Some(d("0.5")));
+        assert_eq!(book.mid(), Some(d("100.25")));
+        assert_eq!(book.depth(Side::Ask, 1).len(), 1);
+        assert_eq!(book.qty_to_price(Side::Ask, d("101.25")), Some(d("3.5")));
+        assert_eq!(book.qty_to_price(Side::Bid, d("100")), Some(d("2")));
+    }
+
+    #[test]
+    fn duplicate_price_is_an_error() {
+        // 100.0 and 100 are the same price at different scales
+        let frame = snapshot(
+            2,
+            &[("100", "1")],
+            &[("101", "1"), ("100.0", "2"), ("100", "3")],
+        );
+        assert_eq!(
+            OrderBook::decode(&frame),
+            Err(BookError::DuplicatePriceLevel {
+                group: "asks",
+                price: d("100"),
+            })
+        );
+        // the same price on both sides is a crossed book, not a duplicate
+        let mut book = OrderBook::decode(&snapshot(1, &[("100", "1")], &[("100", "1")])).unwrap();
+        let duplicate_bid = snapshot(3, &[("99", "1"), ("99", "2")], &[]);
+        assert!(book.apply_snapshot(&duplicate_bid).is_err());
+        // and a rejected snapshot leaves the book as it was
+        assert_eq!(book.last_update_id(), Some(1));
+        assert_eq!(book.best_bid().map(|level| level.price), Some(d("100")));
+    }
+
+    #[test]
+    fn encodes_back_into_a_snapshot() {
+        let book = OrderBook::decode(&snapshot(9, &[("0.001", "15")], &[("0.1", "0.25")])).unwrap();
+        assert_eq!(OrderBook::decode(&book.encode().unwrap()), Ok(book.clone()));
+        assert_eq!(OrderBook::default().encode().map(|data| data.len()), Ok(30));
+    }
+}
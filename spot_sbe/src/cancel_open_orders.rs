@@ -0,0 +1,168 @@
+//! Typed iteration over the `responses` of a `CancelOpenOrdersResponse`.
+//!
+//! Each entry embeds the response to one cancellation: a
+//! `CancelOrderResponse` for an order placed on its own, or a
+//! `CancelOrderListResponse` for an order list, which cancels every order
+//! in the list at once.
+
+use crate::cancel_open_orders_response_codec::decoder::ResponsesDecoder;
+use crate::*;
+
+/// The response to one cancellation.
+#[derive(Clone, Copy, Debug)]
+pub enum Canceled<'a> {
+    Order(CancelOrderResponseDecoder<'a>),
+    OrderList(CancelOrderListResponseDecoder<'a>),
+}
+
+/// Iterator over the entries of `responses`, in the order the exchange
+/// canceled them. It ends after the first error.
+#[derive(Debug)]
+pub struct CanceledIter<'a> {
+    responses: ResponsesDecoder<'a, CancelOpenOrdersResponseDecoder<'a>>,
+    data: &'a [u8],
+    remaining: usize,
+}
+
+impl<'a> CanceledIter<'a> {
+    /// Iterates over the `CancelOpenOrdersResponse`, header included, at the
+    /// start of `data`.
+    pub fn decode(data: &'a [u8]) -> SbeResult<Self> {
+        let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+        Self::from_decoder(CancelOpenOrdersResponseDecoder::default().header(header)?)
+    }
+
+    /// Iterates over the entries of a decoder positioned after its header.
+    pub fn from_decoder(decoder: CancelOpenOrdersResponseDecoder<'a>) -> SbeResult<Self> {
+        let data = decoder.get_buf().as_slice();
+        let responses = decoder.responses_decoder()?;
+        let remaining = responses.count() as usize;
+        Ok(Self {
+            responses,
+            data,
+            remaining,
+        })
+    }
+
+    fn read_next(&mut self) -> SbeResult<Canceled<'a>> {
+        self.responses.advance()?;
+        let (offset, length) = self.responses.response_decoder()?;
+        match decode_any(&self.data[offset..offset + length])? {
+            AnyMessage::CancelOrderResponse(decoder) => Ok(Canceled::Order(decoder)),
+            AnyMessage::CancelOrderListResponse(decoder) => Ok(Canceled::OrderList(decoder)),
+            message => Err(SbeErr::UnexpectedEmbeddedMessage {
+                field: "response",
+                template_id: Some(message.template_id()),
+            }),
+        }
+    }
+}
+
+impl<'a> Iterator for CanceledIter<'a> {
+    type Item = SbeResult<Canceled<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let item = self.read_next();
+        self.remaining = if item.is_ok() { self.remaining - 1 } else { 0 };
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::cancel_open_orders_response_codec::owned as cancel_open_orders;
+    use crate::cancel_order_list_response_codec::owned::CancelOrderListResponse;
+    use crate::cancel_order_response_codec::owned::CancelOrderResponse;
+    use crate::ping_response_codec::owned::PingResponse;
+
+    fn order(order_id: i64) -> Vec<u8> {
+        CancelOrderResponse {
+            order_id,
+            status: OrderStatus::Canceled,
+            symbol: "BTCUSDT".into(),
+            ..Default::default()
+        }
+        .encode()
+        .unwrap()
+    }
+
+    fn order_list(order_list_id: i64) -> Vec<u8> {
+        CancelOrderListResponse {
+            order_list_id,
+            symbol: "BTCUSDT".into(),
+            ..Default::default()
+        }
+        .encode()
+        .unwrap()
+    }
+
+    fn response(entries: Vec<Vec<u8>>) -> Vec<u8> {
+        cancel_open_orders::CancelOpenOrdersResponse {
+            responses: entries
+                .into_iter()
+                .map(|response| cancel_open_orders::Responses { response })
+                .collect(),
+        }
+        .encode()
+        .unwrap()
+    }
+
+    #[test]
+    fn yields_orders_and_order_lists_in_order() {
+        let frame = response(vec![order(1), order_list(7), order(2)]);
+        let canceled: Vec<_> = CanceledIter::decode(&frame)
+            .unwrap()
+            .collect::<SbeResult<_>>()
+            .unwrap();
+        let ids: Vec<_> = canceled
+            .iter()
+            .map(|canceled| match canceled {
+                Canceled::Order(decoder) => ("order", decoder.order_id()),
+                Canceled::OrderList(decoder) => ("list", decoder.order_list_id()),
+            })
+            .collect();
+        assert_eq!(ids, [("order", 1), ("list", 7), ("order", 2)]);
+    }
+
+    #[test]
+    fn unexpected_embedded_message_is_an_error() {
+        let frame = response(vec![PingResponse {}.encode().unwrap()]);
+        let mut iter = CanceledIter::decode(&frame).unwrap();
+        assert_eq!(
+            iter.next().unwrap().unwrap_err(),
+            SbeErr::UnexpectedEmbeddedMessage {
+                field: "response",
+                template_id: Some(ping_response_codec::SBE_TEMPLATE_ID),
+            }
+        );
+    }
+
+    #[test]
+    fn truncated_entry_is_an_error() {
+        let mut frame = response(vec![order(1), order(2)]);
+        frame.truncate(frame.len() - 10);
+        let mut iter = CanceledIter::decode(&frame).unwrap();
+        assert!(matches!(iter.next(), Some(Ok(Canceled::Order(_)))));
+        assert!(matches!(
+            iter.next(),
+            Some(Err(SbeErr::BufferTooShort { .. }))
+        ));
+    }
+
+    #[test]
+    fn ends_after_the_first_error() {
+        let frame = response(vec![PingResponse {}.encode().unwrap(), order(1), order(2)]);
+        let mut iter = CanceledIter::decode(&frame).unwrap();
+        assert!(iter.next().unwrap().is_err());
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert!(iter.next().is_none());
+    }
+}
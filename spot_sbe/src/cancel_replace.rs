@@ -0,0 +1,323 @@
+//! Typed decoding of the messages embedded in a `CancelReplaceOrderResponse`.
+//!
+//! `cancelResponse` holds a `CancelOrderResponse` when `cancelResult` is
+//! `SUCCESS` and an `ErrorResponse` when it is `FAILURE`; `newOrderResponse`
+//! holds one of the `NewOrder[Ack|Result|Full]Response` messages or an
+//! `ErrorResponse` the same way. Either may be empty, and both are empty when
+//! their status is `NOT_ATTEMPTED`. Any other pairing is an error.
+//!
+//! When the cancel or the new order fails, the whole request fails with an
+//! `ErrorResponse` whose `data` holds the `CancelReplaceOrderResponse`, so
+//! [`CancelReplace::decode`] accepts either at the top level. Only that one
+//! level of nesting is allowed: the `ErrorResponse`s embedded in a
+//! `CancelReplaceOrderResponse` must have empty `data`.
+
+use crate::*;
+
+/// A `CancelReplaceOrderResponse`, or an `ErrorResponse` that may wrap one.
+#[derive(Clone, Debug)]
+pub enum CancelReplace<'a> {
+    Response(CancelReplaceResponse<'a>),
+    Error(ErrorReply<'a>),
+}
+
+impl<'a> CancelReplace<'a> {
+    /// Decodes the `CancelReplaceOrderResponse` or `ErrorResponse`, header
+    /// included, at the start of `data`.
+    pub fn decode(data: &'a [u8]) -> SbeResult<Self> {
+        match decode_any(data)? {
+            AnyMessage::CancelReplaceOrderResponse(decoder) => {
+                CancelReplaceResponse::from_decoder(decoder).map(Self::Response)
+            }
+            AnyMessage::ErrorResponse(decoder) => {
+                ErrorReply::from_decoder(decoder).map(Self::Error)
+            }
+            message => Err(SbeErr::UnexpectedTemplateId {
+                expected: cancel_replace_order_response_codec::SBE_TEMPLATE_ID,
+                actual: message.template_id(),
+            }),
+        }
+    }
+
+    /// The `CancelReplaceOrderResponse`, whether it is the message itself or
+    /// the `data` of an `ErrorResponse`.
+    pub fn response(&self) -> Option<&CancelReplaceResponse<'a>> {
+        match self {
+            Self::Response(response) => Some(response),
+            Self::Error(error) => error.cancel_replace.as_deref(),
+        }
+    }
+}
+
+/// A `CancelReplaceOrderResponse` with its embedded messages decoded.
+#[derive(Clone, Debug)]
+pub struct CancelReplaceResponse<'a> {
+    pub cancel_result: CancelReplaceStatus,
+    pub new_order_result: CancelReplaceStatus,
+    pub cancel: CancelOutcome<'a>,
+    pub new_order: NewOrderOutcome<'a>,
+}
+
+impl<'a> CancelReplaceResponse<'a> {
+    /// Decodes the embedded messages of a decoder positioned after its
+    /// header, checking each against its status.
+    pub fn from_decoder(mut decoder: CancelReplaceOrderResponseDecoder<'a>) -> SbeResult<Self> {
+        let data = decoder.get_buf().as_slice();
+        let cancel_result = decoder.cancel_result();
+        let new_order_result = decoder.new_order_result();
+        let (offset, length) = decoder.cancel_response_decoder()?;
+        let cancel_response = &data[offset..offset + length];
+        let (offset, length) = decoder.new_order_response_decoder()?;
+        let new_order_response = &data[offset..offset + length];
+
+        let field = "cancelResponse";
+        let cancel = match embedded(field, cancel_result, cancel_response)? {
+            Embedded::None(status) => match status {
+                CancelReplaceStatus::Success => CancelOutcome::Success(None),
+                CancelReplaceStatus::Failure => CancelOutcome::Failure(None),
+                _ => CancelOutcome::NotAttempted,
+            },
+            Embedded::Error(error) => CancelOutcome::Failure(Some(error)),
+            Embedded::Message(AnyMessage::CancelOrderResponse(decoder)) => {
+                CancelOutcome::Success(Some(decoder))
+            }
+            Embedded::Message(message) => return Err(unexpected(field, &message)),
+        };
+
+        let field = "newOrderResponse";
+        let new_order = match embedded(field, new_order_result, new_order_response)? {
+            Embedded::None(status) => match status {
+                CancelReplaceStatus::Success => NewOrderOutcome::Success(None),
+                CancelReplaceStatus::Failure => NewOrderOutcome::Failure(None),
+                _ => NewOrderOutcome::NotAttempted,
+            },
+            Embedded::Error(error) => NewOrderOutcome::Failure(Some(error)),
+            Embedded::Message(AnyMessage::NewOrderAckResponse(decoder)) => {
+                NewOrderOutcome::Success(Some(NewOrderResponse::Ack(decoder)))
+            }
+            Embedded::Message(AnyMessage::NewOrderResultResponse(decoder)) => {
+                NewOrderOutcome::Success(Some(NewOrderResponse::Result(decoder)))
+            }
+            Embedded::Message(AnyMessage::NewOrderFullResponse(decoder)) => {
+                NewOrderOutcome::Success(Some(NewOrderResponse::Full(decoder)))
+            }
+            Embedded::Message(message) => return Err(unexpected(field, &message)),
+        };
+
+        Ok(Self {
+            cancel_result,
+            new_order_result,
+            cancel,
+            new_order,
+        })
+    }
+}
+
+/// What became of the order to cancel.
+#[derive(Clone, Debug)]
+pub enum CancelOutcome<'a> {
+    /// `None` when `cancelResponse` was empty
+    Success(Option<CancelOrderResponseDecoder<'a>>),
+    /// `None` when `cancelResponse` was empty
+    Failure(Option<ErrorReply<'a>>),
+    NotAttempted,
+}
+
+/// What became of the order to place.
+#[derive(Clone, Debug)]
+pub enum NewOrderOutcome<'a> {
+    /// `None` when `newOrderResponse` was empty
+    Success(Option<NewOrderResponse<'a>>),
+    /// `None` when `newOrderResponse` was empty
+    Failure(Option<ErrorReply<'a>>),
+    NotAttempted,
+}
+
+/// The response to the new order, as chosen by `newOrderRespType`.
+#[derive(Clone, Copy, Debug)]
+pub enum NewOrderResponse<'a> {
+    Ack(NewOrderAckResponseDecoder<'a>),
+    Result(NewOrderResultResponseDecoder<'a>),
+    Full(NewOrderFullResponseDecoder<'a>),
+}
+
+impl NewOrderResponse<'_> {
+    #[inline]
+    pub fn order_id(&self) -> i64 {
+        match self {
+            Self::Ack(decoder) => decoder.order_id(),
+            Self::Result(decoder) => decoder.order_id(),
+            Self::Full(decoder) => decoder.order_id(),
+        }
+    }
+
+    /// `None` for an ACK response, which does not report it.
+    #[inline]
+    pub fn status(&self) -> Option<OrderStatus> {
+        match self {
+            Self::Ack(_) => None,
+            Self::Result(decoder) => Some(decoder.status()),
+            Self::Full(decoder) => Some(decoder.status()),
+        }
+    }
+}
+
+/// An `ErrorResponse`, with the `CancelReplaceOrderResponse` its `data` may
+/// hold.
+#[derive(Clone, Debug)]
+pub struct ErrorReply<'a> {
+    /// For `code`, `serverTime` and `retryAfter`
+    pub decoder: ErrorResponseDecoder<'a>,
+    pub msg: &'a [u8],
+    pub cancel_replace: Option<Box<CancelReplaceResponse<'a>>>,
+}
+
+impl<'a> ErrorReply<'a> {
+    /// Reads `msg` and `data` from a decoder positioned after its header;
+    /// `decoder` is kept as it was given.
+    pub fn from_decoder(decoder: ErrorResponseDecoder<'a>) -> SbeResult<Self> {
+        Self::decode(decoder, true)
+    }
+
+    /// As [`Self::from_decoder`], but `data` must be empty unless `nested`
+    /// allows it to hold a `CancelReplaceOrderResponse`.
+    fn decode(decoder: ErrorResponseDecoder<'a>, nested: bool) -> SbeResult<Self> {
+        let data = decoder.get_buf().as_slice();
+        let mut reader = decoder;
+        let (offset, length) = reader.msg_decoder()?;
+        let msg = &data[offset..offset + length];
+        let (offset, length) = reader.data_decoder()?;
+        let cancel_replace = match &data[offset..offset + length] {
+            [] => None,
+            embedded => match decode_any(embedded)? {
+                AnyMessage::CancelReplaceOrderResponse(decoder) if nested => {
+                    Some(Box::new(CancelReplaceResponse::from_decoder(decoder)?))
+                }
+                message => return Err(unexpected("data", &message)),
+            },
+        };
+        Ok(Self {
+            decoder,
+            msg,
+            cancel_replace,
+        })
+    }
+
+    /// `msg` as a string, which the schema declares as UTF-8.
+    pub fn msg(&self) -> SbeResult<&'a str> {
+        core::str::from_utf8(self.msg).map_err(|_| SbeErr::InvalidUtf8)
+    }
+}
+
+enum Embedded<'a> {
+    /// Nothing embedded; the status is valid
+    None(CancelReplaceStatus),
+    Error(ErrorReply<'a>),
+    /// Any message but an `ErrorResponse`, when the status is `SUCCESS`
+    Message(AnyMessage<'a>),
+}
+
+/// Decodes `data` and checks that `status` allows an embedded message of its
+/// kind.
+fn embedded<'a>(
+    field: &'static str,
+    status: CancelReplaceStatus,
+    data: &'a [u8],
+) -> SbeResult<Embedded<'a>> {
+    use CancelReplaceStatus as S;
+    if data.is_empty() {
+        return match status {
+            S::Success | S::Failure | S::NotAttempted => Ok(Embedded::None(status)),
+            S::NullVal => Err(SbeErr::UnexpectedEmbeddedMessage {
+                field,
+                template_id: None,
+            }),
+        };
+    }
+    let message = decode_any(data)?;
+    match (status, message) {
+        (S::Failure, AnyMessage::ErrorResponse(decoder)) => {
+            ErrorReply::decode(decoder, false).map(Embedded::Error)
+        }
+        (S::Success, AnyMessage::ErrorResponse(_)) => Err(unexpected(field, &message)),
+        (S::Success, message) => Ok(Embedded::Message(message)),
+        (S::Failure | S::NotAttempted | S::NullVal, message) => Err(unexpected(field, &message)),
+    }
+}
+
+fn unexpected(field: &'static str, message: &AnyMessage<'_>) -> SbeErr {
+    SbeErr::UnexpectedEmbeddedMessage {
+        field,
+        template_id: Some(message.template_id()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_response(data: &[u8]) -> Vec<u8> {
+        let mut buf = vec![0; 128 + data.len()];
+        let encoder = ErrorResponseEncoder::default().wrap(
+            WriteBuf::new(&mut buf),
+            message_header_codec::ENCODED_LENGTH,
+        );
+        let mut encoder = encoder.header(0).parent().unwrap();
+        encoder.code(-2021);
+        encoder.msg("Order cancel-replace partially failed.");
+        encoder.data(data);
+        let length = encoder.get_limit();
+        buf.truncate(length);
+        buf
+    }
+
+    fn cancel_replace_response(cancel_response: &[u8]) -> Vec<u8> {
+        let mut buf = vec![0; 64 + cancel_response.len()];
+        let encoder = CancelReplaceOrderResponseEncoder::default().wrap(
+            WriteBuf::new(&mut buf),
+            message_header_codec::ENCODED_LENGTH,
+        );
+        let mut encoder = encoder.header(0).parent().unwrap();
+        encoder.cancel_result(CancelReplaceStatus::Failure);
+        encoder.new_order_result(CancelReplaceStatus::NotAttempted);
+        encoder.cancel_response(cancel_response);
+        encoder.new_order_response(&[]);
+        let length = encoder.get_limit();
+        buf.truncate(length);
+        buf
+    }
+
+    #[test]
+    fn error_wrapping_cancel_replace_is_decoded() {
+        let frame = error_response(&cancel_replace_response(&error_response(&[])));
+        let CancelReplace::Error(error) = CancelReplace::decode(&frame).unwrap() else {
+            panic!("expected an error reply");
+        };
+        assert_eq!(error.decoder.code(), -2021);
+        let response = error.cancel_replace.as_deref().unwrap();
+        assert!(matches!(response.cancel, CancelOutcome::Failure(Some(_))));
+        assert!(matches!(response.new_order, NewOrderOutcome::NotAttempted));
+    }
+
+    #[test]
+    fn embedded_error_with_data_is_rejected() {
+        let inner = error_response(&cancel_replace_response(&error_response(&[])));
+        let frame = error_response(&cancel_replace_response(&inner));
+        assert_eq!(
+            CancelReplace::decode(&frame).unwrap_err(),
+            SbeErr::UnexpectedEmbeddedMessage {
+                field: "data",
+                template_id: Some(cancel_replace_order_response_codec::SBE_TEMPLATE_ID),
+            }
+        );
+    }
+
+    #[test]
+    fn deep_nesting_does_not_recurse() {
+        let mut frame = error_response(&[]);
+        for _ in 0..1_000 {
+            frame = error_response(&cancel_replace_response(&frame));
+        }
+        assert!(CancelReplace::decode(&frame).is_err());
+    }
+}
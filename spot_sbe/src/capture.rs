@@ -0,0 +1,347 @@
+//! Capture files: append-only recordings of SBE traffic.
+//!
+//! A capture starts with the 8-byte magic `SBECAP\r\n` and a little-endian
+//! `u16` format version, followed by records one after another:
+//!
+//! | Offset | Size | Field                                              |
+//! |--------|------|----------------------------------------------------|
+//! | 0      | 8    | receive time, microseconds since the Unix epoch    |
+//! | 8      | 4    | connection ID                                      |
+//! | 12     | 1    | direction: 0 inbound, 1 outbound                   |
+//! | 13     | 4    | frame length                                       |
+//! | 17     | n    | frame, as sent or received                         |
+//!
+//! All integers are little-endian. A record is only complete once its whole
+//! frame is written, so a capture cut short by a crash ends in a partial
+//! record: [`Capture`] leaves it out of its index, and
+//! [`CaptureWriter::append`] drops it before appending.
+
+use crate::*;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const MAGIC: &[u8; 8] = b"SBECAP\r\n";
+pub const FORMAT_VERSION: u16 = 1;
+pub const FILE_HEADER_LENGTH: usize = MAGIC.len() + 2;
+pub const RECORD_HEADER_LENGTH: usize = 17;
+
+/// Which way a frame went, as seen by the side that recorded it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Received from the server
+    Inbound,
+    /// Sent to the server
+    Outbound,
+}
+
+impl Direction {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Inbound),
+            1 => Some(Self::Outbound),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn as_u8(self) -> u8 {
+        match self {
+            Self::Inbound => 0,
+            Self::Outbound => 1,
+        }
+    }
+}
+
+/// One recorded frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Record<'a> {
+    /// Microseconds since the Unix epoch
+    pub received_at: i64,
+    pub connection_id: u32,
+    pub direction: Direction,
+    /// The frame as sent or received: an SBE message, header included, or
+    /// the JSON text of a request.
+    pub frame: &'a [u8],
+}
+
+impl<'a> Record<'a> {
+    /// Header of the SBE message in the frame.
+    pub fn header(&self) -> SbeResult<MessageHeaderDecoder<ReadBuf<'a>>> {
+        MessageHeaderDecoder::default().wrap(ReadBuf::new(self.frame), 0)
+    }
+
+    /// Decoder for the SBE message in the frame, from whichever schema its
+    /// header names.
+    pub fn message(&self) -> SbeResult<VersionedMessage<'a>> {
+        VersionedMessage::from_header(self.header()?)
+    }
+}
+
+/// The current time as a record's `received_at`: microseconds since the
+/// Unix epoch.
+pub fn now_us() -> i64 {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    i64::try_from(elapsed.as_micros()).unwrap_or(i64::MAX)
+}
+
+/// A capture read into memory, with the offset of every complete record.
+#[derive(Clone, Debug)]
+pub struct Capture {
+    data: Vec<u8>,
+    offsets: Vec<usize>,
+    /// Start of the partial record at the end, if any
+    end: usize,
+}
+
+impl Capture {
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_bytes(std::fs::read(path)?)
+    }
+
+    /// Indexes a whole capture, file header included.
+    pub fn from_bytes(data: Vec<u8>) -> io::Result<Self> {
+        check_file_header(&data)?;
+        let (offsets, end) = index(&data)?;
+        Ok(Self { data, offsets, end })
+    }
+
+    /// Number of complete records.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Record<'_>> {
+        let offset = *self.offsets.get(index)?;
+        // Indexed records were read once already, and are complete.
+        read_record(&self.data[offset..]).ok().flatten()
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Record<'_>> + '_ {
+        (0..self.len()).map(|index| self.get(index).unwrap())
+    }
+
+    /// Length of the partial record the capture ends with, which is not
+    /// indexed; zero for a capture that was closed cleanly.
+    #[inline]
+    pub fn truncated_bytes(&self) -> usize {
+        self.data.len() - self.end
+    }
+}
+
+/// Appends records to a capture.
+#[derive(Debug)]
+pub struct CaptureWriter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> CaptureWriter<W> {
+    /// Starts a new capture on `inner` by writing its file header.
+    pub fn new(mut inner: W) -> io::Result<Self> {
+        write_file_header(&mut inner)?;
+        Ok(Self { inner })
+    }
+
+    /// Writes one record. Nothing is buffered beyond what `inner` buffers.
+    pub fn write(&mut self, record: &Record<'_>) -> io::Result<()> {
+        let length = u32::try_from(record.frame.len()).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidInput, "frame longer than u32::MAX")
+        })?;
+        let mut header = [0; RECORD_HEADER_LENGTH];
+        header[0..8].copy_from_slice(&record.received_at.to_le_bytes());
+        header[8..12].copy_from_slice(&record.connection_id.to_le_bytes());
+        header[12] = record.direction.as_u8();
+        header[13..17].copy_from_slice(&length.to_le_bytes());
+        self.inner.write_all(&header)?;
+        self.inner.write_all(record.frame)
+    }
+
+    #[inline]
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl CaptureWriter<File> {
+    /// Opens a capture to append to, creating it if needed. A partial record
+    /// left at its end is dropped first.
+    pub fn append(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)?;
+        if data.is_empty() {
+            return Self::new(file);
+        }
+        check_file_header(&data)?;
+        let (_, end) = index(&data)?;
+        file.set_len(end as u64)?;
+        file.seek(SeekFrom::Start(end as u64))?;
+        Ok(Self { inner: file })
+    }
+}
+
+fn write_file_header(out: &mut impl Write) -> io::Result<()> {
+    out.write_all(MAGIC)?;
+    out.write_all(&FORMAT_VERSION.to_le_bytes())
+}
+
+fn check_file_header(data: &[u8]) -> io::Result<()> {
+    if data.len() < FILE_HEADER_LENGTH || &data[..MAGIC.len()] != MAGIC {
+        return Err(invalid_data("not a capture file".into()));
+    }
+    let version = u16::from_le_bytes([data[MAGIC.len()], data[MAGIC.len() + 1]]);
+    if version != FORMAT_VERSION {
+        return Err(invalid_data(format!(
+            "unsupported capture format version {version}"
+        )));
+    }
+    Ok(())
+}
+
+/// Offsets of the complete records, and the end of the last one.
+fn index(data: &[u8]) -> io::Result<(Vec<usize>, usize)> {
+    let mut offsets = Vec::new();
+    let mut offset = FILE_HEADER_LENGTH;
+    while let Some(record) = read_record(&data[offset..])? {
+        offsets.push(offset);
+        offset += RECORD_HEADER_LENGTH + record.frame.len();
+    }
+    Ok((offsets, offset))
+}
+
+/// The record at the start of `data`, or `None` if it is incomplete.
+fn read_record(data: &[u8]) -> io::Result<Option<Record<'_>>> {
+    let Some(header) = data.get(..RECORD_HEADER_LENGTH) else {
+        return Ok(None);
+    };
+    let field = |range: core::ops::Range<usize>| &header[range];
+    let received_at = i64::from_le_bytes(field(0..8).try_into().unwrap());
+    let connection_id = u32::from_le_bytes(field(8..12).try_into().unwrap());
+    let direction = Direction::from_u8(header[12])
+        .ok_or_else(|| invalid_data(format!("invalid record direction {}", header[12])))?;
+    let length = u32::from_le_bytes(field(13..17).try_into().unwrap()) as usize;
+    let Some(frame) = data.get(RECORD_HEADER_LENGTH..RECORD_HEADER_LENGTH + length) else {
+        return Ok(None);
+    };
+    Ok(Some(Record {
+        received_at,
+        connection_id,
+        direction,
+        frame,
+    }))
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PING: [u8; 8] = [0, 0, 101, 0, 2, 0, 1, 0];
+
+    fn records() -> [Record<'static>; 2] {
+        [
+            Record {
+                received_at: 1_700_000_000_000_000,
+                connection_id: 7,
+                direction: Direction::Outbound,
+                frame: br#"{"id":"1","method":"ping"}"#,
+            },
+            Record {
+                received_at: 1_700_000_000_000_250,
+                connection_id: 7,
+                direction: Direction::Inbound,
+                frame: &PING,
+            },
+        ]
+    }
+
+    fn capture_bytes() -> Vec<u8> {
+        let mut writer = CaptureWriter::new(Vec::new()).unwrap();
+        for record in records() {
+            writer.write(&record).unwrap();
+        }
+        writer.into_inner()
+    }
+
+    #[test]
+    fn records_round_trip() {
+        let data = capture_bytes();
+        assert_eq!(&data[..MAGIC.len()], MAGIC);
+        assert_eq!(
+            data.len(),
+            FILE_HEADER_LENGTH
+                + 2 * RECORD_HEADER_LENGTH
+                + records().map(|r| r.frame.len()).iter().sum::<usize>()
+        );
+        let capture = Capture::from_bytes(data).unwrap();
+        assert_eq!(capture.len(), 2);
+        assert_eq!(capture.truncated_bytes(), 0);
+        assert!(capture.iter().eq(records()));
+        let ping = capture.get(1).unwrap();
+        assert_eq!(ping.header().unwrap().template_id(), 101);
+        assert!(ping.message().is_ok());
+        assert_eq!(capture.get(2), None);
+    }
+
+    #[test]
+    fn partial_final_record_is_left_out() {
+        let data = capture_bytes();
+        for cut in 1..RECORD_HEADER_LENGTH + PING.len() {
+            let capture = Capture::from_bytes(data[..data.len() - cut].to_vec()).unwrap();
+            assert_eq!(capture.len(), 1);
+            assert_eq!(
+                capture.truncated_bytes(),
+                RECORD_HEADER_LENGTH + PING.len() - cut
+            );
+        }
+    }
+
+    #[test]
+    fn append_drops_a_partial_record() {
+        let path =
+            std::env::temp_dir().join(format!("spot_sbe-capture-{}.sbecap", std::process::id()));
+        let data = capture_bytes();
+        std::fs::write(&path, &data[..data.len() - 3]).unwrap();
+        let mut writer = CaptureWriter::append(&path).unwrap();
+        writer.write(&records()[1]).unwrap();
+        drop(writer);
+        let capture = Capture::open(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(capture.iter().eq(records()));
+        assert_eq!(capture.truncated_bytes(), 0);
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        let kind = |data: Vec<u8>| Capture::from_bytes(data).unwrap_err().kind();
+        assert_eq!(kind(b"not a capture".to_vec()), io::ErrorKind::InvalidData);
+        let mut data = capture_bytes();
+        data[MAGIC.len()] = 2;
+        assert_eq!(kind(data), io::ErrorKind::InvalidData);
+        let mut data = capture_bytes();
+        data[FILE_HEADER_LENGTH + 12] = 9;
+        assert_eq!(kind(data), io::ErrorKind::InvalidData);
+    }
+}
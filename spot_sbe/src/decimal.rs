@@ -0,0 +1,463 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+/// Decimal floating point number `mantissa * 10^exponent`, as carried on the
+/// wire by a mantissa field and the `exponent8` field named by its
+/// `mbx:exponent` attribute.
+///
+/// Equality, ordering and hashing are by value, so `1.0` (10, -1) and
+/// `1.00` (100, -2) compare equal. Formatting keeps the scale of the
+/// encoded value.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Decimal {
+    mantissa: i128,
+    exponent: i8,
+}
+
+/// Rounding mode used by [`Decimal::round_to_tick`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round to the nearest multiple, ties away from zero.
+    Nearest,
+}
+
+/// Error returned when parsing a [`Decimal`] from a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseDecimalError {
+    Empty,
+    InvalidDigit,
+    Overflow,
+}
+
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("cannot parse decimal from empty string"),
+            Self::InvalidDigit => f.write_str("invalid digit found in decimal string"),
+            Self::Overflow => f.write_str("decimal out of range"),
+        }
+    }
+}
+
+impl std::error::Error for ParseDecimalError {}
+
+impl Decimal {
+    pub const ZERO: Self = Self {
+        mantissa: 0,
+        exponent: 0,
+    };
+
+    #[inline]
+    pub fn new(mantissa: impl Into<i128>, exponent: i8) -> Self {
+        Self {
+            mantissa: mantissa.into(),
+            exponent,
+        }
+    }
+
+    #[inline]
+    pub const fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    #[inline]
+    pub const fn exponent(&self) -> i8 {
+        self.exponent
+    }
+
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    #[inline]
+    pub const fn is_sign_negative(&self) -> bool {
+        self.mantissa < 0
+    }
+
+    /// Strips trailing zeros from the mantissa, e.g. (1500, -3) becomes (15, -1).
+    pub fn normalize(&self) -> Self {
+        if self.mantissa == 0 {
+            return Self::ZERO;
+        }
+        let mut value = *self;
+        while value.mantissa % 10 == 0 && value.exponent < i8::MAX {
+            value.mantissa /= 10;
+            value.exponent += 1;
+        }
+        value
+    }
+
+    /// Returns the same value expressed with `exponent`, or `None` if that
+    /// would lose precision or overflow the mantissa.
+    pub fn rescale(&self, exponent: i8) -> Option<Self> {
+        if self.mantissa == 0 {
+            return Some(Self::new(0, exponent));
+        }
+        let diff = i32::from(self.exponent) - i32::from(exponent);
+        let mantissa = match diff.cmp(&0) {
+            Ordering::Equal => self.mantissa,
+            Ordering::Greater => self.mantissa.checked_mul(pow10(diff.unsigned_abs())?)?,
+            Ordering::Less => {
+                let factor = pow10(diff.unsigned_abs())?;
+                if self.mantissa % factor != 0 {
+                    return None;
+                }
+                self.mantissa / factor
+            }
+        };
+        Some(Self { mantissa, exponent })
+    }
+
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        let (a, b) = align(self, &other)?;
+        Some(Self::new(a.mantissa.checked_add(b.mantissa)?, a.exponent))
+    }
+
+    pub fn checked_sub(&self, other: Self) -> Option<Self> {
+        self.checked_add(Self::new(other.mantissa.checked_neg()?, other.exponent))
+    }
+
+    pub fn checked_mul(&self, other: Self) -> Option<Self> {
+        let mantissa = self.mantissa.checked_mul(other.mantissa)?;
+        let exponent = self.exponent.checked_add(other.exponent)?;
+        Some(Self { mantissa, exponent })
+    }
+
+    /// Rounds to a multiple of `tick` (e.g. a `tickSize` or `stepSize`),
+    /// returning a value with the tick's exponent. Returns `None` if `tick`
+    /// is not positive or on overflow.
+    pub fn round_to_tick(&self, tick: Self, rounding: Rounding) -> Option<Self> {
+        if tick.mantissa <= 0 {
+            return None;
+        }
+        let (value, step) = align(self, &tick)?;
+        let quotient = value.mantissa.div_euclid(step.mantissa);
+        let remainder = value.mantissa.rem_euclid(step.mantissa);
+        let round_up = match rounding {
+            Rounding::Floor => false,
+            Rounding::Ceil => remainder != 0,
+            Rounding::Nearest => {
+                let twice = remainder.checked_mul(2)?;
+                match twice.cmp(&step.mantissa) {
+                    Ordering::Greater => true,
+                    Ordering::Less => false,
+                    // tie: away from zero
+                    Ordering::Equal => !value.is_sign_negative(),
+                }
+            }
+        };
+        let quotient = if round_up {
+            quotient.checked_add(1)?
+        } else {
+            quotient
+        };
+        Some(Self::new(
+            quotient.checked_mul(tick.mantissa)?,
+            tick.exponent,
+        ))
+    }
+
+    /// Nearest `f64`; lossy, intended for display and statistics only.
+    pub fn to_f64(&self) -> f64 {
+        format!("{}e{}", self.mantissa, self.exponent)
+            .parse()
+            .unwrap_or(f64::NAN)
+    }
+}
+
+#[inline]
+fn pow10(exp: u32) -> Option<i128> {
+    10_i128.checked_pow(exp)
+}
+
+/// Expresses both values with the smaller of their exponents.
+fn align(a: &Decimal, b: &Decimal) -> Option<(Decimal, Decimal)> {
+    let exponent = a.exponent.min(b.exponent);
+    Some((a.rescale(exponent)?, b.rescale(exponent)?))
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match align(self, other) {
+            Some((a, b)) => a.mantissa.cmp(&b.mantissa),
+            // rescaling the coarser value overflowed, so its magnitude dominates
+            None if self.exponent > other.exponent => self.mantissa.signum().cmp(&0),
+            None => 0.cmp(&other.mantissa.signum()),
+        }
+    }
+}
+
+impl Hash for Decimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let value = self.normalize();
+        value.mantissa.hash(state);
+        value.exponent.hash(state);
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let s = if self.mantissa == 0 {
+            if self.exponent < 0 {
+                format!("0.{}", "0".repeat(self.exponent.unsigned_abs() as usize))
+            } else {
+                "0".to_string()
+            }
+        } else if self.exponent >= 0 {
+            format!("{sign}{digits}{}", "0".repeat(self.exponent as usize))
+        } else {
+            let scale = self.exponent.unsigned_abs() as usize;
+            if digits.len() > scale {
+                let (int, frac) = digits.split_at(digits.len() - scale);
+                format!("{sign}{int}.{frac}")
+            } else {
+                format!("{sign}0.{}{digits}", "0".repeat(scale - digits.len()))
+            }
+        };
+        f.pad(&s)
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ParseDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (s, negative) = match s.as_bytes().first() {
+            None => return Err(ParseDecimalError::Empty),
+            Some(b'-') => (&s[1..], true),
+            Some(b'+') => (&s[1..], false),
+            Some(_) => (s, false),
+        };
+        let (number, exp) = match s.find(['e', 'E']) {
+            Some(i) => {
+                let exp = s[i + 1..]
+                    .parse::<i32>()
+                    .map_err(|_| ParseDecimalError::InvalidDigit)?;
+                (&s[..i], exp)
+            }
+            None => (s, 0),
+        };
+        let (int, frac) = number.split_once('.').unwrap_or((number, ""));
+        if int.is_empty() && frac.is_empty() {
+            return Err(ParseDecimalError::Empty);
+        }
+        let mut mantissa: i128 = 0;
+        for b in int.bytes().chain(frac.bytes()) {
+            if !b.is_ascii_digit() {
+                return Err(ParseDecimalError::InvalidDigit);
+            }
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(i128::from(b - b'0')))
+                .ok_or(ParseDecimalError::Overflow)?;
+        }
+        if negative {
+            mantissa = -mantissa;
+        }
+        let exponent = i64::from(exp) - frac.len() as i64;
+        let exponent = i8::try_from(exponent).map_err(|_| ParseDecimalError::Overflow)?;
+        Ok(Self { mantissa, exponent })
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl TryFrom<Decimal> for rust_decimal::Decimal {
+    type Error = rust_decimal::Error;
+
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        // rust_decimal has no positive exponents and at most 28 places, so
+        // drop trailing zeros first: (10^30, -29) is 10, not an error
+        let value = if value.exponent < -28 {
+            value.normalize()
+        } else {
+            value
+        };
+        let value = if value.exponent > 0 {
+            value
+                .rescale(0)
+                .ok_or(rust_decimal::Error::ExceedsMaximumPossibleValue)?
+        } else {
+            value
+        };
+        rust_decimal::Decimal::try_from_i128_with_scale(
+            value.mantissa,
+            value.exponent.unsigned_abs() as u32,
+        )
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for Decimal {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Self::new(value.mantissa(), -(value.scale() as i8))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Decimal {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Decimal {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Decimal;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a decimal number or string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Decimal, E> {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Decimal, E> {
+                Ok(Decimal::new(v, 0))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Decimal, E> {
+                Ok(Decimal::new(v, 0))
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Decimal, E> {
+                // `{:e}` is the shortest representation that round-trips,
+                // so 0.1 becomes (1, -1) rather than its binary expansion
+                if !v.is_finite() {
+                    return Err(E::invalid_value(serde::de::Unexpected::Float(v), &self));
+                }
+                format!("{v:e}").parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_and_display_keep_scale() {
+        assert_eq!(d("1.50").to_string(), "1.50");
+        assert_eq!(d("1.50").exponent(), -2);
+        assert_eq!(d("-0.5e3"), Decimal::new(-500, 0));
+        assert_eq!(Decimal::new(5, -3).to_string(), "0.005");
+        assert_eq!(Decimal::new(0, -2).to_string(), "0.00");
+        assert_eq!(Decimal::new(12, 2).to_string(), "1200");
+        assert_eq!(format!("{:>6}", d("1.5")), "   1.5");
+        assert_eq!("".parse::<Decimal>(), Err(ParseDecimalError::Empty));
+        assert_eq!(".".parse::<Decimal>(), Err(ParseDecimalError::Empty));
+        assert_eq!(
+            "1a".parse::<Decimal>(),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+        assert_eq!("1e200".parse::<Decimal>(), Err(ParseDecimalError::Overflow));
+    }
+
+    #[test]
+    fn equality_is_by_value() {
+        assert_eq!(d("1.0"), d("1.00"));
+        assert!(d("0.1") < d("0.11"));
+        assert!(Decimal::new(i128::MAX, 0) > Decimal::new(1, -30));
+        assert_eq!(d("1500e-3").normalize(), Decimal::new(15, -1));
+        assert_eq!(d("1500e-3").normalize().exponent(), -1);
+    }
+
+    #[test]
+    fn arithmetic_aligns_exponents() {
+        assert_eq!(d("1.5").checked_add(d("0.25")).unwrap().to_string(), "1.75");
+        assert_eq!(d("0.1").checked_sub(d("0.3")).unwrap().to_string(), "-0.2");
+        assert_eq!(d("1.5").checked_mul(d("2")).unwrap().to_string(), "3.0");
+        assert_eq!(d("1.5").rescale(-3), Some(Decimal::new(1500, -3)));
+        assert_eq!(d("1.5").rescale(0), None);
+        assert_eq!(Decimal::new(i128::MAX, 0).checked_add(d("1")), None);
+        assert_eq!(Decimal::new(1, 0).checked_add(Decimal::new(1, -100)), None);
+    }
+
+    #[test]
+    fn round_to_tick() {
+        let tick = d("0.01");
+        let cases = [
+            ("1.2345", Rounding::Floor, "1.23"),
+            ("1.2345", Rounding::Ceil, "1.24"),
+            ("1.2345", Rounding::Nearest, "1.23"),
+            ("1.235", Rounding::Nearest, "1.24"),
+            ("-1.235", Rounding::Nearest, "-1.24"),
+            ("-1.2345", Rounding::Floor, "-1.24"),
+            ("-1.2345", Rounding::Ceil, "-1.23"),
+            ("1.2", Rounding::Ceil, "1.20"),
+        ];
+        for (value, rounding, expected) in cases {
+            let rounded = d(value).round_to_tick(tick, rounding).unwrap();
+            assert_eq!(rounded.to_string(), expected, "{value} {rounding:?}");
+        }
+        assert_eq!(d("1").round_to_tick(d("0"), Rounding::Floor), None);
+        assert_eq!(d("1").round_to_tick(d("-0.1"), Rounding::Floor), None);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn converts_to_rust_decimal() {
+        let convert = |value: Decimal| rust_decimal::Decimal::try_from(value);
+        assert_eq!(
+            convert(Decimal::new(10_i128.pow(30), -29)),
+            Ok(rust_decimal::Decimal::new(10, 0))
+        );
+        assert_eq!(
+            convert(Decimal::new(15, 2)),
+            Ok(rust_decimal::Decimal::new(1500, 0))
+        );
+        assert_eq!(convert(d("1.50")), Ok(rust_decimal::Decimal::new(150, 2)));
+        assert!(convert(Decimal::new(1, -29)).is_err());
+        assert_eq!(
+            Decimal::from(rust_decimal::Decimal::new(150, 2)),
+            Decimal::new(150, -2)
+        );
+    }
+
+    #[cfg(all(feature = "serde", feature = "json"))]
+    #[test]
+    fn deserializes_strings_and_numbers() {
+        let parse = |json: &str| serde_json::from_str::<Decimal>(json).unwrap();
+        assert_eq!(parse("\"1.50\"").exponent(), -2);
+        assert_eq!(parse("42"), Decimal::new(42, 0));
+        assert_eq!(parse("-7"), Decimal::new(-7, 0));
+        assert_eq!(parse("0.1"), Decimal::new(1, -1));
+        assert_eq!(parse("123.456"), Decimal::new(123456, -3));
+        assert_eq!(parse("1e-7"), Decimal::new(1, -7));
+        assert!(serde_json::from_str::<Decimal>("true").is_err());
+    }
+}
@@ -0,0 +1,1178 @@
+//! Converts between SBE messages and the JSON the non-SBE API returns,
+//! following the schema's `mbx:jsonPath`, `mbx:jsonValue`,
+//! `mbx:jsonDefaultValue` and `mbx:jsonOmitNull` attributes:
+//!
+//! - `jsonPath="x"` renames a member, `"a.b"` nests it in an object,
+//!   `"[]"` renders the entry as an array of its members and `".."`
+//!   replaces the enclosing value with the member itself.
+//! - Exponent fields are folded into their mantissas, which are rendered as
+//!   decimal strings.
+//! - Enums and sets are rendered by `jsonValue`; `boolEnum` as a boolean.
+//! - User data stream events lead with their event type, e.g.
+//!   `"e":"executionReport"`.
+//! - Optional var strings, whose null is the empty string, render as `""`.
+//!
+//! [`from_json`] reverses the mapping. Each exponent field is set to the
+//! finest scale of the decimals that use it unless the JSON object carries
+//! it explicitly under the field's name, and optional fields equal to their
+//! `jsonDefaultValue` are encoded as null. An event's `"e"` may be left out,
+//! but must name the event when present.
+
+use crate::message_header_codec::ENCODED_LENGTH;
+use crate::meta::*;
+use crate::{schema_for, Decimal, ReadBuf, SbeErr, SbeResult, WriteBuf};
+use serde_json::{Map, Value};
+
+/// Messages embedded deeper than this, e.g. in the `data` of an
+/// `ErrorResponse` in the `result` of a `WebSocketResponse`, are rejected.
+const MAX_DEPTH: usize = 8;
+
+/// Renders the message (header included) at the start of `data`.
+pub fn to_json(data: &[u8]) -> SbeResult<Value> {
+    render_message(ReadBuf::new(data), 0, 0).map(|(value, _)| value)
+}
+
+pub fn to_json_string(data: &[u8]) -> SbeResult<String> {
+    to_json(data).map(|value| value.to_string())
+}
+
+pub fn to_json_string_pretty(data: &[u8]) -> SbeResult<String> {
+    to_json(data).map(|value| format!("{value:#}"))
+}
+
+/// Returns the rendered message and the offset just past its last byte.
+fn render_message(buf: ReadBuf<'_>, offset: usize, depth: usize) -> SbeResult<(Value, usize)> {
+    if depth > MAX_DEPTH {
+        return Err(SbeErr::NestingTooDeep { depth: MAX_DEPTH });
+    }
+    buf.check_bounds(offset, ENCODED_LENGTH)?;
+    let block_length = buf.get_u16_at(offset) as usize;
+    let template_id = buf.get_u16_at(offset + 2);
+    let version = buf.get_u16_at(offset + 6);
+    let meta = schema_for(buf.get_u16_at(offset + 4), version)?
+        .message_by_id(template_id)
+        .ok_or(SbeErr::UnknownTemplateId { template_id })?;
+
+    let offset = offset + ENCODED_LENGTH;
+    buf.check_bounds(offset, block_length)?;
+    let mut renderer = Renderer {
+        buf,
+        version,
+        limit: offset + block_length,
+        depth,
+        exponents: Vec::new(),
+    };
+    let block = Block {
+        fields: meta.fields,
+        groups: meta.groups,
+        data: meta.data,
+        offset,
+        length: block_length,
+    };
+    let value = match (renderer.render_block(&block)?, meta.event_type) {
+        (Value::Object(members), Some(event_type)) => {
+            let mut object = Map::new();
+            object.insert("e".into(), event_type.into());
+            object.extend(members);
+            Value::Object(object)
+        }
+        (value, _) => value,
+    };
+    Ok((value, renderer.limit))
+}
+
+struct Block {
+    fields: &'static [FieldMeta],
+    groups: &'static [GroupMeta],
+    data: &'static [DataMeta],
+    offset: usize,
+    /// Acting block length from the message or group header
+    length: usize,
+}
+
+struct Renderer<'a> {
+    buf: ReadBuf<'a>,
+    /// Acting version from the message header
+    version: u16,
+    /// Position of the next group header or var data
+    limit: usize,
+    /// Number of messages this one is embedded in
+    depth: usize,
+    /// Exponents of the enclosing blocks, innermost last
+    exponents: Vec<(&'static str, i8)>,
+}
+
+impl Renderer<'_> {
+    fn render_block(&mut self, block: &Block) -> SbeResult<Value> {
+        let scope = self.exponents.len();
+        for field in block.fields {
+            if matches!(field.field_type, FieldType::Exponent) && self.is_present(field, block) {
+                let exponent = self.buf.get_i8_at(block.offset + field.offset);
+                self.exponents.push((field.name, exponent));
+            }
+        }
+
+        let mut members = Vec::new();
+        for field in block.fields {
+            if matches!(field.field_type, FieldType::Exponent) {
+                continue;
+            }
+            let value = match (self.field_value(field, block), field.json_default_value) {
+                (Value::Null, Some(default)) => self.default_value(field, default),
+                (value, _) => value,
+            };
+            if field.json_omit_null && value.is_null() {
+                continue;
+            }
+            members.push((field.json_path.unwrap_or(field.name), value));
+        }
+        for group in block.groups {
+            let value = self.render_group(group)?;
+            if group.json_omit_null && value.as_array().is_some_and(Vec::is_empty) {
+                continue;
+            }
+            members.push((group.json_path.unwrap_or(group.name), value));
+        }
+        for data in block.data {
+            let value = self.render_data(data)?;
+            members.push((data.json_path.unwrap_or(data.name), value));
+        }
+
+        self.exponents.truncate(scope);
+        Ok(assemble(members))
+    }
+
+    fn render_group(&mut self, group: &'static GroupMeta) -> SbeResult<Value> {
+        if group.since_version > self.version {
+            return Ok(Value::Array(Vec::new()));
+        }
+        self.buf.check_bounds(self.limit, group.header_length())?;
+        let block_length = self.buf.get_u16_at(self.limit) as usize;
+        let count = match group.num_in_group_type {
+            Primitive::U16 => self.buf.get_u16_at(self.limit + 2) as usize,
+            _ => self.buf.get_u32_at(self.limit + 2) as usize,
+        };
+        self.limit += group.header_length();
+
+        let expected = group.min_block_length(self.version);
+        if block_length < expected {
+            return Err(SbeErr::InvalidBlockLength {
+                block_length,
+                expected,
+            });
+        }
+        let trailing = group.min_trailing_length(self.version);
+        let available = self.buf.len() - self.limit;
+        if count.saturating_mul(block_length + trailing) > available {
+            return Err(SbeErr::InvalidGroupCount {
+                count,
+                block_length,
+                available,
+            });
+        }
+        let mut entries = Vec::new();
+        for _ in 0..count {
+            let offset = self.limit;
+            self.buf.check_bounds(offset, block_length)?;
+            self.limit += block_length;
+            entries.push(self.render_block(&Block {
+                fields: group.fields,
+                groups: group.groups,
+                data: group.data,
+                offset,
+                length: block_length,
+            })?);
+        }
+        Ok(Value::Array(entries))
+    }
+
+    fn render_data(&mut self, data: &'static DataMeta) -> SbeResult<Value> {
+        if data.since_version > self.version {
+            return Ok(Value::Null);
+        }
+        let prefix = data.length_type.size();
+        self.buf.check_bounds(self.limit, prefix)?;
+        let length = match data.length_type {
+            Primitive::U8 => self.buf.get_u8_at(self.limit) as usize,
+            Primitive::U16 => self.buf.get_u16_at(self.limit) as usize,
+            _ => self.buf.get_u32_at(self.limit) as usize,
+        };
+        self.limit += prefix;
+        self.buf.check_bounds(self.limit, length)?;
+        let buf = self.buf;
+        let slice = buf.get_slice_at(self.limit, length);
+        self.limit += length;
+
+        Ok(match data.data_type {
+            DataType::String | DataType::OptionalString => {
+                Value::String(String::from_utf8_lossy(slice).into_owned())
+            }
+            DataType::Message | DataType::OptionalMessage if slice.is_empty() => Value::Null,
+            DataType::Message | DataType::OptionalMessage => {
+                render_message(ReadBuf::new(slice), 0, self.depth + 1)?.0
+            }
+        })
+    }
+
+    fn is_present(&self, field: &FieldMeta, block: &Block) -> bool {
+        field.since_version <= self.version && field.offset + field.length <= block.length
+    }
+
+    fn exponent(&self, field: &FieldMeta) -> Option<i8> {
+        let name = field.exponent?;
+        self.exponents
+            .iter()
+            .rev()
+            .find(|(exponent, _)| *exponent == name)
+            .map(|&(_, value)| value)
+            .filter(|&value| value != i8::MIN)
+    }
+
+    fn field_value(&self, field: &FieldMeta, block: &Block) -> Value {
+        if field.presence == Presence::Constant {
+            return match (field.field_type, field.value_ref) {
+                (FieldType::Enum(meta), Some(name)) => meta
+                    .values
+                    .iter()
+                    .find(|value| value.name == name)
+                    .map_or(Value::Null, |value| enum_json(meta, value)),
+                _ => Value::Null,
+            };
+        }
+        if !self.is_present(field, block) {
+            return Value::Null;
+        }
+        let optional = field.presence == Presence::Optional;
+        let at = block.offset + field.offset;
+        match field.field_type {
+            FieldType::Primitive(primitive) => primitive_value(self.buf, at, primitive, optional),
+            FieldType::Mantissa64 => {
+                let mantissa = self.buf.get_i64_at(at);
+                if optional && mantissa == i64::MIN {
+                    return Value::Null;
+                }
+                self.decimal_value(field, mantissa.into())
+            }
+            FieldType::Mantissa128 => {
+                let mantissa = self.buf.get_i128_at(at);
+                if mantissa == i128::MIN {
+                    return Value::Null;
+                }
+                self.decimal_value(field, mantissa)
+            }
+            FieldType::Exponent => Value::from(self.buf.get_i8_at(at)),
+            FieldType::Enum(meta) => {
+                let raw = unsigned_at(self.buf, at, meta.encoding);
+                if raw == null_unsigned(meta.encoding) {
+                    return Value::Null;
+                }
+                match meta.values.iter().find(|value| value.value == raw) {
+                    Some(value) => enum_json(meta, value),
+                    None => Value::from(raw),
+                }
+            }
+            FieldType::Set(meta) => {
+                let raw = unsigned_at(self.buf, at, meta.encoding);
+                Value::Array(
+                    meta.choices
+                        .iter()
+                        .filter(|choice| raw & (1 << choice.bit) != 0)
+                        .map(|choice| Value::from(choice.json_value.unwrap_or(choice.name)))
+                        .collect(),
+                )
+            }
+        }
+    }
+
+    fn decimal_value(&self, field: &FieldMeta, mantissa: i128) -> Value {
+        match self.exponent(field) {
+            Some(exponent) => Value::String(Decimal::new(mantissa, exponent).to_string()),
+            None => Value::Null,
+        }
+    }
+
+    /// `mbx:jsonDefaultValue`, rendered the way a non-null value of the field would be
+    fn default_value(&self, field: &FieldMeta, default: &str) -> Value {
+        match field.field_type {
+            FieldType::Mantissa64 | FieldType::Mantissa128 => match default.parse::<Decimal>() {
+                Ok(decimal) => {
+                    let decimal = self
+                        .exponent(field)
+                        .and_then(|exponent| decimal.rescale(exponent))
+                        .unwrap_or(decimal);
+                    Value::String(decimal.to_string())
+                }
+                Err(_) => Value::String(default.to_string()),
+            },
+            FieldType::Primitive(Primitive::F32 | Primitive::F64) => {
+                Value::String(default.to_string())
+            }
+            _ => {
+                serde_json::from_str(default).unwrap_or_else(|_| Value::String(default.to_string()))
+            }
+        }
+    }
+}
+
+fn enum_json(meta: &EnumMeta, value: &EnumValue) -> Value {
+    if meta.name == "boolEnum" {
+        return Value::Bool(value.value != 0);
+    }
+    Value::from(value.json_value.unwrap_or(value.name))
+}
+
+fn unsigned_at(buf: ReadBuf<'_>, at: usize, encoding: Primitive) -> u64 {
+    match encoding {
+        Primitive::U16 => buf.get_u16_at(at).into(),
+        Primitive::U32 => buf.get_u32_at(at).into(),
+        Primitive::U64 => buf.get_u64_at(at),
+        _ => buf.get_u8_at(at).into(),
+    }
+}
+
+fn null_unsigned(encoding: Primitive) -> u64 {
+    match encoding {
+        Primitive::U16 => u16::MAX.into(),
+        Primitive::U32 => u32::MAX.into(),
+        Primitive::U64 => u64::MAX,
+        _ => u8::MAX.into(),
+    }
+}
+
+fn primitive_value(buf: ReadBuf<'_>, at: usize, primitive: Primitive, optional: bool) -> Value {
+    macro_rules! integer {
+        ($get:ident, $t:ty) => {{
+            let value = buf.$get(at);
+            if optional && value == <$t>::MIN {
+                Value::Null
+            } else {
+                Value::from(value)
+            }
+        }};
+    }
+    macro_rules! unsigned {
+        ($get:ident, $t:ty) => {{
+            let value = buf.$get(at);
+            if optional && value == <$t>::MAX {
+                Value::Null
+            } else {
+                Value::from(value)
+            }
+        }};
+    }
+    match primitive {
+        Primitive::Char => match buf.get_u8_at(at) {
+            0 if optional => Value::Null,
+            value => Value::String(char::from(value).to_string()),
+        },
+        Primitive::I8 => integer!(get_i8_at, i8),
+        Primitive::I16 => integer!(get_i16_at, i16),
+        Primitive::I32 => integer!(get_i32_at, i32),
+        Primitive::I64 => integer!(get_i64_at, i64),
+        Primitive::U8 => unsigned!(get_u8_at, u8),
+        Primitive::U16 => unsigned!(get_u16_at, u16),
+        Primitive::U32 => unsigned!(get_u32_at, u32),
+        Primitive::U64 => unsigned!(get_u64_at, u64),
+        // like decimals, the JSON API renders floating point values as strings
+        Primitive::F32 => match buf.get_f32_at(at) {
+            value if optional && value.is_nan() => Value::Null,
+            value => Value::String(value.to_string()),
+        },
+        Primitive::F64 => match buf.get_f64_at(at) {
+            value if optional && value.is_nan() => Value::Null,
+            value => Value::String(value.to_string()),
+        },
+    }
+}
+
+/// Builds the value of a message or group entry from its `(jsonPath, value)` members.
+fn assemble(mut members: Vec<(&'static str, Value)>) -> Value {
+    if let Some(index) = members.iter().position(|(path, _)| *path == "..") {
+        return members.swap_remove(index).1;
+    }
+    if members.iter().any(|(path, _)| *path == "[]") {
+        return Value::Array(
+            members
+                .into_iter()
+                .filter(|(path, _)| *path == "[]")
+                .map(|(_, value)| value)
+                .collect(),
+        );
+    }
+    let mut object = Map::new();
+    for (path, value) in members {
+        insert(&mut object, path, value);
+    }
+    Value::Object(object)
+}
+
+fn insert(object: &mut Map<String, Value>, path: &str, value: Value) {
+    match path.split_once('.') {
+        None => {
+            object.insert(path.to_string(), value);
+        }
+        Some((head, rest)) => {
+            let child = object
+                .entry(head)
+                .or_insert_with(|| Value::Object(Map::new()));
+            if let Value::Object(child) = child {
+                insert(child, rest, value);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum FromJsonError {
+    Parse(serde_json::Error),
+    UnknownTemplate(String),
+    /// A member without a null or default representation is absent.
+    MissingMember {
+        path: String,
+    },
+    /// A member does not correspond to any field, group or var data.
+    UnexpectedMember {
+        path: String,
+    },
+    InvalidValue {
+        path: String,
+        expected: &'static str,
+    },
+    /// No message in the schema can encode the JSON embedded at `path`.
+    NoMatchingTemplate {
+        path: String,
+    },
+}
+
+impl core::fmt::Display for FromJsonError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "invalid JSON: {err}"),
+            Self::UnknownTemplate(name) => write!(f, "unknown template {name:?}"),
+            Self::MissingMember { path } => write!(f, "missing member {path}"),
+            Self::UnexpectedMember { path } => write!(f, "unexpected member {path}"),
+            Self::InvalidValue { path, expected } => {
+                write!(f, "invalid value at {path}, expected {expected}")
+            }
+            Self::NoMatchingTemplate { path } => {
+                write!(f, "no message in the schema matches the JSON at {path}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FromJsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+type FromJsonResult<T> = Result<T, FromJsonError>;
+
+/// Encodes the JSON representation of the message named `template` (e.g.
+/// `"DepthResponse"`), header included.
+///
+/// Embedded messages, such as a `WebSocketResponse`'s `result` or the filters
+/// in an `ExchangeInfoResponse`, are encoded as the first message in the
+/// schema that accepts their JSON without missing or unexpected members.
+pub fn from_json(template: &str, value: &Value) -> FromJsonResult<Vec<u8>> {
+    let meta =
+        message_by_name(template).ok_or_else(|| FromJsonError::UnknownTemplate(template.into()))?;
+    encode_message(meta, value, "$")
+}
+
+pub fn from_json_str(template: &str, json: &str) -> FromJsonResult<Vec<u8>> {
+    let value = serde_json::from_str(json).map_err(FromJsonError::Parse)?;
+    from_json(template, &value)
+}
+
+fn encode_message(meta: &MessageMeta, value: &Value, path: &str) -> FromJsonResult<Vec<u8>> {
+    let mut out = vec![0; ENCODED_LENGTH];
+    let mut header = WriteBuf::new(&mut out);
+    header.put_u16_at(0, meta.block_length);
+    header.put_u16_at(2, meta.id);
+    header.put_u16_at(4, SCHEMA_ID);
+    header.put_u16_at(6, SCHEMA_VERSION);
+
+    // An event's type is not encoded, but must name the event if present.
+    let without_type;
+    let value = match (meta.event_type, value) {
+        (Some(event_type), Value::Object(object)) if object.contains_key("e") => {
+            if object["e"] != event_type {
+                return Err(invalid(&format!("{path}.e"), event_type));
+            }
+            let mut object = object.clone();
+            object.remove("e");
+            without_type = Value::Object(object);
+            &without_type
+        }
+        _ => value,
+    };
+    let mut encoder = JsonEncoder {
+        out,
+        exponents: Vec::new(),
+    };
+    let members = Members {
+        fields: meta.fields,
+        groups: meta.groups,
+        data: meta.data,
+    };
+    encoder.encode_block(members, meta.block_length as usize, value, path)?;
+    Ok(encoder.out)
+}
+
+fn encode_any(value: &Value, path: &str) -> FromJsonResult<Vec<u8>> {
+    MESSAGES
+        .iter()
+        .find_map(|meta| encode_message(meta, value, path).ok())
+        .ok_or_else(|| FromJsonError::NoMatchingTemplate { path: path.into() })
+}
+
+/// The members of a message or group entry, in the order they are rendered
+#[derive(Clone, Copy)]
+struct Members {
+    fields: &'static [FieldMeta],
+    groups: &'static [GroupMeta],
+    data: &'static [DataMeta],
+}
+
+impl Members {
+    fn of(group: &'static GroupMeta) -> Self {
+        Self {
+            fields: group.fields,
+            groups: group.groups,
+            data: group.data,
+        }
+    }
+
+    /// `jsonPath` of every rendered member; exponent fields are not rendered.
+    fn paths(&self) -> Vec<&'static str> {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|field| !matches!(field.field_type, FieldType::Exponent))
+            .map(|field| field.json_path.unwrap_or(field.name));
+        let groups = self
+            .groups
+            .iter()
+            .map(|group| group.json_path.unwrap_or(group.name));
+        let data = self
+            .data
+            .iter()
+            .map(|data| data.json_path.unwrap_or(data.name));
+        fields.chain(groups).chain(data).collect()
+    }
+
+    fn exponent_names(&self) -> impl Iterator<Item = &'static str> {
+        self.fields
+            .iter()
+            .filter(|field| matches!(field.field_type, FieldType::Exponent))
+            .map(|field| field.name)
+    }
+
+    /// Finds the JSON value of every member, the inverse of [`assemble`].
+    fn resolve<'v>(&self, value: &'v Value, path: &str) -> FromJsonResult<Vec<Option<&'v Value>>> {
+        let paths = self.paths();
+        let mut values = vec![None; paths.len()];
+        if let Some(index) = paths.iter().position(|path| *path == "..") {
+            values[index] = Some(value);
+            return Ok(values);
+        }
+        if paths.contains(&"[]") {
+            let tuple = value.as_array().ok_or_else(|| invalid(path, "array"))?;
+            let mut elements = tuple.iter();
+            for (index, _) in paths.iter().enumerate().filter(|(_, path)| **path == "[]") {
+                values[index] = elements.next();
+            }
+            if elements.next().is_some() {
+                return Err(FromJsonError::UnexpectedMember {
+                    path: format!("{path}[{}]", tuple.len() - 1),
+                });
+            }
+            return Ok(values);
+        }
+        let object = value.as_object().ok_or_else(|| invalid(path, "object"))?;
+        let mut known = paths.clone();
+        known.extend(self.exponent_names());
+        check_members(object, &known, path)?;
+        for (index, member) in paths.iter().enumerate() {
+            values[index] = lookup(object, member);
+        }
+        Ok(values)
+    }
+}
+
+struct JsonEncoder {
+    out: Vec<u8>,
+    /// Exponents of the enclosing blocks, innermost last
+    exponents: Vec<(&'static str, i8)>,
+}
+
+impl JsonEncoder {
+    fn encode_block(
+        &mut self,
+        members: Members,
+        block_length: usize,
+        value: &Value,
+        path: &str,
+    ) -> FromJsonResult<()> {
+        let values = members.resolve(value, path)?;
+        let scope = self.exponents.len();
+        for field in members.fields {
+            if !matches!(field.field_type, FieldType::Exponent) {
+                continue;
+            }
+            let exponent = match value.get(field.name) {
+                Some(exponent) => exponent
+                    .as_i64()
+                    .and_then(|exponent| i8::try_from(exponent).ok())
+                    .ok_or_else(|| invalid(&format!("{path}.{}", field.name), "exponent"))?,
+                None => min_exponent(members, value, field.name, path)?.unwrap_or(0),
+            };
+            self.exponents.push((field.name, exponent));
+        }
+
+        let mut values = values.into_iter();
+        let mut block = vec![0; block_length];
+        let mut buf = WriteBuf::new(&mut block);
+        for field in members.fields {
+            if matches!(field.field_type, FieldType::Exponent) {
+                buf.put_i8_at(field.offset, self.exponent(field.name).unwrap_or(0));
+                continue;
+            }
+            let value = values.next().flatten();
+            let path = format!("{path}.{}", field.json_path.unwrap_or(field.name));
+            self.encode_field(&mut buf, field, value, &path)?;
+        }
+        self.out.extend_from_slice(&block);
+
+        for group in members.groups {
+            let value = values.next().flatten();
+            let path = format!("{path}.{}", group.json_path.unwrap_or(group.name));
+            self.encode_group(group, value, &path)?;
+        }
+        for data in members.data {
+            let value = values.next().flatten();
+            let path = format!("{path}.{}", data.json_path.unwrap_or(data.name));
+            self.encode_data(data, value, &path)?;
+        }
+
+        self.exponents.truncate(scope);
+        Ok(())
+    }
+
+    fn encode_group(
+        &mut self,
+        group: &'static GroupMeta,
+        value: Option<&Value>,
+        path: &str,
+    ) -> FromJsonResult<()> {
+        let entries = match value {
+            Some(Value::Array(entries)) => entries.as_slice(),
+            None | Some(Value::Null) if group.json_omit_null => &[],
+            None => return Err(FromJsonError::MissingMember { path: path.into() }),
+            Some(_) => return Err(invalid(path, "array")),
+        };
+        let mut header = vec![0; group.header_length()];
+        let mut buf = WriteBuf::new(&mut header);
+        buf.put_u16_at(0, group.block_length);
+        match group.num_in_group_type {
+            Primitive::U16 => buf.put_u16_at(
+                2,
+                u16::try_from(entries.len()).map_err(|_| invalid(path, "fewer entries"))?,
+            ),
+            _ => buf.put_u32_at(
+                2,
+                u32::try_from(entries.len()).map_err(|_| invalid(path, "fewer entries"))?,
+            ),
+        }
+        self.out.extend_from_slice(&header);
+        for (index, entry) in entries.iter().enumerate() {
+            let path = format!("{path}[{index}]");
+            self.encode_block(
+                Members::of(group),
+                group.block_length as usize,
+                entry,
+                &path,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn encode_data(
+        &mut self,
+        data: &'static DataMeta,
+        value: Option<&Value>,
+        path: &str,
+    ) -> FromJsonResult<()> {
+        let optional = matches!(
+            data.data_type,
+            DataType::OptionalString | DataType::OptionalMessage
+        );
+        let bytes = match value {
+            None if optional => Vec::new(),
+            None => return Err(FromJsonError::MissingMember { path: path.into() }),
+            Some(Value::Null) => Vec::new(),
+            Some(value) => match data.data_type {
+                DataType::String | DataType::OptionalString => value
+                    .as_str()
+                    .ok_or_else(|| invalid(path, "string"))?
+                    .as_bytes()
+                    .to_vec(),
+                DataType::Message | DataType::OptionalMessage => encode_any(value, path)?,
+            },
+        };
+        let length = bytes.len();
+        match data.length_type {
+            Primitive::U8 => self
+                .out
+                .push(u8::try_from(length).map_err(|_| invalid(path, "shorter value"))?),
+            Primitive::U16 => self.out.extend_from_slice(
+                &u16::try_from(length)
+                    .map_err(|_| invalid(path, "shorter value"))?
+                    .to_le_bytes(),
+            ),
+            _ => self.out.extend_from_slice(
+                &u32::try_from(length)
+                    .map_err(|_| invalid(path, "shorter value"))?
+                    .to_le_bytes(),
+            ),
+        }
+        self.out.extend_from_slice(&bytes);
+        Ok(())
+    }
+
+    fn exponent(&self, name: &str) -> Option<i8> {
+        self.exponents
+            .iter()
+            .rev()
+            .find(|(exponent, _)| *exponent == name)
+            .map(|&(_, value)| value)
+    }
+
+    fn encode_field(
+        &self,
+        buf: &mut WriteBuf<'_>,
+        field: &FieldMeta,
+        value: Option<&Value>,
+        path: &str,
+    ) -> FromJsonResult<()> {
+        if field.presence == Presence::Constant {
+            return match (field.field_type, field.value_ref, value) {
+                (FieldType::Enum(meta), Some(name), Some(value)) => {
+                    let expected = meta.values.iter().find(|value| value.name == name);
+                    if expected.map(|expected| enum_json(meta, expected)).as_ref() == Some(value) {
+                        Ok(())
+                    } else {
+                        Err(invalid(path, "the constant value"))
+                    }
+                }
+                _ => Ok(()),
+            };
+        }
+        let default;
+        let value = match value {
+            Some(Value::Null) => None,
+            Some(value)
+                if field.presence == Presence::Optional
+                    && field
+                        .json_default_value
+                        .is_some_and(|default| is_default(field, value, default)) =>
+            {
+                None
+            }
+            Some(value) => Some(value),
+            None if field.presence == Presence::Optional
+                || matches!(field.field_type, FieldType::Mantissa128) =>
+            {
+                None
+            }
+            None => match field.json_default_value {
+                Some(json) => {
+                    default = serde_json::from_str(json).unwrap_or_else(|_| Value::from(json));
+                    Some(&default)
+                }
+                None => return Err(FromJsonError::MissingMember { path: path.into() }),
+            },
+        };
+
+        let at = field.offset;
+        match field.field_type {
+            FieldType::Primitive(primitive) => match value {
+                Some(value) => put_primitive(buf, at, primitive, value, path)?,
+                None => put_null_primitive(buf, at, primitive),
+            },
+            FieldType::Mantissa64 => {
+                let mantissa = match value {
+                    Some(value) => self
+                        .mantissa(field, value, path)?
+                        .and_then(|mantissa| i64::try_from(mantissa).ok())
+                        .filter(|&mantissa| mantissa != i64::MIN)
+                        .ok_or_else(|| invalid(path, "decimal with the block's exponent"))?,
+                    None => i64::MIN,
+                };
+                buf.put_i64_at(at, mantissa);
+            }
+            FieldType::Mantissa128 => {
+                let mantissa = match value {
+                    Some(value) => self
+                        .mantissa(field, value, path)?
+                        .filter(|&mantissa| mantissa != i128::MIN)
+                        .ok_or_else(|| invalid(path, "decimal with the block's exponent"))?,
+                    None => i128::MIN,
+                };
+                buf.put_i128_at(at, mantissa);
+            }
+            FieldType::Exponent => {}
+            FieldType::Enum(meta) => {
+                let raw = match value {
+                    Some(value) => {
+                        enum_raw(meta, value).ok_or_else(|| invalid(path, "enum value"))?
+                    }
+                    None => null_unsigned(meta.encoding),
+                };
+                put_unsigned(buf, at, meta.encoding, raw);
+            }
+            FieldType::Set(meta) => {
+                let raw = match value {
+                    Some(value) => {
+                        set_raw(meta, value).ok_or_else(|| invalid(path, "set choices"))?
+                    }
+                    None => 0,
+                };
+                put_unsigned(buf, at, meta.encoding, raw);
+            }
+        }
+        Ok(())
+    }
+
+    fn mantissa(
+        &self,
+        field: &FieldMeta,
+        value: &Value,
+        path: &str,
+    ) -> FromJsonResult<Option<i128>> {
+        let decimal = decimal(value).ok_or_else(|| invalid(path, "decimal"))?;
+        let exponent = field
+            .exponent
+            .and_then(|name| self.exponent(name))
+            .unwrap_or(0);
+        Ok(decimal.rescale(exponent).map(|decimal| decimal.mantissa()))
+    }
+}
+
+/// Finest scale of the decimals that use the exponent field `name`,
+/// including those in nested groups that do not declare their own.
+fn min_exponent(
+    members: Members,
+    value: &Value,
+    name: &str,
+    path: &str,
+) -> FromJsonResult<Option<i8>> {
+    let values = members.resolve(value, path)?;
+    let mut min: Option<i8> = None;
+    let rendered = members
+        .fields
+        .iter()
+        .filter(|field| !matches!(field.field_type, FieldType::Exponent));
+    for (field, value) in rendered.zip(&values) {
+        if field.exponent != Some(name) {
+            continue;
+        }
+        if let Some(decimal) = value.and_then(decimal) {
+            min = Some(min.map_or(decimal.exponent(), |min| min.min(decimal.exponent())));
+        }
+    }
+    let group_values = &values[values.len() - members.groups.len() - members.data.len()..];
+    for (group, value) in members.groups.iter().zip(group_values) {
+        if Members::of(group)
+            .exponent_names()
+            .any(|exponent| exponent == name)
+        {
+            continue;
+        }
+        let Some(Value::Array(entries)) = value else {
+            continue;
+        };
+        for (index, entry) in entries.iter().enumerate() {
+            let path = format!("{path}.{}[{index}]", group.json_path.unwrap_or(group.name));
+            if let Some(exponent) = min_exponent(Members::of(group), entry, name, &path)? {
+                min = Some(min.map_or(exponent, |min| min.min(exponent)));
+            }
+        }
+    }
+    Ok(min)
+}
+
+fn invalid(path: &str, expected: &'static str) -> FromJsonError {
+    FromJsonError::InvalidValue {
+        path: path.into(),
+        expected,
+    }
+}
+
+/// Rejects members of `object` that are not a prefix of one of `paths`.
+fn check_members(object: &Map<String, Value>, paths: &[&str], path: &str) -> FromJsonResult<()> {
+    for (key, value) in object {
+        let mut leaf = false;
+        let mut nested = Vec::new();
+        for candidate in paths {
+            if candidate == key {
+                leaf = true;
+            } else if let Some(rest) = candidate
+                .strip_prefix(key.as_str())
+                .and_then(|rest| rest.strip_prefix('.'))
+            {
+                nested.push(rest);
+            }
+        }
+        let path = format!("{path}.{key}");
+        match value {
+            _ if leaf => {}
+            Value::Object(object) if !nested.is_empty() => check_members(object, &nested, &path)?,
+            _ => return Err(FromJsonError::UnexpectedMember { path }),
+        }
+    }
+    Ok(())
+}
+
+fn lookup<'v>(object: &'v Map<String, Value>, path: &str) -> Option<&'v Value> {
+    match path.split_once('.') {
+        None => object.get(path),
+        Some((head, rest)) => lookup(object.get(head)?.as_object()?, rest),
+    }
+}
+
+fn decimal(value: &Value) -> Option<Decimal> {
+    match value {
+        Value::String(value) => value.parse().ok(),
+        Value::Number(value) => value.to_string().parse().ok(),
+        _ => None,
+    }
+}
+
+fn float(value: &Value) -> Option<f64> {
+    match value {
+        Value::String(value) => value.parse().ok(),
+        value => value.as_f64(),
+    }
+}
+
+fn is_default(field: &FieldMeta, value: &Value, default: &str) -> bool {
+    match field.field_type {
+        FieldType::Mantissa64 | FieldType::Mantissa128 => {
+            decimal(value).is_some_and(|value| default.parse() == Ok(value))
+        }
+        FieldType::Primitive(Primitive::F32 | Primitive::F64) => {
+            float(value).is_some_and(|value| default.parse() == Ok(value))
+        }
+        _ => serde_json::from_str::<Value>(default).is_ok_and(|default| default == *value),
+    }
+}
+
+fn enum_raw(meta: &EnumMeta, value: &Value) -> Option<u64> {
+    match value {
+        Value::Bool(value) if meta.name == "boolEnum" => Some(u64::from(*value)),
+        Value::String(value) => meta
+            .values
+            .iter()
+            .find(|candidate| candidate.json_value.unwrap_or(candidate.name) == value)
+            .map(|candidate| candidate.value),
+        value => value.as_u64(),
+    }
+}
+
+fn set_raw(meta: &SetMeta, value: &Value) -> Option<u64> {
+    match value {
+        Value::Array(choices) => choices.iter().try_fold(0, |raw, choice| {
+            let choice = choice.as_str()?;
+            meta.choices
+                .iter()
+                .find(|candidate| candidate.json_value.unwrap_or(candidate.name) == choice)
+                .map(|candidate| raw | (1 << candidate.bit))
+        }),
+        value => value.as_u64(),
+    }
+}
+
+fn put_unsigned(buf: &mut WriteBuf<'_>, at: usize, encoding: Primitive, raw: u64) {
+    match encoding {
+        Primitive::U16 => buf.put_u16_at(at, raw as u16),
+        Primitive::U32 => buf.put_u32_at(at, raw as u32),
+        Primitive::U64 => buf.put_u64_at(at, raw),
+        _ => buf.put_u8_at(at, raw as u8),
+    }
+}
+
+fn put_null_primitive(buf: &mut WriteBuf<'_>, at: usize, primitive: Primitive) {
+    match primitive {
+        Primitive::Char => buf.put_u8_at(at, 0),
+        Primitive::I8 => buf.put_i8_at(at, i8::MIN),
+        Primitive::I16 => buf.put_i16_at(at, i16::MIN),
+        Primitive::I32 => buf.put_i32_at(at, i32::MIN),
+        Primitive::I64 => buf.put_i64_at(at, i64::MIN),
+        Primitive::U8 => buf.put_u8_at(at, u8::MAX),
+        Primitive::U16 => buf.put_u16_at(at, u16::MAX),
+        Primitive::U32 => buf.put_u32_at(at, u32::MAX),
+        Primitive::U64 => buf.put_u64_at(at, u64::MAX),
+        Primitive::F32 => buf.put_f32_at(at, f32::NAN),
+        Primitive::F64 => buf.put_f64_at(at, f64::NAN),
+    }
+}
+
+fn put_primitive(
+    buf: &mut WriteBuf<'_>,
+    at: usize,
+    primitive: Primitive,
+    value: &Value,
+    path: &str,
+) -> FromJsonResult<()> {
+    macro_rules! integer {
+        ($put:ident, $t:ty) => {{
+            let value = match value {
+                Value::String(value) => value.parse::<$t>().ok(),
+                value => value
+                    .as_i64()
+                    .and_then(|value| <$t>::try_from(value).ok())
+                    .or_else(|| value.as_u64().and_then(|value| <$t>::try_from(value).ok())),
+            };
+            buf.$put(at, value.ok_or_else(|| invalid(path, stringify!($t)))?);
+        }};
+    }
+    match primitive {
+        Primitive::Char => match value.as_str().map(str::as_bytes) {
+            Some(&[byte]) => buf.put_u8_at(at, byte),
+            _ => return Err(invalid(path, "single character")),
+        },
+        Primitive::I8 => integer!(put_i8_at, i8),
+        Primitive::I16 => integer!(put_i16_at, i16),
+        Primitive::I32 => integer!(put_i32_at, i32),
+        Primitive::I64 => integer!(put_i64_at, i64),
+        Primitive::U8 => integer!(put_u8_at, u8),
+        Primitive::U16 => integer!(put_u16_at, u16),
+        Primitive::U32 => integer!(put_u32_at, u32),
+        Primitive::U64 => integer!(put_u64_at, u64),
+        Primitive::F32 => buf.put_f32_at(
+            at,
+            float(value).ok_or_else(|| invalid(path, "number"))? as f32,
+        ),
+        Primitive::F64 => buf.put_f64_at(at, float(value).ok_or_else(|| invalid(path, "number"))?),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Encoder, ErrorResponseEncoder};
+
+    /// The `executionReport` example of the user data stream documentation.
+    const EXECUTION_REPORT: &str = r#"{
+        "e": "executionReport",
+        "E": 1499405658658,
+        "s": "ETHBTC",
+        "c": "mUvoqJxFIILMdfAW5iGSOW",
+        "S": "BUY",
+        "o": "LIMIT",
+        "f": "GTC",
+        "q": "1.00000000",
+        "p": "0.10264410",
+        "P": "0.00000000",
+        "F": "0.00000000",
+        "g": -1,
+        "C": "",
+        "x": "NEW",
+        "X": "NEW",
+        "r": "NONE",
+        "i": 4293153,
+        "l": "0.00000000",
+        "z": "0.00000000",
+        "L": "0.00000000",
+        "n": "0",
+        "N": null,
+        "T": 1499405658657,
+        "t": -1,
+        "v": 3,
+        "I": 8641984,
+        "w": true,
+        "m": false,
+        "M": false,
+        "O": 1499405658657,
+        "Z": "0.00000000",
+        "Y": "0.00000000",
+        "Q": "0.00000000",
+        "W": 1499405658657,
+        "V": "NONE"
+    }"#;
+
+    fn error_response(data: &[u8]) -> Vec<u8> {
+        let mut buf = vec![0; 64 + data.len()];
+        let encoder = ErrorResponseEncoder::default().wrap(WriteBuf::new(&mut buf), ENCODED_LENGTH);
+        let mut encoder = encoder.header(0).parent().unwrap();
+        encoder.code(-1000);
+        encoder.msg("");
+        encoder.data(data);
+        let length = encoder.get_limit();
+        buf.truncate(length);
+        buf
+    }
+
+    #[test]
+    fn execution_report_renders_as_documented() {
+        let mut documented: Value = serde_json::from_str(EXECUTION_REPORT).unwrap();
+        let rendered = to_json(&from_json("ExecutionReportEvent", &documented).unwrap()).unwrap();
+
+        // The JSON API renders an empty `commissionAsset` as null but an empty
+        // `origClientOrderId` as "", which SBE cannot tell apart.
+        documented["N"] = "".into();
+        let rendered = rendered.as_object().unwrap();
+        assert_eq!(rendered.keys().next().map(String::as_str), Some("e"));
+        for (key, value) in documented.as_object().unwrap() {
+            assert_eq!(rendered.get(key), Some(value), "member {key}");
+        }
+    }
+
+    #[test]
+    fn execution_report_round_trips() {
+        let sbe = from_json_str("ExecutionReportEvent", EXECUTION_REPORT).unwrap();
+        let rendered = to_json(&sbe).unwrap();
+        assert_eq!(from_json("ExecutionReportEvent", &rendered).unwrap(), sbe);
+        // The event type is checked rather than encoded.
+        let mut other = rendered.clone();
+        other["e"] = "listStatus".into();
+        assert!(matches!(
+            from_json("ExecutionReportEvent", &other),
+            Err(FromJsonError::InvalidValue { path, .. }) if path == "$.e"
+        ));
+        other.as_object_mut().unwrap().remove("e");
+        assert_eq!(from_json("ExecutionReportEvent", &other).unwrap(), sbe);
+    }
+
+    #[test]
+    fn short_group_block_length_is_an_error() {
+        let frame = crate::tests::depth(0, 0x7fff_ffff);
+        assert_eq!(
+            to_json(&frame).unwrap_err(),
+            SbeErr::InvalidBlockLength {
+                block_length: 0,
+                expected: 16
+            }
+        );
+    }
+
+    #[test]
+    fn deeply_embedded_messages_are_an_error() {
+        let mut frame = error_response(&[]);
+        for _ in 0..1_000 {
+            frame = error_response(&frame);
+        }
+        assert_eq!(
+            to_json(&frame).unwrap_err(),
+            SbeErr::NestingTooDeep { depth: MAX_DEPTH }
+        );
+        assert!(to_json(&error_response(&error_response(&[]))).is_ok());
+    }
+}
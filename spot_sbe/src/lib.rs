@@ -4,236 +4,142 @@
 #![allow(non_camel_case_types)]
 use ::core::convert::TryInto;
 
-pub mod account_allocations_response_codec;
-pub mod account_commission_response_codec;
-pub mod account_order_rate_limit_response_codec;
-pub mod account_prevented_matches_response_codec;
-pub mod account_response_codec;
-pub mod account_trades_response_codec;
-pub mod account_type;
-pub mod agg_trades_response_codec;
-pub mod allocation_type;
-pub mod allowed_self_trade_prevention_modes;
-pub mod average_price_response_codec;
-pub mod balance_update_event_codec;
-pub mod book_ticker_response_codec;
-pub mod book_ticker_symbol_response_codec;
-pub mod bool_enum;
-pub mod cancel_open_orders_response_codec;
-pub mod cancel_order_list_response_codec;
-pub mod cancel_order_response_codec;
-pub mod cancel_replace_order_response_codec;
-pub mod cancel_replace_status;
-pub mod contingency_type;
-pub mod depth_response_codec;
-pub mod error_response_codec;
-pub mod event_stream_terminated_event_codec;
-pub mod exchange_info_response_codec;
-pub mod exchange_max_num_algo_orders_filter_codec;
-pub mod exchange_max_num_iceberg_orders_filter_codec;
-pub mod exchange_max_num_orders_filter_codec;
-pub mod execution_report_event_codec;
-pub mod execution_type;
-pub mod external_lock_update_event_codec;
-pub mod filter_type;
-pub mod floor;
-pub mod group_size_16_encoding_codec;
-pub mod group_size_encoding_codec;
-pub mod iceberg_parts_filter_codec;
-pub mod klines_response_codec;
-pub mod list_order_status;
-pub mod list_status_event_codec;
-pub mod list_status_type;
-pub mod lot_size_filter_codec;
-pub mod market_lot_size_filter_codec;
-pub mod match_type;
-pub mod max_num_algo_orders_filter_codec;
-pub mod max_num_iceberg_orders_filter_codec;
-pub mod max_num_orders_filter_codec;
-pub mod max_position_filter_codec;
-pub mod message_data_16_codec;
-pub mod message_data_8_codec;
-pub mod message_data_codec;
-pub mod message_header_codec;
-pub mod min_notional_filter_codec;
-pub mod new_order_ack_response_codec;
-pub mod new_order_full_response_codec;
-pub mod new_order_list_ack_response_codec;
-pub mod new_order_list_full_response_codec;
-pub mod new_order_list_result_response_codec;
-pub mod new_order_result_response_codec;
-pub mod notional_filter_codec;
-pub mod optional_message_data_16_codec;
-pub mod optional_message_data_codec;
-pub mod optional_var_string_8_codec;
-pub mod optional_var_string_codec;
-pub mod order_capacity;
-pub mod order_list_response_codec;
-pub mod order_lists_response_codec;
-pub mod order_response_codec;
-pub mod order_side;
-pub mod order_status;
-pub mod order_test_response_codec;
-pub mod order_test_with_commissions_response_codec;
-pub mod order_type;
-pub mod order_types;
-pub mod orders_response_codec;
-pub mod outbound_account_position_event_codec;
-pub mod percent_price_by_side_filter_codec;
-pub mod percent_price_filter_codec;
-pub mod ping_response_codec;
-pub mod price_filter_codec;
-pub mod price_ticker_response_codec;
-pub mod price_ticker_symbol_response_codec;
-pub mod rate_limit_interval;
-pub mod rate_limit_type;
-pub mod self_trade_prevention_mode;
-pub mod server_time_response_codec;
-pub mod symbol_status;
-pub mod ticker_24_hf_ull_response_codec;
-pub mod ticker_24_hm_ini_response_codec;
-pub mod ticker_24_hs_ymbol_full_response_codec;
-pub mod ticker_24_hs_ymbol_mini_response_codec;
-pub mod ticker_full_response_codec;
-pub mod ticker_mini_response_codec;
-pub mod ticker_symbol_full_response_codec;
-pub mod ticker_symbol_mini_response_codec;
-pub mod time_in_force;
-pub mod tp_lus_sell_filter_codec;
-pub mod trades_response_codec;
-pub mod trailing_delta_filter_codec;
-pub mod user_data_stream_ping_response_codec;
-pub mod user_data_stream_start_response_codec;
-pub mod user_data_stream_stop_response_codec;
-pub mod user_data_stream_subscribe_response_codec;
-pub mod user_data_stream_unsubscribe_response_codec;
-pub mod var_string_8_codec;
-pub mod var_string_codec;
-pub mod web_socket_response_codec;
-pub mod web_socket_session_logon_response_codec;
-pub mod web_socket_session_logout_response_codec;
-pub mod web_socket_session_status_response_codec;
-
-pub use crate::account_allocations_response_codec::*;
-pub use crate::account_commission_response_codec::*;
-pub use crate::account_order_rate_limit_response_codec::*;
-pub use crate::account_prevented_matches_response_codec::*;
-pub use crate::account_response_codec::*;
-pub use crate::account_trades_response_codec::*;
-pub use crate::account_type::*;
-pub use crate::agg_trades_response_codec::*;
-pub use crate::allocation_type::*;
-pub use crate::allowed_self_trade_prevention_modes::*;
-pub use crate::average_price_response_codec::*;
-pub use crate::balance_update_event_codec::*;
-pub use crate::book_ticker_response_codec::*;
-pub use crate::book_ticker_symbol_response_codec::*;
-pub use crate::bool_enum::*;
-pub use crate::cancel_open_orders_response_codec::*;
-pub use crate::cancel_order_list_response_codec::*;
-pub use crate::cancel_order_response_codec::*;
-pub use crate::cancel_replace_order_response_codec::*;
-pub use crate::cancel_replace_status::*;
-pub use crate::contingency_type::*;
-pub use crate::depth_response_codec::*;
-pub use crate::error_response_codec::*;
-pub use crate::event_stream_terminated_event_codec::*;
-pub use crate::exchange_info_response_codec::*;
-pub use crate::exchange_max_num_algo_orders_filter_codec::*;
-pub use crate::exchange_max_num_iceberg_orders_filter_codec::*;
-pub use crate::exchange_max_num_orders_filter_codec::*;
-pub use crate::execution_report_event_codec::*;
-pub use crate::execution_type::*;
-pub use crate::external_lock_update_event_codec::*;
-pub use crate::filter_type::*;
-pub use crate::floor::*;
-pub use crate::group_size_16_encoding_codec::*;
-pub use crate::group_size_encoding_codec::*;
-pub use crate::iceberg_parts_filter_codec::*;
-pub use crate::klines_response_codec::*;
-pub use crate::list_order_status::*;
-pub use crate::list_status_event_codec::*;
-pub use crate::list_status_type::*;
-pub use crate::lot_size_filter_codec::*;
-pub use crate::market_lot_size_filter_codec::*;
-pub use crate::match_type::*;
-pub use crate::max_num_algo_orders_filter_codec::*;
-pub use crate::max_num_iceberg_orders_filter_codec::*;
-pub use crate::max_num_orders_filter_codec::*;
-pub use crate::max_position_filter_codec::*;
-pub use crate::message_data_16_codec::*;
-pub use crate::message_data_8_codec::*;
-pub use crate::message_data_codec::*;
-pub use crate::message_header_codec::*;
-pub use crate::min_notional_filter_codec::*;
-pub use crate::new_order_ack_response_codec::*;
-pub use crate::new_order_full_response_codec::*;
-pub use crate::new_order_list_ack_response_codec::*;
-pub use crate::new_order_list_full_response_codec::*;
-pub use crate::new_order_list_result_response_codec::*;
-pub use crate::new_order_result_response_codec::*;
-pub use crate::notional_filter_codec::*;
-pub use crate::optional_message_data_16_codec::*;
-pub use crate::optional_message_data_codec::*;
-pub use crate::optional_var_string_8_codec::*;
-pub use crate::optional_var_string_codec::*;
-pub use crate::order_capacity::*;
-pub use crate::order_list_response_codec::*;
-pub use crate::order_lists_response_codec::*;
-pub use crate::order_response_codec::*;
-pub use crate::order_side::*;
-pub use crate::order_status::*;
-pub use crate::order_test_response_codec::*;
-pub use crate::order_test_with_commissions_response_codec::*;
-pub use crate::order_type::*;
-pub use crate::order_types::*;
-pub use crate::orders_response_codec::*;
-pub use crate::outbound_account_position_event_codec::*;
-pub use crate::percent_price_by_side_filter_codec::*;
-pub use crate::percent_price_filter_codec::*;
-pub use crate::ping_response_codec::*;
-pub use crate::price_filter_codec::*;
-pub use crate::price_ticker_response_codec::*;
-pub use crate::price_ticker_symbol_response_codec::*;
-pub use crate::rate_limit_interval::*;
-pub use crate::rate_limit_type::*;
-pub use crate::self_trade_prevention_mode::*;
-pub use crate::server_time_response_codec::*;
-pub use crate::symbol_status::*;
-pub use crate::ticker_24_hf_ull_response_codec::*;
-pub use crate::ticker_24_hm_ini_response_codec::*;
-pub use crate::ticker_24_hs_ymbol_full_response_codec::*;
-pub use crate::ticker_24_hs_ymbol_mini_response_codec::*;
-pub use crate::ticker_full_response_codec::*;
-pub use crate::ticker_mini_response_codec::*;
-pub use crate::ticker_symbol_full_response_codec::*;
-pub use crate::ticker_symbol_mini_response_codec::*;
-pub use crate::time_in_force::*;
-pub use crate::tp_lus_sell_filter_codec::*;
-pub use crate::trades_response_codec::*;
-pub use crate::trailing_delta_filter_codec::*;
-pub use crate::user_data_stream_ping_response_codec::*;
-pub use crate::user_data_stream_start_response_codec::*;
-pub use crate::user_data_stream_stop_response_codec::*;
-pub use crate::user_data_stream_subscribe_response_codec::*;
-pub use crate::user_data_stream_unsubscribe_response_codec::*;
-pub use crate::var_string_8_codec::*;
-pub use crate::var_string_codec::*;
-pub use crate::web_socket_response_codec::*;
-pub use crate::web_socket_session_logon_response_codec::*;
-pub use crate::web_socket_session_logout_response_codec::*;
-pub use crate::web_socket_session_status_response_codec::*;
+pub mod account;
+pub mod book;
+pub mod cancel_open_orders;
+pub mod cancel_replace;
+pub mod capture;
+pub mod decimal;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod meta;
+pub mod order_lists;
+pub mod orders;
+pub mod v2_1;
+pub mod versions;
+
+pub use crate::decimal::*;
+pub use crate::v2_1::*;
+pub use crate::versions::*;
 
 pub type SbeResult<T> = core::result::Result<T, SbeErr>;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum SbeErr {
     ParentNotSet,
+    /// Reading `needed` bytes at `offset` would run past the end of the buffer.
+    BufferTooShort {
+        offset: usize,
+        needed: usize,
+        available: usize,
+    },
+    /// A group header declares more entries than the remaining buffer can hold.
+    InvalidGroupCount {
+        count: usize,
+        block_length: usize,
+        available: usize,
+    },
+    /// A group header declares entries shorter than the schema's block length
+    /// for the acting version.
+    InvalidBlockLength {
+        block_length: usize,
+        expected: usize,
+    },
+    /// Messages are embedded in one another more than `depth` levels deep.
+    NestingTooDeep {
+        depth: usize,
+    },
+    /// A message decoder was given a header for a different template.
+    UnexpectedTemplateId {
+        expected: u16,
+        actual: u16,
+    },
+    /// No message in the schema has this template ID.
+    UnknownTemplateId {
+        template_id: u16,
+    },
+    /// No schema compiled into the crate has this schema ID.
+    UnknownSchema {
+        schema_id: u16,
+        version: u16,
+    },
+    /// A decimal cannot be written exactly as a mantissa with the block's `exponent`.
+    DecimalNotRepresentable {
+        exponent: i8,
+    },
+    /// A var data string is not valid UTF-8.
+    InvalidUtf8,
+    /// A group or var data is too long for the width of its length field.
+    LengthOverflow {
+        length: usize,
+    },
+    /// An embedded message of a template that `field` cannot hold, e.g. an
+    /// `ErrorResponse` in `cancelResponse` when `cancelResult` is `SUCCESS`.
+    /// `template_id` is `None` when the status it is paired with is invalid.
+    UnexpectedEmbeddedMessage {
+        field: &'static str,
+        template_id: Option<u16>,
+    },
 }
 impl core::fmt::Display for SbeErr {
     #[inline]
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::ParentNotSet => write!(f, "parent not set"),
+            Self::BufferTooShort {
+                offset,
+                needed,
+                available,
+            } => write!(
+                f,
+                "buffer too short: need {needed} bytes at offset {offset}, buffer length is {available}"
+            ),
+            Self::InvalidGroupCount {
+                count,
+                block_length,
+                available,
+            } => write!(
+                f,
+                "invalid group count {count} (block length {block_length}) with {available} bytes remaining"
+            ),
+            Self::InvalidBlockLength {
+                block_length,
+                expected,
+            } => write!(
+                f,
+                "invalid group block length {block_length}, expected at least {expected}"
+            ),
+            Self::NestingTooDeep { depth } => {
+                write!(f, "messages embedded more than {depth} levels deep")
+            }
+            Self::UnexpectedTemplateId { expected, actual } => {
+                write!(f, "unexpected template ID {actual}, expected {expected}")
+            }
+            Self::UnknownTemplateId { template_id } => {
+                write!(f, "unknown template ID {template_id}")
+            }
+            Self::UnknownSchema { schema_id, version } => {
+                write!(f, "unknown schema {schema_id}:{version}")
+            }
+            Self::DecimalNotRepresentable { exponent } => {
+                write!(f, "decimal cannot be represented with exponent {exponent}")
+            }
+            Self::InvalidUtf8 => write!(f, "var data string is not valid UTF-8"),
+            Self::LengthOverflow { length } => {
+                write!(f, "length {length} does not fit its length field")
+            }
+            Self::UnexpectedEmbeddedMessage {
+                field,
+                template_id: Some(template_id),
+            } => write!(
+                f,
+                "{field} cannot hold template ID {template_id} here"
+            ),
+            Self::UnexpectedEmbeddedMessage {
+                field,
+                template_id: None,
+            } => write!(f, "{field} is paired with an invalid status"),
+        }
     }
 }
 impl std::error::Error for SbeErr {}
@@ -260,6 +166,71 @@ pub trait Reader<'a>: Sized {
 pub trait Decoder<'a>: Reader<'a> {
     fn get_limit(&self) -> usize;
     fn set_limit(&mut self, limit: usize);
+    /// Schema version of the payload, from its message header. Members whose
+    /// `sinceVersion` is newer read as null or default.
+    fn acting_version(&self) -> u16;
+}
+
+/// Owned copy of a message, generated for every template in the `owned`
+/// module of its codec.
+///
+/// Exponent members such as `price_exponent` are kept so that decoding and
+/// re-encoding is lossless, but [`Decimal`] values carry their own scale:
+/// when encoding, each exponent is lowered to the finest exponent among the
+/// values it applies to. A struct built with `Default` and filled with
+/// decimals therefore encodes without setting any exponent.
+#[cfg(feature = "serde")]
+pub trait OwnedMessage: Sized {
+    const SBE_TEMPLATE_ID: u16;
+
+    /// Decodes the message, header included, at the start of `data`.
+    fn decode(data: &[u8]) -> SbeResult<Self>;
+
+    /// Encodes the message, header included.
+    fn encode(&self) -> SbeResult<Vec<u8>>;
+}
+
+/// Decodes an owned message, e.g. `let depth: DepthResponse = decode(&bytes)?;`
+#[cfg(feature = "serde")]
+#[inline]
+pub fn decode<T: OwnedMessage>(data: &[u8]) -> SbeResult<T> {
+    T::decode(data)
+}
+
+/// Copies a var data string, which the schema declares as UTF-8.
+#[inline]
+pub(crate) fn utf8(slice: &[u8]) -> SbeResult<String> {
+    String::from_utf8(slice.into()).map_err(|_| SbeErr::InvalidUtf8)
+}
+
+#[inline]
+pub(crate) fn checked_length<T: TryFrom<usize>>(length: usize) -> SbeResult<T> {
+    T::try_from(length).map_err(|_| SbeErr::LengthOverflow { length })
+}
+
+/// `exponent`, lowered to fit the finest of `values`.
+#[cfg(feature = "serde")]
+#[inline]
+pub(crate) fn fit_exponent(exponent: i8, values: impl IntoIterator<Item = Decimal>) -> i8 {
+    values
+        .into_iter()
+        .map(|value| value.exponent())
+        .fold(exponent, i8::min)
+}
+
+/// As [`fit_exponent`] for an optional exponent, which is only null if
+/// there are no values.
+#[cfg(feature = "serde")]
+#[inline]
+pub(crate) fn fit_optional_exponent(
+    exponent: Option<i8>,
+    values: impl IntoIterator<Item = Decimal>,
+) -> Option<i8> {
+    values
+        .into_iter()
+        .map(|value| value.exponent())
+        .chain(exponent)
+        .min()
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -279,8 +250,28 @@ impl<'a> ReadBuf<'a> {
     }
 
     #[inline]
-    fn get_bytes<const COUNT: usize>(slice: &[u8]) -> [u8; COUNT] {
-        slice.try_into().expect("slice with incorrect length")
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns `SbeErr::BufferTooShort` unless `len` bytes starting at `index`
+    /// are within the buffer. Decoders call this before handing out offsets so
+    /// that the unchecked `get_*_at` accessors below cannot panic.
+    #[inline]
+    pub fn check_bounds(&self, index: usize, len: usize) -> SbeResult<()> {
+        match index.checked_add(len) {
+            Some(end) if end <= self.data.len() => Ok(()),
+            _ => Err(SbeErr::BufferTooShort {
+                offset: index,
+                needed: len,
+                available: self.data.len(),
+            }),
+        }
     }
 
     #[inline]
@@ -315,6 +306,11 @@ impl<'a> ReadBuf<'a> {
         i64::from_le_bytes(Self::get_bytes_at(self.data, index))
     }
 
+    #[inline]
+    pub fn get_i128_at(&self, index: usize) -> i128 {
+        i128::from_le_bytes(Self::get_bytes_at(self.data, index))
+    }
+
     #[inline]
     pub fn get_u16_at(&self, index: usize) -> u16 {
         u16::from_le_bytes(Self::get_bytes_at(self.data, index))
@@ -344,6 +340,13 @@ impl<'a> ReadBuf<'a> {
     pub fn get_slice_at(&self, index: usize, len: usize) -> &[u8] {
         &self.data[index..index + len]
     }
+
+    /// The whole buffer, borrowed for as long as the data rather than the
+    /// `ReadBuf`, so that var data can outlive the decoder it was read with.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [u8] {
+        self.data
+    }
 }
 
 #[derive(Debug, Default)]
@@ -361,6 +364,12 @@ impl<'a> WriteBuf<'a> {
         COUNT
     }
 
+    /// Reads back a previously written exponent so decimal setters can rescale to it.
+    #[inline]
+    pub fn get_i8_at(&self, index: usize) -> i8 {
+        self.data[index] as i8
+    }
+
     #[inline]
     pub fn put_u8_at(&mut self, index: usize, value: u8) {
         self.data[index] = value;
@@ -386,6 +395,11 @@ impl<'a> WriteBuf<'a> {
         self.put_bytes_at(index, i64::to_le_bytes(value));
     }
 
+    #[inline]
+    pub fn put_i128_at(&mut self, index: usize, value: i128) {
+        self.put_bytes_at(index, i128::to_le_bytes(value));
+    }
+
     #[inline]
     pub fn put_u16_at(&mut self, index: usize, value: u16) {
         self.put_bytes_at(index, u16::to_le_bytes(value));
@@ -419,3 +433,200 @@ impl<'a> WriteBuf<'a> {
         len
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `DepthResponse` with `bids` entries of `block_length` bytes each
+    /// and no asks, for the tests of malformed groups across the crate.
+    pub(crate) fn depth(block_length: u16, bids: u32) -> Vec<u8> {
+        let mut frame = Vec::new();
+        for value in [10u16, 200, 2, 1] {
+            frame.extend_from_slice(&value.to_le_bytes());
+        }
+        frame.extend_from_slice(&[0; 10]);
+        frame.extend_from_slice(&block_length.to_le_bytes());
+        frame.extend_from_slice(&bids.to_le_bytes());
+        frame.resize(frame.len() + usize::from(block_length) * bids as usize, 0);
+        frame.extend_from_slice(&16u16.to_le_bytes());
+        frame.extend_from_slice(&0u32.to_le_bytes());
+        frame
+    }
+
+    fn depth_decoder(frame: &[u8]) -> SbeResult<DepthResponseDecoder<'_>> {
+        let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(frame), 0)?;
+        DepthResponseDecoder::default().header(header)
+    }
+
+    #[test]
+    fn group_block_length_below_schema_is_rejected() {
+        let frame = depth(0, 0x7fff_ffff);
+        let err = depth_decoder(&frame).unwrap().bids_decoder().unwrap_err();
+        assert_eq!(
+            err,
+            SbeErr::InvalidBlockLength {
+                block_length: 0,
+                expected: 16
+            }
+        );
+    }
+
+    #[test]
+    fn group_count_is_checked_against_remaining_bytes() {
+        let mut frame = depth(0, 0x7fff_ffff);
+        frame[18..20].copy_from_slice(&16u16.to_le_bytes());
+        let err = depth_decoder(&frame).unwrap().bids_decoder().unwrap_err();
+        assert_eq!(
+            err,
+            SbeErr::InvalidGroupCount {
+                count: 0x7fff_ffff,
+                block_length: 16,
+                available: 6
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn owned_decoder_rejects_zero_block_length() {
+        let frame = depth(0, 0x7fff_ffff);
+        let mut decoder = depth_decoder(&frame).unwrap();
+        let err = depth_response_codec::owned::DepthResponse::from_decoder(&mut decoder);
+        assert!(matches!(err, Err(SbeErr::InvalidBlockLength { .. })));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn group_decimal_needs_its_parent() {
+        use depth_response_codec::owned::{Bids, DepthResponse};
+
+        let frame = DepthResponse {
+            last_update_id: 7,
+            price_exponent: -2,
+            bids: vec![Bids {
+                price: Decimal::new(12345, -2),
+                qty: Decimal::new(3, 0),
+            }],
+            ..Default::default()
+        }
+        .encode()
+        .unwrap();
+        let mut bids = depth_decoder(&frame).unwrap().bids_decoder().unwrap();
+        assert_eq!(bids.advance(), Ok(Some(0)));
+        assert_eq!(bids.price_decimal(), Ok(Decimal::new(12345, -2)));
+        assert_eq!(bids.qty_decimal(), Ok(Decimal::new(3, 0)));
+        bids.parent().unwrap();
+        assert_eq!(bids.price_decimal(), Err(SbeErr::ParentNotSet));
+        // the entry itself stays readable
+        assert_eq!(bids.price(), 12345);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn group_var_data_is_bounds_checked_and_needs_its_parent() {
+        use account_response_codec::owned::{AccountResponse, Balances};
+
+        let frame = AccountResponse {
+            balances: vec![Balances {
+                asset: "BTC".into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+        .encode()
+        .unwrap();
+        let header = MessageHeaderDecoder::default()
+            .wrap(ReadBuf::new(&frame), 0)
+            .unwrap();
+        let decoder = AccountResponseDecoder::default().header(header).unwrap();
+        let mut balances = decoder.balances_decoder().unwrap();
+        assert_eq!(balances.advance(), Ok(Some(0)));
+        let (offset, length) = balances.asset_decoder().unwrap();
+        assert_eq!(balances.asset_slice((offset, length)), Ok(&b"BTC"[..]));
+        assert!(matches!(
+            balances.asset_slice((offset, frame.len())),
+            Err(SbeErr::BufferTooShort { .. })
+        ));
+        balances.parent().unwrap();
+        assert_eq!(balances.asset_decoder(), Err(SbeErr::ParentNotSet));
+    }
+
+    #[test]
+    fn mantissa128_setter_takes_what_the_getter_returns() {
+        let length = message_header_codec::ENCODED_LENGTH + 8 + 2 * 120;
+        let mut data = vec![0; length];
+        let encoder = KlinesResponseEncoder::default().wrap(
+            WriteBuf::new(&mut data),
+            message_header_codec::ENCODED_LENGTH,
+        );
+        let encoder = encoder.header(0).parent().unwrap();
+        let mut klines = encoder.klines_encoder(2, Default::default());
+        for volume in [Some(12345), None] {
+            klines.advance().unwrap();
+            klines.volume(volume);
+        }
+        klines.parent().unwrap();
+
+        let header = MessageHeaderDecoder::default()
+            .wrap(ReadBuf::new(&data), 0)
+            .unwrap();
+        let decoder = KlinesResponseDecoder::default().header(header).unwrap();
+        let mut klines = decoder.klines_decoder().unwrap();
+        for volume in [Some(12345), None] {
+            klines.advance().unwrap();
+            assert_eq!(klines.volume(), volume);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn owned_encoding_fits_exponents_to_values() {
+        use depth_response_codec::owned::{Asks, Bids, DepthResponse};
+
+        let depth = DepthResponse {
+            bids: vec![Bids {
+                price: "0.01".parse().unwrap(),
+                qty: "1.5".parse().unwrap(),
+            }],
+            asks: vec![Asks {
+                price: "0.015".parse().unwrap(),
+                qty: "200".parse().unwrap(),
+            }],
+            ..Default::default()
+        };
+        let decoded: DepthResponse = decode(&depth.encode().unwrap()).unwrap();
+        assert_eq!((decoded.price_exponent, decoded.qty_exponent), (-3, -1));
+        assert_eq!(decoded.bids, depth.bids);
+        assert_eq!(decoded.asks, depth.asks);
+        // values already at the decoded scale leave it alone
+        assert_eq!(
+            decode::<DepthResponse>(&decoded.encode().unwrap()),
+            Ok(decoded)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn owned_encoding_fills_in_optional_exponents() {
+        use account_allocations_response_codec::owned::{AccountAllocationsResponse, Allocations};
+
+        let mut response = AccountAllocationsResponse {
+            allocations: vec![Allocations {
+                commission: Some("0.001".parse().unwrap()),
+                ..Default::default()
+            }],
+        };
+        let decoded: AccountAllocationsResponse = decode(&response.encode().unwrap()).unwrap();
+        assert_eq!(decoded.allocations[0].commission_exponent, Some(-3));
+        assert_eq!(
+            decoded.allocations[0].commission,
+            response.allocations[0].commission
+        );
+
+        response.allocations[0].commission = None;
+        let decoded: AccountAllocationsResponse = decode(&response.encode().unwrap()).unwrap();
+        assert_eq!(decoded.allocations[0].commission_exponent, None);
+        assert_eq!(decoded.allocations[0].commission, None);
+    }
+}
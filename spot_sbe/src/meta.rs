@@ -0,0 +1,321 @@
+//! Static description of the schema's messages, groups, fields and types,
+//! including the `sinceVersion`, `presence` and `mbx:*` attributes that the
+//! typed codecs do not carry. Used to handle messages generically, e.g. to
+//! render them as JSON.
+//!
+//! [`MESSAGES`] doubles as the template registry: look templates up with
+//! [`message_by_id`] or [`message_by_name`] for their name, block length and
+//! `sinceVersion`, or get the entry of a decoded message from
+//! [`AnyMessage::meta`](crate::AnyMessage::meta). These are the tables of the
+//! schema re-exported at the crate root; every compiled-in schema's tables
+//! are on its [`SchemaVersion`](crate::SchemaVersion).
+
+pub use crate::v2_1::meta_tables::{ENUMS, MESSAGES, SCHEMA_ID, SCHEMA_VERSION, SETS};
+
+use crate::message_header_codec::ENCODED_LENGTH;
+use crate::{schema_for, ReadBuf, SbeErr, SbeResult};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Primitive {
+    Char,
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    F32,
+    F64,
+}
+
+impl Primitive {
+    pub const fn size(&self) -> usize {
+        match self {
+            Self::Char | Self::I8 | Self::U8 => 1,
+            Self::I16 | Self::U16 => 2,
+            Self::I32 | Self::U32 | Self::F32 => 4,
+            Self::I64 | Self::U64 | Self::F64 => 8,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Presence {
+    Required,
+    Optional,
+    Constant,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum FieldType {
+    Primitive(Primitive),
+    /// `mantissa64`, combined with the exponent field named by `FieldMeta::exponent`
+    Mantissa64,
+    /// `mantissa128`, combined with the exponent field named by `FieldMeta::exponent`
+    Mantissa128,
+    /// `exponent8`
+    Exponent,
+    Enum(&'static EnumMeta),
+    Set(&'static SetMeta),
+}
+
+#[derive(Debug)]
+pub struct EnumMeta {
+    pub name: &'static str,
+    pub encoding: Primitive,
+    pub since_version: u16,
+    pub values: &'static [EnumValue],
+}
+
+#[derive(Debug)]
+pub struct EnumValue {
+    pub name: &'static str,
+    pub value: u64,
+    pub since_version: u16,
+    /// `mbx:jsonValue`
+    pub json_value: Option<&'static str>,
+}
+
+#[derive(Debug)]
+pub struct SetMeta {
+    pub name: &'static str,
+    pub encoding: Primitive,
+    pub since_version: u16,
+    pub choices: &'static [SetChoice],
+}
+
+#[derive(Debug)]
+pub struct SetChoice {
+    pub name: &'static str,
+    pub bit: u8,
+    /// `mbx:jsonValue`
+    pub json_value: Option<&'static str>,
+}
+
+#[derive(Debug)]
+pub struct FieldMeta {
+    pub name: &'static str,
+    pub id: u16,
+    pub field_type: FieldType,
+    pub presence: Presence,
+    /// Enum value name for `presence="constant"` fields
+    pub value_ref: Option<&'static str>,
+    pub offset: usize,
+    pub length: usize,
+    pub since_version: u16,
+    /// `mbx:exponent`
+    pub exponent: Option<&'static str>,
+    /// `mbx:jsonPath`
+    pub json_path: Option<&'static str>,
+    /// `mbx:jsonDefaultValue`
+    pub json_default_value: Option<&'static str>,
+    /// `mbx:jsonOmitNull`
+    pub json_omit_null: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataType {
+    String,
+    /// Empty string means null
+    OptionalString,
+    /// Message header plus SBE-encoded message
+    Message,
+    /// Message header plus SBE-encoded message, empty means null
+    OptionalMessage,
+}
+
+#[derive(Debug)]
+pub struct DataMeta {
+    pub name: &'static str,
+    pub id: u16,
+    pub data_type: DataType,
+    /// Width of the length prefix
+    pub length_type: Primitive,
+    pub since_version: u16,
+    /// `mbx:jsonPath`
+    pub json_path: Option<&'static str>,
+}
+
+#[derive(Debug)]
+pub struct GroupMeta {
+    pub name: &'static str,
+    pub id: u16,
+    pub block_length: u16,
+    /// Width of `numInGroup` in the group dimension header
+    pub num_in_group_type: Primitive,
+    pub since_version: u16,
+    /// `mbx:jsonPath`
+    pub json_path: Option<&'static str>,
+    /// `mbx:jsonOmitNull`
+    pub json_omit_null: bool,
+    pub fields: &'static [FieldMeta],
+    pub groups: &'static [GroupMeta],
+    pub data: &'static [DataMeta],
+}
+
+impl GroupMeta {
+    /// Size of the group dimension header
+    pub const fn header_length(&self) -> usize {
+        2 + self.num_in_group_type.size()
+    }
+
+    /// Shortest block an entry may have at `version`: the end of the last
+    /// field the version includes.
+    pub fn min_block_length(&self, version: u16) -> usize {
+        self.fields
+            .iter()
+            .filter(|field| field.since_version <= version)
+            .map(|field| field.offset + field.length)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Bytes each entry carries after its block at `version`: the headers of
+    /// its nested groups and the length prefixes of its var data, at least.
+    pub fn min_trailing_length(&self, version: u16) -> usize {
+        let groups = self
+            .groups
+            .iter()
+            .filter(|group| group.since_version <= version)
+            .map(GroupMeta::header_length);
+        let data = self
+            .data
+            .iter()
+            .filter(|data| data.since_version <= version)
+            .map(|data| data.length_type.size());
+        groups.chain(data).sum()
+    }
+}
+
+#[derive(Debug)]
+pub struct MessageMeta {
+    pub name: &'static str,
+    pub id: u16,
+    pub block_length: u16,
+    pub since_version: u16,
+    pub description: Option<&'static str>,
+    /// The JSON `"e"` of a user data stream event
+    pub event_type: Option<&'static str>,
+    pub fields: &'static [FieldMeta],
+    pub groups: &'static [GroupMeta],
+    pub data: &'static [DataMeta],
+}
+
+#[inline]
+pub fn message_by_id(template_id: u16) -> Option<&'static MessageMeta> {
+    MESSAGES.iter().copied().find(|m| m.id == template_id)
+}
+
+#[inline]
+pub fn message_by_name(name: &str) -> Option<&'static MessageMeta> {
+    MESSAGES.iter().copied().find(|m| m.name == name)
+}
+
+/// Length in bytes of the message (header included) at the start of `data`,
+/// found by walking its group dimensions and var data length prefixes. Used to
+/// split a stream of concatenated messages into frames.
+pub fn message_length(data: &[u8]) -> SbeResult<usize> {
+    let buf = ReadBuf::new(data);
+    buf.check_bounds(0, ENCODED_LENGTH)?;
+    let block_length = buf.get_u16_at(0) as usize;
+    let template_id = buf.get_u16_at(2);
+    let version = buf.get_u16_at(6);
+    let meta = schema_for(buf.get_u16_at(4), version)?
+        .message_by_id(template_id)
+        .ok_or(SbeErr::UnknownTemplateId { template_id })?;
+
+    let mut limit = ENCODED_LENGTH + block_length;
+    buf.check_bounds(0, limit)?;
+    skip_members(buf, version, meta.groups, meta.data, &mut limit)?;
+    Ok(limit)
+}
+
+fn skip_members(
+    buf: ReadBuf<'_>,
+    version: u16,
+    groups: &[GroupMeta],
+    data: &[DataMeta],
+    limit: &mut usize,
+) -> SbeResult<()> {
+    for group in groups.iter().filter(|group| group.since_version <= version) {
+        buf.check_bounds(*limit, group.header_length())?;
+        let block_length = buf.get_u16_at(*limit) as usize;
+        let count = match group.num_in_group_type {
+            Primitive::U16 => buf.get_u16_at(*limit + 2) as usize,
+            _ => buf.get_u32_at(*limit + 2) as usize,
+        };
+        *limit += group.header_length();
+        let expected = group.min_block_length(version);
+        if block_length < expected {
+            return Err(SbeErr::InvalidBlockLength {
+                block_length,
+                expected,
+            });
+        }
+        let trailing = group.min_trailing_length(version);
+        buf.check_bounds(*limit, count.saturating_mul(block_length + trailing))?;
+        if trailing == 0 {
+            // Entries without nested members are all one block long.
+            *limit += count * block_length;
+            continue;
+        }
+        for _ in 0..count {
+            buf.check_bounds(*limit, block_length)?;
+            *limit += block_length;
+            skip_members(buf, version, group.groups, group.data, limit)?;
+        }
+    }
+    for data in data.iter().filter(|data| data.since_version <= version) {
+        let prefix = data.length_type.size();
+        buf.check_bounds(*limit, prefix)?;
+        let length = match data.length_type {
+            Primitive::U8 => buf.get_u8_at(*limit) as usize,
+            Primitive::U16 => buf.get_u16_at(*limit) as usize,
+            _ => buf.get_u32_at(*limit) as usize,
+        };
+        *limit += prefix;
+        buf.check_bounds(*limit, length)?;
+        *limit += length;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::depth;
+
+    #[test]
+    fn message_length_walks_groups() {
+        let frame = depth(16, 1_000);
+        assert_eq!(message_length(&frame), Ok(frame.len()));
+        let mut stream = frame.clone();
+        stream.extend_from_slice(&depth(24, 3));
+        assert_eq!(message_length(&stream), Ok(frame.len()));
+    }
+
+    #[test]
+    fn message_length_rejects_short_group_block_length() {
+        let mut frame = depth(0, 0);
+        frame.truncate(24);
+        frame[20..24].copy_from_slice(&0x7fff_ffffu32.to_le_bytes());
+        assert_eq!(
+            message_length(&frame),
+            Err(SbeErr::InvalidBlockLength {
+                block_length: 0,
+                expected: 16
+            })
+        );
+    }
+
+    #[test]
+    fn message_length_of_partial_frame_is_buffer_too_short() {
+        let frame = depth(16, 1_000);
+        assert!(matches!(
+            message_length(&frame[..frame.len() - 1]),
+            Err(SbeErr::BufferTooShort { .. })
+        ));
+    }
+}
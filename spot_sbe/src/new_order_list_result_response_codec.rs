@@ -1,1309 +0,0 @@
-use crate::*;
-
-pub use decoder::NewOrderListResultResponseDecoder;
-pub use encoder::NewOrderListResultResponseEncoder;
-
-pub const SBE_BLOCK_LENGTH: u16 = 21;
-pub const SBE_TEMPLATE_ID: u16 = 310;
-pub const SBE_SCHEMA_ID: u16 = 2;
-pub const SBE_SCHEMA_VERSION: u16 = 1;
-pub const SBE_SEMANTIC_VERSION: &str = "5.2";
-
-pub mod encoder {
-    use super::*;
-
-    #[derive(Debug, Default)]
-    pub struct NewOrderListResultResponseEncoder<'a> {
-        buf: WriteBuf<'a>,
-        initial_offset: usize,
-        offset: usize,
-        limit: usize,
-    }
-
-    impl<'a> Writer<'a> for NewOrderListResultResponseEncoder<'a> {
-        #[inline]
-        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
-            &mut self.buf
-        }
-    }
-
-    impl<'a> Encoder<'a> for NewOrderListResultResponseEncoder<'a> {
-        #[inline]
-        fn get_limit(&self) -> usize {
-            self.limit
-        }
-
-        #[inline]
-        fn set_limit(&mut self, limit: usize) {
-            self.limit = limit;
-        }
-    }
-
-    impl<'a> NewOrderListResultResponseEncoder<'a> {
-        pub fn wrap(mut self, buf: WriteBuf<'a>, offset: usize) -> Self {
-            let limit = offset + SBE_BLOCK_LENGTH as usize;
-            self.buf = buf;
-            self.initial_offset = offset;
-            self.offset = offset;
-            self.limit = limit;
-            self
-        }
-
-        #[inline]
-        pub fn encoded_length(&self) -> usize {
-            self.limit - self.offset
-        }
-
-        pub fn header(self, offset: usize) -> MessageHeaderEncoder<Self> {
-            let mut header = MessageHeaderEncoder::default().wrap(self, offset);
-            header.block_length(SBE_BLOCK_LENGTH);
-            header.template_id(SBE_TEMPLATE_ID);
-            header.schema_id(SBE_SCHEMA_ID);
-            header.version(SBE_SCHEMA_VERSION);
-            header
-        }
-
-        /// primitive field 'orderListId'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 0
-        /// - encodedLength: 8
-        #[inline]
-        pub fn order_list_id(&mut self, value: i64) {
-            let offset = self.offset;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn contingency_type(&mut self, value: ContingencyType) {
-            let offset = self.offset + 8;
-            self.get_buf_mut().put_u8_at(offset, value as u8)
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn list_status_type(&mut self, value: ListStatusType) {
-            let offset = self.offset + 9;
-            self.get_buf_mut().put_u8_at(offset, value as u8)
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn list_order_status(&mut self, value: ListOrderStatus) {
-            let offset = self.offset + 10;
-            self.get_buf_mut().put_u8_at(offset, value as u8)
-        }
-
-        /// primitive field 'transactionTime'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 11
-        /// - encodedLength: 8
-        #[inline]
-        pub fn transaction_time(&mut self, value: i64) {
-            let offset = self.offset + 11;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// primitive field 'priceExponent'
-        /// - min value: -127
-        /// - max value: 127
-        /// - null value: -128
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 19
-        /// - encodedLength: 1
-        #[inline]
-        pub fn price_exponent(&mut self, value: i8) {
-            let offset = self.offset + 19;
-            self.get_buf_mut().put_i8_at(offset, value);
-        }
-
-        /// primitive field 'qtyExponent'
-        /// - min value: -127
-        /// - max value: 127
-        /// - null value: -128
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 20
-        /// - encodedLength: 1
-        #[inline]
-        pub fn qty_exponent(&mut self, value: i8) {
-            let offset = self.offset + 20;
-            self.get_buf_mut().put_i8_at(offset, value);
-        }
-
-        /// GROUP ENCODER (id=100)
-        #[inline]
-        pub fn orders_encoder(
-            self,
-            count: u16,
-            orders_encoder: OrdersEncoder<Self>,
-        ) -> OrdersEncoder<Self> {
-            orders_encoder.wrap(self, count)
-        }
-
-        /// GROUP ENCODER (id=101)
-        #[inline]
-        pub fn order_reports_encoder(
-            self,
-            count: u16,
-            order_reports_encoder: OrderReportsEncoder<Self>,
-        ) -> OrderReportsEncoder<Self> {
-            order_reports_encoder.wrap(self, count)
-        }
-
-        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
-        #[inline]
-        pub fn list_client_order_id(&mut self, value: &str) {
-            let limit = self.get_limit();
-            let data_length = value.len();
-            self.set_limit(limit + 1 + data_length);
-            self.get_buf_mut().put_u8_at(limit, data_length as u8);
-            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
-        }
-
-        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
-        #[inline]
-        pub fn symbol(&mut self, value: &str) {
-            let limit = self.get_limit();
-            let data_length = value.len();
-            self.set_limit(limit + 1 + data_length);
-            self.get_buf_mut().put_u8_at(limit, data_length as u8);
-            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
-        }
-    }
-
-    #[derive(Debug, Default)]
-    pub struct OrdersEncoder<P> {
-        parent: Option<P>,
-        count: u16,
-        index: usize,
-        offset: usize,
-        initial_limit: usize,
-    }
-
-    impl<'a, P> Writer<'a> for OrdersEncoder<P>
-    where
-        P: Writer<'a> + Default,
-    {
-        #[inline]
-        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
-            if let Some(parent) = self.parent.as_mut() {
-                parent.get_buf_mut()
-            } else {
-                panic!("parent was None")
-            }
-        }
-    }
-
-    impl<'a, P> Encoder<'a> for OrdersEncoder<P>
-    where
-        P: Encoder<'a> + Default,
-    {
-        #[inline]
-        fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
-        }
-
-        #[inline]
-        fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
-        }
-    }
-
-    impl<'a, P> OrdersEncoder<P>
-    where
-        P: Encoder<'a> + Default,
-    {
-        #[inline]
-        pub fn wrap(mut self, mut parent: P, count: u16) -> Self {
-            let initial_limit = parent.get_limit();
-            parent.set_limit(initial_limit + 4);
-            parent
-                .get_buf_mut()
-                .put_u16_at(initial_limit, Self::block_length());
-            parent.get_buf_mut().put_u16_at(initial_limit + 2, count);
-            self.parent = Some(parent);
-            self.count = count;
-            self.index = usize::MAX;
-            self.offset = usize::MAX;
-            self.initial_limit = initial_limit;
-            self
-        }
-
-        #[inline]
-        pub fn block_length() -> u16 {
-            8
-        }
-
-        #[inline]
-        pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
-        }
-
-        /// will return Some(current index) when successful otherwise None
-        #[inline]
-        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
-            let index = self.index.wrapping_add(1);
-            if index >= self.count as usize {
-                return Ok(None);
-            }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + Self::block_length() as usize);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
-            }
-        }
-
-        /// primitive field 'orderId'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 0
-        /// - encodedLength: 8
-        #[inline]
-        pub fn order_id(&mut self, value: i64) {
-            let offset = self.offset;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
-        #[inline]
-        pub fn symbol(&mut self, value: &str) {
-            let limit = self.get_limit();
-            let data_length = value.len();
-            self.set_limit(limit + 1 + data_length);
-            self.get_buf_mut().put_u8_at(limit, data_length as u8);
-            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
-        }
-
-        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
-        #[inline]
-        pub fn client_order_id(&mut self, value: &str) {
-            let limit = self.get_limit();
-            let data_length = value.len();
-            self.set_limit(limit + 1 + data_length);
-            self.get_buf_mut().put_u8_at(limit, data_length as u8);
-            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
-        }
-    }
-
-    #[derive(Debug, Default)]
-    pub struct OrderReportsEncoder<P> {
-        parent: Option<P>,
-        count: u16,
-        index: usize,
-        offset: usize,
-        initial_limit: usize,
-    }
-
-    impl<'a, P> Writer<'a> for OrderReportsEncoder<P>
-    where
-        P: Writer<'a> + Default,
-    {
-        #[inline]
-        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
-            if let Some(parent) = self.parent.as_mut() {
-                parent.get_buf_mut()
-            } else {
-                panic!("parent was None")
-            }
-        }
-    }
-
-    impl<'a, P> Encoder<'a> for OrderReportsEncoder<P>
-    where
-        P: Encoder<'a> + Default,
-    {
-        #[inline]
-        fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
-        }
-
-        #[inline]
-        fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
-        }
-    }
-
-    impl<'a, P> OrderReportsEncoder<P>
-    where
-        P: Encoder<'a> + Default,
-    {
-        #[inline]
-        pub fn wrap(mut self, mut parent: P, count: u16) -> Self {
-            let initial_limit = parent.get_limit();
-            parent.set_limit(initial_limit + 4);
-            parent
-                .get_buf_mut()
-                .put_u16_at(initial_limit, Self::block_length());
-            parent.get_buf_mut().put_u16_at(initial_limit + 2, count);
-            self.parent = Some(parent);
-            self.count = count;
-            self.index = usize::MAX;
-            self.offset = usize::MAX;
-            self.initial_limit = initial_limit;
-            self
-        }
-
-        #[inline]
-        pub fn block_length() -> u16 {
-            140
-        }
-
-        #[inline]
-        pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
-        }
-
-        /// will return Some(current index) when successful otherwise None
-        #[inline]
-        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
-            let index = self.index.wrapping_add(1);
-            if index >= self.count as usize {
-                return Ok(None);
-            }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + Self::block_length() as usize);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
-            }
-        }
-
-        /// primitive field 'orderId'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 0
-        /// - encodedLength: 8
-        #[inline]
-        pub fn order_id(&mut self, value: i64) {
-            let offset = self.offset;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// primitive field 'orderListId'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 8
-        /// - encodedLength: 8
-        #[inline]
-        pub fn order_list_id(&mut self, value: i64) {
-            let offset = self.offset + 8;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// primitive field 'transactTime'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 16
-        /// - encodedLength: 8
-        #[inline]
-        pub fn transact_time(&mut self, value: i64) {
-            let offset = self.offset + 16;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// primitive field 'price'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 24
-        /// - encodedLength: 8
-        #[inline]
-        pub fn price(&mut self, value: i64) {
-            let offset = self.offset + 24;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// primitive field 'origQty'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 32
-        /// - encodedLength: 8
-        #[inline]
-        pub fn orig_qty(&mut self, value: i64) {
-            let offset = self.offset + 32;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// primitive field 'executedQty'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 40
-        /// - encodedLength: 8
-        #[inline]
-        pub fn executed_qty(&mut self, value: i64) {
-            let offset = self.offset + 40;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// primitive field 'cummulativeQuoteQty'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 48
-        /// - encodedLength: 8
-        #[inline]
-        pub fn cummulative_quote_qty(&mut self, value: i64) {
-            let offset = self.offset + 48;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn status(&mut self, value: OrderStatus) {
-            let offset = self.offset + 56;
-            self.get_buf_mut().put_u8_at(offset, value as u8)
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn time_in_force(&mut self, value: TimeInForce) {
-            let offset = self.offset + 57;
-            self.get_buf_mut().put_u8_at(offset, value as u8)
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn order_type(&mut self, value: OrderType) {
-            let offset = self.offset + 58;
-            self.get_buf_mut().put_u8_at(offset, value as u8)
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn side(&mut self, value: OrderSide) {
-            let offset = self.offset + 59;
-            self.get_buf_mut().put_u8_at(offset, value as u8)
-        }
-
-        /// primitive field 'stopPrice'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 60
-        /// - encodedLength: 8
-        #[inline]
-        pub fn stop_price(&mut self, value: i64) {
-            let offset = self.offset + 60;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// primitive field 'trailingDelta'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 68
-        /// - encodedLength: 8
-        #[inline]
-        pub fn trailing_delta(&mut self, value: i64) {
-            let offset = self.offset + 68;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// primitive field 'trailingTime'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 76
-        /// - encodedLength: 8
-        #[inline]
-        pub fn trailing_time(&mut self, value: i64) {
-            let offset = self.offset + 76;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// primitive field 'workingTime'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 84
-        /// - encodedLength: 8
-        #[inline]
-        pub fn working_time(&mut self, value: i64) {
-            let offset = self.offset + 84;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// primitive field 'icebergQty'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 92
-        /// - encodedLength: 8
-        #[inline]
-        pub fn iceberg_qty(&mut self, value: i64) {
-            let offset = self.offset + 92;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// primitive field 'strategyId'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 100
-        /// - encodedLength: 8
-        #[inline]
-        pub fn strategy_id(&mut self, value: i64) {
-            let offset = self.offset + 100;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// primitive field 'strategyType'
-        /// - min value: -2147483647
-        /// - max value: 2147483647
-        /// - null value: -2147483648
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 108
-        /// - encodedLength: 4
-        #[inline]
-        pub fn strategy_type(&mut self, value: i32) {
-            let offset = self.offset + 108;
-            self.get_buf_mut().put_i32_at(offset, value);
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn order_capacity(&mut self, value: OrderCapacity) {
-            let offset = self.offset + 112;
-            self.get_buf_mut().put_u8_at(offset, value as u8)
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn working_floor(&mut self, value: Floor) {
-            let offset = self.offset + 113;
-            self.get_buf_mut().put_u8_at(offset, value as u8)
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn self_trade_prevention_mode(&mut self, value: SelfTradePreventionMode) {
-            let offset = self.offset + 114;
-            self.get_buf_mut().put_u8_at(offset, value as u8)
-        }
-
-        /// primitive field 'tradeGroupId'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 115
-        /// - encodedLength: 8
-        #[inline]
-        pub fn trade_group_id(&mut self, value: i64) {
-            let offset = self.offset + 115;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// primitive field 'preventedQuantity'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 123
-        /// - encodedLength: 8
-        #[inline]
-        pub fn prevented_quantity(&mut self, value: i64) {
-            let offset = self.offset + 123;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn used_sor(&mut self, value: BoolEnum) {
-            let offset = self.offset + 131;
-            self.get_buf_mut().put_u8_at(offset, value as u8)
-        }
-
-        /// primitive field 'origQuoteOrderQty'
-        /// - min value: -9223372036854775807
-        /// - max value: 9223372036854775807
-        /// - null value: -9223372036854775808
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 132
-        /// - encodedLength: 8
-        #[inline]
-        pub fn orig_quote_order_qty(&mut self, value: i64) {
-            let offset = self.offset + 132;
-            self.get_buf_mut().put_i64_at(offset, value);
-        }
-
-        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
-        #[inline]
-        pub fn symbol(&mut self, value: &str) {
-            let limit = self.get_limit();
-            let data_length = value.len();
-            self.set_limit(limit + 1 + data_length);
-            self.get_buf_mut().put_u8_at(limit, data_length as u8);
-            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
-        }
-
-        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
-        #[inline]
-        pub fn client_order_id(&mut self, value: &str) {
-            let limit = self.get_limit();
-            let data_length = value.len();
-            self.set_limit(limit + 1 + data_length);
-            self.get_buf_mut().put_u8_at(limit, data_length as u8);
-            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
-        }
-    }
-} // end encoder
-
-pub mod decoder {
-    use super::*;
-
-    #[derive(Clone, Copy, Debug, Default)]
-    pub struct NewOrderListResultResponseDecoder<'a> {
-        buf: ReadBuf<'a>,
-        initial_offset: usize,
-        offset: usize,
-        limit: usize,
-        pub acting_block_length: u16,
-        pub acting_version: u16,
-    }
-
-    impl<'a> Reader<'a> for NewOrderListResultResponseDecoder<'a> {
-        #[inline]
-        fn get_buf(&self) -> &ReadBuf<'a> {
-            &self.buf
-        }
-    }
-
-    impl<'a> Decoder<'a> for NewOrderListResultResponseDecoder<'a> {
-        #[inline]
-        fn get_limit(&self) -> usize {
-            self.limit
-        }
-
-        #[inline]
-        fn set_limit(&mut self, limit: usize) {
-            self.limit = limit;
-        }
-    }
-
-    impl<'a> NewOrderListResultResponseDecoder<'a> {
-        pub fn wrap(
-            mut self,
-            buf: ReadBuf<'a>,
-            offset: usize,
-            acting_block_length: u16,
-            acting_version: u16,
-        ) -> Self {
-            let limit = offset + acting_block_length as usize;
-            self.buf = buf;
-            self.initial_offset = offset;
-            self.offset = offset;
-            self.limit = limit;
-            self.acting_block_length = acting_block_length;
-            self.acting_version = acting_version;
-            self
-        }
-
-        #[inline]
-        pub fn encoded_length(&self) -> usize {
-            self.limit - self.offset
-        }
-
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
-            let acting_block_length = header.block_length();
-            let acting_version = header.version();
-
-            self.wrap(
-                header.parent().unwrap(),
-                message_header_codec::ENCODED_LENGTH,
-                acting_block_length,
-                acting_version,
-            )
-        }
-
-        /// primitive field - 'REQUIRED'
-        #[inline]
-        pub fn order_list_id(&self) -> i64 {
-            self.get_buf().get_i64_at(self.offset)
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn contingency_type(&self) -> ContingencyType {
-            self.get_buf().get_u8_at(self.offset + 8).into()
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn list_status_type(&self) -> ListStatusType {
-            self.get_buf().get_u8_at(self.offset + 9).into()
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn list_order_status(&self) -> ListOrderStatus {
-            self.get_buf().get_u8_at(self.offset + 10).into()
-        }
-
-        /// primitive field - 'REQUIRED'
-        #[inline]
-        pub fn transaction_time(&self) -> i64 {
-            self.get_buf().get_i64_at(self.offset + 11)
-        }
-
-        /// primitive field - 'REQUIRED'
-        #[inline]
-        pub fn price_exponent(&self) -> i8 {
-            self.get_buf().get_i8_at(self.offset + 19)
-        }
-
-        /// primitive field - 'REQUIRED'
-        #[inline]
-        pub fn qty_exponent(&self) -> i8 {
-            self.get_buf().get_i8_at(self.offset + 20)
-        }
-
-        /// GROUP DECODER (id=100)
-        #[inline]
-        pub fn orders_decoder(self) -> OrdersDecoder<Self> {
-            OrdersDecoder::default().wrap(self)
-        }
-
-        /// GROUP DECODER (id=101)
-        #[inline]
-        pub fn order_reports_decoder(self) -> OrderReportsDecoder<Self> {
-            OrderReportsDecoder::default().wrap(self)
-        }
-
-        /// VAR_DATA DECODER - character encoding: 'UTF-8'
-        #[inline]
-        pub fn list_client_order_id_decoder(&mut self) -> (usize, usize) {
-            let offset = self.get_limit();
-            let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
-        }
-
-        #[inline]
-        pub fn list_client_order_id_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
-        }
-
-        /// VAR_DATA DECODER - character encoding: 'UTF-8'
-        #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.get_limit();
-            let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
-        }
-
-        #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
-        }
-    }
-
-    #[derive(Debug, Default)]
-    pub struct OrdersDecoder<P> {
-        parent: Option<P>,
-        block_length: usize,
-        count: u16,
-        index: usize,
-        offset: usize,
-    }
-
-    impl<'a, P> Reader<'a> for OrdersDecoder<P>
-    where
-        P: Reader<'a> + Default,
-    {
-        #[inline]
-        fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
-        }
-    }
-
-    impl<'a, P> Decoder<'a> for OrdersDecoder<P>
-    where
-        P: Decoder<'a> + Default,
-    {
-        #[inline]
-        fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
-        }
-
-        #[inline]
-        fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
-        }
-    }
-
-    impl<'a, P> OrdersDecoder<P>
-    where
-        P: Decoder<'a> + Default,
-    {
-        pub fn wrap(mut self, mut parent: P) -> Self {
-            let initial_offset = parent.get_limit();
-            let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
-            let count = parent.get_buf().get_u16_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 4);
-            self.parent = Some(parent);
-            self.block_length = block_length;
-            self.count = count;
-            self.index = usize::MAX;
-            self.offset = 0;
-            self
-        }
-
-        /// group token - Token{signal=BEGIN_GROUP, name='orders', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=8, offset=21, componentTokenCount=21, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
-        #[inline]
-        pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
-        }
-
-        #[inline]
-        pub fn count(&self) -> u16 {
-            self.count
-        }
-
-        /// will return Some(current index) when successful otherwise None
-        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
-            let index = self.index.wrapping_add(1);
-            if index >= self.count as usize {
-                return Ok(None);
-            }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
-            }
-        }
-
-        /// primitive field - 'REQUIRED'
-        #[inline]
-        pub fn order_id(&self) -> i64 {
-            self.get_buf().get_i64_at(self.offset)
-        }
-
-        /// VAR_DATA DECODER - character encoding: 'UTF-8'
-        #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
-            let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
-        }
-
-        #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
-        }
-
-        /// VAR_DATA DECODER - character encoding: 'UTF-8'
-        #[inline]
-        pub fn client_order_id_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
-            let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
-        }
-
-        #[inline]
-        pub fn client_order_id_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
-        }
-    }
-
-    #[derive(Debug, Default)]
-    pub struct OrderReportsDecoder<P> {
-        parent: Option<P>,
-        block_length: usize,
-        count: u16,
-        index: usize,
-        offset: usize,
-    }
-
-    impl<'a, P> Reader<'a> for OrderReportsDecoder<P>
-    where
-        P: Reader<'a> + Default,
-    {
-        #[inline]
-        fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
-        }
-    }
-
-    impl<'a, P> Decoder<'a> for OrderReportsDecoder<P>
-    where
-        P: Decoder<'a> + Default,
-    {
-        #[inline]
-        fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
-        }
-
-        #[inline]
-        fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
-        }
-    }
-
-    impl<'a, P> OrderReportsDecoder<P>
-    where
-        P: Decoder<'a> + Default,
-    {
-        pub fn wrap(mut self, mut parent: P) -> Self {
-            let initial_offset = parent.get_limit();
-            let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
-            let count = parent.get_buf().get_u16_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 4);
-            self.parent = Some(parent);
-            self.block_length = block_length;
-            self.count = count;
-            self.index = usize::MAX;
-            self.offset = 0;
-            self
-        }
-
-        /// group token - Token{signal=BEGIN_GROUP, name='orderReports', referencedName='null', description='null', packageName='null', id=101, version=0, deprecated=0, encodedLength=140, offset=-1, componentTokenCount=134, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
-        #[inline]
-        pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
-        }
-
-        #[inline]
-        pub fn count(&self) -> u16 {
-            self.count
-        }
-
-        /// will return Some(current index) when successful otherwise None
-        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
-            let index = self.index.wrapping_add(1);
-            if index >= self.count as usize {
-                return Ok(None);
-            }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
-            }
-        }
-
-        /// primitive field - 'REQUIRED'
-        #[inline]
-        pub fn order_id(&self) -> i64 {
-            self.get_buf().get_i64_at(self.offset)
-        }
-
-        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
-        #[inline]
-        pub fn order_list_id(&self) -> Option<i64> {
-            let value = self.get_buf().get_i64_at(self.offset + 8);
-            if value == -9223372036854775808_i64 {
-                None
-            } else {
-                Some(value)
-            }
-        }
-
-        /// primitive field - 'REQUIRED'
-        #[inline]
-        pub fn transact_time(&self) -> i64 {
-            self.get_buf().get_i64_at(self.offset + 16)
-        }
-
-        /// primitive field - 'REQUIRED'
-        #[inline]
-        pub fn price(&self) -> i64 {
-            self.get_buf().get_i64_at(self.offset + 24)
-        }
-
-        /// primitive field - 'REQUIRED'
-        #[inline]
-        pub fn orig_qty(&self) -> i64 {
-            self.get_buf().get_i64_at(self.offset + 32)
-        }
-
-        /// primitive field - 'REQUIRED'
-        #[inline]
-        pub fn executed_qty(&self) -> i64 {
-            self.get_buf().get_i64_at(self.offset + 40)
-        }
-
-        /// primitive field - 'REQUIRED'
-        #[inline]
-        pub fn cummulative_quote_qty(&self) -> i64 {
-            self.get_buf().get_i64_at(self.offset + 48)
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn status(&self) -> OrderStatus {
-            self.get_buf().get_u8_at(self.offset + 56).into()
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn time_in_force(&self) -> TimeInForce {
-            self.get_buf().get_u8_at(self.offset + 57).into()
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn order_type(&self) -> OrderType {
-            self.get_buf().get_u8_at(self.offset + 58).into()
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn side(&self) -> OrderSide {
-            self.get_buf().get_u8_at(self.offset + 59).into()
-        }
-
-        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
-        #[inline]
-        pub fn stop_price(&self) -> Option<i64> {
-            let value = self.get_buf().get_i64_at(self.offset + 60);
-            if value == -9223372036854775808_i64 {
-                None
-            } else {
-                Some(value)
-            }
-        }
-
-        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
-        #[inline]
-        pub fn trailing_delta(&self) -> Option<i64> {
-            let value = self.get_buf().get_i64_at(self.offset + 68);
-            if value == -9223372036854775808_i64 {
-                None
-            } else {
-                Some(value)
-            }
-        }
-
-        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
-        #[inline]
-        pub fn trailing_time(&self) -> Option<i64> {
-            let value = self.get_buf().get_i64_at(self.offset + 76);
-            if value == -9223372036854775808_i64 {
-                None
-            } else {
-                Some(value)
-            }
-        }
-
-        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
-        #[inline]
-        pub fn working_time(&self) -> Option<i64> {
-            let value = self.get_buf().get_i64_at(self.offset + 84);
-            if value == -9223372036854775808_i64 {
-                None
-            } else {
-                Some(value)
-            }
-        }
-
-        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
-        #[inline]
-        pub fn iceberg_qty(&self) -> Option<i64> {
-            let value = self.get_buf().get_i64_at(self.offset + 92);
-            if value == -9223372036854775808_i64 {
-                None
-            } else {
-                Some(value)
-            }
-        }
-
-        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
-        #[inline]
-        pub fn strategy_id(&self) -> Option<i64> {
-            let value = self.get_buf().get_i64_at(self.offset + 100);
-            if value == -9223372036854775808_i64 {
-                None
-            } else {
-                Some(value)
-            }
-        }
-
-        /// primitive field - 'OPTIONAL' { null_value: '-2147483648' }
-        #[inline]
-        pub fn strategy_type(&self) -> Option<i32> {
-            let value = self.get_buf().get_i32_at(self.offset + 108);
-            if value == -2147483648_i32 {
-                None
-            } else {
-                Some(value)
-            }
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn order_capacity(&self) -> OrderCapacity {
-            self.get_buf().get_u8_at(self.offset + 112).into()
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn working_floor(&self) -> Floor {
-            self.get_buf().get_u8_at(self.offset + 113).into()
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn self_trade_prevention_mode(&self) -> SelfTradePreventionMode {
-            self.get_buf().get_u8_at(self.offset + 114).into()
-        }
-
-        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
-        #[inline]
-        pub fn trade_group_id(&self) -> Option<i64> {
-            let value = self.get_buf().get_i64_at(self.offset + 115);
-            if value == -9223372036854775808_i64 {
-                None
-            } else {
-                Some(value)
-            }
-        }
-
-        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
-        #[inline]
-        pub fn prevented_quantity(&self) -> Option<i64> {
-            let value = self.get_buf().get_i64_at(self.offset + 123);
-            if value == -9223372036854775808_i64 {
-                None
-            } else {
-                Some(value)
-            }
-        }
-
-        /// REQUIRED enum
-        #[inline]
-        pub fn used_sor(&self) -> BoolEnum {
-            self.get_buf().get_u8_at(self.offset + 131).into()
-        }
-
-        /// primitive field - 'REQUIRED'
-        #[inline]
-        pub fn orig_quote_order_qty(&self) -> i64 {
-            // if self.acting_version > 0 && self.acting_version < 1 {
-            //     return -9223372036854775808_i64;
-            // }
-
-            self.get_buf().get_i64_at(self.offset + 132)
-        }
-
-        /// VAR_DATA DECODER - character encoding: 'UTF-8'
-        #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
-            let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
-        }
-
-        #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
-        }
-
-        /// VAR_DATA DECODER - character encoding: 'UTF-8'
-        #[inline]
-        pub fn client_order_id_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
-            let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
-        }
-
-        #[inline]
-        pub fn client_order_id_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
-        }
-    }
-} // end decoder
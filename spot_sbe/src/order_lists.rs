@@ -0,0 +1,853 @@
+//! Order list (OCO and OTO) tracking from the user data stream's
+//! `listStatus` events and the order list responses.
+//!
+//! Each list is keyed by symbol and `orderListId`. Its member orders are
+//! linked both ways: by the orders the list names, and by the
+//! `orderListId` of their execution reports, whichever arrives first, so
+//! that their state can be looked up in an [`OrderTracker`].
+
+use crate::orders::{OrderTracker, TrackedOrder};
+use crate::*;
+use std::collections::{BTreeMap, HashMap};
+
+/// An order named by an order list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListOrder {
+    pub order_id: i64,
+    pub symbol: String,
+    pub client_order_id: String,
+}
+
+/// An order list as of its last status.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrackedList {
+    pub symbol: String,
+    pub order_list_id: i64,
+    pub list_client_order_id: String,
+    pub contingency_type: ContingencyType,
+    pub list_status_type: ListStatusType,
+    pub list_order_status: ListOrderStatus,
+    pub transaction_time: i64,
+    pub orders: Vec<ListOrder>,
+    /// Empty unless the list was rejected
+    pub reject_reason: String,
+}
+
+/// A change of an order list's `listStatusType` or `listOrderStatus`, e.g.
+/// `EXEC_STARTED` to `ALL_DONE`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transition {
+    pub symbol: String,
+    pub order_list_id: i64,
+    pub list_client_order_id: String,
+    pub transaction_time: i64,
+    /// `None` for a list not seen before
+    pub from: Option<(ListStatusType, ListOrderStatus)>,
+    pub to: (ListStatusType, ListOrderStatus),
+    /// Set when the list moves to `REJECT`
+    pub reject_reason: Option<String>,
+}
+
+/// A list status as decoded from any of the messages that carry one.
+struct ListUpdate {
+    symbol: String,
+    order_list_id: i64,
+    list_client_order_id: String,
+    contingency_type: ContingencyType,
+    list_status_type: ListStatusType,
+    list_order_status: ListOrderStatus,
+    transaction_time: i64,
+    orders: Vec<ListOrder>,
+    reject_reason: String,
+}
+
+/// Order lists of one account.
+#[derive(Clone, Debug, Default)]
+pub struct OrderListTracker {
+    lists: BTreeMap<(String, i64), TrackedList>,
+    /// `(symbol, orderId)` to `orderListId`
+    list_ids: HashMap<(String, i64), i64>,
+    transitions: Vec<Transition>,
+}
+
+/// Reads an `orders` group of `orderId`, `symbol` and `clientOrderId`
+/// entries, which every order list message has in a codec of its own.
+macro_rules! read_orders {
+    ($parent:expr) => {{
+        let mut orders_decoder = $parent.orders_decoder()?;
+        let mut orders = Vec::new();
+        while orders_decoder.advance()?.is_some() {
+            let order_id = orders_decoder.order_id();
+            let coordinates = orders_decoder.symbol_decoder()?;
+            let symbol = utf8(orders_decoder.symbol_slice(coordinates)?)?;
+            let coordinates = orders_decoder.client_order_id_decoder()?;
+            let client_order_id = utf8(orders_decoder.client_order_id_slice(coordinates)?)?;
+            orders.push(ListOrder {
+                order_id,
+                symbol,
+                client_order_id,
+            });
+        }
+        (orders, orders_decoder.parent()?)
+    }};
+}
+
+/// Decodes an `orderList.place` response whose order reports end with only
+/// `symbol` and `clientOrderId` var data, as the ACK and RESULT ones do.
+macro_rules! decode_placed_list {
+    ($decoder:expr) => {{
+        let decoder = $decoder;
+        let order_list_id = decoder.order_list_id();
+        let contingency_type = decoder.contingency_type();
+        let list_status_type = decoder.list_status_type();
+        let list_order_status = decoder.list_order_status();
+        let transaction_time = decoder.transaction_time();
+        let (orders, decoder) = read_orders!(decoder);
+        let mut reports_decoder = decoder.order_reports_decoder()?;
+        while reports_decoder.advance()?.is_some() {
+            reports_decoder.symbol_decoder()?;
+            reports_decoder.client_order_id_decoder()?;
+        }
+        let mut decoder = reports_decoder.parent()?;
+        let coordinates = decoder.list_client_order_id_decoder()?;
+        let list_client_order_id = utf8(decoder.list_client_order_id_slice(coordinates)?)?;
+        let coordinates = decoder.symbol_decoder()?;
+        let symbol = utf8(decoder.symbol_slice(coordinates)?)?;
+        Ok(ListUpdate {
+            symbol,
+            order_list_id,
+            list_client_order_id,
+            contingency_type,
+            list_status_type,
+            list_order_status,
+            transaction_time,
+            orders,
+            reject_reason: String::new(),
+        })
+    }};
+}
+
+impl OrderListTracker {
+    /// Applies the message at the start of `data` with [`apply`](Self::apply).
+    pub fn apply_bytes(&mut self, data: &[u8]) -> SbeResult<bool> {
+        self.apply(decode_any(data)?)
+    }
+
+    /// Applies a `listStatus` event, an `orderList.place` response of any
+    /// `newOrderRespType`, an `orderList.cancel` or `orderList.status`
+    /// response, an `openOrderLists` or `allOrderLists` response, or links
+    /// an `executionReport` to its list.
+    /// Returns `false` for any other message, and for list statuses older
+    /// than the one the tracker has.
+    pub fn apply(&mut self, message: AnyMessage<'_>) -> SbeResult<bool> {
+        match message {
+            AnyMessage::ListStatusEvent(decoder) => {
+                let update = decode_list_status(decoder)?;
+                Ok(self.update(update))
+            }
+            AnyMessage::NewOrderListAckResponse(decoder) => {
+                let update = decode_new_order_list_ack(decoder)?;
+                Ok(self.update(update))
+            }
+            AnyMessage::NewOrderListResultResponse(decoder) => {
+                let update = decode_new_order_list_result(decoder)?;
+                Ok(self.update(update))
+            }
+            AnyMessage::NewOrderListFullResponse(decoder) => {
+                let update = decode_new_order_list(decoder)?;
+                Ok(self.update(update))
+            }
+            AnyMessage::CancelOrderListResponse(decoder) => {
+                let update = decode_cancel_order_list(decoder)?;
+                Ok(self.update(update))
+            }
+            AnyMessage::OrderListResponse(decoder) => {
+                let update = decode_order_list(decoder)?;
+                Ok(self.update(update))
+            }
+            AnyMessage::OrderListsResponse(decoder) => {
+                let mut applied = false;
+                for update in decode_order_lists(decoder)? {
+                    applied |= self.update(update);
+                }
+                Ok(applied)
+            }
+            AnyMessage::ExecutionReportEvent(mut decoder) => {
+                let (order_id, order_list_id) = (decoder.order_id(), decoder.order_list_id());
+                let coordinates = decoder.symbol_decoder()?;
+                let symbol = utf8(decoder.symbol_slice(coordinates)?)?;
+                let Some(order_list_id) = order_list_id else {
+                    return Ok(false);
+                };
+                self.list_ids.insert((symbol, order_id), order_list_id);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn update(&mut self, update: ListUpdate) -> bool {
+        let key = (update.symbol.clone(), update.order_list_id);
+        let to = (update.list_status_type, update.list_order_status);
+        let from = match self.lists.get(&key) {
+            Some(list) if update.transaction_time < list.transaction_time => return false,
+            Some(list) => Some((list.list_status_type, list.list_order_status)),
+            None => None,
+        };
+        for order in &update.orders {
+            self.list_ids
+                .insert((order.symbol.clone(), order.order_id), update.order_list_id);
+        }
+        if from != Some(to) {
+            self.transitions.push(Transition {
+                symbol: update.symbol.clone(),
+                order_list_id: update.order_list_id,
+                list_client_order_id: update.list_client_order_id.clone(),
+                transaction_time: update.transaction_time,
+                from,
+                to,
+                reject_reason: (update.list_order_status == ListOrderStatus::Reject)
+                    .then(|| update.reject_reason.clone()),
+            });
+        }
+        let list = self.lists.entry(key).or_insert_with(|| TrackedList {
+            symbol: update.symbol,
+            order_list_id: update.order_list_id,
+            list_client_order_id: String::new(),
+            contingency_type: ContingencyType::NullVal,
+            list_status_type: ListStatusType::NullVal,
+            list_order_status: ListOrderStatus::NullVal,
+            transaction_time: 0,
+            orders: Vec::new(),
+            reject_reason: String::new(),
+        });
+        // Not every message carries every member
+        if !update.list_client_order_id.is_empty() {
+            list.list_client_order_id = update.list_client_order_id;
+        }
+        if !update.orders.is_empty() {
+            list.orders = update.orders;
+        }
+        if update.list_order_status == ListOrderStatus::Reject {
+            list.reject_reason = update.reject_reason;
+        }
+        list.contingency_type = update.contingency_type;
+        list.list_status_type = update.list_status_type;
+        list.list_order_status = update.list_order_status;
+        list.transaction_time = update.transaction_time;
+        true
+    }
+
+    #[inline]
+    pub fn list(&self, symbol: &str, order_list_id: i64) -> Option<&TrackedList> {
+        self.lists.get(&(symbol.to_owned(), order_list_id))
+    }
+
+    /// The list an order belongs to, if the list has been seen.
+    pub fn list_of_order(&self, symbol: &str, order_id: i64) -> Option<&TrackedList> {
+        let order_list_id = *self.list_ids.get(&(symbol.to_owned(), order_id))?;
+        self.list(symbol, order_list_id)
+    }
+
+    /// Every list, by symbol then `orderListId`.
+    pub fn lists(&self) -> impl Iterator<Item = &TrackedList> + '_ {
+        self.lists.values()
+    }
+
+    /// The member orders of a list that `orders` has execution reports for,
+    /// whether the list named them or their reports did.
+    pub fn orders_of<'a>(
+        &'a self,
+        symbol: &'a str,
+        order_list_id: i64,
+        orders: &'a OrderTracker,
+    ) -> impl Iterator<Item = &'a TrackedOrder> + 'a {
+        orders.orders().filter(move |order| {
+            order.symbol == symbol
+                && self
+                    .list_ids
+                    .get(&(order.symbol.clone(), order.order_id))
+                    .is_some_and(|&id| id == order_list_id)
+        })
+    }
+
+    /// Transitions since they were last taken, in the order they happened.
+    #[inline]
+    pub fn transitions(&self) -> &[Transition] {
+        &self.transitions
+    }
+
+    pub fn take_transitions(&mut self) -> Vec<Transition> {
+        core::mem::take(&mut self.transitions)
+    }
+}
+
+fn decode_list_status(decoder: ListStatusEventDecoder<'_>) -> SbeResult<ListUpdate> {
+    let order_list_id = decoder.order_list_id();
+    let contingency_type = decoder.contingency_type();
+    let list_status_type = decoder.list_status_type();
+    let list_order_status = decoder.list_order_status();
+    let transaction_time = decoder.transact_time();
+    let (orders, mut decoder) = read_orders!(decoder);
+    let coordinates = decoder.symbol_decoder()?;
+    let symbol = utf8(decoder.symbol_slice(coordinates)?)?;
+    let coordinates = decoder.list_client_order_id_decoder()?;
+    let list_client_order_id = utf8(decoder.list_client_order_id_slice(coordinates)?)?;
+    let coordinates = decoder.reject_reason_decoder()?;
+    let reject_reason = utf8(decoder.reject_reason_slice(coordinates)?)?;
+    Ok(ListUpdate {
+        symbol,
+        order_list_id,
+        list_client_order_id,
+        contingency_type,
+        list_status_type,
+        list_order_status,
+        transaction_time,
+        orders,
+        reject_reason,
+    })
+}
+
+fn decode_new_order_list_ack(decoder: NewOrderListAckResponseDecoder<'_>) -> SbeResult<ListUpdate> {
+    decode_placed_list!(decoder)
+}
+
+fn decode_new_order_list_result(
+    decoder: NewOrderListResultResponseDecoder<'_>,
+) -> SbeResult<ListUpdate> {
+    decode_placed_list!(decoder)
+}
+
+fn decode_new_order_list(decoder: NewOrderListFullResponseDecoder<'_>) -> SbeResult<ListUpdate> {
+    let order_list_id = decoder.order_list_id();
+    let contingency_type = decoder.contingency_type();
+    let list_status_type = decoder.list_status_type();
+    let list_order_status = decoder.list_order_status();
+    let transaction_time = decoder.transaction_time();
+    let (orders, decoder) = read_orders!(decoder);
+    // Skip the order reports, whose fills and prevented matches are only
+    // reachable by walking them.
+    let mut reports_decoder = decoder.order_reports_decoder()?;
+    while reports_decoder.advance()?.is_some() {
+        let mut fills_decoder = reports_decoder.fills_decoder()?;
+        while fills_decoder.advance()?.is_some() {
+            fills_decoder.commission_asset_decoder()?;
+        }
+        let mut prevented_decoder = fills_decoder.parent()?.prevented_matches_decoder()?;
+        while prevented_decoder.advance()?.is_some() {
+            prevented_decoder.maker_symbol_decoder()?;
+        }
+        reports_decoder = prevented_decoder.parent()?;
+        reports_decoder.symbol_decoder()?;
+        reports_decoder.client_order_id_decoder()?;
+    }
+    let mut decoder = reports_decoder.parent()?;
+    let coordinates = decoder.list_client_order_id_decoder()?;
+    let list_client_order_id = utf8(decoder.list_client_order_id_slice(coordinates)?)?;
+    let coordinates = decoder.symbol_decoder()?;
+    let symbol = utf8(decoder.symbol_slice(coordinates)?)?;
+    Ok(ListUpdate {
+        symbol,
+        order_list_id,
+        list_client_order_id,
+        contingency_type,
+        list_status_type,
+        list_order_status,
+        transaction_time,
+        orders,
+        reject_reason: String::new(),
+    })
+}
+
+fn decode_cancel_order_list(decoder: CancelOrderListResponseDecoder<'_>) -> SbeResult<ListUpdate> {
+    let order_list_id = decoder.order_list_id();
+    let contingency_type = decoder.contingency_type();
+    let list_status_type = decoder.list_status_type();
+    let list_order_status = decoder.list_order_status();
+    let transaction_time = decoder.transaction_time();
+    let (orders, decoder) = read_orders!(decoder);
+    let mut reports_decoder = decoder.order_reports_decoder()?;
+    while reports_decoder.advance()?.is_some() {
+        reports_decoder.symbol_decoder()?;
+        reports_decoder.orig_client_order_id_decoder()?;
+        reports_decoder.client_order_id_decoder()?;
+    }
+    let mut decoder = reports_decoder.parent()?;
+    let coordinates = decoder.list_client_order_id_decoder()?;
+    let list_client_order_id = utf8(decoder.list_client_order_id_slice(coordinates)?)?;
+    let coordinates = decoder.symbol_decoder()?;
+    let symbol = utf8(decoder.symbol_slice(coordinates)?)?;
+    Ok(ListUpdate {
+        symbol,
+        order_list_id,
+        list_client_order_id,
+        contingency_type,
+        list_status_type,
+        list_order_status,
+        transaction_time,
+        orders,
+        reject_reason: String::new(),
+    })
+}
+
+fn decode_order_list(decoder: OrderListResponseDecoder<'_>) -> SbeResult<ListUpdate> {
+    let order_list_id = decoder.order_list_id();
+    let contingency_type = decoder.contingency_type();
+    let list_status_type = decoder.list_status_type();
+    let list_order_status = decoder.list_order_status();
+    let transaction_time = decoder.transaction_time();
+    let (orders, mut decoder) = read_orders!(decoder);
+    let coordinates = decoder.list_client_order_id_decoder()?;
+    let list_client_order_id = utf8(decoder.list_client_order_id_slice(coordinates)?)?;
+    let coordinates = decoder.symbol_decoder()?;
+    let symbol = utf8(decoder.symbol_slice(coordinates)?)?;
+    Ok(ListUpdate {
+        symbol,
+        order_list_id,
+        list_client_order_id,
+        contingency_type,
+        list_status_type,
+        list_order_status,
+        transaction_time,
+        orders,
+        reject_reason: String::new(),
+    })
+}
+
+fn decode_order_lists(decoder: OrderListsResponseDecoder<'_>) -> SbeResult<Vec<ListUpdate>> {
+    let mut lists_decoder = decoder.order_lists_decoder()?;
+    let mut updates = Vec::new();
+    while lists_decoder.advance()?.is_some() {
+        let order_list_id = lists_decoder.order_list_id();
+        let contingency_type = lists_decoder.contingency_type();
+        let list_status_type = lists_decoder.list_status_type();
+        let list_order_status = lists_decoder.list_order_status();
+        let transaction_time = lists_decoder.transaction_time();
+        let (orders, parent) = read_orders!(lists_decoder);
+        lists_decoder = parent;
+        let coordinates = lists_decoder.list_client_order_id_decoder()?;
+        let list_client_order_id = utf8(lists_decoder.list_client_order_id_slice(coordinates)?)?;
+        let coordinates = lists_decoder.symbol_decoder()?;
+        let symbol = utf8(lists_decoder.symbol_slice(coordinates)?)?;
+        updates.push(ListUpdate {
+            symbol,
+            order_list_id,
+            list_client_order_id,
+            contingency_type,
+            list_status_type,
+            list_order_status,
+            transaction_time,
+            orders,
+            reject_reason: String::new(),
+        });
+    }
+    Ok(updates)
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::cancel_order_list_response_codec::owned as cancel;
+    use crate::execution_report_event_codec::owned::ExecutionReportEvent;
+    use crate::list_status_event_codec::owned as event;
+    use crate::new_order_list_ack_response_codec::owned as ack;
+    use crate::new_order_list_full_response_codec::owned as full;
+    use crate::new_order_list_result_response_codec::owned as result;
+    use crate::order_list_response_codec::owned as status;
+    use crate::order_lists_response_codec::owned as all;
+
+    const SYMBOL: &str = "BTCUSDT";
+
+    fn order(order_id: i64) -> ListOrder {
+        ListOrder {
+            order_id,
+            symbol: SYMBOL.into(),
+            client_order_id: format!("leg-{order_id}"),
+        }
+    }
+
+    fn placed_ack(order_list_id: i64, transaction_time: i64) -> Vec<u8> {
+        let orders = [order(1), order(2)].map(|order| ack::Orders {
+            order_id: order.order_id,
+            symbol: order.symbol,
+            client_order_id: order.client_order_id,
+        });
+        ack::NewOrderListAckResponse {
+            order_list_id,
+            contingency_type: ContingencyType::Oco,
+            list_status_type: ListStatusType::ExecStarted,
+            list_order_status: ListOrderStatus::Executing,
+            transaction_time,
+            orders: orders.to_vec(),
+            order_reports: vec![ack::OrderReports {
+                order_id: 1,
+                symbol: SYMBOL.into(),
+                client_order_id: "leg-1".into(),
+                ..Default::default()
+            }],
+            list_client_order_id: "my-list".into(),
+            symbol: SYMBOL.into(),
+        }
+        .encode()
+        .unwrap()
+    }
+
+    fn placed_result(order_list_id: i64, transaction_time: i64) -> Vec<u8> {
+        result::NewOrderListResultResponse {
+            order_list_id,
+            contingency_type: ContingencyType::Oto,
+            list_status_type: ListStatusType::ExecStarted,
+            list_order_status: ListOrderStatus::Executing,
+            transaction_time,
+            orders: vec![result::Orders {
+                order_id: 3,
+                symbol: SYMBOL.into(),
+                client_order_id: "leg-3".into(),
+            }],
+            order_reports: vec![result::OrderReports {
+                order_id: 3,
+                price: "100.5".parse().unwrap(),
+                symbol: SYMBOL.into(),
+                client_order_id: "leg-3".into(),
+                ..Default::default()
+            }],
+            list_client_order_id: "other-list".into(),
+            symbol: SYMBOL.into(),
+            ..Default::default()
+        }
+        .encode()
+        .unwrap()
+    }
+
+    fn queried(order_list_id: i64, transaction_time: i64) -> Vec<u8> {
+        status::OrderListResponse {
+            order_list_id,
+            contingency_type: ContingencyType::Oco,
+            list_status_type: ListStatusType::AllDone,
+            list_order_status: ListOrderStatus::AllDone,
+            transaction_time,
+            orders: vec![],
+            list_client_order_id: "my-list".into(),
+            symbol: SYMBOL.into(),
+        }
+        .encode()
+        .unwrap()
+    }
+
+    fn list_status(
+        order_list_id: i64,
+        transact_time: i64,
+        list_status_type: ListStatusType,
+        list_order_status: ListOrderStatus,
+        reject_reason: Option<&str>,
+    ) -> Vec<u8> {
+        event::ListStatusEvent {
+            event_time: transact_time,
+            transact_time,
+            order_list_id,
+            contingency_type: ContingencyType::Oco,
+            list_status_type,
+            list_order_status,
+            orders: [order(1), order(2)]
+                .map(|order| event::Orders {
+                    order_id: order.order_id,
+                    symbol: order.symbol,
+                    client_order_id: order.client_order_id,
+                })
+                .to_vec(),
+            symbol: SYMBOL.into(),
+            list_client_order_id: "my-list".into(),
+            reject_reason: reject_reason.map(Into::into),
+        }
+        .encode()
+        .unwrap()
+    }
+
+    fn execution_report(order_id: i64, order_list_id: Option<i64>) -> Vec<u8> {
+        ExecutionReportEvent {
+            order_id,
+            order_list_id,
+            execution_id: order_id,
+            execution_type: ExecutionType::New,
+            order_status: OrderStatus::New,
+            side: OrderSide::Sell,
+            orig_qty: "1".parse().unwrap(),
+            price: "100".parse().unwrap(),
+            symbol: SYMBOL.into(),
+            client_order_id: format!("leg-{order_id}"),
+            ..Default::default()
+        }
+        .encode()
+        .unwrap()
+    }
+
+    #[test]
+    fn follows_list_status_events() {
+        let mut lists = OrderListTracker::default();
+        let started = list_status(
+            7,
+            10,
+            ListStatusType::ExecStarted,
+            ListOrderStatus::Executing,
+            None,
+        );
+        let done = list_status(
+            7,
+            20,
+            ListStatusType::AllDone,
+            ListOrderStatus::AllDone,
+            None,
+        );
+        assert_eq!(lists.apply_bytes(&started), Ok(true));
+        assert_eq!(lists.apply_bytes(&done), Ok(true));
+        // a repeated status is applied but is not a transition
+        assert_eq!(lists.apply_bytes(&done), Ok(true));
+        let list = lists.list_of_order(SYMBOL, 1).unwrap();
+        assert_eq!(list.order_list_id, 7);
+        assert_eq!(list.transaction_time, 20);
+        assert_eq!(list.orders, [order(1), order(2)]);
+        let transitions = lists.take_transitions();
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(
+            transitions[1],
+            Transition {
+                symbol: SYMBOL.into(),
+                order_list_id: 7,
+                list_client_order_id: "my-list".into(),
+                transaction_time: 20,
+                from: Some((ListStatusType::ExecStarted, ListOrderStatus::Executing)),
+                to: (ListStatusType::AllDone, ListOrderStatus::AllDone),
+                reject_reason: None,
+            }
+        );
+    }
+
+    #[test]
+    fn records_the_reject_reason() {
+        let mut lists = OrderListTracker::default();
+        let rejected = list_status(
+            7,
+            10,
+            ListStatusType::Response,
+            ListOrderStatus::Reject,
+            Some("Order would immediately trigger."),
+        );
+        assert_eq!(lists.apply_bytes(&rejected), Ok(true));
+        let list = lists.list(SYMBOL, 7).unwrap();
+        assert_eq!(list.reject_reason, "Order would immediately trigger.");
+        let [transition] = lists.transitions() else {
+            panic!("expected one transition");
+        };
+        assert_eq!(transition.from, None);
+        assert_eq!(
+            transition.to,
+            (ListStatusType::Response, ListOrderStatus::Reject)
+        );
+        assert_eq!(
+            transition.reject_reason.as_deref(),
+            Some("Order would immediately trigger.")
+        );
+    }
+
+    #[test]
+    fn links_execution_reports_to_their_list() {
+        let mut lists = OrderListTracker::default();
+        let mut orders = OrderTracker::default();
+        for (frame, linked) in [
+            (execution_report(4, Some(9)), true),
+            (execution_report(5, Some(9)), true),
+            (execution_report(6, None), false),
+        ] {
+            assert_eq!(lists.apply_bytes(&frame), Ok(linked));
+            assert_eq!(orders.apply_bytes(&frame), Ok(true));
+        }
+        // the reports arrive before the list itself
+        assert!(lists.list_of_order(SYMBOL, 4).is_none());
+        lists.apply_bytes(&queried(9, 10)).unwrap();
+        assert_eq!(lists.list_of_order(SYMBOL, 4).unwrap().order_list_id, 9);
+        assert!(lists.list_of_order(SYMBOL, 6).is_none());
+
+        let members: Vec<_> = lists
+            .orders_of(SYMBOL, 9, &orders)
+            .map(|order| order.order_id)
+            .collect();
+        assert_eq!(members, [4, 5]);
+        assert_eq!(lists.orders_of("ETHUSDT", 9, &orders).count(), 0);
+    }
+
+    #[test]
+    fn tracks_lists_from_full_responses() {
+        let frame = full::NewOrderListFullResponse {
+            order_list_id: 7,
+            contingency_type: ContingencyType::Oco,
+            list_status_type: ListStatusType::ExecStarted,
+            list_order_status: ListOrderStatus::Executing,
+            transaction_time: 10,
+            orders: vec![full::Orders {
+                order_id: 1,
+                symbol: SYMBOL.into(),
+                client_order_id: "leg-1".into(),
+            }],
+            order_reports: vec![full::OrderReports {
+                order_id: 1,
+                fills: vec![full::Fills {
+                    price: "100".parse().unwrap(),
+                    qty: "0.5".parse().unwrap(),
+                    commission_asset: Some("BNB".into()),
+                    ..Default::default()
+                }],
+                prevented_matches: vec![full::PreventedMatches {
+                    prevented_match_id: 3,
+                    maker_symbol: Some(SYMBOL.into()),
+                    ..Default::default()
+                }],
+                symbol: SYMBOL.into(),
+                client_order_id: "leg-1".into(),
+                ..Default::default()
+            }],
+            list_client_order_id: "my-list".into(),
+            symbol: SYMBOL.into(),
+            ..Default::default()
+        }
+        .encode()
+        .unwrap();
+        let mut lists = OrderListTracker::default();
+        assert_eq!(lists.apply_bytes(&frame), Ok(true));
+        // the list members after the order reports are still read
+        let list = lists.list_of_order(SYMBOL, 1).unwrap();
+        assert_eq!(list.list_client_order_id, "my-list");
+        assert_eq!(list.orders, [order(1)]);
+    }
+
+    #[test]
+    fn tracks_lists_from_cancel_responses() {
+        let frame = cancel::CancelOrderListResponse {
+            order_list_id: 7,
+            contingency_type: ContingencyType::Oco,
+            list_status_type: ListStatusType::AllDone,
+            list_order_status: ListOrderStatus::AllDone,
+            transaction_time: 20,
+            orders: vec![cancel::Orders {
+                order_id: 2,
+                symbol: SYMBOL.into(),
+                client_order_id: "leg-2".into(),
+            }],
+            order_reports: vec![cancel::OrderReports {
+                order_id: 2,
+                status: OrderStatus::Canceled,
+                symbol: SYMBOL.into(),
+                orig_client_order_id: "leg-2".into(),
+                client_order_id: "cancel-2".into(),
+                ..Default::default()
+            }],
+            list_client_order_id: "my-list".into(),
+            symbol: SYMBOL.into(),
+            ..Default::default()
+        }
+        .encode()
+        .unwrap();
+        let mut lists = OrderListTracker::default();
+        lists.apply_bytes(&placed_ack(7, 10)).unwrap();
+        assert_eq!(lists.apply_bytes(&frame), Ok(true));
+        let list = lists.list_of_order(SYMBOL, 2).unwrap();
+        assert_eq!(list.list_order_status, ListOrderStatus::AllDone);
+        assert_eq!(list.orders, [order(2)]);
+        assert_eq!(
+            lists.transitions().last().unwrap().to,
+            (ListStatusType::AllDone, ListOrderStatus::AllDone)
+        );
+    }
+
+    #[test]
+    fn tracks_every_list_of_an_order_lists_response() {
+        let list = |order_list_id: i64, order_id: i64| all::OrderLists {
+            order_list_id,
+            contingency_type: ContingencyType::Oto,
+            list_status_type: ListStatusType::ExecStarted,
+            list_order_status: ListOrderStatus::Executing,
+            transaction_time: 10,
+            orders: vec![all::Orders {
+                order_id,
+                symbol: SYMBOL.into(),
+                client_order_id: format!("leg-{order_id}"),
+            }],
+            list_client_order_id: format!("list-{order_list_id}"),
+            symbol: SYMBOL.into(),
+        };
+        let frame = all::OrderListsResponse {
+            order_lists: vec![list(7, 1), list(8, 2)],
+        }
+        .encode()
+        .unwrap();
+        let mut lists = OrderListTracker::default();
+        assert_eq!(lists.apply_bytes(&frame), Ok(true));
+        let ids: Vec<_> = lists.lists().map(|list| list.order_list_id).collect();
+        assert_eq!(ids, [7, 8]);
+        assert_eq!(
+            lists.list_of_order(SYMBOL, 2).unwrap().list_client_order_id,
+            "list-8"
+        );
+        // an older copy of a tracked list is not applied
+        let stale = all::OrderListsResponse {
+            order_lists: vec![all::OrderLists {
+                transaction_time: 5,
+                ..list(7, 1)
+            }],
+        }
+        .encode()
+        .unwrap();
+        assert_eq!(lists.apply_bytes(&stale), Ok(false));
+        assert_eq!(lists.transitions().len(), 2);
+    }
+
+    #[test]
+    fn tracks_lists_from_ack_and_status_responses() {
+        let mut lists = OrderListTracker::default();
+        assert_eq!(lists.apply_bytes(&placed_ack(7, 10)), Ok(true));
+        let list = lists.list_of_order(SYMBOL, 2).unwrap();
+        assert_eq!(list.order_list_id, 7);
+        assert_eq!(list.list_client_order_id, "my-list");
+        assert_eq!(list.orders, [order(1), order(2)]);
+
+        assert_eq!(lists.apply_bytes(&queried(7, 20)), Ok(true));
+        let list = lists.list(SYMBOL, 7).unwrap();
+        assert_eq!(list.list_order_status, ListOrderStatus::AllDone);
+        // the status response names no orders, so the ack's are kept
+        assert_eq!(list.orders.len(), 2);
+
+        let transitions = lists.take_transitions();
+        let steps: Vec<_> = transitions.iter().map(|t| (t.from, t.to)).collect();
+        assert_eq!(
+            steps,
+            [
+                (
+                    None,
+                    (ListStatusType::ExecStarted, ListOrderStatus::Executing)
+                ),
+                (
+                    Some((ListStatusType::ExecStarted, ListOrderStatus::Executing)),
+                    (ListStatusType::AllDone, ListOrderStatus::AllDone)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn tracks_lists_from_result_responses() {
+        let mut lists = OrderListTracker::default();
+        assert_eq!(lists.apply_bytes(&placed_result(8, 10)), Ok(true));
+        let list = lists.list_of_order(SYMBOL, 3).unwrap();
+        assert_eq!(list.contingency_type, ContingencyType::Oto);
+        assert_eq!(list.list_client_order_id, "other-list");
+    }
+
+    #[test]
+    fn ignores_statuses_older_than_the_tracked_one() {
+        let mut lists = OrderListTracker::default();
+        lists.apply_bytes(&queried(7, 20)).unwrap();
+        assert_eq!(lists.apply_bytes(&placed_ack(7, 10)), Ok(false));
+        let list = lists.list(SYMBOL, 7).unwrap();
+        assert_eq!(list.list_status_type, ListStatusType::AllDone);
+        assert_eq!(lists.transitions().len(), 1);
+    }
+}
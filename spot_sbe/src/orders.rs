@@ -0,0 +1,515 @@
+//! Order lifecycle tracking from the user data stream's `executionReport`
+//! events.
+//!
+//! Orders are keyed by symbol and `orderId`, and can also be looked up by
+//! the `clientOrderId` they were placed with. Each report is checked against
+//! the order's previous status; the state follows the report either way.
+//!
+//! Each order remembers the `executionId` of the last report applied to it.
+//! A repeated or lower ID marks a duplicate or reordered report, which is
+//! dropped. `executionId` increases per symbol across every account, so the
+//! IDs of one order are not contiguous: trade reports missed between two
+//! IDs show up as an `executedQty` that does not add up, and are reported
+//! with the range of IDs they fell in.
+//!
+//! An order first seen in a later report, such as one placed before
+//! tracking started, is seeded from that report as it stands.
+
+use crate::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// A trade of one order, from an execution report with execution type
+/// `TRADE`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fill {
+    pub execution_id: i64,
+    pub trade_id: Option<i64>,
+    pub transact_time: i64,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub quote_qty: Decimal,
+    pub commission: Decimal,
+    pub commission_asset: String,
+    pub is_maker: bool,
+}
+
+/// An order as of its last execution report.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrackedOrder {
+    pub symbol: String,
+    pub order_id: i64,
+    /// The `clientOrderId` the order was placed with
+    pub client_order_id: String,
+    pub order_list_id: Option<i64>,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub status: OrderStatus,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
+    pub prevented_qty: Option<Decimal>,
+    pub fills: Vec<Fill>,
+    /// Total commission by asset
+    pub commissions: BTreeMap<String, Decimal>,
+    /// Empty unless the order was rejected
+    pub reject_reason: String,
+    pub transact_time: i64,
+    /// `executionId` of the last report applied
+    pub last_execution_id: i64,
+    execution_ids: BTreeSet<i64>,
+}
+
+impl TrackedOrder {
+    /// Filled, canceled, rejected or expired: no further reports are expected.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self.status,
+            OrderStatus::Filled
+                | OrderStatus::Canceled
+                | OrderStatus::Rejected
+                | OrderStatus::Expired
+                | OrderStatus::ExpiredInMatch
+        )
+    }
+}
+
+/// Something wrong with the stream of execution reports.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Inconsistency {
+    /// A report the order already had, which was dropped.
+    Duplicate {
+        symbol: String,
+        order_id: i64,
+        execution_id: i64,
+    },
+    /// A report older than one already applied to the order, which was
+    /// dropped.
+    OutOfOrder {
+        symbol: String,
+        order_id: i64,
+        execution_id: i64,
+        last_execution_id: i64,
+    },
+    /// `executedQty` differs from the previous one plus `lastQty`: trade
+    /// reports with IDs between `last_execution_id` and `execution_id` were
+    /// missed.
+    MissedFill {
+        symbol: String,
+        order_id: i64,
+        last_execution_id: i64,
+        execution_id: i64,
+        expected_executed_qty: Decimal,
+        executed_qty: Decimal,
+    },
+    /// A status change the order lifecycle does not allow, such as
+    /// `FILLED` to `NEW`, or one that does not match the execution type.
+    IllegalTransition {
+        symbol: String,
+        order_id: i64,
+        execution_id: i64,
+        from: Option<OrderStatus>,
+        to: OrderStatus,
+        execution_type: ExecutionType,
+    },
+}
+
+/// Orders of one account, as of the last execution report applied for each.
+#[derive(Clone, Debug, Default)]
+pub struct OrderTracker {
+    orders: BTreeMap<(String, i64), TrackedOrder>,
+    /// `(symbol, clientOrderId)` to `orderId`
+    client_order_ids: HashMap<(String, String), i64>,
+    inconsistencies: Vec<Inconsistency>,
+}
+
+impl OrderTracker {
+    /// Applies the message at the start of `data` with [`apply`](Self::apply).
+    pub fn apply_bytes(&mut self, data: &[u8]) -> SbeResult<bool> {
+        self.apply(decode_any(data)?)
+    }
+
+    /// Applies an `executionReport`. Returns `false` for any other message,
+    /// and for reports that were dropped as duplicate or out of order.
+    pub fn apply(&mut self, message: AnyMessage<'_>) -> SbeResult<bool> {
+        match message {
+            AnyMessage::ExecutionReportEvent(decoder) => self.apply_report(decoder),
+            _ => Ok(false),
+        }
+    }
+
+    pub fn apply_report(
+        &mut self,
+        mut decoder: ExecutionReportEventDecoder<'_>,
+    ) -> SbeResult<bool> {
+        let order_id = decoder.order_id();
+        let execution_id = decoder.execution_id();
+        let execution_type = decoder.execution_type();
+        let status = decoder.order_status();
+        let coordinates = decoder.symbol_decoder()?;
+        let symbol = utf8(decoder.symbol_slice(coordinates)?)?;
+        let coordinates = decoder.client_order_id_decoder()?;
+        let client_order_id = utf8(decoder.client_order_id_slice(coordinates)?)?;
+        let coordinates = decoder.orig_client_order_id_decoder()?;
+        let orig_client_order_id = utf8(decoder.orig_client_order_id_slice(coordinates)?)?;
+        let coordinates = decoder.commission_asset_decoder()?;
+        let commission_asset = utf8(decoder.commission_asset_slice(coordinates)?)?;
+        let coordinates = decoder.reject_reason_decoder()?;
+        let reject_reason = utf8(decoder.reject_reason_slice(coordinates)?)?;
+
+        let key = (symbol.clone(), order_id);
+        if let Some(order) = self.orders.get(&key) {
+            let last_execution_id = order.last_execution_id;
+            if execution_id <= last_execution_id {
+                self.inconsistencies
+                    .push(if order.execution_ids.contains(&execution_id) {
+                        Inconsistency::Duplicate {
+                            symbol,
+                            order_id,
+                            execution_id,
+                        }
+                    } else {
+                        Inconsistency::OutOfOrder {
+                            symbol,
+                            order_id,
+                            execution_id,
+                            last_execution_id,
+                        }
+                    });
+                return Ok(false);
+            }
+        }
+
+        // Cancellations carry the cancel request's ID in `clientOrderId` and
+        // the order's own in `origClientOrderId`.
+        let placed_with = if orig_client_order_id.is_empty() {
+            client_order_id
+        } else {
+            orig_client_order_id
+        };
+        let order = self.orders.entry(key).or_insert_with(|| TrackedOrder {
+            symbol: symbol.clone(),
+            order_id,
+            client_order_id: placed_with.clone(),
+            order_list_id: None,
+            side: OrderSide::NullVal,
+            order_type: OrderType::NullVal,
+            time_in_force: TimeInForce::NullVal,
+            price: Decimal::ZERO,
+            orig_qty: Decimal::ZERO,
+            status: OrderStatus::NullVal,
+            executed_qty: Decimal::ZERO,
+            cummulative_quote_qty: Decimal::ZERO,
+            prevented_qty: None,
+            fills: Vec::new(),
+            commissions: BTreeMap::new(),
+            reject_reason: String::new(),
+            transact_time: 0,
+            last_execution_id: execution_id,
+            execution_ids: BTreeSet::new(),
+        });
+        self.client_order_ids
+            .entry((symbol.clone(), placed_with))
+            .or_insert(order_id);
+
+        let from = (!order.execution_ids.is_empty()).then_some(order.status);
+        if !is_legal(from, status, execution_type) {
+            self.inconsistencies.push(Inconsistency::IllegalTransition {
+                symbol: symbol.clone(),
+                order_id,
+                execution_id,
+                from,
+                to: status,
+                execution_type,
+            });
+        }
+
+        let executed_qty = decoder.executed_qty_decimal();
+        if execution_type == ExecutionType::Trade {
+            let last_qty = decoder.last_qty_decimal();
+            let expected = order.executed_qty.checked_add(last_qty);
+            // an order first seen now has no earlier quantity to add up to
+            if from.is_some() && expected != Some(executed_qty) {
+                self.inconsistencies.push(Inconsistency::MissedFill {
+                    symbol,
+                    order_id,
+                    last_execution_id: order.last_execution_id,
+                    execution_id,
+                    expected_executed_qty: expected.unwrap_or(order.executed_qty),
+                    executed_qty,
+                });
+            }
+            let commission = decoder.commission_decimal();
+            let total = order
+                .commissions
+                .entry(commission_asset.clone())
+                .or_insert(Decimal::ZERO);
+            *total = total.checked_add(commission).unwrap_or(*total);
+            order.fills.push(Fill {
+                execution_id,
+                trade_id: decoder.trade_id(),
+                transact_time: decoder.transact_time(),
+                price: decoder.last_price_decimal(),
+                qty: last_qty,
+                quote_qty: decoder.quote_qty_decimal(),
+                commission,
+                commission_asset,
+                is_maker: decoder.is_maker() == BoolEnum::True,
+            });
+        }
+
+        order.order_list_id = decoder.order_list_id();
+        order.side = decoder.side();
+        order.order_type = decoder.order_type();
+        order.time_in_force = decoder.time_in_force();
+        order.price = decoder.price_decimal();
+        order.orig_qty = decoder.orig_qty_decimal();
+        order.status = status;
+        order.executed_qty = executed_qty;
+        order.cummulative_quote_qty = decoder.cummulative_quote_qty_decimal();
+        order.prevented_qty = decoder.prevented_qty_decimal();
+        order.transact_time = decoder.transact_time();
+        if !reject_reason.is_empty() {
+            order.reject_reason = reject_reason;
+        }
+        order.last_execution_id = execution_id;
+        order.execution_ids.insert(execution_id);
+        Ok(true)
+    }
+
+    #[inline]
+    pub fn order(&self, symbol: &str, order_id: i64) -> Option<&TrackedOrder> {
+        self.orders.get(&(symbol.to_owned(), order_id))
+    }
+
+    pub fn order_by_client_id(&self, symbol: &str, client_order_id: &str) -> Option<&TrackedOrder> {
+        let key = (symbol.to_owned(), client_order_id.to_owned());
+        let order_id = *self.client_order_ids.get(&key)?;
+        self.order(symbol, order_id)
+    }
+
+    /// Every order, by symbol then `orderId`.
+    pub fn orders(&self) -> impl Iterator<Item = &TrackedOrder> + '_ {
+        self.orders.values()
+    }
+
+    /// Orders that may still receive reports.
+    pub fn open_orders(&self) -> impl Iterator<Item = &TrackedOrder> + '_ {
+        self.orders().filter(|order| !order.is_final())
+    }
+
+    /// Inconsistencies found since they were last taken.
+    #[inline]
+    pub fn inconsistencies(&self) -> &[Inconsistency] {
+        &self.inconsistencies
+    }
+
+    pub fn take_inconsistencies(&mut self) -> Vec<Inconsistency> {
+        core::mem::take(&mut self.inconsistencies)
+    }
+}
+
+/// Whether a report with `execution_type` may move an order from `from`,
+/// or `None` for its first report, to `to`.
+fn is_legal(from: Option<OrderStatus>, to: OrderStatus, execution_type: ExecutionType) -> bool {
+    use ExecutionType as E;
+    use OrderStatus as S;
+    let matches_type = match execution_type {
+        E::New => matches!(to, S::New | S::PendingNew),
+        E::Trade => matches!(to, S::PartiallyFilled | S::Filled),
+        E::Canceled => to == S::Canceled,
+        E::Replaced => matches!(to, S::New | S::PartiallyFilled),
+        E::Rejected => to == S::Rejected,
+        E::Expired => matches!(to, S::Expired | S::ExpiredInMatch),
+        E::TradePrevention => matches!(to, S::New | S::PartiallyFilled | S::ExpiredInMatch),
+        E::Unknown | E::NullVal => false,
+    };
+    if !matches_type {
+        return false;
+    }
+    let Some(from) = from else {
+        // The first report an order gets is NEW, or REJECTED if it never
+        // got onto the book. Orders placed before tracking started are
+        // first seen in any status.
+        return true;
+    };
+    match (from, to) {
+        (S::PendingNew, S::New | S::Canceled | S::Expired | S::Rejected) => true,
+        (S::New | S::PartiallyFilled, S::PartiallyFilled | S::Filled) => true,
+        // An amended order, or a match prevented without expiring the order
+        (S::New, S::New) => matches!(execution_type, E::Replaced | E::TradePrevention),
+        (
+            S::New | S::PartiallyFilled,
+            S::PendingCancel | S::Canceled | S::Expired | S::ExpiredInMatch,
+        ) => true,
+        (S::PendingCancel, S::Canceled) => true,
+        _ => false,
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::execution_report_event_codec::owned::ExecutionReportEvent;
+
+    fn report(
+        order_id: i64,
+        execution_id: i64,
+        execution_type: ExecutionType,
+        order_status: OrderStatus,
+        last_qty: &str,
+        executed_qty: &str,
+    ) -> Vec<u8> {
+        ExecutionReportEvent {
+            order_id,
+            execution_id,
+            execution_type,
+            order_status,
+            side: OrderSide::Buy,
+            orig_qty: "1".parse().unwrap(),
+            price: "100".parse().unwrap(),
+            last_qty: last_qty.parse().unwrap(),
+            last_price: "100".parse().unwrap(),
+            executed_qty: executed_qty.parse().unwrap(),
+            commission: "0.001".parse().unwrap(),
+            commission_asset: Some("BNB".into()),
+            symbol: "BTCUSDT".into(),
+            client_order_id: format!("client-{order_id}"),
+            ..Default::default()
+        }
+        .encode()
+        .unwrap()
+    }
+
+    fn new(order_id: i64, execution_id: i64) -> Vec<u8> {
+        report(
+            order_id,
+            execution_id,
+            ExecutionType::New,
+            OrderStatus::New,
+            "0",
+            "0",
+        )
+    }
+
+    fn trade(order_id: i64, execution_id: i64, last_qty: &str, executed_qty: &str) -> Vec<u8> {
+        let status = if executed_qty == "1" {
+            OrderStatus::Filled
+        } else {
+            OrderStatus::PartiallyFilled
+        };
+        report(
+            order_id,
+            execution_id,
+            ExecutionType::Trade,
+            status,
+            last_qty,
+            executed_qty,
+        )
+    }
+
+    #[test]
+    fn follows_an_order_to_filled() {
+        let mut tracker = OrderTracker::default();
+        for frame in [
+            new(1, 1),
+            trade(1, 5, "0.4", "0.4"),
+            trade(1, 9, "0.6", "1"),
+        ] {
+            assert_eq!(tracker.apply_bytes(&frame), Ok(true));
+        }
+        assert_eq!(tracker.inconsistencies(), []);
+        let order = tracker.order_by_client_id("BTCUSDT", "client-1").unwrap();
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert_eq!(order.last_execution_id, 9);
+        assert_eq!(order.fills.len(), 2);
+        assert_eq!(order.commissions["BNB"], "0.002".parse().unwrap());
+        assert!(order.is_final());
+        assert_eq!(tracker.open_orders().count(), 0);
+    }
+
+    #[test]
+    fn drops_repeated_and_reordered_reports_per_order() {
+        let mut tracker = OrderTracker::default();
+        tracker.apply_bytes(&new(1, 1)).unwrap();
+        tracker.apply_bytes(&trade(1, 5, "0.4", "0.4")).unwrap();
+        assert_eq!(tracker.apply_bytes(&trade(1, 5, "0.4", "0.4")), Ok(false));
+        assert_eq!(tracker.apply_bytes(&trade(1, 3, "0.1", "0.1")), Ok(false));
+        // IDs are per symbol, so another order's may be lower
+        assert_eq!(tracker.apply_bytes(&new(2, 2)), Ok(true));
+        let order_id = 1;
+        let symbol = String::from("BTCUSDT");
+        assert_eq!(
+            tracker.take_inconsistencies(),
+            [
+                Inconsistency::Duplicate {
+                    symbol: symbol.clone(),
+                    order_id,
+                    execution_id: 5
+                },
+                Inconsistency::OutOfOrder {
+                    symbol,
+                    order_id,
+                    execution_id: 3,
+                    last_execution_id: 5
+                },
+            ]
+        );
+        assert_eq!(
+            tracker.order("BTCUSDT", 1).unwrap().executed_qty,
+            "0.4".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_missed_fills_between_execution_ids() {
+        let mut tracker = OrderTracker::default();
+        tracker.apply_bytes(&new(1, 1)).unwrap();
+        tracker.apply_bytes(&trade(1, 7, "0.3", "0.8")).unwrap();
+        assert_eq!(
+            tracker.take_inconsistencies(),
+            [Inconsistency::MissedFill {
+                symbol: "BTCUSDT".into(),
+                order_id: 1,
+                last_execution_id: 1,
+                execution_id: 7,
+                expected_executed_qty: "0.3".parse().unwrap(),
+                executed_qty: "0.8".parse().unwrap(),
+            }]
+        );
+    }
+
+    #[test]
+    fn seeds_an_order_from_its_first_report() {
+        let mut tracker = OrderTracker::default();
+        tracker.apply_bytes(&trade(1, 7, "0.3", "0.8")).unwrap();
+        tracker.apply_bytes(&trade(1, 8, "0.2", "1")).unwrap();
+        assert_eq!(tracker.inconsistencies(), []);
+        let order = tracker.order("BTCUSDT", 1).unwrap();
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert_eq!(order.fills.len(), 2);
+    }
+
+    #[test]
+    fn reports_illegal_transitions() {
+        let mut tracker = OrderTracker::default();
+        tracker.apply_bytes(&trade(1, 7, "1", "1")).unwrap();
+        assert_eq!(tracker.apply_bytes(&new(1, 8)), Ok(true));
+        assert_eq!(
+            tracker.take_inconsistencies(),
+            [Inconsistency::IllegalTransition {
+                symbol: "BTCUSDT".into(),
+                order_id: 1,
+                execution_id: 8,
+                from: Some(OrderStatus::Filled),
+                to: OrderStatus::New,
+                execution_type: ExecutionType::New,
+            }]
+        );
+        assert_eq!(
+            tracker.order("BTCUSDT", 1).unwrap().status,
+            OrderStatus::New
+        );
+    }
+}
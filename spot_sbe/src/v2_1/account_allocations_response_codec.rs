@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::AccountAllocationsResponseDecoder;
 pub use encoder::AccountAllocationsResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::AccountAllocationsResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 0;
 pub const SBE_TEMPLATE_ID: u16 = 404;
@@ -295,6 +297,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'price' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'qty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -309,6 +325,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'qty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'quoteQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -323,6 +353,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'quoteQty' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn quote_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.quote_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'commission'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -337,6 +381,27 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'commission' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn commission_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.commission(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset + 2;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            if exponent == i8::MIN {
+                return Err(SbeErr::DecimalNotRepresentable { exponent });
+            }
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.commission(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'time'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -434,6 +499,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> AccountAllocationsResponseDecoder<'a> {
@@ -443,7 +513,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -451,7 +522,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -459,13 +530,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -474,69 +551,96 @@ pub mod decoder {
 
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn allocations_decoder(self) -> AllocationsDecoder<Self> {
+        pub fn allocations_decoder(self) -> SbeResult<AllocationsDecoder<'a, Self>> {
             AllocationsDecoder::default().wrap(self)
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct AllocationsDecoder<P> {
+    pub struct AllocationsDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for AllocationsDecoder<P>
+    impl<'a, P> Reader<'a> for AllocationsDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for AllocationsDecoder<P>
+    impl<'a, P> Decoder<'a> for AllocationsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> AllocationsDecoder<P>
+    impl<'a, P> AllocationsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 87;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 3) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='allocations', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=87, offset=0, componentTokenCount=87, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -550,14 +654,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(87))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// primitive field - 'REQUIRED'
@@ -640,18 +745,36 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 44)
         }
 
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> Decimal {
+            Decimal::new(self.price(), self.price_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 52)
         }
 
+        /// 'qty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn qty_decimal(&self) -> Decimal {
+            Decimal::new(self.qty(), self.qty_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn quote_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 60)
         }
 
+        /// 'quoteQty' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn quote_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.quote_qty(), self.price_exponent())
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn commission(&self) -> Option<i64> {
@@ -663,6 +786,14 @@ pub mod decoder {
             }
         }
 
+        /// 'commission' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn commission_decimal(&self) -> Option<Decimal> {
+            self.commission()
+                .zip(self.commission_exponent())
+                .map(|(mantissa, exponent)| Decimal::new(mantissa, exponent))
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn time(&self) -> i64 {
@@ -689,56 +820,276 @@ pub mod decoder {
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn commission_asset_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn commission_asset_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn commission_asset_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn commission_asset_slice(
+            &'a self,
+            coordinates: (usize, usize),
+        ) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn source_symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn source_symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn source_symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn source_symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct AccountAllocationsResponse {
+        pub allocations: Vec<Allocations>,
+    }
+
+    impl AccountAllocationsResponse {
+        pub fn from_decoder(
+            decoder: &mut AccountAllocationsResponseDecoder<'_>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                allocations: {
+                    let mut allocations_decoder = core::mem::take(decoder).allocations_decoder()?;
+                    let mut entries = Vec::new();
+                    while allocations_decoder.advance()?.is_some() {
+                        entries.push(Allocations::from_decoder(&mut allocations_decoder)?);
+                    }
+                    *decoder = allocations_decoder.parent()?;
+                    entries
+                },
+            })
+        }
+
+        pub fn to_encoder(
+            &self,
+            encoder: &mut AccountAllocationsResponseEncoder<'_>,
+        ) -> SbeResult<()> {
+            let count = checked_length::<u32>(self.allocations.len())?;
+            let mut allocations_encoder =
+                core::mem::take(encoder).allocations_encoder(count, AllocationsEncoder::default());
+            for entry in &self.allocations {
+                allocations_encoder.advance()?;
+                entry.to_encoder(&mut allocations_encoder)?;
+            }
+            *encoder = allocations_encoder.parent()?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            6 + self
+                .allocations
+                .iter()
+                .map(Allocations::encoded_length)
+                .sum::<usize>()
+        }
+    }
+
+    impl OwnedMessage for AccountAllocationsResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = AccountAllocationsResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = AccountAllocationsResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Allocations {
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub commission_exponent: Option<i8>,
+        pub allocation_id: i64,
+        pub allocation_type: AllocationType,
+        pub order_id: i64,
+        pub order_list_id: Option<i64>,
+        pub source_trade_id: Option<i64>,
+        pub source_allocation_id: Option<i64>,
+        pub price: Decimal,
+        pub qty: Decimal,
+        pub quote_qty: Decimal,
+        pub commission: Option<Decimal>,
+        pub time: i64,
+        pub is_buyer: BoolEnum,
+        pub is_maker: BoolEnum,
+        pub is_allocator: BoolEnum,
+        pub symbol: String,
+        pub commission_asset: Option<String>,
+        pub source_symbol: Option<String>,
+    }
+
+    impl Allocations {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut AllocationsDecoder<'a, AccountAllocationsResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                commission_exponent: decoder.commission_exponent(),
+                allocation_id: decoder.allocation_id(),
+                allocation_type: decoder.allocation_type(),
+                order_id: decoder.order_id(),
+                order_list_id: decoder.order_list_id(),
+                source_trade_id: decoder.source_trade_id(),
+                source_allocation_id: decoder.source_allocation_id(),
+                price: decoder.price_decimal(),
+                qty: decoder.qty_decimal(),
+                quote_qty: decoder.quote_qty_decimal(),
+                commission: decoder.commission_decimal(),
+                time: decoder.time(),
+                is_buyer: decoder.is_buyer(),
+                is_maker: decoder.is_maker(),
+                is_allocator: decoder.is_allocator(),
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                commission_asset: {
+                    let coordinates = decoder.commission_asset_decoder()?;
+                    let slice = decoder.commission_asset_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(
+                            core::str::from_utf8(slice)
+                                .map_err(|_| SbeErr::InvalidUtf8)?
+                                .to_owned(),
+                        )
+                    }
+                },
+                source_symbol: {
+                    let coordinates = decoder.source_symbol_decoder()?;
+                    let slice = decoder.source_symbol_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(
+                            core::str::from_utf8(slice)
+                                .map_err(|_| SbeErr::InvalidUtf8)?
+                                .to_owned(),
+                        )
+                    }
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut AllocationsEncoder<AccountAllocationsResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                [self.price, self.quote_qty],
+            ));
+            encoder.qty_exponent(fit_exponent(self.qty_exponent, [self.qty]));
+            encoder.commission_exponent(
+                fit_optional_exponent(self.commission_exponent, self.commission).unwrap_or(i8::MIN),
+            );
+            encoder.allocation_id(self.allocation_id);
+            encoder.allocation_type(self.allocation_type);
+            encoder.order_id(self.order_id);
+            encoder.order_list_id(self.order_list_id.unwrap_or(i64::MIN));
+            encoder.source_trade_id(self.source_trade_id.unwrap_or(i64::MIN));
+            encoder.source_allocation_id(self.source_allocation_id.unwrap_or(i64::MIN));
+            encoder.price_decimal(self.price)?;
+            encoder.qty_decimal(self.qty)?;
+            encoder.quote_qty_decimal(self.quote_qty)?;
+            encoder.commission_decimal(self.commission)?;
+            encoder.time(self.time);
+            encoder.is_buyer(self.is_buyer);
+            encoder.is_maker(self.is_maker);
+            encoder.is_allocator(self.is_allocator);
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            let value = self.commission_asset.as_deref().unwrap_or_default();
+            checked_length::<u8>(value.len())?;
+            encoder.commission_asset(value);
+            let value = self.source_symbol.as_deref().unwrap_or_default();
+            checked_length::<u8>(value.len())?;
+            encoder.source_symbol(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            90 + self.symbol.len()
+                + self
+                    .commission_asset
+                    .as_ref()
+                    .map_or(0, |value| value.len())
+                + self.source_symbol.as_ref().map_or(0, |value| value.len())
+        }
+    }
+} // end owned
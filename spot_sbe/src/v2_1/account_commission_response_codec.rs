@@ -0,0 +1,765 @@
+use super::*;
+
+pub use decoder::AccountCommissionResponseDecoder;
+pub use encoder::AccountCommissionResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::AccountCommissionResponse;
+
+pub const SBE_BLOCK_LENGTH: u16 = 76;
+pub const SBE_TEMPLATE_ID: u16 = 405;
+pub const SBE_SCHEMA_ID: u16 = 2;
+pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SEMANTIC_VERSION: &str = "5.2";
+
+pub mod encoder {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct AccountCommissionResponseEncoder<'a> {
+        buf: WriteBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+    }
+
+    impl<'a> Writer<'a> for AccountCommissionResponseEncoder<'a> {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            &mut self.buf
+        }
+    }
+
+    impl<'a> Encoder<'a> for AccountCommissionResponseEncoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+    }
+
+    impl<'a> AccountCommissionResponseEncoder<'a> {
+        pub fn wrap(mut self, buf: WriteBuf<'a>, offset: usize) -> Self {
+            let limit = offset + SBE_BLOCK_LENGTH as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self
+        }
+
+        #[inline]
+        pub fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        pub fn header(self, offset: usize) -> MessageHeaderEncoder<Self> {
+            let mut header = MessageHeaderEncoder::default().wrap(self, offset);
+            header.block_length(SBE_BLOCK_LENGTH);
+            header.template_id(SBE_TEMPLATE_ID);
+            header.schema_id(SBE_SCHEMA_ID);
+            header.version(SBE_SCHEMA_VERSION);
+            header
+        }
+
+        /// primitive field 'commissionExponent'
+        /// - min value: -127
+        /// - max value: 127
+        /// - null value: -128
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 0
+        /// - encodedLength: 1
+        #[inline]
+        pub fn commission_exponent(&mut self, value: i8) {
+            let offset = self.offset;
+            self.get_buf_mut().put_i8_at(offset, value);
+        }
+
+        /// primitive field 'discountExponent'
+        /// - min value: -127
+        /// - max value: 127
+        /// - null value: -128
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 1
+        /// - encodedLength: 1
+        #[inline]
+        pub fn discount_exponent(&mut self, value: i8) {
+            let offset = self.offset + 1;
+            self.get_buf_mut().put_i8_at(offset, value);
+        }
+
+        /// primitive field 'standardCommissionMaker'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 2
+        /// - encodedLength: 8
+        #[inline]
+        pub fn standard_commission_maker(&mut self, value: i64) {
+            let offset = self.offset + 2;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'standardCommissionMaker' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn standard_commission_maker_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.standard_commission_maker(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'standardCommissionTaker'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 10
+        /// - encodedLength: 8
+        #[inline]
+        pub fn standard_commission_taker(&mut self, value: i64) {
+            let offset = self.offset + 10;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'standardCommissionTaker' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn standard_commission_taker_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.standard_commission_taker(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'standardCommissionBuyer'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 18
+        /// - encodedLength: 8
+        #[inline]
+        pub fn standard_commission_buyer(&mut self, value: i64) {
+            let offset = self.offset + 18;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'standardCommissionBuyer' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn standard_commission_buyer_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.standard_commission_buyer(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'standardCommissionSeller'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 26
+        /// - encodedLength: 8
+        #[inline]
+        pub fn standard_commission_seller(&mut self, value: i64) {
+            let offset = self.offset + 26;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'standardCommissionSeller' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn standard_commission_seller_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.standard_commission_seller(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'taxCommissionMaker'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 34
+        /// - encodedLength: 8
+        #[inline]
+        pub fn tax_commission_maker(&mut self, value: i64) {
+            let offset = self.offset + 34;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'taxCommissionMaker' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn tax_commission_maker_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.tax_commission_maker(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'taxCommissionTaker'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 42
+        /// - encodedLength: 8
+        #[inline]
+        pub fn tax_commission_taker(&mut self, value: i64) {
+            let offset = self.offset + 42;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'taxCommissionTaker' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn tax_commission_taker_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.tax_commission_taker(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'taxCommissionBuyer'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 50
+        /// - encodedLength: 8
+        #[inline]
+        pub fn tax_commission_buyer(&mut self, value: i64) {
+            let offset = self.offset + 50;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'taxCommissionBuyer' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn tax_commission_buyer_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.tax_commission_buyer(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'taxCommissionSeller'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 58
+        /// - encodedLength: 8
+        #[inline]
+        pub fn tax_commission_seller(&mut self, value: i64) {
+            let offset = self.offset + 58;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'taxCommissionSeller' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn tax_commission_seller_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.tax_commission_seller(mantissa);
+            Ok(())
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn discount_enabled_for_account(&mut self, value: BoolEnum) {
+            let offset = self.offset + 66;
+            self.get_buf_mut().put_u8_at(offset, value as u8)
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn discount_enabled_for_symbol(&mut self, value: BoolEnum) {
+            let offset = self.offset + 67;
+            self.get_buf_mut().put_u8_at(offset, value as u8)
+        }
+
+        /// primitive field 'discount'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 68
+        /// - encodedLength: 8
+        #[inline]
+        pub fn discount(&mut self, value: i64) {
+            let offset = self.offset + 68;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'discount' rescaled to the 'discountExponent' already written to this block
+        #[inline]
+        pub fn discount_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.discount(mantissa);
+            Ok(())
+        }
+
+        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn symbol(&mut self, value: &str) {
+            let limit = self.get_limit();
+            let data_length = value.len();
+            self.set_limit(limit + 1 + data_length);
+            self.get_buf_mut().put_u8_at(limit, data_length as u8);
+            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
+        }
+
+        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn discount_asset(&mut self, value: &str) {
+            let limit = self.get_limit();
+            let data_length = value.len();
+            self.set_limit(limit + 1 + data_length);
+            self.get_buf_mut().put_u8_at(limit, data_length as u8);
+            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
+        }
+    }
+} // end encoder
+
+pub mod decoder {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct AccountCommissionResponseDecoder<'a> {
+        buf: ReadBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+        pub acting_block_length: u16,
+        pub acting_version: u16,
+    }
+
+    impl<'a> Reader<'a> for AccountCommissionResponseDecoder<'a> {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            &self.buf
+        }
+    }
+
+    impl<'a> Decoder<'a> for AccountCommissionResponseDecoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
+    }
+
+    impl<'a> AccountCommissionResponseDecoder<'a> {
+        pub fn wrap(
+            mut self,
+            buf: ReadBuf<'a>,
+            offset: usize,
+            acting_block_length: u16,
+            acting_version: u16,
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
+            let limit = offset + acting_block_length as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self.acting_block_length = acting_block_length;
+            self.acting_version = acting_version;
+            Ok(self)
+        }
+
+        #[inline]
+        pub fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
+            let acting_block_length = header.block_length();
+            let acting_version = header.version();
+
+            self.wrap(
+                header.parent()?,
+                message_header_codec::ENCODED_LENGTH,
+                acting_block_length,
+                acting_version,
+            )
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn commission_exponent(&self) -> i8 {
+            self.get_buf().get_i8_at(self.offset)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn discount_exponent(&self) -> i8 {
+            self.get_buf().get_i8_at(self.offset + 1)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn standard_commission_maker(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 2)
+        }
+
+        /// 'standardCommissionMaker' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn standard_commission_maker_decimal(&self) -> Decimal {
+            Decimal::new(self.standard_commission_maker(), self.commission_exponent())
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn standard_commission_taker(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 10)
+        }
+
+        /// 'standardCommissionTaker' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn standard_commission_taker_decimal(&self) -> Decimal {
+            Decimal::new(self.standard_commission_taker(), self.commission_exponent())
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn standard_commission_buyer(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 18)
+        }
+
+        /// 'standardCommissionBuyer' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn standard_commission_buyer_decimal(&self) -> Decimal {
+            Decimal::new(self.standard_commission_buyer(), self.commission_exponent())
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn standard_commission_seller(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 26)
+        }
+
+        /// 'standardCommissionSeller' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn standard_commission_seller_decimal(&self) -> Decimal {
+            Decimal::new(
+                self.standard_commission_seller(),
+                self.commission_exponent(),
+            )
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn tax_commission_maker(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 34)
+        }
+
+        /// 'taxCommissionMaker' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn tax_commission_maker_decimal(&self) -> Decimal {
+            Decimal::new(self.tax_commission_maker(), self.commission_exponent())
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn tax_commission_taker(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 42)
+        }
+
+        /// 'taxCommissionTaker' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn tax_commission_taker_decimal(&self) -> Decimal {
+            Decimal::new(self.tax_commission_taker(), self.commission_exponent())
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn tax_commission_buyer(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 50)
+        }
+
+        /// 'taxCommissionBuyer' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn tax_commission_buyer_decimal(&self) -> Decimal {
+            Decimal::new(self.tax_commission_buyer(), self.commission_exponent())
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn tax_commission_seller(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 58)
+        }
+
+        /// 'taxCommissionSeller' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn tax_commission_seller_decimal(&self) -> Decimal {
+            Decimal::new(self.tax_commission_seller(), self.commission_exponent())
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn discount_enabled_for_account(&self) -> BoolEnum {
+            self.get_buf().get_u8_at(self.offset + 66).into()
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn discount_enabled_for_symbol(&self) -> BoolEnum {
+            self.get_buf().get_u8_at(self.offset + 67).into()
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn discount(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 68)
+        }
+
+        /// 'discount' combined with its 'discountExponent' exponent field
+        #[inline]
+        pub fn discount_decimal(&self) -> Decimal {
+            Decimal::new(self.discount(), self.discount_exponent())
+        }
+
+        /// VAR_DATA DECODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
+            let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
+        }
+
+        #[inline]
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
+        }
+
+        /// VAR_DATA DECODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn discount_asset_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
+            let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
+        }
+
+        #[inline]
+        pub fn discount_asset_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
+        }
+    }
+} // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct AccountCommissionResponse {
+        pub commission_exponent: i8,
+        pub discount_exponent: i8,
+        pub standard_commission_maker: Decimal,
+        pub standard_commission_taker: Decimal,
+        pub standard_commission_buyer: Decimal,
+        pub standard_commission_seller: Decimal,
+        pub tax_commission_maker: Decimal,
+        pub tax_commission_taker: Decimal,
+        pub tax_commission_buyer: Decimal,
+        pub tax_commission_seller: Decimal,
+        pub discount_enabled_for_account: BoolEnum,
+        pub discount_enabled_for_symbol: BoolEnum,
+        pub discount: Decimal,
+        pub symbol: String,
+        pub discount_asset: Option<String>,
+    }
+
+    impl AccountCommissionResponse {
+        pub fn from_decoder(decoder: &mut AccountCommissionResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                commission_exponent: decoder.commission_exponent(),
+                discount_exponent: decoder.discount_exponent(),
+                standard_commission_maker: decoder.standard_commission_maker_decimal(),
+                standard_commission_taker: decoder.standard_commission_taker_decimal(),
+                standard_commission_buyer: decoder.standard_commission_buyer_decimal(),
+                standard_commission_seller: decoder.standard_commission_seller_decimal(),
+                tax_commission_maker: decoder.tax_commission_maker_decimal(),
+                tax_commission_taker: decoder.tax_commission_taker_decimal(),
+                tax_commission_buyer: decoder.tax_commission_buyer_decimal(),
+                tax_commission_seller: decoder.tax_commission_seller_decimal(),
+                discount_enabled_for_account: decoder.discount_enabled_for_account(),
+                discount_enabled_for_symbol: decoder.discount_enabled_for_symbol(),
+                discount: decoder.discount_decimal(),
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                discount_asset: {
+                    let coordinates = decoder.discount_asset_decoder()?;
+                    let slice = decoder.discount_asset_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(
+                            core::str::from_utf8(slice)
+                                .map_err(|_| SbeErr::InvalidUtf8)?
+                                .to_owned(),
+                        )
+                    }
+                },
+            })
+        }
+
+        pub fn to_encoder(
+            &self,
+            encoder: &mut AccountCommissionResponseEncoder<'_>,
+        ) -> SbeResult<()> {
+            encoder.commission_exponent(fit_exponent(
+                self.commission_exponent,
+                [
+                    self.standard_commission_maker,
+                    self.standard_commission_taker,
+                    self.standard_commission_buyer,
+                    self.standard_commission_seller,
+                    self.tax_commission_maker,
+                    self.tax_commission_taker,
+                    self.tax_commission_buyer,
+                    self.tax_commission_seller,
+                ],
+            ));
+            encoder.discount_exponent(fit_exponent(self.discount_exponent, [self.discount]));
+            encoder.standard_commission_maker_decimal(self.standard_commission_maker)?;
+            encoder.standard_commission_taker_decimal(self.standard_commission_taker)?;
+            encoder.standard_commission_buyer_decimal(self.standard_commission_buyer)?;
+            encoder.standard_commission_seller_decimal(self.standard_commission_seller)?;
+            encoder.tax_commission_maker_decimal(self.tax_commission_maker)?;
+            encoder.tax_commission_taker_decimal(self.tax_commission_taker)?;
+            encoder.tax_commission_buyer_decimal(self.tax_commission_buyer)?;
+            encoder.tax_commission_seller_decimal(self.tax_commission_seller)?;
+            encoder.discount_enabled_for_account(self.discount_enabled_for_account);
+            encoder.discount_enabled_for_symbol(self.discount_enabled_for_symbol);
+            encoder.discount_decimal(self.discount)?;
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            let value = self.discount_asset.as_deref().unwrap_or_default();
+            checked_length::<u8>(value.len())?;
+            encoder.discount_asset(value);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            78 + self.symbol.len() + self.discount_asset.as_ref().map_or(0, |value| value.len())
+        }
+    }
+
+    impl OwnedMessage for AccountCommissionResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = AccountCommissionResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = AccountCommissionResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
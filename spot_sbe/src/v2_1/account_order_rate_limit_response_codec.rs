@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::AccountOrderRateLimitResponseDecoder;
 pub use encoder::AccountOrderRateLimitResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::AccountOrderRateLimitResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 0;
 pub const SBE_TEMPLATE_ID: u16 = 402;
@@ -250,6 +252,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> AccountOrderRateLimitResponseDecoder<'a> {
@@ -259,7 +266,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -267,7 +275,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -275,13 +283,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -290,69 +304,96 @@ pub mod decoder {
 
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn rate_limits_decoder(self) -> RateLimitsDecoder<Self> {
+        pub fn rate_limits_decoder(self) -> SbeResult<RateLimitsDecoder<'a, Self>> {
             RateLimitsDecoder::default().wrap(self)
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct RateLimitsDecoder<P> {
+    pub struct RateLimitsDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for RateLimitsDecoder<P>
+    impl<'a, P> Reader<'a> for RateLimitsDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for RateLimitsDecoder<P>
+    impl<'a, P> Decoder<'a> for RateLimitsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> RateLimitsDecoder<P>
+    impl<'a, P> RateLimitsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 19;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='rateLimits', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=19, offset=0, componentTokenCount=31, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -366,14 +407,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(19))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// REQUIRED enum
@@ -407,3 +449,123 @@ pub mod decoder {
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct AccountOrderRateLimitResponse {
+        pub rate_limits: Vec<RateLimits>,
+    }
+
+    impl AccountOrderRateLimitResponse {
+        pub fn from_decoder(
+            decoder: &mut AccountOrderRateLimitResponseDecoder<'_>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                rate_limits: {
+                    let mut rate_limits_decoder = core::mem::take(decoder).rate_limits_decoder()?;
+                    let mut entries = Vec::new();
+                    while rate_limits_decoder.advance()?.is_some() {
+                        entries.push(RateLimits::from_decoder(&mut rate_limits_decoder)?);
+                    }
+                    *decoder = rate_limits_decoder.parent()?;
+                    entries
+                },
+            })
+        }
+
+        pub fn to_encoder(
+            &self,
+            encoder: &mut AccountOrderRateLimitResponseEncoder<'_>,
+        ) -> SbeResult<()> {
+            let count = checked_length::<u32>(self.rate_limits.len())?;
+            let mut rate_limits_encoder =
+                core::mem::take(encoder).rate_limits_encoder(count, RateLimitsEncoder::default());
+            for entry in &self.rate_limits {
+                rate_limits_encoder.advance()?;
+                entry.to_encoder(&mut rate_limits_encoder)?;
+            }
+            *encoder = rate_limits_encoder.parent()?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            6 + self
+                .rate_limits
+                .iter()
+                .map(RateLimits::encoded_length)
+                .sum::<usize>()
+        }
+    }
+
+    impl OwnedMessage for AccountOrderRateLimitResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = AccountOrderRateLimitResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = AccountOrderRateLimitResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct RateLimits {
+        pub rate_limit_type: RateLimitType,
+        pub interval: RateLimitInterval,
+        pub interval_num: u8,
+        pub rate_limit: i64,
+        pub num_orders: i64,
+    }
+
+    impl RateLimits {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut RateLimitsDecoder<'a, AccountOrderRateLimitResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                rate_limit_type: decoder.rate_limit_type(),
+                interval: decoder.interval(),
+                interval_num: decoder.interval_num(),
+                rate_limit: decoder.rate_limit(),
+                num_orders: decoder.num_orders(),
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut RateLimitsEncoder<AccountOrderRateLimitResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.rate_limit_type(self.rate_limit_type);
+            encoder.interval(self.interval);
+            encoder.interval_num(self.interval_num);
+            encoder.rate_limit(self.rate_limit);
+            encoder.num_orders(self.num_orders);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            19
+        }
+    }
+} // end owned
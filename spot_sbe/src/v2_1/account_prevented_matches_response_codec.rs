@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::AccountPreventedMatchesResponseDecoder;
 pub use encoder::AccountPreventedMatchesResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::AccountPreventedMatchesResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 0;
 pub const SBE_TEMPLATE_ID: u16 = 403;
@@ -267,6 +269,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'price' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'takerPreventedQuantity'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -281,6 +297,27 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'takerPreventedQuantity' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn taker_prevented_quantity_decimal(
+            &mut self,
+            value: Option<Decimal>,
+        ) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.taker_prevented_quantity(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.taker_prevented_quantity(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'makerPreventedQuantity'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -295,6 +332,27 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'makerPreventedQuantity' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn maker_prevented_quantity_decimal(
+            &mut self,
+            value: Option<Decimal>,
+        ) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.maker_prevented_quantity(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.maker_prevented_quantity(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'transactTime'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -361,6 +419,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> AccountPreventedMatchesResponseDecoder<'a> {
@@ -370,7 +433,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -378,7 +442,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -386,13 +450,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -401,69 +471,96 @@ pub mod decoder {
 
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn prevented_matches_decoder(self) -> PreventedMatchesDecoder<Self> {
+        pub fn prevented_matches_decoder(self) -> SbeResult<PreventedMatchesDecoder<'a, Self>> {
             PreventedMatchesDecoder::default().wrap(self)
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct PreventedMatchesDecoder<P> {
+    pub struct PreventedMatchesDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for PreventedMatchesDecoder<P>
+    impl<'a, P> Reader<'a> for PreventedMatchesDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for PreventedMatchesDecoder<P>
+    impl<'a, P> Decoder<'a> for PreventedMatchesDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> PreventedMatchesDecoder<P>
+    impl<'a, P> PreventedMatchesDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 67;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 2) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='preventedMatches', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=67, offset=0, componentTokenCount=56, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -477,14 +574,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(67))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// primitive field - 'REQUIRED'
@@ -535,6 +633,12 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 35)
         }
 
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> Decimal {
+            Decimal::new(self.price(), self.price_exponent())
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn taker_prevented_quantity(&self) -> Option<i64> {
@@ -546,6 +650,13 @@ pub mod decoder {
             }
         }
 
+        /// 'takerPreventedQuantity' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn taker_prevented_quantity_decimal(&self) -> Option<Decimal> {
+            self.taker_prevented_quantity()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn maker_prevented_quantity(&self) -> Option<i64> {
@@ -557,6 +668,13 @@ pub mod decoder {
             }
         }
 
+        /// 'makerPreventedQuantity' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn maker_prevented_quantity_decimal(&self) -> Option<Decimal> {
+            self.maker_prevented_quantity()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn transact_time(&self) -> i64 {
@@ -565,38 +683,210 @@ pub mod decoder {
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn maker_symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn maker_symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn maker_symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn maker_symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct AccountPreventedMatchesResponse {
+        pub prevented_matches: Vec<PreventedMatches>,
+    }
+
+    impl AccountPreventedMatchesResponse {
+        pub fn from_decoder(
+            decoder: &mut AccountPreventedMatchesResponseDecoder<'_>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                prevented_matches: {
+                    let mut prevented_matches_decoder =
+                        core::mem::take(decoder).prevented_matches_decoder()?;
+                    let mut entries = Vec::new();
+                    while prevented_matches_decoder.advance()?.is_some() {
+                        entries.push(PreventedMatches::from_decoder(
+                            &mut prevented_matches_decoder,
+                        )?);
+                    }
+                    *decoder = prevented_matches_decoder.parent()?;
+                    entries
+                },
+            })
+        }
+
+        pub fn to_encoder(
+            &self,
+            encoder: &mut AccountPreventedMatchesResponseEncoder<'_>,
+        ) -> SbeResult<()> {
+            let count = checked_length::<u32>(self.prevented_matches.len())?;
+            let mut prevented_matches_encoder = core::mem::take(encoder)
+                .prevented_matches_encoder(count, PreventedMatchesEncoder::default());
+            for entry in &self.prevented_matches {
+                prevented_matches_encoder.advance()?;
+                entry.to_encoder(&mut prevented_matches_encoder)?;
+            }
+            *encoder = prevented_matches_encoder.parent()?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            6 + self
+                .prevented_matches
+                .iter()
+                .map(PreventedMatches::encoded_length)
+                .sum::<usize>()
+        }
+    }
+
+    impl OwnedMessage for AccountPreventedMatchesResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = AccountPreventedMatchesResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = AccountPreventedMatchesResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PreventedMatches {
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub prevented_match_id: i64,
+        pub taker_order_id: i64,
+        pub maker_order_id: i64,
+        pub trade_group_id: i64,
+        pub self_trade_prevention_mode: SelfTradePreventionMode,
+        pub price: Decimal,
+        pub taker_prevented_quantity: Option<Decimal>,
+        pub maker_prevented_quantity: Option<Decimal>,
+        pub transact_time: i64,
+        pub symbol: String,
+        pub maker_symbol: String,
+    }
+
+    impl PreventedMatches {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut PreventedMatchesDecoder<'a, AccountPreventedMatchesResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                prevented_match_id: decoder.prevented_match_id(),
+                taker_order_id: decoder.taker_order_id(),
+                maker_order_id: decoder.maker_order_id(),
+                trade_group_id: decoder.trade_group_id(),
+                self_trade_prevention_mode: decoder.self_trade_prevention_mode(),
+                price: decoder.price_decimal(),
+                taker_prevented_quantity: decoder.taker_prevented_quantity_decimal(),
+                maker_prevented_quantity: decoder.maker_prevented_quantity_decimal(),
+                transact_time: decoder.transact_time(),
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                maker_symbol: {
+                    let coordinates = decoder.maker_symbol_decoder()?;
+                    let slice = decoder.maker_symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut PreventedMatchesEncoder<AccountPreventedMatchesResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(self.price_exponent, [self.price]));
+            encoder.qty_exponent(fit_exponent(
+                self.qty_exponent,
+                self.taker_prevented_quantity
+                    .into_iter()
+                    .chain(self.maker_prevented_quantity),
+            ));
+            encoder.prevented_match_id(self.prevented_match_id);
+            encoder.taker_order_id(self.taker_order_id);
+            encoder.maker_order_id(self.maker_order_id);
+            encoder.trade_group_id(self.trade_group_id);
+            encoder.self_trade_prevention_mode(self.self_trade_prevention_mode);
+            encoder.price_decimal(self.price)?;
+            encoder.taker_prevented_quantity_decimal(self.taker_prevented_quantity)?;
+            encoder.maker_prevented_quantity_decimal(self.maker_prevented_quantity)?;
+            encoder.transact_time(self.transact_time);
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            let value = &self.maker_symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.maker_symbol(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            69 + self.symbol.len() + self.maker_symbol.len()
+        }
+    }
+} // end owned
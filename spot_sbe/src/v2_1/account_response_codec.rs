@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::AccountResponseDecoder;
 pub use encoder::AccountResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::AccountResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 64;
 pub const SBE_TEMPLATE_ID: u16 = 400;
@@ -91,6 +93,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'commissionRateMaker' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn commission_rate_maker_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.commission_rate_maker(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'commissionRateTaker'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -105,6 +121,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'commissionRateTaker' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn commission_rate_taker_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.commission_rate_taker(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'commissionRateBuyer'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -119,6 +149,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'commissionRateBuyer' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn commission_rate_buyer_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.commission_rate_buyer(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'commissionRateSeller'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -133,6 +177,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'commissionRateSeller' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn commission_rate_seller_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.commission_rate_seller(mantissa);
+            Ok(())
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn can_trade(&mut self, value: BoolEnum) {
@@ -371,6 +429,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'free' rescaled to the 'exponent' already written to this block
+        #[inline]
+        pub fn free_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.free(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'locked'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -385,6 +457,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'locked' rescaled to the 'exponent' already written to this block
+        #[inline]
+        pub fn locked_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.locked(mantissa);
+            Ok(())
+        }
+
         /// VAR_DATA ENCODER - character encoding: 'UTF-8'
         #[inline]
         pub fn asset(&mut self, value: &str) {
@@ -625,6 +711,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> AccountResponseDecoder<'a> {
@@ -634,7 +725,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -642,7 +734,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -650,13 +742,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -675,24 +773,48 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 1)
         }
 
+        /// 'commissionRateMaker' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn commission_rate_maker_decimal(&self) -> Decimal {
+            Decimal::new(self.commission_rate_maker(), self.commission_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn commission_rate_taker(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 9)
         }
 
+        /// 'commissionRateTaker' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn commission_rate_taker_decimal(&self) -> Decimal {
+            Decimal::new(self.commission_rate_taker(), self.commission_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn commission_rate_buyer(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 17)
         }
 
+        /// 'commissionRateBuyer' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn commission_rate_buyer_decimal(&self) -> Decimal {
+            Decimal::new(self.commission_rate_buyer(), self.commission_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn commission_rate_seller(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 25)
         }
 
+        /// 'commissionRateSeller' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn commission_rate_seller_decimal(&self) -> Decimal {
+            Decimal::new(self.commission_rate_seller(), self.commission_exponent())
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn can_trade(&self) -> BoolEnum {
@@ -760,81 +882,108 @@ pub mod decoder {
 
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn balances_decoder(self) -> BalancesDecoder<Self> {
+        pub fn balances_decoder(self) -> SbeResult<BalancesDecoder<'a, Self>> {
             BalancesDecoder::default().wrap(self)
         }
 
         /// GROUP DECODER (id=101)
         #[inline]
-        pub fn permissions_decoder(self) -> PermissionsDecoder<Self> {
+        pub fn permissions_decoder(self) -> SbeResult<PermissionsDecoder<'a, Self>> {
             PermissionsDecoder::default().wrap(self)
         }
 
         /// GROUP DECODER (id=102)
         #[inline]
-        pub fn reduce_only_assets_decoder(self) -> ReduceOnlyAssetsDecoder<Self> {
+        pub fn reduce_only_assets_decoder(self) -> SbeResult<ReduceOnlyAssetsDecoder<'a, Self>> {
             ReduceOnlyAssetsDecoder::default().wrap(self)
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct BalancesDecoder<P> {
+    pub struct BalancesDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for BalancesDecoder<P>
+    impl<'a, P> Reader<'a> for BalancesDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for BalancesDecoder<P>
+    impl<'a, P> Decoder<'a> for BalancesDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> BalancesDecoder<P>
+    impl<'a, P> BalancesDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 17;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='balances', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=17, offset=64, componentTokenCount=21, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -848,14 +997,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(17))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// primitive field - 'REQUIRED'
@@ -870,89 +1020,123 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 1)
         }
 
+        /// 'free' combined with its 'exponent' exponent field
+        #[inline]
+        pub fn free_decimal(&self) -> Decimal {
+            Decimal::new(self.free(), self.exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn locked(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 9)
         }
 
+        /// 'locked' combined with its 'exponent' exponent field
+        #[inline]
+        pub fn locked_decimal(&self) -> Decimal {
+            Decimal::new(self.locked(), self.exponent())
+        }
+
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn asset_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn asset_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn asset_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn asset_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct PermissionsDecoder<P> {
+    pub struct PermissionsDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for PermissionsDecoder<P>
+    impl<'a, P> Reader<'a> for PermissionsDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for PermissionsDecoder<P>
+    impl<'a, P> Decoder<'a> for PermissionsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> PermissionsDecoder<P>
+    impl<'a, P> PermissionsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='permissions', referencedName='null', description='null', packageName='null', id=101, version=0, deprecated=0, encodedLength=0, offset=-1, componentTokenCount=12, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -966,93 +1150,116 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length)?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn permission_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn permission_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn permission_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn permission_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct ReduceOnlyAssetsDecoder<P> {
+    pub struct ReduceOnlyAssetsDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for ReduceOnlyAssetsDecoder<P>
+    impl<'a, P> Reader<'a> for ReduceOnlyAssetsDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for ReduceOnlyAssetsDecoder<P>
+    impl<'a, P> Decoder<'a> for ReduceOnlyAssetsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> ReduceOnlyAssetsDecoder<P>
+    impl<'a, P> ReduceOnlyAssetsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='reduceOnlyAssets', referencedName='null', description='null', packageName='null', id=102, version=0, deprecated=0, encodedLength=0, offset=-1, componentTokenCount=12, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -1066,32 +1273,335 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length)?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn asset_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn asset_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn asset_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn asset_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct AccountResponse {
+        pub commission_exponent: i8,
+        pub commission_rate_maker: Decimal,
+        pub commission_rate_taker: Decimal,
+        pub commission_rate_buyer: Decimal,
+        pub commission_rate_seller: Decimal,
+        pub can_trade: BoolEnum,
+        pub can_withdraw: BoolEnum,
+        pub can_deposit: BoolEnum,
+        pub brokered: BoolEnum,
+        pub require_self_trade_prevention: BoolEnum,
+        pub prevent_sor: BoolEnum,
+        pub update_time: i64,
+        pub account_type: AccountType,
+        pub trade_group_id: Option<i64>,
+        pub uid: i64,
+        pub balances: Vec<Balances>,
+        pub permissions: Vec<Permissions>,
+        pub reduce_only_assets: Vec<ReduceOnlyAssets>,
+    }
+
+    impl AccountResponse {
+        pub fn from_decoder(decoder: &mut AccountResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                commission_exponent: decoder.commission_exponent(),
+                commission_rate_maker: decoder.commission_rate_maker_decimal(),
+                commission_rate_taker: decoder.commission_rate_taker_decimal(),
+                commission_rate_buyer: decoder.commission_rate_buyer_decimal(),
+                commission_rate_seller: decoder.commission_rate_seller_decimal(),
+                can_trade: decoder.can_trade(),
+                can_withdraw: decoder.can_withdraw(),
+                can_deposit: decoder.can_deposit(),
+                brokered: decoder.brokered(),
+                require_self_trade_prevention: decoder.require_self_trade_prevention(),
+                prevent_sor: decoder.prevent_sor(),
+                update_time: decoder.update_time(),
+                account_type: decoder.account_type(),
+                trade_group_id: decoder.trade_group_id(),
+                uid: decoder.uid(),
+                balances: {
+                    let mut balances_decoder = core::mem::take(decoder).balances_decoder()?;
+                    let mut entries = Vec::new();
+                    while balances_decoder.advance()?.is_some() {
+                        entries.push(Balances::from_decoder(&mut balances_decoder)?);
+                    }
+                    *decoder = balances_decoder.parent()?;
+                    entries
+                },
+                permissions: {
+                    let mut permissions_decoder = core::mem::take(decoder).permissions_decoder()?;
+                    let mut entries = Vec::new();
+                    while permissions_decoder.advance()?.is_some() {
+                        entries.push(Permissions::from_decoder(&mut permissions_decoder)?);
+                    }
+                    *decoder = permissions_decoder.parent()?;
+                    entries
+                },
+                reduce_only_assets: {
+                    let mut reduce_only_assets_decoder =
+                        core::mem::take(decoder).reduce_only_assets_decoder()?;
+                    let mut entries = Vec::new();
+                    while reduce_only_assets_decoder.advance()?.is_some() {
+                        entries.push(ReduceOnlyAssets::from_decoder(
+                            &mut reduce_only_assets_decoder,
+                        )?);
+                    }
+                    *decoder = reduce_only_assets_decoder.parent()?;
+                    entries
+                },
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut AccountResponseEncoder<'_>) -> SbeResult<()> {
+            encoder.commission_exponent(fit_exponent(
+                self.commission_exponent,
+                [
+                    self.commission_rate_maker,
+                    self.commission_rate_taker,
+                    self.commission_rate_buyer,
+                    self.commission_rate_seller,
+                ],
+            ));
+            encoder.commission_rate_maker_decimal(self.commission_rate_maker)?;
+            encoder.commission_rate_taker_decimal(self.commission_rate_taker)?;
+            encoder.commission_rate_buyer_decimal(self.commission_rate_buyer)?;
+            encoder.commission_rate_seller_decimal(self.commission_rate_seller)?;
+            encoder.can_trade(self.can_trade);
+            encoder.can_withdraw(self.can_withdraw);
+            encoder.can_deposit(self.can_deposit);
+            encoder.brokered(self.brokered);
+            encoder.require_self_trade_prevention(self.require_self_trade_prevention);
+            encoder.prevent_sor(self.prevent_sor);
+            encoder.update_time(self.update_time);
+            encoder.account_type(self.account_type);
+            encoder.trade_group_id(self.trade_group_id.unwrap_or(i64::MIN));
+            encoder.uid(self.uid);
+            let count = checked_length::<u32>(self.balances.len())?;
+            let mut balances_encoder =
+                core::mem::take(encoder).balances_encoder(count, BalancesEncoder::default());
+            for entry in &self.balances {
+                balances_encoder.advance()?;
+                entry.to_encoder(&mut balances_encoder)?;
+            }
+            *encoder = balances_encoder.parent()?;
+            let count = checked_length::<u32>(self.permissions.len())?;
+            let mut permissions_encoder =
+                core::mem::take(encoder).permissions_encoder(count, PermissionsEncoder::default());
+            for entry in &self.permissions {
+                permissions_encoder.advance()?;
+                entry.to_encoder(&mut permissions_encoder)?;
+            }
+            *encoder = permissions_encoder.parent()?;
+            let count = checked_length::<u32>(self.reduce_only_assets.len())?;
+            let mut reduce_only_assets_encoder = core::mem::take(encoder)
+                .reduce_only_assets_encoder(count, ReduceOnlyAssetsEncoder::default());
+            for entry in &self.reduce_only_assets {
+                reduce_only_assets_encoder.advance()?;
+                entry.to_encoder(&mut reduce_only_assets_encoder)?;
+            }
+            *encoder = reduce_only_assets_encoder.parent()?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            82 + self
+                .balances
+                .iter()
+                .map(Balances::encoded_length)
+                .sum::<usize>()
+                + self
+                    .permissions
+                    .iter()
+                    .map(Permissions::encoded_length)
+                    .sum::<usize>()
+                + self
+                    .reduce_only_assets
+                    .iter()
+                    .map(ReduceOnlyAssets::encoded_length)
+                    .sum::<usize>()
+        }
+    }
+
+    impl OwnedMessage for AccountResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = AccountResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = AccountResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Balances {
+        pub exponent: i8,
+        pub free: Decimal,
+        pub locked: Decimal,
+        pub asset: String,
+    }
+
+    impl Balances {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut BalancesDecoder<'a, AccountResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                exponent: decoder.exponent(),
+                free: decoder.free_decimal(),
+                locked: decoder.locked_decimal(),
+                asset: {
+                    let coordinates = decoder.asset_decoder()?;
+                    let slice = decoder.asset_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut BalancesEncoder<AccountResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.exponent(fit_exponent(self.exponent, [self.free, self.locked]));
+            encoder.free_decimal(self.free)?;
+            encoder.locked_decimal(self.locked)?;
+            let value = &self.asset;
+            checked_length::<u8>(value.len())?;
+            encoder.asset(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            18 + self.asset.len()
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Permissions {
+        pub permission: String,
+    }
+
+    impl Permissions {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut PermissionsDecoder<'a, AccountResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                permission: {
+                    let coordinates = decoder.permission_decoder()?;
+                    let slice = decoder.permission_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut PermissionsEncoder<AccountResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            let value = &self.permission;
+            checked_length::<u8>(value.len())?;
+            encoder.permission(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            1 + self.permission.len()
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ReduceOnlyAssets {
+        pub asset: String,
+    }
+
+    impl ReduceOnlyAssets {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut ReduceOnlyAssetsDecoder<'a, AccountResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                asset: {
+                    let coordinates = decoder.asset_decoder()?;
+                    let slice = decoder.asset_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut ReduceOnlyAssetsEncoder<AccountResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            let value = &self.asset;
+            checked_length::<u8>(value.len())?;
+            encoder.asset(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            1 + self.asset.len()
+        }
+    }
+} // end owned
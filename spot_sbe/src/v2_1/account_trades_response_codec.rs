@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::AccountTradesResponseDecoder;
 pub use encoder::AccountTradesResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::AccountTradesResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 0;
 pub const SBE_TEMPLATE_ID: u16 = 401;
@@ -260,6 +262,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'price' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'qty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -274,6 +290,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'qty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'quoteQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -288,6 +318,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'quoteQty' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn quote_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.quote_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'commission'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -302,6 +346,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'commission' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn commission_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 2;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.commission(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'time'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -389,6 +447,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> AccountTradesResponseDecoder<'a> {
@@ -398,7 +461,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -406,7 +470,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -414,13 +478,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -429,69 +499,96 @@ pub mod decoder {
 
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn trades_decoder(self) -> TradesDecoder<Self> {
+        pub fn trades_decoder(self) -> SbeResult<TradesDecoder<'a, Self>> {
             TradesDecoder::default().wrap(self)
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct TradesDecoder<P> {
+    pub struct TradesDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for TradesDecoder<P>
+    impl<'a, P> Reader<'a> for TradesDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for TradesDecoder<P>
+    impl<'a, P> Decoder<'a> for TradesDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> TradesDecoder<P>
+    impl<'a, P> TradesDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 70;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 2) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='trades', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=70, offset=0, componentTokenCount=69, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -505,14 +602,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(70))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// primitive field - 'REQUIRED'
@@ -562,24 +660,48 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 27)
         }
 
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> Decimal {
+            Decimal::new(self.price(), self.price_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 35)
         }
 
+        /// 'qty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn qty_decimal(&self) -> Decimal {
+            Decimal::new(self.qty(), self.qty_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn quote_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 43)
         }
 
+        /// 'quoteQty' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn quote_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.quote_qty(), self.price_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn commission(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 51)
         }
 
+        /// 'commission' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn commission_decimal(&self) -> Decimal {
+            Decimal::new(self.commission(), self.commission_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn time(&self) -> i64 {
@@ -606,38 +728,222 @@ pub mod decoder {
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn commission_asset_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn commission_asset_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn commission_asset_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn commission_asset_slice(
+            &'a self,
+            coordinates: (usize, usize),
+        ) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct AccountTradesResponse {
+        pub trades: Vec<Trades>,
+    }
+
+    impl AccountTradesResponse {
+        pub fn from_decoder(decoder: &mut AccountTradesResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                trades: {
+                    let mut trades_decoder = core::mem::take(decoder).trades_decoder()?;
+                    let mut entries = Vec::new();
+                    while trades_decoder.advance()?.is_some() {
+                        entries.push(Trades::from_decoder(&mut trades_decoder)?);
+                    }
+                    *decoder = trades_decoder.parent()?;
+                    entries
+                },
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut AccountTradesResponseEncoder<'_>) -> SbeResult<()> {
+            let count = checked_length::<u32>(self.trades.len())?;
+            let mut trades_encoder =
+                core::mem::take(encoder).trades_encoder(count, TradesEncoder::default());
+            for entry in &self.trades {
+                trades_encoder.advance()?;
+                entry.to_encoder(&mut trades_encoder)?;
+            }
+            *encoder = trades_encoder.parent()?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            6 + self
+                .trades
+                .iter()
+                .map(Trades::encoded_length)
+                .sum::<usize>()
+        }
+    }
+
+    impl OwnedMessage for AccountTradesResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = AccountTradesResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = AccountTradesResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Trades {
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub commission_exponent: i8,
+        pub id: i64,
+        pub order_id: i64,
+        pub order_list_id: Option<i64>,
+        pub price: Decimal,
+        pub qty: Decimal,
+        pub quote_qty: Decimal,
+        pub commission: Decimal,
+        pub time: i64,
+        pub is_buyer: BoolEnum,
+        pub is_maker: BoolEnum,
+        pub is_best_match: BoolEnum,
+        pub symbol: String,
+        pub commission_asset: Option<String>,
+    }
+
+    impl Trades {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut TradesDecoder<'a, AccountTradesResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                commission_exponent: decoder.commission_exponent(),
+                id: decoder.id(),
+                order_id: decoder.order_id(),
+                order_list_id: decoder.order_list_id(),
+                price: decoder.price_decimal(),
+                qty: decoder.qty_decimal(),
+                quote_qty: decoder.quote_qty_decimal(),
+                commission: decoder.commission_decimal(),
+                time: decoder.time(),
+                is_buyer: decoder.is_buyer(),
+                is_maker: decoder.is_maker(),
+                is_best_match: decoder.is_best_match(),
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                commission_asset: {
+                    let coordinates = decoder.commission_asset_decoder()?;
+                    let slice = decoder.commission_asset_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(
+                            core::str::from_utf8(slice)
+                                .map_err(|_| SbeErr::InvalidUtf8)?
+                                .to_owned(),
+                        )
+                    }
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut TradesEncoder<AccountTradesResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                [self.price, self.quote_qty],
+            ));
+            encoder.qty_exponent(fit_exponent(self.qty_exponent, [self.qty]));
+            encoder.commission_exponent(fit_exponent(self.commission_exponent, [self.commission]));
+            encoder.id(self.id);
+            encoder.order_id(self.order_id);
+            encoder.order_list_id(self.order_list_id.unwrap_or(i64::MIN));
+            encoder.price_decimal(self.price)?;
+            encoder.qty_decimal(self.qty)?;
+            encoder.quote_qty_decimal(self.quote_qty)?;
+            encoder.commission_decimal(self.commission)?;
+            encoder.time(self.time);
+            encoder.is_buyer(self.is_buyer);
+            encoder.is_maker(self.is_maker);
+            encoder.is_best_match(self.is_best_match);
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            let value = self.commission_asset.as_deref().unwrap_or_default();
+            checked_length::<u8>(value.len())?;
+            encoder.commission_asset(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            72 + self.symbol.len()
+                + self
+                    .commission_asset
+                    .as_ref()
+                    .map_or(0, |value| value.len())
+        }
+    }
+} // end owned
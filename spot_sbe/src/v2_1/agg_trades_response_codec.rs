@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::AggTradesResponseDecoder;
 pub use encoder::AggTradesResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::AggTradesResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 2;
 pub const SBE_TEMPLATE_ID: u16 = 202;
@@ -288,6 +290,36 @@ pub mod encoder {
             self.get_buf_mut().put_u8_at(offset, value as u8)
         }
     }
+
+    impl AggTradesEncoder<AggTradesResponseEncoder<'_>> {
+        /// 'price' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
+        /// 'qty' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.qty(mantissa);
+            Ok(())
+        }
+    }
 } // end encoder
 
 pub mod decoder {
@@ -320,6 +352,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> AggTradesResponseDecoder<'a> {
@@ -329,7 +366,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -337,7 +375,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -345,13 +383,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -372,69 +416,96 @@ pub mod decoder {
 
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn agg_trades_decoder(self) -> AggTradesDecoder<Self> {
+        pub fn agg_trades_decoder(self) -> SbeResult<AggTradesDecoder<'a, Self>> {
             AggTradesDecoder::default().wrap(self)
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct AggTradesDecoder<P> {
+    pub struct AggTradesDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for AggTradesDecoder<P>
+    impl<'a, P> Reader<'a> for AggTradesDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for AggTradesDecoder<P>
+    impl<'a, P> Decoder<'a> for AggTradesDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> AggTradesDecoder<P>
+    impl<'a, P> AggTradesDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 50;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='aggTrades', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=50, offset=2, componentTokenCount=36, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -448,14 +519,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(50))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// primitive field - 'REQUIRED'
@@ -506,4 +578,164 @@ pub mod decoder {
             self.get_buf().get_u8_at(self.offset + 49).into()
         }
     }
+
+    impl AggTradesDecoder<'_, AggTradesResponseDecoder<'_>> {
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.price(), exponent))
+        }
+
+        /// 'qty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn qty_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(Decimal::new(self.qty(), exponent))
+        }
+    }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct AggTradesResponse {
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub agg_trades: Vec<AggTrades>,
+    }
+
+    impl AggTradesResponse {
+        pub fn from_decoder(decoder: &mut AggTradesResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                agg_trades: {
+                    let mut agg_trades_decoder = core::mem::take(decoder).agg_trades_decoder()?;
+                    let mut entries = Vec::new();
+                    while agg_trades_decoder.advance()?.is_some() {
+                        entries.push(AggTrades::from_decoder(&mut agg_trades_decoder)?);
+                    }
+                    *decoder = agg_trades_decoder.parent()?;
+                    entries
+                },
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut AggTradesResponseEncoder<'_>) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                self.agg_trades.iter().map(|entry| entry.price),
+            ));
+            encoder.qty_exponent(fit_exponent(
+                self.qty_exponent,
+                self.agg_trades.iter().map(|entry| entry.qty),
+            ));
+            let count = checked_length::<u32>(self.agg_trades.len())?;
+            let mut agg_trades_encoder =
+                core::mem::take(encoder).agg_trades_encoder(count, AggTradesEncoder::default());
+            for entry in &self.agg_trades {
+                agg_trades_encoder.advance()?;
+                entry.to_encoder(&mut agg_trades_encoder)?;
+            }
+            *encoder = agg_trades_encoder.parent()?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            8 + self
+                .agg_trades
+                .iter()
+                .map(AggTrades::encoded_length)
+                .sum::<usize>()
+        }
+    }
+
+    impl OwnedMessage for AggTradesResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = AggTradesResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = AggTradesResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct AggTrades {
+        pub agg_trade_id: i64,
+        pub price: Decimal,
+        pub qty: Decimal,
+        pub first_trade_id: i64,
+        pub last_trade_id: i64,
+        pub time: i64,
+        pub is_buyer_maker: BoolEnum,
+        pub is_best_match: BoolEnum,
+    }
+
+    impl AggTrades {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut AggTradesDecoder<'a, AggTradesResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                agg_trade_id: decoder.agg_trade_id(),
+                price: decoder.price_decimal()?,
+                qty: decoder.qty_decimal()?,
+                first_trade_id: decoder.first_trade_id(),
+                last_trade_id: decoder.last_trade_id(),
+                time: decoder.time(),
+                is_buyer_maker: decoder.is_buyer_maker(),
+                is_best_match: decoder.is_best_match(),
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut AggTradesEncoder<AggTradesResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.agg_trade_id(self.agg_trade_id);
+            encoder.price_decimal(self.price)?;
+            encoder.qty_decimal(self.qty)?;
+            encoder.first_trade_id(self.first_trade_id);
+            encoder.last_trade_id(self.last_trade_id);
+            encoder.time(self.time);
+            encoder.is_buyer_maker(self.is_buyer_maker);
+            encoder.is_best_match(self.is_best_match);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            50
+        }
+    }
+} // end owned
@@ -0,0 +1,17 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum AllocationStatus {
+    Accepted = 0x1_u8,
+    #[default]
+    NullVal = 0xff_u8,
+}
+impl From<u8> for AllocationStatus {
+    #[inline]
+    fn from(v: u8) -> Self {
+        match v {
+            0x1_u8 => Self::Accepted,
+            _ => Self::NullVal,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 #[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AllowedSelfTradePreventionModes(pub u8);
 impl AllowedSelfTradePreventionModes {
     #[inline]
@@ -72,6 +73,12 @@ impl AllowedSelfTradePreventionModes {
         self
     }
 }
+impl From<u8> for AllowedSelfTradePreventionModes {
+    #[inline]
+    fn from(v: u8) -> Self {
+        AllowedSelfTradePreventionModes(v)
+    }
+}
 impl core::fmt::Debug for AllowedSelfTradePreventionModes {
     #[inline]
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
@@ -0,0 +1,925 @@
+//! Dispatch over every message in the schema by template ID.
+
+use super::meta_tables::*;
+use super::*;
+use crate::meta::MessageMeta;
+
+/// Decoder for any message in the schema, positioned after its header.
+#[derive(Clone, Copy, Debug)]
+pub enum AnyMessage<'a> {
+    PriceFilter(PriceFilterDecoder<'a>),
+    PercentPriceFilter(PercentPriceFilterDecoder<'a>),
+    PercentPriceBySideFilter(PercentPriceBySideFilterDecoder<'a>),
+    LotSizeFilter(LotSizeFilterDecoder<'a>),
+    MinNotionalFilter(MinNotionalFilterDecoder<'a>),
+    NotionalFilter(NotionalFilterDecoder<'a>),
+    IcebergPartsFilter(IcebergPartsFilterDecoder<'a>),
+    MarketLotSizeFilter(MarketLotSizeFilterDecoder<'a>),
+    MaxNumOrdersFilter(MaxNumOrdersFilterDecoder<'a>),
+    MaxNumAlgoOrdersFilter(MaxNumAlgoOrdersFilterDecoder<'a>),
+    MaxNumIcebergOrdersFilter(MaxNumIcebergOrdersFilterDecoder<'a>),
+    MaxPositionFilter(MaxPositionFilterDecoder<'a>),
+    TrailingDeltaFilter(TrailingDeltaFilterDecoder<'a>),
+    TPlusSellFilter(TPlusSellFilterDecoder<'a>),
+    ExchangeMaxNumOrdersFilter(ExchangeMaxNumOrdersFilterDecoder<'a>),
+    ExchangeMaxNumAlgoOrdersFilter(ExchangeMaxNumAlgoOrdersFilterDecoder<'a>),
+    ExchangeMaxNumIcebergOrdersFilter(ExchangeMaxNumIcebergOrdersFilterDecoder<'a>),
+    WebSocketResponse(WebSocketResponseDecoder<'a>),
+    WebSocketSessionLogonResponse(WebSocketSessionLogonResponseDecoder<'a>),
+    WebSocketSessionStatusResponse(WebSocketSessionStatusResponseDecoder<'a>),
+    WebSocketSessionLogoutResponse(WebSocketSessionLogoutResponseDecoder<'a>),
+    ErrorResponse(ErrorResponseDecoder<'a>),
+    PingResponse(PingResponseDecoder<'a>),
+    ServerTimeResponse(ServerTimeResponseDecoder<'a>),
+    ExchangeInfoResponse(ExchangeInfoResponseDecoder<'a>),
+    DepthResponse(DepthResponseDecoder<'a>),
+    TradesResponse(TradesResponseDecoder<'a>),
+    AggTradesResponse(AggTradesResponseDecoder<'a>),
+    KlinesResponse(KlinesResponseDecoder<'a>),
+    AveragePriceResponse(AveragePriceResponseDecoder<'a>),
+    Ticker24hSymbolFullResponse(Ticker24hSymbolFullResponseDecoder<'a>),
+    Ticker24hFullResponse(Ticker24hFullResponseDecoder<'a>),
+    Ticker24hSymbolMiniResponse(Ticker24hSymbolMiniResponseDecoder<'a>),
+    Ticker24hMiniResponse(Ticker24hMiniResponseDecoder<'a>),
+    PriceTickerSymbolResponse(PriceTickerSymbolResponseDecoder<'a>),
+    PriceTickerResponse(PriceTickerResponseDecoder<'a>),
+    BookTickerSymbolResponse(BookTickerSymbolResponseDecoder<'a>),
+    BookTickerResponse(BookTickerResponseDecoder<'a>),
+    TickerSymbolFullResponse(TickerSymbolFullResponseDecoder<'a>),
+    TickerFullResponse(TickerFullResponseDecoder<'a>),
+    TickerSymbolMiniResponse(TickerSymbolMiniResponseDecoder<'a>),
+    TickerMiniResponse(TickerMiniResponseDecoder<'a>),
+    NewOrderAckResponse(NewOrderAckResponseDecoder<'a>),
+    NewOrderResultResponse(NewOrderResultResponseDecoder<'a>),
+    NewOrderFullResponse(NewOrderFullResponseDecoder<'a>),
+    OrderTestResponse(OrderTestResponseDecoder<'a>),
+    OrderResponse(OrderResponseDecoder<'a>),
+    CancelOrderResponse(CancelOrderResponseDecoder<'a>),
+    CancelOpenOrdersResponse(CancelOpenOrdersResponseDecoder<'a>),
+    CancelReplaceOrderResponse(CancelReplaceOrderResponseDecoder<'a>),
+    OrdersResponse(OrdersResponseDecoder<'a>),
+    NewOrderListAckResponse(NewOrderListAckResponseDecoder<'a>),
+    NewOrderListResultResponse(NewOrderListResultResponseDecoder<'a>),
+    NewOrderListFullResponse(NewOrderListFullResponseDecoder<'a>),
+    CancelOrderListResponse(CancelOrderListResponseDecoder<'a>),
+    OrderListResponse(OrderListResponseDecoder<'a>),
+    OrderListsResponse(OrderListsResponseDecoder<'a>),
+    OrderTestWithCommissionsResponse(OrderTestWithCommissionsResponseDecoder<'a>),
+    AccountResponse(AccountResponseDecoder<'a>),
+    AccountTradesResponse(AccountTradesResponseDecoder<'a>),
+    AccountOrderRateLimitResponse(AccountOrderRateLimitResponseDecoder<'a>),
+    AccountPreventedMatchesResponse(AccountPreventedMatchesResponseDecoder<'a>),
+    AccountAllocationsResponse(AccountAllocationsResponseDecoder<'a>),
+    AccountCommissionResponse(AccountCommissionResponseDecoder<'a>),
+    UserDataStreamStartResponse(UserDataStreamStartResponseDecoder<'a>),
+    UserDataStreamPingResponse(UserDataStreamPingResponseDecoder<'a>),
+    UserDataStreamStopResponse(UserDataStreamStopResponseDecoder<'a>),
+    UserDataStreamSubscribeResponse(UserDataStreamSubscribeResponseDecoder<'a>),
+    UserDataStreamUnsubscribeResponse(UserDataStreamUnsubscribeResponseDecoder<'a>),
+    BalanceUpdateEvent(BalanceUpdateEventDecoder<'a>),
+    EventStreamTerminatedEvent(EventStreamTerminatedEventDecoder<'a>),
+    ExecutionReportEvent(ExecutionReportEventDecoder<'a>),
+    ExternalLockUpdateEvent(ExternalLockUpdateEventDecoder<'a>),
+    ListStatusEvent(ListStatusEventDecoder<'a>),
+    OutboundAccountPositionEvent(OutboundAccountPositionEventDecoder<'a>),
+}
+
+impl<'a> AnyMessage<'a> {
+    /// Wraps the decoder matching the header's template ID.
+    pub fn from_header(header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+        Ok(match header.template_id() {
+            price_filter_codec::SBE_TEMPLATE_ID => {
+                Self::PriceFilter(PriceFilterDecoder::default().header(header)?)
+            }
+            percent_price_filter_codec::SBE_TEMPLATE_ID => {
+                Self::PercentPriceFilter(PercentPriceFilterDecoder::default().header(header)?)
+            }
+            percent_price_by_side_filter_codec::SBE_TEMPLATE_ID => Self::PercentPriceBySideFilter(
+                PercentPriceBySideFilterDecoder::default().header(header)?,
+            ),
+            lot_size_filter_codec::SBE_TEMPLATE_ID => {
+                Self::LotSizeFilter(LotSizeFilterDecoder::default().header(header)?)
+            }
+            min_notional_filter_codec::SBE_TEMPLATE_ID => {
+                Self::MinNotionalFilter(MinNotionalFilterDecoder::default().header(header)?)
+            }
+            notional_filter_codec::SBE_TEMPLATE_ID => {
+                Self::NotionalFilter(NotionalFilterDecoder::default().header(header)?)
+            }
+            iceberg_parts_filter_codec::SBE_TEMPLATE_ID => {
+                Self::IcebergPartsFilter(IcebergPartsFilterDecoder::default().header(header)?)
+            }
+            market_lot_size_filter_codec::SBE_TEMPLATE_ID => {
+                Self::MarketLotSizeFilter(MarketLotSizeFilterDecoder::default().header(header)?)
+            }
+            max_num_orders_filter_codec::SBE_TEMPLATE_ID => {
+                Self::MaxNumOrdersFilter(MaxNumOrdersFilterDecoder::default().header(header)?)
+            }
+            max_num_algo_orders_filter_codec::SBE_TEMPLATE_ID => Self::MaxNumAlgoOrdersFilter(
+                MaxNumAlgoOrdersFilterDecoder::default().header(header)?,
+            ),
+            max_num_iceberg_orders_filter_codec::SBE_TEMPLATE_ID => {
+                Self::MaxNumIcebergOrdersFilter(
+                    MaxNumIcebergOrdersFilterDecoder::default().header(header)?,
+                )
+            }
+            max_position_filter_codec::SBE_TEMPLATE_ID => {
+                Self::MaxPositionFilter(MaxPositionFilterDecoder::default().header(header)?)
+            }
+            trailing_delta_filter_codec::SBE_TEMPLATE_ID => {
+                Self::TrailingDeltaFilter(TrailingDeltaFilterDecoder::default().header(header)?)
+            }
+            tp_lus_sell_filter_codec::SBE_TEMPLATE_ID => {
+                Self::TPlusSellFilter(TPlusSellFilterDecoder::default().header(header)?)
+            }
+            exchange_max_num_orders_filter_codec::SBE_TEMPLATE_ID => {
+                Self::ExchangeMaxNumOrdersFilter(
+                    ExchangeMaxNumOrdersFilterDecoder::default().header(header)?,
+                )
+            }
+            exchange_max_num_algo_orders_filter_codec::SBE_TEMPLATE_ID => {
+                Self::ExchangeMaxNumAlgoOrdersFilter(
+                    ExchangeMaxNumAlgoOrdersFilterDecoder::default().header(header)?,
+                )
+            }
+            exchange_max_num_iceberg_orders_filter_codec::SBE_TEMPLATE_ID => {
+                Self::ExchangeMaxNumIcebergOrdersFilter(
+                    ExchangeMaxNumIcebergOrdersFilterDecoder::default().header(header)?,
+                )
+            }
+            web_socket_response_codec::SBE_TEMPLATE_ID => {
+                Self::WebSocketResponse(WebSocketResponseDecoder::default().header(header)?)
+            }
+            web_socket_session_logon_response_codec::SBE_TEMPLATE_ID => {
+                Self::WebSocketSessionLogonResponse(
+                    WebSocketSessionLogonResponseDecoder::default().header(header)?,
+                )
+            }
+            web_socket_session_status_response_codec::SBE_TEMPLATE_ID => {
+                Self::WebSocketSessionStatusResponse(
+                    WebSocketSessionStatusResponseDecoder::default().header(header)?,
+                )
+            }
+            web_socket_session_logout_response_codec::SBE_TEMPLATE_ID => {
+                Self::WebSocketSessionLogoutResponse(
+                    WebSocketSessionLogoutResponseDecoder::default().header(header)?,
+                )
+            }
+            error_response_codec::SBE_TEMPLATE_ID => {
+                Self::ErrorResponse(ErrorResponseDecoder::default().header(header)?)
+            }
+            ping_response_codec::SBE_TEMPLATE_ID => {
+                Self::PingResponse(PingResponseDecoder::default().header(header)?)
+            }
+            server_time_response_codec::SBE_TEMPLATE_ID => {
+                Self::ServerTimeResponse(ServerTimeResponseDecoder::default().header(header)?)
+            }
+            exchange_info_response_codec::SBE_TEMPLATE_ID => {
+                Self::ExchangeInfoResponse(ExchangeInfoResponseDecoder::default().header(header)?)
+            }
+            depth_response_codec::SBE_TEMPLATE_ID => {
+                Self::DepthResponse(DepthResponseDecoder::default().header(header)?)
+            }
+            trades_response_codec::SBE_TEMPLATE_ID => {
+                Self::TradesResponse(TradesResponseDecoder::default().header(header)?)
+            }
+            agg_trades_response_codec::SBE_TEMPLATE_ID => {
+                Self::AggTradesResponse(AggTradesResponseDecoder::default().header(header)?)
+            }
+            klines_response_codec::SBE_TEMPLATE_ID => {
+                Self::KlinesResponse(KlinesResponseDecoder::default().header(header)?)
+            }
+            average_price_response_codec::SBE_TEMPLATE_ID => {
+                Self::AveragePriceResponse(AveragePriceResponseDecoder::default().header(header)?)
+            }
+            ticker_24_hs_ymbol_full_response_codec::SBE_TEMPLATE_ID => {
+                Self::Ticker24hSymbolFullResponse(
+                    Ticker24hSymbolFullResponseDecoder::default().header(header)?,
+                )
+            }
+            ticker_24_hf_ull_response_codec::SBE_TEMPLATE_ID => {
+                Self::Ticker24hFullResponse(Ticker24hFullResponseDecoder::default().header(header)?)
+            }
+            ticker_24_hs_ymbol_mini_response_codec::SBE_TEMPLATE_ID => {
+                Self::Ticker24hSymbolMiniResponse(
+                    Ticker24hSymbolMiniResponseDecoder::default().header(header)?,
+                )
+            }
+            ticker_24_hm_ini_response_codec::SBE_TEMPLATE_ID => {
+                Self::Ticker24hMiniResponse(Ticker24hMiniResponseDecoder::default().header(header)?)
+            }
+            price_ticker_symbol_response_codec::SBE_TEMPLATE_ID => Self::PriceTickerSymbolResponse(
+                PriceTickerSymbolResponseDecoder::default().header(header)?,
+            ),
+            price_ticker_response_codec::SBE_TEMPLATE_ID => {
+                Self::PriceTickerResponse(PriceTickerResponseDecoder::default().header(header)?)
+            }
+            book_ticker_symbol_response_codec::SBE_TEMPLATE_ID => Self::BookTickerSymbolResponse(
+                BookTickerSymbolResponseDecoder::default().header(header)?,
+            ),
+            book_ticker_response_codec::SBE_TEMPLATE_ID => {
+                Self::BookTickerResponse(BookTickerResponseDecoder::default().header(header)?)
+            }
+            ticker_symbol_full_response_codec::SBE_TEMPLATE_ID => Self::TickerSymbolFullResponse(
+                TickerSymbolFullResponseDecoder::default().header(header)?,
+            ),
+            ticker_full_response_codec::SBE_TEMPLATE_ID => {
+                Self::TickerFullResponse(TickerFullResponseDecoder::default().header(header)?)
+            }
+            ticker_symbol_mini_response_codec::SBE_TEMPLATE_ID => Self::TickerSymbolMiniResponse(
+                TickerSymbolMiniResponseDecoder::default().header(header)?,
+            ),
+            ticker_mini_response_codec::SBE_TEMPLATE_ID => {
+                Self::TickerMiniResponse(TickerMiniResponseDecoder::default().header(header)?)
+            }
+            new_order_ack_response_codec::SBE_TEMPLATE_ID => {
+                Self::NewOrderAckResponse(NewOrderAckResponseDecoder::default().header(header)?)
+            }
+            new_order_result_response_codec::SBE_TEMPLATE_ID => Self::NewOrderResultResponse(
+                NewOrderResultResponseDecoder::default().header(header)?,
+            ),
+            new_order_full_response_codec::SBE_TEMPLATE_ID => {
+                Self::NewOrderFullResponse(NewOrderFullResponseDecoder::default().header(header)?)
+            }
+            order_test_response_codec::SBE_TEMPLATE_ID => {
+                Self::OrderTestResponse(OrderTestResponseDecoder::default().header(header)?)
+            }
+            order_response_codec::SBE_TEMPLATE_ID => {
+                Self::OrderResponse(OrderResponseDecoder::default().header(header)?)
+            }
+            cancel_order_response_codec::SBE_TEMPLATE_ID => {
+                Self::CancelOrderResponse(CancelOrderResponseDecoder::default().header(header)?)
+            }
+            cancel_open_orders_response_codec::SBE_TEMPLATE_ID => Self::CancelOpenOrdersResponse(
+                CancelOpenOrdersResponseDecoder::default().header(header)?,
+            ),
+            cancel_replace_order_response_codec::SBE_TEMPLATE_ID => {
+                Self::CancelReplaceOrderResponse(
+                    CancelReplaceOrderResponseDecoder::default().header(header)?,
+                )
+            }
+            orders_response_codec::SBE_TEMPLATE_ID => {
+                Self::OrdersResponse(OrdersResponseDecoder::default().header(header)?)
+            }
+            new_order_list_ack_response_codec::SBE_TEMPLATE_ID => Self::NewOrderListAckResponse(
+                NewOrderListAckResponseDecoder::default().header(header)?,
+            ),
+            new_order_list_result_response_codec::SBE_TEMPLATE_ID => {
+                Self::NewOrderListResultResponse(
+                    NewOrderListResultResponseDecoder::default().header(header)?,
+                )
+            }
+            new_order_list_full_response_codec::SBE_TEMPLATE_ID => Self::NewOrderListFullResponse(
+                NewOrderListFullResponseDecoder::default().header(header)?,
+            ),
+            cancel_order_list_response_codec::SBE_TEMPLATE_ID => Self::CancelOrderListResponse(
+                CancelOrderListResponseDecoder::default().header(header)?,
+            ),
+            order_list_response_codec::SBE_TEMPLATE_ID => {
+                Self::OrderListResponse(OrderListResponseDecoder::default().header(header)?)
+            }
+            order_lists_response_codec::SBE_TEMPLATE_ID => {
+                Self::OrderListsResponse(OrderListsResponseDecoder::default().header(header)?)
+            }
+            order_test_with_commissions_response_codec::SBE_TEMPLATE_ID => {
+                Self::OrderTestWithCommissionsResponse(
+                    OrderTestWithCommissionsResponseDecoder::default().header(header)?,
+                )
+            }
+            account_response_codec::SBE_TEMPLATE_ID => {
+                Self::AccountResponse(AccountResponseDecoder::default().header(header)?)
+            }
+            account_trades_response_codec::SBE_TEMPLATE_ID => {
+                Self::AccountTradesResponse(AccountTradesResponseDecoder::default().header(header)?)
+            }
+            account_order_rate_limit_response_codec::SBE_TEMPLATE_ID => {
+                Self::AccountOrderRateLimitResponse(
+                    AccountOrderRateLimitResponseDecoder::default().header(header)?,
+                )
+            }
+            account_prevented_matches_response_codec::SBE_TEMPLATE_ID => {
+                Self::AccountPreventedMatchesResponse(
+                    AccountPreventedMatchesResponseDecoder::default().header(header)?,
+                )
+            }
+            account_allocations_response_codec::SBE_TEMPLATE_ID => {
+                Self::AccountAllocationsResponse(
+                    AccountAllocationsResponseDecoder::default().header(header)?,
+                )
+            }
+            account_commission_response_codec::SBE_TEMPLATE_ID => Self::AccountCommissionResponse(
+                AccountCommissionResponseDecoder::default().header(header)?,
+            ),
+            user_data_stream_start_response_codec::SBE_TEMPLATE_ID => {
+                Self::UserDataStreamStartResponse(
+                    UserDataStreamStartResponseDecoder::default().header(header)?,
+                )
+            }
+            user_data_stream_ping_response_codec::SBE_TEMPLATE_ID => {
+                Self::UserDataStreamPingResponse(
+                    UserDataStreamPingResponseDecoder::default().header(header)?,
+                )
+            }
+            user_data_stream_stop_response_codec::SBE_TEMPLATE_ID => {
+                Self::UserDataStreamStopResponse(
+                    UserDataStreamStopResponseDecoder::default().header(header)?,
+                )
+            }
+            user_data_stream_subscribe_response_codec::SBE_TEMPLATE_ID => {
+                Self::UserDataStreamSubscribeResponse(
+                    UserDataStreamSubscribeResponseDecoder::default().header(header)?,
+                )
+            }
+            user_data_stream_unsubscribe_response_codec::SBE_TEMPLATE_ID => {
+                Self::UserDataStreamUnsubscribeResponse(
+                    UserDataStreamUnsubscribeResponseDecoder::default().header(header)?,
+                )
+            }
+            balance_update_event_codec::SBE_TEMPLATE_ID => {
+                Self::BalanceUpdateEvent(BalanceUpdateEventDecoder::default().header(header)?)
+            }
+            event_stream_terminated_event_codec::SBE_TEMPLATE_ID => {
+                Self::EventStreamTerminatedEvent(
+                    EventStreamTerminatedEventDecoder::default().header(header)?,
+                )
+            }
+            execution_report_event_codec::SBE_TEMPLATE_ID => {
+                Self::ExecutionReportEvent(ExecutionReportEventDecoder::default().header(header)?)
+            }
+            external_lock_update_event_codec::SBE_TEMPLATE_ID => Self::ExternalLockUpdateEvent(
+                ExternalLockUpdateEventDecoder::default().header(header)?,
+            ),
+            list_status_event_codec::SBE_TEMPLATE_ID => {
+                Self::ListStatusEvent(ListStatusEventDecoder::default().header(header)?)
+            }
+            outbound_account_position_event_codec::SBE_TEMPLATE_ID => {
+                Self::OutboundAccountPositionEvent(
+                    OutboundAccountPositionEventDecoder::default().header(header)?,
+                )
+            }
+            template_id => return Err(SbeErr::UnknownTemplateId { template_id }),
+        })
+    }
+
+    /// Schema metadata for the message: name, template ID, block length,
+    /// `sinceVersion` and members.
+    pub fn meta(&self) -> &'static MessageMeta {
+        match self {
+            Self::PriceFilter(_) => &PRICE_FILTER,
+            Self::PercentPriceFilter(_) => &PERCENT_PRICE_FILTER,
+            Self::PercentPriceBySideFilter(_) => &PERCENT_PRICE_BY_SIDE_FILTER,
+            Self::LotSizeFilter(_) => &LOT_SIZE_FILTER,
+            Self::MinNotionalFilter(_) => &MIN_NOTIONAL_FILTER,
+            Self::NotionalFilter(_) => &NOTIONAL_FILTER,
+            Self::IcebergPartsFilter(_) => &ICEBERG_PARTS_FILTER,
+            Self::MarketLotSizeFilter(_) => &MARKET_LOT_SIZE_FILTER,
+            Self::MaxNumOrdersFilter(_) => &MAX_NUM_ORDERS_FILTER,
+            Self::MaxNumAlgoOrdersFilter(_) => &MAX_NUM_ALGO_ORDERS_FILTER,
+            Self::MaxNumIcebergOrdersFilter(_) => &MAX_NUM_ICEBERG_ORDERS_FILTER,
+            Self::MaxPositionFilter(_) => &MAX_POSITION_FILTER,
+            Self::TrailingDeltaFilter(_) => &TRAILING_DELTA_FILTER,
+            Self::TPlusSellFilter(_) => &TPLUS_SELL_FILTER,
+            Self::ExchangeMaxNumOrdersFilter(_) => &EXCHANGE_MAX_NUM_ORDERS_FILTER,
+            Self::ExchangeMaxNumAlgoOrdersFilter(_) => &EXCHANGE_MAX_NUM_ALGO_ORDERS_FILTER,
+            Self::ExchangeMaxNumIcebergOrdersFilter(_) => &EXCHANGE_MAX_NUM_ICEBERG_ORDERS_FILTER,
+            Self::WebSocketResponse(_) => &WEB_SOCKET_RESPONSE,
+            Self::WebSocketSessionLogonResponse(_) => &WEB_SOCKET_SESSION_LOGON_RESPONSE,
+            Self::WebSocketSessionStatusResponse(_) => &WEB_SOCKET_SESSION_STATUS_RESPONSE,
+            Self::WebSocketSessionLogoutResponse(_) => &WEB_SOCKET_SESSION_LOGOUT_RESPONSE,
+            Self::ErrorResponse(_) => &ERROR_RESPONSE,
+            Self::PingResponse(_) => &PING_RESPONSE,
+            Self::ServerTimeResponse(_) => &SERVER_TIME_RESPONSE,
+            Self::ExchangeInfoResponse(_) => &EXCHANGE_INFO_RESPONSE,
+            Self::DepthResponse(_) => &DEPTH_RESPONSE,
+            Self::TradesResponse(_) => &TRADES_RESPONSE,
+            Self::AggTradesResponse(_) => &AGG_TRADES_RESPONSE,
+            Self::KlinesResponse(_) => &KLINES_RESPONSE,
+            Self::AveragePriceResponse(_) => &AVERAGE_PRICE_RESPONSE,
+            Self::Ticker24hSymbolFullResponse(_) => &TICKER24H_SYMBOL_FULL_RESPONSE,
+            Self::Ticker24hFullResponse(_) => &TICKER24H_FULL_RESPONSE,
+            Self::Ticker24hSymbolMiniResponse(_) => &TICKER24H_SYMBOL_MINI_RESPONSE,
+            Self::Ticker24hMiniResponse(_) => &TICKER24H_MINI_RESPONSE,
+            Self::PriceTickerSymbolResponse(_) => &PRICE_TICKER_SYMBOL_RESPONSE,
+            Self::PriceTickerResponse(_) => &PRICE_TICKER_RESPONSE,
+            Self::BookTickerSymbolResponse(_) => &BOOK_TICKER_SYMBOL_RESPONSE,
+            Self::BookTickerResponse(_) => &BOOK_TICKER_RESPONSE,
+            Self::TickerSymbolFullResponse(_) => &TICKER_SYMBOL_FULL_RESPONSE,
+            Self::TickerFullResponse(_) => &TICKER_FULL_RESPONSE,
+            Self::TickerSymbolMiniResponse(_) => &TICKER_SYMBOL_MINI_RESPONSE,
+            Self::TickerMiniResponse(_) => &TICKER_MINI_RESPONSE,
+            Self::NewOrderAckResponse(_) => &NEW_ORDER_ACK_RESPONSE,
+            Self::NewOrderResultResponse(_) => &NEW_ORDER_RESULT_RESPONSE,
+            Self::NewOrderFullResponse(_) => &NEW_ORDER_FULL_RESPONSE,
+            Self::OrderTestResponse(_) => &ORDER_TEST_RESPONSE,
+            Self::OrderResponse(_) => &ORDER_RESPONSE,
+            Self::CancelOrderResponse(_) => &CANCEL_ORDER_RESPONSE,
+            Self::CancelOpenOrdersResponse(_) => &CANCEL_OPEN_ORDERS_RESPONSE,
+            Self::CancelReplaceOrderResponse(_) => &CANCEL_REPLACE_ORDER_RESPONSE,
+            Self::OrdersResponse(_) => &ORDERS_RESPONSE,
+            Self::NewOrderListAckResponse(_) => &NEW_ORDER_LIST_ACK_RESPONSE,
+            Self::NewOrderListResultResponse(_) => &NEW_ORDER_LIST_RESULT_RESPONSE,
+            Self::NewOrderListFullResponse(_) => &NEW_ORDER_LIST_FULL_RESPONSE,
+            Self::CancelOrderListResponse(_) => &CANCEL_ORDER_LIST_RESPONSE,
+            Self::OrderListResponse(_) => &ORDER_LIST_RESPONSE,
+            Self::OrderListsResponse(_) => &ORDER_LISTS_RESPONSE,
+            Self::OrderTestWithCommissionsResponse(_) => &ORDER_TEST_WITH_COMMISSIONS_RESPONSE,
+            Self::AccountResponse(_) => &ACCOUNT_RESPONSE,
+            Self::AccountTradesResponse(_) => &ACCOUNT_TRADES_RESPONSE,
+            Self::AccountOrderRateLimitResponse(_) => &ACCOUNT_ORDER_RATE_LIMIT_RESPONSE,
+            Self::AccountPreventedMatchesResponse(_) => &ACCOUNT_PREVENTED_MATCHES_RESPONSE,
+            Self::AccountAllocationsResponse(_) => &ACCOUNT_ALLOCATIONS_RESPONSE,
+            Self::AccountCommissionResponse(_) => &ACCOUNT_COMMISSION_RESPONSE,
+            Self::UserDataStreamStartResponse(_) => &USER_DATA_STREAM_START_RESPONSE,
+            Self::UserDataStreamPingResponse(_) => &USER_DATA_STREAM_PING_RESPONSE,
+            Self::UserDataStreamStopResponse(_) => &USER_DATA_STREAM_STOP_RESPONSE,
+            Self::UserDataStreamSubscribeResponse(_) => &USER_DATA_STREAM_SUBSCRIBE_RESPONSE,
+            Self::UserDataStreamUnsubscribeResponse(_) => &USER_DATA_STREAM_UNSUBSCRIBE_RESPONSE,
+            Self::BalanceUpdateEvent(_) => &BALANCE_UPDATE_EVENT,
+            Self::EventStreamTerminatedEvent(_) => &EVENT_STREAM_TERMINATED_EVENT,
+            Self::ExecutionReportEvent(_) => &EXECUTION_REPORT_EVENT,
+            Self::ExternalLockUpdateEvent(_) => &EXTERNAL_LOCK_UPDATE_EVENT,
+            Self::ListStatusEvent(_) => &LIST_STATUS_EVENT,
+            Self::OutboundAccountPositionEvent(_) => &OUTBOUND_ACCOUNT_POSITION_EVENT,
+        }
+    }
+
+    #[inline]
+    pub fn template_id(&self) -> u16 {
+        self.meta().id
+    }
+
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.meta().name
+    }
+
+    /// Decodes the whole message into its owned struct.
+    #[cfg(feature = "serde")]
+    pub fn to_owned_message(&self) -> SbeResult<OwnedAnyMessage> {
+        Ok(match *self {
+            Self::PriceFilter(mut decoder) => {
+                OwnedAnyMessage::PriceFilter(PriceFilter::from_decoder(&mut decoder)?)
+            }
+            Self::PercentPriceFilter(mut decoder) => {
+                OwnedAnyMessage::PercentPriceFilter(PercentPriceFilter::from_decoder(&mut decoder)?)
+            }
+            Self::PercentPriceBySideFilter(mut decoder) => {
+                OwnedAnyMessage::PercentPriceBySideFilter(PercentPriceBySideFilter::from_decoder(
+                    &mut decoder,
+                )?)
+            }
+            Self::LotSizeFilter(mut decoder) => {
+                OwnedAnyMessage::LotSizeFilter(LotSizeFilter::from_decoder(&mut decoder)?)
+            }
+            Self::MinNotionalFilter(mut decoder) => {
+                OwnedAnyMessage::MinNotionalFilter(MinNotionalFilter::from_decoder(&mut decoder)?)
+            }
+            Self::NotionalFilter(mut decoder) => {
+                OwnedAnyMessage::NotionalFilter(NotionalFilter::from_decoder(&mut decoder)?)
+            }
+            Self::IcebergPartsFilter(mut decoder) => {
+                OwnedAnyMessage::IcebergPartsFilter(IcebergPartsFilter::from_decoder(&mut decoder)?)
+            }
+            Self::MarketLotSizeFilter(mut decoder) => OwnedAnyMessage::MarketLotSizeFilter(
+                MarketLotSizeFilter::from_decoder(&mut decoder)?,
+            ),
+            Self::MaxNumOrdersFilter(mut decoder) => {
+                OwnedAnyMessage::MaxNumOrdersFilter(MaxNumOrdersFilter::from_decoder(&mut decoder)?)
+            }
+            Self::MaxNumAlgoOrdersFilter(mut decoder) => OwnedAnyMessage::MaxNumAlgoOrdersFilter(
+                MaxNumAlgoOrdersFilter::from_decoder(&mut decoder)?,
+            ),
+            Self::MaxNumIcebergOrdersFilter(mut decoder) => {
+                OwnedAnyMessage::MaxNumIcebergOrdersFilter(MaxNumIcebergOrdersFilter::from_decoder(
+                    &mut decoder,
+                )?)
+            }
+            Self::MaxPositionFilter(mut decoder) => {
+                OwnedAnyMessage::MaxPositionFilter(MaxPositionFilter::from_decoder(&mut decoder)?)
+            }
+            Self::TrailingDeltaFilter(mut decoder) => OwnedAnyMessage::TrailingDeltaFilter(
+                TrailingDeltaFilter::from_decoder(&mut decoder)?,
+            ),
+            Self::TPlusSellFilter(mut decoder) => {
+                OwnedAnyMessage::TPlusSellFilter(TPlusSellFilter::from_decoder(&mut decoder)?)
+            }
+            Self::ExchangeMaxNumOrdersFilter(mut decoder) => {
+                OwnedAnyMessage::ExchangeMaxNumOrdersFilter(
+                    ExchangeMaxNumOrdersFilter::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::ExchangeMaxNumAlgoOrdersFilter(mut decoder) => {
+                OwnedAnyMessage::ExchangeMaxNumAlgoOrdersFilter(
+                    ExchangeMaxNumAlgoOrdersFilter::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::ExchangeMaxNumIcebergOrdersFilter(mut decoder) => {
+                OwnedAnyMessage::ExchangeMaxNumIcebergOrdersFilter(
+                    ExchangeMaxNumIcebergOrdersFilter::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::WebSocketResponse(mut decoder) => {
+                OwnedAnyMessage::WebSocketResponse(WebSocketResponse::from_decoder(&mut decoder)?)
+            }
+            Self::WebSocketSessionLogonResponse(mut decoder) => {
+                OwnedAnyMessage::WebSocketSessionLogonResponse(
+                    WebSocketSessionLogonResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::WebSocketSessionStatusResponse(mut decoder) => {
+                OwnedAnyMessage::WebSocketSessionStatusResponse(
+                    WebSocketSessionStatusResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::WebSocketSessionLogoutResponse(mut decoder) => {
+                OwnedAnyMessage::WebSocketSessionLogoutResponse(
+                    WebSocketSessionLogoutResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::ErrorResponse(mut decoder) => {
+                OwnedAnyMessage::ErrorResponse(ErrorResponse::from_decoder(&mut decoder)?)
+            }
+            Self::PingResponse(mut decoder) => {
+                OwnedAnyMessage::PingResponse(PingResponse::from_decoder(&mut decoder)?)
+            }
+            Self::ServerTimeResponse(mut decoder) => {
+                OwnedAnyMessage::ServerTimeResponse(ServerTimeResponse::from_decoder(&mut decoder)?)
+            }
+            Self::ExchangeInfoResponse(mut decoder) => OwnedAnyMessage::ExchangeInfoResponse(
+                ExchangeInfoResponse::from_decoder(&mut decoder)?,
+            ),
+            Self::DepthResponse(mut decoder) => {
+                OwnedAnyMessage::DepthResponse(DepthResponse::from_decoder(&mut decoder)?)
+            }
+            Self::TradesResponse(mut decoder) => {
+                OwnedAnyMessage::TradesResponse(TradesResponse::from_decoder(&mut decoder)?)
+            }
+            Self::AggTradesResponse(mut decoder) => {
+                OwnedAnyMessage::AggTradesResponse(AggTradesResponse::from_decoder(&mut decoder)?)
+            }
+            Self::KlinesResponse(mut decoder) => {
+                OwnedAnyMessage::KlinesResponse(KlinesResponse::from_decoder(&mut decoder)?)
+            }
+            Self::AveragePriceResponse(mut decoder) => OwnedAnyMessage::AveragePriceResponse(
+                AveragePriceResponse::from_decoder(&mut decoder)?,
+            ),
+            Self::Ticker24hSymbolFullResponse(mut decoder) => {
+                OwnedAnyMessage::Ticker24hSymbolFullResponse(
+                    Ticker24hSymbolFullResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::Ticker24hFullResponse(mut decoder) => OwnedAnyMessage::Ticker24hFullResponse(
+                Ticker24hFullResponse::from_decoder(&mut decoder)?,
+            ),
+            Self::Ticker24hSymbolMiniResponse(mut decoder) => {
+                OwnedAnyMessage::Ticker24hSymbolMiniResponse(
+                    Ticker24hSymbolMiniResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::Ticker24hMiniResponse(mut decoder) => OwnedAnyMessage::Ticker24hMiniResponse(
+                Ticker24hMiniResponse::from_decoder(&mut decoder)?,
+            ),
+            Self::PriceTickerSymbolResponse(mut decoder) => {
+                OwnedAnyMessage::PriceTickerSymbolResponse(PriceTickerSymbolResponse::from_decoder(
+                    &mut decoder,
+                )?)
+            }
+            Self::PriceTickerResponse(mut decoder) => OwnedAnyMessage::PriceTickerResponse(
+                PriceTickerResponse::from_decoder(&mut decoder)?,
+            ),
+            Self::BookTickerSymbolResponse(mut decoder) => {
+                OwnedAnyMessage::BookTickerSymbolResponse(BookTickerSymbolResponse::from_decoder(
+                    &mut decoder,
+                )?)
+            }
+            Self::BookTickerResponse(mut decoder) => {
+                OwnedAnyMessage::BookTickerResponse(BookTickerResponse::from_decoder(&mut decoder)?)
+            }
+            Self::TickerSymbolFullResponse(mut decoder) => {
+                OwnedAnyMessage::TickerSymbolFullResponse(TickerSymbolFullResponse::from_decoder(
+                    &mut decoder,
+                )?)
+            }
+            Self::TickerFullResponse(mut decoder) => {
+                OwnedAnyMessage::TickerFullResponse(TickerFullResponse::from_decoder(&mut decoder)?)
+            }
+            Self::TickerSymbolMiniResponse(mut decoder) => {
+                OwnedAnyMessage::TickerSymbolMiniResponse(TickerSymbolMiniResponse::from_decoder(
+                    &mut decoder,
+                )?)
+            }
+            Self::TickerMiniResponse(mut decoder) => {
+                OwnedAnyMessage::TickerMiniResponse(TickerMiniResponse::from_decoder(&mut decoder)?)
+            }
+            Self::NewOrderAckResponse(mut decoder) => OwnedAnyMessage::NewOrderAckResponse(
+                NewOrderAckResponse::from_decoder(&mut decoder)?,
+            ),
+            Self::NewOrderResultResponse(mut decoder) => OwnedAnyMessage::NewOrderResultResponse(
+                NewOrderResultResponse::from_decoder(&mut decoder)?,
+            ),
+            Self::NewOrderFullResponse(mut decoder) => OwnedAnyMessage::NewOrderFullResponse(
+                NewOrderFullResponse::from_decoder(&mut decoder)?,
+            ),
+            Self::OrderTestResponse(mut decoder) => {
+                OwnedAnyMessage::OrderTestResponse(OrderTestResponse::from_decoder(&mut decoder)?)
+            }
+            Self::OrderResponse(mut decoder) => {
+                OwnedAnyMessage::OrderResponse(OrderResponse::from_decoder(&mut decoder)?)
+            }
+            Self::CancelOrderResponse(mut decoder) => OwnedAnyMessage::CancelOrderResponse(
+                CancelOrderResponse::from_decoder(&mut decoder)?,
+            ),
+            Self::CancelOpenOrdersResponse(mut decoder) => {
+                OwnedAnyMessage::CancelOpenOrdersResponse(CancelOpenOrdersResponse::from_decoder(
+                    &mut decoder,
+                )?)
+            }
+            Self::CancelReplaceOrderResponse(mut decoder) => {
+                OwnedAnyMessage::CancelReplaceOrderResponse(
+                    CancelReplaceOrderResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::OrdersResponse(mut decoder) => {
+                OwnedAnyMessage::OrdersResponse(OrdersResponse::from_decoder(&mut decoder)?)
+            }
+            Self::NewOrderListAckResponse(mut decoder) => OwnedAnyMessage::NewOrderListAckResponse(
+                NewOrderListAckResponse::from_decoder(&mut decoder)?,
+            ),
+            Self::NewOrderListResultResponse(mut decoder) => {
+                OwnedAnyMessage::NewOrderListResultResponse(
+                    NewOrderListResultResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::NewOrderListFullResponse(mut decoder) => {
+                OwnedAnyMessage::NewOrderListFullResponse(NewOrderListFullResponse::from_decoder(
+                    &mut decoder,
+                )?)
+            }
+            Self::CancelOrderListResponse(mut decoder) => OwnedAnyMessage::CancelOrderListResponse(
+                CancelOrderListResponse::from_decoder(&mut decoder)?,
+            ),
+            Self::OrderListResponse(mut decoder) => {
+                OwnedAnyMessage::OrderListResponse(OrderListResponse::from_decoder(&mut decoder)?)
+            }
+            Self::OrderListsResponse(mut decoder) => {
+                OwnedAnyMessage::OrderListsResponse(OrderListsResponse::from_decoder(&mut decoder)?)
+            }
+            Self::OrderTestWithCommissionsResponse(mut decoder) => {
+                OwnedAnyMessage::OrderTestWithCommissionsResponse(
+                    OrderTestWithCommissionsResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::AccountResponse(mut decoder) => {
+                OwnedAnyMessage::AccountResponse(AccountResponse::from_decoder(&mut decoder)?)
+            }
+            Self::AccountTradesResponse(mut decoder) => OwnedAnyMessage::AccountTradesResponse(
+                AccountTradesResponse::from_decoder(&mut decoder)?,
+            ),
+            Self::AccountOrderRateLimitResponse(mut decoder) => {
+                OwnedAnyMessage::AccountOrderRateLimitResponse(
+                    AccountOrderRateLimitResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::AccountPreventedMatchesResponse(mut decoder) => {
+                OwnedAnyMessage::AccountPreventedMatchesResponse(
+                    AccountPreventedMatchesResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::AccountAllocationsResponse(mut decoder) => {
+                OwnedAnyMessage::AccountAllocationsResponse(
+                    AccountAllocationsResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::AccountCommissionResponse(mut decoder) => {
+                OwnedAnyMessage::AccountCommissionResponse(AccountCommissionResponse::from_decoder(
+                    &mut decoder,
+                )?)
+            }
+            Self::UserDataStreamStartResponse(mut decoder) => {
+                OwnedAnyMessage::UserDataStreamStartResponse(
+                    UserDataStreamStartResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::UserDataStreamPingResponse(mut decoder) => {
+                OwnedAnyMessage::UserDataStreamPingResponse(
+                    UserDataStreamPingResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::UserDataStreamStopResponse(mut decoder) => {
+                OwnedAnyMessage::UserDataStreamStopResponse(
+                    UserDataStreamStopResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::UserDataStreamSubscribeResponse(mut decoder) => {
+                OwnedAnyMessage::UserDataStreamSubscribeResponse(
+                    UserDataStreamSubscribeResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::UserDataStreamUnsubscribeResponse(mut decoder) => {
+                OwnedAnyMessage::UserDataStreamUnsubscribeResponse(
+                    UserDataStreamUnsubscribeResponse::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::BalanceUpdateEvent(mut decoder) => {
+                OwnedAnyMessage::BalanceUpdateEvent(BalanceUpdateEvent::from_decoder(&mut decoder)?)
+            }
+            Self::EventStreamTerminatedEvent(mut decoder) => {
+                OwnedAnyMessage::EventStreamTerminatedEvent(
+                    EventStreamTerminatedEvent::from_decoder(&mut decoder)?,
+                )
+            }
+            Self::ExecutionReportEvent(mut decoder) => OwnedAnyMessage::ExecutionReportEvent(
+                ExecutionReportEvent::from_decoder(&mut decoder)?,
+            ),
+            Self::ExternalLockUpdateEvent(mut decoder) => OwnedAnyMessage::ExternalLockUpdateEvent(
+                ExternalLockUpdateEvent::from_decoder(&mut decoder)?,
+            ),
+            Self::ListStatusEvent(mut decoder) => {
+                OwnedAnyMessage::ListStatusEvent(ListStatusEvent::from_decoder(&mut decoder)?)
+            }
+            Self::OutboundAccountPositionEvent(mut decoder) => {
+                OwnedAnyMessage::OutboundAccountPositionEvent(
+                    OutboundAccountPositionEvent::from_decoder(&mut decoder)?,
+                )
+            }
+        })
+    }
+}
+
+/// Owned struct of any message in the schema. Serializes as the struct
+/// itself, without the message name.
+#[cfg(feature = "serde")]
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(untagged)]
+pub enum OwnedAnyMessage {
+    PriceFilter(PriceFilter),
+    PercentPriceFilter(PercentPriceFilter),
+    PercentPriceBySideFilter(PercentPriceBySideFilter),
+    LotSizeFilter(LotSizeFilter),
+    MinNotionalFilter(MinNotionalFilter),
+    NotionalFilter(NotionalFilter),
+    IcebergPartsFilter(IcebergPartsFilter),
+    MarketLotSizeFilter(MarketLotSizeFilter),
+    MaxNumOrdersFilter(MaxNumOrdersFilter),
+    MaxNumAlgoOrdersFilter(MaxNumAlgoOrdersFilter),
+    MaxNumIcebergOrdersFilter(MaxNumIcebergOrdersFilter),
+    MaxPositionFilter(MaxPositionFilter),
+    TrailingDeltaFilter(TrailingDeltaFilter),
+    TPlusSellFilter(TPlusSellFilter),
+    ExchangeMaxNumOrdersFilter(ExchangeMaxNumOrdersFilter),
+    ExchangeMaxNumAlgoOrdersFilter(ExchangeMaxNumAlgoOrdersFilter),
+    ExchangeMaxNumIcebergOrdersFilter(ExchangeMaxNumIcebergOrdersFilter),
+    WebSocketResponse(WebSocketResponse),
+    WebSocketSessionLogonResponse(WebSocketSessionLogonResponse),
+    WebSocketSessionStatusResponse(WebSocketSessionStatusResponse),
+    WebSocketSessionLogoutResponse(WebSocketSessionLogoutResponse),
+    ErrorResponse(ErrorResponse),
+    PingResponse(PingResponse),
+    ServerTimeResponse(ServerTimeResponse),
+    ExchangeInfoResponse(ExchangeInfoResponse),
+    DepthResponse(DepthResponse),
+    TradesResponse(TradesResponse),
+    AggTradesResponse(AggTradesResponse),
+    KlinesResponse(KlinesResponse),
+    AveragePriceResponse(AveragePriceResponse),
+    Ticker24hSymbolFullResponse(Ticker24hSymbolFullResponse),
+    Ticker24hFullResponse(Ticker24hFullResponse),
+    Ticker24hSymbolMiniResponse(Ticker24hSymbolMiniResponse),
+    Ticker24hMiniResponse(Ticker24hMiniResponse),
+    PriceTickerSymbolResponse(PriceTickerSymbolResponse),
+    PriceTickerResponse(PriceTickerResponse),
+    BookTickerSymbolResponse(BookTickerSymbolResponse),
+    BookTickerResponse(BookTickerResponse),
+    TickerSymbolFullResponse(TickerSymbolFullResponse),
+    TickerFullResponse(TickerFullResponse),
+    TickerSymbolMiniResponse(TickerSymbolMiniResponse),
+    TickerMiniResponse(TickerMiniResponse),
+    NewOrderAckResponse(NewOrderAckResponse),
+    NewOrderResultResponse(NewOrderResultResponse),
+    NewOrderFullResponse(NewOrderFullResponse),
+    OrderTestResponse(OrderTestResponse),
+    OrderResponse(OrderResponse),
+    CancelOrderResponse(CancelOrderResponse),
+    CancelOpenOrdersResponse(CancelOpenOrdersResponse),
+    CancelReplaceOrderResponse(CancelReplaceOrderResponse),
+    OrdersResponse(OrdersResponse),
+    NewOrderListAckResponse(NewOrderListAckResponse),
+    NewOrderListResultResponse(NewOrderListResultResponse),
+    NewOrderListFullResponse(NewOrderListFullResponse),
+    CancelOrderListResponse(CancelOrderListResponse),
+    OrderListResponse(OrderListResponse),
+    OrderListsResponse(OrderListsResponse),
+    OrderTestWithCommissionsResponse(OrderTestWithCommissionsResponse),
+    AccountResponse(AccountResponse),
+    AccountTradesResponse(AccountTradesResponse),
+    AccountOrderRateLimitResponse(AccountOrderRateLimitResponse),
+    AccountPreventedMatchesResponse(AccountPreventedMatchesResponse),
+    AccountAllocationsResponse(AccountAllocationsResponse),
+    AccountCommissionResponse(AccountCommissionResponse),
+    UserDataStreamStartResponse(UserDataStreamStartResponse),
+    UserDataStreamPingResponse(UserDataStreamPingResponse),
+    UserDataStreamStopResponse(UserDataStreamStopResponse),
+    UserDataStreamSubscribeResponse(UserDataStreamSubscribeResponse),
+    UserDataStreamUnsubscribeResponse(UserDataStreamUnsubscribeResponse),
+    BalanceUpdateEvent(BalanceUpdateEvent),
+    EventStreamTerminatedEvent(EventStreamTerminatedEvent),
+    ExecutionReportEvent(ExecutionReportEvent),
+    ExternalLockUpdateEvent(ExternalLockUpdateEvent),
+    ListStatusEvent(ListStatusEvent),
+    OutboundAccountPositionEvent(OutboundAccountPositionEvent),
+}
+
+#[cfg(feature = "serde")]
+impl OwnedAnyMessage {
+    pub fn meta(&self) -> &'static MessageMeta {
+        match self {
+            Self::PriceFilter(_) => &PRICE_FILTER,
+            Self::PercentPriceFilter(_) => &PERCENT_PRICE_FILTER,
+            Self::PercentPriceBySideFilter(_) => &PERCENT_PRICE_BY_SIDE_FILTER,
+            Self::LotSizeFilter(_) => &LOT_SIZE_FILTER,
+            Self::MinNotionalFilter(_) => &MIN_NOTIONAL_FILTER,
+            Self::NotionalFilter(_) => &NOTIONAL_FILTER,
+            Self::IcebergPartsFilter(_) => &ICEBERG_PARTS_FILTER,
+            Self::MarketLotSizeFilter(_) => &MARKET_LOT_SIZE_FILTER,
+            Self::MaxNumOrdersFilter(_) => &MAX_NUM_ORDERS_FILTER,
+            Self::MaxNumAlgoOrdersFilter(_) => &MAX_NUM_ALGO_ORDERS_FILTER,
+            Self::MaxNumIcebergOrdersFilter(_) => &MAX_NUM_ICEBERG_ORDERS_FILTER,
+            Self::MaxPositionFilter(_) => &MAX_POSITION_FILTER,
+            Self::TrailingDeltaFilter(_) => &TRAILING_DELTA_FILTER,
+            Self::TPlusSellFilter(_) => &TPLUS_SELL_FILTER,
+            Self::ExchangeMaxNumOrdersFilter(_) => &EXCHANGE_MAX_NUM_ORDERS_FILTER,
+            Self::ExchangeMaxNumAlgoOrdersFilter(_) => &EXCHANGE_MAX_NUM_ALGO_ORDERS_FILTER,
+            Self::ExchangeMaxNumIcebergOrdersFilter(_) => &EXCHANGE_MAX_NUM_ICEBERG_ORDERS_FILTER,
+            Self::WebSocketResponse(_) => &WEB_SOCKET_RESPONSE,
+            Self::WebSocketSessionLogonResponse(_) => &WEB_SOCKET_SESSION_LOGON_RESPONSE,
+            Self::WebSocketSessionStatusResponse(_) => &WEB_SOCKET_SESSION_STATUS_RESPONSE,
+            Self::WebSocketSessionLogoutResponse(_) => &WEB_SOCKET_SESSION_LOGOUT_RESPONSE,
+            Self::ErrorResponse(_) => &ERROR_RESPONSE,
+            Self::PingResponse(_) => &PING_RESPONSE,
+            Self::ServerTimeResponse(_) => &SERVER_TIME_RESPONSE,
+            Self::ExchangeInfoResponse(_) => &EXCHANGE_INFO_RESPONSE,
+            Self::DepthResponse(_) => &DEPTH_RESPONSE,
+            Self::TradesResponse(_) => &TRADES_RESPONSE,
+            Self::AggTradesResponse(_) => &AGG_TRADES_RESPONSE,
+            Self::KlinesResponse(_) => &KLINES_RESPONSE,
+            Self::AveragePriceResponse(_) => &AVERAGE_PRICE_RESPONSE,
+            Self::Ticker24hSymbolFullResponse(_) => &TICKER24H_SYMBOL_FULL_RESPONSE,
+            Self::Ticker24hFullResponse(_) => &TICKER24H_FULL_RESPONSE,
+            Self::Ticker24hSymbolMiniResponse(_) => &TICKER24H_SYMBOL_MINI_RESPONSE,
+            Self::Ticker24hMiniResponse(_) => &TICKER24H_MINI_RESPONSE,
+            Self::PriceTickerSymbolResponse(_) => &PRICE_TICKER_SYMBOL_RESPONSE,
+            Self::PriceTickerResponse(_) => &PRICE_TICKER_RESPONSE,
+            Self::BookTickerSymbolResponse(_) => &BOOK_TICKER_SYMBOL_RESPONSE,
+            Self::BookTickerResponse(_) => &BOOK_TICKER_RESPONSE,
+            Self::TickerSymbolFullResponse(_) => &TICKER_SYMBOL_FULL_RESPONSE,
+            Self::TickerFullResponse(_) => &TICKER_FULL_RESPONSE,
+            Self::TickerSymbolMiniResponse(_) => &TICKER_SYMBOL_MINI_RESPONSE,
+            Self::TickerMiniResponse(_) => &TICKER_MINI_RESPONSE,
+            Self::NewOrderAckResponse(_) => &NEW_ORDER_ACK_RESPONSE,
+            Self::NewOrderResultResponse(_) => &NEW_ORDER_RESULT_RESPONSE,
+            Self::NewOrderFullResponse(_) => &NEW_ORDER_FULL_RESPONSE,
+            Self::OrderTestResponse(_) => &ORDER_TEST_RESPONSE,
+            Self::OrderResponse(_) => &ORDER_RESPONSE,
+            Self::CancelOrderResponse(_) => &CANCEL_ORDER_RESPONSE,
+            Self::CancelOpenOrdersResponse(_) => &CANCEL_OPEN_ORDERS_RESPONSE,
+            Self::CancelReplaceOrderResponse(_) => &CANCEL_REPLACE_ORDER_RESPONSE,
+            Self::OrdersResponse(_) => &ORDERS_RESPONSE,
+            Self::NewOrderListAckResponse(_) => &NEW_ORDER_LIST_ACK_RESPONSE,
+            Self::NewOrderListResultResponse(_) => &NEW_ORDER_LIST_RESULT_RESPONSE,
+            Self::NewOrderListFullResponse(_) => &NEW_ORDER_LIST_FULL_RESPONSE,
+            Self::CancelOrderListResponse(_) => &CANCEL_ORDER_LIST_RESPONSE,
+            Self::OrderListResponse(_) => &ORDER_LIST_RESPONSE,
+            Self::OrderListsResponse(_) => &ORDER_LISTS_RESPONSE,
+            Self::OrderTestWithCommissionsResponse(_) => &ORDER_TEST_WITH_COMMISSIONS_RESPONSE,
+            Self::AccountResponse(_) => &ACCOUNT_RESPONSE,
+            Self::AccountTradesResponse(_) => &ACCOUNT_TRADES_RESPONSE,
+            Self::AccountOrderRateLimitResponse(_) => &ACCOUNT_ORDER_RATE_LIMIT_RESPONSE,
+            Self::AccountPreventedMatchesResponse(_) => &ACCOUNT_PREVENTED_MATCHES_RESPONSE,
+            Self::AccountAllocationsResponse(_) => &ACCOUNT_ALLOCATIONS_RESPONSE,
+            Self::AccountCommissionResponse(_) => &ACCOUNT_COMMISSION_RESPONSE,
+            Self::UserDataStreamStartResponse(_) => &USER_DATA_STREAM_START_RESPONSE,
+            Self::UserDataStreamPingResponse(_) => &USER_DATA_STREAM_PING_RESPONSE,
+            Self::UserDataStreamStopResponse(_) => &USER_DATA_STREAM_STOP_RESPONSE,
+            Self::UserDataStreamSubscribeResponse(_) => &USER_DATA_STREAM_SUBSCRIBE_RESPONSE,
+            Self::UserDataStreamUnsubscribeResponse(_) => &USER_DATA_STREAM_UNSUBSCRIBE_RESPONSE,
+            Self::BalanceUpdateEvent(_) => &BALANCE_UPDATE_EVENT,
+            Self::EventStreamTerminatedEvent(_) => &EVENT_STREAM_TERMINATED_EVENT,
+            Self::ExecutionReportEvent(_) => &EXECUTION_REPORT_EVENT,
+            Self::ExternalLockUpdateEvent(_) => &EXTERNAL_LOCK_UPDATE_EVENT,
+            Self::ListStatusEvent(_) => &LIST_STATUS_EVENT,
+            Self::OutboundAccountPositionEvent(_) => &OUTBOUND_ACCOUNT_POSITION_EVENT,
+        }
+    }
+
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.meta().name
+    }
+}
+
+/// Decodes the header at the start of `data` and wraps the matching decoder.
+pub fn decode_any(data: &[u8]) -> SbeResult<AnyMessage<'_>> {
+    let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+    AnyMessage::from_header(header)
+}
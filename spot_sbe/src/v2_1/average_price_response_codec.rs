@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::AveragePriceResponseDecoder;
 pub use encoder::AveragePriceResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::AveragePriceResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 25;
 pub const SBE_TEMPLATE_ID: u16 = 204;
@@ -105,6 +107,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'price' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset + 8;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'closeTime'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -151,6 +171,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> AveragePriceResponseDecoder<'a> {
@@ -160,7 +185,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -168,7 +194,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -176,13 +202,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -212,6 +244,13 @@ pub mod decoder {
             }
         }
 
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> Option<Decimal> {
+            self.price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn close_time(&self) -> Option<i64> {
@@ -224,3 +263,65 @@ pub mod decoder {
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct AveragePriceResponse {
+        pub mins: i64,
+        pub price_exponent: i8,
+        pub price: Option<Decimal>,
+        pub close_time: Option<i64>,
+    }
+
+    impl AveragePriceResponse {
+        pub fn from_decoder(decoder: &mut AveragePriceResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                mins: decoder.mins(),
+                price_exponent: decoder.price_exponent(),
+                price: decoder.price_decimal(),
+                close_time: decoder.close_time(),
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut AveragePriceResponseEncoder<'_>) -> SbeResult<()> {
+            encoder.mins(self.mins);
+            encoder.price_exponent(fit_exponent(self.price_exponent, self.price));
+            encoder.price_decimal(self.price)?;
+            encoder.close_time(self.close_time.unwrap_or(i64::MIN));
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            25
+        }
+    }
+
+    impl OwnedMessage for AveragePriceResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = AveragePriceResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = AveragePriceResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::BalanceUpdateEventDecoder;
 pub use encoder::BalanceUpdateEventEncoder;
+#[cfg(feature = "serde")]
+pub use owned::BalanceUpdateEvent;
 
 pub const SBE_BLOCK_LENGTH: u16 = 25;
 pub const SBE_TEMPLATE_ID: u16 = 601;
@@ -119,6 +121,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'freeQtyDelta' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn free_qty_delta_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 16;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.free_qty_delta(mantissa);
+            Ok(())
+        }
+
         /// VAR_DATA ENCODER - character encoding: 'UTF-8'
         #[inline]
         pub fn asset(&mut self, value: &str) {
@@ -161,6 +177,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> BalanceUpdateEventDecoder<'a> {
@@ -170,7 +191,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -178,7 +200,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -186,13 +208,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -228,19 +256,100 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 17)
         }
 
+        /// 'freeQtyDelta' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn free_qty_delta_decimal(&self) -> Decimal {
+            Decimal::new(self.free_qty_delta(), self.qty_exponent())
+        }
+
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn asset_decoder(&mut self) -> (usize, usize) {
+        pub fn asset_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn asset_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn asset_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct BalanceUpdateEvent {
+        pub event_time: i64,
+        pub clear_time: Option<i64>,
+        pub qty_exponent: i8,
+        pub free_qty_delta: Decimal,
+        pub asset: String,
+    }
+
+    impl BalanceUpdateEvent {
+        pub fn from_decoder(decoder: &mut BalanceUpdateEventDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                event_time: decoder.event_time(),
+                clear_time: decoder.clear_time(),
+                qty_exponent: decoder.qty_exponent(),
+                free_qty_delta: decoder.free_qty_delta_decimal(),
+                asset: {
+                    let coordinates = decoder.asset_decoder()?;
+                    let slice = decoder.asset_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut BalanceUpdateEventEncoder<'_>) -> SbeResult<()> {
+            encoder.event_time(self.event_time);
+            encoder.clear_time(self.clear_time.unwrap_or(i64::MIN));
+            encoder.qty_exponent(fit_exponent(self.qty_exponent, [self.free_qty_delta]));
+            encoder.free_qty_delta_decimal(self.free_qty_delta)?;
+            let value = &self.asset;
+            checked_length::<u8>(value.len())?;
+            encoder.asset(value);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            26 + self.asset.len()
+        }
+    }
+
+    impl OwnedMessage for BalanceUpdateEvent {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = BalanceUpdateEventDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = BalanceUpdateEventEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::BookTickerResponseDecoder;
 pub use encoder::BookTickerResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::BookTickerResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 0;
 pub const SBE_TEMPLATE_ID: u16 = 212;
@@ -204,6 +206,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'bidPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn bid_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.bid_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.bid_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'bidQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -218,6 +238,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'bidQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn bid_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.bid_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'askPrice'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -232,6 +266,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'askPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn ask_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.ask_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.ask_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'askQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -246,6 +298,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'askQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn ask_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.ask_qty(mantissa);
+            Ok(())
+        }
+
         /// VAR_DATA ENCODER - character encoding: 'UTF-8'
         #[inline]
         pub fn symbol(&mut self, value: &str) {
@@ -288,6 +354,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> BookTickerResponseDecoder<'a> {
@@ -297,7 +368,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -305,7 +377,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -313,13 +385,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -328,69 +406,96 @@ pub mod decoder {
 
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn tickers_decoder(self) -> TickersDecoder<Self> {
+        pub fn tickers_decoder(self) -> SbeResult<TickersDecoder<'a, Self>> {
             TickersDecoder::default().wrap(self)
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct TickersDecoder<P> {
+    pub struct TickersDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for TickersDecoder<P>
+    impl<'a, P> Reader<'a> for TickersDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for TickersDecoder<P>
+    impl<'a, P> Decoder<'a> for TickersDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> TickersDecoder<P>
+    impl<'a, P> TickersDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 34;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='tickers', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=34, offset=0, componentTokenCount=30, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -404,14 +509,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(34))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// primitive field - 'REQUIRED'
@@ -437,12 +543,25 @@ pub mod decoder {
             }
         }
 
+        /// 'bidPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn bid_price_decimal(&self) -> Option<Decimal> {
+            self.bid_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn bid_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 10)
         }
 
+        /// 'bidQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn bid_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.bid_qty(), self.qty_exponent())
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn ask_price(&self) -> Option<i64> {
@@ -454,28 +573,178 @@ pub mod decoder {
             }
         }
 
+        /// 'askPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn ask_price_decimal(&self) -> Option<Decimal> {
+            self.ask_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn ask_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 26)
         }
 
+        /// 'askQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn ask_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.ask_qty(), self.qty_exponent())
+        }
+
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct BookTickerResponse {
+        pub tickers: Vec<Tickers>,
+    }
+
+    impl BookTickerResponse {
+        pub fn from_decoder(decoder: &mut BookTickerResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                tickers: {
+                    let mut tickers_decoder = core::mem::take(decoder).tickers_decoder()?;
+                    let mut entries = Vec::new();
+                    while tickers_decoder.advance()?.is_some() {
+                        entries.push(Tickers::from_decoder(&mut tickers_decoder)?);
+                    }
+                    *decoder = tickers_decoder.parent()?;
+                    entries
+                },
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut BookTickerResponseEncoder<'_>) -> SbeResult<()> {
+            let count = checked_length::<u32>(self.tickers.len())?;
+            let mut tickers_encoder =
+                core::mem::take(encoder).tickers_encoder(count, TickersEncoder::default());
+            for entry in &self.tickers {
+                tickers_encoder.advance()?;
+                entry.to_encoder(&mut tickers_encoder)?;
+            }
+            *encoder = tickers_encoder.parent()?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            6 + self
+                .tickers
+                .iter()
+                .map(Tickers::encoded_length)
+                .sum::<usize>()
+        }
+    }
+
+    impl OwnedMessage for BookTickerResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = BookTickerResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = BookTickerResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Tickers {
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub bid_price: Option<Decimal>,
+        pub bid_qty: Decimal,
+        pub ask_price: Option<Decimal>,
+        pub ask_qty: Decimal,
+        pub symbol: String,
+    }
+
+    impl Tickers {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut TickersDecoder<'a, BookTickerResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                bid_price: decoder.bid_price_decimal(),
+                bid_qty: decoder.bid_qty_decimal(),
+                ask_price: decoder.ask_price_decimal(),
+                ask_qty: decoder.ask_qty_decimal(),
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut TickersEncoder<BookTickerResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                self.bid_price.into_iter().chain(self.ask_price),
+            ));
+            encoder.qty_exponent(fit_exponent(
+                self.qty_exponent,
+                [self.bid_qty, self.ask_qty],
+            ));
+            encoder.bid_price_decimal(self.bid_price)?;
+            encoder.bid_qty_decimal(self.bid_qty)?;
+            encoder.ask_price_decimal(self.ask_price)?;
+            encoder.ask_qty_decimal(self.ask_qty)?;
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            35 + self.symbol.len()
+        }
+    }
+} // end owned
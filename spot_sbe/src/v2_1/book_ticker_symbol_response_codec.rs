@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::BookTickerSymbolResponseDecoder;
 pub use encoder::BookTickerSymbolResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::BookTickerSymbolResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 34;
 pub const SBE_TEMPLATE_ID: u16 = 211;
@@ -105,6 +107,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'bidPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn bid_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.bid_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.bid_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'bidQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -119,6 +139,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'bidQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn bid_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.bid_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'askPrice'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -133,6 +167,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'askPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn ask_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.ask_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.ask_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'askQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -147,6 +199,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'askQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn ask_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.ask_qty(mantissa);
+            Ok(())
+        }
+
         /// VAR_DATA ENCODER - character encoding: 'UTF-8'
         #[inline]
         pub fn symbol(&mut self, value: &str) {
@@ -189,6 +255,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> BookTickerSymbolResponseDecoder<'a> {
@@ -198,7 +269,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -206,7 +278,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -214,13 +286,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -250,12 +328,25 @@ pub mod decoder {
             }
         }
 
+        /// 'bidPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn bid_price_decimal(&self) -> Option<Decimal> {
+            self.bid_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn bid_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 10)
         }
 
+        /// 'bidQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn bid_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.bid_qty(), self.qty_exponent())
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn ask_price(&self) -> Option<i64> {
@@ -267,25 +358,128 @@ pub mod decoder {
             }
         }
 
+        /// 'askPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn ask_price_decimal(&self) -> Option<Decimal> {
+            self.ask_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn ask_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 26)
         }
 
+        /// 'askQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn ask_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.ask_qty(), self.qty_exponent())
+        }
+
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct BookTickerSymbolResponse {
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub bid_price: Option<Decimal>,
+        pub bid_qty: Decimal,
+        pub ask_price: Option<Decimal>,
+        pub ask_qty: Decimal,
+        pub symbol: String,
+    }
+
+    impl BookTickerSymbolResponse {
+        pub fn from_decoder(decoder: &mut BookTickerSymbolResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                bid_price: decoder.bid_price_decimal(),
+                bid_qty: decoder.bid_qty_decimal(),
+                ask_price: decoder.ask_price_decimal(),
+                ask_qty: decoder.ask_qty_decimal(),
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        pub fn to_encoder(
+            &self,
+            encoder: &mut BookTickerSymbolResponseEncoder<'_>,
+        ) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                self.bid_price.into_iter().chain(self.ask_price),
+            ));
+            encoder.qty_exponent(fit_exponent(
+                self.qty_exponent,
+                [self.bid_qty, self.ask_qty],
+            ));
+            encoder.bid_price_decimal(self.bid_price)?;
+            encoder.bid_qty_decimal(self.bid_qty)?;
+            encoder.ask_price_decimal(self.ask_price)?;
+            encoder.ask_qty_decimal(self.ask_qty)?;
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            35 + self.symbol.len()
+        }
+    }
+
+    impl OwnedMessage for BookTickerSymbolResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = BookTickerSymbolResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = BookTickerSymbolResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
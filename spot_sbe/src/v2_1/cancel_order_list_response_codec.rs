@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::CancelOrderListResponseDecoder;
 pub use encoder::CancelOrderListResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::CancelOrderListResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 21;
 pub const SBE_TEMPLATE_ID: u16 = 312;
@@ -688,6 +690,132 @@ pub mod encoder {
             self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
         }
     }
+
+    impl OrderReportsEncoder<CancelOrderListResponseEncoder<'_>> {
+        /// 'price' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 19;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
+        /// 'origQty' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn orig_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 20;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.orig_qty(mantissa);
+            Ok(())
+        }
+
+        /// 'executedQty' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn executed_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 20;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.executed_qty(mantissa);
+            Ok(())
+        }
+
+        /// 'cummulativeQuoteQty' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn cummulative_quote_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 19;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.cummulative_quote_qty(mantissa);
+            Ok(())
+        }
+
+        /// 'stopPrice' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn stop_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.stop_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 19;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.stop_price(mantissa);
+            Ok(())
+        }
+
+        /// 'icebergQty' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn iceberg_qty_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.iceberg_qty(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 20;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.iceberg_qty(mantissa);
+            Ok(())
+        }
+
+        /// 'preventedQuantity' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn prevented_quantity_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.prevented_quantity(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 20;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.prevented_quantity(mantissa);
+            Ok(())
+        }
+
+        /// 'origQuoteOrderQty' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn orig_quote_order_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 19;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.orig_quote_order_qty(mantissa);
+            Ok(())
+        }
+    }
 } // end encoder
 
 pub mod decoder {
@@ -720,6 +848,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> CancelOrderListResponseDecoder<'a> {
@@ -729,7 +862,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -737,7 +871,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -745,13 +879,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -802,105 +942,139 @@ pub mod decoder {
 
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn orders_decoder(self) -> OrdersDecoder<Self> {
+        pub fn orders_decoder(self) -> SbeResult<OrdersDecoder<'a, Self>> {
             OrdersDecoder::default().wrap(self)
         }
 
         /// GROUP DECODER (id=101)
         #[inline]
-        pub fn order_reports_decoder(self) -> OrderReportsDecoder<Self> {
+        pub fn order_reports_decoder(self) -> SbeResult<OrderReportsDecoder<'a, Self>> {
             OrderReportsDecoder::default().wrap(self)
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn list_client_order_id_decoder(&mut self) -> (usize, usize) {
+        pub fn list_client_order_id_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn list_client_order_id_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn list_client_order_id_slice(
+            &'a self,
+            coordinates: (usize, usize),
+        ) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct OrdersDecoder<P> {
+    pub struct OrdersDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u16,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for OrdersDecoder<P>
+    impl<'a, P> Reader<'a> for OrdersDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for OrdersDecoder<P>
+    impl<'a, P> Decoder<'a> for OrdersDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> OrdersDecoder<P>
+    impl<'a, P> OrdersDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 4)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u16_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 4);
+            let available = parent.get_buf().len() - (initial_offset + 4);
+            let expected = 8;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if (count as usize).saturating_mul(block_length + 2) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 4;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='orders', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=8, offset=21, componentTokenCount=21, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -914,14 +1088,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(8))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// primitive field - 'REQUIRED'
@@ -932,99 +1107,134 @@ pub mod decoder {
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn client_order_id_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn client_order_id_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn client_order_id_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn client_order_id_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct OrderReportsDecoder<P> {
+    pub struct OrderReportsDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u16,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for OrderReportsDecoder<P>
+    impl<'a, P> Reader<'a> for OrderReportsDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for OrderReportsDecoder<P>
+    impl<'a, P> Decoder<'a> for OrderReportsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> OrderReportsDecoder<P>
+    impl<'a, P> OrderReportsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 4)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u16_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 4);
+            let available = parent.get_buf().len() - (initial_offset + 4);
+            let expected = if parent.acting_version() >= 1 {
+                124
+            } else {
+                116
+            };
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if (count as usize).saturating_mul(block_length + 3) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 4;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='orderReports', referencedName='null', description='null', packageName='null', id=101, version=0, deprecated=0, encodedLength=124, offset=-1, componentTokenCount=134, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -1038,14 +1248,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(124))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// primitive field - 'REQUIRED'
@@ -1223,65 +1434,533 @@ pub mod decoder {
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn orig_quote_order_qty(&self) -> i64 {
-            // if self.acting_version > 0 && self.acting_version < 1 {
-            //     return -9223372036854775808_i64;
-            // }
+            if self.acting_version() < 1 {
+                return -9223372036854775808_i64;
+            }
 
             self.get_buf().get_i64_at(self.offset + 116)
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn orig_client_order_id_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn orig_client_order_id_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn orig_client_order_id_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn orig_client_order_id_slice(
+            &'a self,
+            coordinates: (usize, usize),
+        ) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn client_order_id_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn client_order_id_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn client_order_id_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn client_order_id_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
+        }
+    }
+
+    impl OrderReportsDecoder<'_, CancelOrderListResponseDecoder<'_>> {
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.price(), exponent))
+        }
+
+        /// 'origQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn orig_qty_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(Decimal::new(self.orig_qty(), exponent))
+        }
+
+        /// 'executedQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn executed_qty_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(Decimal::new(self.executed_qty(), exponent))
+        }
+
+        /// 'cummulativeQuoteQty' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn cummulative_quote_qty_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.cummulative_quote_qty(), exponent))
+        }
+
+        /// 'stopPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn stop_price_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(self
+                .stop_price()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+
+        /// 'icebergQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn iceberg_qty_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(self
+                .iceberg_qty()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+
+        /// 'preventedQuantity' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn prevented_quantity_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(self
+                .prevented_quantity()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+
+        /// 'origQuoteOrderQty' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn orig_quote_order_qty_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.orig_quote_order_qty(), exponent))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct CancelOrderListResponse {
+        pub order_list_id: i64,
+        pub contingency_type: ContingencyType,
+        pub list_status_type: ListStatusType,
+        pub list_order_status: ListOrderStatus,
+        pub transaction_time: i64,
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub orders: Vec<Orders>,
+        pub order_reports: Vec<OrderReports>,
+        pub list_client_order_id: String,
+        pub symbol: String,
+    }
+
+    impl CancelOrderListResponse {
+        pub fn from_decoder(decoder: &mut CancelOrderListResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                order_list_id: decoder.order_list_id(),
+                contingency_type: decoder.contingency_type(),
+                list_status_type: decoder.list_status_type(),
+                list_order_status: decoder.list_order_status(),
+                transaction_time: decoder.transaction_time(),
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                orders: {
+                    let mut orders_decoder = core::mem::take(decoder).orders_decoder()?;
+                    let mut entries = Vec::new();
+                    while orders_decoder.advance()?.is_some() {
+                        entries.push(Orders::from_decoder(&mut orders_decoder)?);
+                    }
+                    *decoder = orders_decoder.parent()?;
+                    entries
+                },
+                order_reports: {
+                    let mut order_reports_decoder =
+                        core::mem::take(decoder).order_reports_decoder()?;
+                    let mut entries = Vec::new();
+                    while order_reports_decoder.advance()?.is_some() {
+                        entries.push(OrderReports::from_decoder(&mut order_reports_decoder)?);
+                    }
+                    *decoder = order_reports_decoder.parent()?;
+                    entries
+                },
+                list_client_order_id: {
+                    let coordinates = decoder.list_client_order_id_decoder()?;
+                    let slice = decoder.list_client_order_id_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        pub fn to_encoder(
+            &self,
+            encoder: &mut CancelOrderListResponseEncoder<'_>,
+        ) -> SbeResult<()> {
+            encoder.order_list_id(self.order_list_id);
+            encoder.contingency_type(self.contingency_type);
+            encoder.list_status_type(self.list_status_type);
+            encoder.list_order_status(self.list_order_status);
+            encoder.transaction_time(self.transaction_time);
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                self.order_reports
+                    .iter()
+                    .map(|entry| entry.price)
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .map(|entry| entry.cummulative_quote_qty),
+                    )
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .filter_map(|entry| entry.stop_price),
+                    )
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .map(|entry| entry.orig_quote_order_qty),
+                    ),
+            ));
+            encoder.qty_exponent(fit_exponent(
+                self.qty_exponent,
+                self.order_reports
+                    .iter()
+                    .map(|entry| entry.orig_qty)
+                    .chain(self.order_reports.iter().map(|entry| entry.executed_qty))
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .filter_map(|entry| entry.iceberg_qty),
+                    )
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .filter_map(|entry| entry.prevented_quantity),
+                    ),
+            ));
+            let count = checked_length::<u16>(self.orders.len())?;
+            let mut orders_encoder =
+                core::mem::take(encoder).orders_encoder(count, OrdersEncoder::default());
+            for entry in &self.orders {
+                orders_encoder.advance()?;
+                entry.to_encoder(&mut orders_encoder)?;
+            }
+            *encoder = orders_encoder.parent()?;
+            let count = checked_length::<u16>(self.order_reports.len())?;
+            let mut order_reports_encoder = core::mem::take(encoder)
+                .order_reports_encoder(count, OrderReportsEncoder::default());
+            for entry in &self.order_reports {
+                order_reports_encoder.advance()?;
+                entry.to_encoder(&mut order_reports_encoder)?;
+            }
+            *encoder = order_reports_encoder.parent()?;
+            let value = &self.list_client_order_id;
+            checked_length::<u8>(value.len())?;
+            encoder.list_client_order_id(value);
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            31 + self
+                .orders
+                .iter()
+                .map(Orders::encoded_length)
+                .sum::<usize>()
+                + self
+                    .order_reports
+                    .iter()
+                    .map(OrderReports::encoded_length)
+                    .sum::<usize>()
+                + self.list_client_order_id.len()
+                + self.symbol.len()
+        }
+    }
+
+    impl OwnedMessage for CancelOrderListResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = CancelOrderListResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = CancelOrderListResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Orders {
+        pub order_id: i64,
+        pub symbol: String,
+        pub client_order_id: String,
+    }
+
+    impl Orders {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut OrdersDecoder<'a, CancelOrderListResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                order_id: decoder.order_id(),
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                client_order_id: {
+                    let coordinates = decoder.client_order_id_decoder()?;
+                    let slice = decoder.client_order_id_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut OrdersEncoder<CancelOrderListResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.order_id(self.order_id);
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            let value = &self.client_order_id;
+            checked_length::<u8>(value.len())?;
+            encoder.client_order_id(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            10 + self.symbol.len() + self.client_order_id.len()
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct OrderReports {
+        pub order_id: i64,
+        pub order_list_id: Option<i64>,
+        pub transact_time: i64,
+        pub price: Decimal,
+        pub orig_qty: Decimal,
+        pub executed_qty: Decimal,
+        pub cummulative_quote_qty: Decimal,
+        pub status: OrderStatus,
+        pub time_in_force: TimeInForce,
+        pub order_type: OrderType,
+        pub side: OrderSide,
+        pub stop_price: Option<Decimal>,
+        pub trailing_delta: Option<i64>,
+        pub trailing_time: Option<i64>,
+        pub iceberg_qty: Option<Decimal>,
+        pub strategy_id: Option<i64>,
+        pub strategy_type: Option<i32>,
+        pub order_capacity: OrderCapacity,
+        pub working_floor: Floor,
+        pub self_trade_prevention_mode: SelfTradePreventionMode,
+        pub prevented_quantity: Option<Decimal>,
+        pub used_sor: BoolEnum,
+        pub orig_quote_order_qty: Decimal,
+        pub symbol: String,
+        pub orig_client_order_id: String,
+        pub client_order_id: String,
+    }
+
+    impl OrderReports {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut OrderReportsDecoder<'a, CancelOrderListResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                order_id: decoder.order_id(),
+                order_list_id: decoder.order_list_id(),
+                transact_time: decoder.transact_time(),
+                price: decoder.price_decimal()?,
+                orig_qty: decoder.orig_qty_decimal()?,
+                executed_qty: decoder.executed_qty_decimal()?,
+                cummulative_quote_qty: decoder.cummulative_quote_qty_decimal()?,
+                status: decoder.status(),
+                time_in_force: decoder.time_in_force(),
+                order_type: decoder.order_type(),
+                side: decoder.side(),
+                stop_price: decoder.stop_price_decimal()?,
+                trailing_delta: decoder.trailing_delta(),
+                trailing_time: decoder.trailing_time(),
+                iceberg_qty: decoder.iceberg_qty_decimal()?,
+                strategy_id: decoder.strategy_id(),
+                strategy_type: decoder.strategy_type(),
+                order_capacity: decoder.order_capacity(),
+                working_floor: decoder.working_floor(),
+                self_trade_prevention_mode: decoder.self_trade_prevention_mode(),
+                prevented_quantity: decoder.prevented_quantity_decimal()?,
+                used_sor: decoder.used_sor(),
+                orig_quote_order_qty: decoder.orig_quote_order_qty_decimal()?,
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                orig_client_order_id: {
+                    let coordinates = decoder.orig_client_order_id_decoder()?;
+                    let slice = decoder.orig_client_order_id_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                client_order_id: {
+                    let coordinates = decoder.client_order_id_decoder()?;
+                    let slice = decoder.client_order_id_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut OrderReportsEncoder<CancelOrderListResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.order_id(self.order_id);
+            encoder.order_list_id(self.order_list_id.unwrap_or(i64::MIN));
+            encoder.transact_time(self.transact_time);
+            encoder.price_decimal(self.price)?;
+            encoder.orig_qty_decimal(self.orig_qty)?;
+            encoder.executed_qty_decimal(self.executed_qty)?;
+            encoder.cummulative_quote_qty_decimal(self.cummulative_quote_qty)?;
+            encoder.status(self.status);
+            encoder.time_in_force(self.time_in_force);
+            encoder.order_type(self.order_type);
+            encoder.side(self.side);
+            encoder.stop_price_decimal(self.stop_price)?;
+            encoder.trailing_delta(self.trailing_delta.unwrap_or(i64::MIN));
+            encoder.trailing_time(self.trailing_time.unwrap_or(i64::MIN));
+            encoder.iceberg_qty_decimal(self.iceberg_qty)?;
+            encoder.strategy_id(self.strategy_id.unwrap_or(i64::MIN));
+            encoder.strategy_type(self.strategy_type.unwrap_or(i32::MIN));
+            encoder.order_capacity(self.order_capacity);
+            encoder.working_floor(self.working_floor);
+            encoder.self_trade_prevention_mode(self.self_trade_prevention_mode);
+            encoder.prevented_quantity_decimal(self.prevented_quantity)?;
+            encoder.used_sor(self.used_sor);
+            encoder.orig_quote_order_qty_decimal(self.orig_quote_order_qty)?;
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            let value = &self.orig_client_order_id;
+            checked_length::<u8>(value.len())?;
+            encoder.orig_client_order_id(value);
+            let value = &self.client_order_id;
+            checked_length::<u8>(value.len())?;
+            encoder.client_order_id(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            127 + self.symbol.len() + self.orig_client_order_id.len() + self.client_order_id.len()
+        }
+    }
+} // end owned
@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::CancelOrderResponseDecoder;
 pub use encoder::CancelOrderResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::CancelOrderResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 126;
 pub const SBE_TEMPLATE_ID: u16 = 305;
@@ -147,6 +149,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'price' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'origQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -161,6 +177,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'origQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn orig_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.orig_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'executedQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -175,6 +205,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'executedQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn executed_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.executed_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'cummulativeQuoteQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -189,6 +233,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'cummulativeQuoteQty' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn cummulative_quote_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.cummulative_quote_qty(mantissa);
+            Ok(())
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn status(&mut self, value: OrderStatus) {
@@ -231,6 +289,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'stopPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn stop_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.stop_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.stop_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'trailingDelta'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -273,6 +349,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'icebergQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn iceberg_qty_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.iceberg_qty(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.iceberg_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'strategyId'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -336,6 +430,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'preventedQuantity' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn prevented_quantity_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.prevented_quantity(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.prevented_quantity(mantissa);
+            Ok(())
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn used_sor(&mut self, value: BoolEnum) {
@@ -357,6 +469,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'origQuoteOrderQty' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn orig_quote_order_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.orig_quote_order_qty(mantissa);
+            Ok(())
+        }
+
         /// VAR_DATA ENCODER - character encoding: 'UTF-8'
         #[inline]
         pub fn symbol(&mut self, value: &str) {
@@ -419,6 +545,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> CancelOrderResponseDecoder<'a> {
@@ -428,7 +559,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -436,7 +568,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -444,13 +576,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -498,24 +636,48 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 26)
         }
 
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> Decimal {
+            Decimal::new(self.price(), self.price_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn orig_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 34)
         }
 
+        /// 'origQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn orig_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.orig_qty(), self.qty_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn executed_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 42)
         }
 
+        /// 'executedQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn executed_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.executed_qty(), self.qty_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn cummulative_quote_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 50)
         }
 
+        /// 'cummulativeQuoteQty' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn cummulative_quote_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.cummulative_quote_qty(), self.price_exponent())
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn status(&self) -> OrderStatus {
@@ -551,6 +713,13 @@ pub mod decoder {
             }
         }
 
+        /// 'stopPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn stop_price_decimal(&self) -> Option<Decimal> {
+            self.stop_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn trailing_delta(&self) -> Option<i64> {
@@ -584,6 +753,13 @@ pub mod decoder {
             }
         }
 
+        /// 'icebergQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn iceberg_qty_decimal(&self) -> Option<Decimal> {
+            self.iceberg_qty()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn strategy_id(&self) -> Option<i64> {
@@ -635,6 +811,13 @@ pub mod decoder {
             }
         }
 
+        /// 'preventedQuantity' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn prevented_quantity_decimal(&self) -> Option<Decimal> {
+            self.prevented_quantity()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn used_sor(&self) -> BoolEnum {
@@ -644,56 +827,244 @@ pub mod decoder {
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn orig_quote_order_qty(&self) -> i64 {
-            if self.acting_version > 0 && self.acting_version < 1 {
+            if self.acting_version < 1 {
                 return -9223372036854775808_i64;
             }
 
             self.get_buf().get_i64_at(self.offset + 118)
         }
 
+        /// 'origQuoteOrderQty' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn orig_quote_order_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.orig_quote_order_qty(), self.price_exponent())
+        }
+
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn orig_client_order_id_decoder(&mut self) -> (usize, usize) {
+        pub fn orig_client_order_id_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn orig_client_order_id_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn orig_client_order_id_slice(
+            &'a self,
+            coordinates: (usize, usize),
+        ) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn client_order_id_decoder(&mut self) -> (usize, usize) {
+        pub fn client_order_id_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn client_order_id_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn client_order_id_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct CancelOrderResponse {
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub order_id: i64,
+        pub order_list_id: Option<i64>,
+        pub transact_time: i64,
+        pub price: Decimal,
+        pub orig_qty: Decimal,
+        pub executed_qty: Decimal,
+        pub cummulative_quote_qty: Decimal,
+        pub status: OrderStatus,
+        pub time_in_force: TimeInForce,
+        pub order_type: OrderType,
+        pub side: OrderSide,
+        pub stop_price: Option<Decimal>,
+        pub trailing_delta: Option<i64>,
+        pub trailing_time: Option<i64>,
+        pub iceberg_qty: Option<Decimal>,
+        pub strategy_id: Option<i64>,
+        pub strategy_type: Option<i32>,
+        pub order_capacity: OrderCapacity,
+        pub working_floor: Floor,
+        pub self_trade_prevention_mode: SelfTradePreventionMode,
+        pub prevented_quantity: Option<Decimal>,
+        pub used_sor: BoolEnum,
+        pub orig_quote_order_qty: Decimal,
+        pub symbol: String,
+        pub orig_client_order_id: String,
+        pub client_order_id: String,
+    }
+
+    impl CancelOrderResponse {
+        pub fn from_decoder(decoder: &mut CancelOrderResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                order_id: decoder.order_id(),
+                order_list_id: decoder.order_list_id(),
+                transact_time: decoder.transact_time(),
+                price: decoder.price_decimal(),
+                orig_qty: decoder.orig_qty_decimal(),
+                executed_qty: decoder.executed_qty_decimal(),
+                cummulative_quote_qty: decoder.cummulative_quote_qty_decimal(),
+                status: decoder.status(),
+                time_in_force: decoder.time_in_force(),
+                order_type: decoder.order_type(),
+                side: decoder.side(),
+                stop_price: decoder.stop_price_decimal(),
+                trailing_delta: decoder.trailing_delta(),
+                trailing_time: decoder.trailing_time(),
+                iceberg_qty: decoder.iceberg_qty_decimal(),
+                strategy_id: decoder.strategy_id(),
+                strategy_type: decoder.strategy_type(),
+                order_capacity: decoder.order_capacity(),
+                working_floor: decoder.working_floor(),
+                self_trade_prevention_mode: decoder.self_trade_prevention_mode(),
+                prevented_quantity: decoder.prevented_quantity_decimal(),
+                used_sor: decoder.used_sor(),
+                orig_quote_order_qty: decoder.orig_quote_order_qty_decimal(),
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                orig_client_order_id: {
+                    let coordinates = decoder.orig_client_order_id_decoder()?;
+                    let slice = decoder.orig_client_order_id_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                client_order_id: {
+                    let coordinates = decoder.client_order_id_decoder()?;
+                    let slice = decoder.client_order_id_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut CancelOrderResponseEncoder<'_>) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                [
+                    self.price,
+                    self.cummulative_quote_qty,
+                    self.orig_quote_order_qty,
+                ]
+                .into_iter()
+                .chain(self.stop_price),
+            ));
+            encoder.qty_exponent(fit_exponent(
+                self.qty_exponent,
+                [self.orig_qty, self.executed_qty]
+                    .into_iter()
+                    .chain(self.iceberg_qty)
+                    .chain(self.prevented_quantity),
+            ));
+            encoder.order_id(self.order_id);
+            encoder.order_list_id(self.order_list_id.unwrap_or(i64::MIN));
+            encoder.transact_time(self.transact_time);
+            encoder.price_decimal(self.price)?;
+            encoder.orig_qty_decimal(self.orig_qty)?;
+            encoder.executed_qty_decimal(self.executed_qty)?;
+            encoder.cummulative_quote_qty_decimal(self.cummulative_quote_qty)?;
+            encoder.status(self.status);
+            encoder.time_in_force(self.time_in_force);
+            encoder.order_type(self.order_type);
+            encoder.side(self.side);
+            encoder.stop_price_decimal(self.stop_price)?;
+            encoder.trailing_delta(self.trailing_delta.unwrap_or(i64::MIN));
+            encoder.trailing_time(self.trailing_time.unwrap_or(i64::MIN));
+            encoder.iceberg_qty_decimal(self.iceberg_qty)?;
+            encoder.strategy_id(self.strategy_id.unwrap_or(i64::MIN));
+            encoder.strategy_type(self.strategy_type.unwrap_or(i32::MIN));
+            encoder.order_capacity(self.order_capacity);
+            encoder.working_floor(self.working_floor);
+            encoder.self_trade_prevention_mode(self.self_trade_prevention_mode);
+            encoder.prevented_quantity_decimal(self.prevented_quantity)?;
+            encoder.used_sor(self.used_sor);
+            encoder.orig_quote_order_qty_decimal(self.orig_quote_order_qty)?;
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            let value = &self.orig_client_order_id;
+            checked_length::<u8>(value.len())?;
+            encoder.orig_client_order_id(value);
+            let value = &self.client_order_id;
+            checked_length::<u8>(value.len())?;
+            encoder.client_order_id(value);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            129 + self.symbol.len() + self.orig_client_order_id.len() + self.client_order_id.len()
+        }
+    }
+
+    impl OwnedMessage for CancelOrderResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = CancelOrderResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = CancelOrderResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
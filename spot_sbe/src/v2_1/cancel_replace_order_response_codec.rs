@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::CancelReplaceOrderResponseDecoder;
 pub use encoder::CancelReplaceOrderResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::CancelReplaceOrderResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 2;
 pub const SBE_TEMPLATE_ID: u16 = 307;
@@ -129,6 +131,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> CancelReplaceOrderResponseDecoder<'a> {
@@ -138,7 +145,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -146,7 +154,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -154,13 +162,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -181,32 +195,130 @@ pub mod decoder {
 
         /// VAR_DATA DECODER - character encoding: 'None'
         #[inline]
-        pub fn cancel_response_decoder(&mut self) -> (usize, usize) {
+        pub fn cancel_response_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 2)?;
             let data_length = self.get_buf().get_u16_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 2, data_length)?;
             self.set_limit(offset + 2 + data_length);
-            (offset + 2, data_length)
+            Ok((offset + 2, data_length))
         }
 
         #[inline]
-        pub fn cancel_response_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn cancel_response_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'None'
         #[inline]
-        pub fn new_order_response_decoder(&mut self) -> (usize, usize) {
+        pub fn new_order_response_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 4)?;
             let data_length = self.get_buf().get_u32_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 4, data_length)?;
             self.set_limit(offset + 4 + data_length);
-            (offset + 4, data_length)
+            Ok((offset + 4, data_length))
         }
 
         #[inline]
-        pub fn new_order_response_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn new_order_response_slice(
+            &'a self,
+            coordinates: (usize, usize),
+        ) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct CancelReplaceOrderResponse {
+        pub cancel_result: CancelReplaceStatus,
+        pub new_order_result: CancelReplaceStatus,
+        pub cancel_response: Option<Vec<u8>>,
+        pub new_order_response: Option<Vec<u8>>,
+    }
+
+    impl CancelReplaceOrderResponse {
+        pub fn from_decoder(
+            decoder: &mut CancelReplaceOrderResponseDecoder<'_>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                cancel_result: decoder.cancel_result(),
+                new_order_result: decoder.new_order_result(),
+                cancel_response: {
+                    let coordinates = decoder.cancel_response_decoder()?;
+                    let slice = decoder.cancel_response_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(slice.to_vec())
+                    }
+                },
+                new_order_response: {
+                    let coordinates = decoder.new_order_response_decoder()?;
+                    let slice = decoder.new_order_response_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(slice.to_vec())
+                    }
+                },
+            })
+        }
+
+        pub fn to_encoder(
+            &self,
+            encoder: &mut CancelReplaceOrderResponseEncoder<'_>,
+        ) -> SbeResult<()> {
+            encoder.cancel_result(self.cancel_result);
+            encoder.new_order_result(self.new_order_result);
+            let value = self.cancel_response.as_deref().unwrap_or_default();
+            checked_length::<u16>(value.len())?;
+            encoder.cancel_response(value);
+            let value = self.new_order_response.as_deref().unwrap_or_default();
+            checked_length::<u32>(value.len())?;
+            encoder.new_order_response(value);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            8 + self.cancel_response.as_ref().map_or(0, |value| value.len())
+                + self
+                    .new_order_response
+                    .as_ref()
+                    .map_or(0, |value| value.len())
+        }
+    }
+
+    impl OwnedMessage for CancelReplaceOrderResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = CancelReplaceOrderResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = CancelReplaceOrderResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
@@ -0,0 +1,23 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum CommissionType {
+    RecvAsset = 0x0_u8,
+    QuoteAsset = 0x1_u8,
+    GasOrRecvStrict = 0x2_u8,
+    GasOrRecvOptional = 0x3_u8,
+    #[default]
+    NullVal = 0xff_u8,
+}
+impl From<u8> for CommissionType {
+    #[inline]
+    fn from(v: u8) -> Self {
+        match v {
+            0x0_u8 => Self::RecvAsset,
+            0x1_u8 => Self::QuoteAsset,
+            0x2_u8 => Self::GasOrRecvStrict,
+            0x3_u8 => Self::GasOrRecvOptional,
+            _ => Self::NullVal,
+        }
+    }
+}
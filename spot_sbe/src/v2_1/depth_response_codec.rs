@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::DepthResponseDecoder;
 pub use encoder::DepthResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::DepthResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 10;
 pub const SBE_TEMPLATE_ID: u16 = 200;
@@ -359,6 +361,66 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
     }
+
+    impl BidsEncoder<DepthResponseEncoder<'_>> {
+        /// 'price' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 8;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
+        /// 'qty' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 9;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.qty(mantissa);
+            Ok(())
+        }
+    }
+
+    impl AsksEncoder<DepthResponseEncoder<'_>> {
+        /// 'price' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 8;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
+        /// 'qty' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 9;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.qty(mantissa);
+            Ok(())
+        }
+    }
 } // end encoder
 
 pub mod decoder {
@@ -391,6 +453,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> DepthResponseDecoder<'a> {
@@ -400,7 +467,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -408,7 +476,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -416,13 +484,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -449,75 +523,102 @@ pub mod decoder {
 
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn bids_decoder(self) -> BidsDecoder<Self> {
+        pub fn bids_decoder(self) -> SbeResult<BidsDecoder<'a, Self>> {
             BidsDecoder::default().wrap(self)
         }
 
         /// GROUP DECODER (id=101)
         #[inline]
-        pub fn asks_decoder(self) -> AsksDecoder<Self> {
+        pub fn asks_decoder(self) -> SbeResult<AsksDecoder<'a, Self>> {
             AsksDecoder::default().wrap(self)
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct BidsDecoder<P> {
+    pub struct BidsDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for BidsDecoder<P>
+    impl<'a, P> Reader<'a> for BidsDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for BidsDecoder<P>
+    impl<'a, P> Decoder<'a> for BidsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> BidsDecoder<P>
+    impl<'a, P> BidsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 16;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='bids', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=16, offset=10, componentTokenCount=12, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -531,14 +632,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(16))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// primitive field - 'REQUIRED'
@@ -555,63 +657,90 @@ pub mod decoder {
     }
 
     #[derive(Debug, Default)]
-    pub struct AsksDecoder<P> {
+    pub struct AsksDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for AsksDecoder<P>
+    impl<'a, P> Reader<'a> for AsksDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for AsksDecoder<P>
+    impl<'a, P> Decoder<'a> for AsksDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> AsksDecoder<P>
+    impl<'a, P> AsksDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 16;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='asks', referencedName='null', description='null', packageName='null', id=101, version=0, deprecated=0, encodedLength=16, offset=-1, componentTokenCount=12, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -625,14 +754,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(16))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// primitive field - 'REQUIRED'
@@ -647,4 +777,228 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 8)
         }
     }
+
+    impl BidsDecoder<'_, DepthResponseDecoder<'_>> {
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.price(), exponent))
+        }
+
+        /// 'qty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn qty_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(Decimal::new(self.qty(), exponent))
+        }
+    }
+
+    impl AsksDecoder<'_, DepthResponseDecoder<'_>> {
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.price(), exponent))
+        }
+
+        /// 'qty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn qty_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(Decimal::new(self.qty(), exponent))
+        }
+    }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DepthResponse {
+        pub last_update_id: i64,
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub bids: Vec<Bids>,
+        pub asks: Vec<Asks>,
+    }
+
+    impl DepthResponse {
+        pub fn from_decoder(decoder: &mut DepthResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                last_update_id: decoder.last_update_id(),
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                bids: {
+                    let mut bids_decoder = core::mem::take(decoder).bids_decoder()?;
+                    let mut entries = Vec::new();
+                    while bids_decoder.advance()?.is_some() {
+                        entries.push(Bids::from_decoder(&mut bids_decoder)?);
+                    }
+                    *decoder = bids_decoder.parent()?;
+                    entries
+                },
+                asks: {
+                    let mut asks_decoder = core::mem::take(decoder).asks_decoder()?;
+                    let mut entries = Vec::new();
+                    while asks_decoder.advance()?.is_some() {
+                        entries.push(Asks::from_decoder(&mut asks_decoder)?);
+                    }
+                    *decoder = asks_decoder.parent()?;
+                    entries
+                },
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut DepthResponseEncoder<'_>) -> SbeResult<()> {
+            encoder.last_update_id(self.last_update_id);
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                self.bids
+                    .iter()
+                    .map(|entry| entry.price)
+                    .chain(self.asks.iter().map(|entry| entry.price)),
+            ));
+            encoder.qty_exponent(fit_exponent(
+                self.qty_exponent,
+                self.bids
+                    .iter()
+                    .map(|entry| entry.qty)
+                    .chain(self.asks.iter().map(|entry| entry.qty)),
+            ));
+            let count = checked_length::<u32>(self.bids.len())?;
+            let mut bids_encoder =
+                core::mem::take(encoder).bids_encoder(count, BidsEncoder::default());
+            for entry in &self.bids {
+                bids_encoder.advance()?;
+                entry.to_encoder(&mut bids_encoder)?;
+            }
+            *encoder = bids_encoder.parent()?;
+            let count = checked_length::<u32>(self.asks.len())?;
+            let mut asks_encoder =
+                core::mem::take(encoder).asks_encoder(count, AsksEncoder::default());
+            for entry in &self.asks {
+                asks_encoder.advance()?;
+                entry.to_encoder(&mut asks_encoder)?;
+            }
+            *encoder = asks_encoder.parent()?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            22 + self.bids.iter().map(Bids::encoded_length).sum::<usize>()
+                + self.asks.iter().map(Asks::encoded_length).sum::<usize>()
+        }
+    }
+
+    impl OwnedMessage for DepthResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = DepthResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = DepthResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Bids {
+        pub price: Decimal,
+        pub qty: Decimal,
+    }
+
+    impl Bids {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut BidsDecoder<'a, DepthResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                price: decoder.price_decimal()?,
+                qty: decoder.qty_decimal()?,
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut BidsEncoder<DepthResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.price_decimal(self.price)?;
+            encoder.qty_decimal(self.qty)?;
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            16
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Asks {
+        pub price: Decimal,
+        pub qty: Decimal,
+    }
+
+    impl Asks {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut AsksDecoder<'a, DepthResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                price: decoder.price_decimal()?,
+                qty: decoder.qty_decimal()?,
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut AsksEncoder<DepthResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.price_decimal(self.price)?;
+            encoder.qty_decimal(self.qty)?;
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            16
+        }
+    }
+} // end owned
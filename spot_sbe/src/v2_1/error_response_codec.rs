@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::ErrorResponseDecoder;
 pub use encoder::ErrorResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::ErrorResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 18;
 pub const SBE_TEMPLATE_ID: u16 = 100;
@@ -157,6 +159,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> ErrorResponseDecoder<'a> {
@@ -166,7 +173,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -174,7 +182,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -182,13 +190,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -225,32 +239,119 @@ pub mod decoder {
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn msg_decoder(&mut self) -> (usize, usize) {
+        pub fn msg_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 2)?;
             let data_length = self.get_buf().get_u16_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 2, data_length)?;
             self.set_limit(offset + 2 + data_length);
-            (offset + 2, data_length)
+            Ok((offset + 2, data_length))
         }
 
         #[inline]
-        pub fn msg_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn msg_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'None'
         #[inline]
-        pub fn data_decoder(&mut self) -> (usize, usize) {
+        pub fn data_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 4)?;
             let data_length = self.get_buf().get_u32_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 4, data_length)?;
             self.set_limit(offset + 4 + data_length);
-            (offset + 4, data_length)
+            Ok((offset + 4, data_length))
         }
 
         #[inline]
-        pub fn data_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn data_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ErrorResponse {
+        pub code: i16,
+        pub server_time: Option<i64>,
+        pub retry_after: Option<i64>,
+        pub msg: String,
+        pub data: Option<Vec<u8>>,
+    }
+
+    impl ErrorResponse {
+        pub fn from_decoder(decoder: &mut ErrorResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                code: decoder.code(),
+                server_time: decoder.server_time(),
+                retry_after: decoder.retry_after(),
+                msg: {
+                    let coordinates = decoder.msg_decoder()?;
+                    let slice = decoder.msg_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                data: {
+                    let coordinates = decoder.data_decoder()?;
+                    let slice = decoder.data_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(slice.to_vec())
+                    }
+                },
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut ErrorResponseEncoder<'_>) -> SbeResult<()> {
+            encoder.code(self.code);
+            encoder.server_time(self.server_time.unwrap_or(i64::MIN));
+            encoder.retry_after(self.retry_after.unwrap_or(i64::MIN));
+            let value = &self.msg;
+            checked_length::<u16>(value.len())?;
+            encoder.msg(value);
+            let value = self.data.as_deref().unwrap_or_default();
+            checked_length::<u32>(value.len())?;
+            encoder.data(value);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            24 + self.msg.len() + self.data.as_ref().map_or(0, |value| value.len())
+        }
+    }
+
+    impl OwnedMessage for ErrorResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = ErrorResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = ErrorResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::ExchangeInfoResponseDecoder;
 pub use encoder::ExchangeInfoResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::ExchangeInfoResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 0;
 pub const SBE_TEMPLATE_ID: u16 = 103;
@@ -1150,6 +1152,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> ExchangeInfoResponseDecoder<'a> {
@@ -1159,7 +1166,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -1167,7 +1175,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -1175,13 +1183,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -1190,87 +1204,114 @@ pub mod decoder {
 
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn rate_limits_decoder(self) -> RateLimitsDecoder<Self> {
+        pub fn rate_limits_decoder(self) -> SbeResult<RateLimitsDecoder<'a, Self>> {
             RateLimitsDecoder::default().wrap(self)
         }
 
         /// GROUP DECODER (id=101)
         #[inline]
-        pub fn exchange_filters_decoder(self) -> ExchangeFiltersDecoder<Self> {
+        pub fn exchange_filters_decoder(self) -> SbeResult<ExchangeFiltersDecoder<'a, Self>> {
             ExchangeFiltersDecoder::default().wrap(self)
         }
 
         /// GROUP DECODER (id=102)
         #[inline]
-        pub fn symbols_decoder(self) -> SymbolsDecoder<Self> {
+        pub fn symbols_decoder(self) -> SbeResult<SymbolsDecoder<'a, Self>> {
             SymbolsDecoder::default().wrap(self)
         }
 
         /// GROUP DECODER (id=103)
         #[inline]
-        pub fn sors_decoder(self) -> SorsDecoder<Self> {
+        pub fn sors_decoder(self) -> SbeResult<SorsDecoder<'a, Self>> {
             SorsDecoder::default().wrap(self)
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct RateLimitsDecoder<P> {
+    pub struct RateLimitsDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for RateLimitsDecoder<P>
+    impl<'a, P> Reader<'a> for RateLimitsDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for RateLimitsDecoder<P>
+    impl<'a, P> Decoder<'a> for RateLimitsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> RateLimitsDecoder<P>
+    impl<'a, P> RateLimitsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 11;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='rateLimits', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=11, offset=0, componentTokenCount=28, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -1284,14 +1325,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(11))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// REQUIRED enum
@@ -1320,63 +1362,83 @@ pub mod decoder {
     }
 
     #[derive(Debug, Default)]
-    pub struct ExchangeFiltersDecoder<P> {
+    pub struct ExchangeFiltersDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for ExchangeFiltersDecoder<P>
+    impl<'a, P> Reader<'a> for ExchangeFiltersDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for ExchangeFiltersDecoder<P>
+    impl<'a, P> Decoder<'a> for ExchangeFiltersDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> ExchangeFiltersDecoder<P>
+    impl<'a, P> ExchangeFiltersDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='exchangeFilters', referencedName='null', description='null', packageName='null', id=101, version=0, deprecated=0, encodedLength=0, offset=-1, componentTokenCount=12, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -1390,93 +1452,124 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length)?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// VAR_DATA DECODER - character encoding: 'None'
         #[inline]
-        pub fn filter_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn filter_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn filter_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn filter_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct SymbolsDecoder<P> {
+    pub struct SymbolsDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for SymbolsDecoder<P>
+    impl<'a, P> Reader<'a> for SymbolsDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for SymbolsDecoder<P>
+    impl<'a, P> Decoder<'a> for SymbolsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> SymbolsDecoder<P>
+    impl<'a, P> SymbolsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 17;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 15) > available
+            {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='symbols', referencedName='null', description='null', packageName='null', id=102, version=0, deprecated=0, encodedLength=17, offset=-1, componentTokenCount=152, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -1490,14 +1583,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(17))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// REQUIRED enum
@@ -1596,129 +1690,155 @@ pub mod decoder {
 
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn filters_decoder(self) -> FiltersDecoder<Self> {
+        pub fn filters_decoder(self) -> SbeResult<FiltersDecoder<'a, Self>> {
             FiltersDecoder::default().wrap(self)
         }
 
         /// GROUP DECODER (id=101)
         #[inline]
-        pub fn permission_sets_decoder(self) -> PermissionSetsDecoder<Self> {
+        pub fn permission_sets_decoder(self) -> SbeResult<PermissionSetsDecoder<'a, Self>> {
             PermissionSetsDecoder::default().wrap(self)
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn base_asset_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn base_asset_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn base_asset_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn base_asset_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn quote_asset_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn quote_asset_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn quote_asset_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn quote_asset_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct FiltersDecoder<P> {
+    pub struct FiltersDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for FiltersDecoder<P>
+    impl<'a, P> Reader<'a> for FiltersDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for FiltersDecoder<P>
+    impl<'a, P> Decoder<'a> for FiltersDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> FiltersDecoder<P>
+    impl<'a, P> FiltersDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='filters', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=0, offset=17, componentTokenCount=12, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -1732,93 +1852,116 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length)?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// VAR_DATA DECODER - character encoding: 'None'
         #[inline]
-        pub fn filter_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn filter_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn filter_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn filter_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct PermissionSetsDecoder<P> {
+    pub struct PermissionSetsDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for PermissionSetsDecoder<P>
+    impl<'a, P> Reader<'a> for PermissionSetsDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for PermissionSetsDecoder<P>
+    impl<'a, P> Decoder<'a> for PermissionSetsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> PermissionSetsDecoder<P>
+    impl<'a, P> PermissionSetsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 6) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='permissionSets', referencedName='null', description='null', packageName='null', id=101, version=0, deprecated=0, encodedLength=0, offset=-1, componentTokenCount=18, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -1832,81 +1975,102 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length)?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn permissions_decoder(self) -> PermissionsDecoder<Self> {
+        pub fn permissions_decoder(self) -> SbeResult<PermissionsDecoder<'a, Self>> {
             PermissionsDecoder::default().wrap(self)
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct PermissionsDecoder<P> {
+    pub struct PermissionsDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for PermissionsDecoder<P>
+    impl<'a, P> Reader<'a> for PermissionsDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for PermissionsDecoder<P>
+    impl<'a, P> Decoder<'a> for PermissionsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> PermissionsDecoder<P>
+    impl<'a, P> PermissionsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='permissions', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=0, offset=0, componentTokenCount=12, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -1920,93 +2084,116 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length)?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn permission_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn permission_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn permission_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn permission_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct SorsDecoder<P> {
+    pub struct SorsDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for SorsDecoder<P>
+    impl<'a, P> Reader<'a> for SorsDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for SorsDecoder<P>
+    impl<'a, P> Decoder<'a> for SorsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> SorsDecoder<P>
+    impl<'a, P> SorsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 7) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='sors', referencedName='null', description='null', packageName='null', id=103, version=0, deprecated=0, encodedLength=0, offset=-1, componentTokenCount=24, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -2020,99 +2207,122 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length)?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// GROUP DECODER (id=1)
         #[inline]
-        pub fn sor_symbols_decoder(self) -> SorSymbolsDecoder<Self> {
+        pub fn sor_symbols_decoder(self) -> SbeResult<SorSymbolsDecoder<'a, Self>> {
             SorSymbolsDecoder::default().wrap(self)
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn base_asset_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn base_asset_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn base_asset_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn base_asset_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct SorSymbolsDecoder<P> {
+    pub struct SorSymbolsDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for SorSymbolsDecoder<P>
+    impl<'a, P> Reader<'a> for SorSymbolsDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for SorSymbolsDecoder<P>
+    impl<'a, P> Decoder<'a> for SorSymbolsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> SorSymbolsDecoder<P>
+    impl<'a, P> SorSymbolsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='sorSymbols', referencedName='null', description='null', packageName='null', id=1, version=0, deprecated=0, encodedLength=0, offset=0, componentTokenCount=12, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -2126,32 +2336,645 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length)?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ExchangeInfoResponse {
+        pub rate_limits: Vec<RateLimits>,
+        pub exchange_filters: Vec<ExchangeFilters>,
+        pub symbols: Vec<Symbols>,
+        pub sors: Vec<Sors>,
+    }
+
+    impl ExchangeInfoResponse {
+        pub fn from_decoder(decoder: &mut ExchangeInfoResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                rate_limits: {
+                    let mut rate_limits_decoder = core::mem::take(decoder).rate_limits_decoder()?;
+                    let mut entries = Vec::new();
+                    while rate_limits_decoder.advance()?.is_some() {
+                        entries.push(RateLimits::from_decoder(&mut rate_limits_decoder)?);
+                    }
+                    *decoder = rate_limits_decoder.parent()?;
+                    entries
+                },
+                exchange_filters: {
+                    let mut exchange_filters_decoder =
+                        core::mem::take(decoder).exchange_filters_decoder()?;
+                    let mut entries = Vec::new();
+                    while exchange_filters_decoder.advance()?.is_some() {
+                        entries.push(ExchangeFilters::from_decoder(
+                            &mut exchange_filters_decoder,
+                        )?);
+                    }
+                    *decoder = exchange_filters_decoder.parent()?;
+                    entries
+                },
+                symbols: {
+                    let mut symbols_decoder = core::mem::take(decoder).symbols_decoder()?;
+                    let mut entries = Vec::new();
+                    while symbols_decoder.advance()?.is_some() {
+                        entries.push(Symbols::from_decoder(&mut symbols_decoder)?);
+                    }
+                    *decoder = symbols_decoder.parent()?;
+                    entries
+                },
+                sors: {
+                    let mut sors_decoder = core::mem::take(decoder).sors_decoder()?;
+                    let mut entries = Vec::new();
+                    while sors_decoder.advance()?.is_some() {
+                        entries.push(Sors::from_decoder(&mut sors_decoder)?);
+                    }
+                    *decoder = sors_decoder.parent()?;
+                    entries
+                },
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut ExchangeInfoResponseEncoder<'_>) -> SbeResult<()> {
+            let count = checked_length::<u32>(self.rate_limits.len())?;
+            let mut rate_limits_encoder =
+                core::mem::take(encoder).rate_limits_encoder(count, RateLimitsEncoder::default());
+            for entry in &self.rate_limits {
+                rate_limits_encoder.advance()?;
+                entry.to_encoder(&mut rate_limits_encoder)?;
+            }
+            *encoder = rate_limits_encoder.parent()?;
+            let count = checked_length::<u32>(self.exchange_filters.len())?;
+            let mut exchange_filters_encoder = core::mem::take(encoder)
+                .exchange_filters_encoder(count, ExchangeFiltersEncoder::default());
+            for entry in &self.exchange_filters {
+                exchange_filters_encoder.advance()?;
+                entry.to_encoder(&mut exchange_filters_encoder)?;
+            }
+            *encoder = exchange_filters_encoder.parent()?;
+            let count = checked_length::<u32>(self.symbols.len())?;
+            let mut symbols_encoder =
+                core::mem::take(encoder).symbols_encoder(count, SymbolsEncoder::default());
+            for entry in &self.symbols {
+                symbols_encoder.advance()?;
+                entry.to_encoder(&mut symbols_encoder)?;
+            }
+            *encoder = symbols_encoder.parent()?;
+            let count = checked_length::<u32>(self.sors.len())?;
+            let mut sors_encoder =
+                core::mem::take(encoder).sors_encoder(count, SorsEncoder::default());
+            for entry in &self.sors {
+                sors_encoder.advance()?;
+                entry.to_encoder(&mut sors_encoder)?;
+            }
+            *encoder = sors_encoder.parent()?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            24 + self
+                .rate_limits
+                .iter()
+                .map(RateLimits::encoded_length)
+                .sum::<usize>()
+                + self
+                    .exchange_filters
+                    .iter()
+                    .map(ExchangeFilters::encoded_length)
+                    .sum::<usize>()
+                + self
+                    .symbols
+                    .iter()
+                    .map(Symbols::encoded_length)
+                    .sum::<usize>()
+                + self.sors.iter().map(Sors::encoded_length).sum::<usize>()
+        }
+    }
+
+    impl OwnedMessage for ExchangeInfoResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = ExchangeInfoResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = ExchangeInfoResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct RateLimits {
+        pub rate_limit_type: RateLimitType,
+        pub interval: RateLimitInterval,
+        pub interval_num: u8,
+        pub rate_limit: i64,
+    }
+
+    impl RateLimits {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut RateLimitsDecoder<'a, ExchangeInfoResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                rate_limit_type: decoder.rate_limit_type(),
+                interval: decoder.interval(),
+                interval_num: decoder.interval_num(),
+                rate_limit: decoder.rate_limit(),
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut RateLimitsEncoder<ExchangeInfoResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.rate_limit_type(self.rate_limit_type);
+            encoder.interval(self.interval);
+            encoder.interval_num(self.interval_num);
+            encoder.rate_limit(self.rate_limit);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            11
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ExchangeFilters {
+        pub filter: Vec<u8>,
+    }
+
+    impl ExchangeFilters {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut ExchangeFiltersDecoder<'a, ExchangeInfoResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                filter: {
+                    let coordinates = decoder.filter_decoder()?;
+                    let slice = decoder.filter_slice(coordinates)?;
+                    slice.to_vec()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut ExchangeFiltersEncoder<ExchangeInfoResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            let value = &self.filter;
+            checked_length::<u8>(value.len())?;
+            encoder.filter(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            1 + self.filter.len()
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Symbols {
+        pub status: SymbolStatus,
+        pub base_asset_precision: u8,
+        pub quote_asset_precision: u8,
+        pub base_commission_precision: u8,
+        pub quote_commission_precision: u8,
+        pub order_types: OrderTypes,
+        pub iceberg_allowed: BoolEnum,
+        pub oco_allowed: BoolEnum,
+        pub oto_allowed: BoolEnum,
+        pub quote_order_qty_market_allowed: BoolEnum,
+        pub allow_trailing_stop: BoolEnum,
+        pub cancel_replace_allowed: BoolEnum,
+        pub is_spot_trading_allowed: BoolEnum,
+        pub is_margin_trading_allowed: BoolEnum,
+        pub default_self_trade_prevention_mode: SelfTradePreventionMode,
+        pub allowed_self_trade_prevention_modes: AllowedSelfTradePreventionModes,
+        pub filters: Vec<Filters>,
+        pub permission_sets: Vec<PermissionSets>,
+        pub symbol: String,
+        pub base_asset: String,
+        pub quote_asset: String,
+    }
+
+    impl Symbols {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut SymbolsDecoder<'a, ExchangeInfoResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                status: decoder.status(),
+                base_asset_precision: decoder.base_asset_precision(),
+                quote_asset_precision: decoder.quote_asset_precision(),
+                base_commission_precision: decoder.base_commission_precision(),
+                quote_commission_precision: decoder.quote_commission_precision(),
+                order_types: decoder.order_types(),
+                iceberg_allowed: decoder.iceberg_allowed(),
+                oco_allowed: decoder.oco_allowed(),
+                oto_allowed: decoder.oto_allowed(),
+                quote_order_qty_market_allowed: decoder.quote_order_qty_market_allowed(),
+                allow_trailing_stop: decoder.allow_trailing_stop(),
+                cancel_replace_allowed: decoder.cancel_replace_allowed(),
+                is_spot_trading_allowed: decoder.is_spot_trading_allowed(),
+                is_margin_trading_allowed: decoder.is_margin_trading_allowed(),
+                default_self_trade_prevention_mode: decoder.default_self_trade_prevention_mode(),
+                allowed_self_trade_prevention_modes: decoder.allowed_self_trade_prevention_modes(),
+                filters: {
+                    let mut filters_decoder = core::mem::take(decoder).filters_decoder()?;
+                    let mut entries = Vec::new();
+                    while filters_decoder.advance()?.is_some() {
+                        entries.push(Filters::from_decoder(&mut filters_decoder)?);
+                    }
+                    *decoder = filters_decoder.parent()?;
+                    entries
+                },
+                permission_sets: {
+                    let mut permission_sets_decoder =
+                        core::mem::take(decoder).permission_sets_decoder()?;
+                    let mut entries = Vec::new();
+                    while permission_sets_decoder.advance()?.is_some() {
+                        entries.push(PermissionSets::from_decoder(&mut permission_sets_decoder)?);
+                    }
+                    *decoder = permission_sets_decoder.parent()?;
+                    entries
+                },
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                base_asset: {
+                    let coordinates = decoder.base_asset_decoder()?;
+                    let slice = decoder.base_asset_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                quote_asset: {
+                    let coordinates = decoder.quote_asset_decoder()?;
+                    let slice = decoder.quote_asset_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut SymbolsEncoder<ExchangeInfoResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.status(self.status);
+            encoder.base_asset_precision(self.base_asset_precision);
+            encoder.quote_asset_precision(self.quote_asset_precision);
+            encoder.base_commission_precision(self.base_commission_precision);
+            encoder.quote_commission_precision(self.quote_commission_precision);
+            encoder.order_types(self.order_types);
+            encoder.iceberg_allowed(self.iceberg_allowed);
+            encoder.oco_allowed(self.oco_allowed);
+            encoder.oto_allowed(self.oto_allowed);
+            encoder.quote_order_qty_market_allowed(self.quote_order_qty_market_allowed);
+            encoder.allow_trailing_stop(self.allow_trailing_stop);
+            encoder.cancel_replace_allowed(self.cancel_replace_allowed);
+            encoder.is_spot_trading_allowed(self.is_spot_trading_allowed);
+            encoder.is_margin_trading_allowed(self.is_margin_trading_allowed);
+            encoder.default_self_trade_prevention_mode(self.default_self_trade_prevention_mode);
+            encoder.allowed_self_trade_prevention_modes(self.allowed_self_trade_prevention_modes);
+            let count = checked_length::<u32>(self.filters.len())?;
+            let mut filters_encoder =
+                core::mem::take(encoder).filters_encoder(count, FiltersEncoder::default());
+            for entry in &self.filters {
+                filters_encoder.advance()?;
+                entry.to_encoder(&mut filters_encoder)?;
+            }
+            *encoder = filters_encoder.parent()?;
+            let count = checked_length::<u32>(self.permission_sets.len())?;
+            let mut permission_sets_encoder = core::mem::take(encoder)
+                .permission_sets_encoder(count, PermissionSetsEncoder::default());
+            for entry in &self.permission_sets {
+                permission_sets_encoder.advance()?;
+                entry.to_encoder(&mut permission_sets_encoder)?;
+            }
+            *encoder = permission_sets_encoder.parent()?;
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            let value = &self.base_asset;
+            checked_length::<u8>(value.len())?;
+            encoder.base_asset(value);
+            let value = &self.quote_asset;
+            checked_length::<u8>(value.len())?;
+            encoder.quote_asset(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            32 + self
+                .filters
+                .iter()
+                .map(Filters::encoded_length)
+                .sum::<usize>()
+                + self
+                    .permission_sets
+                    .iter()
+                    .map(PermissionSets::encoded_length)
+                    .sum::<usize>()
+                + self.symbol.len()
+                + self.base_asset.len()
+                + self.quote_asset.len()
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Filters {
+        pub filter: Vec<u8>,
+    }
+
+    impl Filters {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut FiltersDecoder<'a, SymbolsDecoder<'a, ExchangeInfoResponseDecoder<'a>>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                filter: {
+                    let coordinates = decoder.filter_decoder()?;
+                    let slice = decoder.filter_slice(coordinates)?;
+                    slice.to_vec()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut FiltersEncoder<SymbolsEncoder<ExchangeInfoResponseEncoder<'_>>>,
+        ) -> SbeResult<()> {
+            let value = &self.filter;
+            checked_length::<u8>(value.len())?;
+            encoder.filter(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            1 + self.filter.len()
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PermissionSets {
+        pub permissions: Vec<Permissions>,
+    }
+
+    impl PermissionSets {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut PermissionSetsDecoder<
+                'a,
+                SymbolsDecoder<'a, ExchangeInfoResponseDecoder<'a>>,
+            >,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                permissions: {
+                    let mut permissions_decoder = core::mem::take(decoder).permissions_decoder()?;
+                    let mut entries = Vec::new();
+                    while permissions_decoder.advance()?.is_some() {
+                        entries.push(Permissions::from_decoder(&mut permissions_decoder)?);
+                    }
+                    *decoder = permissions_decoder.parent()?;
+                    entries
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut PermissionSetsEncoder<SymbolsEncoder<ExchangeInfoResponseEncoder<'_>>>,
+        ) -> SbeResult<()> {
+            let count = checked_length::<u32>(self.permissions.len())?;
+            let mut permissions_encoder =
+                core::mem::take(encoder).permissions_encoder(count, PermissionsEncoder::default());
+            for entry in &self.permissions {
+                permissions_encoder.advance()?;
+                entry.to_encoder(&mut permissions_encoder)?;
+            }
+            *encoder = permissions_encoder.parent()?;
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            6 + self
+                .permissions
+                .iter()
+                .map(Permissions::encoded_length)
+                .sum::<usize>()
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Permissions {
+        pub permission: String,
+    }
+
+    impl Permissions {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut PermissionsDecoder<
+                'a,
+                PermissionSetsDecoder<'a, SymbolsDecoder<'a, ExchangeInfoResponseDecoder<'a>>>,
+            >,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                permission: {
+                    let coordinates = decoder.permission_decoder()?;
+                    let slice = decoder.permission_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut PermissionsEncoder<
+                PermissionSetsEncoder<SymbolsEncoder<ExchangeInfoResponseEncoder<'_>>>,
+            >,
+        ) -> SbeResult<()> {
+            let value = &self.permission;
+            checked_length::<u8>(value.len())?;
+            encoder.permission(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            1 + self.permission.len()
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Sors {
+        pub sor_symbols: Vec<SorSymbols>,
+        pub base_asset: String,
+    }
+
+    impl Sors {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut SorsDecoder<'a, ExchangeInfoResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                sor_symbols: {
+                    let mut sor_symbols_decoder = core::mem::take(decoder).sor_symbols_decoder()?;
+                    let mut entries = Vec::new();
+                    while sor_symbols_decoder.advance()?.is_some() {
+                        entries.push(SorSymbols::from_decoder(&mut sor_symbols_decoder)?);
+                    }
+                    *decoder = sor_symbols_decoder.parent()?;
+                    entries
+                },
+                base_asset: {
+                    let coordinates = decoder.base_asset_decoder()?;
+                    let slice = decoder.base_asset_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut SorsEncoder<ExchangeInfoResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            let count = checked_length::<u32>(self.sor_symbols.len())?;
+            let mut sor_symbols_encoder =
+                core::mem::take(encoder).sor_symbols_encoder(count, SorSymbolsEncoder::default());
+            for entry in &self.sor_symbols {
+                sor_symbols_encoder.advance()?;
+                entry.to_encoder(&mut sor_symbols_encoder)?;
+            }
+            *encoder = sor_symbols_encoder.parent()?;
+            let value = &self.base_asset;
+            checked_length::<u8>(value.len())?;
+            encoder.base_asset(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            7 + self
+                .sor_symbols
+                .iter()
+                .map(SorSymbols::encoded_length)
+                .sum::<usize>()
+                + self.base_asset.len()
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct SorSymbols {
+        pub symbol: String,
+    }
+
+    impl SorSymbols {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut SorSymbolsDecoder<'a, SorsDecoder<'a, ExchangeInfoResponseDecoder<'a>>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut SorSymbolsEncoder<SorsEncoder<ExchangeInfoResponseEncoder<'_>>>,
+        ) -> SbeResult<()> {
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            1 + self.symbol.len()
+        }
+    }
+} // end owned
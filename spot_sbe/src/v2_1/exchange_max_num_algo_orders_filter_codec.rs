@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::ExchangeMaxNumAlgoOrdersFilterDecoder;
 pub use encoder::ExchangeMaxNumAlgoOrdersFilterEncoder;
+#[cfg(feature = "serde")]
+pub use owned::ExchangeMaxNumAlgoOrdersFilter;
 
 pub const SBE_BLOCK_LENGTH: u16 = 8;
 pub const SBE_TEMPLATE_ID: u16 = 16;
@@ -111,6 +113,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> ExchangeMaxNumAlgoOrdersFilterDecoder<'a> {
@@ -120,7 +127,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -128,7 +136,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -136,13 +144,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -162,3 +176,61 @@ pub mod decoder {
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ExchangeMaxNumAlgoOrdersFilter {
+        pub max_num_algo_orders: i64,
+    }
+
+    impl ExchangeMaxNumAlgoOrdersFilter {
+        pub fn from_decoder(
+            decoder: &mut ExchangeMaxNumAlgoOrdersFilterDecoder<'_>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                max_num_algo_orders: decoder.max_num_algo_orders(),
+            })
+        }
+
+        pub fn to_encoder(
+            &self,
+            encoder: &mut ExchangeMaxNumAlgoOrdersFilterEncoder<'_>,
+        ) -> SbeResult<()> {
+            encoder.max_num_algo_orders(self.max_num_algo_orders);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            8
+        }
+    }
+
+    impl OwnedMessage for ExchangeMaxNumAlgoOrdersFilter {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = ExchangeMaxNumAlgoOrdersFilterDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = ExchangeMaxNumAlgoOrdersFilterEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
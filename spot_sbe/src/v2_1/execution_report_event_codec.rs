@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::ExecutionReportEventDecoder;
 pub use encoder::ExecutionReportEventEncoder;
+#[cfg(feature = "serde")]
+pub use owned::ExecutionReportEvent;
 
 pub const SBE_BLOCK_LENGTH: u16 = 268;
 pub const SBE_TEMPLATE_ID: u16 = 603;
@@ -203,6 +205,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'origQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn orig_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 17;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.orig_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'price'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -217,6 +233,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'price' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 16;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'origQuoteOrderQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -231,6 +261,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'origQuoteOrderQty' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn orig_quote_order_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 16;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.orig_quote_order_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'icebergQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -245,6 +289,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'icebergQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn iceberg_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 17;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.iceberg_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'stopPrice'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -259,6 +317,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'stopPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn stop_price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 16;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.stop_price(mantissa);
+            Ok(())
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn order_type(&mut self, value: OrderType) {
@@ -336,6 +408,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'executedQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn executed_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 17;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.executed_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'cummulativeQuoteQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -350,6 +436,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'cummulativeQuoteQty' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn cummulative_quote_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 16;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.cummulative_quote_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'lastQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -364,6 +464,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'lastQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn last_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 17;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.last_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'lastPrice'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -378,6 +492,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'lastPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn last_price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 16;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.last_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'quoteQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -392,6 +520,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'quoteQty' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn quote_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 16;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.quote_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'commission'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -406,6 +548,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'commission' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn commission_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 18;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.commission(mantissa);
+            Ok(())
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn is_working(&mut self, value: BoolEnum) {
@@ -532,6 +688,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'preventedQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn prevented_qty_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.prevented_qty(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset + 17;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.prevented_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'lastPreventedQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -546,6 +720,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'lastPreventedQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn last_prevented_qty_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.last_prevented_qty(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset + 17;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.last_prevented_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'preventedMatchId'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -574,6 +766,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'preventedExecutionQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn prevented_execution_qty_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.prevented_execution_qty(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset + 17;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.prevented_execution_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'preventedExecutionPrice'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -588,6 +798,27 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'preventedExecutionPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn prevented_execution_price_decimal(
+            &mut self,
+            value: Option<Decimal>,
+        ) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.prevented_execution_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset + 16;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.prevented_execution_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'preventedExecutionQuoteQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -602,6 +833,27 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'preventedExecutionQuoteQty' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn prevented_execution_quote_qty_decimal(
+            &mut self,
+            value: Option<Decimal>,
+        ) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.prevented_execution_quote_qty(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset + 16;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.prevented_execution_quote_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'strategyType'
         /// - min value: -2147483647
         /// - max value: 2147483647
@@ -736,6 +988,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> ExecutionReportEventDecoder<'a> {
@@ -745,7 +1002,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -753,7 +1011,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -761,13 +1019,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -844,30 +1108,60 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 51)
         }
 
+        /// 'origQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn orig_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.orig_qty(), self.qty_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn price(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 59)
         }
 
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> Decimal {
+            Decimal::new(self.price(), self.price_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn orig_quote_order_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 67)
         }
 
+        /// 'origQuoteOrderQty' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn orig_quote_order_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.orig_quote_order_qty(), self.price_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn iceberg_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 75)
         }
 
+        /// 'icebergQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn iceberg_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.iceberg_qty(), self.qty_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn stop_price(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 83)
         }
 
+        /// 'stopPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn stop_price_decimal(&self) -> Decimal {
+            Decimal::new(self.stop_price(), self.price_exponent())
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn order_type(&self) -> OrderType {
@@ -916,7 +1210,7 @@ pub mod decoder {
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn execution_id(&self) -> i64 {
-            if self.acting_version > 0 && self.acting_version < 1 {
+            if self.acting_version < 1 {
                 return -9223372036854775808_i64;
             }
 
@@ -929,36 +1223,72 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 112)
         }
 
+        /// 'executedQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn executed_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.executed_qty(), self.qty_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn cummulative_quote_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 120)
         }
 
+        /// 'cummulativeQuoteQty' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn cummulative_quote_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.cummulative_quote_qty(), self.price_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn last_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 128)
         }
 
+        /// 'lastQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn last_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.last_qty(), self.qty_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn last_price(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 136)
         }
 
+        /// 'lastPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn last_price_decimal(&self) -> Decimal {
+            Decimal::new(self.last_price(), self.price_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn quote_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 144)
         }
 
+        /// 'quoteQty' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn quote_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.quote_qty(), self.price_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn commission(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 152)
         }
 
+        /// 'commission' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn commission_decimal(&self) -> Decimal {
+            Decimal::new(self.commission(), self.commission_exponent())
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn is_working(&self) -> BoolEnum {
@@ -1062,6 +1392,13 @@ pub mod decoder {
             }
         }
 
+        /// 'preventedQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn prevented_qty_decimal(&self) -> Option<Decimal> {
+            self.prevented_qty()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn last_prevented_qty(&self) -> Option<i64> {
@@ -1073,6 +1410,13 @@ pub mod decoder {
             }
         }
 
+        /// 'lastPreventedQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn last_prevented_qty_decimal(&self) -> Option<Decimal> {
+            self.last_prevented_qty()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn prevented_match_id(&self) -> Option<i64> {
@@ -1095,6 +1439,13 @@ pub mod decoder {
             }
         }
 
+        /// 'preventedExecutionQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn prevented_execution_qty_decimal(&self) -> Option<Decimal> {
+            self.prevented_execution_qty()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn prevented_execution_price(&self) -> Option<i64> {
@@ -1106,6 +1457,13 @@ pub mod decoder {
             }
         }
 
+        /// 'preventedExecutionPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn prevented_execution_price_decimal(&self) -> Option<Decimal> {
+            self.prevented_execution_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn prevented_execution_quote_qty(&self) -> Option<i64> {
@@ -1117,6 +1475,13 @@ pub mod decoder {
             }
         }
 
+        /// 'preventedExecutionQuoteQty' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn prevented_execution_quote_qty_decimal(&self) -> Option<Decimal> {
+            self.prevented_execution_quote_qty()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-2147483648' }
         #[inline]
         pub fn strategy_type(&self) -> Option<i32> {
@@ -1152,92 +1517,430 @@ pub mod decoder {
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn client_order_id_decoder(&mut self) -> (usize, usize) {
+        pub fn client_order_id_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn client_order_id_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn client_order_id_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn orig_client_order_id_decoder(&mut self) -> (usize, usize) {
+        pub fn orig_client_order_id_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn orig_client_order_id_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn orig_client_order_id_slice(
+            &'a self,
+            coordinates: (usize, usize),
+        ) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn commission_asset_decoder(&mut self) -> (usize, usize) {
+        pub fn commission_asset_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn commission_asset_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn commission_asset_slice(
+            &'a self,
+            coordinates: (usize, usize),
+        ) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn reject_reason_decoder(&mut self) -> (usize, usize) {
+        pub fn reject_reason_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn reject_reason_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn reject_reason_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn counter_symbol_decoder(&mut self) -> (usize, usize) {
+        pub fn counter_symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn counter_symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn counter_symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ExecutionReportEvent {
+        pub event_time: i64,
+        pub transact_time: i64,
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub commission_exponent: i8,
+        pub order_creation_time: i64,
+        pub working_time: Option<i64>,
+        pub order_id: i64,
+        pub order_list_id: Option<i64>,
+        pub orig_qty: Decimal,
+        pub price: Decimal,
+        pub orig_quote_order_qty: Decimal,
+        pub iceberg_qty: Decimal,
+        pub stop_price: Decimal,
+        pub order_type: OrderType,
+        pub side: OrderSide,
+        pub time_in_force: TimeInForce,
+        pub execution_type: ExecutionType,
+        pub order_status: OrderStatus,
+        pub trade_id: Option<i64>,
+        pub execution_id: i64,
+        pub executed_qty: Decimal,
+        pub cummulative_quote_qty: Decimal,
+        pub last_qty: Decimal,
+        pub last_price: Decimal,
+        pub quote_qty: Decimal,
+        pub commission: Decimal,
+        pub is_working: BoolEnum,
+        pub is_maker: BoolEnum,
+        pub is_best_match: BoolEnum,
+        pub match_type: MatchType,
+        pub self_trade_prevention_mode: SelfTradePreventionMode,
+        pub order_capacity: OrderCapacity,
+        pub working_floor: Floor,
+        pub used_sor: BoolEnum,
+        pub alloc_id: Option<i64>,
+        pub trailing_delta: Option<u64>,
+        pub trailing_time: Option<i64>,
+        pub trade_group_id: Option<i64>,
+        pub prevented_qty: Option<Decimal>,
+        pub last_prevented_qty: Option<Decimal>,
+        pub prevented_match_id: Option<i64>,
+        pub prevented_execution_qty: Option<Decimal>,
+        pub prevented_execution_price: Option<Decimal>,
+        pub prevented_execution_quote_qty: Option<Decimal>,
+        pub strategy_type: Option<i32>,
+        pub strategy_id: Option<i64>,
+        pub counter_order_id: Option<i64>,
+        pub symbol: String,
+        pub client_order_id: String,
+        pub orig_client_order_id: Option<String>,
+        pub commission_asset: Option<String>,
+        pub reject_reason: Option<String>,
+        pub counter_symbol: Option<String>,
+    }
+
+    impl ExecutionReportEvent {
+        pub fn from_decoder(decoder: &mut ExecutionReportEventDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                event_time: decoder.event_time(),
+                transact_time: decoder.transact_time(),
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                commission_exponent: decoder.commission_exponent(),
+                order_creation_time: decoder.order_creation_time(),
+                working_time: decoder.working_time(),
+                order_id: decoder.order_id(),
+                order_list_id: decoder.order_list_id(),
+                orig_qty: decoder.orig_qty_decimal(),
+                price: decoder.price_decimal(),
+                orig_quote_order_qty: decoder.orig_quote_order_qty_decimal(),
+                iceberg_qty: decoder.iceberg_qty_decimal(),
+                stop_price: decoder.stop_price_decimal(),
+                order_type: decoder.order_type(),
+                side: decoder.side(),
+                time_in_force: decoder.time_in_force(),
+                execution_type: decoder.execution_type(),
+                order_status: decoder.order_status(),
+                trade_id: decoder.trade_id(),
+                execution_id: decoder.execution_id(),
+                executed_qty: decoder.executed_qty_decimal(),
+                cummulative_quote_qty: decoder.cummulative_quote_qty_decimal(),
+                last_qty: decoder.last_qty_decimal(),
+                last_price: decoder.last_price_decimal(),
+                quote_qty: decoder.quote_qty_decimal(),
+                commission: decoder.commission_decimal(),
+                is_working: decoder.is_working(),
+                is_maker: decoder.is_maker(),
+                is_best_match: decoder.is_best_match(),
+                match_type: decoder.match_type(),
+                self_trade_prevention_mode: decoder.self_trade_prevention_mode(),
+                order_capacity: decoder.order_capacity(),
+                working_floor: decoder.working_floor(),
+                used_sor: decoder.used_sor(),
+                alloc_id: decoder.alloc_id(),
+                trailing_delta: decoder.trailing_delta(),
+                trailing_time: decoder.trailing_time(),
+                trade_group_id: decoder.trade_group_id(),
+                prevented_qty: decoder.prevented_qty_decimal(),
+                last_prevented_qty: decoder.last_prevented_qty_decimal(),
+                prevented_match_id: decoder.prevented_match_id(),
+                prevented_execution_qty: decoder.prevented_execution_qty_decimal(),
+                prevented_execution_price: decoder.prevented_execution_price_decimal(),
+                prevented_execution_quote_qty: decoder.prevented_execution_quote_qty_decimal(),
+                strategy_type: decoder.strategy_type(),
+                strategy_id: decoder.strategy_id(),
+                counter_order_id: decoder.counter_order_id(),
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                client_order_id: {
+                    let coordinates = decoder.client_order_id_decoder()?;
+                    let slice = decoder.client_order_id_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                orig_client_order_id: {
+                    let coordinates = decoder.orig_client_order_id_decoder()?;
+                    let slice = decoder.orig_client_order_id_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(
+                            core::str::from_utf8(slice)
+                                .map_err(|_| SbeErr::InvalidUtf8)?
+                                .to_owned(),
+                        )
+                    }
+                },
+                commission_asset: {
+                    let coordinates = decoder.commission_asset_decoder()?;
+                    let slice = decoder.commission_asset_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(
+                            core::str::from_utf8(slice)
+                                .map_err(|_| SbeErr::InvalidUtf8)?
+                                .to_owned(),
+                        )
+                    }
+                },
+                reject_reason: {
+                    let coordinates = decoder.reject_reason_decoder()?;
+                    let slice = decoder.reject_reason_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(
+                            core::str::from_utf8(slice)
+                                .map_err(|_| SbeErr::InvalidUtf8)?
+                                .to_owned(),
+                        )
+                    }
+                },
+                counter_symbol: {
+                    let coordinates = decoder.counter_symbol_decoder()?;
+                    let slice = decoder.counter_symbol_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(
+                            core::str::from_utf8(slice)
+                                .map_err(|_| SbeErr::InvalidUtf8)?
+                                .to_owned(),
+                        )
+                    }
+                },
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut ExecutionReportEventEncoder<'_>) -> SbeResult<()> {
+            encoder.event_time(self.event_time);
+            encoder.transact_time(self.transact_time);
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                [
+                    self.price,
+                    self.orig_quote_order_qty,
+                    self.stop_price,
+                    self.cummulative_quote_qty,
+                    self.last_price,
+                    self.quote_qty,
+                ]
+                .into_iter()
+                .chain(self.prevented_execution_price)
+                .chain(self.prevented_execution_quote_qty),
+            ));
+            encoder.qty_exponent(fit_exponent(
+                self.qty_exponent,
+                [
+                    self.orig_qty,
+                    self.iceberg_qty,
+                    self.executed_qty,
+                    self.last_qty,
+                ]
+                .into_iter()
+                .chain(self.prevented_qty)
+                .chain(self.last_prevented_qty)
+                .chain(self.prevented_execution_qty),
+            ));
+            encoder.commission_exponent(fit_exponent(self.commission_exponent, [self.commission]));
+            encoder.order_creation_time(self.order_creation_time);
+            encoder.working_time(self.working_time.unwrap_or(i64::MIN));
+            encoder.order_id(self.order_id);
+            encoder.order_list_id(self.order_list_id.unwrap_or(i64::MIN));
+            encoder.orig_qty_decimal(self.orig_qty)?;
+            encoder.price_decimal(self.price)?;
+            encoder.orig_quote_order_qty_decimal(self.orig_quote_order_qty)?;
+            encoder.iceberg_qty_decimal(self.iceberg_qty)?;
+            encoder.stop_price_decimal(self.stop_price)?;
+            encoder.order_type(self.order_type);
+            encoder.side(self.side);
+            encoder.time_in_force(self.time_in_force);
+            encoder.execution_type(self.execution_type);
+            encoder.order_status(self.order_status);
+            encoder.trade_id(self.trade_id.unwrap_or(i64::MIN));
+            encoder.execution_id(self.execution_id);
+            encoder.executed_qty_decimal(self.executed_qty)?;
+            encoder.cummulative_quote_qty_decimal(self.cummulative_quote_qty)?;
+            encoder.last_qty_decimal(self.last_qty)?;
+            encoder.last_price_decimal(self.last_price)?;
+            encoder.quote_qty_decimal(self.quote_qty)?;
+            encoder.commission_decimal(self.commission)?;
+            encoder.is_working(self.is_working);
+            encoder.is_maker(self.is_maker);
+            encoder.is_best_match(self.is_best_match);
+            encoder.match_type(self.match_type);
+            encoder.self_trade_prevention_mode(self.self_trade_prevention_mode);
+            encoder.order_capacity(self.order_capacity);
+            encoder.working_floor(self.working_floor);
+            encoder.used_sor(self.used_sor);
+            encoder.alloc_id(self.alloc_id.unwrap_or(i64::MIN));
+            encoder.trailing_delta(self.trailing_delta.unwrap_or(u64::MAX));
+            encoder.trailing_time(self.trailing_time.unwrap_or(i64::MIN));
+            encoder.trade_group_id(self.trade_group_id.unwrap_or(i64::MIN));
+            encoder.prevented_qty_decimal(self.prevented_qty)?;
+            encoder.last_prevented_qty_decimal(self.last_prevented_qty)?;
+            encoder.prevented_match_id(self.prevented_match_id.unwrap_or(i64::MIN));
+            encoder.prevented_execution_qty_decimal(self.prevented_execution_qty)?;
+            encoder.prevented_execution_price_decimal(self.prevented_execution_price)?;
+            encoder.prevented_execution_quote_qty_decimal(self.prevented_execution_quote_qty)?;
+            encoder.strategy_type(self.strategy_type.unwrap_or(i32::MIN));
+            encoder.strategy_id(self.strategy_id.unwrap_or(i64::MIN));
+            encoder.counter_order_id(self.counter_order_id.unwrap_or(i64::MIN));
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            let value = &self.client_order_id;
+            checked_length::<u8>(value.len())?;
+            encoder.client_order_id(value);
+            let value = self.orig_client_order_id.as_deref().unwrap_or_default();
+            checked_length::<u8>(value.len())?;
+            encoder.orig_client_order_id(value);
+            let value = self.commission_asset.as_deref().unwrap_or_default();
+            checked_length::<u8>(value.len())?;
+            encoder.commission_asset(value);
+            let value = self.reject_reason.as_deref().unwrap_or_default();
+            checked_length::<u8>(value.len())?;
+            encoder.reject_reason(value);
+            let value = self.counter_symbol.as_deref().unwrap_or_default();
+            checked_length::<u8>(value.len())?;
+            encoder.counter_symbol(value);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            274 + self.symbol.len()
+                + self.client_order_id.len()
+                + self
+                    .orig_client_order_id
+                    .as_ref()
+                    .map_or(0, |value| value.len())
+                + self
+                    .commission_asset
+                    .as_ref()
+                    .map_or(0, |value| value.len())
+                + self.reject_reason.as_ref().map_or(0, |value| value.len())
+                + self.counter_symbol.as_ref().map_or(0, |value| value.len())
+        }
+    }
+
+    impl OwnedMessage for ExecutionReportEvent {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = ExecutionReportEventDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = ExecutionReportEventEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
@@ -0,0 +1,103 @@
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExternalSettlementFlags(pub u64);
+impl ExternalSettlementFlags {
+    #[inline]
+    pub fn new(value: u64) -> Self {
+        ExternalSettlementFlags(value)
+    }
+
+    #[inline]
+    pub fn clear(&mut self) -> &mut Self {
+        self.0 = 0;
+        self
+    }
+
+    #[inline]
+    pub fn get_move_to_available(&self) -> bool {
+        0 != self.0 & (1 << 0)
+    }
+
+    #[inline]
+    pub fn set_move_to_available(&mut self, value: bool) -> &mut Self {
+        self.0 = if value {
+            self.0 | (1 << 0)
+        } else {
+            self.0 & !(1 << 0)
+        };
+        self
+    }
+
+    #[inline]
+    pub fn get_debit_commission_allow_gas(&self) -> bool {
+        0 != self.0 & (1 << 1)
+    }
+
+    #[inline]
+    pub fn set_debit_commission_allow_gas(&mut self, value: bool) -> &mut Self {
+        self.0 = if value {
+            self.0 | (1 << 1)
+        } else {
+            self.0 & !(1 << 1)
+        };
+        self
+    }
+
+    #[inline]
+    pub fn get_credit_commission_allow_gas(&self) -> bool {
+        0 != self.0 & (1 << 2)
+    }
+
+    #[inline]
+    pub fn set_credit_commission_allow_gas(&mut self, value: bool) -> &mut Self {
+        self.0 = if value {
+            self.0 | (1 << 2)
+        } else {
+            self.0 & !(1 << 2)
+        };
+        self
+    }
+
+    #[inline]
+    pub fn get_debit_commission_allow_available(&self) -> bool {
+        0 != self.0 & (1 << 3)
+    }
+
+    #[inline]
+    pub fn set_debit_commission_allow_available(&mut self, value: bool) -> &mut Self {
+        self.0 = if value {
+            self.0 | (1 << 3)
+        } else {
+            self.0 & !(1 << 3)
+        };
+        self
+    }
+
+    #[inline]
+    pub fn get_credit_commission_allow_available(&self) -> bool {
+        0 != self.0 & (1 << 4)
+    }
+
+    #[inline]
+    pub fn set_credit_commission_allow_available(&mut self, value: bool) -> &mut Self {
+        self.0 = if value {
+            self.0 | (1 << 4)
+        } else {
+            self.0 & !(1 << 4)
+        };
+        self
+    }
+}
+impl From<u64> for ExternalSettlementFlags {
+    #[inline]
+    fn from(v: u64) -> Self {
+        ExternalSettlementFlags(v)
+    }
+}
+impl core::fmt::Debug for ExternalSettlementFlags {
+    #[inline]
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(fmt, "ExternalSettlementFlags[move_to_available(0)={},debit_commission_allow_gas(1)={},credit_commission_allow_gas(2)={},debit_commission_allow_available(3)={},credit_commission_allow_available(4)={}]",
+            self.get_move_to_available(),self.get_debit_commission_allow_gas(),self.get_credit_commission_allow_gas(),self.get_debit_commission_allow_available(),self.get_credit_commission_allow_available(),)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::*;
+use super::*;
 
 pub use decoder::GroupSize16EncodingDecoder;
 pub use encoder::GroupSize16EncodingEncoder;
@@ -96,10 +96,11 @@ pub mod decoder {
     where
         P: Reader<'a> + Default,
     {
-        pub fn wrap(mut self, parent: P, offset: usize) -> Self {
+        pub fn wrap(mut self, parent: P, offset: usize) -> SbeResult<Self> {
+            parent.get_buf().check_bounds(offset, ENCODED_LENGTH)?;
             self.parent = Some(parent);
             self.offset = offset;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -0,0 +1,1025 @@
+use super::*;
+
+pub use decoder::KlinesResponseDecoder;
+pub use encoder::KlinesResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::KlinesResponse;
+
+pub const SBE_BLOCK_LENGTH: u16 = 2;
+pub const SBE_TEMPLATE_ID: u16 = 203;
+pub const SBE_SCHEMA_ID: u16 = 2;
+pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SEMANTIC_VERSION: &str = "5.2";
+
+pub mod encoder {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct KlinesResponseEncoder<'a> {
+        buf: WriteBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+    }
+
+    impl<'a> Writer<'a> for KlinesResponseEncoder<'a> {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            &mut self.buf
+        }
+    }
+
+    impl<'a> Encoder<'a> for KlinesResponseEncoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+    }
+
+    impl<'a> KlinesResponseEncoder<'a> {
+        pub fn wrap(mut self, buf: WriteBuf<'a>, offset: usize) -> Self {
+            let limit = offset + SBE_BLOCK_LENGTH as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self
+        }
+
+        #[inline]
+        pub fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        pub fn header(self, offset: usize) -> MessageHeaderEncoder<Self> {
+            let mut header = MessageHeaderEncoder::default().wrap(self, offset);
+            header.block_length(SBE_BLOCK_LENGTH);
+            header.template_id(SBE_TEMPLATE_ID);
+            header.schema_id(SBE_SCHEMA_ID);
+            header.version(SBE_SCHEMA_VERSION);
+            header
+        }
+
+        /// primitive field 'priceExponent'
+        /// - min value: -127
+        /// - max value: 127
+        /// - null value: -128
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 0
+        /// - encodedLength: 1
+        #[inline]
+        pub fn price_exponent(&mut self, value: i8) {
+            let offset = self.offset;
+            self.get_buf_mut().put_i8_at(offset, value);
+        }
+
+        /// primitive field 'qtyExponent'
+        /// - min value: -127
+        /// - max value: 127
+        /// - null value: -128
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 1
+        /// - encodedLength: 1
+        #[inline]
+        pub fn qty_exponent(&mut self, value: i8) {
+            let offset = self.offset + 1;
+            self.get_buf_mut().put_i8_at(offset, value);
+        }
+
+        /// GROUP ENCODER (id=100)
+        #[inline]
+        pub fn klines_encoder(
+            self,
+            count: u32,
+            klines_encoder: KlinesEncoder<Self>,
+        ) -> KlinesEncoder<Self> {
+            klines_encoder.wrap(self, count)
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct KlinesEncoder<P> {
+        parent: Option<P>,
+        count: u32,
+        index: usize,
+        offset: usize,
+        initial_limit: usize,
+    }
+
+    impl<'a, P> Writer<'a> for KlinesEncoder<P>
+    where
+        P: Writer<'a> + Default,
+    {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            if let Some(parent) = self.parent.as_mut() {
+                parent.get_buf_mut()
+            } else {
+                panic!("parent was None")
+            }
+        }
+    }
+
+    impl<'a, P> Encoder<'a> for KlinesEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.parent.as_ref().expect("parent missing").get_limit()
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.parent
+                .as_mut()
+                .expect("parent missing")
+                .set_limit(limit);
+        }
+    }
+
+    impl<'a, P> KlinesEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        pub fn wrap(mut self, mut parent: P, count: u32) -> Self {
+            let initial_limit = parent.get_limit();
+            parent.set_limit(initial_limit + 6);
+            parent
+                .get_buf_mut()
+                .put_u16_at(initial_limit, Self::block_length());
+            parent.get_buf_mut().put_u32_at(initial_limit + 2, count);
+            self.parent = Some(parent);
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = usize::MAX;
+            self.initial_limit = initial_limit;
+            self
+        }
+
+        #[inline]
+        pub fn block_length() -> u16 {
+            120
+        }
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }
+
+        /// will return Some(current index) when successful otherwise None
+        #[inline]
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+            if let Some(parent) = self.parent.as_mut() {
+                self.offset = parent.get_limit();
+                parent.set_limit(self.offset + Self::block_length() as usize);
+                self.index = index;
+                Ok(Some(index))
+            } else {
+                Err(SbeErr::ParentNotSet)
+            }
+        }
+
+        /// primitive field 'openTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 0
+        /// - encodedLength: 8
+        #[inline]
+        pub fn open_time(&mut self, value: i64) {
+            let offset = self.offset;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'openPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 8
+        /// - encodedLength: 8
+        #[inline]
+        pub fn open_price(&mut self, value: i64) {
+            let offset = self.offset + 8;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'highPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 16
+        /// - encodedLength: 8
+        #[inline]
+        pub fn high_price(&mut self, value: i64) {
+            let offset = self.offset + 16;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'lowPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 24
+        /// - encodedLength: 8
+        #[inline]
+        pub fn low_price(&mut self, value: i64) {
+            let offset = self.offset + 24;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'closePrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 32
+        /// - encodedLength: 8
+        #[inline]
+        pub fn close_price(&mut self, value: i64) {
+            let offset = self.offset + 32;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// mantissa128 field 'volume' as a little-endian i128
+        /// - null value: -170141183460469231731687303715884105728
+        /// - encodedOffset: 40
+        /// - encodedLength: 16
+        /// - version: 0
+        #[inline]
+        pub fn volume(&mut self, value: Option<i128>) {
+            let offset = self.offset + 40;
+            self.get_buf_mut()
+                .put_i128_at(offset, value.unwrap_or(i128::MIN));
+        }
+
+        /// primitive field 'closeTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 56
+        /// - encodedLength: 8
+        #[inline]
+        pub fn close_time(&mut self, value: i64) {
+            let offset = self.offset + 56;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// mantissa128 field 'quoteVolume' as a little-endian i128
+        /// - null value: -170141183460469231731687303715884105728
+        /// - encodedOffset: 64
+        /// - encodedLength: 16
+        /// - version: 0
+        #[inline]
+        pub fn quote_volume(&mut self, value: Option<i128>) {
+            let offset = self.offset + 64;
+            self.get_buf_mut()
+                .put_i128_at(offset, value.unwrap_or(i128::MIN));
+        }
+
+        /// primitive field 'numTrades'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 80
+        /// - encodedLength: 8
+        #[inline]
+        pub fn num_trades(&mut self, value: i64) {
+            let offset = self.offset + 80;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// mantissa128 field 'takerBuyBaseVolume' as a little-endian i128
+        /// - null value: -170141183460469231731687303715884105728
+        /// - encodedOffset: 88
+        /// - encodedLength: 16
+        /// - version: 0
+        #[inline]
+        pub fn taker_buy_base_volume(&mut self, value: Option<i128>) {
+            let offset = self.offset + 88;
+            self.get_buf_mut()
+                .put_i128_at(offset, value.unwrap_or(i128::MIN));
+        }
+
+        /// mantissa128 field 'takerBuyQuoteVolume' as a little-endian i128
+        /// - null value: -170141183460469231731687303715884105728
+        /// - encodedOffset: 104
+        /// - encodedLength: 16
+        /// - version: 0
+        #[inline]
+        pub fn taker_buy_quote_volume(&mut self, value: Option<i128>) {
+            let offset = self.offset + 104;
+            self.get_buf_mut()
+                .put_i128_at(offset, value.unwrap_or(i128::MIN));
+        }
+    }
+
+    impl KlinesEncoder<KlinesResponseEncoder<'_>> {
+        /// 'openPrice' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn open_price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.open_price(mantissa);
+            Ok(())
+        }
+
+        /// 'highPrice' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn high_price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.high_price(mantissa);
+            Ok(())
+        }
+
+        /// 'lowPrice' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn low_price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.low_price(mantissa);
+            Ok(())
+        }
+
+        /// 'closePrice' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn close_price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.close_price(mantissa);
+            Ok(())
+        }
+
+        /// 'volume' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn volume_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.volume(None);
+                return Ok(());
+            };
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .map(|value| value.mantissa())
+                .filter(|&mantissa| mantissa != i128::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.volume(Some(mantissa));
+            Ok(())
+        }
+
+        /// 'quoteVolume' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn quote_volume_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.quote_volume(None);
+                return Ok(());
+            };
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .map(|value| value.mantissa())
+                .filter(|&mantissa| mantissa != i128::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.quote_volume(Some(mantissa));
+            Ok(())
+        }
+
+        /// 'takerBuyBaseVolume' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn taker_buy_base_volume_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.taker_buy_base_volume(None);
+                return Ok(());
+            };
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .map(|value| value.mantissa())
+                .filter(|&mantissa| mantissa != i128::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.taker_buy_base_volume(Some(mantissa));
+            Ok(())
+        }
+
+        /// 'takerBuyQuoteVolume' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn taker_buy_quote_volume_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.taker_buy_quote_volume(None);
+                return Ok(());
+            };
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .map(|value| value.mantissa())
+                .filter(|&mantissa| mantissa != i128::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.taker_buy_quote_volume(Some(mantissa));
+            Ok(())
+        }
+    }
+} // end encoder
+
+pub mod decoder {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct KlinesResponseDecoder<'a> {
+        buf: ReadBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+        pub acting_block_length: u16,
+        pub acting_version: u16,
+    }
+
+    impl<'a> Reader<'a> for KlinesResponseDecoder<'a> {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            &self.buf
+        }
+    }
+
+    impl<'a> Decoder<'a> for KlinesResponseDecoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
+    }
+
+    impl<'a> KlinesResponseDecoder<'a> {
+        pub fn wrap(
+            mut self,
+            buf: ReadBuf<'a>,
+            offset: usize,
+            acting_block_length: u16,
+            acting_version: u16,
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
+            let limit = offset + acting_block_length as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self.acting_block_length = acting_block_length;
+            self.acting_version = acting_version;
+            Ok(self)
+        }
+
+        #[inline]
+        pub fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
+            let acting_block_length = header.block_length();
+            let acting_version = header.version();
+
+            self.wrap(
+                header.parent()?,
+                message_header_codec::ENCODED_LENGTH,
+                acting_block_length,
+                acting_version,
+            )
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn price_exponent(&self) -> i8 {
+            self.get_buf().get_i8_at(self.offset)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn qty_exponent(&self) -> i8 {
+            self.get_buf().get_i8_at(self.offset + 1)
+        }
+
+        /// GROUP DECODER (id=100)
+        #[inline]
+        pub fn klines_decoder(self) -> SbeResult<KlinesDecoder<'a, Self>> {
+            KlinesDecoder::default().wrap(self)
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct KlinesDecoder<'a, P> {
+        parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
+        block_length: usize,
+        count: u32,
+        index: usize,
+        offset: usize,
+    }
+
+    impl<'a, P> Reader<'a> for KlinesDecoder<'a, P>
+    where
+        P: Reader<'a> + Default,
+    {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            &self.buf
+        }
+    }
+
+    impl<'a, P> Decoder<'a> for KlinesDecoder<'a, P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
+    }
+
+    impl<'a, P> KlinesDecoder<'a, P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
+            let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
+            let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
+            let count = parent.get_buf().get_u32_at(initial_offset + 2);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 120;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
+            self.parent = Some(parent);
+            self.block_length = block_length;
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = 0;
+            Ok(self)
+        }
+
+        /// group token - Token{signal=BEGIN_GROUP, name='klines', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=120, offset=2, componentTokenCount=39, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
+        }
+
+        #[inline]
+        pub fn count(&self) -> u32 {
+            self.count
+        }
+
+        /// will return Some(current index) when successful otherwise None
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(120))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn open_time(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn open_price(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 8)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn high_price(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 16)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn low_price(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 24)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn close_price(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 32)
+        }
+
+        /// mantissa128 field - little-endian i128 { null_value: '-170141183460469231731687303715884105728' }
+        #[inline]
+        pub fn volume(&self) -> Option<i128> {
+            let value = self.get_buf().get_i128_at(self.offset + 40);
+            if value == -170141183460469231731687303715884105728_i128 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn close_time(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 56)
+        }
+
+        /// mantissa128 field - little-endian i128 { null_value: '-170141183460469231731687303715884105728' }
+        #[inline]
+        pub fn quote_volume(&self) -> Option<i128> {
+            let value = self.get_buf().get_i128_at(self.offset + 64);
+            if value == -170141183460469231731687303715884105728_i128 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn num_trades(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 80)
+        }
+
+        /// mantissa128 field - little-endian i128 { null_value: '-170141183460469231731687303715884105728' }
+        #[inline]
+        pub fn taker_buy_base_volume(&self) -> Option<i128> {
+            let value = self.get_buf().get_i128_at(self.offset + 88);
+            if value == -170141183460469231731687303715884105728_i128 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// mantissa128 field - little-endian i128 { null_value: '-170141183460469231731687303715884105728' }
+        #[inline]
+        pub fn taker_buy_quote_volume(&self) -> Option<i128> {
+            let value = self.get_buf().get_i128_at(self.offset + 104);
+            if value == -170141183460469231731687303715884105728_i128 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+    }
+
+    impl KlinesDecoder<'_, KlinesResponseDecoder<'_>> {
+        /// 'openPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn open_price_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.open_price(), exponent))
+        }
+
+        /// 'highPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn high_price_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.high_price(), exponent))
+        }
+
+        /// 'lowPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn low_price_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.low_price(), exponent))
+        }
+
+        /// 'closePrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn close_price_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.close_price(), exponent))
+        }
+
+        /// 'volume' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn volume_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(self
+                .volume()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+
+        /// 'quoteVolume' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn quote_volume_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(self
+                .quote_volume()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+
+        /// 'takerBuyBaseVolume' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn taker_buy_base_volume_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(self
+                .taker_buy_base_volume()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+
+        /// 'takerBuyQuoteVolume' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn taker_buy_quote_volume_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(self
+                .taker_buy_quote_volume()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+    }
+} // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct KlinesResponse {
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub klines: Vec<Klines>,
+    }
+
+    impl KlinesResponse {
+        pub fn from_decoder(decoder: &mut KlinesResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                klines: {
+                    let mut klines_decoder = core::mem::take(decoder).klines_decoder()?;
+                    let mut entries = Vec::new();
+                    while klines_decoder.advance()?.is_some() {
+                        entries.push(Klines::from_decoder(&mut klines_decoder)?);
+                    }
+                    *decoder = klines_decoder.parent()?;
+                    entries
+                },
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut KlinesResponseEncoder<'_>) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                self.klines
+                    .iter()
+                    .map(|entry| entry.open_price)
+                    .chain(self.klines.iter().map(|entry| entry.high_price))
+                    .chain(self.klines.iter().map(|entry| entry.low_price))
+                    .chain(self.klines.iter().map(|entry| entry.close_price))
+                    .chain(self.klines.iter().filter_map(|entry| entry.quote_volume))
+                    .chain(
+                        self.klines
+                            .iter()
+                            .filter_map(|entry| entry.taker_buy_quote_volume),
+                    ),
+            ));
+            encoder.qty_exponent(fit_exponent(
+                self.qty_exponent,
+                self.klines.iter().filter_map(|entry| entry.volume).chain(
+                    self.klines
+                        .iter()
+                        .filter_map(|entry| entry.taker_buy_base_volume),
+                ),
+            ));
+            let count = checked_length::<u32>(self.klines.len())?;
+            let mut klines_encoder =
+                core::mem::take(encoder).klines_encoder(count, KlinesEncoder::default());
+            for entry in &self.klines {
+                klines_encoder.advance()?;
+                entry.to_encoder(&mut klines_encoder)?;
+            }
+            *encoder = klines_encoder.parent()?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            8 + self
+                .klines
+                .iter()
+                .map(Klines::encoded_length)
+                .sum::<usize>()
+        }
+    }
+
+    impl OwnedMessage for KlinesResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = KlinesResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = KlinesResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Klines {
+        pub open_time: i64,
+        pub open_price: Decimal,
+        pub high_price: Decimal,
+        pub low_price: Decimal,
+        pub close_price: Decimal,
+        pub volume: Option<Decimal>,
+        pub close_time: i64,
+        pub quote_volume: Option<Decimal>,
+        pub num_trades: i64,
+        pub taker_buy_base_volume: Option<Decimal>,
+        pub taker_buy_quote_volume: Option<Decimal>,
+    }
+
+    impl Klines {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut KlinesDecoder<'a, KlinesResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                open_time: decoder.open_time(),
+                open_price: decoder.open_price_decimal()?,
+                high_price: decoder.high_price_decimal()?,
+                low_price: decoder.low_price_decimal()?,
+                close_price: decoder.close_price_decimal()?,
+                volume: decoder.volume_decimal()?,
+                close_time: decoder.close_time(),
+                quote_volume: decoder.quote_volume_decimal()?,
+                num_trades: decoder.num_trades(),
+                taker_buy_base_volume: decoder.taker_buy_base_volume_decimal()?,
+                taker_buy_quote_volume: decoder.taker_buy_quote_volume_decimal()?,
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut KlinesEncoder<KlinesResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.open_time(self.open_time);
+            encoder.open_price_decimal(self.open_price)?;
+            encoder.high_price_decimal(self.high_price)?;
+            encoder.low_price_decimal(self.low_price)?;
+            encoder.close_price_decimal(self.close_price)?;
+            encoder.volume_decimal(self.volume)?;
+            encoder.close_time(self.close_time);
+            encoder.quote_volume_decimal(self.quote_volume)?;
+            encoder.num_trades(self.num_trades);
+            encoder.taker_buy_base_volume_decimal(self.taker_buy_base_volume)?;
+            encoder.taker_buy_quote_volume_decimal(self.taker_buy_quote_volume)?;
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            120
+        }
+    }
+} // end owned
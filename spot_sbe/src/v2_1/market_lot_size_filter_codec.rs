@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::MarketLotSizeFilterDecoder;
 pub use encoder::MarketLotSizeFilterEncoder;
+#[cfg(feature = "serde")]
+pub use owned::MarketLotSizeFilter;
 
 pub const SBE_BLOCK_LENGTH: u16 = 25;
 pub const SBE_TEMPLATE_ID: u16 = 8;
@@ -93,6 +95,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'minQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn min_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.min_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'maxQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -107,6 +123,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'maxQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn max_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.max_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'stepSize'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -120,6 +150,20 @@ pub mod encoder {
             let offset = self.offset + 17;
             self.get_buf_mut().put_i64_at(offset, value);
         }
+
+        /// 'stepSize' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn step_size_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.step_size(mantissa);
+            Ok(())
+        }
     }
 } // end encoder
 
@@ -153,6 +197,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> MarketLotSizeFilterDecoder<'a> {
@@ -162,7 +211,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -170,7 +220,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -178,13 +228,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -209,16 +265,99 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 1)
         }
 
+        /// 'minQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn min_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.min_qty(), self.qty_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn max_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 9)
         }
 
+        /// 'maxQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn max_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.max_qty(), self.qty_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn step_size(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 17)
         }
+
+        /// 'stepSize' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn step_size_decimal(&self) -> Decimal {
+            Decimal::new(self.step_size(), self.qty_exponent())
+        }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct MarketLotSizeFilter {
+        pub qty_exponent: i8,
+        pub min_qty: Decimal,
+        pub max_qty: Decimal,
+        pub step_size: Decimal,
+    }
+
+    impl MarketLotSizeFilter {
+        pub fn from_decoder(decoder: &mut MarketLotSizeFilterDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                qty_exponent: decoder.qty_exponent(),
+                min_qty: decoder.min_qty_decimal(),
+                max_qty: decoder.max_qty_decimal(),
+                step_size: decoder.step_size_decimal(),
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut MarketLotSizeFilterEncoder<'_>) -> SbeResult<()> {
+            encoder.qty_exponent(fit_exponent(
+                self.qty_exponent,
+                [self.min_qty, self.max_qty, self.step_size],
+            ));
+            encoder.min_qty_decimal(self.min_qty)?;
+            encoder.max_qty_decimal(self.max_qty)?;
+            encoder.step_size_decimal(self.step_size)?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            25
+        }
+    }
+
+    impl OwnedMessage for MarketLotSizeFilter {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = MarketLotSizeFilterDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = MarketLotSizeFilterEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
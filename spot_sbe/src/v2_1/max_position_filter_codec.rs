@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::MaxPositionFilterDecoder;
 pub use encoder::MaxPositionFilterEncoder;
+#[cfg(feature = "serde")]
+pub use owned::MaxPositionFilter;
 
 pub const SBE_BLOCK_LENGTH: u16 = 9;
 pub const SBE_TEMPLATE_ID: u16 = 12;
@@ -92,6 +94,20 @@ pub mod encoder {
             let offset = self.offset + 1;
             self.get_buf_mut().put_i64_at(offset, value);
         }
+
+        /// 'maxPosition' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn max_position_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.max_position(mantissa);
+            Ok(())
+        }
     }
 } // end encoder
 
@@ -125,6 +141,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> MaxPositionFilterDecoder<'a> {
@@ -134,7 +155,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -142,7 +164,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -150,13 +172,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -180,5 +208,67 @@ pub mod decoder {
         pub fn max_position(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 1)
         }
+
+        /// 'maxPosition' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn max_position_decimal(&self) -> Decimal {
+            Decimal::new(self.max_position(), self.qty_exponent())
+        }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct MaxPositionFilter {
+        pub qty_exponent: i8,
+        pub max_position: Decimal,
+    }
+
+    impl MaxPositionFilter {
+        pub fn from_decoder(decoder: &mut MaxPositionFilterDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                qty_exponent: decoder.qty_exponent(),
+                max_position: decoder.max_position_decimal(),
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut MaxPositionFilterEncoder<'_>) -> SbeResult<()> {
+            encoder.qty_exponent(fit_exponent(self.qty_exponent, [self.max_position]));
+            encoder.max_position_decimal(self.max_position)?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            9
+        }
+    }
+
+    impl OwnedMessage for MaxPositionFilter {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = MaxPositionFilterDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = MaxPositionFilterEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
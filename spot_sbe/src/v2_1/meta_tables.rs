@@ -0,0 +1,13722 @@
+//! Generated from spot_2_1.xml, see `crate::meta`.
+
+use crate::meta::*;
+
+pub const SCHEMA_ID: u16 = 2;
+pub const SCHEMA_VERSION: u16 = 1;
+
+pub static ACCOUNT_TYPE: EnumMeta = EnumMeta {
+    name: "accountType",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "Spot",
+            value: 0,
+            since_version: 0,
+            json_value: Some("SPOT"),
+        },
+        EnumValue {
+            name: "Unknown",
+            value: 2,
+            since_version: 0,
+            json_value: Some("UNKNOWN"),
+        },
+    ],
+};
+
+pub static ALLOCATION_REPORT_TYPE: EnumMeta = EnumMeta {
+    name: "allocationReportType",
+    encoding: Primitive::U8,
+    since_version: 1,
+    values: &[EnumValue {
+        name: "Accept",
+        value: 1,
+        since_version: 0,
+        json_value: None,
+    }],
+};
+
+pub static ALLOCATION_STATUS: EnumMeta = EnumMeta {
+    name: "allocationStatus",
+    encoding: Primitive::U8,
+    since_version: 1,
+    values: &[EnumValue {
+        name: "Accepted",
+        value: 1,
+        since_version: 0,
+        json_value: None,
+    }],
+};
+
+pub static ALLOCATION_TRANSACTION_TYPE: EnumMeta = EnumMeta {
+    name: "allocationTransactionType",
+    encoding: Primitive::U8,
+    since_version: 1,
+    values: &[EnumValue {
+        name: "New",
+        value: 1,
+        since_version: 0,
+        json_value: None,
+    }],
+};
+
+pub static ALLOCATION_TYPE: EnumMeta = EnumMeta {
+    name: "allocationType",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "Unknown",
+            value: 0,
+            since_version: 0,
+            json_value: Some("UNKNOWN"),
+        },
+        EnumValue {
+            name: "Sor",
+            value: 2,
+            since_version: 0,
+            json_value: Some("SOR"),
+        },
+    ],
+};
+
+pub static BOOL_ENUM: EnumMeta = EnumMeta {
+    name: "boolEnum",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "False",
+            value: 0,
+            since_version: 0,
+            json_value: None,
+        },
+        EnumValue {
+            name: "True",
+            value: 1,
+            since_version: 0,
+            json_value: None,
+        },
+    ],
+};
+
+pub static CANCEL_REPLACE_STATUS: EnumMeta = EnumMeta {
+    name: "cancelReplaceStatus",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "Success",
+            value: 0,
+            since_version: 0,
+            json_value: Some("SUCCESS"),
+        },
+        EnumValue {
+            name: "Failure",
+            value: 1,
+            since_version: 0,
+            json_value: Some("FAILURE"),
+        },
+        EnumValue {
+            name: "NotAttempted",
+            value: 2,
+            since_version: 0,
+            json_value: Some("NOT_ATTEMPTED"),
+        },
+    ],
+};
+
+pub static COMMISSION_TYPE: EnumMeta = EnumMeta {
+    name: "commissionType",
+    encoding: Primitive::U8,
+    since_version: 1,
+    values: &[
+        EnumValue {
+            name: "RecvAsset",
+            value: 0,
+            since_version: 0,
+            json_value: Some("RECV_ASSET"),
+        },
+        EnumValue {
+            name: "QuoteAsset",
+            value: 1,
+            since_version: 0,
+            json_value: Some("QUOTE_ASSET"),
+        },
+        EnumValue {
+            name: "GasOrRecvStrict",
+            value: 2,
+            since_version: 0,
+            json_value: Some("GAS_OR_RECV_STRICT"),
+        },
+        EnumValue {
+            name: "GasOrRecvOptional",
+            value: 3,
+            since_version: 0,
+            json_value: Some("GAS_OR_RECV_OPTIONAL"),
+        },
+    ],
+};
+
+pub static CONTINGENCY_TYPE: EnumMeta = EnumMeta {
+    name: "contingencyType",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "Oco",
+            value: 1,
+            since_version: 0,
+            json_value: Some("OCO"),
+        },
+        EnumValue {
+            name: "Oto",
+            value: 2,
+            since_version: 0,
+            json_value: Some("OTO"),
+        },
+    ],
+};
+
+pub static COUNTER_PARTY_ROLE: EnumMeta = EnumMeta {
+    name: "counterPartyRole",
+    encoding: Primitive::U8,
+    since_version: 1,
+    values: &[EnumValue {
+        name: "Client",
+        value: 1,
+        since_version: 0,
+        json_value: None,
+    }],
+};
+
+pub static EXECUTION_TYPE: EnumMeta = EnumMeta {
+    name: "executionType",
+    encoding: Primitive::U8,
+    since_version: 1,
+    values: &[
+        EnumValue {
+            name: "New",
+            value: 0,
+            since_version: 0,
+            json_value: Some("NEW"),
+        },
+        EnumValue {
+            name: "Canceled",
+            value: 1,
+            since_version: 0,
+            json_value: Some("CANCELED"),
+        },
+        EnumValue {
+            name: "Replaced",
+            value: 2,
+            since_version: 0,
+            json_value: Some("REPLACED"),
+        },
+        EnumValue {
+            name: "Rejected",
+            value: 3,
+            since_version: 0,
+            json_value: Some("REJECTED"),
+        },
+        EnumValue {
+            name: "Trade",
+            value: 4,
+            since_version: 0,
+            json_value: Some("TRADE"),
+        },
+        EnumValue {
+            name: "Expired",
+            value: 5,
+            since_version: 0,
+            json_value: Some("EXPIRED"),
+        },
+        EnumValue {
+            name: "TradePrevention",
+            value: 8,
+            since_version: 0,
+            json_value: Some("TRADE_PREVENTION"),
+        },
+        EnumValue {
+            name: "Unknown",
+            value: 254,
+            since_version: 0,
+            json_value: Some("UNKNOWN"),
+        },
+    ],
+};
+
+pub static EXTERNAL_SETTLEMENT_SIDE: EnumMeta = EnumMeta {
+    name: "externalSettlementSide",
+    encoding: Primitive::U8,
+    since_version: 1,
+    values: &[
+        EnumValue {
+            name: "Debit",
+            value: 0,
+            since_version: 0,
+            json_value: Some("DEBIT"),
+        },
+        EnumValue {
+            name: "Credit",
+            value: 1,
+            since_version: 0,
+            json_value: Some("CREDIT"),
+        },
+    ],
+};
+
+pub static FILTER_TYPE: EnumMeta = EnumMeta {
+    name: "filterType",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "MaxPosition",
+            value: 0,
+            since_version: 0,
+            json_value: Some("MAX_POSITION"),
+        },
+        EnumValue {
+            name: "PriceFilter",
+            value: 1,
+            since_version: 0,
+            json_value: Some("PRICE_FILTER"),
+        },
+        EnumValue {
+            name: "TPlusSell",
+            value: 2,
+            since_version: 0,
+            json_value: Some("T_PLUS_SELL"),
+        },
+        EnumValue {
+            name: "LotSize",
+            value: 3,
+            since_version: 0,
+            json_value: Some("LOT_SIZE"),
+        },
+        EnumValue {
+            name: "MaxNumOrders",
+            value: 4,
+            since_version: 0,
+            json_value: Some("MAX_NUM_ORDERS"),
+        },
+        EnumValue {
+            name: "MinNotional",
+            value: 5,
+            since_version: 0,
+            json_value: Some("MIN_NOTIONAL"),
+        },
+        EnumValue {
+            name: "MaxNumAlgoOrders",
+            value: 6,
+            since_version: 0,
+            json_value: Some("MAX_NUM_ALGO_ORDERS"),
+        },
+        EnumValue {
+            name: "ExchangeMaxNumOrders",
+            value: 7,
+            since_version: 0,
+            json_value: Some("EXCHANGE_MAX_NUM_ORDERS"),
+        },
+        EnumValue {
+            name: "ExchangeMaxNumAlgoOrders",
+            value: 8,
+            since_version: 0,
+            json_value: Some("EXCHANGE_MAX_NUM_ALGO_ORDERS"),
+        },
+        EnumValue {
+            name: "IcebergParts",
+            value: 9,
+            since_version: 0,
+            json_value: Some("ICEBERG_PARTS"),
+        },
+        EnumValue {
+            name: "MarketLotSize",
+            value: 10,
+            since_version: 0,
+            json_value: Some("MARKET_LOT_SIZE"),
+        },
+        EnumValue {
+            name: "PercentPrice",
+            value: 11,
+            since_version: 0,
+            json_value: Some("PERCENT_PRICE"),
+        },
+        EnumValue {
+            name: "MaxNumIcebergOrders",
+            value: 12,
+            since_version: 0,
+            json_value: Some("MAX_NUM_ICEBERG_ORDERS"),
+        },
+        EnumValue {
+            name: "ExchangeMaxNumIcebergOrders",
+            value: 13,
+            since_version: 0,
+            json_value: Some("EXCHANGE_MAX_NUM_ICEBERG_ORDERS"),
+        },
+        EnumValue {
+            name: "TrailingDelta",
+            value: 14,
+            since_version: 0,
+            json_value: Some("TRAILING_DELTA"),
+        },
+        EnumValue {
+            name: "PercentPriceBySide",
+            value: 15,
+            since_version: 0,
+            json_value: Some("PERCENT_PRICE_BY_SIDE"),
+        },
+        EnumValue {
+            name: "Notional",
+            value: 16,
+            since_version: 0,
+            json_value: Some("NOTIONAL"),
+        },
+    ],
+};
+
+pub static FLOOR: EnumMeta = EnumMeta {
+    name: "floor",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "Exchange",
+            value: 1,
+            since_version: 0,
+            json_value: Some("EXCHANGE"),
+        },
+        EnumValue {
+            name: "Broker",
+            value: 2,
+            since_version: 0,
+            json_value: Some("BROKER"),
+        },
+        EnumValue {
+            name: "Sor",
+            value: 3,
+            since_version: 0,
+            json_value: Some("SOR"),
+        },
+    ],
+};
+
+pub static LIST_ORDER_STATUS: EnumMeta = EnumMeta {
+    name: "listOrderStatus",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "Canceling",
+            value: 0,
+            since_version: 0,
+            json_value: Some("CANCELING"),
+        },
+        EnumValue {
+            name: "Executing",
+            value: 1,
+            since_version: 0,
+            json_value: Some("EXECUTING"),
+        },
+        EnumValue {
+            name: "AllDone",
+            value: 2,
+            since_version: 0,
+            json_value: Some("ALL_DONE"),
+        },
+        EnumValue {
+            name: "Reject",
+            value: 3,
+            since_version: 0,
+            json_value: Some("REJECT"),
+        },
+    ],
+};
+
+pub static LIST_STATUS_TYPE: EnumMeta = EnumMeta {
+    name: "listStatusType",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "Response",
+            value: 0,
+            since_version: 0,
+            json_value: Some("RESPONSE"),
+        },
+        EnumValue {
+            name: "ExecStarted",
+            value: 1,
+            since_version: 0,
+            json_value: Some("EXEC_STARTED"),
+        },
+        EnumValue {
+            name: "AllDone",
+            value: 2,
+            since_version: 0,
+            json_value: Some("ALL_DONE"),
+        },
+    ],
+};
+
+pub static MATCH_TYPE: EnumMeta = EnumMeta {
+    name: "matchType",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "AutoMatch",
+            value: 1,
+            since_version: 0,
+            json_value: Some("AUTO_MATCH"),
+        },
+        EnumValue {
+            name: "OnePartyTradeReport",
+            value: 2,
+            since_version: 0,
+            json_value: Some("ONE_PARTY_TRADE_REPORT"),
+        },
+    ],
+};
+
+pub static ORDER_CAPACITY: EnumMeta = EnumMeta {
+    name: "orderCapacity",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "Principal",
+            value: 1,
+            since_version: 0,
+            json_value: Some("PRINCIPAL"),
+        },
+        EnumValue {
+            name: "Agency",
+            value: 2,
+            since_version: 0,
+            json_value: Some("AGENCY"),
+        },
+    ],
+};
+
+pub static ORDER_SIDE: EnumMeta = EnumMeta {
+    name: "orderSide",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "Buy",
+            value: 0,
+            since_version: 0,
+            json_value: Some("BUY"),
+        },
+        EnumValue {
+            name: "Sell",
+            value: 1,
+            since_version: 0,
+            json_value: Some("SELL"),
+        },
+    ],
+};
+
+pub static ORDER_STATUS: EnumMeta = EnumMeta {
+    name: "orderStatus",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "New",
+            value: 0,
+            since_version: 0,
+            json_value: Some("NEW"),
+        },
+        EnumValue {
+            name: "PartiallyFilled",
+            value: 1,
+            since_version: 0,
+            json_value: Some("PARTIALLY_FILLED"),
+        },
+        EnumValue {
+            name: "Filled",
+            value: 2,
+            since_version: 0,
+            json_value: Some("FILLED"),
+        },
+        EnumValue {
+            name: "Canceled",
+            value: 3,
+            since_version: 0,
+            json_value: Some("CANCELED"),
+        },
+        EnumValue {
+            name: "PendingCancel",
+            value: 4,
+            since_version: 0,
+            json_value: Some("PENDING_CANCEL"),
+        },
+        EnumValue {
+            name: "Rejected",
+            value: 5,
+            since_version: 0,
+            json_value: Some("REJECTED"),
+        },
+        EnumValue {
+            name: "Expired",
+            value: 6,
+            since_version: 0,
+            json_value: Some("EXPIRED"),
+        },
+        EnumValue {
+            name: "ExpiredInMatch",
+            value: 9,
+            since_version: 0,
+            json_value: Some("EXPIRED_IN_MATCH"),
+        },
+        EnumValue {
+            name: "PendingNew",
+            value: 11,
+            since_version: 0,
+            json_value: Some("PENDING_NEW"),
+        },
+        EnumValue {
+            name: "Unknown",
+            value: 254,
+            since_version: 0,
+            json_value: Some("UNKNOWN"),
+        },
+    ],
+};
+
+pub static ORDER_TYPE: EnumMeta = EnumMeta {
+    name: "orderType",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "Market",
+            value: 0,
+            since_version: 0,
+            json_value: Some("MARKET"),
+        },
+        EnumValue {
+            name: "Limit",
+            value: 1,
+            since_version: 0,
+            json_value: Some("LIMIT"),
+        },
+        EnumValue {
+            name: "StopLoss",
+            value: 2,
+            since_version: 0,
+            json_value: Some("STOP_LOSS"),
+        },
+        EnumValue {
+            name: "StopLossLimit",
+            value: 3,
+            since_version: 0,
+            json_value: Some("STOP_LOSS_LIMIT"),
+        },
+        EnumValue {
+            name: "TakeProfit",
+            value: 4,
+            since_version: 0,
+            json_value: Some("TAKE_PROFIT"),
+        },
+        EnumValue {
+            name: "TakeProfitLimit",
+            value: 5,
+            since_version: 0,
+            json_value: Some("TAKE_PROFIT_LIMIT"),
+        },
+        EnumValue {
+            name: "LimitMaker",
+            value: 6,
+            since_version: 0,
+            json_value: Some("LIMIT_MAKER"),
+        },
+    ],
+};
+
+pub static RATE_LIMIT_INTERVAL: EnumMeta = EnumMeta {
+    name: "rateLimitInterval",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "Second",
+            value: 0,
+            since_version: 0,
+            json_value: Some("SECOND"),
+        },
+        EnumValue {
+            name: "Minute",
+            value: 1,
+            since_version: 0,
+            json_value: Some("MINUTE"),
+        },
+        EnumValue {
+            name: "Hour",
+            value: 2,
+            since_version: 0,
+            json_value: Some("HOUR"),
+        },
+        EnumValue {
+            name: "Day",
+            value: 3,
+            since_version: 0,
+            json_value: Some("DAY"),
+        },
+    ],
+};
+
+pub static RATE_LIMIT_TYPE: EnumMeta = EnumMeta {
+    name: "rateLimitType",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "RawRequests",
+            value: 0,
+            since_version: 0,
+            json_value: Some("RAW_REQUESTS"),
+        },
+        EnumValue {
+            name: "Connections",
+            value: 1,
+            since_version: 0,
+            json_value: Some("CONNECTIONS"),
+        },
+        EnumValue {
+            name: "RequestWeight",
+            value: 2,
+            since_version: 0,
+            json_value: Some("REQUEST_WEIGHT"),
+        },
+        EnumValue {
+            name: "Orders",
+            value: 3,
+            since_version: 0,
+            json_value: Some("ORDERS"),
+        },
+    ],
+};
+
+pub static SELF_TRADE_PREVENTION_MODE: EnumMeta = EnumMeta {
+    name: "selfTradePreventionMode",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "None",
+            value: 1,
+            since_version: 0,
+            json_value: Some("NONE"),
+        },
+        EnumValue {
+            name: "ExpireTaker",
+            value: 2,
+            since_version: 0,
+            json_value: Some("EXPIRE_TAKER"),
+        },
+        EnumValue {
+            name: "ExpireMaker",
+            value: 3,
+            since_version: 0,
+            json_value: Some("EXPIRE_MAKER"),
+        },
+        EnumValue {
+            name: "ExpireBoth",
+            value: 4,
+            since_version: 0,
+            json_value: Some("EXPIRE_BOTH"),
+        },
+    ],
+};
+
+pub static SYMBOL_STATUS: EnumMeta = EnumMeta {
+    name: "symbolStatus",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "PreTrading",
+            value: 0,
+            since_version: 0,
+            json_value: Some("PRE_TRADING"),
+        },
+        EnumValue {
+            name: "Trading",
+            value: 1,
+            since_version: 0,
+            json_value: Some("TRADING"),
+        },
+        EnumValue {
+            name: "PostTrading",
+            value: 2,
+            since_version: 0,
+            json_value: Some("POST_TRADING"),
+        },
+        EnumValue {
+            name: "EndOfDay",
+            value: 3,
+            since_version: 0,
+            json_value: Some("END_OF_DAY"),
+        },
+        EnumValue {
+            name: "Halt",
+            value: 4,
+            since_version: 0,
+            json_value: Some("HALT"),
+        },
+        EnumValue {
+            name: "AuctionMatch",
+            value: 5,
+            since_version: 0,
+            json_value: Some("AUCTION_MATCH"),
+        },
+        EnumValue {
+            name: "Break",
+            value: 7,
+            since_version: 0,
+            json_value: Some("BREAK"),
+        },
+    ],
+};
+
+pub static TIME_IN_FORCE: EnumMeta = EnumMeta {
+    name: "timeInForce",
+    encoding: Primitive::U8,
+    since_version: 0,
+    values: &[
+        EnumValue {
+            name: "Gtc",
+            value: 0,
+            since_version: 0,
+            json_value: Some("GTC"),
+        },
+        EnumValue {
+            name: "Ioc",
+            value: 1,
+            since_version: 0,
+            json_value: Some("IOC"),
+        },
+        EnumValue {
+            name: "Fok",
+            value: 2,
+            since_version: 0,
+            json_value: Some("FOK"),
+        },
+    ],
+};
+
+pub static TRADE_TYPE: EnumMeta = EnumMeta {
+    name: "tradeType",
+    encoding: Primitive::U8,
+    since_version: 1,
+    values: &[EnumValue {
+        name: "BlockTrade",
+        value: 0,
+        since_version: 0,
+        json_value: Some("BLOCK_TRADE"),
+    }],
+};
+
+pub static ALLOWED_SELF_TRADE_PREVENTION_MODES: SetMeta = SetMeta {
+    name: "allowedSelfTradePreventionModes",
+    encoding: Primitive::U8,
+    since_version: 0,
+    choices: &[
+        SetChoice {
+            name: "None",
+            bit: 0,
+            json_value: Some("NONE"),
+        },
+        SetChoice {
+            name: "ExpireTaker",
+            bit: 1,
+            json_value: Some("EXPIRE_TAKER"),
+        },
+        SetChoice {
+            name: "ExpireMaker",
+            bit: 2,
+            json_value: Some("EXPIRE_MAKER"),
+        },
+        SetChoice {
+            name: "ExpireBoth",
+            bit: 3,
+            json_value: Some("EXPIRE_BOTH"),
+        },
+    ],
+};
+
+pub static EXTERNAL_SETTLEMENT_FLAGS: SetMeta = SetMeta {
+    name: "externalSettlementFlags",
+    encoding: Primitive::U64,
+    since_version: 1,
+    choices: &[
+        SetChoice {
+            name: "MoveToAvailable",
+            bit: 0,
+            json_value: Some("MOVE_TO_AVAILABLE"),
+        },
+        SetChoice {
+            name: "DebitCommissionAllowGas",
+            bit: 1,
+            json_value: Some("DEBIT_COMM_ALLOW_GAS"),
+        },
+        SetChoice {
+            name: "CreditCommissionAllowGas",
+            bit: 2,
+            json_value: Some("CREDIT_COMM_ALLOW_GAS"),
+        },
+        SetChoice {
+            name: "DebitCommissionAllowAvailable",
+            bit: 3,
+            json_value: Some("DEBIT_COMM_ALLOW_AVAILABLE"),
+        },
+        SetChoice {
+            name: "CreditCommissionAllowAvailable",
+            bit: 4,
+            json_value: Some("CREDIT_COMM_ALLOW_AVAILABLE"),
+        },
+    ],
+};
+
+pub static ORDER_TYPES: SetMeta = SetMeta {
+    name: "orderTypes",
+    encoding: Primitive::U16,
+    since_version: 0,
+    choices: &[
+        SetChoice {
+            name: "Market",
+            bit: 0,
+            json_value: Some("MARKET"),
+        },
+        SetChoice {
+            name: "Limit",
+            bit: 1,
+            json_value: Some("LIMIT"),
+        },
+        SetChoice {
+            name: "StopLoss",
+            bit: 2,
+            json_value: Some("STOP_LOSS"),
+        },
+        SetChoice {
+            name: "StopLossLimit",
+            bit: 3,
+            json_value: Some("STOP_LOSS_LIMIT"),
+        },
+        SetChoice {
+            name: "TakeProfit",
+            bit: 4,
+            json_value: Some("TAKE_PROFIT"),
+        },
+        SetChoice {
+            name: "TakeProfitLimit",
+            bit: 5,
+            json_value: Some("TAKE_PROFIT_LIMIT"),
+        },
+        SetChoice {
+            name: "LimitMaker",
+            bit: 6,
+            json_value: Some("LIMIT_MAKER"),
+        },
+    ],
+};
+
+pub static PRICE_FILTER: MessageMeta = MessageMeta {
+    name: "PriceFilter",
+    id: 1,
+    block_length: 25,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("PriceFilter"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "priceExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "minPrice",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "maxPrice",
+            id: 4,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 9,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "tickSize",
+            id: 5,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 17,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static PERCENT_PRICE_FILTER: MessageMeta = MessageMeta {
+    name: "PercentPriceFilter",
+    id: 2,
+    block_length: 21,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("PercentPrice"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "multiplierExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "multiplierUp",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 8,
+            since_version: 0,
+            exponent: Some("multiplierExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "multiplierDown",
+            id: 4,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 9,
+            length: 8,
+            since_version: 0,
+            exponent: Some("multiplierExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "avgPriceMins",
+            id: 5,
+            field_type: FieldType::Primitive(Primitive::I32),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 17,
+            length: 4,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static PERCENT_PRICE_BY_SIDE_FILTER: MessageMeta = MessageMeta {
+    name: "PercentPriceBySideFilter",
+    id: 3,
+    block_length: 37,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("PercentPriceBySide"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "multiplierExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "bidMultiplierUp",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 8,
+            since_version: 0,
+            exponent: Some("multiplierExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "bidMultiplierDown",
+            id: 4,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 9,
+            length: 8,
+            since_version: 0,
+            exponent: Some("multiplierExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "askMultiplierUp",
+            id: 5,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 17,
+            length: 8,
+            since_version: 0,
+            exponent: Some("multiplierExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "askMultiplierDown",
+            id: 6,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 25,
+            length: 8,
+            since_version: 0,
+            exponent: Some("multiplierExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "avgPriceMins",
+            id: 7,
+            field_type: FieldType::Primitive(Primitive::I32),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 33,
+            length: 4,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static LOT_SIZE_FILTER: MessageMeta = MessageMeta {
+    name: "LotSizeFilter",
+    id: 4,
+    block_length: 25,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("LotSize"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "minQty",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "maxQty",
+            id: 4,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 9,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "stepSize",
+            id: 5,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 17,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static MIN_NOTIONAL_FILTER: MessageMeta = MessageMeta {
+    name: "MinNotionalFilter",
+    id: 5,
+    block_length: 14,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("MinNotional"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "priceExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "minNotional",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "applyToMarket",
+            id: 4,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 9,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "avgPriceMins",
+            id: 5,
+            field_type: FieldType::Primitive(Primitive::I32),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 10,
+            length: 4,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static NOTIONAL_FILTER: MessageMeta = MessageMeta {
+    name: "NotionalFilter",
+    id: 6,
+    block_length: 23,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("Notional"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "priceExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "minNotional",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "applyMinToMarket",
+            id: 4,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 9,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "maxNotional",
+            id: 5,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 10,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "applyMaxToMarket",
+            id: 6,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 18,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "avgPriceMins",
+            id: 7,
+            field_type: FieldType::Primitive(Primitive::I32),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 19,
+            length: 4,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static ICEBERG_PARTS_FILTER: MessageMeta = MessageMeta {
+    name: "IcebergPartsFilter",
+    id: 7,
+    block_length: 8,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("IcebergParts"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "filterLimit",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("limit"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static MARKET_LOT_SIZE_FILTER: MessageMeta = MessageMeta {
+    name: "MarketLotSizeFilter",
+    id: 8,
+    block_length: 25,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("MarketLotSize"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "minQty",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "maxQty",
+            id: 4,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 9,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "stepSize",
+            id: 5,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 17,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static MAX_NUM_ORDERS_FILTER: MessageMeta = MessageMeta {
+    name: "MaxNumOrdersFilter",
+    id: 9,
+    block_length: 8,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("MaxNumOrders"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "maxNumOrders",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static MAX_NUM_ALGO_ORDERS_FILTER: MessageMeta = MessageMeta {
+    name: "MaxNumAlgoOrdersFilter",
+    id: 10,
+    block_length: 8,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("MaxNumAlgoOrders"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "maxNumAlgoOrders",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static MAX_NUM_ICEBERG_ORDERS_FILTER: MessageMeta = MessageMeta {
+    name: "MaxNumIcebergOrdersFilter",
+    id: 11,
+    block_length: 8,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("MaxNumIcebergOrders"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "maxNumIcebergOrders",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static MAX_POSITION_FILTER: MessageMeta = MessageMeta {
+    name: "MaxPositionFilter",
+    id: 12,
+    block_length: 9,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("MaxPosition"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "maxPosition",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static TRAILING_DELTA_FILTER: MessageMeta = MessageMeta {
+    name: "TrailingDeltaFilter",
+    id: 13,
+    block_length: 32,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("TrailingDelta"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "minTrailingAboveDelta",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "maxTrailingAboveDelta",
+            id: 3,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "minTrailingBelowDelta",
+            id: 4,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 16,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "maxTrailingBelowDelta",
+            id: 5,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 24,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static TPLUS_SELL_FILTER: MessageMeta = MessageMeta {
+    name: "TPlusSellFilter",
+    id: 14,
+    block_length: 8,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("TPlusSell"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "endTime",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static EXCHANGE_MAX_NUM_ORDERS_FILTER: MessageMeta = MessageMeta {
+    name: "ExchangeMaxNumOrdersFilter",
+    id: 15,
+    block_length: 8,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("ExchangeMaxNumOrders"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "maxNumOrders",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static EXCHANGE_MAX_NUM_ALGO_ORDERS_FILTER: MessageMeta = MessageMeta {
+    name: "ExchangeMaxNumAlgoOrdersFilter",
+    id: 16,
+    block_length: 8,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("ExchangeMaxNumAlgoOrders"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "maxNumAlgoOrders",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static EXCHANGE_MAX_NUM_ICEBERG_ORDERS_FILTER: MessageMeta = MessageMeta {
+    name: "ExchangeMaxNumIcebergOrdersFilter",
+    id: 17,
+    block_length: 8,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "filterType",
+            id: 1,
+            field_type: FieldType::Enum(&FILTER_TYPE),
+            presence: Presence::Constant,
+            value_ref: Some("ExchangeMaxNumIcebergOrders"),
+            offset: 0,
+            length: 0,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "maxNumIcebergOrders",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static WEB_SOCKET_RESPONSE: MessageMeta = MessageMeta {
+    name: "WebSocketResponse",
+    id: 50,
+    block_length: 3,
+    since_version: 0,
+    description: Some("Message wrapper for WebSocket API."),
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "sbeSchemaIdVersionDeprecated",
+            id: 1,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "status",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::U16),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 2,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[GroupMeta {
+        name: "rateLimits",
+        id: 100,
+        block_length: 19,
+        num_in_group_type: Primitive::U16,
+        since_version: 0,
+        json_path: None,
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "rateLimitType",
+                id: 1,
+                field_type: FieldType::Enum(&RATE_LIMIT_TYPE),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "interval",
+                id: 2,
+                field_type: FieldType::Enum(&RATE_LIMIT_INTERVAL),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 1,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "intervalNum",
+                id: 3,
+                field_type: FieldType::Primitive(Primitive::U8),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 2,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "rateLimit",
+                id: 4,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 3,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("limit"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "current",
+                id: 5,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 11,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("count"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[],
+    }],
+    data: &[
+        DataMeta {
+            name: "id",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "result",
+            id: 201,
+            data_type: DataType::Message,
+            length_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+        },
+    ],
+};
+
+pub static WEB_SOCKET_SESSION_LOGON_RESPONSE: MessageMeta = MessageMeta {
+    name: "WebSocketSessionLogonResponse",
+    id: 51,
+    block_length: 26,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "authorizedSince",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "connectedSince",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "returnRateLimits",
+            id: 3,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 16,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "serverTime",
+            id: 4,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 17,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "userDataStream",
+            id: 5,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 25,
+            length: 1,
+            since_version: 1,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[DataMeta {
+        name: "apiKey",
+        id: 200,
+        data_type: DataType::String,
+        length_type: Primitive::U16,
+        since_version: 0,
+        json_path: None,
+    }],
+};
+
+pub static WEB_SOCKET_SESSION_STATUS_RESPONSE: MessageMeta = MessageMeta {
+    name: "WebSocketSessionStatusResponse",
+    id: 52,
+    block_length: 26,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "authorizedSince",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "connectedSince",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "returnRateLimits",
+            id: 3,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 16,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "serverTime",
+            id: 4,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 17,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "userDataStream",
+            id: 5,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 25,
+            length: 1,
+            since_version: 1,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[DataMeta {
+        name: "apiKey",
+        id: 200,
+        data_type: DataType::OptionalString,
+        length_type: Primitive::U16,
+        since_version: 0,
+        json_path: None,
+    }],
+};
+
+pub static WEB_SOCKET_SESSION_LOGOUT_RESPONSE: MessageMeta = MessageMeta {
+    name: "WebSocketSessionLogoutResponse",
+    id: 53,
+    block_length: 26,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "authorizedSince",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "connectedSince",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "returnRateLimits",
+            id: 3,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 16,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "serverTime",
+            id: 4,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 17,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "userDataStream",
+            id: 5,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 25,
+            length: 1,
+            since_version: 1,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[DataMeta {
+        name: "apiKey",
+        id: 200,
+        data_type: DataType::OptionalString,
+        length_type: Primitive::U16,
+        since_version: 0,
+        json_path: None,
+    }],
+};
+
+pub static ERROR_RESPONSE: MessageMeta = MessageMeta {
+    name: "ErrorResponse",
+    id: 100,
+    block_length: 18,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "code",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I16),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 2,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "serverTime",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 2,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "retryAfter",
+            id: 3,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 10,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[
+        DataMeta {
+            name: "msg",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U16,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "data",
+            id: 201,
+            data_type: DataType::OptionalMessage,
+            length_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+        },
+    ],
+};
+
+pub static PING_RESPONSE: MessageMeta = MessageMeta {
+    name: "PingResponse",
+    id: 101,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[],
+    data: &[],
+};
+
+pub static SERVER_TIME_RESPONSE: MessageMeta = MessageMeta {
+    name: "ServerTimeResponse",
+    id: 102,
+    block_length: 8,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[FieldMeta {
+        name: "serverTime",
+        id: 1,
+        field_type: FieldType::Primitive(Primitive::I64),
+        presence: Presence::Required,
+        value_ref: None,
+        offset: 0,
+        length: 8,
+        since_version: 0,
+        exponent: None,
+        json_path: None,
+        json_default_value: None,
+        json_omit_null: false,
+    }],
+    groups: &[],
+    data: &[],
+};
+
+pub static EXCHANGE_INFO_RESPONSE: MessageMeta = MessageMeta {
+    name: "ExchangeInfoResponse",
+    id: 103,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[
+        GroupMeta {
+            name: "rateLimits",
+            id: 100,
+            block_length: 11,
+            num_in_group_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[
+                FieldMeta {
+                    name: "rateLimitType",
+                    id: 1,
+                    field_type: FieldType::Enum(&RATE_LIMIT_TYPE),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 0,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "interval",
+                    id: 2,
+                    field_type: FieldType::Enum(&RATE_LIMIT_INTERVAL),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 1,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "intervalNum",
+                    id: 3,
+                    field_type: FieldType::Primitive(Primitive::U8),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 2,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "rateLimit",
+                    id: 4,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 3,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: Some("limit"),
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+            ],
+            groups: &[],
+            data: &[],
+        },
+        GroupMeta {
+            name: "exchangeFilters",
+            id: 101,
+            block_length: 0,
+            num_in_group_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[],
+            groups: &[],
+            data: &[DataMeta {
+                name: "filter",
+                id: 200,
+                data_type: DataType::Message,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: Some(".."),
+            }],
+        },
+        GroupMeta {
+            name: "symbols",
+            id: 102,
+            block_length: 17,
+            num_in_group_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[
+                FieldMeta {
+                    name: "status",
+                    id: 1,
+                    field_type: FieldType::Enum(&SYMBOL_STATUS),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 0,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "baseAssetPrecision",
+                    id: 2,
+                    field_type: FieldType::Primitive(Primitive::U8),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 1,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "quoteAssetPrecision",
+                    id: 3,
+                    field_type: FieldType::Primitive(Primitive::U8),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 2,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "baseCommissionPrecision",
+                    id: 4,
+                    field_type: FieldType::Primitive(Primitive::U8),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 3,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "quoteCommissionPrecision",
+                    id: 5,
+                    field_type: FieldType::Primitive(Primitive::U8),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 4,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "orderTypes",
+                    id: 6,
+                    field_type: FieldType::Set(&ORDER_TYPES),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 5,
+                    length: 2,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "icebergAllowed",
+                    id: 7,
+                    field_type: FieldType::Enum(&BOOL_ENUM),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 7,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "ocoAllowed",
+                    id: 8,
+                    field_type: FieldType::Enum(&BOOL_ENUM),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 8,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "otoAllowed",
+                    id: 9,
+                    field_type: FieldType::Enum(&BOOL_ENUM),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 9,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "quoteOrderQtyMarketAllowed",
+                    id: 10,
+                    field_type: FieldType::Enum(&BOOL_ENUM),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 10,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "allowTrailingStop",
+                    id: 11,
+                    field_type: FieldType::Enum(&BOOL_ENUM),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 11,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "cancelReplaceAllowed",
+                    id: 12,
+                    field_type: FieldType::Enum(&BOOL_ENUM),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 12,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "isSpotTradingAllowed",
+                    id: 13,
+                    field_type: FieldType::Enum(&BOOL_ENUM),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 13,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "isMarginTradingAllowed",
+                    id: 14,
+                    field_type: FieldType::Enum(&BOOL_ENUM),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 14,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "defaultSelfTradePreventionMode",
+                    id: 15,
+                    field_type: FieldType::Enum(&SELF_TRADE_PREVENTION_MODE),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 15,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "allowedSelfTradePreventionModes",
+                    id: 16,
+                    field_type: FieldType::Set(&ALLOWED_SELF_TRADE_PREVENTION_MODES),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 16,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+            ],
+            groups: &[
+                GroupMeta {
+                    name: "filters",
+                    id: 100,
+                    block_length: 0,
+                    num_in_group_type: Primitive::U32,
+                    since_version: 0,
+                    json_path: None,
+                    json_omit_null: false,
+                    fields: &[],
+                    groups: &[],
+                    data: &[DataMeta {
+                        name: "filter",
+                        id: 200,
+                        data_type: DataType::Message,
+                        length_type: Primitive::U8,
+                        since_version: 0,
+                        json_path: Some(".."),
+                    }],
+                },
+                GroupMeta {
+                    name: "permissionSets",
+                    id: 101,
+                    block_length: 0,
+                    num_in_group_type: Primitive::U32,
+                    since_version: 0,
+                    json_path: None,
+                    json_omit_null: false,
+                    fields: &[],
+                    groups: &[GroupMeta {
+                        name: "permissions",
+                        id: 100,
+                        block_length: 0,
+                        num_in_group_type: Primitive::U32,
+                        since_version: 0,
+                        json_path: Some(".."),
+                        json_omit_null: false,
+                        fields: &[],
+                        groups: &[],
+                        data: &[DataMeta {
+                            name: "permission",
+                            id: 200,
+                            data_type: DataType::String,
+                            length_type: Primitive::U8,
+                            since_version: 0,
+                            json_path: Some(".."),
+                        }],
+                    }],
+                    data: &[],
+                },
+            ],
+            data: &[
+                DataMeta {
+                    name: "symbol",
+                    id: 200,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+                DataMeta {
+                    name: "baseAsset",
+                    id: 201,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+                DataMeta {
+                    name: "quoteAsset",
+                    id: 202,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+            ],
+        },
+        GroupMeta {
+            name: "sors",
+            id: 103,
+            block_length: 0,
+            num_in_group_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: true,
+            fields: &[],
+            groups: &[GroupMeta {
+                name: "sorSymbols",
+                id: 1,
+                block_length: 0,
+                num_in_group_type: Primitive::U32,
+                since_version: 0,
+                json_path: Some("symbols"),
+                json_omit_null: false,
+                fields: &[],
+                groups: &[],
+                data: &[DataMeta {
+                    name: "symbol",
+                    id: 200,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: Some(".."),
+                }],
+            }],
+            data: &[DataMeta {
+                name: "baseAsset",
+                id: 200,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            }],
+        },
+    ],
+    data: &[],
+};
+
+pub static DEPTH_RESPONSE: MessageMeta = MessageMeta {
+    name: "DepthResponse",
+    id: 200,
+    block_length: 10,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "lastUpdateId",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "priceExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 3,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 9,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[
+        GroupMeta {
+            name: "bids",
+            id: 100,
+            block_length: 16,
+            num_in_group_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[
+                FieldMeta {
+                    name: "price",
+                    id: 1,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 0,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("priceExponent"),
+                    json_path: Some("[]"),
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "qty",
+                    id: 2,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 8,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: Some("[]"),
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+            ],
+            groups: &[],
+            data: &[],
+        },
+        GroupMeta {
+            name: "asks",
+            id: 101,
+            block_length: 16,
+            num_in_group_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[
+                FieldMeta {
+                    name: "price",
+                    id: 1,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 0,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("priceExponent"),
+                    json_path: Some("[]"),
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "qty",
+                    id: 2,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 8,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: Some("[]"),
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+            ],
+            groups: &[],
+            data: &[],
+        },
+    ],
+    data: &[],
+};
+
+pub static TRADES_RESPONSE: MessageMeta = MessageMeta {
+    name: "TradesResponse",
+    id: 201,
+    block_length: 2,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "priceExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[GroupMeta {
+        name: "trades",
+        id: 100,
+        block_length: 42,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "id",
+                id: 1,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "price",
+                id: 2,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 8,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "qty",
+                id: 3,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 16,
+                length: 8,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "quoteQty",
+                id: 4,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 24,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "time",
+                id: 5,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 32,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "isBuyerMaker",
+                id: 6,
+                field_type: FieldType::Enum(&BOOL_ENUM),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 40,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "isBestMatch",
+                id: 7,
+                field_type: FieldType::Enum(&BOOL_ENUM),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 41,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[],
+    }],
+    data: &[],
+};
+
+pub static AGG_TRADES_RESPONSE: MessageMeta = MessageMeta {
+    name: "AggTradesResponse",
+    id: 202,
+    block_length: 2,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "priceExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[GroupMeta {
+        name: "aggTrades",
+        id: 100,
+        block_length: 50,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "aggTradeId",
+                id: 1,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("a"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "price",
+                id: 2,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 8,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: Some("p"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "qty",
+                id: 3,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 16,
+                length: 8,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: Some("q"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "firstTradeId",
+                id: 4,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 24,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("f"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lastTradeId",
+                id: 5,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 32,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("l"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "time",
+                id: 7,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 40,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("T"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "isBuyerMaker",
+                id: 8,
+                field_type: FieldType::Enum(&BOOL_ENUM),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 48,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("m"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "isBestMatch",
+                id: 9,
+                field_type: FieldType::Enum(&BOOL_ENUM),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 49,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("M"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[],
+    }],
+    data: &[],
+};
+
+pub static KLINES_RESPONSE: MessageMeta = MessageMeta {
+    name: "KlinesResponse",
+    id: 203,
+    block_length: 2,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "priceExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[GroupMeta {
+        name: "klines",
+        id: 100,
+        block_length: 120,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "openTime",
+                id: 1,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("[]"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "openPrice",
+                id: 2,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 8,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: Some("[]"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "highPrice",
+                id: 3,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 16,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: Some("[]"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lowPrice",
+                id: 4,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 24,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: Some("[]"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "closePrice",
+                id: 5,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 32,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: Some("[]"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "volume",
+                id: 6,
+                field_type: FieldType::Mantissa128,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 40,
+                length: 16,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: Some("[]"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "closeTime",
+                id: 7,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 56,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("[]"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "quoteVolume",
+                id: 8,
+                field_type: FieldType::Mantissa128,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 64,
+                length: 16,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: Some("[]"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "numTrades",
+                id: 9,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 80,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("[]"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "takerBuyBaseVolume",
+                id: 10,
+                field_type: FieldType::Mantissa128,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 88,
+                length: 16,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: Some("[]"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "takerBuyQuoteVolume",
+                id: 11,
+                field_type: FieldType::Mantissa128,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 104,
+                length: 16,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: Some("[]"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[],
+    }],
+    data: &[],
+};
+
+pub static AVERAGE_PRICE_RESPONSE: MessageMeta = MessageMeta {
+    name: "AveragePriceResponse",
+    id: 204,
+    block_length: 25,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "mins",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "priceExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "price",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 9,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "closeTime",
+            id: 4,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 17,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[],
+};
+
+pub static TICKER24H_SYMBOL_FULL_RESPONSE: MessageMeta = MessageMeta {
+    name: "Ticker24hSymbolFullResponse",
+    id: 205,
+    block_length: 182,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "priceExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "priceChange",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 2,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "priceChangePercent",
+            id: 4,
+            field_type: FieldType::Primitive(Primitive::F32),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 10,
+            length: 4,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("0.0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "weightedAvgPrice",
+            id: 5,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 14,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "prevClosePrice",
+            id: 6,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 22,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lastPrice",
+            id: 7,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 30,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lastQty",
+            id: 8,
+            field_type: FieldType::Mantissa128,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 38,
+            length: 16,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "bidPrice",
+            id: 9,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 54,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "bidQty",
+            id: 10,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 62,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "askPrice",
+            id: 11,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 70,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "askQty",
+            id: 12,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 78,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "openPrice",
+            id: 13,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 86,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "highPrice",
+            id: 14,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 94,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lowPrice",
+            id: 15,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 102,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "volume",
+            id: 16,
+            field_type: FieldType::Mantissa128,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 110,
+            length: 16,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "quoteVolume",
+            id: 17,
+            field_type: FieldType::Mantissa128,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 126,
+            length: 16,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "openTime",
+            id: 18,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 142,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "closeTime",
+            id: 19,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 150,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "firstId",
+            id: 20,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 158,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lastId",
+            id: 21,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 166,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "numTrades",
+            id: 22,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 174,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("count"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[DataMeta {
+        name: "symbol",
+        id: 200,
+        data_type: DataType::String,
+        length_type: Primitive::U8,
+        since_version: 0,
+        json_path: None,
+    }],
+};
+
+pub static TICKER24H_FULL_RESPONSE: MessageMeta = MessageMeta {
+    name: "Ticker24hFullResponse",
+    id: 206,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[GroupMeta {
+        name: "tickers",
+        id: 100,
+        block_length: 182,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "priceExponent",
+                id: 1,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "qtyExponent",
+                id: 2,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 1,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "priceChange",
+                id: 3,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 2,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "priceChangePercent",
+                id: 4,
+                field_type: FieldType::Primitive(Primitive::F32),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 10,
+                length: 4,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: Some("0.0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "weightedAvgPrice",
+                id: 5,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 14,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "prevClosePrice",
+                id: 6,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 22,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lastPrice",
+                id: 7,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 30,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lastQty",
+                id: 8,
+                field_type: FieldType::Mantissa128,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 38,
+                length: 16,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "bidPrice",
+                id: 9,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 54,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "bidQty",
+                id: 10,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 62,
+                length: 8,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "askPrice",
+                id: 11,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 70,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "askQty",
+                id: 12,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 78,
+                length: 8,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "openPrice",
+                id: 13,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 86,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "highPrice",
+                id: 14,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 94,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lowPrice",
+                id: 15,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 102,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "volume",
+                id: 16,
+                field_type: FieldType::Mantissa128,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 110,
+                length: 16,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "quoteVolume",
+                id: 17,
+                field_type: FieldType::Mantissa128,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 126,
+                length: 16,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "openTime",
+                id: 18,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 142,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "closeTime",
+                id: 19,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 150,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "firstId",
+                id: 20,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 158,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: Some("-1"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lastId",
+                id: 21,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 166,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: Some("-1"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "numTrades",
+                id: 22,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 174,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("count"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[DataMeta {
+            name: "symbol",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        }],
+    }],
+    data: &[],
+};
+
+pub static TICKER24H_SYMBOL_MINI_RESPONSE: MessageMeta = MessageMeta {
+    name: "Ticker24hSymbolMiniResponse",
+    id: 207,
+    block_length: 106,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "priceExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "openPrice",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 2,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "highPrice",
+            id: 4,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 10,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lowPrice",
+            id: 5,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 18,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lastPrice",
+            id: 6,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 26,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "volume",
+            id: 7,
+            field_type: FieldType::Mantissa128,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 34,
+            length: 16,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "quoteVolume",
+            id: 8,
+            field_type: FieldType::Mantissa128,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 50,
+            length: 16,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "openTime",
+            id: 9,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 66,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "closeTime",
+            id: 10,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 74,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "firstId",
+            id: 11,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 82,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lastId",
+            id: 12,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 90,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "numTrades",
+            id: 13,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 98,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("count"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[DataMeta {
+        name: "symbol",
+        id: 200,
+        data_type: DataType::String,
+        length_type: Primitive::U8,
+        since_version: 0,
+        json_path: None,
+    }],
+};
+
+pub static TICKER24H_MINI_RESPONSE: MessageMeta = MessageMeta {
+    name: "Ticker24hMiniResponse",
+    id: 208,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[GroupMeta {
+        name: "tickers",
+        id: 100,
+        block_length: 106,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "priceExponent",
+                id: 1,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "qtyExponent",
+                id: 2,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 1,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "openPrice",
+                id: 3,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 2,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "highPrice",
+                id: 4,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 10,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lowPrice",
+                id: 5,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 18,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lastPrice",
+                id: 6,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 26,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "volume",
+                id: 7,
+                field_type: FieldType::Mantissa128,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 34,
+                length: 16,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "quoteVolume",
+                id: 8,
+                field_type: FieldType::Mantissa128,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 50,
+                length: 16,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "openTime",
+                id: 9,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 66,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "closeTime",
+                id: 10,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 74,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "firstId",
+                id: 11,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 82,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: Some("-1"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lastId",
+                id: 12,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 90,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: Some("-1"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "numTrades",
+                id: 13,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 98,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("count"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[DataMeta {
+            name: "symbol",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        }],
+    }],
+    data: &[],
+};
+
+pub static PRICE_TICKER_SYMBOL_RESPONSE: MessageMeta = MessageMeta {
+    name: "PriceTickerSymbolResponse",
+    id: 209,
+    block_length: 9,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "priceExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "price",
+            id: 2,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 1,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[DataMeta {
+        name: "symbol",
+        id: 200,
+        data_type: DataType::String,
+        length_type: Primitive::U8,
+        since_version: 0,
+        json_path: None,
+    }],
+};
+
+pub static PRICE_TICKER_RESPONSE: MessageMeta = MessageMeta {
+    name: "PriceTickerResponse",
+    id: 210,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[GroupMeta {
+        name: "tickers",
+        id: 100,
+        block_length: 9,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "priceExponent",
+                id: 1,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "price",
+                id: 2,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 1,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[DataMeta {
+            name: "symbol",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        }],
+    }],
+    data: &[],
+};
+
+pub static BOOK_TICKER_SYMBOL_RESPONSE: MessageMeta = MessageMeta {
+    name: "BookTickerSymbolResponse",
+    id: 211,
+    block_length: 34,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "priceExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "bidPrice",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 2,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "bidQty",
+            id: 4,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 10,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "askPrice",
+            id: 5,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 18,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "askQty",
+            id: 6,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 26,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[DataMeta {
+        name: "symbol",
+        id: 200,
+        data_type: DataType::String,
+        length_type: Primitive::U8,
+        since_version: 0,
+        json_path: None,
+    }],
+};
+
+pub static BOOK_TICKER_RESPONSE: MessageMeta = MessageMeta {
+    name: "BookTickerResponse",
+    id: 212,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[GroupMeta {
+        name: "tickers",
+        id: 100,
+        block_length: 34,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "priceExponent",
+                id: 1,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "qtyExponent",
+                id: 2,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 1,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "bidPrice",
+                id: 3,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 2,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "bidQty",
+                id: 4,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 10,
+                length: 8,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "askPrice",
+                id: 5,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 18,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "askQty",
+                id: 6,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 26,
+                length: 8,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[DataMeta {
+            name: "symbol",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        }],
+    }],
+    data: &[],
+};
+
+pub static TICKER_SYMBOL_FULL_RESPONSE: MessageMeta = MessageMeta {
+    name: "TickerSymbolFullResponse",
+    id: 213,
+    block_length: 126,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "priceExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "priceChange",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 2,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "priceChangePercent",
+            id: 4,
+            field_type: FieldType::Primitive(Primitive::F32),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 10,
+            length: 4,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("0.0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "weightedAvgPrice",
+            id: 5,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 14,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "openPrice",
+            id: 6,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 22,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "highPrice",
+            id: 7,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 30,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lowPrice",
+            id: 8,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 38,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lastPrice",
+            id: 9,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 46,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "volume",
+            id: 10,
+            field_type: FieldType::Mantissa128,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 54,
+            length: 16,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "quoteVolume",
+            id: 11,
+            field_type: FieldType::Mantissa128,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 70,
+            length: 16,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "openTime",
+            id: 12,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 86,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "closeTime",
+            id: 13,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 94,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "firstId",
+            id: 14,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 102,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lastId",
+            id: 15,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 110,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "numTrades",
+            id: 16,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 118,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("count"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[DataMeta {
+        name: "symbol",
+        id: 200,
+        data_type: DataType::String,
+        length_type: Primitive::U8,
+        since_version: 0,
+        json_path: None,
+    }],
+};
+
+pub static TICKER_FULL_RESPONSE: MessageMeta = MessageMeta {
+    name: "TickerFullResponse",
+    id: 214,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[GroupMeta {
+        name: "tickers",
+        id: 100,
+        block_length: 126,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "priceExponent",
+                id: 1,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "qtyExponent",
+                id: 2,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 1,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "priceChange",
+                id: 3,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 2,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "priceChangePercent",
+                id: 4,
+                field_type: FieldType::Primitive(Primitive::F32),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 10,
+                length: 4,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: Some("0.0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "weightedAvgPrice",
+                id: 5,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 14,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "openPrice",
+                id: 6,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 22,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "highPrice",
+                id: 7,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 30,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lowPrice",
+                id: 8,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 38,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lastPrice",
+                id: 9,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 46,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "volume",
+                id: 10,
+                field_type: FieldType::Mantissa128,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 54,
+                length: 16,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "quoteVolume",
+                id: 11,
+                field_type: FieldType::Mantissa128,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 70,
+                length: 16,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "openTime",
+                id: 12,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 86,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "closeTime",
+                id: 13,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 94,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "firstId",
+                id: 14,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 102,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: Some("-1"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lastId",
+                id: 15,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 110,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: Some("-1"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "numTrades",
+                id: 16,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 118,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("count"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[DataMeta {
+            name: "symbol",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        }],
+    }],
+    data: &[],
+};
+
+pub static TICKER_SYMBOL_MINI_RESPONSE: MessageMeta = MessageMeta {
+    name: "TickerSymbolMiniResponse",
+    id: 215,
+    block_length: 106,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "priceExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "openPrice",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 2,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "highPrice",
+            id: 4,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 10,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lowPrice",
+            id: 5,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 18,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lastPrice",
+            id: 6,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 26,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: Some("0"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "volume",
+            id: 7,
+            field_type: FieldType::Mantissa128,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 34,
+            length: 16,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "quoteVolume",
+            id: 8,
+            field_type: FieldType::Mantissa128,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 50,
+            length: 16,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "openTime",
+            id: 9,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 66,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "closeTime",
+            id: 10,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 74,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "firstId",
+            id: 11,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 82,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lastId",
+            id: 12,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 90,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "numTrades",
+            id: 13,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 98,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("count"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[DataMeta {
+        name: "symbol",
+        id: 200,
+        data_type: DataType::String,
+        length_type: Primitive::U8,
+        since_version: 0,
+        json_path: None,
+    }],
+};
+
+pub static TICKER_MINI_RESPONSE: MessageMeta = MessageMeta {
+    name: "TickerMiniResponse",
+    id: 216,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[GroupMeta {
+        name: "tickers",
+        id: 100,
+        block_length: 106,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "priceExponent",
+                id: 1,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "qtyExponent",
+                id: 2,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 1,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "openPrice",
+                id: 3,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 2,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "highPrice",
+                id: 4,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 10,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lowPrice",
+                id: 5,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 18,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lastPrice",
+                id: 6,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 26,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: Some("0"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "volume",
+                id: 7,
+                field_type: FieldType::Mantissa128,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 34,
+                length: 16,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "quoteVolume",
+                id: 8,
+                field_type: FieldType::Mantissa128,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 50,
+                length: 16,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "openTime",
+                id: 9,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 66,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "closeTime",
+                id: 10,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 74,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "firstId",
+                id: 11,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 82,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: Some("-1"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "lastId",
+                id: 12,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 90,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: Some("-1"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "numTrades",
+                id: 13,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 98,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("count"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[DataMeta {
+            name: "symbol",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        }],
+    }],
+    data: &[],
+};
+
+pub static NEW_ORDER_ACK_RESPONSE: MessageMeta = MessageMeta {
+    name: "NewOrderAckResponse",
+    id: 300,
+    block_length: 24,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "orderId",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderListId",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 8,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "transactTime",
+            id: 3,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 16,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[
+        DataMeta {
+            name: "symbol",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "clientOrderId",
+            id: 201,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+    ],
+};
+
+pub static NEW_ORDER_RESULT_RESPONSE: MessageMeta = MessageMeta {
+    name: "NewOrderResultResponse",
+    id: 301,
+    block_length: 142,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "priceExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderId",
+            id: 3,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 2,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderListId",
+            id: 4,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 10,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "transactTime",
+            id: 5,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 18,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "price",
+            id: 6,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 26,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "origQty",
+            id: 7,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 34,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "executedQty",
+            id: 8,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 42,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "cummulativeQuoteQty",
+            id: 9,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 50,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "status",
+            id: 10,
+            field_type: FieldType::Enum(&ORDER_STATUS),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 58,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "timeInForce",
+            id: 11,
+            field_type: FieldType::Enum(&TIME_IN_FORCE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 59,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderType",
+            id: 12,
+            field_type: FieldType::Enum(&ORDER_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 60,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("type"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "side",
+            id: 13,
+            field_type: FieldType::Enum(&ORDER_SIDE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 61,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "stopPrice",
+            id: 14,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 62,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "trailingDelta",
+            id: 15,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 70,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "trailingTime",
+            id: 16,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 78,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "workingTime",
+            id: 17,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 86,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "icebergQty",
+            id: 18,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 94,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "strategyId",
+            id: 19,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 102,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "strategyType",
+            id: 20,
+            field_type: FieldType::Primitive(Primitive::I32),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 110,
+            length: 4,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderCapacity",
+            id: 21,
+            field_type: FieldType::Enum(&ORDER_CAPACITY),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 114,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "workingFloor",
+            id: 22,
+            field_type: FieldType::Enum(&FLOOR),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 115,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "selfTradePreventionMode",
+            id: 23,
+            field_type: FieldType::Enum(&SELF_TRADE_PREVENTION_MODE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 116,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "tradeGroupId",
+            id: 24,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 117,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "preventedQuantity",
+            id: 25,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 125,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "usedSor",
+            id: 26,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 133,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "origQuoteOrderQty",
+            id: 27,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 134,
+            length: 8,
+            since_version: 1,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[
+        DataMeta {
+            name: "symbol",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "clientOrderId",
+            id: 201,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+    ],
+};
+
+pub static NEW_ORDER_FULL_RESPONSE: MessageMeta = MessageMeta {
+    name: "NewOrderFullResponse",
+    id: 302,
+    block_length: 142,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "priceExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderId",
+            id: 3,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 2,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderListId",
+            id: 4,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 10,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "transactTime",
+            id: 5,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 18,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "price",
+            id: 6,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 26,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "origQty",
+            id: 7,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 34,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "executedQty",
+            id: 8,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 42,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "cummulativeQuoteQty",
+            id: 9,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 50,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "status",
+            id: 10,
+            field_type: FieldType::Enum(&ORDER_STATUS),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 58,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "timeInForce",
+            id: 11,
+            field_type: FieldType::Enum(&TIME_IN_FORCE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 59,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderType",
+            id: 12,
+            field_type: FieldType::Enum(&ORDER_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 60,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("type"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "side",
+            id: 13,
+            field_type: FieldType::Enum(&ORDER_SIDE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 61,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "stopPrice",
+            id: 14,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 62,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "trailingDelta",
+            id: 15,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 70,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "trailingTime",
+            id: 16,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 78,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "workingTime",
+            id: 17,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 86,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "icebergQty",
+            id: 18,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 94,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "strategyId",
+            id: 19,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 102,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "strategyType",
+            id: 20,
+            field_type: FieldType::Primitive(Primitive::I32),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 110,
+            length: 4,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderCapacity",
+            id: 21,
+            field_type: FieldType::Enum(&ORDER_CAPACITY),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 114,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "workingFloor",
+            id: 22,
+            field_type: FieldType::Enum(&FLOOR),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 115,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "selfTradePreventionMode",
+            id: 23,
+            field_type: FieldType::Enum(&SELF_TRADE_PREVENTION_MODE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 116,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "tradeGroupId",
+            id: 24,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 117,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "preventedQuantity",
+            id: 25,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 125,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "usedSor",
+            id: 26,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 133,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "origQuoteOrderQty",
+            id: 27,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 134,
+            length: 8,
+            since_version: 1,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[
+        GroupMeta {
+            name: "fills",
+            id: 100,
+            block_length: 42,
+            num_in_group_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[
+                FieldMeta {
+                    name: "commissionExponent",
+                    id: 1,
+                    field_type: FieldType::Exponent,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 0,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "matchType",
+                    id: 2,
+                    field_type: FieldType::Enum(&MATCH_TYPE),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 1,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "price",
+                    id: 3,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 2,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("priceExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "qty",
+                    id: 4,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 10,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "commission",
+                    id: 5,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 18,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("commissionExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "tradeId",
+                    id: 6,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 26,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "allocId",
+                    id: 7,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 34,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+            ],
+            groups: &[],
+            data: &[DataMeta {
+                name: "commissionAsset",
+                id: 200,
+                data_type: DataType::OptionalString,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            }],
+        },
+        GroupMeta {
+            name: "preventedMatches",
+            id: 101,
+            block_length: 40,
+            num_in_group_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[
+                FieldMeta {
+                    name: "preventedMatchId",
+                    id: 1,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 0,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "makerOrderId",
+                    id: 2,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 8,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "price",
+                    id: 3,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 16,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("priceExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "takerPreventedQuantity",
+                    id: 4,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 24,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "makerPreventedQuantity",
+                    id: 5,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 32,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+            ],
+            groups: &[],
+            data: &[DataMeta {
+                name: "makerSymbol",
+                id: 200,
+                data_type: DataType::OptionalString,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            }],
+        },
+    ],
+    data: &[
+        DataMeta {
+            name: "symbol",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "clientOrderId",
+            id: 201,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+    ],
+};
+
+pub static ORDER_TEST_RESPONSE: MessageMeta = MessageMeta {
+    name: "OrderTestResponse",
+    id: 303,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[],
+    data: &[],
+};
+
+pub static ORDER_RESPONSE: MessageMeta = MessageMeta {
+    name: "OrderResponse",
+    id: 304,
+    block_length: 151,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "priceExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderId",
+            id: 3,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 2,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderListId",
+            id: 4,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 10,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "price",
+            id: 5,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 18,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "origQty",
+            id: 6,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 26,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "executedQty",
+            id: 7,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 34,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "cummulativeQuoteQty",
+            id: 8,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 42,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "status",
+            id: 9,
+            field_type: FieldType::Enum(&ORDER_STATUS),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 50,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "timeInForce",
+            id: 10,
+            field_type: FieldType::Enum(&TIME_IN_FORCE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 51,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderType",
+            id: 11,
+            field_type: FieldType::Enum(&ORDER_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 52,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("type"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "side",
+            id: 12,
+            field_type: FieldType::Enum(&ORDER_SIDE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 53,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "stopPrice",
+            id: 13,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 54,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "trailingDelta",
+            id: 14,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 62,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "trailingTime",
+            id: 15,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 70,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "icebergQty",
+            id: 16,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 78,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "time",
+            id: 17,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 86,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "updateTime",
+            id: 18,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 94,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "isWorking",
+            id: 19,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 102,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "workingTime",
+            id: 20,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 103,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "origQuoteOrderQty",
+            id: 21,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 111,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "strategyId",
+            id: 22,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 119,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "strategyType",
+            id: 23,
+            field_type: FieldType::Primitive(Primitive::I32),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 127,
+            length: 4,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderCapacity",
+            id: 24,
+            field_type: FieldType::Enum(&ORDER_CAPACITY),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 131,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "workingFloor",
+            id: 25,
+            field_type: FieldType::Enum(&FLOOR),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 132,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "selfTradePreventionMode",
+            id: 26,
+            field_type: FieldType::Enum(&SELF_TRADE_PREVENTION_MODE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 133,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "preventedMatchId",
+            id: 27,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 134,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "preventedQuantity",
+            id: 28,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 142,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "usedSor",
+            id: 29,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 150,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[
+        DataMeta {
+            name: "symbol",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "clientOrderId",
+            id: 201,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+    ],
+};
+
+pub static CANCEL_ORDER_RESPONSE: MessageMeta = MessageMeta {
+    name: "CancelOrderResponse",
+    id: 305,
+    block_length: 126,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "priceExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderId",
+            id: 3,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 2,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderListId",
+            id: 4,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 10,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "transactTime",
+            id: 5,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 18,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "price",
+            id: 6,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 26,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "origQty",
+            id: 7,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 34,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "executedQty",
+            id: 8,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 42,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "cummulativeQuoteQty",
+            id: 9,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 50,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "status",
+            id: 10,
+            field_type: FieldType::Enum(&ORDER_STATUS),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 58,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "timeInForce",
+            id: 11,
+            field_type: FieldType::Enum(&TIME_IN_FORCE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 59,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderType",
+            id: 12,
+            field_type: FieldType::Enum(&ORDER_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 60,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("type"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "side",
+            id: 13,
+            field_type: FieldType::Enum(&ORDER_SIDE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 61,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "stopPrice",
+            id: 14,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 62,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "trailingDelta",
+            id: 15,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 70,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "trailingTime",
+            id: 16,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 78,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "icebergQty",
+            id: 17,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 86,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "strategyId",
+            id: 18,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 94,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "strategyType",
+            id: 19,
+            field_type: FieldType::Primitive(Primitive::I32),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 102,
+            length: 4,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderCapacity",
+            id: 20,
+            field_type: FieldType::Enum(&ORDER_CAPACITY),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 106,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "workingFloor",
+            id: 21,
+            field_type: FieldType::Enum(&FLOOR),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 107,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "selfTradePreventionMode",
+            id: 22,
+            field_type: FieldType::Enum(&SELF_TRADE_PREVENTION_MODE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 108,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "preventedQuantity",
+            id: 23,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 109,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "usedSor",
+            id: 24,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 117,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "origQuoteOrderQty",
+            id: 25,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 118,
+            length: 8,
+            since_version: 1,
+            exponent: Some("priceExponent"),
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[
+        DataMeta {
+            name: "symbol",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "origClientOrderId",
+            id: 201,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "clientOrderId",
+            id: 202,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+    ],
+};
+
+pub static CANCEL_OPEN_ORDERS_RESPONSE: MessageMeta = MessageMeta {
+    name: "CancelOpenOrdersResponse",
+    id: 306,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[GroupMeta {
+        name: "responses",
+        id: 100,
+        block_length: 0,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[],
+        groups: &[],
+        data: &[DataMeta {
+            name: "response",
+            id: 200,
+            data_type: DataType::Message,
+            length_type: Primitive::U16,
+            since_version: 0,
+            json_path: None,
+        }],
+    }],
+    data: &[],
+};
+
+pub static CANCEL_REPLACE_ORDER_RESPONSE: MessageMeta = MessageMeta {
+    name: "CancelReplaceOrderResponse",
+    id: 307,
+    block_length: 2,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "cancelResult",
+            id: 1,
+            field_type: FieldType::Enum(&CANCEL_REPLACE_STATUS),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "newOrderResult",
+            id: 2,
+            field_type: FieldType::Enum(&CANCEL_REPLACE_STATUS),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[
+        DataMeta {
+            name: "cancelResponse",
+            id: 200,
+            data_type: DataType::OptionalMessage,
+            length_type: Primitive::U16,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "newOrderResponse",
+            id: 201,
+            data_type: DataType::OptionalMessage,
+            length_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+        },
+    ],
+};
+
+pub static ORDERS_RESPONSE: MessageMeta = MessageMeta {
+    name: "OrdersResponse",
+    id: 308,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[GroupMeta {
+        name: "orders",
+        id: 100,
+        block_length: 151,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "priceExponent",
+                id: 1,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "qtyExponent",
+                id: 2,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 1,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "orderId",
+                id: 3,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 2,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "orderListId",
+                id: 4,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 10,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: Some("-1"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "price",
+                id: 5,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 18,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "origQty",
+                id: 6,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 26,
+                length: 8,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "executedQty",
+                id: 7,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 34,
+                length: 8,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "cummulativeQuoteQty",
+                id: 8,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 42,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "status",
+                id: 9,
+                field_type: FieldType::Enum(&ORDER_STATUS),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 50,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "timeInForce",
+                id: 10,
+                field_type: FieldType::Enum(&TIME_IN_FORCE),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 51,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "orderType",
+                id: 11,
+                field_type: FieldType::Enum(&ORDER_TYPE),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 52,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("type"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "side",
+                id: 12,
+                field_type: FieldType::Enum(&ORDER_SIDE),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 53,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "stopPrice",
+                id: 13,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 54,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "trailingDelta",
+                id: 14,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 62,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "trailingTime",
+                id: 15,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 70,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "icebergQty",
+                id: 16,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 78,
+                length: 8,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "time",
+                id: 17,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 86,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "updateTime",
+                id: 18,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 94,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "isWorking",
+                id: 19,
+                field_type: FieldType::Enum(&BOOL_ENUM),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 102,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "workingTime",
+                id: 20,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 103,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: Some("-1"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "origQuoteOrderQty",
+                id: 21,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 111,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "strategyId",
+                id: 22,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 119,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "strategyType",
+                id: 23,
+                field_type: FieldType::Primitive(Primitive::I32),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 127,
+                length: 4,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "orderCapacity",
+                id: 24,
+                field_type: FieldType::Enum(&ORDER_CAPACITY),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 131,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "workingFloor",
+                id: 25,
+                field_type: FieldType::Enum(&FLOOR),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 132,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "selfTradePreventionMode",
+                id: 26,
+                field_type: FieldType::Enum(&SELF_TRADE_PREVENTION_MODE),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 133,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "preventedMatchId",
+                id: 27,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 134,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "preventedQuantity",
+                id: 28,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 142,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "usedSor",
+                id: 29,
+                field_type: FieldType::Enum(&BOOL_ENUM),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 150,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[
+            DataMeta {
+                name: "symbol",
+                id: 200,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            },
+            DataMeta {
+                name: "clientOrderId",
+                id: 201,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            },
+        ],
+    }],
+    data: &[],
+};
+
+pub static NEW_ORDER_LIST_ACK_RESPONSE: MessageMeta = MessageMeta {
+    name: "NewOrderListAckResponse",
+    id: 309,
+    block_length: 19,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "orderListId",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "contingencyType",
+            id: 2,
+            field_type: FieldType::Enum(&CONTINGENCY_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "listStatusType",
+            id: 3,
+            field_type: FieldType::Enum(&LIST_STATUS_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 9,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "listOrderStatus",
+            id: 4,
+            field_type: FieldType::Enum(&LIST_ORDER_STATUS),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 10,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "transactionTime",
+            id: 5,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 11,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[
+        GroupMeta {
+            name: "orders",
+            id: 100,
+            block_length: 8,
+            num_in_group_type: Primitive::U16,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[FieldMeta {
+                name: "orderId",
+                id: 1,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            }],
+            groups: &[],
+            data: &[
+                DataMeta {
+                    name: "symbol",
+                    id: 200,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+                DataMeta {
+                    name: "clientOrderId",
+                    id: 201,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+            ],
+        },
+        GroupMeta {
+            name: "orderReports",
+            id: 101,
+            block_length: 24,
+            num_in_group_type: Primitive::U16,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[
+                FieldMeta {
+                    name: "orderId",
+                    id: 1,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 0,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "orderListId",
+                    id: 2,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 8,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: Some("-1"),
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "transactTime",
+                    id: 3,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 16,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+            ],
+            groups: &[],
+            data: &[
+                DataMeta {
+                    name: "symbol",
+                    id: 200,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+                DataMeta {
+                    name: "clientOrderId",
+                    id: 201,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+            ],
+        },
+    ],
+    data: &[
+        DataMeta {
+            name: "listClientOrderId",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "symbol",
+            id: 201,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+    ],
+};
+
+pub static NEW_ORDER_LIST_RESULT_RESPONSE: MessageMeta = MessageMeta {
+    name: "NewOrderListResultResponse",
+    id: 310,
+    block_length: 21,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "orderListId",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "contingencyType",
+            id: 2,
+            field_type: FieldType::Enum(&CONTINGENCY_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "listStatusType",
+            id: 3,
+            field_type: FieldType::Enum(&LIST_STATUS_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 9,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "listOrderStatus",
+            id: 4,
+            field_type: FieldType::Enum(&LIST_ORDER_STATUS),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 10,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "transactionTime",
+            id: 5,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 11,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "priceExponent",
+            id: 6,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 19,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 7,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 20,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[
+        GroupMeta {
+            name: "orders",
+            id: 100,
+            block_length: 8,
+            num_in_group_type: Primitive::U16,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[FieldMeta {
+                name: "orderId",
+                id: 1,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            }],
+            groups: &[],
+            data: &[
+                DataMeta {
+                    name: "symbol",
+                    id: 200,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+                DataMeta {
+                    name: "clientOrderId",
+                    id: 201,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+            ],
+        },
+        GroupMeta {
+            name: "orderReports",
+            id: 101,
+            block_length: 140,
+            num_in_group_type: Primitive::U16,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[
+                FieldMeta {
+                    name: "orderId",
+                    id: 1,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 0,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "orderListId",
+                    id: 2,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 8,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: Some("-1"),
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "transactTime",
+                    id: 3,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 16,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "price",
+                    id: 4,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 24,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("priceExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "origQty",
+                    id: 5,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 32,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "executedQty",
+                    id: 6,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 40,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "cummulativeQuoteQty",
+                    id: 7,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 48,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("priceExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "status",
+                    id: 8,
+                    field_type: FieldType::Enum(&ORDER_STATUS),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 56,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "timeInForce",
+                    id: 9,
+                    field_type: FieldType::Enum(&TIME_IN_FORCE),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 57,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "orderType",
+                    id: 10,
+                    field_type: FieldType::Enum(&ORDER_TYPE),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 58,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: Some("type"),
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "side",
+                    id: 11,
+                    field_type: FieldType::Enum(&ORDER_SIDE),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 59,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "stopPrice",
+                    id: 12,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 60,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("priceExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "trailingDelta",
+                    id: 13,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 68,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "trailingTime",
+                    id: 14,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 76,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "workingTime",
+                    id: 15,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 84,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: Some("-1"),
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "icebergQty",
+                    id: 16,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 92,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "strategyId",
+                    id: 17,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 100,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "strategyType",
+                    id: 18,
+                    field_type: FieldType::Primitive(Primitive::I32),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 108,
+                    length: 4,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "orderCapacity",
+                    id: 19,
+                    field_type: FieldType::Enum(&ORDER_CAPACITY),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 112,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "workingFloor",
+                    id: 20,
+                    field_type: FieldType::Enum(&FLOOR),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 113,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "selfTradePreventionMode",
+                    id: 21,
+                    field_type: FieldType::Enum(&SELF_TRADE_PREVENTION_MODE),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 114,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "tradeGroupId",
+                    id: 22,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 115,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "preventedQuantity",
+                    id: 23,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 123,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "usedSor",
+                    id: 24,
+                    field_type: FieldType::Enum(&BOOL_ENUM),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 131,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "origQuoteOrderQty",
+                    id: 25,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 132,
+                    length: 8,
+                    since_version: 1,
+                    exponent: Some("priceExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+            ],
+            groups: &[],
+            data: &[
+                DataMeta {
+                    name: "symbol",
+                    id: 200,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+                DataMeta {
+                    name: "clientOrderId",
+                    id: 201,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+            ],
+        },
+    ],
+    data: &[
+        DataMeta {
+            name: "listClientOrderId",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "symbol",
+            id: 201,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+    ],
+};
+
+pub static NEW_ORDER_LIST_FULL_RESPONSE: MessageMeta = MessageMeta {
+    name: "NewOrderListFullResponse",
+    id: 311,
+    block_length: 21,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "orderListId",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "contingencyType",
+            id: 2,
+            field_type: FieldType::Enum(&CONTINGENCY_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "listStatusType",
+            id: 3,
+            field_type: FieldType::Enum(&LIST_STATUS_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 9,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "listOrderStatus",
+            id: 4,
+            field_type: FieldType::Enum(&LIST_ORDER_STATUS),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 10,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "transactionTime",
+            id: 5,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 11,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "priceExponent",
+            id: 6,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 19,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 7,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 20,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[
+        GroupMeta {
+            name: "orders",
+            id: 100,
+            block_length: 8,
+            num_in_group_type: Primitive::U16,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[FieldMeta {
+                name: "orderId",
+                id: 1,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            }],
+            groups: &[],
+            data: &[
+                DataMeta {
+                    name: "symbol",
+                    id: 200,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+                DataMeta {
+                    name: "clientOrderId",
+                    id: 201,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+            ],
+        },
+        GroupMeta {
+            name: "orderReports",
+            id: 101,
+            block_length: 140,
+            num_in_group_type: Primitive::U16,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[
+                FieldMeta {
+                    name: "orderId",
+                    id: 1,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 0,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "orderListId",
+                    id: 2,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 8,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: Some("-1"),
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "transactTime",
+                    id: 3,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 16,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "price",
+                    id: 4,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 24,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("priceExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "origQty",
+                    id: 5,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 32,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "executedQty",
+                    id: 6,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 40,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "cummulativeQuoteQty",
+                    id: 7,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 48,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("priceExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "status",
+                    id: 8,
+                    field_type: FieldType::Enum(&ORDER_STATUS),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 56,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "timeInForce",
+                    id: 9,
+                    field_type: FieldType::Enum(&TIME_IN_FORCE),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 57,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "orderType",
+                    id: 10,
+                    field_type: FieldType::Enum(&ORDER_TYPE),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 58,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: Some("type"),
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "side",
+                    id: 11,
+                    field_type: FieldType::Enum(&ORDER_SIDE),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 59,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "stopPrice",
+                    id: 12,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 60,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("priceExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "trailingDelta",
+                    id: 13,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 68,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "trailingTime",
+                    id: 14,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 76,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "workingTime",
+                    id: 15,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 84,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: Some("-1"),
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "icebergQty",
+                    id: 16,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 92,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "strategyId",
+                    id: 17,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 100,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "strategyType",
+                    id: 18,
+                    field_type: FieldType::Primitive(Primitive::I32),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 108,
+                    length: 4,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "orderCapacity",
+                    id: 19,
+                    field_type: FieldType::Enum(&ORDER_CAPACITY),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 112,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "workingFloor",
+                    id: 20,
+                    field_type: FieldType::Enum(&FLOOR),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 113,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "selfTradePreventionMode",
+                    id: 21,
+                    field_type: FieldType::Enum(&SELF_TRADE_PREVENTION_MODE),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 114,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "tradeGroupId",
+                    id: 22,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 115,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "preventedQuantity",
+                    id: 23,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 123,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "usedSor",
+                    id: 24,
+                    field_type: FieldType::Enum(&BOOL_ENUM),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 131,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "origQuoteOrderQty",
+                    id: 25,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 132,
+                    length: 8,
+                    since_version: 1,
+                    exponent: Some("priceExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+            ],
+            groups: &[
+                GroupMeta {
+                    name: "fills",
+                    id: 100,
+                    block_length: 42,
+                    num_in_group_type: Primitive::U32,
+                    since_version: 0,
+                    json_path: None,
+                    json_omit_null: false,
+                    fields: &[
+                        FieldMeta {
+                            name: "commissionExponent",
+                            id: 1,
+                            field_type: FieldType::Exponent,
+                            presence: Presence::Required,
+                            value_ref: None,
+                            offset: 0,
+                            length: 1,
+                            since_version: 0,
+                            exponent: None,
+                            json_path: None,
+                            json_default_value: None,
+                            json_omit_null: false,
+                        },
+                        FieldMeta {
+                            name: "matchType",
+                            id: 2,
+                            field_type: FieldType::Enum(&MATCH_TYPE),
+                            presence: Presence::Optional,
+                            value_ref: None,
+                            offset: 1,
+                            length: 1,
+                            since_version: 0,
+                            exponent: None,
+                            json_path: None,
+                            json_default_value: None,
+                            json_omit_null: false,
+                        },
+                        FieldMeta {
+                            name: "price",
+                            id: 3,
+                            field_type: FieldType::Mantissa64,
+                            presence: Presence::Required,
+                            value_ref: None,
+                            offset: 2,
+                            length: 8,
+                            since_version: 0,
+                            exponent: Some("priceExponent"),
+                            json_path: None,
+                            json_default_value: None,
+                            json_omit_null: false,
+                        },
+                        FieldMeta {
+                            name: "qty",
+                            id: 4,
+                            field_type: FieldType::Mantissa64,
+                            presence: Presence::Required,
+                            value_ref: None,
+                            offset: 10,
+                            length: 8,
+                            since_version: 0,
+                            exponent: Some("qtyExponent"),
+                            json_path: None,
+                            json_default_value: None,
+                            json_omit_null: false,
+                        },
+                        FieldMeta {
+                            name: "commission",
+                            id: 5,
+                            field_type: FieldType::Mantissa64,
+                            presence: Presence::Required,
+                            value_ref: None,
+                            offset: 18,
+                            length: 8,
+                            since_version: 0,
+                            exponent: Some("commissionExponent"),
+                            json_path: None,
+                            json_default_value: None,
+                            json_omit_null: false,
+                        },
+                        FieldMeta {
+                            name: "tradeId",
+                            id: 6,
+                            field_type: FieldType::Primitive(Primitive::I64),
+                            presence: Presence::Optional,
+                            value_ref: None,
+                            offset: 26,
+                            length: 8,
+                            since_version: 0,
+                            exponent: None,
+                            json_path: None,
+                            json_default_value: None,
+                            json_omit_null: false,
+                        },
+                        FieldMeta {
+                            name: "allocId",
+                            id: 7,
+                            field_type: FieldType::Primitive(Primitive::I64),
+                            presence: Presence::Optional,
+                            value_ref: None,
+                            offset: 34,
+                            length: 8,
+                            since_version: 0,
+                            exponent: None,
+                            json_path: None,
+                            json_default_value: None,
+                            json_omit_null: false,
+                        },
+                    ],
+                    groups: &[],
+                    data: &[DataMeta {
+                        name: "commissionAsset",
+                        id: 200,
+                        data_type: DataType::OptionalString,
+                        length_type: Primitive::U8,
+                        since_version: 0,
+                        json_path: None,
+                    }],
+                },
+                GroupMeta {
+                    name: "preventedMatches",
+                    id: 101,
+                    block_length: 40,
+                    num_in_group_type: Primitive::U32,
+                    since_version: 0,
+                    json_path: None,
+                    json_omit_null: false,
+                    fields: &[
+                        FieldMeta {
+                            name: "preventedMatchId",
+                            id: 1,
+                            field_type: FieldType::Primitive(Primitive::I64),
+                            presence: Presence::Required,
+                            value_ref: None,
+                            offset: 0,
+                            length: 8,
+                            since_version: 0,
+                            exponent: None,
+                            json_path: None,
+                            json_default_value: None,
+                            json_omit_null: false,
+                        },
+                        FieldMeta {
+                            name: "makerOrderId",
+                            id: 2,
+                            field_type: FieldType::Primitive(Primitive::I64),
+                            presence: Presence::Optional,
+                            value_ref: None,
+                            offset: 8,
+                            length: 8,
+                            since_version: 0,
+                            exponent: None,
+                            json_path: None,
+                            json_default_value: None,
+                            json_omit_null: false,
+                        },
+                        FieldMeta {
+                            name: "price",
+                            id: 3,
+                            field_type: FieldType::Mantissa64,
+                            presence: Presence::Optional,
+                            value_ref: None,
+                            offset: 16,
+                            length: 8,
+                            since_version: 0,
+                            exponent: Some("priceExponent"),
+                            json_path: None,
+                            json_default_value: None,
+                            json_omit_null: false,
+                        },
+                        FieldMeta {
+                            name: "takerPreventedQuantity",
+                            id: 4,
+                            field_type: FieldType::Mantissa64,
+                            presence: Presence::Optional,
+                            value_ref: None,
+                            offset: 24,
+                            length: 8,
+                            since_version: 0,
+                            exponent: Some("qtyExponent"),
+                            json_path: None,
+                            json_default_value: None,
+                            json_omit_null: false,
+                        },
+                        FieldMeta {
+                            name: "makerPreventedQuantity",
+                            id: 5,
+                            field_type: FieldType::Mantissa64,
+                            presence: Presence::Optional,
+                            value_ref: None,
+                            offset: 32,
+                            length: 8,
+                            since_version: 0,
+                            exponent: Some("qtyExponent"),
+                            json_path: None,
+                            json_default_value: None,
+                            json_omit_null: false,
+                        },
+                    ],
+                    groups: &[],
+                    data: &[DataMeta {
+                        name: "makerSymbol",
+                        id: 200,
+                        data_type: DataType::OptionalString,
+                        length_type: Primitive::U8,
+                        since_version: 0,
+                        json_path: None,
+                    }],
+                },
+            ],
+            data: &[
+                DataMeta {
+                    name: "symbol",
+                    id: 200,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+                DataMeta {
+                    name: "clientOrderId",
+                    id: 201,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+            ],
+        },
+    ],
+    data: &[
+        DataMeta {
+            name: "listClientOrderId",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "symbol",
+            id: 201,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+    ],
+};
+
+pub static CANCEL_ORDER_LIST_RESPONSE: MessageMeta = MessageMeta {
+    name: "CancelOrderListResponse",
+    id: 312,
+    block_length: 21,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "orderListId",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "contingencyType",
+            id: 2,
+            field_type: FieldType::Enum(&CONTINGENCY_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "listStatusType",
+            id: 3,
+            field_type: FieldType::Enum(&LIST_STATUS_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 9,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "listOrderStatus",
+            id: 4,
+            field_type: FieldType::Enum(&LIST_ORDER_STATUS),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 10,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "transactionTime",
+            id: 5,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 11,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "priceExponent",
+            id: 6,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 19,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 7,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 20,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[
+        GroupMeta {
+            name: "orders",
+            id: 100,
+            block_length: 8,
+            num_in_group_type: Primitive::U16,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[FieldMeta {
+                name: "orderId",
+                id: 1,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            }],
+            groups: &[],
+            data: &[
+                DataMeta {
+                    name: "symbol",
+                    id: 200,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+                DataMeta {
+                    name: "clientOrderId",
+                    id: 201,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+            ],
+        },
+        GroupMeta {
+            name: "orderReports",
+            id: 101,
+            block_length: 124,
+            num_in_group_type: Primitive::U16,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[
+                FieldMeta {
+                    name: "orderId",
+                    id: 1,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 0,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "orderListId",
+                    id: 2,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 8,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: Some("-1"),
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "transactTime",
+                    id: 3,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 16,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "price",
+                    id: 4,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 24,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("priceExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "origQty",
+                    id: 5,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 32,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "executedQty",
+                    id: 6,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 40,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "cummulativeQuoteQty",
+                    id: 7,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 48,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("priceExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "status",
+                    id: 8,
+                    field_type: FieldType::Enum(&ORDER_STATUS),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 56,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "timeInForce",
+                    id: 9,
+                    field_type: FieldType::Enum(&TIME_IN_FORCE),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 57,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "orderType",
+                    id: 10,
+                    field_type: FieldType::Enum(&ORDER_TYPE),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 58,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: Some("type"),
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "side",
+                    id: 11,
+                    field_type: FieldType::Enum(&ORDER_SIDE),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 59,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "stopPrice",
+                    id: 12,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 60,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("priceExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "trailingDelta",
+                    id: 13,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 68,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "trailingTime",
+                    id: 14,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 76,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "icebergQty",
+                    id: 15,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 84,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "strategyId",
+                    id: 16,
+                    field_type: FieldType::Primitive(Primitive::I64),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 92,
+                    length: 8,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "strategyType",
+                    id: 17,
+                    field_type: FieldType::Primitive(Primitive::I32),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 100,
+                    length: 4,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "orderCapacity",
+                    id: 18,
+                    field_type: FieldType::Enum(&ORDER_CAPACITY),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 104,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "workingFloor",
+                    id: 19,
+                    field_type: FieldType::Enum(&FLOOR),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 105,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "selfTradePreventionMode",
+                    id: 20,
+                    field_type: FieldType::Enum(&SELF_TRADE_PREVENTION_MODE),
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 106,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "preventedQuantity",
+                    id: 21,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 107,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("qtyExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "usedSor",
+                    id: 22,
+                    field_type: FieldType::Enum(&BOOL_ENUM),
+                    presence: Presence::Optional,
+                    value_ref: None,
+                    offset: 115,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "origQuoteOrderQty",
+                    id: 23,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 116,
+                    length: 8,
+                    since_version: 1,
+                    exponent: Some("priceExponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+            ],
+            groups: &[],
+            data: &[
+                DataMeta {
+                    name: "symbol",
+                    id: 200,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+                DataMeta {
+                    name: "origClientOrderId",
+                    id: 201,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+                DataMeta {
+                    name: "clientOrderId",
+                    id: 202,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+            ],
+        },
+    ],
+    data: &[
+        DataMeta {
+            name: "listClientOrderId",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "symbol",
+            id: 201,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+    ],
+};
+
+pub static ORDER_LIST_RESPONSE: MessageMeta = MessageMeta {
+    name: "OrderListResponse",
+    id: 313,
+    block_length: 19,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "orderListId",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "contingencyType",
+            id: 2,
+            field_type: FieldType::Enum(&CONTINGENCY_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "listStatusType",
+            id: 3,
+            field_type: FieldType::Enum(&LIST_STATUS_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 9,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "listOrderStatus",
+            id: 4,
+            field_type: FieldType::Enum(&LIST_ORDER_STATUS),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 10,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "transactionTime",
+            id: 5,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 11,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[GroupMeta {
+        name: "orders",
+        id: 100,
+        block_length: 8,
+        num_in_group_type: Primitive::U16,
+        since_version: 0,
+        json_path: None,
+        json_omit_null: false,
+        fields: &[FieldMeta {
+            name: "orderId",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        }],
+        groups: &[],
+        data: &[
+            DataMeta {
+                name: "symbol",
+                id: 200,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            },
+            DataMeta {
+                name: "clientOrderId",
+                id: 201,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            },
+        ],
+    }],
+    data: &[
+        DataMeta {
+            name: "listClientOrderId",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "symbol",
+            id: 201,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+    ],
+};
+
+pub static ORDER_LISTS_RESPONSE: MessageMeta = MessageMeta {
+    name: "OrderListsResponse",
+    id: 314,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[GroupMeta {
+        name: "orderLists",
+        id: 100,
+        block_length: 19,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "orderListId",
+                id: 1,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "contingencyType",
+                id: 2,
+                field_type: FieldType::Enum(&CONTINGENCY_TYPE),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 8,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "listStatusType",
+                id: 3,
+                field_type: FieldType::Enum(&LIST_STATUS_TYPE),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 9,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "listOrderStatus",
+                id: 4,
+                field_type: FieldType::Enum(&LIST_ORDER_STATUS),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 10,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "transactionTime",
+                id: 5,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 11,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[GroupMeta {
+            name: "orders",
+            id: 100,
+            block_length: 8,
+            num_in_group_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[FieldMeta {
+                name: "orderId",
+                id: 1,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            }],
+            groups: &[],
+            data: &[
+                DataMeta {
+                    name: "symbol",
+                    id: 200,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+                DataMeta {
+                    name: "clientOrderId",
+                    id: 201,
+                    data_type: DataType::String,
+                    length_type: Primitive::U8,
+                    since_version: 0,
+                    json_path: None,
+                },
+            ],
+        }],
+        data: &[
+            DataMeta {
+                name: "listClientOrderId",
+                id: 200,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            },
+            DataMeta {
+                name: "symbol",
+                id: 201,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            },
+        ],
+    }],
+    data: &[],
+};
+
+pub static ORDER_TEST_WITH_COMMISSIONS_RESPONSE: MessageMeta = MessageMeta {
+    name: "OrderTestWithCommissionsResponse",
+    id: 315,
+    block_length: 44,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "commissionExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "discountExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "standardCommissionForOrderMaker",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 2,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("standardCommissionForOrder.maker"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "standardCommissionForOrderTaker",
+            id: 4,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 10,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("standardCommissionForOrder.taker"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "taxCommissionForOrderMaker",
+            id: 5,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 18,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("taxCommissionForOrder.maker"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "taxCommissionForOrderTaker",
+            id: 6,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 26,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("taxCommissionForOrder.taker"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "discountEnabledForAccount",
+            id: 7,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 34,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("discount.enabledForAccount"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "discountEnabledForSymbol",
+            id: 8,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 35,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("discount.enabledForSymbol"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "discount",
+            id: 9,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 36,
+            length: 8,
+            since_version: 0,
+            exponent: Some("discountExponent"),
+            json_path: Some("discount.discount"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[DataMeta {
+        name: "discountAsset",
+        id: 200,
+        data_type: DataType::OptionalString,
+        length_type: Primitive::U8,
+        since_version: 0,
+        json_path: Some("discount.discountAsset"),
+    }],
+};
+
+pub static ACCOUNT_RESPONSE: MessageMeta = MessageMeta {
+    name: "AccountResponse",
+    id: 400,
+    block_length: 64,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "commissionExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "commissionRateMaker",
+            id: 2,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("commissionRates.maker"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "commissionRateTaker",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 9,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("commissionRates.taker"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "commissionRateBuyer",
+            id: 4,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 17,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("commissionRates.buyer"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "commissionRateSeller",
+            id: 5,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 25,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("commissionRates.seller"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "canTrade",
+            id: 6,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 33,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "canWithdraw",
+            id: 7,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 34,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "canDeposit",
+            id: 8,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 35,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "brokered",
+            id: 9,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 36,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "requireSelfTradePrevention",
+            id: 10,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 37,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "preventSor",
+            id: 11,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 38,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "updateTime",
+            id: 12,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 39,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "accountType",
+            id: 13,
+            field_type: FieldType::Enum(&ACCOUNT_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 47,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "tradeGroupId",
+            id: 14,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 48,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "uid",
+            id: 15,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 56,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[
+        GroupMeta {
+            name: "balances",
+            id: 100,
+            block_length: 17,
+            num_in_group_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[
+                FieldMeta {
+                    name: "exponent",
+                    id: 1,
+                    field_type: FieldType::Exponent,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 0,
+                    length: 1,
+                    since_version: 0,
+                    exponent: None,
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "free",
+                    id: 2,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 1,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("exponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+                FieldMeta {
+                    name: "locked",
+                    id: 3,
+                    field_type: FieldType::Mantissa64,
+                    presence: Presence::Required,
+                    value_ref: None,
+                    offset: 9,
+                    length: 8,
+                    since_version: 0,
+                    exponent: Some("exponent"),
+                    json_path: None,
+                    json_default_value: None,
+                    json_omit_null: false,
+                },
+            ],
+            groups: &[],
+            data: &[DataMeta {
+                name: "asset",
+                id: 200,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            }],
+        },
+        GroupMeta {
+            name: "permissions",
+            id: 101,
+            block_length: 0,
+            num_in_group_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[],
+            groups: &[],
+            data: &[DataMeta {
+                name: "permission",
+                id: 200,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: Some(".."),
+            }],
+        },
+        GroupMeta {
+            name: "reduceOnlyAssets",
+            id: 102,
+            block_length: 0,
+            num_in_group_type: Primitive::U32,
+            since_version: 0,
+            json_path: None,
+            json_omit_null: false,
+            fields: &[],
+            groups: &[],
+            data: &[DataMeta {
+                name: "asset",
+                id: 200,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: Some(".."),
+            }],
+        },
+    ],
+    data: &[],
+};
+
+pub static ACCOUNT_TRADES_RESPONSE: MessageMeta = MessageMeta {
+    name: "AccountTradesResponse",
+    id: 401,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[GroupMeta {
+        name: "trades",
+        id: 100,
+        block_length: 70,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "priceExponent",
+                id: 1,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "qtyExponent",
+                id: 2,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 1,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "commissionExponent",
+                id: 3,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 2,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "id",
+                id: 4,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 3,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "orderId",
+                id: 5,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 11,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "orderListId",
+                id: 6,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 19,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: Some("-1"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "price",
+                id: 7,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 27,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "qty",
+                id: 8,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 35,
+                length: 8,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "quoteQty",
+                id: 9,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 43,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "commission",
+                id: 10,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 51,
+                length: 8,
+                since_version: 0,
+                exponent: Some("commissionExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "time",
+                id: 11,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 59,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "isBuyer",
+                id: 12,
+                field_type: FieldType::Enum(&BOOL_ENUM),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 67,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "isMaker",
+                id: 13,
+                field_type: FieldType::Enum(&BOOL_ENUM),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 68,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "isBestMatch",
+                id: 14,
+                field_type: FieldType::Enum(&BOOL_ENUM),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 69,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[
+            DataMeta {
+                name: "symbol",
+                id: 200,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            },
+            DataMeta {
+                name: "commissionAsset",
+                id: 201,
+                data_type: DataType::OptionalString,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            },
+        ],
+    }],
+    data: &[],
+};
+
+pub static ACCOUNT_ORDER_RATE_LIMIT_RESPONSE: MessageMeta = MessageMeta {
+    name: "AccountOrderRateLimitResponse",
+    id: 402,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[GroupMeta {
+        name: "rateLimits",
+        id: 100,
+        block_length: 19,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "rateLimitType",
+                id: 1,
+                field_type: FieldType::Enum(&RATE_LIMIT_TYPE),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "interval",
+                id: 2,
+                field_type: FieldType::Enum(&RATE_LIMIT_INTERVAL),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 1,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "intervalNum",
+                id: 3,
+                field_type: FieldType::Primitive(Primitive::U8),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 2,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "rateLimit",
+                id: 4,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 3,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("limit"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "numOrders",
+                id: 5,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 11,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: Some("count"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[],
+    }],
+    data: &[],
+};
+
+pub static ACCOUNT_PREVENTED_MATCHES_RESPONSE: MessageMeta = MessageMeta {
+    name: "AccountPreventedMatchesResponse",
+    id: 403,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[GroupMeta {
+        name: "preventedMatches",
+        id: 100,
+        block_length: 67,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "priceExponent",
+                id: 1,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "qtyExponent",
+                id: 2,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 1,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "preventedMatchId",
+                id: 3,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 2,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "takerOrderId",
+                id: 4,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 10,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "makerOrderId",
+                id: 5,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 18,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "tradeGroupId",
+                id: 6,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 26,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "selfTradePreventionMode",
+                id: 7,
+                field_type: FieldType::Enum(&SELF_TRADE_PREVENTION_MODE),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 34,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "price",
+                id: 8,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 35,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "takerPreventedQuantity",
+                id: 9,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 43,
+                length: 8,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "makerPreventedQuantity",
+                id: 10,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 51,
+                length: 8,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "transactTime",
+                id: 11,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 59,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[
+            DataMeta {
+                name: "symbol",
+                id: 200,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            },
+            DataMeta {
+                name: "makerSymbol",
+                id: 201,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            },
+        ],
+    }],
+    data: &[],
+};
+
+pub static ACCOUNT_ALLOCATIONS_RESPONSE: MessageMeta = MessageMeta {
+    name: "AccountAllocationsResponse",
+    id: 404,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[GroupMeta {
+        name: "allocations",
+        id: 100,
+        block_length: 87,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some(".."),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "priceExponent",
+                id: 1,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "qtyExponent",
+                id: 2,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 1,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "commissionExponent",
+                id: 3,
+                field_type: FieldType::Exponent,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 2,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "allocationId",
+                id: 4,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 3,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "allocationType",
+                id: 5,
+                field_type: FieldType::Enum(&ALLOCATION_TYPE),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 11,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "orderId",
+                id: 6,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 12,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "orderListId",
+                id: 7,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 20,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: Some("-1"),
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "sourceTradeId",
+                id: 8,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 28,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "sourceAllocationId",
+                id: 9,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 36,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "price",
+                id: 10,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 44,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "qty",
+                id: 11,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 52,
+                length: 8,
+                since_version: 0,
+                exponent: Some("qtyExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "quoteQty",
+                id: 12,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 60,
+                length: 8,
+                since_version: 0,
+                exponent: Some("priceExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "commission",
+                id: 13,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Optional,
+                value_ref: None,
+                offset: 68,
+                length: 8,
+                since_version: 0,
+                exponent: Some("commissionExponent"),
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "time",
+                id: 14,
+                field_type: FieldType::Primitive(Primitive::I64),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 76,
+                length: 8,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "isBuyer",
+                id: 15,
+                field_type: FieldType::Enum(&BOOL_ENUM),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 84,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "isMaker",
+                id: 16,
+                field_type: FieldType::Enum(&BOOL_ENUM),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 85,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "isAllocator",
+                id: 17,
+                field_type: FieldType::Enum(&BOOL_ENUM),
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 86,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[
+            DataMeta {
+                name: "symbol",
+                id: 200,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            },
+            DataMeta {
+                name: "commissionAsset",
+                id: 201,
+                data_type: DataType::OptionalString,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            },
+            DataMeta {
+                name: "sourceSymbol",
+                id: 202,
+                data_type: DataType::OptionalString,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: None,
+            },
+        ],
+    }],
+    data: &[],
+};
+
+pub static ACCOUNT_COMMISSION_RESPONSE: MessageMeta = MessageMeta {
+    name: "AccountCommissionResponse",
+    id: 405,
+    block_length: 76,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[
+        FieldMeta {
+            name: "commissionExponent",
+            id: 1,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "discountExponent",
+            id: 2,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 1,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "standardCommissionMaker",
+            id: 3,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 2,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("standardCommission.maker"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "standardCommissionTaker",
+            id: 4,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 10,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("standardCommission.taker"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "standardCommissionBuyer",
+            id: 5,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 18,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("standardCommission.buyer"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "standardCommissionSeller",
+            id: 6,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 26,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("standardCommission.seller"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "taxCommissionMaker",
+            id: 7,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 34,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("taxCommission.maker"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "taxCommissionTaker",
+            id: 8,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 42,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("taxCommission.taker"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "taxCommissionBuyer",
+            id: 9,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 50,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("taxCommission.buyer"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "taxCommissionSeller",
+            id: 10,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 58,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("taxCommission.seller"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "discountEnabledForAccount",
+            id: 11,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 66,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("discount.enabledForAccount"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "discountEnabledForSymbol",
+            id: 12,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 67,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("discount.enabledForSymbol"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "discount",
+            id: 13,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 68,
+            length: 8,
+            since_version: 0,
+            exponent: Some("discountExponent"),
+            json_path: Some("discount.discount"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[
+        DataMeta {
+            name: "symbol",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: None,
+        },
+        DataMeta {
+            name: "discountAsset",
+            id: 201,
+            data_type: DataType::OptionalString,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: Some("discount.discountAsset"),
+        },
+    ],
+};
+
+pub static USER_DATA_STREAM_START_RESPONSE: MessageMeta = MessageMeta {
+    name: "UserDataStreamStartResponse",
+    id: 500,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[],
+    data: &[DataMeta {
+        name: "listenKey",
+        id: 200,
+        data_type: DataType::String,
+        length_type: Primitive::U8,
+        since_version: 0,
+        json_path: None,
+    }],
+};
+
+pub static USER_DATA_STREAM_PING_RESPONSE: MessageMeta = MessageMeta {
+    name: "UserDataStreamPingResponse",
+    id: 501,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[],
+    data: &[],
+};
+
+pub static USER_DATA_STREAM_STOP_RESPONSE: MessageMeta = MessageMeta {
+    name: "UserDataStreamStopResponse",
+    id: 502,
+    block_length: 0,
+    since_version: 0,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[],
+    data: &[],
+};
+
+pub static USER_DATA_STREAM_SUBSCRIBE_RESPONSE: MessageMeta = MessageMeta {
+    name: "UserDataStreamSubscribeResponse",
+    id: 503,
+    block_length: 0,
+    since_version: 1,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[],
+    data: &[],
+};
+
+pub static USER_DATA_STREAM_UNSUBSCRIBE_RESPONSE: MessageMeta = MessageMeta {
+    name: "UserDataStreamUnsubscribeResponse",
+    id: 504,
+    block_length: 0,
+    since_version: 1,
+    description: None,
+    event_type: None,
+    fields: &[],
+    groups: &[],
+    data: &[],
+};
+
+pub static BALANCE_UPDATE_EVENT: MessageMeta = MessageMeta {
+    name: "BalanceUpdateEvent",
+    id: 601,
+    block_length: 25,
+    since_version: 1,
+    description: None,
+    event_type: Some("balanceUpdate"),
+    fields: &[
+        FieldMeta {
+            name: "eventTime",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("E"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "clearTime",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 8,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("T"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 3,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 16,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "freeQtyDelta",
+            id: 4,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 17,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: Some("d"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[DataMeta {
+        name: "asset",
+        id: 200,
+        data_type: DataType::String,
+        length_type: Primitive::U8,
+        since_version: 0,
+        json_path: Some("a"),
+    }],
+};
+
+pub static EVENT_STREAM_TERMINATED_EVENT: MessageMeta = MessageMeta {
+    name: "EventStreamTerminatedEvent",
+    id: 602,
+    block_length: 8,
+    since_version: 1,
+    description: None,
+    event_type: Some("eventStreamTerminated"),
+    fields: &[FieldMeta {
+        name: "eventTime",
+        id: 1,
+        field_type: FieldType::Primitive(Primitive::I64),
+        presence: Presence::Required,
+        value_ref: None,
+        offset: 0,
+        length: 8,
+        since_version: 0,
+        exponent: None,
+        json_path: Some("E"),
+        json_default_value: None,
+        json_omit_null: false,
+    }],
+    groups: &[],
+    data: &[],
+};
+
+pub static EXECUTION_REPORT_EVENT: MessageMeta = MessageMeta {
+    name: "ExecutionReportEvent",
+    id: 603,
+    block_length: 268,
+    since_version: 1,
+    description: None,
+    event_type: Some("executionReport"),
+    fields: &[
+        FieldMeta {
+            name: "eventTime",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("E"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "transactTime",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("T"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "priceExponent",
+            id: 3,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 16,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 4,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 17,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "commissionExponent",
+            id: 5,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 18,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderCreationTime",
+            id: 6,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 19,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("O"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "workingTime",
+            id: 7,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 27,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("W"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderId",
+            id: 8,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 35,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("i"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderListId",
+            id: 9,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 43,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("g"),
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "origQty",
+            id: 10,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 51,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: Some("q"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "price",
+            id: 11,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 59,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: Some("p"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "origQuoteOrderQty",
+            id: 12,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 67,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: Some("Q"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "icebergQty",
+            id: 13,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 75,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: Some("F"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "stopPrice",
+            id: 14,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 83,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: Some("P"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderType",
+            id: 15,
+            field_type: FieldType::Enum(&ORDER_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 91,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("o"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "side",
+            id: 16,
+            field_type: FieldType::Enum(&ORDER_SIDE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 92,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("S"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "timeInForce",
+            id: 17,
+            field_type: FieldType::Enum(&TIME_IN_FORCE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 93,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("f"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "executionType",
+            id: 18,
+            field_type: FieldType::Enum(&EXECUTION_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 94,
+            length: 1,
+            since_version: 1,
+            exponent: None,
+            json_path: Some("x"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderStatus",
+            id: 19,
+            field_type: FieldType::Enum(&ORDER_STATUS),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 95,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("X"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "tradeId",
+            id: 20,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 96,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("t"),
+            json_default_value: Some("-1"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "executionId",
+            id: 21,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 104,
+            length: 8,
+            since_version: 1,
+            exponent: None,
+            json_path: Some("I"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "executedQty",
+            id: 22,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 112,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: Some("z"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "cummulativeQuoteQty",
+            id: 23,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 120,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: Some("Z"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lastQty",
+            id: 24,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 128,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: Some("l"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lastPrice",
+            id: 25,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 136,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: Some("L"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "quoteQty",
+            id: 26,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 144,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: Some("Y"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "commission",
+            id: 27,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 152,
+            length: 8,
+            since_version: 0,
+            exponent: Some("commissionExponent"),
+            json_path: Some("n"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "isWorking",
+            id: 28,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 160,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("w"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "isMaker",
+            id: 29,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 161,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("m"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "isBestMatch",
+            id: 30,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 162,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("M"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "matchType",
+            id: 31,
+            field_type: FieldType::Enum(&MATCH_TYPE),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 163,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("b"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "selfTradePreventionMode",
+            id: 32,
+            field_type: FieldType::Enum(&SELF_TRADE_PREVENTION_MODE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 164,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("V"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderCapacity",
+            id: 33,
+            field_type: FieldType::Enum(&ORDER_CAPACITY),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 165,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("y"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "workingFloor",
+            id: 34,
+            field_type: FieldType::Enum(&FLOOR),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 166,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("k"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "usedSor",
+            id: 35,
+            field_type: FieldType::Enum(&BOOL_ENUM),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 167,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("uS"),
+            json_default_value: Some("false"),
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "allocId",
+            id: 36,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 168,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("a"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "trailingDelta",
+            id: 37,
+            field_type: FieldType::Primitive(Primitive::U64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 176,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("d"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "trailingTime",
+            id: 38,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 184,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("D"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "tradeGroupId",
+            id: 39,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 192,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("u"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "preventedQty",
+            id: 40,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 200,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: Some("A"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lastPreventedQty",
+            id: 41,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 208,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: Some("B"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "preventedMatchId",
+            id: 42,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 216,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("v"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "preventedExecutionQty",
+            id: 43,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 224,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: Some("pl"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "preventedExecutionPrice",
+            id: 44,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 232,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: Some("pL"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "preventedExecutionQuoteQty",
+            id: 45,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 240,
+            length: 8,
+            since_version: 0,
+            exponent: Some("priceExponent"),
+            json_path: Some("pY"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "strategyType",
+            id: 46,
+            field_type: FieldType::Primitive(Primitive::I32),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 248,
+            length: 4,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("J"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "strategyId",
+            id: 47,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 252,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("j"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "counterOrderId",
+            id: 48,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Optional,
+            value_ref: None,
+            offset: 260,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("U"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[
+        DataMeta {
+            name: "symbol",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: Some("s"),
+        },
+        DataMeta {
+            name: "clientOrderId",
+            id: 201,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: Some("c"),
+        },
+        DataMeta {
+            name: "origClientOrderId",
+            id: 202,
+            data_type: DataType::OptionalString,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: Some("C"),
+        },
+        DataMeta {
+            name: "commissionAsset",
+            id: 203,
+            data_type: DataType::OptionalString,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: Some("N"),
+        },
+        DataMeta {
+            name: "rejectReason",
+            id: 204,
+            data_type: DataType::OptionalString,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: Some("r"),
+        },
+        DataMeta {
+            name: "counterSymbol",
+            id: 205,
+            data_type: DataType::OptionalString,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: Some("Cs"),
+        },
+    ],
+};
+
+pub static EXTERNAL_LOCK_UPDATE_EVENT: MessageMeta = MessageMeta {
+    name: "ExternalLockUpdateEvent",
+    id: 604,
+    block_length: 25,
+    since_version: 1,
+    description: None,
+    event_type: Some("externalLockUpdate"),
+    fields: &[
+        FieldMeta {
+            name: "eventTime",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("E"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "clearTime",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("T"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "qtyExponent",
+            id: 3,
+            field_type: FieldType::Exponent,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 16,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: None,
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "lockedQtyDelta",
+            id: 4,
+            field_type: FieldType::Mantissa64,
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 17,
+            length: 8,
+            since_version: 0,
+            exponent: Some("qtyExponent"),
+            json_path: Some("d"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[],
+    data: &[DataMeta {
+        name: "asset",
+        id: 200,
+        data_type: DataType::String,
+        length_type: Primitive::U8,
+        since_version: 0,
+        json_path: Some("a"),
+    }],
+};
+
+pub static LIST_STATUS_EVENT: MessageMeta = MessageMeta {
+    name: "ListStatusEvent",
+    id: 606,
+    block_length: 27,
+    since_version: 1,
+    description: None,
+    event_type: Some("listStatus"),
+    fields: &[
+        FieldMeta {
+            name: "eventTime",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("E"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "transactTime",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("T"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "orderListId",
+            id: 3,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 16,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("g"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "contingencyType",
+            id: 4,
+            field_type: FieldType::Enum(&CONTINGENCY_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 24,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("c"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "listStatusType",
+            id: 5,
+            field_type: FieldType::Enum(&LIST_STATUS_TYPE),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 25,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("l"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "listOrderStatus",
+            id: 6,
+            field_type: FieldType::Enum(&LIST_ORDER_STATUS),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 26,
+            length: 1,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("L"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[GroupMeta {
+        name: "orders",
+        id: 100,
+        block_length: 8,
+        num_in_group_type: Primitive::U16,
+        since_version: 0,
+        json_path: Some("O"),
+        json_omit_null: false,
+        fields: &[FieldMeta {
+            name: "orderId",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("i"),
+            json_default_value: None,
+            json_omit_null: false,
+        }],
+        groups: &[],
+        data: &[
+            DataMeta {
+                name: "symbol",
+                id: 200,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: Some("s"),
+            },
+            DataMeta {
+                name: "clientOrderId",
+                id: 201,
+                data_type: DataType::String,
+                length_type: Primitive::U8,
+                since_version: 0,
+                json_path: Some("c"),
+            },
+        ],
+    }],
+    data: &[
+        DataMeta {
+            name: "symbol",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: Some("s"),
+        },
+        DataMeta {
+            name: "listClientOrderId",
+            id: 201,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: Some("C"),
+        },
+        DataMeta {
+            name: "rejectReason",
+            id: 202,
+            data_type: DataType::OptionalString,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: Some("r"),
+        },
+    ],
+};
+
+pub static OUTBOUND_ACCOUNT_POSITION_EVENT: MessageMeta = MessageMeta {
+    name: "OutboundAccountPositionEvent",
+    id: 607,
+    block_length: 16,
+    since_version: 1,
+    description: None,
+    event_type: Some("outboundAccountPosition"),
+    fields: &[
+        FieldMeta {
+            name: "eventTime",
+            id: 1,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 0,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("E"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+        FieldMeta {
+            name: "updateTime",
+            id: 2,
+            field_type: FieldType::Primitive(Primitive::I64),
+            presence: Presence::Required,
+            value_ref: None,
+            offset: 8,
+            length: 8,
+            since_version: 0,
+            exponent: None,
+            json_path: Some("u"),
+            json_default_value: None,
+            json_omit_null: false,
+        },
+    ],
+    groups: &[GroupMeta {
+        name: "balances",
+        id: 100,
+        block_length: 17,
+        num_in_group_type: Primitive::U32,
+        since_version: 0,
+        json_path: Some("B"),
+        json_omit_null: false,
+        fields: &[
+            FieldMeta {
+                name: "exponent",
+                id: 1,
+                field_type: FieldType::Exponent,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 0,
+                length: 1,
+                since_version: 0,
+                exponent: None,
+                json_path: None,
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "free",
+                id: 2,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 1,
+                length: 8,
+                since_version: 0,
+                exponent: Some("exponent"),
+                json_path: Some("f"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+            FieldMeta {
+                name: "locked",
+                id: 3,
+                field_type: FieldType::Mantissa64,
+                presence: Presence::Required,
+                value_ref: None,
+                offset: 9,
+                length: 8,
+                since_version: 0,
+                exponent: Some("exponent"),
+                json_path: Some("l"),
+                json_default_value: None,
+                json_omit_null: false,
+            },
+        ],
+        groups: &[],
+        data: &[DataMeta {
+            name: "asset",
+            id: 200,
+            data_type: DataType::String,
+            length_type: Primitive::U8,
+            since_version: 0,
+            json_path: Some("a"),
+        }],
+    }],
+    data: &[],
+};
+
+pub static ENUMS: &[&EnumMeta] = &[
+    &ACCOUNT_TYPE,
+    &ALLOCATION_REPORT_TYPE,
+    &ALLOCATION_STATUS,
+    &ALLOCATION_TRANSACTION_TYPE,
+    &ALLOCATION_TYPE,
+    &BOOL_ENUM,
+    &CANCEL_REPLACE_STATUS,
+    &COMMISSION_TYPE,
+    &CONTINGENCY_TYPE,
+    &COUNTER_PARTY_ROLE,
+    &EXECUTION_TYPE,
+    &EXTERNAL_SETTLEMENT_SIDE,
+    &FILTER_TYPE,
+    &FLOOR,
+    &LIST_ORDER_STATUS,
+    &LIST_STATUS_TYPE,
+    &MATCH_TYPE,
+    &ORDER_CAPACITY,
+    &ORDER_SIDE,
+    &ORDER_STATUS,
+    &ORDER_TYPE,
+    &RATE_LIMIT_INTERVAL,
+    &RATE_LIMIT_TYPE,
+    &SELF_TRADE_PREVENTION_MODE,
+    &SYMBOL_STATUS,
+    &TIME_IN_FORCE,
+    &TRADE_TYPE,
+];
+
+pub static SETS: &[&SetMeta] = &[
+    &ALLOWED_SELF_TRADE_PREVENTION_MODES,
+    &EXTERNAL_SETTLEMENT_FLAGS,
+    &ORDER_TYPES,
+];
+
+pub static MESSAGES: &[&MessageMeta] = &[
+    &PRICE_FILTER,
+    &PERCENT_PRICE_FILTER,
+    &PERCENT_PRICE_BY_SIDE_FILTER,
+    &LOT_SIZE_FILTER,
+    &MIN_NOTIONAL_FILTER,
+    &NOTIONAL_FILTER,
+    &ICEBERG_PARTS_FILTER,
+    &MARKET_LOT_SIZE_FILTER,
+    &MAX_NUM_ORDERS_FILTER,
+    &MAX_NUM_ALGO_ORDERS_FILTER,
+    &MAX_NUM_ICEBERG_ORDERS_FILTER,
+    &MAX_POSITION_FILTER,
+    &TRAILING_DELTA_FILTER,
+    &TPLUS_SELL_FILTER,
+    &EXCHANGE_MAX_NUM_ORDERS_FILTER,
+    &EXCHANGE_MAX_NUM_ALGO_ORDERS_FILTER,
+    &EXCHANGE_MAX_NUM_ICEBERG_ORDERS_FILTER,
+    &WEB_SOCKET_RESPONSE,
+    &WEB_SOCKET_SESSION_LOGON_RESPONSE,
+    &WEB_SOCKET_SESSION_STATUS_RESPONSE,
+    &WEB_SOCKET_SESSION_LOGOUT_RESPONSE,
+    &ERROR_RESPONSE,
+    &PING_RESPONSE,
+    &SERVER_TIME_RESPONSE,
+    &EXCHANGE_INFO_RESPONSE,
+    &DEPTH_RESPONSE,
+    &TRADES_RESPONSE,
+    &AGG_TRADES_RESPONSE,
+    &KLINES_RESPONSE,
+    &AVERAGE_PRICE_RESPONSE,
+    &TICKER24H_SYMBOL_FULL_RESPONSE,
+    &TICKER24H_FULL_RESPONSE,
+    &TICKER24H_SYMBOL_MINI_RESPONSE,
+    &TICKER24H_MINI_RESPONSE,
+    &PRICE_TICKER_SYMBOL_RESPONSE,
+    &PRICE_TICKER_RESPONSE,
+    &BOOK_TICKER_SYMBOL_RESPONSE,
+    &BOOK_TICKER_RESPONSE,
+    &TICKER_SYMBOL_FULL_RESPONSE,
+    &TICKER_FULL_RESPONSE,
+    &TICKER_SYMBOL_MINI_RESPONSE,
+    &TICKER_MINI_RESPONSE,
+    &NEW_ORDER_ACK_RESPONSE,
+    &NEW_ORDER_RESULT_RESPONSE,
+    &NEW_ORDER_FULL_RESPONSE,
+    &ORDER_TEST_RESPONSE,
+    &ORDER_RESPONSE,
+    &CANCEL_ORDER_RESPONSE,
+    &CANCEL_OPEN_ORDERS_RESPONSE,
+    &CANCEL_REPLACE_ORDER_RESPONSE,
+    &ORDERS_RESPONSE,
+    &NEW_ORDER_LIST_ACK_RESPONSE,
+    &NEW_ORDER_LIST_RESULT_RESPONSE,
+    &NEW_ORDER_LIST_FULL_RESPONSE,
+    &CANCEL_ORDER_LIST_RESPONSE,
+    &ORDER_LIST_RESPONSE,
+    &ORDER_LISTS_RESPONSE,
+    &ORDER_TEST_WITH_COMMISSIONS_RESPONSE,
+    &ACCOUNT_RESPONSE,
+    &ACCOUNT_TRADES_RESPONSE,
+    &ACCOUNT_ORDER_RATE_LIMIT_RESPONSE,
+    &ACCOUNT_PREVENTED_MATCHES_RESPONSE,
+    &ACCOUNT_ALLOCATIONS_RESPONSE,
+    &ACCOUNT_COMMISSION_RESPONSE,
+    &USER_DATA_STREAM_START_RESPONSE,
+    &USER_DATA_STREAM_PING_RESPONSE,
+    &USER_DATA_STREAM_STOP_RESPONSE,
+    &USER_DATA_STREAM_SUBSCRIBE_RESPONSE,
+    &USER_DATA_STREAM_UNSUBSCRIBE_RESPONSE,
+    &BALANCE_UPDATE_EVENT,
+    &EVENT_STREAM_TERMINATED_EVENT,
+    &EXECUTION_REPORT_EVENT,
+    &EXTERNAL_LOCK_UPDATE_EVENT,
+    &LIST_STATUS_EVENT,
+    &OUTBOUND_ACCOUNT_POSITION_EVENT,
+];
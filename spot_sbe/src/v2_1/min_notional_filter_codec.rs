@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::MinNotionalFilterDecoder;
 pub use encoder::MinNotionalFilterEncoder;
+#[cfg(feature = "serde")]
+pub use owned::MinNotionalFilter;
 
 pub const SBE_BLOCK_LENGTH: u16 = 14;
 pub const SBE_TEMPLATE_ID: u16 = 5;
@@ -93,6 +95,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'minNotional' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn min_notional_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.min_notional(mantissa);
+            Ok(())
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn apply_to_market(&mut self, value: BoolEnum) {
@@ -146,6 +162,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> MinNotionalFilterDecoder<'a> {
@@ -155,7 +176,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -163,7 +185,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -171,13 +193,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -202,6 +230,12 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 1)
         }
 
+        /// 'minNotional' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn min_notional_decimal(&self) -> Decimal {
+            Decimal::new(self.min_notional(), self.price_exponent())
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn apply_to_market(&self) -> BoolEnum {
@@ -215,3 +249,65 @@ pub mod decoder {
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct MinNotionalFilter {
+        pub price_exponent: i8,
+        pub min_notional: Decimal,
+        pub apply_to_market: BoolEnum,
+        pub avg_price_mins: i32,
+    }
+
+    impl MinNotionalFilter {
+        pub fn from_decoder(decoder: &mut MinNotionalFilterDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                min_notional: decoder.min_notional_decimal(),
+                apply_to_market: decoder.apply_to_market(),
+                avg_price_mins: decoder.avg_price_mins(),
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut MinNotionalFilterEncoder<'_>) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(self.price_exponent, [self.min_notional]));
+            encoder.min_notional_decimal(self.min_notional)?;
+            encoder.apply_to_market(self.apply_to_market);
+            encoder.avg_price_mins(self.avg_price_mins);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            14
+        }
+    }
+
+    impl OwnedMessage for MinNotionalFilter {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = MinNotionalFilterDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = MinNotionalFilterEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
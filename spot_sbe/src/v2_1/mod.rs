@@ -0,0 +1,244 @@
+//! Codecs generated from `spot_2_1.xml`, schema ID 2 version 1.
+
+#[cfg(feature = "serde")]
+use crate::{checked_length, fit_exponent, fit_optional_exponent, OwnedMessage};
+use crate::{Decimal, Decoder, Encoder, ReadBuf, Reader, SbeErr, SbeResult, WriteBuf, Writer};
+
+pub mod account_allocations_response_codec;
+pub mod account_commission_response_codec;
+pub mod account_order_rate_limit_response_codec;
+pub mod account_prevented_matches_response_codec;
+pub mod account_response_codec;
+pub mod account_trades_response_codec;
+pub mod account_type;
+pub mod agg_trades_response_codec;
+pub mod allocation_report_type;
+pub mod allocation_status;
+pub mod allocation_transaction_type;
+pub mod allocation_type;
+pub mod allowed_self_trade_prevention_modes;
+pub mod any_message;
+pub mod average_price_response_codec;
+pub mod balance_update_event_codec;
+pub mod book_ticker_response_codec;
+pub mod book_ticker_symbol_response_codec;
+pub mod bool_enum;
+pub mod cancel_open_orders_response_codec;
+pub mod cancel_order_list_response_codec;
+pub mod cancel_order_response_codec;
+pub mod cancel_replace_order_response_codec;
+pub mod cancel_replace_status;
+pub mod commission_type;
+pub mod contingency_type;
+pub mod counter_party_role;
+pub mod depth_response_codec;
+pub mod error_response_codec;
+pub mod event_stream_terminated_event_codec;
+pub mod exchange_info_response_codec;
+pub mod exchange_max_num_algo_orders_filter_codec;
+pub mod exchange_max_num_iceberg_orders_filter_codec;
+pub mod exchange_max_num_orders_filter_codec;
+pub mod execution_report_event_codec;
+pub mod execution_type;
+pub mod external_lock_update_event_codec;
+pub mod external_settlement_flags;
+pub mod external_settlement_side;
+pub mod filter_type;
+pub mod floor;
+pub mod group_size_16_encoding_codec;
+pub mod group_size_encoding_codec;
+pub mod iceberg_parts_filter_codec;
+pub mod klines_response_codec;
+pub mod list_order_status;
+pub mod list_status_event_codec;
+pub mod list_status_type;
+pub mod lot_size_filter_codec;
+pub mod market_lot_size_filter_codec;
+pub mod match_type;
+pub mod max_num_algo_orders_filter_codec;
+pub mod max_num_iceberg_orders_filter_codec;
+pub mod max_num_orders_filter_codec;
+pub mod max_position_filter_codec;
+pub mod message_data_16_codec;
+pub mod message_data_8_codec;
+pub mod message_data_codec;
+pub mod message_header_codec;
+pub(crate) mod meta_tables;
+pub mod min_notional_filter_codec;
+pub mod new_order_ack_response_codec;
+pub mod new_order_full_response_codec;
+pub mod new_order_list_ack_response_codec;
+pub mod new_order_list_full_response_codec;
+pub mod new_order_list_result_response_codec;
+pub mod new_order_result_response_codec;
+pub mod notional_filter_codec;
+pub mod optional_message_data_16_codec;
+pub mod optional_message_data_codec;
+pub mod optional_var_string_8_codec;
+pub mod optional_var_string_codec;
+pub mod order_capacity;
+pub mod order_list_response_codec;
+pub mod order_lists_response_codec;
+pub mod order_response_codec;
+pub mod order_side;
+pub mod order_status;
+pub mod order_test_response_codec;
+pub mod order_test_with_commissions_response_codec;
+pub mod order_type;
+pub mod order_types;
+pub mod orders_response_codec;
+pub mod outbound_account_position_event_codec;
+pub mod percent_price_by_side_filter_codec;
+pub mod percent_price_filter_codec;
+pub mod ping_response_codec;
+pub mod price_filter_codec;
+pub mod price_ticker_response_codec;
+pub mod price_ticker_symbol_response_codec;
+pub mod rate_limit_interval;
+pub mod rate_limit_type;
+pub mod self_trade_prevention_mode;
+pub mod server_time_response_codec;
+pub mod symbol_status;
+pub mod ticker_24_hf_ull_response_codec;
+pub mod ticker_24_hm_ini_response_codec;
+pub mod ticker_24_hs_ymbol_full_response_codec;
+pub mod ticker_24_hs_ymbol_mini_response_codec;
+pub mod ticker_full_response_codec;
+pub mod ticker_mini_response_codec;
+pub mod ticker_symbol_full_response_codec;
+pub mod ticker_symbol_mini_response_codec;
+pub mod time_in_force;
+pub mod tp_lus_sell_filter_codec;
+pub mod trade_type;
+pub mod trades_response_codec;
+pub mod trailing_delta_filter_codec;
+pub mod user_data_stream_ping_response_codec;
+pub mod user_data_stream_start_response_codec;
+pub mod user_data_stream_stop_response_codec;
+pub mod user_data_stream_subscribe_response_codec;
+pub mod user_data_stream_unsubscribe_response_codec;
+pub mod var_string_8_codec;
+pub mod var_string_codec;
+pub mod web_socket_response_codec;
+pub mod web_socket_session_logon_response_codec;
+pub mod web_socket_session_logout_response_codec;
+pub mod web_socket_session_status_response_codec;
+
+pub use self::account_allocations_response_codec::*;
+pub use self::account_commission_response_codec::*;
+pub use self::account_order_rate_limit_response_codec::*;
+pub use self::account_prevented_matches_response_codec::*;
+pub use self::account_response_codec::*;
+pub use self::account_trades_response_codec::*;
+pub use self::account_type::*;
+pub use self::agg_trades_response_codec::*;
+pub use self::allocation_report_type::*;
+pub use self::allocation_status::*;
+pub use self::allocation_transaction_type::*;
+pub use self::allocation_type::*;
+pub use self::allowed_self_trade_prevention_modes::*;
+pub use self::any_message::*;
+pub use self::average_price_response_codec::*;
+pub use self::balance_update_event_codec::*;
+pub use self::book_ticker_response_codec::*;
+pub use self::book_ticker_symbol_response_codec::*;
+pub use self::bool_enum::*;
+pub use self::cancel_open_orders_response_codec::*;
+pub use self::cancel_order_list_response_codec::*;
+pub use self::cancel_order_response_codec::*;
+pub use self::cancel_replace_order_response_codec::*;
+pub use self::cancel_replace_status::*;
+pub use self::commission_type::*;
+pub use self::contingency_type::*;
+pub use self::counter_party_role::*;
+pub use self::depth_response_codec::*;
+pub use self::error_response_codec::*;
+pub use self::event_stream_terminated_event_codec::*;
+pub use self::exchange_info_response_codec::*;
+pub use self::exchange_max_num_algo_orders_filter_codec::*;
+pub use self::exchange_max_num_iceberg_orders_filter_codec::*;
+pub use self::exchange_max_num_orders_filter_codec::*;
+pub use self::execution_report_event_codec::*;
+pub use self::execution_type::*;
+pub use self::external_lock_update_event_codec::*;
+pub use self::external_settlement_flags::*;
+pub use self::external_settlement_side::*;
+pub use self::filter_type::*;
+pub use self::floor::*;
+pub use self::group_size_16_encoding_codec::*;
+pub use self::group_size_encoding_codec::*;
+pub use self::iceberg_parts_filter_codec::*;
+pub use self::klines_response_codec::*;
+pub use self::list_order_status::*;
+pub use self::list_status_event_codec::*;
+pub use self::list_status_type::*;
+pub use self::lot_size_filter_codec::*;
+pub use self::market_lot_size_filter_codec::*;
+pub use self::match_type::*;
+pub use self::max_num_algo_orders_filter_codec::*;
+pub use self::max_num_iceberg_orders_filter_codec::*;
+pub use self::max_num_orders_filter_codec::*;
+pub use self::max_position_filter_codec::*;
+pub use self::message_data_16_codec::*;
+pub use self::message_data_8_codec::*;
+pub use self::message_data_codec::*;
+pub use self::message_header_codec::*;
+pub use self::min_notional_filter_codec::*;
+pub use self::new_order_ack_response_codec::*;
+pub use self::new_order_full_response_codec::*;
+pub use self::new_order_list_ack_response_codec::*;
+pub use self::new_order_list_full_response_codec::*;
+pub use self::new_order_list_result_response_codec::*;
+pub use self::new_order_result_response_codec::*;
+pub use self::notional_filter_codec::*;
+pub use self::optional_message_data_16_codec::*;
+pub use self::optional_message_data_codec::*;
+pub use self::optional_var_string_8_codec::*;
+pub use self::optional_var_string_codec::*;
+pub use self::order_capacity::*;
+pub use self::order_list_response_codec::*;
+pub use self::order_lists_response_codec::*;
+pub use self::order_response_codec::*;
+pub use self::order_side::*;
+pub use self::order_status::*;
+pub use self::order_test_response_codec::*;
+pub use self::order_test_with_commissions_response_codec::*;
+pub use self::order_type::*;
+pub use self::order_types::*;
+pub use self::orders_response_codec::*;
+pub use self::outbound_account_position_event_codec::*;
+pub use self::percent_price_by_side_filter_codec::*;
+pub use self::percent_price_filter_codec::*;
+pub use self::ping_response_codec::*;
+pub use self::price_filter_codec::*;
+pub use self::price_ticker_response_codec::*;
+pub use self::price_ticker_symbol_response_codec::*;
+pub use self::rate_limit_interval::*;
+pub use self::rate_limit_type::*;
+pub use self::self_trade_prevention_mode::*;
+pub use self::server_time_response_codec::*;
+pub use self::symbol_status::*;
+pub use self::ticker_24_hf_ull_response_codec::*;
+pub use self::ticker_24_hm_ini_response_codec::*;
+pub use self::ticker_24_hs_ymbol_full_response_codec::*;
+pub use self::ticker_24_hs_ymbol_mini_response_codec::*;
+pub use self::ticker_full_response_codec::*;
+pub use self::ticker_mini_response_codec::*;
+pub use self::ticker_symbol_full_response_codec::*;
+pub use self::ticker_symbol_mini_response_codec::*;
+pub use self::time_in_force::*;
+pub use self::tp_lus_sell_filter_codec::*;
+pub use self::trade_type::*;
+pub use self::trades_response_codec::*;
+pub use self::trailing_delta_filter_codec::*;
+pub use self::user_data_stream_ping_response_codec::*;
+pub use self::user_data_stream_start_response_codec::*;
+pub use self::user_data_stream_stop_response_codec::*;
+pub use self::user_data_stream_subscribe_response_codec::*;
+pub use self::user_data_stream_unsubscribe_response_codec::*;
+pub use self::var_string_8_codec::*;
+pub use self::var_string_codec::*;
+pub use self::web_socket_response_codec::*;
+pub use self::web_socket_session_logon_response_codec::*;
+pub use self::web_socket_session_logout_response_codec::*;
+pub use self::web_socket_session_status_response_codec::*;
@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::NewOrderFullResponseDecoder;
 pub use encoder::NewOrderFullResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::NewOrderFullResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 142;
 pub const SBE_TEMPLATE_ID: u16 = 302;
@@ -147,6 +149,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'price' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'origQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -161,6 +177,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'origQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn orig_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.orig_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'executedQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -175,6 +205,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'executedQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn executed_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.executed_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'cummulativeQuoteQty'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -189,6 +233,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'cummulativeQuoteQty' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn cummulative_quote_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.cummulative_quote_qty(mantissa);
+            Ok(())
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn status(&mut self, value: OrderStatus) {
@@ -231,6 +289,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'stopPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn stop_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.stop_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.stop_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'trailingDelta'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -287,6 +363,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'icebergQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn iceberg_qty_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.iceberg_qty(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.iceberg_qty(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'strategyId'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -364,6 +458,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'preventedQuantity' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn prevented_quantity_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.prevented_quantity(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.prevented_quantity(mantissa);
+            Ok(())
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn used_sor(&mut self, value: BoolEnum) {
@@ -385,6 +497,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'origQuoteOrderQty' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn orig_quote_order_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.orig_quote_order_qty(mantissa);
+            Ok(())
+        }
+
         /// GROUP ENCODER (id=100)
         #[inline]
         pub fn fills_encoder(
@@ -577,6 +703,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'commission' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn commission_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.commission(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'tradeId'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -784,6 +924,98 @@ pub mod encoder {
             self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
         }
     }
+
+    impl FillsEncoder<NewOrderFullResponseEncoder<'_>> {
+        /// 'price' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
+        /// 'qty' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.qty(mantissa);
+            Ok(())
+        }
+    }
+
+    impl PreventedMatchesEncoder<NewOrderFullResponseEncoder<'_>> {
+        /// 'price' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
+        /// 'takerPreventedQuantity' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn taker_prevented_quantity_decimal(
+            &mut self,
+            value: Option<Decimal>,
+        ) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.taker_prevented_quantity(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.taker_prevented_quantity(mantissa);
+            Ok(())
+        }
+
+        /// 'makerPreventedQuantity' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn maker_prevented_quantity_decimal(
+            &mut self,
+            value: Option<Decimal>,
+        ) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.maker_prevented_quantity(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.maker_prevented_quantity(mantissa);
+            Ok(())
+        }
+    }
 } // end encoder
 
 pub mod decoder {
@@ -816,6 +1048,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> NewOrderFullResponseDecoder<'a> {
@@ -825,7 +1062,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -833,7 +1071,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -841,13 +1079,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -895,24 +1139,48 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 26)
         }
 
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> Decimal {
+            Decimal::new(self.price(), self.price_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn orig_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 34)
         }
 
+        /// 'origQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn orig_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.orig_qty(), self.qty_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn executed_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 42)
         }
 
+        /// 'executedQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn executed_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.executed_qty(), self.qty_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn cummulative_quote_qty(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 50)
         }
 
+        /// 'cummulativeQuoteQty' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn cummulative_quote_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.cummulative_quote_qty(), self.price_exponent())
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn status(&self) -> OrderStatus {
@@ -948,6 +1216,13 @@ pub mod decoder {
             }
         }
 
+        /// 'stopPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn stop_price_decimal(&self) -> Option<Decimal> {
+            self.stop_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn trailing_delta(&self) -> Option<i64> {
@@ -992,6 +1267,13 @@ pub mod decoder {
             }
         }
 
+        /// 'icebergQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn iceberg_qty_decimal(&self) -> Option<Decimal> {
+            self.iceberg_qty()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn strategy_id(&self) -> Option<i64> {
@@ -1054,6 +1336,13 @@ pub mod decoder {
             }
         }
 
+        /// 'preventedQuantity' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn prevented_quantity_decimal(&self) -> Option<Decimal> {
+            self.prevented_quantity()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
         /// REQUIRED enum
         #[inline]
         pub fn used_sor(&self) -> BoolEnum {
@@ -1063,114 +1352,151 @@ pub mod decoder {
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn orig_quote_order_qty(&self) -> i64 {
-            if self.acting_version > 0 && self.acting_version < 1 {
+            if self.acting_version < 1 {
                 return -9223372036854775808_i64;
             }
 
             self.get_buf().get_i64_at(self.offset + 134)
         }
 
+        /// 'origQuoteOrderQty' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn orig_quote_order_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.orig_quote_order_qty(), self.price_exponent())
+        }
+
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn fills_decoder(self) -> FillsDecoder<Self> {
+        pub fn fills_decoder(self) -> SbeResult<FillsDecoder<'a, Self>> {
             FillsDecoder::default().wrap(self)
         }
 
         /// GROUP DECODER (id=101)
         #[inline]
-        pub fn prevented_matches_decoder(self) -> PreventedMatchesDecoder<Self> {
+        pub fn prevented_matches_decoder(self) -> SbeResult<PreventedMatchesDecoder<'a, Self>> {
             PreventedMatchesDecoder::default().wrap(self)
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn client_order_id_decoder(&mut self) -> (usize, usize) {
+        pub fn client_order_id_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn client_order_id_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn client_order_id_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct FillsDecoder<P> {
+    pub struct FillsDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for FillsDecoder<P>
+    impl<'a, P> Reader<'a> for FillsDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for FillsDecoder<P>
+    impl<'a, P> Decoder<'a> for FillsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> FillsDecoder<P>
+    impl<'a, P> FillsDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 42;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='fills', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=42, offset=142, componentTokenCount=36, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -1184,14 +1510,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(42))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// primitive field - 'REQUIRED'
@@ -1224,6 +1551,12 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 18)
         }
 
+        /// 'commission' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn commission_decimal(&self) -> Decimal {
+            Decimal::new(self.commission(), self.commission_exponent())
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn trade_id(&self) -> Option<i64> {
@@ -1248,81 +1581,113 @@ pub mod decoder {
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn commission_asset_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn commission_asset_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn commission_asset_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn commission_asset_slice(
+            &'a self,
+            coordinates: (usize, usize),
+        ) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct PreventedMatchesDecoder<P> {
+    pub struct PreventedMatchesDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for PreventedMatchesDecoder<P>
+    impl<'a, P> Reader<'a> for PreventedMatchesDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for PreventedMatchesDecoder<P>
+    impl<'a, P> Decoder<'a> for PreventedMatchesDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> PreventedMatchesDecoder<P>
+    impl<'a, P> PreventedMatchesDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 40;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='preventedMatches', referencedName='null', description='null', packageName='null', id=101, version=0, deprecated=0, encodedLength=40, offset=-1, componentTokenCount=27, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -1336,14 +1701,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(40))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// primitive field - 'REQUIRED'
@@ -1398,20 +1764,447 @@ pub mod decoder {
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn maker_symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn maker_symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn maker_symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn maker_symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
+        }
+    }
+
+    impl FillsDecoder<'_, NewOrderFullResponseDecoder<'_>> {
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.price(), exponent))
+        }
+
+        /// 'qty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn qty_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(Decimal::new(self.qty(), exponent))
+        }
+    }
+
+    impl PreventedMatchesDecoder<'_, NewOrderFullResponseDecoder<'_>> {
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(self
+                .price()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+
+        /// 'takerPreventedQuantity' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn taker_prevented_quantity_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(self
+                .taker_prevented_quantity()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+
+        /// 'makerPreventedQuantity' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn maker_prevented_quantity_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(self
+                .maker_prevented_quantity()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct NewOrderFullResponse {
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub order_id: i64,
+        pub order_list_id: Option<i64>,
+        pub transact_time: i64,
+        pub price: Decimal,
+        pub orig_qty: Decimal,
+        pub executed_qty: Decimal,
+        pub cummulative_quote_qty: Decimal,
+        pub status: OrderStatus,
+        pub time_in_force: TimeInForce,
+        pub order_type: OrderType,
+        pub side: OrderSide,
+        pub stop_price: Option<Decimal>,
+        pub trailing_delta: Option<i64>,
+        pub trailing_time: Option<i64>,
+        pub working_time: Option<i64>,
+        pub iceberg_qty: Option<Decimal>,
+        pub strategy_id: Option<i64>,
+        pub strategy_type: Option<i32>,
+        pub order_capacity: OrderCapacity,
+        pub working_floor: Floor,
+        pub self_trade_prevention_mode: SelfTradePreventionMode,
+        pub trade_group_id: Option<i64>,
+        pub prevented_quantity: Option<Decimal>,
+        pub used_sor: BoolEnum,
+        pub orig_quote_order_qty: Decimal,
+        pub fills: Vec<Fills>,
+        pub prevented_matches: Vec<PreventedMatches>,
+        pub symbol: String,
+        pub client_order_id: String,
+    }
+
+    impl NewOrderFullResponse {
+        pub fn from_decoder(decoder: &mut NewOrderFullResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                order_id: decoder.order_id(),
+                order_list_id: decoder.order_list_id(),
+                transact_time: decoder.transact_time(),
+                price: decoder.price_decimal(),
+                orig_qty: decoder.orig_qty_decimal(),
+                executed_qty: decoder.executed_qty_decimal(),
+                cummulative_quote_qty: decoder.cummulative_quote_qty_decimal(),
+                status: decoder.status(),
+                time_in_force: decoder.time_in_force(),
+                order_type: decoder.order_type(),
+                side: decoder.side(),
+                stop_price: decoder.stop_price_decimal(),
+                trailing_delta: decoder.trailing_delta(),
+                trailing_time: decoder.trailing_time(),
+                working_time: decoder.working_time(),
+                iceberg_qty: decoder.iceberg_qty_decimal(),
+                strategy_id: decoder.strategy_id(),
+                strategy_type: decoder.strategy_type(),
+                order_capacity: decoder.order_capacity(),
+                working_floor: decoder.working_floor(),
+                self_trade_prevention_mode: decoder.self_trade_prevention_mode(),
+                trade_group_id: decoder.trade_group_id(),
+                prevented_quantity: decoder.prevented_quantity_decimal(),
+                used_sor: decoder.used_sor(),
+                orig_quote_order_qty: decoder.orig_quote_order_qty_decimal(),
+                fills: {
+                    let mut fills_decoder = core::mem::take(decoder).fills_decoder()?;
+                    let mut entries = Vec::new();
+                    while fills_decoder.advance()?.is_some() {
+                        entries.push(Fills::from_decoder(&mut fills_decoder)?);
+                    }
+                    *decoder = fills_decoder.parent()?;
+                    entries
+                },
+                prevented_matches: {
+                    let mut prevented_matches_decoder =
+                        core::mem::take(decoder).prevented_matches_decoder()?;
+                    let mut entries = Vec::new();
+                    while prevented_matches_decoder.advance()?.is_some() {
+                        entries.push(PreventedMatches::from_decoder(
+                            &mut prevented_matches_decoder,
+                        )?);
+                    }
+                    *decoder = prevented_matches_decoder.parent()?;
+                    entries
+                },
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                client_order_id: {
+                    let coordinates = decoder.client_order_id_decoder()?;
+                    let slice = decoder.client_order_id_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut NewOrderFullResponseEncoder<'_>) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                [
+                    self.price,
+                    self.cummulative_quote_qty,
+                    self.orig_quote_order_qty,
+                ]
+                .into_iter()
+                .chain(self.stop_price)
+                .chain(self.fills.iter().map(|entry| entry.price))
+                .chain(
+                    self.prevented_matches
+                        .iter()
+                        .filter_map(|entry| entry.price),
+                ),
+            ));
+            encoder.qty_exponent(fit_exponent(
+                self.qty_exponent,
+                [self.orig_qty, self.executed_qty]
+                    .into_iter()
+                    .chain(self.iceberg_qty)
+                    .chain(self.prevented_quantity)
+                    .chain(self.fills.iter().map(|entry| entry.qty))
+                    .chain(
+                        self.prevented_matches
+                            .iter()
+                            .filter_map(|entry| entry.taker_prevented_quantity),
+                    )
+                    .chain(
+                        self.prevented_matches
+                            .iter()
+                            .filter_map(|entry| entry.maker_prevented_quantity),
+                    ),
+            ));
+            encoder.order_id(self.order_id);
+            encoder.order_list_id(self.order_list_id.unwrap_or(i64::MIN));
+            encoder.transact_time(self.transact_time);
+            encoder.price_decimal(self.price)?;
+            encoder.orig_qty_decimal(self.orig_qty)?;
+            encoder.executed_qty_decimal(self.executed_qty)?;
+            encoder.cummulative_quote_qty_decimal(self.cummulative_quote_qty)?;
+            encoder.status(self.status);
+            encoder.time_in_force(self.time_in_force);
+            encoder.order_type(self.order_type);
+            encoder.side(self.side);
+            encoder.stop_price_decimal(self.stop_price)?;
+            encoder.trailing_delta(self.trailing_delta.unwrap_or(i64::MIN));
+            encoder.trailing_time(self.trailing_time.unwrap_or(i64::MIN));
+            encoder.working_time(self.working_time.unwrap_or(i64::MIN));
+            encoder.iceberg_qty_decimal(self.iceberg_qty)?;
+            encoder.strategy_id(self.strategy_id.unwrap_or(i64::MIN));
+            encoder.strategy_type(self.strategy_type.unwrap_or(i32::MIN));
+            encoder.order_capacity(self.order_capacity);
+            encoder.working_floor(self.working_floor);
+            encoder.self_trade_prevention_mode(self.self_trade_prevention_mode);
+            encoder.trade_group_id(self.trade_group_id.unwrap_or(i64::MIN));
+            encoder.prevented_quantity_decimal(self.prevented_quantity)?;
+            encoder.used_sor(self.used_sor);
+            encoder.orig_quote_order_qty_decimal(self.orig_quote_order_qty)?;
+            let count = checked_length::<u32>(self.fills.len())?;
+            let mut fills_encoder =
+                core::mem::take(encoder).fills_encoder(count, FillsEncoder::default());
+            for entry in &self.fills {
+                fills_encoder.advance()?;
+                entry.to_encoder(&mut fills_encoder)?;
+            }
+            *encoder = fills_encoder.parent()?;
+            let count = checked_length::<u32>(self.prevented_matches.len())?;
+            let mut prevented_matches_encoder = core::mem::take(encoder)
+                .prevented_matches_encoder(count, PreventedMatchesEncoder::default());
+            for entry in &self.prevented_matches {
+                prevented_matches_encoder.advance()?;
+                entry.to_encoder(&mut prevented_matches_encoder)?;
+            }
+            *encoder = prevented_matches_encoder.parent()?;
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            let value = &self.client_order_id;
+            checked_length::<u8>(value.len())?;
+            encoder.client_order_id(value);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            156 + self.fills.iter().map(Fills::encoded_length).sum::<usize>()
+                + self
+                    .prevented_matches
+                    .iter()
+                    .map(PreventedMatches::encoded_length)
+                    .sum::<usize>()
+                + self.symbol.len()
+                + self.client_order_id.len()
+        }
+    }
+
+    impl OwnedMessage for NewOrderFullResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = NewOrderFullResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = NewOrderFullResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Fills {
+        pub commission_exponent: i8,
+        pub match_type: MatchType,
+        pub price: Decimal,
+        pub qty: Decimal,
+        pub commission: Decimal,
+        pub trade_id: Option<i64>,
+        pub alloc_id: Option<i64>,
+        pub commission_asset: Option<String>,
+    }
+
+    impl Fills {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut FillsDecoder<'a, NewOrderFullResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                commission_exponent: decoder.commission_exponent(),
+                match_type: decoder.match_type(),
+                price: decoder.price_decimal()?,
+                qty: decoder.qty_decimal()?,
+                commission: decoder.commission_decimal(),
+                trade_id: decoder.trade_id(),
+                alloc_id: decoder.alloc_id(),
+                commission_asset: {
+                    let coordinates = decoder.commission_asset_decoder()?;
+                    let slice = decoder.commission_asset_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(
+                            core::str::from_utf8(slice)
+                                .map_err(|_| SbeErr::InvalidUtf8)?
+                                .to_owned(),
+                        )
+                    }
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut FillsEncoder<NewOrderFullResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.commission_exponent(fit_exponent(self.commission_exponent, [self.commission]));
+            encoder.match_type(self.match_type);
+            encoder.price_decimal(self.price)?;
+            encoder.qty_decimal(self.qty)?;
+            encoder.commission_decimal(self.commission)?;
+            encoder.trade_id(self.trade_id.unwrap_or(i64::MIN));
+            encoder.alloc_id(self.alloc_id.unwrap_or(i64::MIN));
+            let value = self.commission_asset.as_deref().unwrap_or_default();
+            checked_length::<u8>(value.len())?;
+            encoder.commission_asset(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            43 + self
+                .commission_asset
+                .as_ref()
+                .map_or(0, |value| value.len())
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PreventedMatches {
+        pub prevented_match_id: i64,
+        pub maker_order_id: Option<i64>,
+        pub price: Option<Decimal>,
+        pub taker_prevented_quantity: Option<Decimal>,
+        pub maker_prevented_quantity: Option<Decimal>,
+        pub maker_symbol: Option<String>,
+    }
+
+    impl PreventedMatches {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut PreventedMatchesDecoder<'a, NewOrderFullResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                prevented_match_id: decoder.prevented_match_id(),
+                maker_order_id: decoder.maker_order_id(),
+                price: decoder.price_decimal()?,
+                taker_prevented_quantity: decoder.taker_prevented_quantity_decimal()?,
+                maker_prevented_quantity: decoder.maker_prevented_quantity_decimal()?,
+                maker_symbol: {
+                    let coordinates = decoder.maker_symbol_decoder()?;
+                    let slice = decoder.maker_symbol_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(
+                            core::str::from_utf8(slice)
+                                .map_err(|_| SbeErr::InvalidUtf8)?
+                                .to_owned(),
+                        )
+                    }
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut PreventedMatchesEncoder<NewOrderFullResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.prevented_match_id(self.prevented_match_id);
+            encoder.maker_order_id(self.maker_order_id.unwrap_or(i64::MIN));
+            encoder.price_decimal(self.price)?;
+            encoder.taker_prevented_quantity_decimal(self.taker_prevented_quantity)?;
+            encoder.maker_prevented_quantity_decimal(self.maker_prevented_quantity)?;
+            let value = self.maker_symbol.as_deref().unwrap_or_default();
+            checked_length::<u8>(value.len())?;
+            encoder.maker_symbol(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            41 + self.maker_symbol.as_ref().map_or(0, |value| value.len())
+        }
+    }
+} // end owned
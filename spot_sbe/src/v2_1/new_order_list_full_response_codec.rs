@@ -0,0 +1,3179 @@
+use super::*;
+
+pub use decoder::NewOrderListFullResponseDecoder;
+pub use encoder::NewOrderListFullResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::NewOrderListFullResponse;
+
+pub const SBE_BLOCK_LENGTH: u16 = 21;
+pub const SBE_TEMPLATE_ID: u16 = 311;
+pub const SBE_SCHEMA_ID: u16 = 2;
+pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SEMANTIC_VERSION: &str = "5.2";
+
+pub mod encoder {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct NewOrderListFullResponseEncoder<'a> {
+        buf: WriteBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+    }
+
+    impl<'a> Writer<'a> for NewOrderListFullResponseEncoder<'a> {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            &mut self.buf
+        }
+    }
+
+    impl<'a> Encoder<'a> for NewOrderListFullResponseEncoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+    }
+
+    impl<'a> NewOrderListFullResponseEncoder<'a> {
+        pub fn wrap(mut self, buf: WriteBuf<'a>, offset: usize) -> Self {
+            let limit = offset + SBE_BLOCK_LENGTH as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self
+        }
+
+        #[inline]
+        pub fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        pub fn header(self, offset: usize) -> MessageHeaderEncoder<Self> {
+            let mut header = MessageHeaderEncoder::default().wrap(self, offset);
+            header.block_length(SBE_BLOCK_LENGTH);
+            header.template_id(SBE_TEMPLATE_ID);
+            header.schema_id(SBE_SCHEMA_ID);
+            header.version(SBE_SCHEMA_VERSION);
+            header
+        }
+
+        /// primitive field 'orderListId'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 0
+        /// - encodedLength: 8
+        #[inline]
+        pub fn order_list_id(&mut self, value: i64) {
+            let offset = self.offset;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn contingency_type(&mut self, value: ContingencyType) {
+            let offset = self.offset + 8;
+            self.get_buf_mut().put_u8_at(offset, value as u8)
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn list_status_type(&mut self, value: ListStatusType) {
+            let offset = self.offset + 9;
+            self.get_buf_mut().put_u8_at(offset, value as u8)
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn list_order_status(&mut self, value: ListOrderStatus) {
+            let offset = self.offset + 10;
+            self.get_buf_mut().put_u8_at(offset, value as u8)
+        }
+
+        /// primitive field 'transactionTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 11
+        /// - encodedLength: 8
+        #[inline]
+        pub fn transaction_time(&mut self, value: i64) {
+            let offset = self.offset + 11;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'priceExponent'
+        /// - min value: -127
+        /// - max value: 127
+        /// - null value: -128
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 19
+        /// - encodedLength: 1
+        #[inline]
+        pub fn price_exponent(&mut self, value: i8) {
+            let offset = self.offset + 19;
+            self.get_buf_mut().put_i8_at(offset, value);
+        }
+
+        /// primitive field 'qtyExponent'
+        /// - min value: -127
+        /// - max value: 127
+        /// - null value: -128
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 20
+        /// - encodedLength: 1
+        #[inline]
+        pub fn qty_exponent(&mut self, value: i8) {
+            let offset = self.offset + 20;
+            self.get_buf_mut().put_i8_at(offset, value);
+        }
+
+        /// GROUP ENCODER (id=100)
+        #[inline]
+        pub fn orders_encoder(
+            self,
+            count: u16,
+            orders_encoder: OrdersEncoder<Self>,
+        ) -> OrdersEncoder<Self> {
+            orders_encoder.wrap(self, count)
+        }
+
+        /// GROUP ENCODER (id=101)
+        #[inline]
+        pub fn order_reports_encoder(
+            self,
+            count: u16,
+            order_reports_encoder: OrderReportsEncoder<Self>,
+        ) -> OrderReportsEncoder<Self> {
+            order_reports_encoder.wrap(self, count)
+        }
+
+        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn list_client_order_id(&mut self, value: &str) {
+            let limit = self.get_limit();
+            let data_length = value.len();
+            self.set_limit(limit + 1 + data_length);
+            self.get_buf_mut().put_u8_at(limit, data_length as u8);
+            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
+        }
+
+        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn symbol(&mut self, value: &str) {
+            let limit = self.get_limit();
+            let data_length = value.len();
+            self.set_limit(limit + 1 + data_length);
+            self.get_buf_mut().put_u8_at(limit, data_length as u8);
+            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct OrdersEncoder<P> {
+        parent: Option<P>,
+        count: u16,
+        index: usize,
+        offset: usize,
+        initial_limit: usize,
+    }
+
+    impl<'a, P> Writer<'a> for OrdersEncoder<P>
+    where
+        P: Writer<'a> + Default,
+    {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            if let Some(parent) = self.parent.as_mut() {
+                parent.get_buf_mut()
+            } else {
+                panic!("parent was None")
+            }
+        }
+    }
+
+    impl<'a, P> Encoder<'a> for OrdersEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.parent.as_ref().expect("parent missing").get_limit()
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.parent
+                .as_mut()
+                .expect("parent missing")
+                .set_limit(limit);
+        }
+    }
+
+    impl<'a, P> OrdersEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        pub fn wrap(mut self, mut parent: P, count: u16) -> Self {
+            let initial_limit = parent.get_limit();
+            parent.set_limit(initial_limit + 4);
+            parent
+                .get_buf_mut()
+                .put_u16_at(initial_limit, Self::block_length());
+            parent.get_buf_mut().put_u16_at(initial_limit + 2, count);
+            self.parent = Some(parent);
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = usize::MAX;
+            self.initial_limit = initial_limit;
+            self
+        }
+
+        #[inline]
+        pub fn block_length() -> u16 {
+            8
+        }
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }
+
+        /// will return Some(current index) when successful otherwise None
+        #[inline]
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+            if let Some(parent) = self.parent.as_mut() {
+                self.offset = parent.get_limit();
+                parent.set_limit(self.offset + Self::block_length() as usize);
+                self.index = index;
+                Ok(Some(index))
+            } else {
+                Err(SbeErr::ParentNotSet)
+            }
+        }
+
+        /// primitive field 'orderId'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 0
+        /// - encodedLength: 8
+        #[inline]
+        pub fn order_id(&mut self, value: i64) {
+            let offset = self.offset;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn symbol(&mut self, value: &str) {
+            let limit = self.get_limit();
+            let data_length = value.len();
+            self.set_limit(limit + 1 + data_length);
+            self.get_buf_mut().put_u8_at(limit, data_length as u8);
+            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
+        }
+
+        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn client_order_id(&mut self, value: &str) {
+            let limit = self.get_limit();
+            let data_length = value.len();
+            self.set_limit(limit + 1 + data_length);
+            self.get_buf_mut().put_u8_at(limit, data_length as u8);
+            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct OrderReportsEncoder<P> {
+        parent: Option<P>,
+        count: u16,
+        index: usize,
+        offset: usize,
+        initial_limit: usize,
+    }
+
+    impl<'a, P> Writer<'a> for OrderReportsEncoder<P>
+    where
+        P: Writer<'a> + Default,
+    {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            if let Some(parent) = self.parent.as_mut() {
+                parent.get_buf_mut()
+            } else {
+                panic!("parent was None")
+            }
+        }
+    }
+
+    impl<'a, P> Encoder<'a> for OrderReportsEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.parent.as_ref().expect("parent missing").get_limit()
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.parent
+                .as_mut()
+                .expect("parent missing")
+                .set_limit(limit);
+        }
+    }
+
+    impl<'a, P> OrderReportsEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        pub fn wrap(mut self, mut parent: P, count: u16) -> Self {
+            let initial_limit = parent.get_limit();
+            parent.set_limit(initial_limit + 4);
+            parent
+                .get_buf_mut()
+                .put_u16_at(initial_limit, Self::block_length());
+            parent.get_buf_mut().put_u16_at(initial_limit + 2, count);
+            self.parent = Some(parent);
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = usize::MAX;
+            self.initial_limit = initial_limit;
+            self
+        }
+
+        #[inline]
+        pub fn block_length() -> u16 {
+            140
+        }
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }
+
+        /// will return Some(current index) when successful otherwise None
+        #[inline]
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+            if let Some(parent) = self.parent.as_mut() {
+                self.offset = parent.get_limit();
+                parent.set_limit(self.offset + Self::block_length() as usize);
+                self.index = index;
+                Ok(Some(index))
+            } else {
+                Err(SbeErr::ParentNotSet)
+            }
+        }
+
+        /// primitive field 'orderId'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 0
+        /// - encodedLength: 8
+        #[inline]
+        pub fn order_id(&mut self, value: i64) {
+            let offset = self.offset;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'orderListId'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 8
+        /// - encodedLength: 8
+        #[inline]
+        pub fn order_list_id(&mut self, value: i64) {
+            let offset = self.offset + 8;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'transactTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 16
+        /// - encodedLength: 8
+        #[inline]
+        pub fn transact_time(&mut self, value: i64) {
+            let offset = self.offset + 16;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'price'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 24
+        /// - encodedLength: 8
+        #[inline]
+        pub fn price(&mut self, value: i64) {
+            let offset = self.offset + 24;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'origQty'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 32
+        /// - encodedLength: 8
+        #[inline]
+        pub fn orig_qty(&mut self, value: i64) {
+            let offset = self.offset + 32;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'executedQty'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 40
+        /// - encodedLength: 8
+        #[inline]
+        pub fn executed_qty(&mut self, value: i64) {
+            let offset = self.offset + 40;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'cummulativeQuoteQty'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 48
+        /// - encodedLength: 8
+        #[inline]
+        pub fn cummulative_quote_qty(&mut self, value: i64) {
+            let offset = self.offset + 48;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn status(&mut self, value: OrderStatus) {
+            let offset = self.offset + 56;
+            self.get_buf_mut().put_u8_at(offset, value as u8)
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn time_in_force(&mut self, value: TimeInForce) {
+            let offset = self.offset + 57;
+            self.get_buf_mut().put_u8_at(offset, value as u8)
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn order_type(&mut self, value: OrderType) {
+            let offset = self.offset + 58;
+            self.get_buf_mut().put_u8_at(offset, value as u8)
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn side(&mut self, value: OrderSide) {
+            let offset = self.offset + 59;
+            self.get_buf_mut().put_u8_at(offset, value as u8)
+        }
+
+        /// primitive field 'stopPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 60
+        /// - encodedLength: 8
+        #[inline]
+        pub fn stop_price(&mut self, value: i64) {
+            let offset = self.offset + 60;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'trailingDelta'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 68
+        /// - encodedLength: 8
+        #[inline]
+        pub fn trailing_delta(&mut self, value: i64) {
+            let offset = self.offset + 68;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'trailingTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 76
+        /// - encodedLength: 8
+        #[inline]
+        pub fn trailing_time(&mut self, value: i64) {
+            let offset = self.offset + 76;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'workingTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 84
+        /// - encodedLength: 8
+        #[inline]
+        pub fn working_time(&mut self, value: i64) {
+            let offset = self.offset + 84;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'icebergQty'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 92
+        /// - encodedLength: 8
+        #[inline]
+        pub fn iceberg_qty(&mut self, value: i64) {
+            let offset = self.offset + 92;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'strategyId'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 100
+        /// - encodedLength: 8
+        #[inline]
+        pub fn strategy_id(&mut self, value: i64) {
+            let offset = self.offset + 100;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'strategyType'
+        /// - min value: -2147483647
+        /// - max value: 2147483647
+        /// - null value: -2147483648
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 108
+        /// - encodedLength: 4
+        #[inline]
+        pub fn strategy_type(&mut self, value: i32) {
+            let offset = self.offset + 108;
+            self.get_buf_mut().put_i32_at(offset, value);
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn order_capacity(&mut self, value: OrderCapacity) {
+            let offset = self.offset + 112;
+            self.get_buf_mut().put_u8_at(offset, value as u8)
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn working_floor(&mut self, value: Floor) {
+            let offset = self.offset + 113;
+            self.get_buf_mut().put_u8_at(offset, value as u8)
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn self_trade_prevention_mode(&mut self, value: SelfTradePreventionMode) {
+            let offset = self.offset + 114;
+            self.get_buf_mut().put_u8_at(offset, value as u8)
+        }
+
+        /// primitive field 'tradeGroupId'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 115
+        /// - encodedLength: 8
+        #[inline]
+        pub fn trade_group_id(&mut self, value: i64) {
+            let offset = self.offset + 115;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'preventedQuantity'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 123
+        /// - encodedLength: 8
+        #[inline]
+        pub fn prevented_quantity(&mut self, value: i64) {
+            let offset = self.offset + 123;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn used_sor(&mut self, value: BoolEnum) {
+            let offset = self.offset + 131;
+            self.get_buf_mut().put_u8_at(offset, value as u8)
+        }
+
+        /// primitive field 'origQuoteOrderQty'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 132
+        /// - encodedLength: 8
+        #[inline]
+        pub fn orig_quote_order_qty(&mut self, value: i64) {
+            let offset = self.offset + 132;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// GROUP ENCODER (id=100)
+        #[inline]
+        pub fn fills_encoder(
+            self,
+            count: u32,
+            fills_encoder: FillsEncoder<Self>,
+        ) -> FillsEncoder<Self> {
+            fills_encoder.wrap(self, count)
+        }
+
+        /// GROUP ENCODER (id=101)
+        #[inline]
+        pub fn prevented_matches_encoder(
+            self,
+            count: u32,
+            prevented_matches_encoder: PreventedMatchesEncoder<Self>,
+        ) -> PreventedMatchesEncoder<Self> {
+            prevented_matches_encoder.wrap(self, count)
+        }
+
+        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn symbol(&mut self, value: &str) {
+            let limit = self.get_limit();
+            let data_length = value.len();
+            self.set_limit(limit + 1 + data_length);
+            self.get_buf_mut().put_u8_at(limit, data_length as u8);
+            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
+        }
+
+        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn client_order_id(&mut self, value: &str) {
+            let limit = self.get_limit();
+            let data_length = value.len();
+            self.set_limit(limit + 1 + data_length);
+            self.get_buf_mut().put_u8_at(limit, data_length as u8);
+            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct FillsEncoder<P> {
+        parent: Option<P>,
+        count: u32,
+        index: usize,
+        offset: usize,
+        initial_limit: usize,
+    }
+
+    impl<'a, P> Writer<'a> for FillsEncoder<P>
+    where
+        P: Writer<'a> + Default,
+    {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            if let Some(parent) = self.parent.as_mut() {
+                parent.get_buf_mut()
+            } else {
+                panic!("parent was None")
+            }
+        }
+    }
+
+    impl<'a, P> Encoder<'a> for FillsEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.parent.as_ref().expect("parent missing").get_limit()
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.parent
+                .as_mut()
+                .expect("parent missing")
+                .set_limit(limit);
+        }
+    }
+
+    impl<'a, P> FillsEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        pub fn wrap(mut self, mut parent: P, count: u32) -> Self {
+            let initial_limit = parent.get_limit();
+            parent.set_limit(initial_limit + 6);
+            parent
+                .get_buf_mut()
+                .put_u16_at(initial_limit, Self::block_length());
+            parent.get_buf_mut().put_u32_at(initial_limit + 2, count);
+            self.parent = Some(parent);
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = usize::MAX;
+            self.initial_limit = initial_limit;
+            self
+        }
+
+        #[inline]
+        pub fn block_length() -> u16 {
+            42
+        }
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }
+
+        /// will return Some(current index) when successful otherwise None
+        #[inline]
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+            if let Some(parent) = self.parent.as_mut() {
+                self.offset = parent.get_limit();
+                parent.set_limit(self.offset + Self::block_length() as usize);
+                self.index = index;
+                Ok(Some(index))
+            } else {
+                Err(SbeErr::ParentNotSet)
+            }
+        }
+
+        /// primitive field 'commissionExponent'
+        /// - min value: -127
+        /// - max value: 127
+        /// - null value: -128
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 0
+        /// - encodedLength: 1
+        #[inline]
+        pub fn commission_exponent(&mut self, value: i8) {
+            let offset = self.offset;
+            self.get_buf_mut().put_i8_at(offset, value);
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn match_type(&mut self, value: MatchType) {
+            let offset = self.offset + 1;
+            self.get_buf_mut().put_u8_at(offset, value as u8)
+        }
+
+        /// primitive field 'price'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 2
+        /// - encodedLength: 8
+        #[inline]
+        pub fn price(&mut self, value: i64) {
+            let offset = self.offset + 2;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'qty'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 10
+        /// - encodedLength: 8
+        #[inline]
+        pub fn qty(&mut self, value: i64) {
+            let offset = self.offset + 10;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'commission'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 18
+        /// - encodedLength: 8
+        #[inline]
+        pub fn commission(&mut self, value: i64) {
+            let offset = self.offset + 18;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'commission' rescaled to the 'commissionExponent' already written to this block
+        #[inline]
+        pub fn commission_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.commission(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'tradeId'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 26
+        /// - encodedLength: 8
+        #[inline]
+        pub fn trade_id(&mut self, value: i64) {
+            let offset = self.offset + 26;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'allocId'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 34
+        /// - encodedLength: 8
+        #[inline]
+        pub fn alloc_id(&mut self, value: i64) {
+            let offset = self.offset + 34;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn commission_asset(&mut self, value: &str) {
+            let limit = self.get_limit();
+            let data_length = value.len();
+            self.set_limit(limit + 1 + data_length);
+            self.get_buf_mut().put_u8_at(limit, data_length as u8);
+            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct PreventedMatchesEncoder<P> {
+        parent: Option<P>,
+        count: u32,
+        index: usize,
+        offset: usize,
+        initial_limit: usize,
+    }
+
+    impl<'a, P> Writer<'a> for PreventedMatchesEncoder<P>
+    where
+        P: Writer<'a> + Default,
+    {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            if let Some(parent) = self.parent.as_mut() {
+                parent.get_buf_mut()
+            } else {
+                panic!("parent was None")
+            }
+        }
+    }
+
+    impl<'a, P> Encoder<'a> for PreventedMatchesEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.parent.as_ref().expect("parent missing").get_limit()
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.parent
+                .as_mut()
+                .expect("parent missing")
+                .set_limit(limit);
+        }
+    }
+
+    impl<'a, P> PreventedMatchesEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        pub fn wrap(mut self, mut parent: P, count: u32) -> Self {
+            let initial_limit = parent.get_limit();
+            parent.set_limit(initial_limit + 6);
+            parent
+                .get_buf_mut()
+                .put_u16_at(initial_limit, Self::block_length());
+            parent.get_buf_mut().put_u32_at(initial_limit + 2, count);
+            self.parent = Some(parent);
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = usize::MAX;
+            self.initial_limit = initial_limit;
+            self
+        }
+
+        #[inline]
+        pub fn block_length() -> u16 {
+            40
+        }
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }
+
+        /// will return Some(current index) when successful otherwise None
+        #[inline]
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+            if let Some(parent) = self.parent.as_mut() {
+                self.offset = parent.get_limit();
+                parent.set_limit(self.offset + Self::block_length() as usize);
+                self.index = index;
+                Ok(Some(index))
+            } else {
+                Err(SbeErr::ParentNotSet)
+            }
+        }
+
+        /// primitive field 'preventedMatchId'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 0
+        /// - encodedLength: 8
+        #[inline]
+        pub fn prevented_match_id(&mut self, value: i64) {
+            let offset = self.offset;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'makerOrderId'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 8
+        /// - encodedLength: 8
+        #[inline]
+        pub fn maker_order_id(&mut self, value: i64) {
+            let offset = self.offset + 8;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'price'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 16
+        /// - encodedLength: 8
+        #[inline]
+        pub fn price(&mut self, value: i64) {
+            let offset = self.offset + 16;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'takerPreventedQuantity'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 24
+        /// - encodedLength: 8
+        #[inline]
+        pub fn taker_prevented_quantity(&mut self, value: i64) {
+            let offset = self.offset + 24;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'makerPreventedQuantity'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 32
+        /// - encodedLength: 8
+        #[inline]
+        pub fn maker_prevented_quantity(&mut self, value: i64) {
+            let offset = self.offset + 32;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn maker_symbol(&mut self, value: &str) {
+            let limit = self.get_limit();
+            let data_length = value.len();
+            self.set_limit(limit + 1 + data_length);
+            self.get_buf_mut().put_u8_at(limit, data_length as u8);
+            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
+        }
+    }
+
+    impl OrderReportsEncoder<NewOrderListFullResponseEncoder<'_>> {
+        /// 'price' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 19;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
+        /// 'origQty' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn orig_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 20;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.orig_qty(mantissa);
+            Ok(())
+        }
+
+        /// 'executedQty' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn executed_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 20;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.executed_qty(mantissa);
+            Ok(())
+        }
+
+        /// 'cummulativeQuoteQty' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn cummulative_quote_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 19;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.cummulative_quote_qty(mantissa);
+            Ok(())
+        }
+
+        /// 'stopPrice' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn stop_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.stop_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 19;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.stop_price(mantissa);
+            Ok(())
+        }
+
+        /// 'icebergQty' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn iceberg_qty_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.iceberg_qty(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 20;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.iceberg_qty(mantissa);
+            Ok(())
+        }
+
+        /// 'preventedQuantity' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn prevented_quantity_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.prevented_quantity(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 20;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.prevented_quantity(mantissa);
+            Ok(())
+        }
+
+        /// 'origQuoteOrderQty' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn orig_quote_order_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.parent.as_ref().ok_or(SbeErr::ParentNotSet)?.offset + 19;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.orig_quote_order_qty(mantissa);
+            Ok(())
+        }
+    }
+
+    impl FillsEncoder<OrderReportsEncoder<NewOrderListFullResponseEncoder<'_>>> {
+        /// 'price' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .offset
+                + 19;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
+        /// 'qty' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .offset
+                + 20;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.qty(mantissa);
+            Ok(())
+        }
+    }
+
+    impl PreventedMatchesEncoder<OrderReportsEncoder<NewOrderListFullResponseEncoder<'_>>> {
+        /// 'price' rescaled to the 'priceExponent' already written to the enclosing block
+        #[inline]
+        pub fn price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .offset
+                + 19;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price(mantissa);
+            Ok(())
+        }
+
+        /// 'takerPreventedQuantity' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn taker_prevented_quantity_decimal(
+            &mut self,
+            value: Option<Decimal>,
+        ) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.taker_prevented_quantity(i64::MIN);
+                return Ok(());
+            };
+            let offset = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .offset
+                + 20;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.taker_prevented_quantity(mantissa);
+            Ok(())
+        }
+
+        /// 'makerPreventedQuantity' rescaled to the 'qtyExponent' already written to the enclosing block
+        #[inline]
+        pub fn maker_prevented_quantity_decimal(
+            &mut self,
+            value: Option<Decimal>,
+        ) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.maker_prevented_quantity(i64::MIN);
+                return Ok(());
+            };
+            let offset = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .offset
+                + 20;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.maker_prevented_quantity(mantissa);
+            Ok(())
+        }
+    }
+} // end encoder
+
+pub mod decoder {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct NewOrderListFullResponseDecoder<'a> {
+        buf: ReadBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+        pub acting_block_length: u16,
+        pub acting_version: u16,
+    }
+
+    impl<'a> Reader<'a> for NewOrderListFullResponseDecoder<'a> {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            &self.buf
+        }
+    }
+
+    impl<'a> Decoder<'a> for NewOrderListFullResponseDecoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
+    }
+
+    impl<'a> NewOrderListFullResponseDecoder<'a> {
+        pub fn wrap(
+            mut self,
+            buf: ReadBuf<'a>,
+            offset: usize,
+            acting_block_length: u16,
+            acting_version: u16,
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
+            let limit = offset + acting_block_length as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self.acting_block_length = acting_block_length;
+            self.acting_version = acting_version;
+            Ok(self)
+        }
+
+        #[inline]
+        pub fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
+            let acting_block_length = header.block_length();
+            let acting_version = header.version();
+
+            self.wrap(
+                header.parent()?,
+                message_header_codec::ENCODED_LENGTH,
+                acting_block_length,
+                acting_version,
+            )
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn order_list_id(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset)
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn contingency_type(&self) -> ContingencyType {
+            self.get_buf().get_u8_at(self.offset + 8).into()
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn list_status_type(&self) -> ListStatusType {
+            self.get_buf().get_u8_at(self.offset + 9).into()
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn list_order_status(&self) -> ListOrderStatus {
+            self.get_buf().get_u8_at(self.offset + 10).into()
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn transaction_time(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 11)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn price_exponent(&self) -> i8 {
+            self.get_buf().get_i8_at(self.offset + 19)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn qty_exponent(&self) -> i8 {
+            self.get_buf().get_i8_at(self.offset + 20)
+        }
+
+        /// GROUP DECODER (id=100)
+        #[inline]
+        pub fn orders_decoder(self) -> SbeResult<OrdersDecoder<'a, Self>> {
+            OrdersDecoder::default().wrap(self)
+        }
+
+        /// GROUP DECODER (id=101)
+        #[inline]
+        pub fn order_reports_decoder(self) -> SbeResult<OrderReportsDecoder<'a, Self>> {
+            OrderReportsDecoder::default().wrap(self)
+        }
+
+        /// VAR_DATA DECODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn list_client_order_id_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
+            let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
+        }
+
+        #[inline]
+        pub fn list_client_order_id_slice(
+            &'a self,
+            coordinates: (usize, usize),
+        ) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
+        }
+
+        /// VAR_DATA DECODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
+            let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
+        }
+
+        #[inline]
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct OrdersDecoder<'a, P> {
+        parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
+        block_length: usize,
+        count: u16,
+        index: usize,
+        offset: usize,
+    }
+
+    impl<'a, P> Reader<'a> for OrdersDecoder<'a, P>
+    where
+        P: Reader<'a> + Default,
+    {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            &self.buf
+        }
+    }
+
+    impl<'a, P> Decoder<'a> for OrdersDecoder<'a, P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
+    }
+
+    impl<'a, P> OrdersDecoder<'a, P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
+            let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 4)?;
+            let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
+            let count = parent.get_buf().get_u16_at(initial_offset + 2);
+            let available = parent.get_buf().len() - (initial_offset + 4);
+            let expected = 8;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if (count as usize).saturating_mul(block_length + 2) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 4;
+            self.acting_version = parent.acting_version();
+            self.parent = Some(parent);
+            self.block_length = block_length;
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = 0;
+            Ok(self)
+        }
+
+        /// group token - Token{signal=BEGIN_GROUP, name='orders', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=8, offset=21, componentTokenCount=21, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
+        }
+
+        #[inline]
+        pub fn count(&self) -> u16 {
+            self.count
+        }
+
+        /// will return Some(current index) when successful otherwise None
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(8))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn order_id(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset)
+        }
+
+        /// VAR_DATA DECODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
+            let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
+        }
+
+        #[inline]
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
+        }
+
+        /// VAR_DATA DECODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn client_order_id_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
+            let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
+        }
+
+        #[inline]
+        pub fn client_order_id_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct OrderReportsDecoder<'a, P> {
+        parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
+        block_length: usize,
+        count: u16,
+        index: usize,
+        offset: usize,
+    }
+
+    impl<'a, P> Reader<'a> for OrderReportsDecoder<'a, P>
+    where
+        P: Reader<'a> + Default,
+    {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            &self.buf
+        }
+    }
+
+    impl<'a, P> Decoder<'a> for OrderReportsDecoder<'a, P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
+    }
+
+    impl<'a, P> OrderReportsDecoder<'a, P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
+            let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 4)?;
+            let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
+            let count = parent.get_buf().get_u16_at(initial_offset + 2);
+            let available = parent.get_buf().len() - (initial_offset + 4);
+            let expected = if parent.acting_version() >= 1 {
+                140
+            } else {
+                132
+            };
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if (count as usize).saturating_mul(block_length + 14) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 4;
+            self.acting_version = parent.acting_version();
+            self.parent = Some(parent);
+            self.block_length = block_length;
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = 0;
+            Ok(self)
+        }
+
+        /// group token - Token{signal=BEGIN_GROUP, name='orderReports', referencedName='null', description='null', packageName='null', id=101, version=0, deprecated=0, encodedLength=140, offset=-1, componentTokenCount=197, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
+        }
+
+        #[inline]
+        pub fn count(&self) -> u16 {
+            self.count
+        }
+
+        /// will return Some(current index) when successful otherwise None
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(140))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn order_id(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset)
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn order_list_id(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 8);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn transact_time(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 16)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn price(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 24)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn orig_qty(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 32)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn executed_qty(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 40)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn cummulative_quote_qty(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 48)
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn status(&self) -> OrderStatus {
+            self.get_buf().get_u8_at(self.offset + 56).into()
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn time_in_force(&self) -> TimeInForce {
+            self.get_buf().get_u8_at(self.offset + 57).into()
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn order_type(&self) -> OrderType {
+            self.get_buf().get_u8_at(self.offset + 58).into()
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn side(&self) -> OrderSide {
+            self.get_buf().get_u8_at(self.offset + 59).into()
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn stop_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 60);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn trailing_delta(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 68);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn trailing_time(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 76);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn working_time(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 84);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn iceberg_qty(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 92);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn strategy_id(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 100);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-2147483648' }
+        #[inline]
+        pub fn strategy_type(&self) -> Option<i32> {
+            let value = self.get_buf().get_i32_at(self.offset + 108);
+            if value == -2147483648_i32 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn order_capacity(&self) -> OrderCapacity {
+            self.get_buf().get_u8_at(self.offset + 112).into()
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn working_floor(&self) -> Floor {
+            self.get_buf().get_u8_at(self.offset + 113).into()
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn self_trade_prevention_mode(&self) -> SelfTradePreventionMode {
+            self.get_buf().get_u8_at(self.offset + 114).into()
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn trade_group_id(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 115);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn prevented_quantity(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 123);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn used_sor(&self) -> BoolEnum {
+            self.get_buf().get_u8_at(self.offset + 131).into()
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn orig_quote_order_qty(&self) -> i64 {
+            if self.acting_version() < 1 {
+                return -9223372036854775808_i64;
+            }
+
+            self.get_buf().get_i64_at(self.offset + 132)
+        }
+
+        /// GROUP DECODER (id=100)
+        #[inline]
+        pub fn fills_decoder(self) -> SbeResult<FillsDecoder<'a, Self>> {
+            FillsDecoder::default().wrap(self)
+        }
+
+        /// GROUP DECODER (id=101)
+        #[inline]
+        pub fn prevented_matches_decoder(self) -> SbeResult<PreventedMatchesDecoder<'a, Self>> {
+            PreventedMatchesDecoder::default().wrap(self)
+        }
+
+        /// VAR_DATA DECODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
+            let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
+        }
+
+        #[inline]
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
+        }
+
+        /// VAR_DATA DECODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn client_order_id_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
+            let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
+        }
+
+        #[inline]
+        pub fn client_order_id_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct FillsDecoder<'a, P> {
+        parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
+        block_length: usize,
+        count: u32,
+        index: usize,
+        offset: usize,
+    }
+
+    impl<'a, P> Reader<'a> for FillsDecoder<'a, P>
+    where
+        P: Reader<'a> + Default,
+    {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            &self.buf
+        }
+    }
+
+    impl<'a, P> Decoder<'a> for FillsDecoder<'a, P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
+    }
+
+    impl<'a, P> FillsDecoder<'a, P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
+            let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
+            let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
+            let count = parent.get_buf().get_u32_at(initial_offset + 2);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 42;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
+            self.parent = Some(parent);
+            self.block_length = block_length;
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = 0;
+            Ok(self)
+        }
+
+        /// group token - Token{signal=BEGIN_GROUP, name='fills', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=42, offset=140, componentTokenCount=36, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
+        }
+
+        #[inline]
+        pub fn count(&self) -> u32 {
+            self.count
+        }
+
+        /// will return Some(current index) when successful otherwise None
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(42))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn commission_exponent(&self) -> i8 {
+            self.get_buf().get_i8_at(self.offset)
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn match_type(&self) -> MatchType {
+            self.get_buf().get_u8_at(self.offset + 1).into()
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn price(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 2)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn qty(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 10)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn commission(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 18)
+        }
+
+        /// 'commission' combined with its 'commissionExponent' exponent field
+        #[inline]
+        pub fn commission_decimal(&self) -> Decimal {
+            Decimal::new(self.commission(), self.commission_exponent())
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn trade_id(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 26);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn alloc_id(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 34);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// VAR_DATA DECODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn commission_asset_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
+            let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
+        }
+
+        #[inline]
+        pub fn commission_asset_slice(
+            &'a self,
+            coordinates: (usize, usize),
+        ) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct PreventedMatchesDecoder<'a, P> {
+        parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
+        block_length: usize,
+        count: u32,
+        index: usize,
+        offset: usize,
+    }
+
+    impl<'a, P> Reader<'a> for PreventedMatchesDecoder<'a, P>
+    where
+        P: Reader<'a> + Default,
+    {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            &self.buf
+        }
+    }
+
+    impl<'a, P> Decoder<'a> for PreventedMatchesDecoder<'a, P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
+    }
+
+    impl<'a, P> PreventedMatchesDecoder<'a, P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
+            let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
+            let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
+            let count = parent.get_buf().get_u32_at(initial_offset + 2);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 40;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
+            self.parent = Some(parent);
+            self.block_length = block_length;
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = 0;
+            Ok(self)
+        }
+
+        /// group token - Token{signal=BEGIN_GROUP, name='preventedMatches', referencedName='null', description='null', packageName='null', id=101, version=0, deprecated=0, encodedLength=40, offset=-1, componentTokenCount=27, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
+        }
+
+        #[inline]
+        pub fn count(&self) -> u32 {
+            self.count
+        }
+
+        /// will return Some(current index) when successful otherwise None
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(40))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn prevented_match_id(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset)
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn maker_order_id(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 8);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 16);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn taker_prevented_quantity(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 24);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn maker_prevented_quantity(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 32);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// VAR_DATA DECODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn maker_symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
+            let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
+        }
+
+        #[inline]
+        pub fn maker_symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
+        }
+    }
+
+    impl OrderReportsDecoder<'_, NewOrderListFullResponseDecoder<'_>> {
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.price(), exponent))
+        }
+
+        /// 'origQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn orig_qty_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(Decimal::new(self.orig_qty(), exponent))
+        }
+
+        /// 'executedQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn executed_qty_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(Decimal::new(self.executed_qty(), exponent))
+        }
+
+        /// 'cummulativeQuoteQty' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn cummulative_quote_qty_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.cummulative_quote_qty(), exponent))
+        }
+
+        /// 'stopPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn stop_price_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(self
+                .stop_price()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+
+        /// 'icebergQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn iceberg_qty_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(self
+                .iceberg_qty()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+
+        /// 'preventedQuantity' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn prevented_quantity_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(self
+                .prevented_quantity()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+
+        /// 'origQuoteOrderQty' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn orig_quote_order_qty_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.orig_quote_order_qty(), exponent))
+        }
+    }
+
+    impl FillsDecoder<'_, OrderReportsDecoder<'_, NewOrderListFullResponseDecoder<'_>>> {
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(Decimal::new(self.price(), exponent))
+        }
+
+        /// 'qty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn qty_decimal(&self) -> SbeResult<Decimal> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(Decimal::new(self.qty(), exponent))
+        }
+    }
+
+    impl PreventedMatchesDecoder<'_, OrderReportsDecoder<'_, NewOrderListFullResponseDecoder<'_>>> {
+        /// 'price' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .price_exponent();
+            Ok(self
+                .price()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+
+        /// 'takerPreventedQuantity' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn taker_prevented_quantity_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(self
+                .taker_prevented_quantity()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+
+        /// 'makerPreventedQuantity' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn maker_prevented_quantity_decimal(&self) -> SbeResult<Option<Decimal>> {
+            let exponent = self
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .parent
+                .as_ref()
+                .ok_or(SbeErr::ParentNotSet)?
+                .qty_exponent();
+            Ok(self
+                .maker_prevented_quantity()
+                .map(|mantissa| Decimal::new(mantissa, exponent)))
+        }
+    }
+} // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct NewOrderListFullResponse {
+        pub order_list_id: i64,
+        pub contingency_type: ContingencyType,
+        pub list_status_type: ListStatusType,
+        pub list_order_status: ListOrderStatus,
+        pub transaction_time: i64,
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub orders: Vec<Orders>,
+        pub order_reports: Vec<OrderReports>,
+        pub list_client_order_id: String,
+        pub symbol: String,
+    }
+
+    impl NewOrderListFullResponse {
+        pub fn from_decoder(decoder: &mut NewOrderListFullResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                order_list_id: decoder.order_list_id(),
+                contingency_type: decoder.contingency_type(),
+                list_status_type: decoder.list_status_type(),
+                list_order_status: decoder.list_order_status(),
+                transaction_time: decoder.transaction_time(),
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                orders: {
+                    let mut orders_decoder = core::mem::take(decoder).orders_decoder()?;
+                    let mut entries = Vec::new();
+                    while orders_decoder.advance()?.is_some() {
+                        entries.push(Orders::from_decoder(&mut orders_decoder)?);
+                    }
+                    *decoder = orders_decoder.parent()?;
+                    entries
+                },
+                order_reports: {
+                    let mut order_reports_decoder =
+                        core::mem::take(decoder).order_reports_decoder()?;
+                    let mut entries = Vec::new();
+                    while order_reports_decoder.advance()?.is_some() {
+                        entries.push(OrderReports::from_decoder(&mut order_reports_decoder)?);
+                    }
+                    *decoder = order_reports_decoder.parent()?;
+                    entries
+                },
+                list_client_order_id: {
+                    let coordinates = decoder.list_client_order_id_decoder()?;
+                    let slice = decoder.list_client_order_id_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        pub fn to_encoder(
+            &self,
+            encoder: &mut NewOrderListFullResponseEncoder<'_>,
+        ) -> SbeResult<()> {
+            encoder.order_list_id(self.order_list_id);
+            encoder.contingency_type(self.contingency_type);
+            encoder.list_status_type(self.list_status_type);
+            encoder.list_order_status(self.list_order_status);
+            encoder.transaction_time(self.transaction_time);
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                self.order_reports
+                    .iter()
+                    .map(|entry| entry.price)
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .map(|entry| entry.cummulative_quote_qty),
+                    )
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .filter_map(|entry| entry.stop_price),
+                    )
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .map(|entry| entry.orig_quote_order_qty),
+                    )
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .flat_map(|entry| &entry.fills)
+                            .map(|entry| entry.price),
+                    )
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .flat_map(|entry| &entry.prevented_matches)
+                            .filter_map(|entry| entry.price),
+                    ),
+            ));
+            encoder.qty_exponent(fit_exponent(
+                self.qty_exponent,
+                self.order_reports
+                    .iter()
+                    .map(|entry| entry.orig_qty)
+                    .chain(self.order_reports.iter().map(|entry| entry.executed_qty))
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .filter_map(|entry| entry.iceberg_qty),
+                    )
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .filter_map(|entry| entry.prevented_quantity),
+                    )
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .flat_map(|entry| &entry.fills)
+                            .map(|entry| entry.qty),
+                    )
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .flat_map(|entry| &entry.prevented_matches)
+                            .filter_map(|entry| entry.taker_prevented_quantity),
+                    )
+                    .chain(
+                        self.order_reports
+                            .iter()
+                            .flat_map(|entry| &entry.prevented_matches)
+                            .filter_map(|entry| entry.maker_prevented_quantity),
+                    ),
+            ));
+            let count = checked_length::<u16>(self.orders.len())?;
+            let mut orders_encoder =
+                core::mem::take(encoder).orders_encoder(count, OrdersEncoder::default());
+            for entry in &self.orders {
+                orders_encoder.advance()?;
+                entry.to_encoder(&mut orders_encoder)?;
+            }
+            *encoder = orders_encoder.parent()?;
+            let count = checked_length::<u16>(self.order_reports.len())?;
+            let mut order_reports_encoder = core::mem::take(encoder)
+                .order_reports_encoder(count, OrderReportsEncoder::default());
+            for entry in &self.order_reports {
+                order_reports_encoder.advance()?;
+                entry.to_encoder(&mut order_reports_encoder)?;
+            }
+            *encoder = order_reports_encoder.parent()?;
+            let value = &self.list_client_order_id;
+            checked_length::<u8>(value.len())?;
+            encoder.list_client_order_id(value);
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            31 + self
+                .orders
+                .iter()
+                .map(Orders::encoded_length)
+                .sum::<usize>()
+                + self
+                    .order_reports
+                    .iter()
+                    .map(OrderReports::encoded_length)
+                    .sum::<usize>()
+                + self.list_client_order_id.len()
+                + self.symbol.len()
+        }
+    }
+
+    impl OwnedMessage for NewOrderListFullResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = NewOrderListFullResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = NewOrderListFullResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Orders {
+        pub order_id: i64,
+        pub symbol: String,
+        pub client_order_id: String,
+    }
+
+    impl Orders {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut OrdersDecoder<'a, NewOrderListFullResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                order_id: decoder.order_id(),
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                client_order_id: {
+                    let coordinates = decoder.client_order_id_decoder()?;
+                    let slice = decoder.client_order_id_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut OrdersEncoder<NewOrderListFullResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.order_id(self.order_id);
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            let value = &self.client_order_id;
+            checked_length::<u8>(value.len())?;
+            encoder.client_order_id(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            10 + self.symbol.len() + self.client_order_id.len()
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct OrderReports {
+        pub order_id: i64,
+        pub order_list_id: Option<i64>,
+        pub transact_time: i64,
+        pub price: Decimal,
+        pub orig_qty: Decimal,
+        pub executed_qty: Decimal,
+        pub cummulative_quote_qty: Decimal,
+        pub status: OrderStatus,
+        pub time_in_force: TimeInForce,
+        pub order_type: OrderType,
+        pub side: OrderSide,
+        pub stop_price: Option<Decimal>,
+        pub trailing_delta: Option<i64>,
+        pub trailing_time: Option<i64>,
+        pub working_time: Option<i64>,
+        pub iceberg_qty: Option<Decimal>,
+        pub strategy_id: Option<i64>,
+        pub strategy_type: Option<i32>,
+        pub order_capacity: OrderCapacity,
+        pub working_floor: Floor,
+        pub self_trade_prevention_mode: SelfTradePreventionMode,
+        pub trade_group_id: Option<i64>,
+        pub prevented_quantity: Option<Decimal>,
+        pub used_sor: BoolEnum,
+        pub orig_quote_order_qty: Decimal,
+        pub fills: Vec<Fills>,
+        pub prevented_matches: Vec<PreventedMatches>,
+        pub symbol: String,
+        pub client_order_id: String,
+    }
+
+    impl OrderReports {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut OrderReportsDecoder<'a, NewOrderListFullResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                order_id: decoder.order_id(),
+                order_list_id: decoder.order_list_id(),
+                transact_time: decoder.transact_time(),
+                price: decoder.price_decimal()?,
+                orig_qty: decoder.orig_qty_decimal()?,
+                executed_qty: decoder.executed_qty_decimal()?,
+                cummulative_quote_qty: decoder.cummulative_quote_qty_decimal()?,
+                status: decoder.status(),
+                time_in_force: decoder.time_in_force(),
+                order_type: decoder.order_type(),
+                side: decoder.side(),
+                stop_price: decoder.stop_price_decimal()?,
+                trailing_delta: decoder.trailing_delta(),
+                trailing_time: decoder.trailing_time(),
+                working_time: decoder.working_time(),
+                iceberg_qty: decoder.iceberg_qty_decimal()?,
+                strategy_id: decoder.strategy_id(),
+                strategy_type: decoder.strategy_type(),
+                order_capacity: decoder.order_capacity(),
+                working_floor: decoder.working_floor(),
+                self_trade_prevention_mode: decoder.self_trade_prevention_mode(),
+                trade_group_id: decoder.trade_group_id(),
+                prevented_quantity: decoder.prevented_quantity_decimal()?,
+                used_sor: decoder.used_sor(),
+                orig_quote_order_qty: decoder.orig_quote_order_qty_decimal()?,
+                fills: {
+                    let mut fills_decoder = core::mem::take(decoder).fills_decoder()?;
+                    let mut entries = Vec::new();
+                    while fills_decoder.advance()?.is_some() {
+                        entries.push(Fills::from_decoder(&mut fills_decoder)?);
+                    }
+                    *decoder = fills_decoder.parent()?;
+                    entries
+                },
+                prevented_matches: {
+                    let mut prevented_matches_decoder =
+                        core::mem::take(decoder).prevented_matches_decoder()?;
+                    let mut entries = Vec::new();
+                    while prevented_matches_decoder.advance()?.is_some() {
+                        entries.push(PreventedMatches::from_decoder(
+                            &mut prevented_matches_decoder,
+                        )?);
+                    }
+                    *decoder = prevented_matches_decoder.parent()?;
+                    entries
+                },
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+                client_order_id: {
+                    let coordinates = decoder.client_order_id_decoder()?;
+                    let slice = decoder.client_order_id_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut OrderReportsEncoder<NewOrderListFullResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.order_id(self.order_id);
+            encoder.order_list_id(self.order_list_id.unwrap_or(i64::MIN));
+            encoder.transact_time(self.transact_time);
+            encoder.price_decimal(self.price)?;
+            encoder.orig_qty_decimal(self.orig_qty)?;
+            encoder.executed_qty_decimal(self.executed_qty)?;
+            encoder.cummulative_quote_qty_decimal(self.cummulative_quote_qty)?;
+            encoder.status(self.status);
+            encoder.time_in_force(self.time_in_force);
+            encoder.order_type(self.order_type);
+            encoder.side(self.side);
+            encoder.stop_price_decimal(self.stop_price)?;
+            encoder.trailing_delta(self.trailing_delta.unwrap_or(i64::MIN));
+            encoder.trailing_time(self.trailing_time.unwrap_or(i64::MIN));
+            encoder.working_time(self.working_time.unwrap_or(i64::MIN));
+            encoder.iceberg_qty_decimal(self.iceberg_qty)?;
+            encoder.strategy_id(self.strategy_id.unwrap_or(i64::MIN));
+            encoder.strategy_type(self.strategy_type.unwrap_or(i32::MIN));
+            encoder.order_capacity(self.order_capacity);
+            encoder.working_floor(self.working_floor);
+            encoder.self_trade_prevention_mode(self.self_trade_prevention_mode);
+            encoder.trade_group_id(self.trade_group_id.unwrap_or(i64::MIN));
+            encoder.prevented_quantity_decimal(self.prevented_quantity)?;
+            encoder.used_sor(self.used_sor);
+            encoder.orig_quote_order_qty_decimal(self.orig_quote_order_qty)?;
+            let count = checked_length::<u32>(self.fills.len())?;
+            let mut fills_encoder =
+                core::mem::take(encoder).fills_encoder(count, FillsEncoder::default());
+            for entry in &self.fills {
+                fills_encoder.advance()?;
+                entry.to_encoder(&mut fills_encoder)?;
+            }
+            *encoder = fills_encoder.parent()?;
+            let count = checked_length::<u32>(self.prevented_matches.len())?;
+            let mut prevented_matches_encoder = core::mem::take(encoder)
+                .prevented_matches_encoder(count, PreventedMatchesEncoder::default());
+            for entry in &self.prevented_matches {
+                prevented_matches_encoder.advance()?;
+                entry.to_encoder(&mut prevented_matches_encoder)?;
+            }
+            *encoder = prevented_matches_encoder.parent()?;
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            let value = &self.client_order_id;
+            checked_length::<u8>(value.len())?;
+            encoder.client_order_id(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            154 + self.fills.iter().map(Fills::encoded_length).sum::<usize>()
+                + self
+                    .prevented_matches
+                    .iter()
+                    .map(PreventedMatches::encoded_length)
+                    .sum::<usize>()
+                + self.symbol.len()
+                + self.client_order_id.len()
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Fills {
+        pub commission_exponent: i8,
+        pub match_type: MatchType,
+        pub price: Decimal,
+        pub qty: Decimal,
+        pub commission: Decimal,
+        pub trade_id: Option<i64>,
+        pub alloc_id: Option<i64>,
+        pub commission_asset: Option<String>,
+    }
+
+    impl Fills {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut FillsDecoder<
+                'a,
+                OrderReportsDecoder<'a, NewOrderListFullResponseDecoder<'a>>,
+            >,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                commission_exponent: decoder.commission_exponent(),
+                match_type: decoder.match_type(),
+                price: decoder.price_decimal()?,
+                qty: decoder.qty_decimal()?,
+                commission: decoder.commission_decimal(),
+                trade_id: decoder.trade_id(),
+                alloc_id: decoder.alloc_id(),
+                commission_asset: {
+                    let coordinates = decoder.commission_asset_decoder()?;
+                    let slice = decoder.commission_asset_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(
+                            core::str::from_utf8(slice)
+                                .map_err(|_| SbeErr::InvalidUtf8)?
+                                .to_owned(),
+                        )
+                    }
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut FillsEncoder<OrderReportsEncoder<NewOrderListFullResponseEncoder<'_>>>,
+        ) -> SbeResult<()> {
+            encoder.commission_exponent(fit_exponent(self.commission_exponent, [self.commission]));
+            encoder.match_type(self.match_type);
+            encoder.price_decimal(self.price)?;
+            encoder.qty_decimal(self.qty)?;
+            encoder.commission_decimal(self.commission)?;
+            encoder.trade_id(self.trade_id.unwrap_or(i64::MIN));
+            encoder.alloc_id(self.alloc_id.unwrap_or(i64::MIN));
+            let value = self.commission_asset.as_deref().unwrap_or_default();
+            checked_length::<u8>(value.len())?;
+            encoder.commission_asset(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            43 + self
+                .commission_asset
+                .as_ref()
+                .map_or(0, |value| value.len())
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PreventedMatches {
+        pub prevented_match_id: i64,
+        pub maker_order_id: Option<i64>,
+        pub price: Option<Decimal>,
+        pub taker_prevented_quantity: Option<Decimal>,
+        pub maker_prevented_quantity: Option<Decimal>,
+        pub maker_symbol: Option<String>,
+    }
+
+    impl PreventedMatches {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut PreventedMatchesDecoder<
+                'a,
+                OrderReportsDecoder<'a, NewOrderListFullResponseDecoder<'a>>,
+            >,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                prevented_match_id: decoder.prevented_match_id(),
+                maker_order_id: decoder.maker_order_id(),
+                price: decoder.price_decimal()?,
+                taker_prevented_quantity: decoder.taker_prevented_quantity_decimal()?,
+                maker_prevented_quantity: decoder.maker_prevented_quantity_decimal()?,
+                maker_symbol: {
+                    let coordinates = decoder.maker_symbol_decoder()?;
+                    let slice = decoder.maker_symbol_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(
+                            core::str::from_utf8(slice)
+                                .map_err(|_| SbeErr::InvalidUtf8)?
+                                .to_owned(),
+                        )
+                    }
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut PreventedMatchesEncoder<
+                OrderReportsEncoder<NewOrderListFullResponseEncoder<'_>>,
+            >,
+        ) -> SbeResult<()> {
+            encoder.prevented_match_id(self.prevented_match_id);
+            encoder.maker_order_id(self.maker_order_id.unwrap_or(i64::MIN));
+            encoder.price_decimal(self.price)?;
+            encoder.taker_prevented_quantity_decimal(self.taker_prevented_quantity)?;
+            encoder.maker_prevented_quantity_decimal(self.maker_prevented_quantity)?;
+            let value = self.maker_symbol.as_deref().unwrap_or_default();
+            checked_length::<u8>(value.len())?;
+            encoder.maker_symbol(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            41 + self.maker_symbol.as_ref().map_or(0, |value| value.len())
+        }
+    }
+} // end owned
@@ -1,4 +1,4 @@
-use crate::*;
+use super::*;
 
 pub use decoder::OptionalMessageDataDecoder;
 pub use encoder::OptionalMessageDataEncoder;
@@ -94,10 +94,11 @@ pub mod decoder {
     where
         P: Reader<'a> + Default,
     {
-        pub fn wrap(mut self, parent: P, offset: usize) -> Self {
+        pub fn wrap(mut self, parent: P, offset: usize) -> SbeResult<Self> {
+            parent.get_buf().check_bounds(offset, 4)?;
             self.parent = Some(parent);
             self.offset = offset;
-            self
+            Ok(self)
         }
 
         #[inline]
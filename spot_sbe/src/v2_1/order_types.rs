@@ -1,4 +1,5 @@
 #[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderTypes(pub u16);
 impl OrderTypes {
     #[inline]
@@ -117,6 +118,12 @@ impl OrderTypes {
         self
     }
 }
+impl From<u16> for OrderTypes {
+    #[inline]
+    fn from(v: u16) -> Self {
+        OrderTypes(v)
+    }
+}
 impl core::fmt::Debug for OrderTypes {
     #[inline]
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
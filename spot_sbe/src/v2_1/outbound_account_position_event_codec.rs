@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::OutboundAccountPositionEventDecoder;
 pub use encoder::OutboundAccountPositionEventEncoder;
+#[cfg(feature = "serde")]
+pub use owned::OutboundAccountPositionEvent;
 
 pub const SBE_BLOCK_LENGTH: u16 = 16;
 pub const SBE_TEMPLATE_ID: u16 = 607;
@@ -218,6 +220,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'free' rescaled to the 'exponent' already written to this block
+        #[inline]
+        pub fn free_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.free(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'locked'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -232,6 +248,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'locked' rescaled to the 'exponent' already written to this block
+        #[inline]
+        pub fn locked_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.locked(mantissa);
+            Ok(())
+        }
+
         /// VAR_DATA ENCODER - character encoding: 'UTF-8'
         #[inline]
         pub fn asset(&mut self, value: &str) {
@@ -274,6 +304,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> OutboundAccountPositionEventDecoder<'a> {
@@ -283,7 +318,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -291,7 +327,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -299,13 +335,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -326,69 +368,96 @@ pub mod decoder {
 
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn balances_decoder(self) -> BalancesDecoder<Self> {
+        pub fn balances_decoder(self) -> SbeResult<BalancesDecoder<'a, Self>> {
             BalancesDecoder::default().wrap(self)
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct BalancesDecoder<P> {
+    pub struct BalancesDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for BalancesDecoder<P>
+    impl<'a, P> Reader<'a> for BalancesDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for BalancesDecoder<P>
+    impl<'a, P> Decoder<'a> for BalancesDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> BalancesDecoder<P>
+    impl<'a, P> BalancesDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 17;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='balances', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=17, offset=16, componentTokenCount=21, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -402,14 +471,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(17))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// primitive field - 'REQUIRED'
@@ -424,28 +494,173 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 1)
         }
 
+        /// 'free' combined with its 'exponent' exponent field
+        #[inline]
+        pub fn free_decimal(&self) -> Decimal {
+            Decimal::new(self.free(), self.exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn locked(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 9)
         }
 
+        /// 'locked' combined with its 'exponent' exponent field
+        #[inline]
+        pub fn locked_decimal(&self) -> Decimal {
+            Decimal::new(self.locked(), self.exponent())
+        }
+
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn asset_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn asset_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn asset_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn asset_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct OutboundAccountPositionEvent {
+        pub event_time: i64,
+        pub update_time: i64,
+        pub balances: Vec<Balances>,
+    }
+
+    impl OutboundAccountPositionEvent {
+        pub fn from_decoder(
+            decoder: &mut OutboundAccountPositionEventDecoder<'_>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                event_time: decoder.event_time(),
+                update_time: decoder.update_time(),
+                balances: {
+                    let mut balances_decoder = core::mem::take(decoder).balances_decoder()?;
+                    let mut entries = Vec::new();
+                    while balances_decoder.advance()?.is_some() {
+                        entries.push(Balances::from_decoder(&mut balances_decoder)?);
+                    }
+                    *decoder = balances_decoder.parent()?;
+                    entries
+                },
+            })
+        }
+
+        pub fn to_encoder(
+            &self,
+            encoder: &mut OutboundAccountPositionEventEncoder<'_>,
+        ) -> SbeResult<()> {
+            encoder.event_time(self.event_time);
+            encoder.update_time(self.update_time);
+            let count = checked_length::<u32>(self.balances.len())?;
+            let mut balances_encoder =
+                core::mem::take(encoder).balances_encoder(count, BalancesEncoder::default());
+            for entry in &self.balances {
+                balances_encoder.advance()?;
+                entry.to_encoder(&mut balances_encoder)?;
+            }
+            *encoder = balances_encoder.parent()?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            22 + self
+                .balances
+                .iter()
+                .map(Balances::encoded_length)
+                .sum::<usize>()
+        }
+    }
+
+    impl OwnedMessage for OutboundAccountPositionEvent {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = OutboundAccountPositionEventDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = OutboundAccountPositionEventEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Balances {
+        pub exponent: i8,
+        pub free: Decimal,
+        pub locked: Decimal,
+        pub asset: String,
+    }
+
+    impl Balances {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut BalancesDecoder<'a, OutboundAccountPositionEventDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                exponent: decoder.exponent(),
+                free: decoder.free_decimal(),
+                locked: decoder.locked_decimal(),
+                asset: {
+                    let coordinates = decoder.asset_decoder()?;
+                    let slice = decoder.asset_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut BalancesEncoder<OutboundAccountPositionEventEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.exponent(fit_exponent(self.exponent, [self.free, self.locked]));
+            encoder.free_decimal(self.free)?;
+            encoder.locked_decimal(self.locked)?;
+            let value = &self.asset;
+            checked_length::<u8>(value.len())?;
+            encoder.asset(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            18 + self.asset.len()
+        }
+    }
+} // end owned
@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::PercentPriceBySideFilterDecoder;
 pub use encoder::PercentPriceBySideFilterEncoder;
+#[cfg(feature = "serde")]
+pub use owned::PercentPriceBySideFilter;
 
 pub const SBE_BLOCK_LENGTH: u16 = 37;
 pub const SBE_TEMPLATE_ID: u16 = 3;
@@ -93,6 +95,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'bidMultiplierUp' rescaled to the 'multiplierExponent' already written to this block
+        #[inline]
+        pub fn bid_multiplier_up_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.bid_multiplier_up(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'bidMultiplierDown'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -107,6 +123,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'bidMultiplierDown' rescaled to the 'multiplierExponent' already written to this block
+        #[inline]
+        pub fn bid_multiplier_down_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.bid_multiplier_down(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'askMultiplierUp'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -121,6 +151,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'askMultiplierUp' rescaled to the 'multiplierExponent' already written to this block
+        #[inline]
+        pub fn ask_multiplier_up_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.ask_multiplier_up(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'askMultiplierDown'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -135,6 +179,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'askMultiplierDown' rescaled to the 'multiplierExponent' already written to this block
+        #[inline]
+        pub fn ask_multiplier_down_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.ask_multiplier_down(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'avgPriceMins'
         /// - min value: -2147483647
         /// - max value: 2147483647
@@ -181,6 +239,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> PercentPriceBySideFilterDecoder<'a> {
@@ -190,7 +253,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -198,7 +262,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -206,13 +270,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -237,24 +307,48 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 1)
         }
 
+        /// 'bidMultiplierUp' combined with its 'multiplierExponent' exponent field
+        #[inline]
+        pub fn bid_multiplier_up_decimal(&self) -> Decimal {
+            Decimal::new(self.bid_multiplier_up(), self.multiplier_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn bid_multiplier_down(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 9)
         }
 
+        /// 'bidMultiplierDown' combined with its 'multiplierExponent' exponent field
+        #[inline]
+        pub fn bid_multiplier_down_decimal(&self) -> Decimal {
+            Decimal::new(self.bid_multiplier_down(), self.multiplier_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn ask_multiplier_up(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 17)
         }
 
+        /// 'askMultiplierUp' combined with its 'multiplierExponent' exponent field
+        #[inline]
+        pub fn ask_multiplier_up_decimal(&self) -> Decimal {
+            Decimal::new(self.ask_multiplier_up(), self.multiplier_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn ask_multiplier_down(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 25)
         }
 
+        /// 'askMultiplierDown' combined with its 'multiplierExponent' exponent field
+        #[inline]
+        pub fn ask_multiplier_down_decimal(&self) -> Decimal {
+            Decimal::new(self.ask_multiplier_down(), self.multiplier_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn avg_price_mins(&self) -> i32 {
@@ -262,3 +356,82 @@ pub mod decoder {
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PercentPriceBySideFilter {
+        pub multiplier_exponent: i8,
+        pub bid_multiplier_up: Decimal,
+        pub bid_multiplier_down: Decimal,
+        pub ask_multiplier_up: Decimal,
+        pub ask_multiplier_down: Decimal,
+        pub avg_price_mins: i32,
+    }
+
+    impl PercentPriceBySideFilter {
+        pub fn from_decoder(decoder: &mut PercentPriceBySideFilterDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                multiplier_exponent: decoder.multiplier_exponent(),
+                bid_multiplier_up: decoder.bid_multiplier_up_decimal(),
+                bid_multiplier_down: decoder.bid_multiplier_down_decimal(),
+                ask_multiplier_up: decoder.ask_multiplier_up_decimal(),
+                ask_multiplier_down: decoder.ask_multiplier_down_decimal(),
+                avg_price_mins: decoder.avg_price_mins(),
+            })
+        }
+
+        pub fn to_encoder(
+            &self,
+            encoder: &mut PercentPriceBySideFilterEncoder<'_>,
+        ) -> SbeResult<()> {
+            encoder.multiplier_exponent(fit_exponent(
+                self.multiplier_exponent,
+                [
+                    self.bid_multiplier_up,
+                    self.bid_multiplier_down,
+                    self.ask_multiplier_up,
+                    self.ask_multiplier_down,
+                ],
+            ));
+            encoder.bid_multiplier_up_decimal(self.bid_multiplier_up)?;
+            encoder.bid_multiplier_down_decimal(self.bid_multiplier_down)?;
+            encoder.ask_multiplier_up_decimal(self.ask_multiplier_up)?;
+            encoder.ask_multiplier_down_decimal(self.ask_multiplier_down)?;
+            encoder.avg_price_mins(self.avg_price_mins);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            37
+        }
+    }
+
+    impl OwnedMessage for PercentPriceBySideFilter {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = PercentPriceBySideFilterDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = PercentPriceBySideFilterEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::PercentPriceFilterDecoder;
 pub use encoder::PercentPriceFilterEncoder;
+#[cfg(feature = "serde")]
+pub use owned::PercentPriceFilter;
 
 pub const SBE_BLOCK_LENGTH: u16 = 21;
 pub const SBE_TEMPLATE_ID: u16 = 2;
@@ -93,6 +95,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'multiplierUp' rescaled to the 'multiplierExponent' already written to this block
+        #[inline]
+        pub fn multiplier_up_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.multiplier_up(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'multiplierDown'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -107,6 +123,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'multiplierDown' rescaled to the 'multiplierExponent' already written to this block
+        #[inline]
+        pub fn multiplier_down_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.multiplier_down(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'avgPriceMins'
         /// - min value: -2147483647
         /// - max value: 2147483647
@@ -153,6 +183,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> PercentPriceFilterDecoder<'a> {
@@ -162,7 +197,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -170,7 +206,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -178,13 +214,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -209,12 +251,24 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 1)
         }
 
+        /// 'multiplierUp' combined with its 'multiplierExponent' exponent field
+        #[inline]
+        pub fn multiplier_up_decimal(&self) -> Decimal {
+            Decimal::new(self.multiplier_up(), self.multiplier_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn multiplier_down(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 9)
         }
 
+        /// 'multiplierDown' combined with its 'multiplierExponent' exponent field
+        #[inline]
+        pub fn multiplier_down_decimal(&self) -> Decimal {
+            Decimal::new(self.multiplier_down(), self.multiplier_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn avg_price_mins(&self) -> i32 {
@@ -222,3 +276,68 @@ pub mod decoder {
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PercentPriceFilter {
+        pub multiplier_exponent: i8,
+        pub multiplier_up: Decimal,
+        pub multiplier_down: Decimal,
+        pub avg_price_mins: i32,
+    }
+
+    impl PercentPriceFilter {
+        pub fn from_decoder(decoder: &mut PercentPriceFilterDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                multiplier_exponent: decoder.multiplier_exponent(),
+                multiplier_up: decoder.multiplier_up_decimal(),
+                multiplier_down: decoder.multiplier_down_decimal(),
+                avg_price_mins: decoder.avg_price_mins(),
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut PercentPriceFilterEncoder<'_>) -> SbeResult<()> {
+            encoder.multiplier_exponent(fit_exponent(
+                self.multiplier_exponent,
+                [self.multiplier_up, self.multiplier_down],
+            ));
+            encoder.multiplier_up_decimal(self.multiplier_up)?;
+            encoder.multiplier_down_decimal(self.multiplier_down)?;
+            encoder.avg_price_mins(self.avg_price_mins);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            21
+        }
+    }
+
+    impl OwnedMessage for PercentPriceFilter {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = PercentPriceFilterDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = PercentPriceFilterEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::PriceFilterDecoder;
 pub use encoder::PriceFilterEncoder;
+#[cfg(feature = "serde")]
+pub use owned::PriceFilter;
 
 pub const SBE_BLOCK_LENGTH: u16 = 25;
 pub const SBE_TEMPLATE_ID: u16 = 1;
@@ -93,6 +95,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'minPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn min_price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.min_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'maxPrice'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -107,6 +123,20 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'maxPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn max_price_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.max_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'tickSize'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -120,6 +150,20 @@ pub mod encoder {
             let offset = self.offset + 17;
             self.get_buf_mut().put_i64_at(offset, value);
         }
+
+        /// 'tickSize' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn tick_size_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.tick_size(mantissa);
+            Ok(())
+        }
     }
 } // end encoder
 
@@ -153,6 +197,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> PriceFilterDecoder<'a> {
@@ -162,7 +211,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -170,7 +220,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -178,13 +228,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -209,16 +265,99 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 1)
         }
 
+        /// 'minPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn min_price_decimal(&self) -> Decimal {
+            Decimal::new(self.min_price(), self.price_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn max_price(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 9)
         }
 
+        /// 'maxPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn max_price_decimal(&self) -> Decimal {
+            Decimal::new(self.max_price(), self.price_exponent())
+        }
+
         /// primitive field - 'REQUIRED'
         #[inline]
         pub fn tick_size(&self) -> i64 {
             self.get_buf().get_i64_at(self.offset + 17)
         }
+
+        /// 'tickSize' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn tick_size_decimal(&self) -> Decimal {
+            Decimal::new(self.tick_size(), self.price_exponent())
+        }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PriceFilter {
+        pub price_exponent: i8,
+        pub min_price: Decimal,
+        pub max_price: Decimal,
+        pub tick_size: Decimal,
+    }
+
+    impl PriceFilter {
+        pub fn from_decoder(decoder: &mut PriceFilterDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                min_price: decoder.min_price_decimal(),
+                max_price: decoder.max_price_decimal(),
+                tick_size: decoder.tick_size_decimal(),
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut PriceFilterEncoder<'_>) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                [self.min_price, self.max_price, self.tick_size],
+            ));
+            encoder.min_price_decimal(self.min_price)?;
+            encoder.max_price_decimal(self.max_price)?;
+            encoder.tick_size_decimal(self.tick_size)?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            25
+        }
+    }
+
+    impl OwnedMessage for PriceFilter {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = PriceFilterDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = PriceFilterEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
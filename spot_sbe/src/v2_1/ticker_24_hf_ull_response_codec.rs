@@ -0,0 +1,1440 @@
+use super::*;
+
+pub use decoder::Ticker24hFullResponseDecoder;
+pub use encoder::Ticker24hFullResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::Ticker24hFullResponse;
+
+pub const SBE_BLOCK_LENGTH: u16 = 0;
+pub const SBE_TEMPLATE_ID: u16 = 206;
+pub const SBE_SCHEMA_ID: u16 = 2;
+pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SEMANTIC_VERSION: &str = "5.2";
+
+pub mod encoder {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct Ticker24hFullResponseEncoder<'a> {
+        buf: WriteBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+    }
+
+    impl<'a> Writer<'a> for Ticker24hFullResponseEncoder<'a> {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            &mut self.buf
+        }
+    }
+
+    impl<'a> Encoder<'a> for Ticker24hFullResponseEncoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+    }
+
+    impl<'a> Ticker24hFullResponseEncoder<'a> {
+        pub fn wrap(mut self, buf: WriteBuf<'a>, offset: usize) -> Self {
+            let limit = offset + SBE_BLOCK_LENGTH as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self
+        }
+
+        #[inline]
+        pub fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        pub fn header(self, offset: usize) -> MessageHeaderEncoder<Self> {
+            let mut header = MessageHeaderEncoder::default().wrap(self, offset);
+            header.block_length(SBE_BLOCK_LENGTH);
+            header.template_id(SBE_TEMPLATE_ID);
+            header.schema_id(SBE_SCHEMA_ID);
+            header.version(SBE_SCHEMA_VERSION);
+            header
+        }
+
+        /// GROUP ENCODER (id=100)
+        #[inline]
+        pub fn tickers_encoder(
+            self,
+            count: u32,
+            tickers_encoder: TickersEncoder<Self>,
+        ) -> TickersEncoder<Self> {
+            tickers_encoder.wrap(self, count)
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct TickersEncoder<P> {
+        parent: Option<P>,
+        count: u32,
+        index: usize,
+        offset: usize,
+        initial_limit: usize,
+    }
+
+    impl<'a, P> Writer<'a> for TickersEncoder<P>
+    where
+        P: Writer<'a> + Default,
+    {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            if let Some(parent) = self.parent.as_mut() {
+                parent.get_buf_mut()
+            } else {
+                panic!("parent was None")
+            }
+        }
+    }
+
+    impl<'a, P> Encoder<'a> for TickersEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.parent.as_ref().expect("parent missing").get_limit()
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.parent
+                .as_mut()
+                .expect("parent missing")
+                .set_limit(limit);
+        }
+    }
+
+    impl<'a, P> TickersEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        pub fn wrap(mut self, mut parent: P, count: u32) -> Self {
+            let initial_limit = parent.get_limit();
+            parent.set_limit(initial_limit + 6);
+            parent
+                .get_buf_mut()
+                .put_u16_at(initial_limit, Self::block_length());
+            parent.get_buf_mut().put_u32_at(initial_limit + 2, count);
+            self.parent = Some(parent);
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = usize::MAX;
+            self.initial_limit = initial_limit;
+            self
+        }
+
+        #[inline]
+        pub fn block_length() -> u16 {
+            182
+        }
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }
+
+        /// will return Some(current index) when successful otherwise None
+        #[inline]
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+            if let Some(parent) = self.parent.as_mut() {
+                self.offset = parent.get_limit();
+                parent.set_limit(self.offset + Self::block_length() as usize);
+                self.index = index;
+                Ok(Some(index))
+            } else {
+                Err(SbeErr::ParentNotSet)
+            }
+        }
+
+        /// primitive field 'priceExponent'
+        /// - min value: -127
+        /// - max value: 127
+        /// - null value: -128
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 0
+        /// - encodedLength: 1
+        #[inline]
+        pub fn price_exponent(&mut self, value: i8) {
+            let offset = self.offset;
+            self.get_buf_mut().put_i8_at(offset, value);
+        }
+
+        /// primitive field 'qtyExponent'
+        /// - min value: -127
+        /// - max value: 127
+        /// - null value: -128
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 1
+        /// - encodedLength: 1
+        #[inline]
+        pub fn qty_exponent(&mut self, value: i8) {
+            let offset = self.offset + 1;
+            self.get_buf_mut().put_i8_at(offset, value);
+        }
+
+        /// primitive field 'priceChange'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 2
+        /// - encodedLength: 8
+        #[inline]
+        pub fn price_change(&mut self, value: i64) {
+            let offset = self.offset + 2;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'priceChange' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn price_change_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.price_change(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price_change(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'priceChangePercent'
+        /// - min value: 1.401298464324817E-45
+        /// - max value: 3.4028234663852886E38
+        /// - null value: NaN
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 10
+        /// - encodedLength: 4
+        #[inline]
+        pub fn price_change_percent(&mut self, value: f32) {
+            let offset = self.offset + 10;
+            self.get_buf_mut().put_f32_at(offset, value);
+        }
+
+        /// primitive field 'weightedAvgPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 14
+        /// - encodedLength: 8
+        #[inline]
+        pub fn weighted_avg_price(&mut self, value: i64) {
+            let offset = self.offset + 14;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'weightedAvgPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn weighted_avg_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.weighted_avg_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.weighted_avg_price(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'prevClosePrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 22
+        /// - encodedLength: 8
+        #[inline]
+        pub fn prev_close_price(&mut self, value: i64) {
+            let offset = self.offset + 22;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'prevClosePrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn prev_close_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.prev_close_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.prev_close_price(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'lastPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 30
+        /// - encodedLength: 8
+        #[inline]
+        pub fn last_price(&mut self, value: i64) {
+            let offset = self.offset + 30;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'lastPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn last_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.last_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.last_price(mantissa);
+            Ok(())
+        }
+
+        /// mantissa128 field 'lastQty' as a little-endian i128
+        /// - null value: -170141183460469231731687303715884105728
+        /// - encodedOffset: 38
+        /// - encodedLength: 16
+        /// - version: 0
+        #[inline]
+        pub fn last_qty(&mut self, value: Option<i128>) {
+            let offset = self.offset + 38;
+            self.get_buf_mut()
+                .put_i128_at(offset, value.unwrap_or(i128::MIN));
+        }
+
+        /// 'lastQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn last_qty_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.last_qty(None);
+                return Ok(());
+            };
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .map(|value| value.mantissa())
+                .filter(|&mantissa| mantissa != i128::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.last_qty(Some(mantissa));
+            Ok(())
+        }
+
+        /// primitive field 'bidPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 54
+        /// - encodedLength: 8
+        #[inline]
+        pub fn bid_price(&mut self, value: i64) {
+            let offset = self.offset + 54;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'bidPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn bid_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.bid_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.bid_price(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'bidQty'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 62
+        /// - encodedLength: 8
+        #[inline]
+        pub fn bid_qty(&mut self, value: i64) {
+            let offset = self.offset + 62;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'bidQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn bid_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.bid_qty(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'askPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 70
+        /// - encodedLength: 8
+        #[inline]
+        pub fn ask_price(&mut self, value: i64) {
+            let offset = self.offset + 70;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'askPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn ask_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.ask_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.ask_price(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'askQty'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 78
+        /// - encodedLength: 8
+        #[inline]
+        pub fn ask_qty(&mut self, value: i64) {
+            let offset = self.offset + 78;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'askQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn ask_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.ask_qty(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'openPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 86
+        /// - encodedLength: 8
+        #[inline]
+        pub fn open_price(&mut self, value: i64) {
+            let offset = self.offset + 86;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'openPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn open_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.open_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.open_price(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'highPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 94
+        /// - encodedLength: 8
+        #[inline]
+        pub fn high_price(&mut self, value: i64) {
+            let offset = self.offset + 94;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'highPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn high_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.high_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.high_price(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'lowPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 102
+        /// - encodedLength: 8
+        #[inline]
+        pub fn low_price(&mut self, value: i64) {
+            let offset = self.offset + 102;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'lowPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn low_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.low_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.low_price(mantissa);
+            Ok(())
+        }
+
+        /// mantissa128 field 'volume' as a little-endian i128
+        /// - null value: -170141183460469231731687303715884105728
+        /// - encodedOffset: 110
+        /// - encodedLength: 16
+        /// - version: 0
+        #[inline]
+        pub fn volume(&mut self, value: Option<i128>) {
+            let offset = self.offset + 110;
+            self.get_buf_mut()
+                .put_i128_at(offset, value.unwrap_or(i128::MIN));
+        }
+
+        /// 'volume' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn volume_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.volume(None);
+                return Ok(());
+            };
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .map(|value| value.mantissa())
+                .filter(|&mantissa| mantissa != i128::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.volume(Some(mantissa));
+            Ok(())
+        }
+
+        /// mantissa128 field 'quoteVolume' as a little-endian i128
+        /// - null value: -170141183460469231731687303715884105728
+        /// - encodedOffset: 126
+        /// - encodedLength: 16
+        /// - version: 0
+        #[inline]
+        pub fn quote_volume(&mut self, value: Option<i128>) {
+            let offset = self.offset + 126;
+            self.get_buf_mut()
+                .put_i128_at(offset, value.unwrap_or(i128::MIN));
+        }
+
+        /// 'quoteVolume' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn quote_volume_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.quote_volume(None);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .map(|value| value.mantissa())
+                .filter(|&mantissa| mantissa != i128::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.quote_volume(Some(mantissa));
+            Ok(())
+        }
+
+        /// primitive field 'openTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 142
+        /// - encodedLength: 8
+        #[inline]
+        pub fn open_time(&mut self, value: i64) {
+            let offset = self.offset + 142;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'closeTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 150
+        /// - encodedLength: 8
+        #[inline]
+        pub fn close_time(&mut self, value: i64) {
+            let offset = self.offset + 150;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'firstId'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 158
+        /// - encodedLength: 8
+        #[inline]
+        pub fn first_id(&mut self, value: i64) {
+            let offset = self.offset + 158;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'lastId'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 166
+        /// - encodedLength: 8
+        #[inline]
+        pub fn last_id(&mut self, value: i64) {
+            let offset = self.offset + 166;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'numTrades'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 174
+        /// - encodedLength: 8
+        #[inline]
+        pub fn num_trades(&mut self, value: i64) {
+            let offset = self.offset + 174;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn symbol(&mut self, value: &str) {
+            let limit = self.get_limit();
+            let data_length = value.len();
+            self.set_limit(limit + 1 + data_length);
+            self.get_buf_mut().put_u8_at(limit, data_length as u8);
+            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
+        }
+    }
+} // end encoder
+
+pub mod decoder {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Ticker24hFullResponseDecoder<'a> {
+        buf: ReadBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+        pub acting_block_length: u16,
+        pub acting_version: u16,
+    }
+
+    impl<'a> Reader<'a> for Ticker24hFullResponseDecoder<'a> {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            &self.buf
+        }
+    }
+
+    impl<'a> Decoder<'a> for Ticker24hFullResponseDecoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
+    }
+
+    impl<'a> Ticker24hFullResponseDecoder<'a> {
+        pub fn wrap(
+            mut self,
+            buf: ReadBuf<'a>,
+            offset: usize,
+            acting_block_length: u16,
+            acting_version: u16,
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length as usize)?;
+            let limit = offset + acting_block_length as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self.acting_block_length = acting_block_length;
+            self.acting_version = acting_version;
+            Ok(self)
+        }
+
+        #[inline]
+        pub fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
+            let acting_block_length = header.block_length();
+            let acting_version = header.version();
+
+            self.wrap(
+                header.parent()?,
+                message_header_codec::ENCODED_LENGTH,
+                acting_block_length,
+                acting_version,
+            )
+        }
+
+        /// GROUP DECODER (id=100)
+        #[inline]
+        pub fn tickers_decoder(self) -> SbeResult<TickersDecoder<'a, Self>> {
+            TickersDecoder::default().wrap(self)
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct TickersDecoder<'a, P> {
+        parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
+        block_length: usize,
+        count: u32,
+        index: usize,
+        offset: usize,
+    }
+
+    impl<'a, P> Reader<'a> for TickersDecoder<'a, P>
+    where
+        P: Reader<'a> + Default,
+    {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            &self.buf
+        }
+    }
+
+    impl<'a, P> Decoder<'a> for TickersDecoder<'a, P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
+    }
+
+    impl<'a, P> TickersDecoder<'a, P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
+            let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
+            let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
+            let count = parent.get_buf().get_u32_at(initial_offset + 2);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 182;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
+            self.parent = Some(parent);
+            self.block_length = block_length;
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = 0;
+            Ok(self)
+        }
+
+        /// group token - Token{signal=BEGIN_GROUP, name='tickers', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=182, offset=0, componentTokenCount=78, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
+        }
+
+        #[inline]
+        pub fn count(&self) -> u32 {
+            self.count
+        }
+
+        /// will return Some(current index) when successful otherwise None
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(182))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn price_exponent(&self) -> i8 {
+            self.get_buf().get_i8_at(self.offset)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn qty_exponent(&self) -> i8 {
+            self.get_buf().get_i8_at(self.offset + 1)
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn price_change(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 2);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'priceChange' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_change_decimal(&self) -> Option<Decimal> {
+            self.price_change()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: 'NaN' }
+        #[inline]
+        pub fn price_change_percent(&self) -> Option<f32> {
+            let value = self.get_buf().get_f32_at(self.offset + 10);
+            if value.is_nan() {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn weighted_avg_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 14);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'weightedAvgPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn weighted_avg_price_decimal(&self) -> Option<Decimal> {
+            self.weighted_avg_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn prev_close_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 22);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'prevClosePrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn prev_close_price_decimal(&self) -> Option<Decimal> {
+            self.prev_close_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn last_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 30);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'lastPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn last_price_decimal(&self) -> Option<Decimal> {
+            self.last_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// mantissa128 field - little-endian i128 { null_value: '-170141183460469231731687303715884105728' }
+        #[inline]
+        pub fn last_qty(&self) -> Option<i128> {
+            let value = self.get_buf().get_i128_at(self.offset + 38);
+            if value == -170141183460469231731687303715884105728_i128 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'lastQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn last_qty_decimal(&self) -> Option<Decimal> {
+            self.last_qty()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn bid_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 54);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'bidPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn bid_price_decimal(&self) -> Option<Decimal> {
+            self.bid_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn bid_qty(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 62)
+        }
+
+        /// 'bidQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn bid_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.bid_qty(), self.qty_exponent())
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn ask_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 70);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'askPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn ask_price_decimal(&self) -> Option<Decimal> {
+            self.ask_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn ask_qty(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 78)
+        }
+
+        /// 'askQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn ask_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.ask_qty(), self.qty_exponent())
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn open_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 86);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'openPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn open_price_decimal(&self) -> Option<Decimal> {
+            self.open_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn high_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 94);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'highPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn high_price_decimal(&self) -> Option<Decimal> {
+            self.high_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn low_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 102);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'lowPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn low_price_decimal(&self) -> Option<Decimal> {
+            self.low_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// mantissa128 field - little-endian i128 { null_value: '-170141183460469231731687303715884105728' }
+        #[inline]
+        pub fn volume(&self) -> Option<i128> {
+            let value = self.get_buf().get_i128_at(self.offset + 110);
+            if value == -170141183460469231731687303715884105728_i128 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'volume' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn volume_decimal(&self) -> Option<Decimal> {
+            self.volume()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
+        /// mantissa128 field - little-endian i128 { null_value: '-170141183460469231731687303715884105728' }
+        #[inline]
+        pub fn quote_volume(&self) -> Option<i128> {
+            let value = self.get_buf().get_i128_at(self.offset + 126);
+            if value == -170141183460469231731687303715884105728_i128 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'quoteVolume' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn quote_volume_decimal(&self) -> Option<Decimal> {
+            self.quote_volume()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn open_time(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 142)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn close_time(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 150)
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn first_id(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 158);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn last_id(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 166);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn num_trades(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 174)
+        }
+
+        /// VAR_DATA DECODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
+            let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
+        }
+
+        #[inline]
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
+        }
+    }
+} // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Ticker24hFullResponse {
+        pub tickers: Vec<Tickers>,
+    }
+
+    impl Ticker24hFullResponse {
+        pub fn from_decoder(decoder: &mut Ticker24hFullResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                tickers: {
+                    let mut tickers_decoder = core::mem::take(decoder).tickers_decoder()?;
+                    let mut entries = Vec::new();
+                    while tickers_decoder.advance()?.is_some() {
+                        entries.push(Tickers::from_decoder(&mut tickers_decoder)?);
+                    }
+                    *decoder = tickers_decoder.parent()?;
+                    entries
+                },
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut Ticker24hFullResponseEncoder<'_>) -> SbeResult<()> {
+            let count = checked_length::<u32>(self.tickers.len())?;
+            let mut tickers_encoder =
+                core::mem::take(encoder).tickers_encoder(count, TickersEncoder::default());
+            for entry in &self.tickers {
+                tickers_encoder.advance()?;
+                entry.to_encoder(&mut tickers_encoder)?;
+            }
+            *encoder = tickers_encoder.parent()?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            6 + self
+                .tickers
+                .iter()
+                .map(Tickers::encoded_length)
+                .sum::<usize>()
+        }
+    }
+
+    impl OwnedMessage for Ticker24hFullResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = Ticker24hFullResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = Ticker24hFullResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Tickers {
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub price_change: Option<Decimal>,
+        pub price_change_percent: Option<f32>,
+        pub weighted_avg_price: Option<Decimal>,
+        pub prev_close_price: Option<Decimal>,
+        pub last_price: Option<Decimal>,
+        pub last_qty: Option<Decimal>,
+        pub bid_price: Option<Decimal>,
+        pub bid_qty: Decimal,
+        pub ask_price: Option<Decimal>,
+        pub ask_qty: Decimal,
+        pub open_price: Option<Decimal>,
+        pub high_price: Option<Decimal>,
+        pub low_price: Option<Decimal>,
+        pub volume: Option<Decimal>,
+        pub quote_volume: Option<Decimal>,
+        pub open_time: i64,
+        pub close_time: i64,
+        pub first_id: Option<i64>,
+        pub last_id: Option<i64>,
+        pub num_trades: i64,
+        pub symbol: String,
+    }
+
+    impl Tickers {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut TickersDecoder<'a, Ticker24hFullResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                price_change: decoder.price_change_decimal(),
+                price_change_percent: decoder.price_change_percent(),
+                weighted_avg_price: decoder.weighted_avg_price_decimal(),
+                prev_close_price: decoder.prev_close_price_decimal(),
+                last_price: decoder.last_price_decimal(),
+                last_qty: decoder.last_qty_decimal(),
+                bid_price: decoder.bid_price_decimal(),
+                bid_qty: decoder.bid_qty_decimal(),
+                ask_price: decoder.ask_price_decimal(),
+                ask_qty: decoder.ask_qty_decimal(),
+                open_price: decoder.open_price_decimal(),
+                high_price: decoder.high_price_decimal(),
+                low_price: decoder.low_price_decimal(),
+                volume: decoder.volume_decimal(),
+                quote_volume: decoder.quote_volume_decimal(),
+                open_time: decoder.open_time(),
+                close_time: decoder.close_time(),
+                first_id: decoder.first_id(),
+                last_id: decoder.last_id(),
+                num_trades: decoder.num_trades(),
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut TickersEncoder<Ticker24hFullResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                self.price_change
+                    .into_iter()
+                    .chain(self.weighted_avg_price)
+                    .chain(self.prev_close_price)
+                    .chain(self.last_price)
+                    .chain(self.bid_price)
+                    .chain(self.ask_price)
+                    .chain(self.open_price)
+                    .chain(self.high_price)
+                    .chain(self.low_price)
+                    .chain(self.quote_volume),
+            ));
+            encoder.qty_exponent(fit_exponent(
+                self.qty_exponent,
+                [self.bid_qty, self.ask_qty]
+                    .into_iter()
+                    .chain(self.last_qty)
+                    .chain(self.volume),
+            ));
+            encoder.price_change_decimal(self.price_change)?;
+            encoder.price_change_percent(self.price_change_percent.unwrap_or(f32::NAN));
+            encoder.weighted_avg_price_decimal(self.weighted_avg_price)?;
+            encoder.prev_close_price_decimal(self.prev_close_price)?;
+            encoder.last_price_decimal(self.last_price)?;
+            encoder.last_qty_decimal(self.last_qty)?;
+            encoder.bid_price_decimal(self.bid_price)?;
+            encoder.bid_qty_decimal(self.bid_qty)?;
+            encoder.ask_price_decimal(self.ask_price)?;
+            encoder.ask_qty_decimal(self.ask_qty)?;
+            encoder.open_price_decimal(self.open_price)?;
+            encoder.high_price_decimal(self.high_price)?;
+            encoder.low_price_decimal(self.low_price)?;
+            encoder.volume_decimal(self.volume)?;
+            encoder.quote_volume_decimal(self.quote_volume)?;
+            encoder.open_time(self.open_time);
+            encoder.close_time(self.close_time);
+            encoder.first_id(self.first_id.unwrap_or(i64::MIN));
+            encoder.last_id(self.last_id.unwrap_or(i64::MIN));
+            encoder.num_trades(self.num_trades);
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            183 + self.symbol.len()
+        }
+    }
+} // end owned
@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::Ticker24hMiniResponseDecoder;
 pub use encoder::Ticker24hMiniResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::Ticker24hMiniResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 0;
 pub const SBE_TEMPLATE_ID: u16 = 208;
@@ -204,6 +206,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'openPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn open_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.open_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.open_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'highPrice'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -218,6 +238,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'highPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn high_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.high_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.high_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'lowPrice'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -232,6 +270,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'lowPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn low_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.low_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.low_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'lastPrice'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -246,36 +302,82 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
-        /// primitive array field 'volume'
-        /// - min value: 0
-        /// - max value: 254
-        /// - null value: 255
-        /// - characterEncoding: null
-        /// - semanticType: null
+        /// 'lastPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn last_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.last_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.last_price(mantissa);
+            Ok(())
+        }
+
+        /// mantissa128 field 'volume' as a little-endian i128
+        /// - null value: -170141183460469231731687303715884105728
         /// - encodedOffset: 34
         /// - encodedLength: 16
         /// - version: 0
         #[inline]
-        pub fn volume(&mut self, value: [u8; 16]) {
+        pub fn volume(&mut self, value: Option<i128>) {
             let offset = self.offset + 34;
-            let buf = self.get_buf_mut();
-            buf.put_bytes_at(offset, value);
+            self.get_buf_mut()
+                .put_i128_at(offset, value.unwrap_or(i128::MIN));
         }
 
-        /// primitive array field 'quoteVolume'
-        /// - min value: 0
-        /// - max value: 254
-        /// - null value: 255
-        /// - characterEncoding: null
-        /// - semanticType: null
+        /// 'volume' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn volume_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.volume(None);
+                return Ok(());
+            };
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .map(|value| value.mantissa())
+                .filter(|&mantissa| mantissa != i128::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.volume(Some(mantissa));
+            Ok(())
+        }
+
+        /// mantissa128 field 'quoteVolume' as a little-endian i128
+        /// - null value: -170141183460469231731687303715884105728
         /// - encodedOffset: 50
         /// - encodedLength: 16
         /// - version: 0
         #[inline]
-        pub fn quote_volume(&mut self, value: [u8; 16]) {
+        pub fn quote_volume(&mut self, value: Option<i128>) {
             let offset = self.offset + 50;
-            let buf = self.get_buf_mut();
-            buf.put_bytes_at(offset, value);
+            self.get_buf_mut()
+                .put_i128_at(offset, value.unwrap_or(i128::MIN));
+        }
+
+        /// 'quoteVolume' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn quote_volume_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.quote_volume(None);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .map(|value| value.mantissa())
+                .filter(|&mantissa| mantissa != i128::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.quote_volume(Some(mantissa));
+            Ok(())
         }
 
         /// primitive field 'openTime'
@@ -390,6 +492,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> Ticker24hMiniResponseDecoder<'a> {
@@ -399,7 +506,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -407,7 +515,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -415,13 +523,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -430,69 +544,96 @@ pub mod decoder {
 
         /// GROUP DECODER (id=100)
         #[inline]
-        pub fn tickers_decoder(self) -> TickersDecoder<Self> {
+        pub fn tickers_decoder(self) -> SbeResult<TickersDecoder<'a, Self>> {
             TickersDecoder::default().wrap(self)
         }
     }
 
     #[derive(Debug, Default)]
-    pub struct TickersDecoder<P> {
+    pub struct TickersDecoder<'a, P> {
         parent: Option<P>,
+        /// Copied from the parent on `wrap`, so that reading the group never
+        /// needs the parent; its limit is handed back by `parent`.
+        buf: ReadBuf<'a>,
+        limit: usize,
+        acting_version: u16,
         block_length: usize,
         count: u32,
         index: usize,
         offset: usize,
     }
 
-    impl<'a, P> Reader<'a> for TickersDecoder<P>
+    impl<'a, P> Reader<'a> for TickersDecoder<'a, P>
     where
         P: Reader<'a> + Default,
     {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
-            self.parent.as_ref().expect("parent missing").get_buf()
+            &self.buf
         }
     }
 
-    impl<'a, P> Decoder<'a> for TickersDecoder<P>
+    impl<'a, P> Decoder<'a> for TickersDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
         #[inline]
         fn get_limit(&self) -> usize {
-            self.parent.as_ref().expect("parent missing").get_limit()
+            self.limit
         }
 
         #[inline]
         fn set_limit(&mut self, limit: usize) {
-            self.parent
-                .as_mut()
-                .expect("parent missing")
-                .set_limit(limit);
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
         }
     }
 
-    impl<'a, P> TickersDecoder<P>
+    impl<'a, P> TickersDecoder<'a, P>
     where
         P: Decoder<'a> + Default,
     {
-        pub fn wrap(mut self, mut parent: P) -> Self {
+        pub fn wrap(mut self, parent: P) -> SbeResult<Self> {
             let initial_offset = parent.get_limit();
+            parent.get_buf().check_bounds(initial_offset, 6)?;
             let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;
             let count = parent.get_buf().get_u32_at(initial_offset + 2);
-            parent.set_limit(initial_offset + 6);
+            let available = parent.get_buf().len() - (initial_offset + 6);
+            let expected = 106;
+            if block_length < expected {
+                return Err(SbeErr::InvalidBlockLength {
+                    block_length,
+                    expected,
+                });
+            }
+            if count > 2147483647 || (count as usize).saturating_mul(block_length + 1) > available {
+                return Err(SbeErr::InvalidGroupCount {
+                    count: count as usize,
+                    block_length,
+                    available,
+                });
+            }
+            self.buf = *parent.get_buf();
+            self.limit = initial_offset + 6;
+            self.acting_version = parent.acting_version();
             self.parent = Some(parent);
             self.block_length = block_length;
             self.count = count;
             self.index = usize::MAX;
             self.offset = 0;
-            self
+            Ok(self)
         }
 
         /// group token - Token{signal=BEGIN_GROUP, name='tickers', referencedName='null', description='null', packageName='null', id=100, version=0, deprecated=0, encodedLength=106, offset=0, componentTokenCount=51, encoding=Encoding{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}
         #[inline]
         pub fn parent(&mut self) -> SbeResult<P> {
-            self.parent.take().ok_or(SbeErr::ParentNotSet)
+            let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;
+            parent.set_limit(self.limit);
+            Ok(parent)
         }
 
         #[inline]
@@ -506,14 +647,15 @@ pub mod decoder {
             if index >= self.count as usize {
                 return Ok(None);
             }
-            if let Some(parent) = self.parent.as_mut() {
-                self.offset = parent.get_limit();
-                parent.set_limit(self.offset + self.block_length);
-                self.index = index;
-                Ok(Some(index))
-            } else {
-                Err(SbeErr::ParentNotSet)
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
             }
+            let offset = self.limit;
+            self.buf.check_bounds(offset, self.block_length.max(106))?;
+            self.offset = offset;
+            self.limit = offset + self.block_length;
+            self.index = index;
+            Ok(Some(index))
         }
 
         /// primitive field - 'REQUIRED'
@@ -539,6 +681,13 @@ pub mod decoder {
             }
         }
 
+        /// 'openPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn open_price_decimal(&self) -> Option<Decimal> {
+            self.open_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn high_price(&self) -> Option<i64> {
@@ -550,6 +699,13 @@ pub mod decoder {
             }
         }
 
+        /// 'highPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn high_price_decimal(&self) -> Option<Decimal> {
+            self.high_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn low_price(&self) -> Option<i64> {
@@ -561,6 +717,13 @@ pub mod decoder {
             }
         }
 
+        /// 'lowPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn low_price_decimal(&self) -> Option<Decimal> {
+            self.low_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn last_price(&self) -> Option<i64> {
@@ -572,16 +735,47 @@ pub mod decoder {
             }
         }
 
+        /// 'lastPrice' combined with its 'priceExponent' exponent field
         #[inline]
-        pub fn volume(&self) -> [u8; 16] {
-            let buf = self.get_buf();
-            ReadBuf::get_bytes_at(buf.data, self.offset + 34)
+        pub fn last_price_decimal(&self) -> Option<Decimal> {
+            self.last_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
         }
 
+        /// mantissa128 field - little-endian i128 { null_value: '-170141183460469231731687303715884105728' }
         #[inline]
-        pub fn quote_volume(&self) -> [u8; 16] {
-            let buf = self.get_buf();
-            ReadBuf::get_bytes_at(buf.data, self.offset + 50)
+        pub fn volume(&self) -> Option<i128> {
+            let value = self.get_buf().get_i128_at(self.offset + 34);
+            if value == -170141183460469231731687303715884105728_i128 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'volume' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn volume_decimal(&self) -> Option<Decimal> {
+            self.volume()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
+        /// mantissa128 field - little-endian i128 { null_value: '-170141183460469231731687303715884105728' }
+        #[inline]
+        pub fn quote_volume(&self) -> Option<i128> {
+            let value = self.get_buf().get_i128_at(self.offset + 50);
+            if value == -170141183460469231731687303715884105728_i128 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'quoteVolume' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn quote_volume_decimal(&self) -> Option<Decimal> {
+            self.quote_volume()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
         }
 
         /// primitive field - 'REQUIRED'
@@ -626,20 +820,180 @@ pub mod decoder {
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
-            let offset = self.parent.as_ref().expect("parent missing").get_limit();
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            if self.parent.is_none() {
+                return Err(SbeErr::ParentNotSet);
+            }
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
-            self.parent
-                .as_mut()
-                .unwrap()
-                .set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Ticker24hMiniResponse {
+        pub tickers: Vec<Tickers>,
+    }
+
+    impl Ticker24hMiniResponse {
+        pub fn from_decoder(decoder: &mut Ticker24hMiniResponseDecoder<'_>) -> SbeResult<Self> {
+            Ok(Self {
+                tickers: {
+                    let mut tickers_decoder = core::mem::take(decoder).tickers_decoder()?;
+                    let mut entries = Vec::new();
+                    while tickers_decoder.advance()?.is_some() {
+                        entries.push(Tickers::from_decoder(&mut tickers_decoder)?);
+                    }
+                    *decoder = tickers_decoder.parent()?;
+                    entries
+                },
+            })
+        }
+
+        pub fn to_encoder(&self, encoder: &mut Ticker24hMiniResponseEncoder<'_>) -> SbeResult<()> {
+            let count = checked_length::<u32>(self.tickers.len())?;
+            let mut tickers_encoder =
+                core::mem::take(encoder).tickers_encoder(count, TickersEncoder::default());
+            for entry in &self.tickers {
+                tickers_encoder.advance()?;
+                entry.to_encoder(&mut tickers_encoder)?;
+            }
+            *encoder = tickers_encoder.parent()?;
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            6 + self
+                .tickers
+                .iter()
+                .map(Tickers::encoded_length)
+                .sum::<usize>()
+        }
+    }
+
+    impl OwnedMessage for Ticker24hMiniResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = Ticker24hMiniResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = Ticker24hMiniResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Tickers {
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub open_price: Option<Decimal>,
+        pub high_price: Option<Decimal>,
+        pub low_price: Option<Decimal>,
+        pub last_price: Option<Decimal>,
+        pub volume: Option<Decimal>,
+        pub quote_volume: Option<Decimal>,
+        pub open_time: i64,
+        pub close_time: i64,
+        pub first_id: Option<i64>,
+        pub last_id: Option<i64>,
+        pub num_trades: i64,
+        pub symbol: String,
+    }
+
+    impl Tickers {
+        /// Reads the entry `decoder` has advanced to.
+        pub fn from_decoder<'a>(
+            decoder: &mut TickersDecoder<'a, Ticker24hMiniResponseDecoder<'a>>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                open_price: decoder.open_price_decimal(),
+                high_price: decoder.high_price_decimal(),
+                low_price: decoder.low_price_decimal(),
+                last_price: decoder.last_price_decimal(),
+                volume: decoder.volume_decimal(),
+                quote_volume: decoder.quote_volume_decimal(),
+                open_time: decoder.open_time(),
+                close_time: decoder.close_time(),
+                first_id: decoder.first_id(),
+                last_id: decoder.last_id(),
+                num_trades: decoder.num_trades(),
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        /// Writes the entry `encoder` has advanced to.
+        pub fn to_encoder(
+            &self,
+            encoder: &mut TickersEncoder<Ticker24hMiniResponseEncoder<'_>>,
+        ) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                self.open_price
+                    .into_iter()
+                    .chain(self.high_price)
+                    .chain(self.low_price)
+                    .chain(self.last_price)
+                    .chain(self.quote_volume),
+            ));
+            encoder.qty_exponent(fit_exponent(self.qty_exponent, self.volume));
+            encoder.open_price_decimal(self.open_price)?;
+            encoder.high_price_decimal(self.high_price)?;
+            encoder.low_price_decimal(self.low_price)?;
+            encoder.last_price_decimal(self.last_price)?;
+            encoder.volume_decimal(self.volume)?;
+            encoder.quote_volume_decimal(self.quote_volume)?;
+            encoder.open_time(self.open_time);
+            encoder.close_time(self.close_time);
+            encoder.first_id(self.first_id.unwrap_or(i64::MIN));
+            encoder.last_id(self.last_id.unwrap_or(i64::MIN));
+            encoder.num_trades(self.num_trades);
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            Ok(())
+        }
+
+        /// Length of the entry including its groups and var data
+        pub fn encoded_length(&self) -> usize {
+            107 + self.symbol.len()
+        }
+    }
+} // end owned
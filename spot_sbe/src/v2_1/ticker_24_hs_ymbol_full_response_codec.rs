@@ -0,0 +1,1177 @@
+use super::*;
+
+pub use decoder::Ticker24hSymbolFullResponseDecoder;
+pub use encoder::Ticker24hSymbolFullResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::Ticker24hSymbolFullResponse;
+
+pub const SBE_BLOCK_LENGTH: u16 = 182;
+pub const SBE_TEMPLATE_ID: u16 = 205;
+pub const SBE_SCHEMA_ID: u16 = 2;
+pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SEMANTIC_VERSION: &str = "5.2";
+
+pub mod encoder {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub struct Ticker24hSymbolFullResponseEncoder<'a> {
+        buf: WriteBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+    }
+
+    impl<'a> Writer<'a> for Ticker24hSymbolFullResponseEncoder<'a> {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            &mut self.buf
+        }
+    }
+
+    impl<'a> Encoder<'a> for Ticker24hSymbolFullResponseEncoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+    }
+
+    impl<'a> Ticker24hSymbolFullResponseEncoder<'a> {
+        pub fn wrap(mut self, buf: WriteBuf<'a>, offset: usize) -> Self {
+            let limit = offset + SBE_BLOCK_LENGTH as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self
+        }
+
+        #[inline]
+        pub fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        pub fn header(self, offset: usize) -> MessageHeaderEncoder<Self> {
+            let mut header = MessageHeaderEncoder::default().wrap(self, offset);
+            header.block_length(SBE_BLOCK_LENGTH);
+            header.template_id(SBE_TEMPLATE_ID);
+            header.schema_id(SBE_SCHEMA_ID);
+            header.version(SBE_SCHEMA_VERSION);
+            header
+        }
+
+        /// primitive field 'priceExponent'
+        /// - min value: -127
+        /// - max value: 127
+        /// - null value: -128
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 0
+        /// - encodedLength: 1
+        #[inline]
+        pub fn price_exponent(&mut self, value: i8) {
+            let offset = self.offset;
+            self.get_buf_mut().put_i8_at(offset, value);
+        }
+
+        /// primitive field 'qtyExponent'
+        /// - min value: -127
+        /// - max value: 127
+        /// - null value: -128
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 1
+        /// - encodedLength: 1
+        #[inline]
+        pub fn qty_exponent(&mut self, value: i8) {
+            let offset = self.offset + 1;
+            self.get_buf_mut().put_i8_at(offset, value);
+        }
+
+        /// primitive field 'priceChange'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 2
+        /// - encodedLength: 8
+        #[inline]
+        pub fn price_change(&mut self, value: i64) {
+            let offset = self.offset + 2;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'priceChange' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn price_change_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.price_change(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.price_change(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'priceChangePercent'
+        /// - min value: 1.401298464324817E-45
+        /// - max value: 3.4028234663852886E38
+        /// - null value: NaN
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 10
+        /// - encodedLength: 4
+        #[inline]
+        pub fn price_change_percent(&mut self, value: f32) {
+            let offset = self.offset + 10;
+            self.get_buf_mut().put_f32_at(offset, value);
+        }
+
+        /// primitive field 'weightedAvgPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 14
+        /// - encodedLength: 8
+        #[inline]
+        pub fn weighted_avg_price(&mut self, value: i64) {
+            let offset = self.offset + 14;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'weightedAvgPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn weighted_avg_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.weighted_avg_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.weighted_avg_price(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'prevClosePrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 22
+        /// - encodedLength: 8
+        #[inline]
+        pub fn prev_close_price(&mut self, value: i64) {
+            let offset = self.offset + 22;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'prevClosePrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn prev_close_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.prev_close_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.prev_close_price(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'lastPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 30
+        /// - encodedLength: 8
+        #[inline]
+        pub fn last_price(&mut self, value: i64) {
+            let offset = self.offset + 30;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'lastPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn last_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.last_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.last_price(mantissa);
+            Ok(())
+        }
+
+        /// mantissa128 field 'lastQty' as a little-endian i128
+        /// - null value: -170141183460469231731687303715884105728
+        /// - encodedOffset: 38
+        /// - encodedLength: 16
+        /// - version: 0
+        #[inline]
+        pub fn last_qty(&mut self, value: Option<i128>) {
+            let offset = self.offset + 38;
+            self.get_buf_mut()
+                .put_i128_at(offset, value.unwrap_or(i128::MIN));
+        }
+
+        /// 'lastQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn last_qty_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.last_qty(None);
+                return Ok(());
+            };
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .map(|value| value.mantissa())
+                .filter(|&mantissa| mantissa != i128::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.last_qty(Some(mantissa));
+            Ok(())
+        }
+
+        /// primitive field 'bidPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 54
+        /// - encodedLength: 8
+        #[inline]
+        pub fn bid_price(&mut self, value: i64) {
+            let offset = self.offset + 54;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'bidPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn bid_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.bid_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.bid_price(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'bidQty'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 62
+        /// - encodedLength: 8
+        #[inline]
+        pub fn bid_qty(&mut self, value: i64) {
+            let offset = self.offset + 62;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'bidQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn bid_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.bid_qty(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'askPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 70
+        /// - encodedLength: 8
+        #[inline]
+        pub fn ask_price(&mut self, value: i64) {
+            let offset = self.offset + 70;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'askPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn ask_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.ask_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.ask_price(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'askQty'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 78
+        /// - encodedLength: 8
+        #[inline]
+        pub fn ask_qty(&mut self, value: i64) {
+            let offset = self.offset + 78;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'askQty' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn ask_qty_decimal(&mut self, value: Decimal) -> SbeResult<()> {
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.ask_qty(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'openPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 86
+        /// - encodedLength: 8
+        #[inline]
+        pub fn open_price(&mut self, value: i64) {
+            let offset = self.offset + 86;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'openPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn open_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.open_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.open_price(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'highPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 94
+        /// - encodedLength: 8
+        #[inline]
+        pub fn high_price(&mut self, value: i64) {
+            let offset = self.offset + 94;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'highPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn high_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.high_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.high_price(mantissa);
+            Ok(())
+        }
+
+        /// primitive field 'lowPrice'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 102
+        /// - encodedLength: 8
+        #[inline]
+        pub fn low_price(&mut self, value: i64) {
+            let offset = self.offset + 102;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// 'lowPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn low_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.low_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.low_price(mantissa);
+            Ok(())
+        }
+
+        /// mantissa128 field 'volume' as a little-endian i128
+        /// - null value: -170141183460469231731687303715884105728
+        /// - encodedOffset: 110
+        /// - encodedLength: 16
+        /// - version: 0
+        #[inline]
+        pub fn volume(&mut self, value: Option<i128>) {
+            let offset = self.offset + 110;
+            self.get_buf_mut()
+                .put_i128_at(offset, value.unwrap_or(i128::MIN));
+        }
+
+        /// 'volume' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn volume_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.volume(None);
+                return Ok(());
+            };
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .map(|value| value.mantissa())
+                .filter(|&mantissa| mantissa != i128::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.volume(Some(mantissa));
+            Ok(())
+        }
+
+        /// mantissa128 field 'quoteVolume' as a little-endian i128
+        /// - null value: -170141183460469231731687303715884105728
+        /// - encodedOffset: 126
+        /// - encodedLength: 16
+        /// - version: 0
+        #[inline]
+        pub fn quote_volume(&mut self, value: Option<i128>) {
+            let offset = self.offset + 126;
+            self.get_buf_mut()
+                .put_i128_at(offset, value.unwrap_or(i128::MIN));
+        }
+
+        /// 'quoteVolume' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn quote_volume_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.quote_volume(None);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .map(|value| value.mantissa())
+                .filter(|&mantissa| mantissa != i128::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.quote_volume(Some(mantissa));
+            Ok(())
+        }
+
+        /// primitive field 'openTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 142
+        /// - encodedLength: 8
+        #[inline]
+        pub fn open_time(&mut self, value: i64) {
+            let offset = self.offset + 142;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'closeTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 150
+        /// - encodedLength: 8
+        #[inline]
+        pub fn close_time(&mut self, value: i64) {
+            let offset = self.offset + 150;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'firstId'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 158
+        /// - encodedLength: 8
+        #[inline]
+        pub fn first_id(&mut self, value: i64) {
+            let offset = self.offset + 158;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'lastId'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 166
+        /// - encodedLength: 8
+        #[inline]
+        pub fn last_id(&mut self, value: i64) {
+            let offset = self.offset + 166;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'numTrades'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 174
+        /// - encodedLength: 8
+        #[inline]
+        pub fn num_trades(&mut self, value: i64) {
+            let offset = self.offset + 174;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// VAR_DATA ENCODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn symbol(&mut self, value: &str) {
+            let limit = self.get_limit();
+            let data_length = value.len();
+            self.set_limit(limit + 1 + data_length);
+            self.get_buf_mut().put_u8_at(limit, data_length as u8);
+            self.get_buf_mut().put_slice_at(limit + 1, value.as_bytes());
+        }
+    }
+} // end encoder
+
+pub mod decoder {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Ticker24hSymbolFullResponseDecoder<'a> {
+        buf: ReadBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+        pub acting_block_length: u16,
+        pub acting_version: u16,
+    }
+
+    impl<'a> Reader<'a> for Ticker24hSymbolFullResponseDecoder<'a> {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            &self.buf
+        }
+    }
+
+    impl<'a> Decoder<'a> for Ticker24hSymbolFullResponseDecoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
+    }
+
+    impl<'a> Ticker24hSymbolFullResponseDecoder<'a> {
+        pub fn wrap(
+            mut self,
+            buf: ReadBuf<'a>,
+            offset: usize,
+            acting_block_length: u16,
+            acting_version: u16,
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
+            let limit = offset + acting_block_length as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self.acting_block_length = acting_block_length;
+            self.acting_version = acting_version;
+            Ok(self)
+        }
+
+        #[inline]
+        pub fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
+            let acting_block_length = header.block_length();
+            let acting_version = header.version();
+
+            self.wrap(
+                header.parent()?,
+                message_header_codec::ENCODED_LENGTH,
+                acting_block_length,
+                acting_version,
+            )
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn price_exponent(&self) -> i8 {
+            self.get_buf().get_i8_at(self.offset)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn qty_exponent(&self) -> i8 {
+            self.get_buf().get_i8_at(self.offset + 1)
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn price_change(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 2);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'priceChange' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn price_change_decimal(&self) -> Option<Decimal> {
+            self.price_change()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: 'NaN' }
+        #[inline]
+        pub fn price_change_percent(&self) -> Option<f32> {
+            let value = self.get_buf().get_f32_at(self.offset + 10);
+            if value.is_nan() {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn weighted_avg_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 14);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'weightedAvgPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn weighted_avg_price_decimal(&self) -> Option<Decimal> {
+            self.weighted_avg_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn prev_close_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 22);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'prevClosePrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn prev_close_price_decimal(&self) -> Option<Decimal> {
+            self.prev_close_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn last_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 30);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'lastPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn last_price_decimal(&self) -> Option<Decimal> {
+            self.last_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// mantissa128 field - little-endian i128 { null_value: '-170141183460469231731687303715884105728' }
+        #[inline]
+        pub fn last_qty(&self) -> Option<i128> {
+            let value = self.get_buf().get_i128_at(self.offset + 38);
+            if value == -170141183460469231731687303715884105728_i128 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'lastQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn last_qty_decimal(&self) -> Option<Decimal> {
+            self.last_qty()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn bid_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 54);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'bidPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn bid_price_decimal(&self) -> Option<Decimal> {
+            self.bid_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn bid_qty(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 62)
+        }
+
+        /// 'bidQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn bid_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.bid_qty(), self.qty_exponent())
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn ask_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 70);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'askPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn ask_price_decimal(&self) -> Option<Decimal> {
+            self.ask_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn ask_qty(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 78)
+        }
+
+        /// 'askQty' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn ask_qty_decimal(&self) -> Decimal {
+            Decimal::new(self.ask_qty(), self.qty_exponent())
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn open_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 86);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'openPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn open_price_decimal(&self) -> Option<Decimal> {
+            self.open_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn high_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 94);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'highPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn high_price_decimal(&self) -> Option<Decimal> {
+            self.high_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn low_price(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 102);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'lowPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn low_price_decimal(&self) -> Option<Decimal> {
+            self.low_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// mantissa128 field - little-endian i128 { null_value: '-170141183460469231731687303715884105728' }
+        #[inline]
+        pub fn volume(&self) -> Option<i128> {
+            let value = self.get_buf().get_i128_at(self.offset + 110);
+            if value == -170141183460469231731687303715884105728_i128 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'volume' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn volume_decimal(&self) -> Option<Decimal> {
+            self.volume()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
+        /// mantissa128 field - little-endian i128 { null_value: '-170141183460469231731687303715884105728' }
+        #[inline]
+        pub fn quote_volume(&self) -> Option<i128> {
+            let value = self.get_buf().get_i128_at(self.offset + 126);
+            if value == -170141183460469231731687303715884105728_i128 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'quoteVolume' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn quote_volume_decimal(&self) -> Option<Decimal> {
+            self.quote_volume()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn open_time(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 142)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn close_time(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 150)
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn first_id(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 158);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
+        #[inline]
+        pub fn last_id(&self) -> Option<i64> {
+            let value = self.get_buf().get_i64_at(self.offset + 166);
+            if value == -9223372036854775808_i64 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn num_trades(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 174)
+        }
+
+        /// VAR_DATA DECODER - character encoding: 'UTF-8'
+        #[inline]
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
+            let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
+            let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
+            self.set_limit(offset + 1 + data_length);
+            Ok((offset + 1, data_length))
+        }
+
+        #[inline]
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
+        }
+    }
+} // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Ticker24hSymbolFullResponse {
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub price_change: Option<Decimal>,
+        pub price_change_percent: Option<f32>,
+        pub weighted_avg_price: Option<Decimal>,
+        pub prev_close_price: Option<Decimal>,
+        pub last_price: Option<Decimal>,
+        pub last_qty: Option<Decimal>,
+        pub bid_price: Option<Decimal>,
+        pub bid_qty: Decimal,
+        pub ask_price: Option<Decimal>,
+        pub ask_qty: Decimal,
+        pub open_price: Option<Decimal>,
+        pub high_price: Option<Decimal>,
+        pub low_price: Option<Decimal>,
+        pub volume: Option<Decimal>,
+        pub quote_volume: Option<Decimal>,
+        pub open_time: i64,
+        pub close_time: i64,
+        pub first_id: Option<i64>,
+        pub last_id: Option<i64>,
+        pub num_trades: i64,
+        pub symbol: String,
+    }
+
+    impl Ticker24hSymbolFullResponse {
+        pub fn from_decoder(
+            decoder: &mut Ticker24hSymbolFullResponseDecoder<'_>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                price_change: decoder.price_change_decimal(),
+                price_change_percent: decoder.price_change_percent(),
+                weighted_avg_price: decoder.weighted_avg_price_decimal(),
+                prev_close_price: decoder.prev_close_price_decimal(),
+                last_price: decoder.last_price_decimal(),
+                last_qty: decoder.last_qty_decimal(),
+                bid_price: decoder.bid_price_decimal(),
+                bid_qty: decoder.bid_qty_decimal(),
+                ask_price: decoder.ask_price_decimal(),
+                ask_qty: decoder.ask_qty_decimal(),
+                open_price: decoder.open_price_decimal(),
+                high_price: decoder.high_price_decimal(),
+                low_price: decoder.low_price_decimal(),
+                volume: decoder.volume_decimal(),
+                quote_volume: decoder.quote_volume_decimal(),
+                open_time: decoder.open_time(),
+                close_time: decoder.close_time(),
+                first_id: decoder.first_id(),
+                last_id: decoder.last_id(),
+                num_trades: decoder.num_trades(),
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        pub fn to_encoder(
+            &self,
+            encoder: &mut Ticker24hSymbolFullResponseEncoder<'_>,
+        ) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                self.price_change
+                    .into_iter()
+                    .chain(self.weighted_avg_price)
+                    .chain(self.prev_close_price)
+                    .chain(self.last_price)
+                    .chain(self.bid_price)
+                    .chain(self.ask_price)
+                    .chain(self.open_price)
+                    .chain(self.high_price)
+                    .chain(self.low_price)
+                    .chain(self.quote_volume),
+            ));
+            encoder.qty_exponent(fit_exponent(
+                self.qty_exponent,
+                [self.bid_qty, self.ask_qty]
+                    .into_iter()
+                    .chain(self.last_qty)
+                    .chain(self.volume),
+            ));
+            encoder.price_change_decimal(self.price_change)?;
+            encoder.price_change_percent(self.price_change_percent.unwrap_or(f32::NAN));
+            encoder.weighted_avg_price_decimal(self.weighted_avg_price)?;
+            encoder.prev_close_price_decimal(self.prev_close_price)?;
+            encoder.last_price_decimal(self.last_price)?;
+            encoder.last_qty_decimal(self.last_qty)?;
+            encoder.bid_price_decimal(self.bid_price)?;
+            encoder.bid_qty_decimal(self.bid_qty)?;
+            encoder.ask_price_decimal(self.ask_price)?;
+            encoder.ask_qty_decimal(self.ask_qty)?;
+            encoder.open_price_decimal(self.open_price)?;
+            encoder.high_price_decimal(self.high_price)?;
+            encoder.low_price_decimal(self.low_price)?;
+            encoder.volume_decimal(self.volume)?;
+            encoder.quote_volume_decimal(self.quote_volume)?;
+            encoder.open_time(self.open_time);
+            encoder.close_time(self.close_time);
+            encoder.first_id(self.first_id.unwrap_or(i64::MIN));
+            encoder.last_id(self.last_id.unwrap_or(i64::MIN));
+            encoder.num_trades(self.num_trades);
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            183 + self.symbol.len()
+        }
+    }
+
+    impl OwnedMessage for Ticker24hSymbolFullResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = Ticker24hSymbolFullResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = Ticker24hSymbolFullResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::Ticker24hSymbolMiniResponseDecoder;
 pub use encoder::Ticker24hSymbolMiniResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::Ticker24hSymbolMiniResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 106;
 pub const SBE_TEMPLATE_ID: u16 = 207;
@@ -105,6 +107,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'openPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn open_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.open_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.open_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'highPrice'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -119,6 +139,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'highPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn high_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.high_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.high_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'lowPrice'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -133,6 +171,24 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
+        /// 'lowPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn low_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.low_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.low_price(mantissa);
+            Ok(())
+        }
+
         /// primitive field 'lastPrice'
         /// - min value: -9223372036854775807
         /// - max value: 9223372036854775807
@@ -147,36 +203,82 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
-        /// primitive array field 'volume'
-        /// - min value: 0
-        /// - max value: 254
-        /// - null value: 255
-        /// - characterEncoding: null
-        /// - semanticType: null
+        /// 'lastPrice' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn last_price_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.last_price(i64::MIN);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .and_then(|value| i64::try_from(value.mantissa()).ok())
+                .filter(|&mantissa| mantissa != i64::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.last_price(mantissa);
+            Ok(())
+        }
+
+        /// mantissa128 field 'volume' as a little-endian i128
+        /// - null value: -170141183460469231731687303715884105728
         /// - encodedOffset: 34
         /// - encodedLength: 16
         /// - version: 0
         #[inline]
-        pub fn volume(&mut self, value: [u8; 16]) {
+        pub fn volume(&mut self, value: Option<i128>) {
             let offset = self.offset + 34;
-            let buf = self.get_buf_mut();
-            buf.put_bytes_at(offset, value);
+            self.get_buf_mut()
+                .put_i128_at(offset, value.unwrap_or(i128::MIN));
         }
 
-        /// primitive array field 'quoteVolume'
-        /// - min value: 0
-        /// - max value: 254
-        /// - null value: 255
-        /// - characterEncoding: null
-        /// - semanticType: null
+        /// 'volume' rescaled to the 'qtyExponent' already written to this block
+        #[inline]
+        pub fn volume_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.volume(None);
+                return Ok(());
+            };
+            let offset = self.offset + 1;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .map(|value| value.mantissa())
+                .filter(|&mantissa| mantissa != i128::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.volume(Some(mantissa));
+            Ok(())
+        }
+
+        /// mantissa128 field 'quoteVolume' as a little-endian i128
+        /// - null value: -170141183460469231731687303715884105728
         /// - encodedOffset: 50
         /// - encodedLength: 16
         /// - version: 0
         #[inline]
-        pub fn quote_volume(&mut self, value: [u8; 16]) {
+        pub fn quote_volume(&mut self, value: Option<i128>) {
             let offset = self.offset + 50;
-            let buf = self.get_buf_mut();
-            buf.put_bytes_at(offset, value);
+            self.get_buf_mut()
+                .put_i128_at(offset, value.unwrap_or(i128::MIN));
+        }
+
+        /// 'quoteVolume' rescaled to the 'priceExponent' already written to this block
+        #[inline]
+        pub fn quote_volume_decimal(&mut self, value: Option<Decimal>) -> SbeResult<()> {
+            let Some(value) = value else {
+                self.quote_volume(None);
+                return Ok(());
+            };
+            let offset = self.offset;
+            let exponent = self.get_buf_mut().get_i8_at(offset);
+            let mantissa = value
+                .rescale(exponent)
+                .map(|value| value.mantissa())
+                .filter(|&mantissa| mantissa != i128::MIN)
+                .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;
+            self.quote_volume(Some(mantissa));
+            Ok(())
         }
 
         /// primitive field 'openTime'
@@ -291,6 +393,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> Ticker24hSymbolMiniResponseDecoder<'a> {
@@ -300,7 +407,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -308,7 +416,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -316,13 +424,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -352,6 +466,13 @@ pub mod decoder {
             }
         }
 
+        /// 'openPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn open_price_decimal(&self) -> Option<Decimal> {
+            self.open_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn high_price(&self) -> Option<i64> {
@@ -363,6 +484,13 @@ pub mod decoder {
             }
         }
 
+        /// 'highPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn high_price_decimal(&self) -> Option<Decimal> {
+            self.high_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn low_price(&self) -> Option<i64> {
@@ -374,6 +502,13 @@ pub mod decoder {
             }
         }
 
+        /// 'lowPrice' combined with its 'priceExponent' exponent field
+        #[inline]
+        pub fn low_price_decimal(&self) -> Option<Decimal> {
+            self.low_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
         /// primitive field - 'OPTIONAL' { null_value: '-9223372036854775808' }
         #[inline]
         pub fn last_price(&self) -> Option<i64> {
@@ -385,16 +520,47 @@ pub mod decoder {
             }
         }
 
+        /// 'lastPrice' combined with its 'priceExponent' exponent field
         #[inline]
-        pub fn volume(&self) -> [u8; 16] {
-            let buf = self.get_buf();
-            ReadBuf::get_bytes_at(buf.data, self.offset + 34)
+        pub fn last_price_decimal(&self) -> Option<Decimal> {
+            self.last_price()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
+        }
+
+        /// mantissa128 field - little-endian i128 { null_value: '-170141183460469231731687303715884105728' }
+        #[inline]
+        pub fn volume(&self) -> Option<i128> {
+            let value = self.get_buf().get_i128_at(self.offset + 34);
+            if value == -170141183460469231731687303715884105728_i128 {
+                None
+            } else {
+                Some(value)
+            }
+        }
+
+        /// 'volume' combined with its 'qtyExponent' exponent field
+        #[inline]
+        pub fn volume_decimal(&self) -> Option<Decimal> {
+            self.volume()
+                .map(|mantissa| Decimal::new(mantissa, self.qty_exponent()))
+        }
+
+        /// mantissa128 field - little-endian i128 { null_value: '-170141183460469231731687303715884105728' }
+        #[inline]
+        pub fn quote_volume(&self) -> Option<i128> {
+            let value = self.get_buf().get_i128_at(self.offset + 50);
+            if value == -170141183460469231731687303715884105728_i128 {
+                None
+            } else {
+                Some(value)
+            }
         }
 
+        /// 'quoteVolume' combined with its 'priceExponent' exponent field
         #[inline]
-        pub fn quote_volume(&self) -> [u8; 16] {
-            let buf = self.get_buf();
-            ReadBuf::get_bytes_at(buf.data, self.offset + 50)
+        pub fn quote_volume_decimal(&self) -> Option<Decimal> {
+            self.quote_volume()
+                .map(|mantissa| Decimal::new(mantissa, self.price_exponent()))
         }
 
         /// primitive field - 'REQUIRED'
@@ -439,17 +605,132 @@ pub mod decoder {
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn symbol_decoder(&mut self) -> (usize, usize) {
+        pub fn symbol_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 1)?;
             let data_length = self.get_buf().get_u8_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 1, data_length)?;
             self.set_limit(offset + 1 + data_length);
-            (offset + 1, data_length)
+            Ok((offset + 1, data_length))
         }
 
         #[inline]
-        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn symbol_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Ticker24hSymbolMiniResponse {
+        pub price_exponent: i8,
+        pub qty_exponent: i8,
+        pub open_price: Option<Decimal>,
+        pub high_price: Option<Decimal>,
+        pub low_price: Option<Decimal>,
+        pub last_price: Option<Decimal>,
+        pub volume: Option<Decimal>,
+        pub quote_volume: Option<Decimal>,
+        pub open_time: i64,
+        pub close_time: i64,
+        pub first_id: Option<i64>,
+        pub last_id: Option<i64>,
+        pub num_trades: i64,
+        pub symbol: String,
+    }
+
+    impl Ticker24hSymbolMiniResponse {
+        pub fn from_decoder(
+            decoder: &mut Ticker24hSymbolMiniResponseDecoder<'_>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                price_exponent: decoder.price_exponent(),
+                qty_exponent: decoder.qty_exponent(),
+                open_price: decoder.open_price_decimal(),
+                high_price: decoder.high_price_decimal(),
+                low_price: decoder.low_price_decimal(),
+                last_price: decoder.last_price_decimal(),
+                volume: decoder.volume_decimal(),
+                quote_volume: decoder.quote_volume_decimal(),
+                open_time: decoder.open_time(),
+                close_time: decoder.close_time(),
+                first_id: decoder.first_id(),
+                last_id: decoder.last_id(),
+                num_trades: decoder.num_trades(),
+                symbol: {
+                    let coordinates = decoder.symbol_decoder()?;
+                    let slice = decoder.symbol_slice(coordinates)?;
+                    core::str::from_utf8(slice)
+                        .map_err(|_| SbeErr::InvalidUtf8)?
+                        .to_owned()
+                },
+            })
+        }
+
+        pub fn to_encoder(
+            &self,
+            encoder: &mut Ticker24hSymbolMiniResponseEncoder<'_>,
+        ) -> SbeResult<()> {
+            encoder.price_exponent(fit_exponent(
+                self.price_exponent,
+                self.open_price
+                    .into_iter()
+                    .chain(self.high_price)
+                    .chain(self.low_price)
+                    .chain(self.last_price)
+                    .chain(self.quote_volume),
+            ));
+            encoder.qty_exponent(fit_exponent(self.qty_exponent, self.volume));
+            encoder.open_price_decimal(self.open_price)?;
+            encoder.high_price_decimal(self.high_price)?;
+            encoder.low_price_decimal(self.low_price)?;
+            encoder.last_price_decimal(self.last_price)?;
+            encoder.volume_decimal(self.volume)?;
+            encoder.quote_volume_decimal(self.quote_volume)?;
+            encoder.open_time(self.open_time);
+            encoder.close_time(self.close_time);
+            encoder.first_id(self.first_id.unwrap_or(i64::MIN));
+            encoder.last_id(self.last_id.unwrap_or(i64::MIN));
+            encoder.num_trades(self.num_trades);
+            let value = &self.symbol;
+            checked_length::<u8>(value.len())?;
+            encoder.symbol(value);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            107 + self.symbol.len()
+        }
+    }
+
+    impl OwnedMessage for Ticker24hSymbolMiniResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = Ticker24hSymbolMiniResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = Ticker24hSymbolMiniResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
@@ -1,7 +1,9 @@
-use crate::*;
+use super::*;
 
 pub use decoder::WebSocketSessionLogoutResponseDecoder;
 pub use encoder::WebSocketSessionLogoutResponseEncoder;
+#[cfg(feature = "serde")]
+pub use owned::WebSocketSessionLogoutResponse;
 
 pub const SBE_BLOCK_LENGTH: u16 = 26;
 pub const SBE_TEMPLATE_ID: u16 = 53;
@@ -161,6 +163,11 @@ pub mod decoder {
         fn set_limit(&mut self, limit: usize) {
             self.limit = limit;
         }
+
+        #[inline]
+        fn acting_version(&self) -> u16 {
+            self.acting_version
+        }
     }
 
     impl<'a> WebSocketSessionLogoutResponseDecoder<'a> {
@@ -170,7 +177,8 @@ pub mod decoder {
             offset: usize,
             acting_block_length: u16,
             acting_version: u16,
-        ) -> Self {
+        ) -> SbeResult<Self> {
+            buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;
             let limit = offset + acting_block_length as usize;
             self.buf = buf;
             self.initial_offset = offset;
@@ -178,7 +186,7 @@ pub mod decoder {
             self.limit = limit;
             self.acting_block_length = acting_block_length;
             self.acting_version = acting_version;
-            self
+            Ok(self)
         }
 
         #[inline]
@@ -186,13 +194,19 @@ pub mod decoder {
             self.limit - self.offset
         }
 
-        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
-            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+            let template_id = header.template_id();
+            if template_id != SBE_TEMPLATE_ID {
+                return Err(SbeErr::UnexpectedTemplateId {
+                    expected: SBE_TEMPLATE_ID,
+                    actual: template_id,
+                });
+            }
             let acting_block_length = header.block_length();
             let acting_version = header.version();
 
             self.wrap(
-                header.parent().unwrap(),
+                header.parent()?,
                 message_header_codec::ENCODED_LENGTH,
                 acting_block_length,
                 acting_version,
@@ -240,17 +254,106 @@ pub mod decoder {
 
         /// VAR_DATA DECODER - character encoding: 'UTF-8'
         #[inline]
-        pub fn api_key_decoder(&mut self) -> (usize, usize) {
+        pub fn api_key_decoder(&mut self) -> SbeResult<(usize, usize)> {
             let offset = self.get_limit();
+            self.get_buf().check_bounds(offset, 2)?;
             let data_length = self.get_buf().get_u16_at(offset) as usize;
+            self.get_buf().check_bounds(offset + 2, data_length)?;
             self.set_limit(offset + 2 + data_length);
-            (offset + 2, data_length)
+            Ok((offset + 2, data_length))
         }
 
         #[inline]
-        pub fn api_key_slice(&'a self, coordinates: (usize, usize)) -> &'a [u8] {
-            debug_assert!(self.get_limit() >= coordinates.0 + coordinates.1);
-            self.get_buf().get_slice_at(coordinates.0, coordinates.1)
+        pub fn api_key_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {
+            self.get_buf().check_bounds(coordinates.0, coordinates.1)?;
+            Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))
         }
     }
 } // end decoder
+
+#[cfg(feature = "serde")]
+pub mod owned {
+    use super::decoder::*;
+    use super::encoder::*;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct WebSocketSessionLogoutResponse {
+        pub authorized_since: Option<i64>,
+        pub connected_since: i64,
+        pub return_rate_limits: BoolEnum,
+        pub server_time: i64,
+        pub user_data_stream: BoolEnum,
+        pub api_key: Option<String>,
+    }
+
+    impl WebSocketSessionLogoutResponse {
+        pub fn from_decoder(
+            decoder: &mut WebSocketSessionLogoutResponseDecoder<'_>,
+        ) -> SbeResult<Self> {
+            Ok(Self {
+                authorized_since: decoder.authorized_since(),
+                connected_since: decoder.connected_since(),
+                return_rate_limits: decoder.return_rate_limits(),
+                server_time: decoder.server_time(),
+                user_data_stream: decoder.user_data_stream(),
+                api_key: {
+                    let coordinates = decoder.api_key_decoder()?;
+                    let slice = decoder.api_key_slice(coordinates)?;
+                    if slice.is_empty() {
+                        None
+                    } else {
+                        Some(
+                            core::str::from_utf8(slice)
+                                .map_err(|_| SbeErr::InvalidUtf8)?
+                                .to_owned(),
+                        )
+                    }
+                },
+            })
+        }
+
+        pub fn to_encoder(
+            &self,
+            encoder: &mut WebSocketSessionLogoutResponseEncoder<'_>,
+        ) -> SbeResult<()> {
+            encoder.authorized_since(self.authorized_since.unwrap_or(i64::MIN));
+            encoder.connected_since(self.connected_since);
+            encoder.return_rate_limits(self.return_rate_limits);
+            encoder.server_time(self.server_time);
+            encoder.user_data_stream(self.user_data_stream);
+            let value = self.api_key.as_deref().unwrap_or_default();
+            checked_length::<u16>(value.len())?;
+            encoder.api_key(value);
+            Ok(())
+        }
+
+        /// Length of the message excluding its header
+        pub fn encoded_length(&self) -> usize {
+            28 + self.api_key.as_ref().map_or(0, |value| value.len())
+        }
+    }
+
+    impl OwnedMessage for WebSocketSessionLogoutResponse {
+        const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;
+
+        fn decode(data: &[u8]) -> SbeResult<Self> {
+            let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+            let mut decoder = WebSocketSessionLogoutResponseDecoder::default().header(header)?;
+            Self::from_decoder(&mut decoder)
+        }
+
+        fn encode(&self) -> SbeResult<Vec<u8>> {
+            let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];
+            let encoder = WebSocketSessionLogoutResponseEncoder::default().wrap(
+                WriteBuf::new(&mut data),
+                message_header_codec::ENCODED_LENGTH,
+            );
+            let mut encoder = encoder.header(0).parent()?;
+            self.to_encoder(&mut encoder)?;
+            Ok(data)
+        }
+    }
+} // end owned
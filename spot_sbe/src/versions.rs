@@ -0,0 +1,148 @@
+//! The schema versions compiled into the crate, and dispatch on the
+//! `schemaId` and `version` of a message header.
+//!
+//! Each schema is generated into its own module, e.g. [`crate::v2_1`] from
+//! `spot_2_1.xml`, with its own codecs and [`meta`](crate::meta) tables. The
+//! newest one is also re-exported at the crate root. [`SCHEMAS`] lists them
+//! all, so that a service can decode both sides of a schema rollover with
+//! [`decode_versioned`] and move to the new codecs at its own pace.
+
+use crate::meta::{EnumMeta, MessageMeta, SetMeta};
+use crate::*;
+
+/// A schema compiled into the crate.
+#[derive(Debug)]
+pub struct SchemaVersion {
+    pub id: u16,
+    pub version: u16,
+    /// Path of the generated module, e.g. `"v2_1"`
+    pub module: &'static str,
+    pub messages: &'static [&'static MessageMeta],
+    pub enums: &'static [&'static EnumMeta],
+    pub sets: &'static [&'static SetMeta],
+    from_header: for<'a> fn(MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<VersionedMessage<'a>>,
+}
+
+impl SchemaVersion {
+    #[inline]
+    pub fn message_by_id(&self, template_id: u16) -> Option<&'static MessageMeta> {
+        self.messages.iter().copied().find(|m| m.id == template_id)
+    }
+
+    #[inline]
+    pub fn message_by_name(&self, name: &str) -> Option<&'static MessageMeta> {
+        self.messages.iter().copied().find(|m| m.name == name)
+    }
+}
+
+pub static SCHEMA_2_1: SchemaVersion = SchemaVersion {
+    id: v2_1::meta_tables::SCHEMA_ID,
+    version: v2_1::meta_tables::SCHEMA_VERSION,
+    module: "v2_1",
+    messages: v2_1::meta_tables::MESSAGES,
+    enums: v2_1::meta_tables::ENUMS,
+    sets: v2_1::meta_tables::SETS,
+    from_header: |header| v2_1::AnyMessage::from_header(header).map(VersionedMessage::V2_1),
+};
+
+/// Every schema compiled into the crate, oldest first.
+pub static SCHEMAS: &[&SchemaVersion] = &[&SCHEMA_2_1];
+
+/// The schema to decode a message with this `schemaId` and `version`: the
+/// exact version when it is compiled in, otherwise the newest one with the
+/// same ID. Versions of one schema ID only ever add members, so older
+/// messages decode with their `acting_version` and newer ones by skipping
+/// what the codecs do not know about.
+pub fn schema_for(schema_id: u16, version: u16) -> SbeResult<&'static SchemaVersion> {
+    let same_id = || SCHEMAS.iter().copied().filter(move |s| s.id == schema_id);
+    same_id()
+        .find(|s| s.version == version)
+        .or_else(|| same_id().max_by_key(|s| s.version))
+        .ok_or(SbeErr::UnknownSchema { schema_id, version })
+}
+
+/// Decoder for any message of any schema compiled into the crate,
+/// positioned after its header.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum VersionedMessage<'a> {
+    V2_1(v2_1::AnyMessage<'a>),
+}
+
+impl<'a> VersionedMessage<'a> {
+    /// Picks the schema from the header's `schemaId` and `version` with
+    /// [`schema_for`], then wraps the decoder matching its template ID.
+    pub fn from_header(header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {
+        let schema = schema_for(header.schema_id(), header.version())?;
+        (schema.from_header)(header)
+    }
+
+    #[inline]
+    pub fn schema(&self) -> &'static SchemaVersion {
+        match self {
+            Self::V2_1(_) => &SCHEMA_2_1,
+        }
+    }
+
+    #[inline]
+    pub fn meta(&self) -> &'static MessageMeta {
+        match self {
+            Self::V2_1(message) => message.meta(),
+        }
+    }
+
+    #[inline]
+    pub fn template_id(&self) -> u16 {
+        self.meta().id
+    }
+
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.meta().name
+    }
+}
+
+/// Decodes the header at the start of `data` and wraps the matching decoder
+/// of whichever schema the header names.
+pub fn decode_versioned(data: &[u8]) -> SbeResult<VersionedMessage<'_>> {
+    let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;
+    VersionedMessage::from_header(header)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ping(schema_id: u16, version: u16) -> Vec<u8> {
+        [0, ping_response_codec::SBE_TEMPLATE_ID, schema_id, version]
+            .into_iter()
+            .flat_map(u16::to_le_bytes)
+            .collect()
+    }
+
+    #[test]
+    fn dispatches_on_schema_id_and_version() {
+        let frame = ping(2, 1);
+        let message = decode_versioned(&frame).unwrap();
+        assert!(core::ptr::eq(message.schema(), &SCHEMA_2_1));
+        assert_eq!(message.name(), "PingResponse");
+    }
+
+    #[test]
+    fn older_version_decodes_with_newest_of_same_id() {
+        let schema = schema_for(2, 0).unwrap();
+        assert_eq!((schema.id, schema.version), (2, 1));
+        assert_eq!(decode_versioned(&ping(2, 0)).unwrap().template_id(), 101);
+    }
+
+    #[test]
+    fn unknown_schema_id_is_an_error() {
+        assert_eq!(
+            decode_versioned(&ping(99, 0)).unwrap_err(),
+            SbeErr::UnknownSchema {
+                schema_id: 99,
+                version: 0
+            }
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Sends the JSON requests on stdin to the WebSocket API and writes each
+//! `WebSocketResponse` to stdout, in the order of the requests.
+//!
+//! ```shell
+//! echo '{"id":"1","method":"exchangeInfo","params":{"symbol":"BTCUSDT"}}' \
+//!     | ws-api-send wss://ws-api.binance.com:443 | sbe-sample-app
+//! ```
+//!
+//! With `--capture <file>`, every request and response is also appended to
+//! a capture file, as they were sent and received.
+
+use anyhow::{bail, Context};
+use serde_json::Value;
+use spot_sbe::capture::{now_us, CaptureWriter, Direction, Record};
+use std::collections::HashMap;
+use std::io::{self, Write};
+use ws_api_client::Client;
+
+fn main() -> anyhow::Result<()> {
+    const USAGE: &str =
+        "Usage: ws-api-send [--capture <file>] <base URL or full /ws-api/v3 URL> < requests.json";
+    let (mut capture, mut url) = (None, None);
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--capture" => {
+                let Some(path) = args.next() else {
+                    bail!("Missing value for --capture");
+                };
+                capture = Some(path);
+            }
+            _ if arg.starts_with('-') || url.is_some() => bail!(USAGE),
+            _ => url = Some(arg),
+        }
+    }
+    let Some(url) = url else {
+        bail!(USAGE);
+    };
+    let mut capture = match capture {
+        Some(path) => Some(
+            CaptureWriter::append(&path)
+                .with_context(|| format!("Failed to open {path} for appending"))?,
+        ),
+        None => None,
+    };
+    let mut client = if url.contains("/ws-api/") {
+        Client::connect_url(&url)
+    } else {
+        Client::connect(&url)
+    }
+    .with_context(|| format!("Failed to connect to {url}"))?;
+    let capturing = capture.is_some();
+    // Tells apart the connections of several runs recorded to one capture.
+    let connection_id = std::process::id();
+    let mut record = |direction, frame: &[u8]| -> io::Result<()> {
+        let Some(capture) = &mut capture else {
+            return Ok(());
+        };
+        capture.write(&Record {
+            received_at: now_us(),
+            connection_id,
+            direction,
+            frame,
+        })?;
+        capture.flush()
+    };
+
+    // Every request is sent before any response is read, so that they are
+    // all in flight at once.
+    let mut ids = Vec::new();
+    for request in serde_json::Deserializer::from_reader(io::stdin().lock()).into_iter::<Value>() {
+        let request = request.context("Failed to read a JSON request from stdin")?;
+        let text = serde_json::to_string(&request)?;
+        ids.push(client.send_request(request)?);
+        record(Direction::Outbound, text.as_bytes())?;
+    }
+    let mut stdout = io::stdout().lock();
+    if !capturing {
+        for id in ids {
+            let response = client.wait(&id)?;
+            // Note: this writes binary data to stdout.
+            stdout.write_all(response.frame())?;
+        }
+    } else {
+        // Responses are recorded as they arrive, whatever their order.
+        let mut responses = HashMap::new();
+        while responses.len() < ids.len() {
+            let response = client.next_response()?;
+            record(Direction::Inbound, response.frame())?;
+            responses.insert(response.id.clone(), response);
+        }
+        for id in ids {
+            stdout.write_all(responses[&id].frame())?;
+        }
+    }
+    stdout.flush()?;
+    client.close()?;
+    Ok(())
+}
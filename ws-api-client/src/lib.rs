@@ -0,0 +1,452 @@
+//! Blocking client for the Spot WebSocket API with `responseFormat=sbe`.
+//!
+//! Every response arrives as a `WebSocketResponse` whose `id` matches the
+//! request it answers. Any number of requests can be in flight at once:
+//! responses are read off the socket in whatever order the server sends
+//! them, and kept until [`Client::wait`] asks for their `id`.
+//!
+//! [`stand_in`] serves the same protocol on a local port, so code using the
+//! client can be exercised without reaching the exchange.
+
+use serde_json::{json, Value};
+use spot_sbe::web_socket_response_codec::owned::RateLimits;
+use spot_sbe::*;
+use std::collections::{HashSet, VecDeque};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{Message, WebSocket};
+
+pub mod stand_in;
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    WebSocket(tungstenite::Error),
+    Sbe(SbeErr),
+    Json(serde_json::Error),
+    /// The request's `result` is an `ErrorResponse`.
+    Api {
+        id: String,
+        status: u16,
+        error: ErrorResponse,
+    },
+    /// The request has no `id` this client can correlate a response with.
+    InvalidId(Value),
+    /// A request with this `id` is already in flight.
+    DuplicateId(String),
+    /// No request with this `id` is in flight or waiting to be taken.
+    UnknownId(String),
+    /// A response whose `id` matches no request in flight.
+    UnexpectedId(String),
+    /// A frame of the wrong kind: text from the server, which it only sends
+    /// when it could not answer in SBE, or binary from a client.
+    UnexpectedFrame(String),
+    /// The server closed the connection.
+    Closed,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WebSocket(err) => write!(f, "WebSocket error: {err}"),
+            Self::Sbe(err) => write!(f, "SBE error: {err}"),
+            Self::Json(err) => write!(f, "JSON error: {err}"),
+            Self::Api { id, status, error } => write!(
+                f,
+                "request {id:?} failed with status {status}: code {} {}",
+                error.code, error.msg
+            ),
+            Self::InvalidId(id) => write!(f, "request id {id} is not a string, integer or null"),
+            Self::DuplicateId(id) => write!(f, "request {id:?} is already in flight"),
+            Self::UnknownId(id) => write!(f, "no request {id:?} is in flight"),
+            Self::UnexpectedId(id) => write!(f, "response {id:?} matches no request in flight"),
+            Self::UnexpectedFrame(frame) => write!(f, "unexpected frame: {frame}"),
+            Self::Closed => write!(f, "connection closed"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<tungstenite::Error> for Error {
+    fn from(err: tungstenite::Error) -> Self {
+        match err {
+            tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed => {
+                Self::Closed
+            }
+            err => Self::WebSocket(err),
+        }
+    }
+}
+
+impl From<SbeErr> for Error {
+    fn from(err: SbeErr) -> Self {
+        Self::Sbe(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+/// URL of the WebSocket API at `base_url`, e.g.
+/// `wss://ws-api.binance.com:443`, asking for SBE responses in `schema`.
+pub fn endpoint_url(base_url: &str, schema: &SchemaVersion) -> String {
+    format!(
+        "{}/ws-api/v3?responseFormat=sbe&sbeSchemaId={}&sbeSchemaVersion={}",
+        base_url.trim_end_matches('/'),
+        schema.id,
+        schema.version
+    )
+}
+
+/// A `WebSocketResponse`, with its `result` kept undecoded until asked for.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response {
+    pub id: String,
+    pub status: u16,
+    /// The server will stop accepting the schema the connection asked for
+    pub sbe_schema_id_version_deprecated: bool,
+    pub rate_limits: Vec<RateLimits>,
+    frame: Vec<u8>,
+    /// Offset and length of `result` within `frame`
+    result: (usize, usize),
+}
+
+impl Response {
+    /// Decodes the `WebSocketResponse`, header included, in `frame`.
+    pub fn decode(frame: Vec<u8>) -> SbeResult<Self> {
+        let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(&frame), 0)?;
+        let decoder = WebSocketResponseDecoder::default().header(header)?;
+        let sbe_schema_id_version_deprecated =
+            decoder.sbe_schema_id_version_deprecated() == BoolEnum::True;
+        let status = decoder.status();
+        let mut rate_limits_decoder = decoder.rate_limits_decoder()?;
+        let mut rate_limits = Vec::new();
+        while rate_limits_decoder.advance()?.is_some() {
+            rate_limits.push(RateLimits {
+                rate_limit_type: rate_limits_decoder.rate_limit_type(),
+                interval: rate_limits_decoder.interval(),
+                interval_num: rate_limits_decoder.interval_num(),
+                rate_limit: rate_limits_decoder.rate_limit(),
+                current: rate_limits_decoder.current(),
+            });
+        }
+        let mut decoder = rate_limits_decoder.parent()?;
+        let coordinates = decoder.id_decoder()?;
+        let id = String::from_utf8(decoder.id_slice(coordinates)?.into())
+            .map_err(|_| SbeErr::InvalidUtf8)?;
+        let result = decoder.result_decoder()?;
+        Ok(Self {
+            id,
+            status,
+            sbe_schema_id_version_deprecated,
+            rate_limits,
+            frame,
+            result,
+        })
+    }
+
+    /// The whole `WebSocketResponse`, header included.
+    #[inline]
+    pub fn frame(&self) -> &[u8] {
+        &self.frame
+    }
+
+    /// The message in `result`, header included.
+    #[inline]
+    pub fn result_bytes(&self) -> &[u8] {
+        let (offset, length) = self.result;
+        &self.frame[offset..offset + length]
+    }
+
+    /// Decoder for the message in `result`, whatever its template.
+    pub fn result(&self) -> SbeResult<AnyMessage<'_>> {
+        decode_any(self.result_bytes())
+    }
+
+    pub fn is_error(&self) -> bool {
+        MessageHeaderDecoder::default()
+            .wrap(ReadBuf::new(self.result_bytes()), 0)
+            .is_ok_and(|header| header.template_id() == error_response_codec::SBE_TEMPLATE_ID)
+    }
+
+    /// The `ErrorResponse` in `result`, if it holds one.
+    pub fn error(&self) -> SbeResult<Option<ErrorResponse>> {
+        if !self.is_error() {
+            return Ok(None);
+        }
+        decode(self.result_bytes()).map(Some)
+    }
+
+    /// Decodes `result` as a `T`, e.g. `let depth: DepthResponse =
+    /// response.decode_result()?;`. An `ErrorResponse` is returned as
+    /// [`Error::Api`], and any other template as
+    /// [`SbeErr::UnexpectedTemplateId`].
+    pub fn decode_result<T: OwnedMessage>(&self) -> Result<T> {
+        if let Some(error) = self.error()? {
+            return Err(Error::Api {
+                id: self.id.clone(),
+                status: self.status,
+                error,
+            });
+        }
+        Ok(decode(self.result_bytes())?)
+    }
+}
+
+/// Connection to the WebSocket API.
+#[derive(Debug)]
+pub struct Client<S> {
+    socket: WebSocket<S>,
+    /// Last `id` assigned by [`send`](Self::send)
+    last_id: u64,
+    /// Requests sent whose response has not been read yet
+    in_flight: HashSet<String>,
+    /// Responses read but not yet taken, in the order they arrived
+    received: VecDeque<Response>,
+}
+
+impl Client<MaybeTlsStream<TcpStream>> {
+    /// Connects to the WebSocket API at `base_url`, e.g.
+    /// `wss://ws-api.binance.com:443`, with the newest schema compiled into
+    /// `spot_sbe`.
+    pub fn connect(base_url: &str) -> Result<Self> {
+        let schema = SCHEMAS[SCHEMAS.len() - 1];
+        Self::connect_with_schema(base_url, schema)
+    }
+
+    pub fn connect_with_schema(base_url: &str, schema: &SchemaVersion) -> Result<Self> {
+        Self::connect_url(&endpoint_url(base_url, schema))
+    }
+
+    /// Connects to a full endpoint URL, query string included.
+    pub fn connect_url(url: &str) -> Result<Self> {
+        let (socket, _) = tungstenite::connect(url)?;
+        Ok(Self::new(socket))
+    }
+}
+
+impl<S: Read + Write> Client<S> {
+    /// Wraps a socket that has completed its handshake.
+    pub fn new(socket: WebSocket<S>) -> Self {
+        Self {
+            socket,
+            last_id: 0,
+            in_flight: HashSet::new(),
+            received: VecDeque::new(),
+        }
+    }
+
+    /// Sends a request with an `id` assigned by the client, and returns it.
+    /// `params` is left out when it is null.
+    pub fn send(&mut self, method: &str, params: Value) -> Result<String> {
+        let id = loop {
+            self.last_id += 1;
+            let id = self.last_id.to_string();
+            if !self.in_flight.contains(&id) {
+                break id;
+            }
+        };
+        let mut request = json!({ "id": id, "method": method });
+        if !params.is_null() {
+            request["params"] = params;
+        }
+        self.send_request(request)
+    }
+
+    /// Sends a request as is, and returns the `id` its response will carry:
+    /// the request's `id` as a string, and the empty string for null.
+    pub fn send_request(&mut self, request: Value) -> Result<String> {
+        let id = match request.get("id") {
+            Some(Value::String(id)) => id.clone(),
+            Some(Value::Number(id)) if id.is_i64() || id.is_u64() => id.to_string(),
+            Some(Value::Null) => String::new(),
+            Some(id) => return Err(Error::InvalidId(id.clone())),
+            None => return Err(Error::InvalidId(Value::Null)),
+        };
+        if self.in_flight.contains(&id) || self.received.iter().any(|r| r.id == id) {
+            return Err(Error::DuplicateId(id));
+        }
+        self.socket
+            .send(Message::text(serde_json::to_string(&request)?))?;
+        self.in_flight.insert(id.clone());
+        Ok(id)
+    }
+
+    /// Reads responses until the one for `id` arrives, keeping the others
+    /// for later.
+    pub fn wait(&mut self, id: &str) -> Result<Response> {
+        loop {
+            if let Some(index) = self.received.iter().position(|r| r.id == id) {
+                return Ok(self.received.remove(index).unwrap());
+            }
+            if !self.in_flight.contains(id) {
+                return Err(Error::UnknownId(id.to_owned()));
+            }
+            let response = self.read_response()?;
+            self.received.push_back(response);
+        }
+    }
+
+    /// The oldest response not taken yet, whichever request it answers.
+    pub fn next_response(&mut self) -> Result<Response> {
+        match self.received.pop_front() {
+            Some(response) => Ok(response),
+            None => self.read_response(),
+        }
+    }
+
+    /// Sends a request and waits for its response.
+    pub fn request(&mut self, method: &str, params: Value) -> Result<Response> {
+        let id = self.send(method, params)?;
+        self.wait(&id)
+    }
+
+    /// Sends a request and decodes its `result` as a `T`.
+    pub fn call<T: OwnedMessage>(&mut self, method: &str, params: Value) -> Result<T> {
+        self.request(method, params)?.decode_result()
+    }
+
+    /// Number of requests whose response has not been read yet.
+    #[inline]
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    /// Sends a close frame and waits for the server to close the
+    /// connection. Responses not taken yet are dropped.
+    pub fn close(mut self) -> Result<()> {
+        self.socket.close(None)?;
+        loop {
+            match self.socket.read() {
+                Ok(_) => {}
+                Err(err) => match Error::from(err) {
+                    Error::Closed => return Ok(()),
+                    err => return Err(err),
+                },
+            }
+        }
+    }
+
+    fn read_response(&mut self) -> Result<Response> {
+        loop {
+            match self.socket.read()? {
+                Message::Binary(frame) => {
+                    let response = Response::decode(frame.to_vec())?;
+                    if !self.in_flight.remove(&response.id) {
+                        return Err(Error::UnexpectedId(response.id));
+                    }
+                    return Ok(response);
+                }
+                Message::Text(text) => return Err(Error::UnexpectedFrame(text.as_str().into())),
+                Message::Close(_) => return Err(Error::Closed),
+                // Pings are answered by tungstenite itself.
+                Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stand_in::{self, StandIn};
+    use std::thread::{self, JoinHandle};
+
+    /// Accepts one connection on a thread, which runs `serve` on it, and
+    /// connects a client to it.
+    fn connect<T: Send + 'static>(
+        serve: impl FnOnce(stand_in::Connection) -> T + Send + 'static,
+    ) -> (Client<MaybeTlsStream<TcpStream>>, JoinHandle<T>) {
+        let stand_in = StandIn::bind().unwrap();
+        let base_url = stand_in.base_url().unwrap();
+        let server = thread::spawn(move || serve(stand_in.accept().unwrap()));
+        (Client::connect(&base_url).unwrap(), server)
+    }
+
+    fn pong(id: &str) -> WebSocketResponse {
+        stand_in::ok(id, PingResponse::default().encode().unwrap())
+    }
+
+    #[test]
+    fn handshake_asks_for_sbe_in_the_newest_schema() {
+        let (client, server) = connect(|connection| connection.query().clone());
+        drop(client);
+        let query = server.join().unwrap();
+        let schema = SCHEMAS[SCHEMAS.len() - 1];
+        assert_eq!(query["responseFormat"], "sbe");
+        assert_eq!(query["sbeSchemaId"], schema.id.to_string());
+        assert_eq!(query["sbeSchemaVersion"], schema.version.to_string());
+    }
+
+    #[test]
+    fn responses_are_matched_to_requests_in_any_order() {
+        let (mut client, server) = connect(|mut connection| {
+            let requests: Vec<_> = (0..3).map(|_| connection.read_request().unwrap()).collect();
+            for request in requests.iter().rev() {
+                connection.reply(&pong(&request.id)).unwrap();
+            }
+            requests
+        });
+        let ids: Vec<_> = (0..3)
+            .map(|_| client.send("ping", Value::Null).unwrap())
+            .collect();
+        assert_eq!(client.in_flight(), 3);
+        let first = client.wait(&ids[0]).unwrap();
+        assert_eq!(first.id, ids[0]);
+        // The others arrived first and were kept.
+        assert_eq!(client.in_flight(), 0);
+        assert_eq!(client.wait(&ids[2]).unwrap().id, ids[2]);
+        assert_eq!(client.next_response().unwrap().id, ids[1]);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].method, "ping");
+        assert_eq!(requests[0].params, Value::Null);
+    }
+
+    #[test]
+    fn ids_must_be_unique_and_known() {
+        let (mut client, server) = connect(|mut connection| {
+            let request = connection.read_request().unwrap();
+            connection.reply(&pong("other")).unwrap();
+            request
+        });
+        let request = json!({"id": "a", "method": "ping"});
+        assert_eq!(client.send_request(request.clone()).unwrap(), "a");
+        assert!(matches!(
+            client.send_request(request),
+            Err(Error::DuplicateId(id)) if id == "a"
+        ));
+        assert!(matches!(
+            client.send_request(json!({"id": [1], "method": "ping"})),
+            Err(Error::InvalidId(_))
+        ));
+        assert!(matches!(client.wait("b"), Err(Error::UnknownId(id)) if id == "b"));
+        assert!(matches!(client.wait("a"), Err(Error::UnexpectedId(id)) if id == "other"));
+        assert_eq!(server.join().unwrap().id, "a");
+    }
+
+    #[test]
+    fn error_response_is_an_api_error() {
+        let (mut client, server) = connect(|mut connection| {
+            let request = connection.read_request().unwrap();
+            let response = stand_in::error(&request.id, 400, -1121, "Invalid symbol.").unwrap();
+            connection.reply(&response).unwrap();
+        });
+        let response = client.request("depth", json!({"symbol": "NOPE"})).unwrap();
+        assert!(response.is_error());
+        match response.decode_result::<DepthResponse>() {
+            Err(Error::Api { id, status, error }) => {
+                assert_eq!((id, status), (response.id.clone(), 400));
+                assert_eq!((error.code, error.msg.as_str()), (-1121, "Invalid symbol."));
+            }
+            other => panic!("expected an API error, got {other:?}"),
+        }
+        server.join().unwrap();
+    }
+}
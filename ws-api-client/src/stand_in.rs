@@ -0,0 +1,187 @@
+//! A local stand-in for the WebSocket API, for exercising code that uses
+//! [`Client`](crate::Client) without reaching the exchange.
+//!
+//! The stand-in accepts connections on a local port, hands each JSON request
+//! to the caller and sends back whatever `WebSocketResponse` the caller
+//! builds, in whatever order. Nothing is validated beyond the handshake's
+//! `responseFormat=sbe`.
+
+use crate::{Error, Result};
+use serde_json::Value;
+use spot_sbe::*;
+use std::collections::HashMap;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use tungstenite::handshake::server::{ErrorResponse as Rejection, Request as Handshake, Response};
+use tungstenite::{Message, WebSocket};
+
+/// Listener for stand-in connections.
+#[derive(Debug)]
+pub struct StandIn {
+    listener: TcpListener,
+}
+
+impl StandIn {
+    /// Listens on a free port of the loopback interface.
+    pub fn bind() -> std::io::Result<Self> {
+        Self::bind_to("127.0.0.1:0")
+    }
+
+    pub fn bind_to(addr: &str) -> std::io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(addr)?,
+        })
+    }
+
+    #[inline]
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// The `base_url` to pass to [`Client::connect`](crate::Client::connect).
+    pub fn base_url(&self) -> std::io::Result<String> {
+        Ok(format!("ws://{}", self.local_addr()?))
+    }
+
+    /// Accepts the next connection. The handshake is refused unless its path
+    /// is `/ws-api/v3` and it asks for `responseFormat=sbe`.
+    pub fn accept(&self) -> Result<Connection> {
+        let (stream, _) = self.listener.accept().map_err(tungstenite::Error::Io)?;
+        let mut query = HashMap::new();
+        let callback = |request: &Handshake, response: Response| {
+            query = parse_query(request.uri().query().unwrap_or_default());
+            if request.uri().path() != "/ws-api/v3"
+                || query.get("responseFormat").map(String::as_str) != Some("sbe")
+            {
+                let mut rejection =
+                    Rejection::new(Some("expected /ws-api/v3?responseFormat=sbe".into()));
+                *rejection.status_mut() = tungstenite::http::StatusCode::BAD_REQUEST;
+                return Err(rejection);
+            }
+            Ok(response)
+        };
+        let socket = tungstenite::accept_hdr(stream, callback).map_err(|err| match err {
+            tungstenite::HandshakeError::Failure(err) => Error::from(err),
+            tungstenite::HandshakeError::Interrupted(_) => Error::Closed,
+        })?;
+        Ok(Connection { socket, query })
+    }
+
+    /// Accepts one connection and answers each of its requests with
+    /// `handler` until the client closes it.
+    pub fn serve_one(&self, mut handler: impl FnMut(&Request) -> WebSocketResponse) -> Result<()> {
+        let mut connection = self.accept()?;
+        loop {
+            let request = match connection.read_request() {
+                Ok(request) => request,
+                Err(Error::Closed) => return Ok(()),
+                Err(err) => return Err(err),
+            };
+            connection.reply(&handler(&request))?;
+        }
+    }
+}
+
+/// A JSON request as the stand-in received it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Request {
+    /// The `id` the response must carry: integers as strings, and null as
+    /// the empty string
+    pub id: String,
+    pub method: String,
+    /// `Value::Null` when the request had none
+    pub params: Value,
+}
+
+/// One client connection to the stand-in.
+#[derive(Debug)]
+pub struct Connection {
+    socket: WebSocket<TcpStream>,
+    query: HashMap<String, String>,
+}
+
+impl Connection {
+    /// Parameters of the handshake's query string, e.g. `sbeSchemaId`.
+    #[inline]
+    pub fn query(&self) -> &HashMap<String, String> {
+        &self.query
+    }
+
+    /// Reads the next request, or [`Error::Closed`] once the client has
+    /// closed the connection.
+    pub fn read_request(&mut self) -> Result<Request> {
+        loop {
+            let text = match self.socket.read()? {
+                Message::Text(text) => text,
+                Message::Binary(_) => return Err(Error::UnexpectedFrame("binary".into())),
+                Message::Close(_) => {
+                    // Sends the reply to the close frame that tungstenite
+                    // has queued.
+                    let _ = self.socket.flush();
+                    return Err(Error::Closed);
+                }
+                Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => continue,
+            };
+            let mut request: Value = serde_json::from_str(text.as_str())?;
+            let id = match request.get("id") {
+                Some(Value::String(id)) => id.clone(),
+                Some(Value::Number(id)) => id.to_string(),
+                Some(Value::Null) | None => String::new(),
+                Some(id) => return Err(Error::InvalidId(id.clone())),
+            };
+            let method = match request.get("method") {
+                Some(Value::String(method)) => method.clone(),
+                _ => String::new(),
+            };
+            let params = request
+                .get_mut("params")
+                .map(Value::take)
+                .unwrap_or_default();
+            return Ok(Request { id, method, params });
+        }
+    }
+
+    /// Sends a response, which may answer any request read so far.
+    pub fn reply(&mut self, response: &WebSocketResponse) -> Result<()> {
+        self.socket.send(Message::binary(response.encode()?))?;
+        Ok(())
+    }
+}
+
+/// A `WebSocketResponse` with status 200 wrapping `result`, an encoded
+/// message header included.
+pub fn ok(id: &str, result: Vec<u8>) -> WebSocketResponse {
+    WebSocketResponse {
+        sbe_schema_id_version_deprecated: BoolEnum::False,
+        status: 200,
+        rate_limits: Vec::new(),
+        id: id.to_owned(),
+        result,
+    }
+}
+
+/// A `WebSocketResponse` wrapping an `ErrorResponse`.
+pub fn error(id: &str, status: u16, code: i16, msg: &str) -> SbeResult<WebSocketResponse> {
+    let result = ErrorResponse {
+        code,
+        server_time: None,
+        retry_after: None,
+        msg: msg.to_owned(),
+        data: None,
+    }
+    .encode()?;
+    Ok(WebSocketResponse {
+        status,
+        ..ok(id, result)
+    })
+}
+
+fn parse_query(query: &str) -> HashMap<String, String> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => (key.to_owned(), value.to_owned()),
+            None => (pair.to_owned(), String::new()),
+        })
+        .collect()
+}
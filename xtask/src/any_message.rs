@@ -0,0 +1,124 @@
+//! `any_message.rs`: dispatch over every message by template ID.
+
+use crate::code::Code;
+use crate::schema::{to_camel, to_snake, to_upper_snake, Schema};
+use crate::GeneratedFile;
+
+pub fn file(schema: &Schema) -> GeneratedFile {
+    let mut code = Code::default();
+    code.line("//! Dispatch over every message in the schema by template ID.")
+        .blank()
+        .line("use crate::meta::MessageMeta;")
+        .line("use super::meta_tables::*;")
+        .line("use super::*;")
+        .blank()
+        .line("/// Decoder for any message in the schema, positioned after its header.")
+        .line("#[derive(Clone, Copy, Debug)]")
+        .open("pub enum AnyMessage<'a> {");
+    for message in &schema.messages {
+        let name = to_camel(&message.name);
+        code.line(format!("{name}({name}Decoder<'a>),"));
+    }
+    code.close("}")
+        .blank()
+        .open("impl<'a> AnyMessage<'a> {")
+        .line("/// Wraps the decoder matching the header's template ID.")
+        .open("pub fn from_header(header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {")
+        .open("Ok(match header.template_id() {");
+    for message in &schema.messages {
+        let name = to_camel(&message.name);
+        code.open(format!(
+            "{}_codec::SBE_TEMPLATE_ID => {{",
+            to_snake(&message.name)
+        ))
+        .line(format!(
+            "Self::{name}({name}Decoder::default().header(header)?)"
+        ))
+        .close("}");
+    }
+    code.line("template_id => return Err(SbeErr::UnknownTemplateId { template_id }),")
+        .close("})")
+        .close("}")
+        .blank()
+        .line("/// Schema metadata for the message: name, template ID, block length,")
+        .line("/// `sinceVersion` and members.")
+        .open("pub fn meta(&self) -> &'static MessageMeta {")
+        .open("match self {");
+    for message in &schema.messages {
+        code.line(format!(
+            "Self::{}(_) => &{},",
+            to_camel(&message.name),
+            to_upper_snake(&message.name)
+        ));
+    }
+    code.close("}")
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("pub fn template_id(&self) -> u16 {")
+        .line("self.meta().id")
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("pub fn name(&self) -> &'static str {")
+        .line("self.meta().name")
+        .close("}")
+        .blank()
+        .line("/// Decodes the whole message into its owned struct.")
+        .line("#[cfg(feature = \"serde\")]")
+        .open("pub fn to_owned_message(&self) -> SbeResult<OwnedAnyMessage> {")
+        .open("Ok(match *self {");
+    for message in &schema.messages {
+        let name = to_camel(&message.name);
+        code.line(format!(
+            "Self::{name}(mut decoder) => OwnedAnyMessage::{name}({name}::from_decoder(&mut decoder)?),"
+        ));
+    }
+    code.close("})")
+        .close("}")
+        .close("}")
+        .blank()
+        .line("/// Owned struct of any message in the schema. Serializes as the struct")
+        .line("/// itself, without the message name.")
+        .line("#[cfg(feature = \"serde\")]")
+        .line("#[allow(clippy::large_enum_variant)]")
+        .line("#[derive(Clone, Debug, PartialEq, serde::Serialize)]")
+        .line("#[serde(untagged)]")
+        .open("pub enum OwnedAnyMessage {");
+    for message in &schema.messages {
+        let name = to_camel(&message.name);
+        code.line(format!("{name}({name}),"));
+    }
+    code.close("}")
+        .blank()
+        .line("#[cfg(feature = \"serde\")]")
+        .open("impl OwnedAnyMessage {")
+        .open("pub fn meta(&self) -> &'static MessageMeta {")
+        .open("match self {");
+    for message in &schema.messages {
+        code.line(format!(
+            "Self::{}(_) => &{},",
+            to_camel(&message.name),
+            to_upper_snake(&message.name)
+        ));
+    }
+    code.close("}")
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("pub fn name(&self) -> &'static str {")
+        .line("self.meta().name")
+        .close("}")
+        .close("}")
+        .blank()
+        .line("/// Decodes the header at the start of `data` and wraps the matching decoder.")
+        .open("pub fn decode_any(data: &[u8]) -> SbeResult<AnyMessage<'_>> {")
+        .line("let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;")
+        .line("AnyMessage::from_header(header)")
+        .close("}");
+
+    GeneratedFile {
+        name: "any_message.rs".into(),
+        contents: code.finish(),
+    }
+}
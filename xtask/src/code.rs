@@ -0,0 +1,57 @@
+//! Line-oriented source builder. Output is passed through rustfmt, so only
+//! the lines rustfmt leaves alone (comments, blank lines, overlong macro
+//! calls) need exact indentation.
+
+#[derive(Default)]
+pub struct Code {
+    out: String,
+    indent: usize,
+}
+
+impl Code {
+    /// Appends `text` line by line at the current indentation.
+    pub fn line(&mut self, text: impl AsRef<str>) -> &mut Self {
+        for line in text.as_ref().split('\n') {
+            if line.is_empty() {
+                self.out.push('\n');
+            } else {
+                for _ in 0..self.indent {
+                    self.out.push_str("    ");
+                }
+                self.out.push_str(line);
+                self.out.push('\n');
+            }
+        }
+        self
+    }
+
+    pub fn blank(&mut self) -> &mut Self {
+        self.out.push('\n');
+        self
+    }
+
+    /// Appends `text` and indents the lines that follow.
+    pub fn open(&mut self, text: impl AsRef<str>) -> &mut Self {
+        self.line(text);
+        self.indent += 1;
+        self
+    }
+
+    /// Outdents, then appends `text`.
+    pub fn close(&mut self, text: impl AsRef<str>) -> &mut Self {
+        self.indent -= 1;
+        self.line(text)
+    }
+
+    /// Outdents for `text` only, e.g. `} else {`.
+    pub fn middle(&mut self, text: impl AsRef<str>) -> &mut Self {
+        self.indent -= 1;
+        self.line(text);
+        self.indent += 1;
+        self
+    }
+
+    pub fn finish(self) -> String {
+        self.out
+    }
+}
@@ -0,0 +1,147 @@
+//! `<composite>` types: message header, group dimensions and var data
+//! encodings. Each gets an encoder and decoder wrapping a parent codec.
+
+use crate::code::Code;
+use crate::message::primitive_setter;
+use crate::schema::{to_camel, to_snake, Composite};
+use crate::GeneratedFile;
+
+pub fn file(composite: &Composite) -> GeneratedFile {
+    let name = to_camel(&composite.name);
+    let fixed_length = !composite.is_var_data();
+
+    let mut code = Code::default();
+    code.line("use super::*;")
+        .blank()
+        .line(format!("pub use decoder::{name}Decoder;"))
+        .line(format!("pub use encoder::{name}Encoder;"))
+        .blank();
+    if fixed_length {
+        code.line(format!(
+            "pub const ENCODED_LENGTH: usize = {};",
+            composite.size()
+        ))
+        .blank();
+    }
+
+    code.open("pub mod encoder {")
+        .line("use super::*;")
+        .blank()
+        .line("#[derive(Debug, Default)]")
+        .open(format!("pub struct {name}Encoder<P> {{"))
+        .line("parent: Option<P>,")
+        .line("offset: usize,")
+        .close("}")
+        .blank()
+        .open(format!("impl<'a, P> Writer<'a> for {name}Encoder<P>"))
+        .line("where")
+        .line("    P: Writer<'a> + Default,")
+        .middle("{")
+        .line("#[inline]")
+        .open("fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {")
+        .open("if let Some(parent) = self.parent.as_mut() {")
+        .line("parent.get_buf_mut()")
+        .middle("} else {")
+        .line("panic!(\"parent was None\")")
+        .close("}")
+        .close("}")
+        .close("}")
+        .blank()
+        .open(format!("impl<'a, P> {name}Encoder<P>"))
+        .line("where")
+        .line("    P: Writer<'a> + Default,")
+        .middle("{")
+        .open("pub fn wrap(mut self, parent: P, offset: usize) -> Self {")
+        .line("self.parent = Some(parent);")
+        .line("self.offset = offset;")
+        .line("self")
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("pub fn parent(&mut self) -> SbeResult<P> {")
+        .line("self.parent.take().ok_or(SbeErr::ParentNotSet)")
+        .close("}");
+    let mut offset = 0;
+    for part in &composite.parts {
+        code.blank();
+        primitive_setter(&mut code, &part.name, part, offset, "");
+        offset += part.size();
+    }
+    code.close("}").close("} // end encoder mod").blank();
+
+    let check_length = if fixed_length {
+        "ENCODED_LENGTH".to_string()
+    } else {
+        composite.size().to_string()
+    };
+    code.open("pub mod decoder {")
+        .line("use super::*;")
+        .blank()
+        .line("#[derive(Debug, Default)]")
+        .open(format!("pub struct {name}Decoder<P> {{"))
+        .line("parent: Option<P>,")
+        .line("offset: usize,")
+        .close("}")
+        .blank()
+        .open(format!("impl<'a, P> Reader<'a> for {name}Decoder<P>"))
+        .line("where")
+        .line("    P: Reader<'a> + Default,")
+        .middle("{")
+        .line("#[inline]")
+        .open("fn get_buf(&self) -> &ReadBuf<'a> {")
+        .line("self.parent.as_ref().expect(\"parent missing\").get_buf()")
+        .close("}")
+        .close("}")
+        .blank()
+        .open(format!("impl<'a, P> {name}Decoder<P>"))
+        .line("where")
+        .line("    P: Reader<'a> + Default,")
+        .middle("{")
+        .open("pub fn wrap(mut self, parent: P, offset: usize) -> SbeResult<Self> {")
+        .line(format!(
+            "parent.get_buf().check_bounds(offset, {check_length})?;"
+        ))
+        .line("self.parent = Some(parent);")
+        .line("self.offset = offset;")
+        .line("Ok(self)")
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("pub fn parent(&mut self) -> SbeResult<P> {")
+        .line("self.parent.take().ok_or(SbeErr::ParentNotSet)")
+        .close("}");
+    let mut offset = 0;
+    for part in &composite.parts {
+        let rust = part.primitive.rust();
+        code.blank().line("/// primitive field - 'REQUIRED'");
+        if let Some(encoding) = &part.character_encoding {
+            code.line(format!("/// characterEncoding: '{encoding}'"));
+        }
+        code.line("#[inline]")
+            .open(format!(
+                "pub fn {}(&self) -> {rust} {{",
+                to_snake(&part.name)
+            ))
+            .line(format!(
+                "self.get_buf().get_{rust}_at({})",
+                offset_expr("self.offset", offset)
+            ))
+            .close("}");
+        offset += part.size();
+    }
+    code.close("}").close("} // end decoder mod");
+
+    GeneratedFile {
+        name: format!("{}_codec.rs", to_snake(&composite.name)),
+        contents: code.finish(),
+    }
+}
+
+/// `base`, plus `offset` if non-zero.
+pub fn offset_expr(base: &str, offset: usize) -> String {
+    if offset == 0 {
+        base.to_string()
+    } else {
+        format!("{base} + {offset}")
+    }
+}
@@ -0,0 +1,165 @@
+//! Workspace tasks, run with `cargo xtask <task>`.
+//!
+//! `codegen` regenerates the `spot_sbe` codecs from each schema in `TARGETS`;
+//! `codegen --check` only compares, and fails if the checked-in sources have
+//! drifted from what the schema produces.
+
+use anyhow::{bail, Context};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+mod any_message;
+mod code;
+mod composite;
+mod message;
+mod meta;
+mod owned;
+mod schema;
+mod types;
+
+use crate::schema::Schema;
+
+/// A schema and the `spot_sbe` module generated from it.
+struct Target {
+    schema: &'static str,
+    module: &'static str,
+}
+
+/// Every schema compiled into `spot_sbe`. Each module also needs a `mod.rs`
+/// declaring its files and an entry in `spot_sbe::versions`.
+const TARGETS: &[Target] = &[Target {
+    schema: "spot_2_1.xml",
+    module: "spot_sbe/src/v2_1",
+}];
+
+/// A generated source file, relative to its target's module.
+pub struct GeneratedFile {
+    pub name: String,
+    pub contents: String,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["codegen"] => codegen(false),
+        ["codegen", "--check"] => codegen(true),
+        _ => bail!("Usage: cargo xtask codegen [--check]"),
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives in the workspace root")
+        .to_path_buf()
+}
+
+fn codegen(check: bool) -> anyhow::Result<()> {
+    let root = workspace_root();
+    let mut drifted = Vec::new();
+    for target in TARGETS {
+        drifted.extend(codegen_target(&root, target, check)?);
+    }
+    if !drifted.is_empty() {
+        for path in &drifted {
+            eprintln!("{path} is out of date");
+        }
+        bail!(
+            "{} generated file(s) differ from their schema; run `cargo xtask codegen`",
+            drifted.len()
+        );
+    }
+    Ok(())
+}
+
+/// Writes, or in `check` mode compares, one target's files. Returns the paths
+/// that differ in `check` mode.
+fn codegen_target(root: &Path, target: &Target, check: bool) -> anyhow::Result<Vec<String>> {
+    let Target { schema, module } = target;
+    let xml = fs::read_to_string(root.join(schema))
+        .with_context(|| format!("Failed to read {schema}"))?;
+    let parsed = Schema::parse(&xml).with_context(|| format!("Failed to parse {schema}"))?;
+    let files = generate(&parsed, schema)?;
+
+    let output_dir = root.join(module);
+    let mut drifted = Vec::new();
+    for file in &files {
+        let path = output_dir.join(&file.name);
+        let contents = rustfmt(&file.contents)
+            .with_context(|| format!("Failed to format generated {}", file.name))?;
+        let current = fs::read_to_string(&path).ok();
+        if current.as_deref() == Some(contents.as_str()) {
+            continue;
+        }
+        if check {
+            drifted.push(format!("{module}/{}", file.name));
+        } else {
+            fs::write(&path, contents)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            println!("Wrote {module}/{}", file.name);
+        }
+    }
+    let mod_rs = fs::read_to_string(output_dir.join("mod.rs"))
+        .with_context(|| format!("Failed to read {module}/mod.rs"))?;
+    let undeclared: Vec<_> = files
+        .iter()
+        .filter_map(|file| file.name.strip_suffix(".rs"))
+        .filter(|name| !mod_rs.contains(&format!("mod {name};")))
+        .collect();
+    if !undeclared.is_empty() {
+        for name in &undeclared {
+            eprintln!("{module}/mod.rs does not declare `mod {name};`");
+        }
+        bail!(
+            "{} generated module(s) missing from {module}/mod.rs",
+            undeclared.len()
+        );
+    }
+    if check && drifted.is_empty() {
+        println!("{} generated files match {schema}", files.len());
+    }
+    Ok(drifted)
+}
+
+fn generate(schema: &Schema, path: &str) -> anyhow::Result<Vec<GeneratedFile>> {
+    let mut files = Vec::new();
+    files.extend(schema.enums.iter().map(types::enum_file));
+    files.extend(schema.sets.iter().map(types::set_file));
+    files.extend(schema.composites.iter().map(composite::file));
+    for message in &schema.messages {
+        files.push(message::file(schema, message)?);
+    }
+    files.push(meta::file(schema, path)?);
+    files.push(any_message::file(schema));
+    Ok(files)
+}
+
+fn rustfmt(source: &str) -> anyhow::Result<String> {
+    let mut child = Command::new(std::env::var("RUSTFMT").unwrap_or_else(|_| "rustfmt".into()))
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to run rustfmt")?;
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(source.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        bail!(
+            "rustfmt failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
@@ -0,0 +1,1165 @@
+//! `<message>` codecs: an encoder and decoder for the message block, one per
+//! repeating group, and the owned form in `owned`.
+
+use crate::code::Code;
+use crate::composite::offset_expr;
+use crate::owned;
+use crate::schema::{
+    to_camel, to_snake, Block, Composite, Data, EncodedType, Field, FieldKind, Group, Message,
+    Presence, Primitive, Schema,
+};
+use crate::GeneratedFile;
+use anyhow::{bail, Result};
+
+const MANTISSA128_NULL: &str = "-170141183460469231731687303715884105728";
+
+/// The message block or one of its repeating groups, with the types of the
+/// codecs that wrap it.
+pub struct Container<'s> {
+    pub name: String,
+    pub group: Option<&'s Group>,
+    pub block: &'s Block,
+    /// Blocks from the message down to this one
+    pub chain: Vec<&'s Block>,
+    pub encoder_type: String,
+    pub decoder_type: String,
+}
+
+impl<'s> Container<'s> {
+    /// The message followed by its groups, depth first.
+    pub fn all(message: &'s Message) -> Vec<Self> {
+        let name = to_camel(&message.name);
+        let root = Container {
+            group: None,
+            block: &message.block,
+            chain: vec![&message.block],
+            encoder_type: format!("{name}Encoder<'a>"),
+            decoder_type: format!("{name}Decoder<'a>"),
+            name,
+        };
+        let mut all = Vec::new();
+        root.collect(&mut all);
+        all
+    }
+
+    fn collect(self, all: &mut Vec<Self>) {
+        let children: Vec<_> = self
+            .block
+            .groups
+            .iter()
+            .map(|group| {
+                let name = to_camel(&group.name);
+                let mut chain = self.chain.clone();
+                chain.push(&group.block);
+                Container {
+                    group: Some(group),
+                    block: &group.block,
+                    chain,
+                    encoder_type: format!("{name}Encoder<{}>", self.encoder_type),
+                    decoder_type: format!("{name}Decoder<'a, {}>", self.decoder_type),
+                    name,
+                }
+            })
+            .collect();
+        all.push(self);
+        for child in children {
+            child.collect(all);
+        }
+    }
+
+    pub fn is_message(&self) -> bool {
+        self.group.is_none()
+    }
+
+    /// The exponent field named by `field`'s `mbx:exponent`, and how many
+    /// blocks up it lives.
+    pub fn exponent_of(&self, field: &Field) -> Result<Option<(&'s Field, usize)>> {
+        let Some(exponent) = &field.exponent else {
+            return Ok(None);
+        };
+        for (depth, block) in self.chain.iter().rev().enumerate() {
+            if let Some(found) = block.fields.iter().find(|f| &f.name == exponent) {
+                return Ok(Some((found, depth)));
+            }
+        }
+        bail!("Exponent field '{exponent}' of '{}' not found", field.name)
+    }
+}
+
+/// The owner of the field `depth` blocks up, e.g. `self.parent.as_ref()...`,
+/// returning `SbeErr::ParentNotSet` from the enclosing function if it is gone.
+fn owner(depth: usize) -> String {
+    let mut owner = String::from("self");
+    for _ in 0..depth {
+        owner.push_str(".parent.as_ref().ok_or(SbeErr::ParentNotSet)?");
+    }
+    owner
+}
+
+fn is_mantissa128(t: &EncodedType) -> bool {
+    t.name == "mantissa128"
+}
+
+pub fn file(schema: &Schema, message: &Message) -> Result<GeneratedFile> {
+    let name = to_camel(&message.name);
+    let containers = Container::all(message);
+
+    let mut code = Code::default();
+    code.line("use super::*;")
+        .blank()
+        .line(format!("pub use decoder::{name}Decoder;"))
+        .line(format!("pub use encoder::{name}Encoder;"))
+        .line("#[cfg(feature = \"serde\")]")
+        .line(format!("pub use owned::{name};"))
+        .blank()
+        .line(format!(
+            "pub const SBE_BLOCK_LENGTH: u16 = {};",
+            message.block.block_length
+        ))
+        .line(format!("pub const SBE_TEMPLATE_ID: u16 = {};", message.id))
+        .line(format!("pub const SBE_SCHEMA_ID: u16 = {};", schema.id))
+        .line(format!(
+            "pub const SBE_SCHEMA_VERSION: u16 = {};",
+            schema.version
+        ))
+        .line(format!(
+            "pub const SBE_SEMANTIC_VERSION: &str = \"{}\";",
+            schema.semantic_version
+        ))
+        .blank();
+
+    encoder(&mut code, schema, &containers)?;
+    code.blank();
+    decoder(&mut code, schema, &containers)?;
+    code.blank();
+    owned::module(&mut code, schema, message, &containers)?;
+
+    Ok(GeneratedFile {
+        name: format!("{}_codec.rs", to_snake(&message.name)),
+        contents: code.finish(),
+    })
+}
+
+fn encoder(code: &mut Code, schema: &Schema, containers: &[Container]) -> Result<()> {
+    code.open("pub mod encoder {").line("use super::*;");
+    for container in containers {
+        code.blank();
+        if container.is_message() {
+            message_encoder(code, &container.name);
+        } else {
+            group_encoder(code, schema, container)?;
+        }
+        encoder_members(code, schema, container)?;
+        code.close("}");
+    }
+
+    let mut extensions = Extensions::default();
+    for container in containers {
+        for field in &container.block.fields {
+            if let Some((exponent, depth @ 1..)) = container.exponent_of(field)? {
+                let mut body = Code::default();
+                decimal_setter(&mut body, schema, field, exponent, depth)?;
+                extensions.add(&container.encoder_type, body.finish());
+            }
+        }
+    }
+    extensions.write(code);
+    code.close("} // end encoder");
+    Ok(())
+}
+
+fn message_encoder(code: &mut Code, name: &str) {
+    code.line("#[derive(Debug, Default)]")
+        .open(format!("pub struct {name}Encoder<'a> {{"))
+        .line("buf: WriteBuf<'a>,")
+        .line("initial_offset: usize,")
+        .line("offset: usize,")
+        .line("limit: usize,")
+        .close("}")
+        .blank()
+        .open(format!("impl<'a> Writer<'a> for {name}Encoder<'a> {{"))
+        .line("#[inline]")
+        .open("fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {")
+        .line("&mut self.buf")
+        .close("}")
+        .close("}")
+        .blank()
+        .open(format!("impl<'a> Encoder<'a> for {name}Encoder<'a> {{"))
+        .line("#[inline]")
+        .open("fn get_limit(&self) -> usize {")
+        .line("self.limit")
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("fn set_limit(&mut self, limit: usize) {")
+        .line("self.limit = limit;")
+        .close("}")
+        .close("}")
+        .blank()
+        .open(format!("impl<'a> {name}Encoder<'a> {{"))
+        .open("pub fn wrap(mut self, buf: WriteBuf<'a>, offset: usize) -> Self {")
+        .line("let limit = offset + SBE_BLOCK_LENGTH as usize;")
+        .line("self.buf = buf;")
+        .line("self.initial_offset = offset;")
+        .line("self.offset = offset;")
+        .line("self.limit = limit;")
+        .line("self")
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("pub fn encoded_length(&self) -> usize {")
+        .line("self.limit - self.offset")
+        .close("}")
+        .blank()
+        .open("pub fn header(self, offset: usize) -> MessageHeaderEncoder<Self> {")
+        .line("let mut header = MessageHeaderEncoder::default().wrap(self, offset);")
+        .line("header.block_length(SBE_BLOCK_LENGTH);")
+        .line("header.template_id(SBE_TEMPLATE_ID);")
+        .line("header.schema_id(SBE_SCHEMA_ID);")
+        .line("header.version(SBE_SCHEMA_VERSION);")
+        .line("header")
+        .close("}");
+}
+
+/// Width of a group's `numInGroup`, and the header length.
+fn dimension(schema: &Schema, group: &Group) -> Result<(&'static str, usize, Option<String>)> {
+    let composite: &Composite = schema.composite(&group.dimension_type)?;
+    let Some(num_in_group) = composite.parts.get(1) else {
+        bail!("Dimension type '{}' has no numInGroup", composite.name);
+    };
+    Ok((
+        num_in_group.primitive.rust(),
+        composite.size(),
+        num_in_group.max_value.clone(),
+    ))
+}
+
+fn group_encoder(code: &mut Code, schema: &Schema, container: &Container) -> Result<()> {
+    let name = &container.name;
+    let group = container.group.expect("group container");
+    let (count, header, _) = dimension(schema, group)?;
+    code.line("#[derive(Debug, Default)]")
+        .open(format!("pub struct {name}Encoder<P> {{"))
+        .line("parent: Option<P>,")
+        .line(format!("count: {count},"))
+        .line("index: usize,")
+        .line("offset: usize,")
+        .line("initial_limit: usize,")
+        .close("}")
+        .blank()
+        .line(format!("impl<'a, P> Writer<'a> for {name}Encoder<P>"))
+        .line("where")
+        .line("    P: Writer<'a> + Default,")
+        .open("{")
+        .line("#[inline]")
+        .open("fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {")
+        .open("if let Some(parent) = self.parent.as_mut() {")
+        .line("parent.get_buf_mut()")
+        .middle("} else {")
+        .line("panic!(\"parent was None\")")
+        .close("}")
+        .close("}")
+        .close("}")
+        .blank()
+        .line(format!("impl<'a, P> Encoder<'a> for {name}Encoder<P>"))
+        .line("where")
+        .line("    P: Encoder<'a> + Default,")
+        .open("{")
+        .line("#[inline]")
+        .open("fn get_limit(&self) -> usize {")
+        .line("self.parent.as_ref().expect(\"parent missing\").get_limit()")
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("fn set_limit(&mut self, limit: usize) {")
+        .line("self.parent.as_mut().expect(\"parent missing\").set_limit(limit);")
+        .close("}")
+        .close("}")
+        .blank()
+        .line(format!("impl<'a, P> {name}Encoder<P>"))
+        .line("where")
+        .line("    P: Encoder<'a> + Default,")
+        .open("{")
+        .line("#[inline]")
+        .open(format!(
+            "pub fn wrap(mut self, mut parent: P, count: {count}) -> Self {{"
+        ))
+        .line("let initial_limit = parent.get_limit();")
+        .line(format!("parent.set_limit(initial_limit + {header});"))
+        .line("parent.get_buf_mut().put_u16_at(initial_limit, Self::block_length());")
+        .line(format!(
+            "parent.get_buf_mut().put_{count}_at(initial_limit + 2, count);"
+        ))
+        .line("self.parent = Some(parent);")
+        .line("self.count = count;")
+        .line("self.index = usize::MAX;")
+        .line("self.offset = usize::MAX;")
+        .line("self.initial_limit = initial_limit;")
+        .line("self")
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("pub fn block_length() -> u16 {")
+        .line(container.block.block_length.to_string())
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("pub fn parent(&mut self) -> SbeResult<P> {")
+        .line("self.parent.take().ok_or(SbeErr::ParentNotSet)")
+        .close("}")
+        .blank()
+        .line("/// will return Some(current index) when successful otherwise None")
+        .line("#[inline]")
+        .open("pub fn advance(&mut self) -> SbeResult<Option<usize>> {")
+        .line("let index = self.index.wrapping_add(1);")
+        .open("if index >= self.count as usize {")
+        .line("return Ok(None);")
+        .close("}")
+        .open("if let Some(parent) = self.parent.as_mut() {")
+        .line("self.offset = parent.get_limit();")
+        .line("parent.set_limit(self.offset + Self::block_length() as usize);")
+        .line("self.index = index;")
+        .line("Ok(Some(index))")
+        .middle("} else {")
+        .line("Err(SbeErr::ParentNotSet)")
+        .close("}")
+        .close("}");
+    Ok(())
+}
+
+fn encoder_members(code: &mut Code, schema: &Schema, container: &Container) -> Result<()> {
+    for field in &container.block.fields {
+        code.blank();
+        let offset = field.offset;
+        match (schema.field_kind(&field.type_name)?, field.presence) {
+            (FieldKind::Enum(_), Presence::Constant) => {
+                code.line(format!("// skipping CONSTANT enum '{}'", field.name));
+            }
+            (_, Presence::Constant) => bail!("Unsupported constant field '{}'", field.name),
+            (FieldKind::Enum(e), _) => {
+                let raw = e.encoding.rust();
+                code.line("/// REQUIRED enum")
+                    .line("#[inline]")
+                    .open(format!(
+                        "pub fn {}(&mut self, value: {}) {{",
+                        to_snake(&field.name),
+                        to_camel(&e.name)
+                    ))
+                    .line(format!(
+                        "let offset = {};",
+                        offset_expr("self.offset", offset)
+                    ))
+                    .line(format!(
+                        "self.get_buf_mut().put_{raw}_at(offset, value as {raw})"
+                    ))
+                    .close("}");
+            }
+            (FieldKind::Set(set), _) => {
+                code.line("#[inline]")
+                    .open(format!(
+                        "pub fn {}(&mut self, value: {}) {{",
+                        to_snake(&field.name),
+                        to_camel(&set.name)
+                    ))
+                    .line(format!(
+                        "let offset = {};",
+                        offset_expr("self.offset", offset)
+                    ))
+                    .line(format!(
+                        "self.get_buf_mut().put_{}_at(offset, value.0)",
+                        set.encoding.rust()
+                    ))
+                    .close("}");
+            }
+            (FieldKind::Encoded(t), _) if is_mantissa128(t) => {
+                code.line(format!(
+                    "/// mantissa128 field '{}' as a little-endian i128",
+                    field.name
+                ))
+                .line(format!("/// - null value: {MANTISSA128_NULL}"))
+                .line(format!("/// - encodedOffset: {offset}"))
+                .line("/// - encodedLength: 16")
+                .line(format!("/// - version: {}", field.since_version))
+                .line("#[inline]")
+                .open(format!(
+                    "pub fn {}(&mut self, value: Option<i128>) {{",
+                    to_snake(&field.name)
+                ))
+                .line(format!(
+                    "let offset = {};",
+                    offset_expr("self.offset", offset)
+                ))
+                .line("self.get_buf_mut().put_i128_at(offset, value.unwrap_or(i128::MIN));")
+                .close("}");
+            }
+            (FieldKind::Encoded(t), _) if t.length != 1 => {
+                bail!("Unsupported array field '{}'", field.name)
+            }
+            (FieldKind::Encoded(t), _) => {
+                primitive_setter(code, &field.name, t, offset, "");
+            }
+        }
+        if let Some((exponent, 0)) = container.exponent_of(field)? {
+            code.blank();
+            decimal_setter(code, schema, field, exponent, 0)?;
+        }
+    }
+    for group in &container.block.groups {
+        let (count, _, _) = dimension(schema, group)?;
+        let snake = to_snake(&group.name);
+        let camel = to_camel(&group.name);
+        code.blank()
+            .line(format!("/// GROUP ENCODER (id={})", group.id))
+            .line("#[inline]")
+            .open(format!(
+                "pub fn {snake}_encoder(self, count: {count}, {snake}_encoder: {camel}Encoder<Self>) -> {camel}Encoder<Self> {{"
+            ))
+            .line(format!("{snake}_encoder.wrap(self, count)"))
+            .close("}");
+    }
+    for data in &container.block.data {
+        let composite = schema.composite(&data.type_name)?;
+        let (length_type, prefix, encoding) = data_layout(composite);
+        let (argument, bytes) = if encoding == "UTF-8" {
+            ("&str", "value.as_bytes()")
+        } else {
+            ("&[u8]", "value")
+        };
+        code.blank()
+            .line(format!(
+                "/// VAR_DATA ENCODER - character encoding: '{encoding}'"
+            ))
+            .line("#[inline]")
+            .open(format!(
+                "pub fn {}(&mut self, value: {argument}) {{",
+                to_snake(&data.name)
+            ))
+            .line("let limit = self.get_limit();")
+            .line("let data_length = value.len();")
+            .line(format!("self.set_limit(limit + {prefix} + data_length);"))
+            .line(format!(
+                "self.get_buf_mut().put_{length_type}_at(limit, data_length as {length_type});"
+            ))
+            .line(format!(
+                "self.get_buf_mut().put_slice_at(limit + {prefix}, {bytes});"
+            ))
+            .close("}");
+    }
+    Ok(())
+}
+
+/// Length prefix type and width, and character encoding of var data.
+fn data_layout(composite: &Composite) -> (&'static str, usize, &str) {
+    let length = &composite.parts[0];
+    let encoding = composite
+        .parts
+        .get(1)
+        .and_then(|part| part.character_encoding.as_deref())
+        .unwrap_or("None");
+    (length.primitive.rust(), length.size(), encoding)
+}
+
+/// Encoder setter with sbetool's doc comment, also used by composites.
+pub fn primitive_setter(code: &mut Code, name: &str, t: &EncodedType, offset: usize, _: &str) {
+    let primitive = t.primitive;
+    let length = if t.length == 0 {
+        "-1".to_string()
+    } else {
+        t.size().to_string()
+    };
+    code.line(format!("/// primitive field '{name}'"))
+        .line(format!("/// - min value: {}", primitive.min_value()))
+        .line(format!(
+            "/// - max value: {}",
+            t.max_value.as_deref().unwrap_or(primitive.max_value())
+        ))
+        .line(format!("/// - null value: {}", primitive.null_value()))
+        .line(format!(
+            "/// - characterEncoding: {}",
+            t.character_encoding.as_deref().unwrap_or("null")
+        ))
+        .line("/// - semanticType: null")
+        .line(format!("/// - encodedOffset: {offset}"))
+        .line(format!("/// - encodedLength: {length}"))
+        .line("#[inline]")
+        .open(format!(
+            "pub fn {}(&mut self, value: {}) {{",
+            to_snake(name),
+            primitive.rust()
+        ))
+        .line(format!(
+            "let offset = {};",
+            offset_expr("self.offset", offset)
+        ))
+        .line(format!(
+            "self.get_buf_mut().put_{}_at(offset, value);",
+            primitive.rust()
+        ))
+        .close("}");
+}
+
+fn decimal_setter(
+    code: &mut Code,
+    schema: &Schema,
+    field: &Field,
+    exponent: &Field,
+    depth: usize,
+) -> Result<()> {
+    let name = to_snake(&field.name);
+    let wide =
+        matches!(schema.field_kind(&field.type_name)?, FieldKind::Encoded(t) if is_mantissa128(t));
+    let optional = field.presence == Presence::Optional || wide;
+    let null = if wide { "i128::MIN" } else { "i64::MIN" };
+    let place = if depth == 0 {
+        "this block"
+    } else {
+        "the enclosing block"
+    };
+    code.line(format!(
+        "/// '{}' rescaled to the '{}' already written to {place}",
+        field.name, exponent.name
+    ))
+    .line("#[inline]")
+    .open(format!(
+        "pub fn {name}_decimal(&mut self, value: {}) -> SbeResult<()> {{",
+        if optional {
+            "Option<Decimal>"
+        } else {
+            "Decimal"
+        }
+    ));
+    if optional {
+        code.open("let Some(value) = value else {")
+            .line(if wide {
+                format!("self.{name}(None);")
+            } else {
+                format!("self.{name}({null});")
+            })
+            .line("return Ok(());")
+            .close("};");
+    }
+    code.line(format!(
+        "let offset = {};",
+        offset_expr(&format!("{}.offset", owner(depth)), exponent.offset)
+    ))
+    .line("let exponent = self.get_buf_mut().get_i8_at(offset);");
+    if exponent.presence == Presence::Optional {
+        code.open("if exponent == i8::MIN {")
+            .line("return Err(SbeErr::DecimalNotRepresentable { exponent });")
+            .close("}");
+    }
+    code.line("let mantissa = value")
+        .line("    .rescale(exponent)");
+    if wide {
+        code.line("    .map(|value| value.mantissa())");
+    } else {
+        code.line("    .and_then(|value| i64::try_from(value.mantissa()).ok())");
+    }
+    code.line(format!("    .filter(|&mantissa| mantissa != {null})"))
+        .line("    .ok_or(SbeErr::DecimalNotRepresentable { exponent })?;")
+        .line(if wide {
+            format!("self.{name}(Some(mantissa));")
+        } else {
+            format!("self.{name}(mantissa);")
+        })
+        .line("Ok(())")
+        .close("}");
+    Ok(())
+}
+
+/// `impl` blocks added after the generated codecs, one per codec type, for
+/// accessors that need the concrete parent type.
+#[derive(Default)]
+struct Extensions {
+    blocks: Vec<(String, Vec<String>)>,
+}
+
+impl Extensions {
+    fn add(&mut self, codec_type: &str, body: String) {
+        let codec_type = codec_type.replace("'a", "'_");
+        match self.blocks.iter_mut().find(|(t, _)| *t == codec_type) {
+            Some((_, bodies)) => bodies.push(body),
+            None => self.blocks.push((codec_type, vec![body])),
+        }
+    }
+
+    fn write(self, code: &mut Code) {
+        for (codec_type, bodies) in self.blocks {
+            code.blank().open(format!("impl {codec_type} {{"));
+            for (i, body) in bodies.iter().enumerate() {
+                if i > 0 {
+                    code.blank();
+                }
+                code.line(body.trim_end());
+            }
+            code.close("}");
+        }
+    }
+}
+
+fn decoder(code: &mut Code, schema: &Schema, containers: &[Container]) -> Result<()> {
+    code.open("pub mod decoder {").line("use super::*;");
+    for container in containers {
+        code.blank();
+        if container.is_message() {
+            message_decoder(code, &container.name, container.block.block_length);
+        } else {
+            group_decoder(code, schema, container)?;
+        }
+        decoder_members(code, schema, container)?;
+        code.close("}");
+    }
+
+    let mut extensions = Extensions::default();
+    for container in containers {
+        for field in &container.block.fields {
+            if let Some((exponent, depth @ 1..)) = container.exponent_of(field)? {
+                let mut body = Code::default();
+                decimal_getter(&mut body, schema, field, exponent, depth)?;
+                extensions.add(&container.decoder_type, body.finish());
+            }
+        }
+    }
+    extensions.write(code);
+    code.close("} // end decoder");
+    Ok(())
+}
+
+fn message_decoder(code: &mut Code, name: &str, block_length: usize) {
+    code.line("#[derive(Clone, Copy, Debug, Default)]")
+        .open(format!("pub struct {name}Decoder<'a> {{"))
+        .line("buf: ReadBuf<'a>,")
+        .line("initial_offset: usize,")
+        .line("offset: usize,")
+        .line("limit: usize,")
+        .line("pub acting_block_length: u16,")
+        .line("pub acting_version: u16,")
+        .close("}")
+        .blank()
+        .open(format!("impl<'a> Reader<'a> for {name}Decoder<'a> {{"))
+        .line("#[inline]")
+        .open("fn get_buf(&self) -> &ReadBuf<'a> {")
+        .line("&self.buf")
+        .close("}")
+        .close("}")
+        .blank()
+        .open(format!("impl<'a> Decoder<'a> for {name}Decoder<'a> {{"))
+        .line("#[inline]")
+        .open("fn get_limit(&self) -> usize {")
+        .line("self.limit")
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("fn set_limit(&mut self, limit: usize) {")
+        .line("self.limit = limit;")
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("fn acting_version(&self) -> u16 {")
+        .line("self.acting_version")
+        .close("}")
+        .close("}")
+        .blank()
+        .open(format!("impl<'a> {name}Decoder<'a> {{"))
+        .open("pub fn wrap(")
+        .line("mut self,")
+        .line("buf: ReadBuf<'a>,")
+        .line("offset: usize,")
+        .line("acting_block_length: u16,")
+        .line("acting_version: u16,")
+        .middle(") -> SbeResult<Self> {")
+        .line(if block_length == 0 {
+            "buf.check_bounds(offset, acting_block_length as usize)?;"
+        } else {
+            "buf.check_bounds(offset, acting_block_length.max(SBE_BLOCK_LENGTH) as usize)?;"
+        })
+        .line("let limit = offset + acting_block_length as usize;")
+        .line("self.buf = buf;")
+        .line("self.initial_offset = offset;")
+        .line("self.offset = offset;")
+        .line("self.limit = limit;")
+        .line("self.acting_block_length = acting_block_length;")
+        .line("self.acting_version = acting_version;")
+        .line("Ok(self)")
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("pub fn encoded_length(&self) -> usize {")
+        .line("self.limit - self.offset")
+        .close("}")
+        .blank()
+        .open("pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> SbeResult<Self> {")
+        .line("let template_id = header.template_id();")
+        .open("if template_id != SBE_TEMPLATE_ID {")
+        .open("return Err(SbeErr::UnexpectedTemplateId {")
+        .line("expected: SBE_TEMPLATE_ID,")
+        .line("actual: template_id,")
+        .close("});")
+        .close("}")
+        .line("let acting_block_length = header.block_length();")
+        .line("let acting_version = header.version();")
+        .blank()
+        .open("self.wrap(")
+        .line("header.parent()?,")
+        .line("message_header_codec::ENCODED_LENGTH,")
+        .line("acting_block_length,")
+        .line("acting_version,")
+        .close(")")
+        .close("}");
+}
+
+/// Tokens sbetool's IR holds for a block's members, for the group token dump.
+fn token_count(schema: &Schema, block: &Block) -> Result<usize> {
+    let mut count = 0;
+    for field in &block.fields {
+        count += match schema.field_kind(&field.type_name)? {
+            FieldKind::Encoded(_) => 3,
+            FieldKind::Enum(e) => 4 + e.values.len(),
+            FieldKind::Set(set) => 4 + set.choices.len(),
+        };
+    }
+    for group in &block.groups {
+        count += 6 + token_count(schema, &group.block)?;
+    }
+    count += 6 * block.data.len();
+    Ok(count)
+}
+
+fn group_decoder(code: &mut Code, schema: &Schema, container: &Container) -> Result<()> {
+    let name = &container.name;
+    let group = container.group.expect("group container");
+    let (count, header, max_count) = dimension(schema, group)?;
+    let block_length = container.block.block_length;
+    let parent = &container.chain[container.chain.len() - 2];
+    let first = std::ptr::eq(&parent.groups[0], group);
+    let offset = if first {
+        parent.block_length.to_string()
+    } else {
+        "-1".to_string()
+    };
+    let token = format!(
+        "Token{{signal=BEGIN_GROUP, name='{}', referencedName='null', description='null', packageName='null', id={}, version={}, deprecated=0, encodedLength={block_length}, offset={offset}, componentTokenCount={}, encoding=Encoding{{presence=REQUIRED, primitiveType=null, byteOrder=LITTLE_ENDIAN, minValue=null, maxValue=null, nullValue=null, constValue=null, characterEncoding='null', epoch='null', timeUnit=null, semanticType='null'}}}}",
+        group.name,
+        group.id,
+        group.since_version,
+        6 + token_count(schema, &group.block)?,
+    );
+    let count_check = match max_count {
+        Some(max) => format!("count > {max} || "),
+        None => String::new(),
+    };
+    // Each entry also carries at least its nested group headers and var data
+    // length prefixes.
+    let mut trailing = 0;
+    for nested in &group.block.groups {
+        trailing += dimension(schema, nested)?.1;
+    }
+    for data in &group.block.data {
+        trailing += data_layout(schema.composite(&data.type_name)?).1;
+    }
+    // Entries may only be shorter than the schema's block by the fields added
+    // after the acting version.
+    let mut versions: Vec<u16> = group
+        .block
+        .fields
+        .iter()
+        .map(|field| field.since_version)
+        .filter(|&since| since > 0)
+        .collect();
+    versions.sort_unstable();
+    versions.dedup();
+    let length_at = |version: u16| {
+        group
+            .block
+            .fields
+            .iter()
+            .filter(|field| field.since_version <= version)
+            .map(|field| field.offset + field.length)
+            .max()
+            .unwrap_or(0)
+    };
+    let mut min_block_length = String::new();
+    for (i, &since) in versions.iter().enumerate().rev() {
+        let length = if i + 1 == versions.len() {
+            block_length
+        } else {
+            length_at(since)
+        };
+        min_block_length += &format!("if parent.acting_version() >= {since} {{ {length} }} else ");
+    }
+    min_block_length += &if versions.is_empty() {
+        block_length.to_string()
+    } else {
+        format!("{{ {} }}", length_at(0))
+    };
+    let min_entry = if trailing == 0 {
+        "block_length".to_string()
+    } else {
+        format!("block_length + {trailing}")
+    };
+    code.line("#[derive(Debug, Default)]")
+        .open(format!("pub struct {name}Decoder<'a, P> {{"))
+        .line("parent: Option<P>,")
+        .line("/// Copied from the parent on `wrap`, so that reading the group never")
+        .line("/// needs the parent; its limit is handed back by `parent`.")
+        .line("buf: ReadBuf<'a>,")
+        .line("limit: usize,")
+        .line("acting_version: u16,")
+        .line("block_length: usize,")
+        .line(format!("count: {count},"))
+        .line("index: usize,")
+        .line("offset: usize,")
+        .close("}")
+        .blank()
+        .line(format!("impl<'a, P> Reader<'a> for {name}Decoder<'a, P>"))
+        .line("where")
+        .line("    P: Reader<'a> + Default,")
+        .open("{")
+        .line("#[inline]")
+        .open("fn get_buf(&self) -> &ReadBuf<'a> {")
+        .line("&self.buf")
+        .close("}")
+        .close("}")
+        .blank()
+        .line(format!("impl<'a, P> Decoder<'a> for {name}Decoder<'a, P>"))
+        .line("where")
+        .line("    P: Decoder<'a> + Default,")
+        .open("{")
+        .line("#[inline]")
+        .open("fn get_limit(&self) -> usize {")
+        .line("self.limit")
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("fn set_limit(&mut self, limit: usize) {")
+        .line("self.limit = limit;")
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("fn acting_version(&self) -> u16 {")
+        .line("self.acting_version")
+        .close("}")
+        .close("}")
+        .blank()
+        .line(format!("impl<'a, P> {name}Decoder<'a, P>"))
+        .line("where")
+        .line("    P: Decoder<'a> + Default,")
+        .open("{")
+        .open("pub fn wrap(mut self, parent: P) -> SbeResult<Self> {")
+        .line("let initial_offset = parent.get_limit();")
+        .line(format!(
+            "parent.get_buf().check_bounds(initial_offset, {header})?;"
+        ))
+        .line("let block_length = parent.get_buf().get_u16_at(initial_offset) as usize;")
+        .line(format!(
+            "let count = parent.get_buf().get_{count}_at(initial_offset + 2);"
+        ))
+        .line(format!(
+            "let available = parent.get_buf().len() - (initial_offset + {header});"
+        ));
+    if min_block_length != "0" {
+        code.line(format!("let expected = {min_block_length};"))
+            .open("if block_length < expected {")
+            .line("return Err(SbeErr::InvalidBlockLength { block_length, expected });")
+            .close("}");
+    }
+    code.open(format!(
+        "if {count_check}(count as usize).saturating_mul({min_entry}) > available {{"
+    ))
+    .open("return Err(SbeErr::InvalidGroupCount {")
+    .line("count: count as usize,")
+    .line("block_length,")
+    .line("available,")
+    .close("});")
+    .close("}")
+    .line("self.buf = *parent.get_buf();")
+    .line(format!("self.limit = initial_offset + {header};"))
+    .line("self.acting_version = parent.acting_version();")
+    .line("self.parent = Some(parent);")
+    .line("self.block_length = block_length;")
+    .line("self.count = count;")
+    .line("self.index = usize::MAX;")
+    .line("self.offset = 0;")
+    .line("Ok(self)")
+    .close("}")
+    .blank()
+    .line(format!("/// group token - {token}"))
+    .line("#[inline]")
+    .open("pub fn parent(&mut self) -> SbeResult<P> {")
+    .line("let mut parent = self.parent.take().ok_or(SbeErr::ParentNotSet)?;")
+    .line("parent.set_limit(self.limit);")
+    .line("Ok(parent)")
+    .close("}")
+    .blank()
+    .line("#[inline]")
+    .open(format!("pub fn count(&self) -> {count} {{"))
+    .line("self.count")
+    .close("}")
+    .blank()
+    .line("/// will return Some(current index) when successful otherwise None")
+    .open("pub fn advance(&mut self) -> SbeResult<Option<usize>> {")
+    .line("let index = self.index.wrapping_add(1);")
+    .open("if index >= self.count as usize {")
+    .line("return Ok(None);")
+    .close("}")
+    .open("if self.parent.is_none() {")
+    .line("return Err(SbeErr::ParentNotSet);")
+    .close("}")
+    .line("let offset = self.limit;")
+    .line(if block_length == 0 {
+        "self.buf.check_bounds(offset, self.block_length)?;".to_string()
+    } else {
+        format!("self.buf.check_bounds(offset, self.block_length.max({block_length}))?;")
+    })
+    .line("self.offset = offset;")
+    .line("self.limit = offset + self.block_length;")
+    .line("self.index = index;")
+    .line("Ok(Some(index))")
+    .close("}");
+    Ok(())
+}
+
+fn decoder_members(code: &mut Code, schema: &Schema, container: &Container) -> Result<()> {
+    for field in &container.block.fields {
+        code.blank();
+        let offset = offset_expr("self.offset", field.offset);
+        let name = to_snake(&field.name);
+        let since = field.since_version;
+        match (schema.field_kind(&field.type_name)?, field.presence) {
+            (FieldKind::Enum(e), Presence::Constant) => {
+                let Some(value) = &field.value_ref else {
+                    bail!("Constant field '{}' has no valueRef", field.name);
+                };
+                code.line("/// CONSTANT enum")
+                    .line("#[inline]")
+                    .open(format!("pub fn {name}(&self) -> {} {{", to_camel(&e.name)))
+                    .line(format!("{}::{value}", to_camel(&e.name)))
+                    .close("}");
+            }
+            (_, Presence::Constant) => bail!("Unsupported constant field '{}'", field.name),
+            (FieldKind::Enum(e), _) => {
+                let camel = to_camel(&e.name);
+                code.line("/// REQUIRED enum")
+                    .line("#[inline]")
+                    .open(format!("pub fn {name}(&self) -> {camel} {{"));
+                since_check(code, container, since, &format!("{camel}::default()"));
+                code.line(format!(
+                    "self.get_buf().get_{}_at({offset}).into()",
+                    e.encoding.rust()
+                ))
+                .close("}");
+            }
+            (FieldKind::Set(set), _) => {
+                let camel = to_camel(&set.name);
+                code.line("#[inline]")
+                    .open(format!("pub fn {name}(&self) -> {camel} {{"));
+                since_check(code, container, since, &format!("{camel}::default()"));
+                code.line(format!(
+                    "{camel}::new(self.get_buf().get_{}_at({offset}))",
+                    set.encoding.rust()
+                ))
+                .close("}");
+            }
+            (FieldKind::Encoded(t), _) if is_mantissa128(t) => {
+                code.line(format!(
+                    "/// mantissa128 field - little-endian i128 {{ null_value: '{MANTISSA128_NULL}' }}"
+                ))
+                .line("#[inline]")
+                .open(format!("pub fn {name}(&self) -> Option<i128> {{"));
+                since_check(code, container, since, "None");
+                code.line(format!("let value = self.get_buf().get_i128_at({offset});"))
+                    .open(format!("if value == {MANTISSA128_NULL}_i128 {{"))
+                    .line("None")
+                    .middle("} else {")
+                    .line("Some(value)")
+                    .close("}")
+                    .close("}");
+            }
+            (FieldKind::Encoded(t), _) if t.length != 1 => {
+                bail!("Unsupported array field '{}'", field.name)
+            }
+            (FieldKind::Encoded(t), Presence::Optional) => {
+                let rust = t.primitive.rust();
+                let null = t.primitive.null_value();
+                code.line(format!(
+                    "/// primitive field - 'OPTIONAL' {{ null_value: '{null}' }}"
+                ))
+                .line("#[inline]")
+                .open(format!("pub fn {name}(&self) -> Option<{rust}> {{"));
+                since_check(code, container, since, "None");
+                code.line(format!(
+                    "let value = self.get_buf().get_{rust}_at({offset});"
+                ));
+                if t.primitive.is_float() {
+                    code.open("if value.is_nan() {");
+                } else {
+                    code.open(format!("if value == {} {{", null_literal(t.primitive)));
+                }
+                code.line("None")
+                    .middle("} else {")
+                    .line("Some(value)")
+                    .close("}")
+                    .close("}");
+            }
+            (FieldKind::Encoded(t), _) => {
+                let rust = t.primitive.rust();
+                code.line("/// primitive field - 'REQUIRED'")
+                    .line("#[inline]")
+                    .open(format!("pub fn {name}(&self) -> {rust} {{"));
+                since_check(code, container, since, &null_literal(t.primitive));
+                code.line(format!("self.get_buf().get_{rust}_at({offset})"))
+                    .close("}");
+            }
+        }
+        if let Some((exponent, 0)) = container.exponent_of(field)? {
+            code.blank();
+            decimal_getter(code, schema, field, exponent, 0)?;
+        }
+    }
+    for group in &container.block.groups {
+        let snake = to_snake(&group.name);
+        let camel = to_camel(&group.name);
+        code.blank()
+            .line(format!("/// GROUP DECODER (id={})", group.id))
+            .line("#[inline]")
+            .open(format!(
+                "pub fn {snake}_decoder(self) -> SbeResult<{camel}Decoder<'a, Self>> {{"
+            ))
+            .line(format!("{camel}Decoder::default().wrap(self)"))
+            .close("}");
+    }
+    for data in &container.block.data {
+        data_decoder(code, schema, container, data)?;
+    }
+    Ok(())
+}
+
+/// Early return of `value` for members newer than the payload's
+/// `acting_version`, which would otherwise read past the acting block length.
+fn since_check(code: &mut Code, container: &Container, since: u16, value: &str) {
+    if since == 0 {
+        return;
+    }
+    let acting_version = if container.is_message() {
+        "self.acting_version"
+    } else {
+        "self.acting_version()"
+    };
+    code.open(format!("if {acting_version} < {since} {{"))
+        .line(format!("return {value};"))
+        .close("}")
+        .blank();
+}
+
+/// The primitive's null value as a suffixed Rust literal.
+fn null_literal(primitive: Primitive) -> String {
+    match primitive {
+        Primitive::U64 => "0xffffffffffffffff_u64".to_string(),
+        _ => format!("{}_{}", primitive.null_value(), primitive.rust()),
+    }
+}
+
+fn data_decoder(
+    code: &mut Code,
+    schema: &Schema,
+    container: &Container,
+    data: &Data,
+) -> Result<()> {
+    let composite = schema.composite(&data.type_name)?;
+    let (length_type, prefix, encoding) = data_layout(composite);
+    let name = to_snake(&data.name);
+    code.blank()
+        .line(format!(
+            "/// VAR_DATA DECODER - character encoding: '{encoding}'"
+        ))
+        .line("#[inline]")
+        .open(format!(
+            "pub fn {name}_decoder(&mut self) -> SbeResult<(usize, usize)> {{"
+        ));
+    if !container.is_message() {
+        code.open("if self.parent.is_none() {")
+            .line("return Err(SbeErr::ParentNotSet);")
+            .close("}");
+    }
+    code.line("let offset = self.get_limit();")
+        .line(format!("self.get_buf().check_bounds(offset, {prefix})?;"))
+        .line(format!(
+            "let data_length = self.get_buf().get_{length_type}_at(offset) as usize;"
+        ))
+        .line(format!(
+            "self.get_buf().check_bounds(offset + {prefix}, data_length)?;"
+        ))
+        .line(format!("self.set_limit(offset + {prefix} + data_length);"))
+        .line(format!("Ok((offset + {prefix}, data_length))"))
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open(format!(
+            "pub fn {name}_slice(&'a self, coordinates: (usize, usize)) -> SbeResult<&'a [u8]> {{"
+        ))
+        .line("self.get_buf().check_bounds(coordinates.0, coordinates.1)?;")
+        .line("Ok(self.get_buf().get_slice_at(coordinates.0, coordinates.1))")
+        .close("}");
+    Ok(())
+}
+
+fn decimal_getter(
+    code: &mut Code,
+    schema: &Schema,
+    field: &Field,
+    exponent: &Field,
+    depth: usize,
+) -> Result<()> {
+    let name = to_snake(&field.name);
+    let wide =
+        matches!(schema.field_kind(&field.type_name)?, FieldKind::Encoded(t) if is_mantissa128(t));
+    let mantissa_optional = field.presence == Presence::Optional || wide;
+    let exponent_optional = exponent.presence == Presence::Optional;
+    let exponent_expr = if depth == 0 {
+        format!("self.{}()", to_snake(&exponent.name))
+    } else {
+        "exponent".to_string()
+    };
+    let value = if mantissa_optional || exponent_optional {
+        "Option<Decimal>"
+    } else {
+        "Decimal"
+    };
+    // reading the exponent from an enclosing block fails once the group
+    // has been handed back to its parent
+    let returns = if depth > 0 {
+        format!("SbeResult<{value}>")
+    } else {
+        value.to_string()
+    };
+    let body = match (mantissa_optional, exponent_optional) {
+        (true, true) => format!(
+            "self.{name}()\n    .zip({exponent_expr})\n    .map(|(mantissa, exponent)| Decimal::new(mantissa, exponent))"
+        ),
+        (false, true) => {
+            format!("{exponent_expr}.map(|exponent| Decimal::new(self.{name}(), exponent))")
+        }
+        (true, false) => format!(
+            "self.{name}()\n    .map(|mantissa| Decimal::new(mantissa, {exponent_expr}))"
+        ),
+        (false, false) => format!("Decimal::new(self.{name}(), {exponent_expr})"),
+    };
+    code.line(format!(
+        "/// '{}' combined with its '{}' exponent field",
+        field.name, exponent.name
+    ))
+    .line("#[inline]")
+    .open(format!("pub fn {name}_decimal(&self) -> {returns} {{"));
+    if depth > 0 {
+        code.line(format!(
+            "let exponent = {}.{}();",
+            owner(depth),
+            to_snake(&exponent.name)
+        ))
+        .line(format!("Ok({body})"));
+    } else {
+        code.line(body);
+    }
+    code.close("}");
+    Ok(())
+}
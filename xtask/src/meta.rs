@@ -0,0 +1,205 @@
+//! `meta_tables.rs`: the schema as `spot_sbe::meta` statics.
+
+use crate::code::Code;
+use crate::schema::{to_upper_snake, Block, Field, FieldKind, Schema};
+use crate::GeneratedFile;
+use anyhow::{bail, Result};
+
+pub fn file(schema: &Schema, path: &str) -> Result<GeneratedFile> {
+    let mut code = Code::default();
+    code.line(format!("//! Generated from {path}, see `crate::meta`."))
+        .blank()
+        .line("use crate::meta::*;")
+        .blank()
+        .line(format!("pub const SCHEMA_ID: u16 = {};", schema.id))
+        .line(format!(
+            "pub const SCHEMA_VERSION: u16 = {};",
+            schema.version
+        ))
+        .blank();
+
+    for e in &schema.enums {
+        code.open(format!(
+            "pub static {}: EnumMeta = EnumMeta {{",
+            to_upper_snake(&e.name)
+        ))
+        .line(format!("name: \"{}\",", e.name))
+        .line(format!("encoding: Primitive::{},", e.encoding.meta()))
+        .line(format!("since_version: {},", e.since_version))
+        .open("values: &[");
+        for value in &e.values {
+            code.open("EnumValue {")
+                .line(format!("name: \"{}\",", value.name))
+                .line(format!("value: {},", value.value))
+                .line(format!("since_version: {},", value.since_version))
+                .line(format!("json_value: {},", optional(&value.json_value)))
+                .close("},");
+        }
+        code.close("],").close("};").blank();
+    }
+    for set in &schema.sets {
+        code.open(format!(
+            "pub static {}: SetMeta = SetMeta {{",
+            to_upper_snake(&set.name)
+        ))
+        .line(format!("name: \"{}\",", set.name))
+        .line(format!("encoding: Primitive::{},", set.encoding.meta()))
+        .line(format!("since_version: {},", set.since_version))
+        .open("choices: &[");
+        for choice in &set.choices {
+            code.open("SetChoice {")
+                .line(format!("name: \"{}\",", choice.name))
+                .line(format!("bit: {},", choice.bit))
+                .line(format!("json_value: {},", optional(&choice.json_value)))
+                .close("},");
+        }
+        code.close("],").close("};").blank();
+    }
+    for message in &schema.messages {
+        let description = message
+            .description
+            .as_ref()
+            .map(|description| description.replace('"', "\\\""));
+        code.open(format!(
+            "pub static {}: MessageMeta = MessageMeta {{",
+            to_upper_snake(&message.name)
+        ))
+        .line(format!("name: \"{}\",", message.name))
+        .line(format!("id: {},", message.id))
+        .line(format!("block_length: {},", message.block.block_length))
+        .line(format!("since_version: {},", message.since_version))
+        .line(format!("description: {},", optional(&description)))
+        .line(format!("event_type: {},", optional(&message.event_type)));
+        members(&mut code, schema, &message.block)?;
+        code.close("};").blank();
+    }
+
+    code.open("pub static ENUMS: &[&EnumMeta] = &[");
+    for e in &schema.enums {
+        code.line(format!("&{},", to_upper_snake(&e.name)));
+    }
+    code.close("];")
+        .blank()
+        .open("pub static SETS: &[&SetMeta] = &[");
+    for set in &schema.sets {
+        code.line(format!("&{},", to_upper_snake(&set.name)));
+    }
+    code.close("];")
+        .blank()
+        .open("pub static MESSAGES: &[&MessageMeta] = &[");
+    for message in &schema.messages {
+        code.line(format!("&{},", to_upper_snake(&message.name)));
+    }
+    code.close("];");
+
+    Ok(GeneratedFile {
+        name: "meta_tables.rs".into(),
+        contents: code.finish(),
+    })
+}
+
+fn members(code: &mut Code, schema: &Schema, block: &Block) -> Result<()> {
+    if block.fields.is_empty() {
+        code.line("fields: &[],");
+    } else {
+        code.open("fields: &[");
+        for field in &block.fields {
+            code.open("FieldMeta {")
+                .line(format!("name: \"{}\",", field.name))
+                .line(format!("id: {},", field.id))
+                .line(format!("field_type: {},", field_type(schema, field)?))
+                .line(format!("presence: Presence::{},", field.presence.meta()))
+                .line(format!("value_ref: {},", optional(&field.value_ref)))
+                .line(format!("offset: {},", field.offset))
+                .line(format!("length: {},", field.length))
+                .line(format!("since_version: {},", field.since_version))
+                .line(format!("exponent: {},", optional(&field.exponent)))
+                .line(format!("json_path: {},", optional(&field.json_path)))
+                .line(format!(
+                    "json_default_value: {},",
+                    optional(&field.json_default_value)
+                ))
+                .line(format!("json_omit_null: {},", field.json_omit_null))
+                .close("},");
+        }
+        code.close("],");
+    }
+    if block.groups.is_empty() {
+        code.line("groups: &[],");
+    } else {
+        code.open("groups: &[");
+        for group in &block.groups {
+            let dimension = schema.composite(&group.dimension_type)?;
+            let Some(num_in_group) = dimension.parts.get(1) else {
+                bail!("Dimension type '{}' has no numInGroup", dimension.name);
+            };
+            code.open("GroupMeta {")
+                .line(format!("name: \"{}\",", group.name))
+                .line(format!("id: {},", group.id))
+                .line(format!("block_length: {},", group.block.block_length))
+                .line(format!(
+                    "num_in_group_type: Primitive::{},",
+                    num_in_group.primitive.meta()
+                ))
+                .line(format!("since_version: {},", group.since_version))
+                .line(format!("json_path: {},", optional(&group.json_path)))
+                .line(format!("json_omit_null: {},", group.json_omit_null));
+            members(code, schema, &group.block)?;
+            code.close("},");
+        }
+        code.close("],");
+    }
+    if block.data.is_empty() {
+        code.line("data: &[],");
+    } else {
+        code.open("data: &[");
+        for data in &block.data {
+            let composite = schema.composite(&data.type_name)?;
+            let data_type = match data.type_name.as_str() {
+                "varString" | "varString8" => "String",
+                "optionalVarString" | "optionalVarString8" => "OptionalString",
+                "messageData" | "messageData8" | "messageData16" => "Message",
+                "optionalMessageData" | "optionalMessageData16" => "OptionalMessage",
+                other => bail!("Unsupported var data type '{other}'"),
+            };
+            code.open("DataMeta {")
+                .line(format!("name: \"{}\",", data.name))
+                .line(format!("id: {},", data.id))
+                .line(format!("data_type: DataType::{data_type},"))
+                .line(format!(
+                    "length_type: Primitive::{},",
+                    composite.parts[0].primitive.meta()
+                ))
+                .line(format!("since_version: {},", data.since_version))
+                .line(format!("json_path: {},", optional(&data.json_path)))
+                .close("},");
+        }
+        code.close("],");
+    }
+    Ok(())
+}
+
+fn field_type(schema: &Schema, field: &Field) -> Result<String> {
+    Ok(
+        match (
+            schema.field_kind(&field.type_name)?,
+            field.type_name.as_str(),
+        ) {
+            (FieldKind::Enum(e), _) => format!("FieldType::Enum(&{})", to_upper_snake(&e.name)),
+            (FieldKind::Set(set), _) => format!("FieldType::Set(&{})", to_upper_snake(&set.name)),
+            (_, "mantissa64") => "FieldType::Mantissa64".into(),
+            (_, "mantissa128") => "FieldType::Mantissa128".into(),
+            (_, "exponent8") => "FieldType::Exponent".into(),
+            (FieldKind::Encoded(t), _) => {
+                format!("FieldType::Primitive(Primitive::{})", t.primitive.meta())
+            }
+        },
+    )
+}
+
+fn optional(value: &Option<String>) -> String {
+    match value {
+        Some(value) => format!("Some(\"{value}\")"),
+        None => "None".into(),
+    }
+}
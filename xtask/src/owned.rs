@@ -0,0 +1,494 @@
+//! The `owned` module of a message codec: serde structs for the message and
+//! each of its groups, converting to and from the flyweight codecs.
+
+use crate::code::Code;
+use crate::message::Container;
+use crate::schema::{to_camel, to_snake, Block, Field, FieldKind, Message, Presence, Schema};
+use anyhow::{bail, Result};
+
+/// One field of an owned struct, with the statements reading and writing it.
+struct Member {
+    name: String,
+    schema_name: String,
+    rust_type: String,
+    kind: MemberKind,
+}
+
+enum MemberKind {
+    Field {
+        decode: String,
+        encode: Vec<String>,
+    },
+    Group {
+        codec: String,
+        count: &'static str,
+        header: usize,
+    },
+    Data {
+        prefix: usize,
+        length_type: &'static str,
+        string: bool,
+        optional: bool,
+    },
+}
+
+fn field_member(schema: &Schema, container: &Container, field: &Field) -> Result<Member> {
+    let name = to_snake(&field.name);
+    let optional = field.presence == Presence::Optional;
+    let (rust_type, decode, encode) = match schema.field_kind(&field.type_name)? {
+        FieldKind::Encoded(t) if field.exponent.is_some() => {
+            let Some((exponent, depth)) = container.exponent_of(field)? else {
+                unreachable!("field has an exponent");
+            };
+            let wide = t.name == "mantissa128";
+            let decoded_optional = decimal_optional(schema, field, exponent)?;
+            let encoded_optional = optional || wide;
+            let encode = if decoded_optional == encoded_optional {
+                vec![format!("encoder.{name}_decimal(self.{name})?;")]
+            } else {
+                vec![
+                    format!("match self.{name} {{"),
+                    format!("    Some(value) => encoder.{name}_decimal(value)?,"),
+                    format!("    None => encoder.{name}(0),"),
+                    "}".to_string(),
+                ]
+            };
+            let rust_type = if decoded_optional {
+                "Option<Decimal>"
+            } else {
+                "Decimal"
+            };
+            (
+                rust_type.to_string(),
+                format!(
+                    "decoder.{name}_decimal(){}",
+                    if depth > 0 { "?" } else { "" }
+                ),
+                encode,
+            )
+        }
+        FieldKind::Enum(e) => (
+            to_camel(&e.name),
+            format!("decoder.{name}()"),
+            vec![format!("encoder.{name}(self.{name});")],
+        ),
+        FieldKind::Set(set) => (
+            to_camel(&set.name),
+            format!("decoder.{name}()"),
+            vec![format!("encoder.{name}(self.{name});")],
+        ),
+        FieldKind::Encoded(t) if t.length != 1 => {
+            bail!("Unsupported array field '{}'", field.name)
+        }
+        FieldKind::Encoded(t) => {
+            let rust = t.primitive.rust();
+            if optional {
+                (
+                    format!("Option<{rust}>"),
+                    format!("decoder.{name}()"),
+                    vec![format!(
+                        "encoder.{name}(self.{name}.unwrap_or({}));",
+                        t.primitive.null_const()
+                    )],
+                )
+            } else {
+                (
+                    rust.to_string(),
+                    format!("decoder.{name}()"),
+                    vec![format!("encoder.{name}(self.{name});")],
+                )
+            }
+        }
+    };
+    Ok(Member {
+        name,
+        schema_name: field.name.clone(),
+        rust_type,
+        kind: MemberKind::Field { decode, encode },
+    })
+}
+
+/// Whether the owned form of a decimal `field` is an `Option<Decimal>`.
+fn decimal_optional(schema: &Schema, field: &Field, exponent: &Field) -> Result<bool> {
+    let wide = matches!(schema.field_kind(&field.type_name)?, FieldKind::Encoded(t) if t.name == "mantissa128");
+    Ok(field.presence == Presence::Optional || wide || exponent.presence == Presence::Optional)
+}
+
+/// Iterators over the decimals scaled by `exponent`, a field of `block`,
+/// reached from `entries` (`self` for the block itself). Groups declaring
+/// their own field of that name are skipped, as it shadows this one.
+fn scaled_values(
+    schema: &Schema,
+    block: &Block,
+    exponent: &Field,
+    entries: Option<&str>,
+    terms: &mut Vec<String>,
+) -> Result<()> {
+    for field in &block.fields {
+        if field.exponent.as_deref() != Some(exponent.name.as_str()) {
+            continue;
+        }
+        let name = to_snake(&field.name);
+        let optional = decimal_optional(schema, field, exponent)?;
+        terms.push(match (entries, optional) {
+            (None, false) => format!("[self.{name}]"),
+            (None, true) => format!("self.{name}"),
+            (Some(entries), false) => format!("{entries}.map(|entry| entry.{name})"),
+            (Some(entries), true) => format!("{entries}.filter_map(|entry| entry.{name})"),
+        });
+    }
+    for group in &block.groups {
+        if group.block.fields.iter().any(|f| f.name == exponent.name) {
+            continue;
+        }
+        let name = to_snake(&group.name);
+        let entries = match entries {
+            None => format!("self.{name}.iter()"),
+            Some(entries) => format!("{entries}.flat_map(|entry| &entry.{name})"),
+        };
+        scaled_values(schema, &group.block, exponent, Some(&entries), terms)?;
+    }
+    Ok(())
+}
+
+/// Statements writing an exponent field lowered to fit the decimals it
+/// scales, or `None` if it scales none.
+fn fitted_exponent(
+    schema: &Schema,
+    block: &Block,
+    exponent: &Field,
+) -> Result<Option<Vec<String>>> {
+    let mut terms = Vec::new();
+    scaled_values(schema, block, exponent, None, &mut terms)?;
+    // one array for the required values of the block itself
+    let (arrays, others): (Vec<_>, Vec<_>) = terms.into_iter().partition(|t| t.starts_with('['));
+    let elements: Vec<_> = arrays.iter().map(|t| &t[1..t.len() - 1]).collect();
+    let mut terms = (!elements.is_empty())
+        .then(|| format!("[{}]", elements.join(", ")))
+        .into_iter()
+        .chain(others);
+    let Some(mut values) = terms.next() else {
+        return Ok(None);
+    };
+    let rest: Vec<_> = terms.collect();
+    // arrays and options must become iterators to chain, group entries already are
+    if !rest.is_empty() && !values.ends_with(')') {
+        values.push_str(".into_iter()");
+    }
+    for term in rest {
+        values.push_str(&format!(".chain({term})"));
+    }
+    let name = to_snake(&exponent.name);
+    Ok(Some(if exponent.presence == Presence::Optional {
+        vec![format!(
+            "encoder.{name}(fit_optional_exponent(self.{name}, {values}).unwrap_or(i8::MIN));"
+        )]
+    } else {
+        vec![format!(
+            "encoder.{name}(fit_exponent(self.{name}, {values}));"
+        )]
+    }))
+}
+
+fn members(schema: &Schema, container: &Container) -> Result<Vec<Member>> {
+    let block = container.block;
+    let mut members = Vec::new();
+    for field in &block.fields {
+        if field.presence != Presence::Constant {
+            let mut member = field_member(schema, container, field)?;
+            if let Some(fitted) = fitted_exponent(schema, block, field)? {
+                if let MemberKind::Field { encode, .. } = &mut member.kind {
+                    *encode = fitted;
+                }
+            }
+            members.push(member);
+        }
+    }
+    for group in &block.groups {
+        let dimension = schema.composite(&group.dimension_type)?;
+        members.push(Member {
+            name: to_snake(&group.name),
+            schema_name: group.name.clone(),
+            rust_type: format!("Vec<{}>", to_camel(&group.name)),
+            kind: MemberKind::Group {
+                codec: to_camel(&group.name),
+                count: dimension.parts[1].primitive.rust(),
+                header: dimension.size(),
+            },
+        });
+    }
+    for data in &block.data {
+        let length = &schema.composite(&data.type_name)?.parts[0];
+        let string = matches!(
+            data.type_name.as_str(),
+            "varString" | "varString8" | "optionalVarString" | "optionalVarString8"
+        );
+        let optional = data.type_name.starts_with("optional");
+        let inner = if string { "String" } else { "Vec<u8>" };
+        members.push(Member {
+            name: to_snake(&data.name),
+            schema_name: data.name.clone(),
+            rust_type: if optional {
+                format!("Option<{inner}>")
+            } else {
+                inner.to_string()
+            },
+            kind: MemberKind::Data {
+                prefix: length.size(),
+                length_type: length.primitive.rust(),
+                string,
+                optional,
+            },
+        });
+    }
+    Ok(members)
+}
+
+/// How serde's `camelCase` would spell a snake case member name.
+fn serde_camel(name: &str) -> String {
+    let mut parts = name.split('_');
+    let mut camel = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            camel.extend(first.to_uppercase());
+            camel.push_str(chars.as_str());
+        }
+    }
+    camel
+}
+
+fn owned_struct(code: &mut Code, name: &str, members: &[Member], description: Option<&str>) {
+    if let Some(description) = description {
+        for line in description.trim().lines() {
+            code.line(format!("/// {}", line.trim()).trim_end());
+        }
+    }
+    code.line("#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]")
+        .line("#[serde(rename_all = \"camelCase\")]")
+        .open(format!("pub struct {name} {{"));
+    for member in members {
+        if serde_camel(&member.name) != member.schema_name {
+            code.line(format!("#[serde(rename = \"{}\")]", member.schema_name));
+        }
+        code.line(format!("pub {}: {},", member.name, member.rust_type));
+    }
+    code.close("}");
+}
+
+fn decode_body(code: &mut Code, members: &[Member]) {
+    code.open("Ok(Self {");
+    for member in members {
+        let name = &member.name;
+        match &member.kind {
+            MemberKind::Field { decode, .. } => {
+                code.line(format!("{name}: {decode},"));
+            }
+            MemberKind::Group { codec, .. } => {
+                let decoder = format!("{name}_decoder");
+                code.open(format!("{name}: {{"))
+                    .line(format!(
+                        "let mut {decoder} = core::mem::take(decoder).{decoder}()?;"
+                    ))
+                    .line("let mut entries = Vec::new();")
+                    .open(format!("while {decoder}.advance()?.is_some() {{"))
+                    .line(format!(
+                        "entries.push({codec}::from_decoder(&mut {decoder})?);"
+                    ))
+                    .close("}")
+                    .line(format!("*decoder = {decoder}.parent()?;"))
+                    .line("entries")
+                    .close("},");
+            }
+            MemberKind::Data {
+                string, optional, ..
+            } => {
+                let value = if *string {
+                    "core::str::from_utf8(slice).map_err(|_| SbeErr::InvalidUtf8)?.to_owned()"
+                } else {
+                    "slice.to_vec()"
+                };
+                code.open(format!("{name}: {{"))
+                    .line(format!("let coordinates = decoder.{name}_decoder()?;"))
+                    .line(format!("let slice = decoder.{name}_slice(coordinates)?;"));
+                if *optional {
+                    code.open("if slice.is_empty() {")
+                        .line("None")
+                        .middle("} else {")
+                        .line(format!("Some({value})"))
+                        .close("}");
+                } else {
+                    code.line(value);
+                }
+                code.close("},");
+            }
+        }
+    }
+    code.close("})");
+}
+
+fn encode_body(code: &mut Code, members: &[Member]) {
+    for member in members {
+        let name = &member.name;
+        match &member.kind {
+            MemberKind::Field { encode, .. } => {
+                for line in encode {
+                    code.line(line);
+                }
+            }
+            MemberKind::Group { codec, count, .. } => {
+                let encoder = format!("{name}_encoder");
+                code.line(format!(
+                    "let count = checked_length::<{count}>(self.{name}.len())?;"
+                ))
+                .line(format!(
+                    "let mut {encoder} = core::mem::take(encoder).{encoder}(count, {codec}Encoder::default());"
+                ))
+                .open(format!("for entry in &self.{name} {{"))
+                .line(format!("{encoder}.advance()?;"))
+                .line(format!("entry.to_encoder(&mut {encoder})?;"))
+                .close("}")
+                .line(format!("*encoder = {encoder}.parent()?;"));
+            }
+            MemberKind::Data {
+                length_type,
+                optional,
+                ..
+            } => {
+                let value = if *optional {
+                    format!("self.{name}.as_deref().unwrap_or_default()")
+                } else {
+                    format!("&self.{name}")
+                };
+                code.line(format!("let value = {value};"))
+                    .line(format!("checked_length::<{length_type}>(value.len())?;"))
+                    .line(format!("encoder.{name}(value);"));
+            }
+        }
+    }
+    code.line("Ok(())");
+}
+
+fn encoded_length(code: &mut Code, block: &Block, members: &[Member]) {
+    let mut fixed = block.block_length;
+    let mut terms = Vec::new();
+    for member in members {
+        let name = &member.name;
+        match &member.kind {
+            MemberKind::Field { .. } => {}
+            MemberKind::Group { codec, header, .. } => {
+                fixed += header;
+                terms.push(format!(
+                    "self.{name}.iter().map({codec}::encoded_length).sum::<usize>()"
+                ));
+            }
+            MemberKind::Data {
+                prefix, optional, ..
+            } => {
+                fixed += prefix;
+                terms.push(if *optional {
+                    format!("self.{name}.as_ref().map_or(0, |value| value.len())")
+                } else {
+                    format!("self.{name}.len()")
+                });
+            }
+        }
+    }
+    let mut expression = fixed.to_string();
+    for term in terms {
+        expression.push_str(" + ");
+        expression.push_str(&term);
+    }
+    code.line(expression);
+}
+
+pub fn module(
+    code: &mut Code,
+    schema: &Schema,
+    message: &Message,
+    containers: &[Container],
+) -> Result<()> {
+    code.line("#[cfg(feature = \"serde\")]")
+        .open("pub mod owned {")
+        .line("use super::decoder::*;")
+        .line("use super::encoder::*;")
+        .line("use super::*;")
+        .line("use serde::{Deserialize, Serialize};");
+
+    for container in containers {
+        let name = &container.name;
+        let members = members(schema, container)?;
+        code.blank();
+        if container.is_message() {
+            let argument = if members.is_empty() { "_" } else { "" };
+            owned_struct(code, name, &members, message.description.as_deref());
+            code.blank()
+                .open(format!("impl {name} {{"))
+                .open(format!(
+                    "pub fn from_decoder({argument}decoder: &mut {name}Decoder<'_>) -> SbeResult<Self> {{"
+                ));
+            decode_body(code, &members);
+            code.close("}")
+                .blank()
+                .open(format!(
+                    "pub fn to_encoder(&self, {argument}encoder: &mut {name}Encoder<'_>) -> SbeResult<()> {{"
+                ));
+            encode_body(code, &members);
+            code.close("}")
+                .blank()
+                .line("/// Length of the message excluding its header")
+                .open("pub fn encoded_length(&self) -> usize {");
+            encoded_length(code, container.block, &members);
+            code.close("}")
+                .close("}")
+                .blank()
+                .open(format!("impl OwnedMessage for {name} {{"))
+                .line("const SBE_TEMPLATE_ID: u16 = SBE_TEMPLATE_ID;")
+                .blank()
+                .open("fn decode(data: &[u8]) -> SbeResult<Self> {")
+                .line("let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(data), 0)?;")
+                .line(format!(
+                    "let mut decoder = {name}Decoder::default().header(header)?;"
+                ))
+                .line("Self::from_decoder(&mut decoder)")
+                .close("}")
+                .blank()
+                .open("fn encode(&self) -> SbeResult<Vec<u8>> {")
+                .line("let mut data = vec![0; message_header_codec::ENCODED_LENGTH + self.encoded_length()];")
+                .line(format!(
+                    "let encoder = {name}Encoder::default().wrap(WriteBuf::new(&mut data), message_header_codec::ENCODED_LENGTH);"
+                ))
+                .line("let mut encoder = encoder.header(0).parent()?;")
+                .line("self.to_encoder(&mut encoder)?;")
+                .line("Ok(data)")
+                .close("}")
+                .close("}");
+        } else {
+            let decoder_type = &container.decoder_type;
+            let encoder_type = container.encoder_type.replace("'a", "'_");
+            owned_struct(code, name, &members, None);
+            code.blank()
+                .open(format!("impl {name} {{"))
+                .line("/// Reads the entry `decoder` has advanced to.")
+                .open(format!(
+                    "pub fn from_decoder<'a>(decoder: &mut {decoder_type}) -> SbeResult<Self> {{"
+                ));
+            decode_body(code, &members);
+            code.close("}")
+                .blank()
+                .line("/// Writes the entry `encoder` has advanced to.")
+                .open(format!(
+                    "pub fn to_encoder(&self, encoder: &mut {encoder_type}) -> SbeResult<()> {{"
+                ));
+            encode_body(code, &members);
+            code.close("}")
+                .blank()
+                .line("/// Length of the entry including its groups and var data")
+                .open("pub fn encoded_length(&self) -> usize {");
+            encoded_length(code, container.block, &members);
+            code.close("}").close("}");
+        }
+    }
+    code.close("} // end owned");
+    Ok(())
+}
@@ -0,0 +1,629 @@
+//! In-memory form of an SBE message schema, resolved enough for code
+//! generation: field offsets, block lengths and effective `sinceVersion`s are
+//! computed here so the generators only deal with layout they can print.
+
+use anyhow::{anyhow, bail, Context, Result};
+use roxmltree::{Document, Node};
+
+const PRIMITIVES: [&str; 11] = [
+    "char", "int8", "uint8", "int16", "uint16", "int32", "uint32", "int64", "uint64", "float",
+    "double",
+];
+
+const MBX: &str = "https://developers.binance.com/docs/binance-spot-api-docs";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Primitive {
+    Char,
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    F32,
+    F64,
+}
+
+impl Primitive {
+    fn parse(name: &str) -> Result<Self> {
+        Ok(match name {
+            "char" => Self::Char,
+            "int8" => Self::I8,
+            "uint8" => Self::U8,
+            "int16" => Self::I16,
+            "uint16" => Self::U16,
+            "int32" => Self::I32,
+            "uint32" => Self::U32,
+            "int64" => Self::I64,
+            "uint64" => Self::U64,
+            "float" => Self::F32,
+            "double" => Self::F64,
+            _ => bail!("Unknown primitive type '{name}'"),
+        })
+    }
+
+    pub fn size(self) -> usize {
+        match self {
+            Self::Char | Self::I8 | Self::U8 => 1,
+            Self::I16 | Self::U16 => 2,
+            Self::I32 | Self::U32 | Self::F32 => 4,
+            Self::I64 | Self::U64 | Self::F64 => 8,
+        }
+    }
+
+    /// Rust type used for the primitive by the codecs.
+    pub fn rust(self) -> &'static str {
+        match self {
+            Self::Char | Self::U8 => "u8",
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::U16 => "u16",
+            Self::I32 => "i32",
+            Self::U32 => "u32",
+            Self::I64 => "i64",
+            Self::U64 => "u64",
+            Self::F32 => "f32",
+            Self::F64 => "f64",
+        }
+    }
+
+    /// Variant name in `spot_sbe::meta::Primitive`.
+    pub fn meta(self) -> &'static str {
+        match self {
+            Self::Char => "Char",
+            Self::I8 => "I8",
+            Self::U8 => "U8",
+            Self::I16 => "I16",
+            Self::U16 => "U16",
+            Self::I32 => "I32",
+            Self::U32 => "U32",
+            Self::I64 => "I64",
+            Self::U64 => "U64",
+            Self::F32 => "F32",
+            Self::F64 => "F64",
+        }
+    }
+
+    /// Default `minValue`, as printed by sbetool.
+    pub fn min_value(self) -> &'static str {
+        match self {
+            Self::Char => "32",
+            Self::I8 => "-127",
+            Self::U8 | Self::U16 | Self::U32 | Self::U64 => "0",
+            Self::I16 => "-32767",
+            Self::I32 => "-2147483647",
+            Self::I64 => "-9223372036854775807",
+            Self::F32 => "1.401298464324817E-45",
+            Self::F64 => "4.9E-324",
+        }
+    }
+
+    /// Default `maxValue`, as printed by sbetool.
+    pub fn max_value(self) -> &'static str {
+        match self {
+            Self::Char => "126",
+            Self::I8 => "127",
+            Self::U8 => "254",
+            Self::I16 => "32767",
+            Self::U16 => "65534",
+            Self::I32 => "2147483647",
+            Self::U32 => "4294967294",
+            Self::I64 => "9223372036854775807",
+            Self::U64 => "-2",
+            Self::F32 => "3.4028234663852886E38",
+            Self::F64 => "1.7976931348623157E308",
+        }
+    }
+
+    /// Default `nullValue`, as printed by sbetool.
+    pub fn null_value(self) -> &'static str {
+        match self {
+            Self::Char => "0",
+            Self::I8 => "-128",
+            Self::U8 => "255",
+            Self::I16 => "-32768",
+            Self::U16 => "65535",
+            Self::I32 => "-2147483648",
+            Self::U32 => "4294967295",
+            Self::I64 => "-9223372036854775808",
+            Self::U64 => "-1",
+            Self::F32 | Self::F64 => "NaN",
+        }
+    }
+
+    /// Null sentinel as a Rust expression of the primitive's type.
+    pub fn null_const(self) -> String {
+        match self {
+            Self::F32 | Self::F64 => format!("{}::NAN", self.rust()),
+            Self::Char | Self::U8 | Self::U16 | Self::U32 | Self::U64 => {
+                format!("{}::MAX", self.rust())
+            }
+            _ => format!("{}::MIN", self.rust()),
+        }
+    }
+
+    pub fn is_float(self) -> bool {
+        matches!(self, Self::F32 | Self::F64)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Presence {
+    Required,
+    Optional,
+    Constant,
+}
+
+impl Presence {
+    fn parse(value: Option<&str>) -> Result<Self> {
+        Ok(match value {
+            None | Some("required") => Self::Required,
+            Some("optional") => Self::Optional,
+            Some("constant") => Self::Constant,
+            Some(other) => bail!("Unknown presence '{other}'"),
+        })
+    }
+
+    pub fn meta(self) -> &'static str {
+        match self {
+            Self::Required => "Required",
+            Self::Optional => "Optional",
+            Self::Constant => "Constant",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct EncodedType {
+    pub name: String,
+    pub primitive: Primitive,
+    pub length: usize,
+    pub max_value: Option<String>,
+    pub character_encoding: Option<String>,
+    pub since_version: u16,
+}
+
+impl EncodedType {
+    pub fn size(&self) -> usize {
+        self.primitive.size() * self.length
+    }
+}
+
+#[derive(Debug)]
+pub struct EnumType {
+    pub name: String,
+    pub encoding: Primitive,
+    pub since_version: u16,
+    pub values: Vec<EnumValue>,
+}
+
+#[derive(Debug)]
+pub struct EnumValue {
+    pub name: String,
+    pub value: u64,
+    pub since_version: u16,
+    pub json_value: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct SetType {
+    pub name: String,
+    pub encoding: Primitive,
+    pub since_version: u16,
+    pub choices: Vec<SetChoice>,
+}
+
+#[derive(Debug)]
+pub struct SetChoice {
+    pub name: String,
+    pub bit: u8,
+    pub json_value: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Composite {
+    pub name: String,
+    pub parts: Vec<EncodedType>,
+}
+
+impl Composite {
+    /// Length of the fixed part; var data parts have `length="0"`.
+    pub fn size(&self) -> usize {
+        self.parts.iter().map(EncodedType::size).sum()
+    }
+
+    pub fn is_var_data(&self) -> bool {
+        self.parts.iter().any(|part| part.length == 0)
+    }
+}
+
+/// Type of a field in a message or group block.
+#[derive(Clone, Copy, Debug)]
+pub enum FieldKind<'a> {
+    Encoded(&'a EncodedType),
+    Enum(&'a EnumType),
+    Set(&'a SetType),
+}
+
+#[derive(Debug)]
+pub struct Field {
+    pub name: String,
+    pub id: u16,
+    pub type_name: String,
+    pub presence: Presence,
+    /// Enum value name of a `presence="constant"` field
+    pub value_ref: Option<String>,
+    pub offset: usize,
+    pub length: usize,
+    /// The field's own `sinceVersion`, or its type's if that is later
+    pub since_version: u16,
+    pub exponent: Option<String>,
+    pub json_path: Option<String>,
+    pub json_default_value: Option<String>,
+    pub json_omit_null: bool,
+}
+
+#[derive(Debug)]
+pub struct Group {
+    pub name: String,
+    pub id: u16,
+    pub dimension_type: String,
+    pub since_version: u16,
+    pub json_path: Option<String>,
+    pub json_omit_null: bool,
+    pub block: Block,
+}
+
+#[derive(Debug)]
+pub struct Data {
+    pub name: String,
+    pub id: u16,
+    pub type_name: String,
+    pub since_version: u16,
+    pub json_path: Option<String>,
+}
+
+/// Members shared by messages and repeating groups.
+#[derive(Debug, Default)]
+pub struct Block {
+    pub block_length: usize,
+    pub fields: Vec<Field>,
+    pub groups: Vec<Group>,
+    pub data: Vec<Data>,
+}
+
+#[derive(Debug)]
+pub struct Message {
+    pub name: String,
+    pub id: u16,
+    pub since_version: u16,
+    pub description: Option<String>,
+    /// The `"e"` of a user data stream event, named by the comment before
+    /// the message, e.g. `<!-- "executionReport" -->`
+    pub event_type: Option<String>,
+    pub block: Block,
+}
+
+#[derive(Debug)]
+pub struct Schema {
+    pub id: u16,
+    pub version: u16,
+    pub semantic_version: String,
+    pub encoded_types: Vec<EncodedType>,
+    pub enums: Vec<EnumType>,
+    pub sets: Vec<SetType>,
+    pub composites: Vec<Composite>,
+    pub messages: Vec<Message>,
+}
+
+impl Schema {
+    pub fn parse(xml: &str) -> Result<Self> {
+        let document = Document::parse(xml).context("Malformed schema XML")?;
+        let root = document.root_element();
+        let mut schema = Schema {
+            id: number(root, "id")?,
+            version: number(root, "version")?,
+            semantic_version: root.attribute("semanticVersion").unwrap_or("").to_owned(),
+            encoded_types: Vec::new(),
+            enums: Vec::new(),
+            sets: Vec::new(),
+            composites: Vec::new(),
+            messages: Vec::new(),
+        };
+        // Fields may name a primitive type directly, e.g. `type="int64"`
+        for primitive in PRIMITIVES {
+            schema.encoded_types.push(EncodedType {
+                name: primitive.into(),
+                primitive: Primitive::parse(primitive)?,
+                length: 1,
+                max_value: None,
+                character_encoding: None,
+                since_version: 0,
+            });
+        }
+        for types in root.children().filter(|node| node.has_tag_name("types")) {
+            for node in types.children().filter(Node::is_element) {
+                match node.tag_name().name() {
+                    "type" => schema.encoded_types.push(encoded_type(node)?),
+                    "enum" => schema.enums.push(enum_type(node)?),
+                    "set" => schema.sets.push(set_type(node)?),
+                    "composite" => schema.composites.push(Composite {
+                        name: name(node)?,
+                        parts: node
+                            .children()
+                            .filter(Node::is_element)
+                            .map(encoded_type)
+                            .collect::<Result<_>>()?,
+                    }),
+                    other => bail!("Unsupported type element <{other}>"),
+                }
+            }
+        }
+        for node in root.children().filter(|node| node.has_tag_name("message")) {
+            let message = Message {
+                name: name(node)?,
+                id: number(node, "id")?,
+                since_version: optional_number(node, "sinceVersion")?,
+                description: node.attribute("description").map(str::to_owned),
+                event_type: event_type(node),
+                block: schema.block(node)?,
+            };
+            schema.messages.push(message);
+        }
+        Ok(schema)
+    }
+
+    fn block(&self, node: Node) -> Result<Block> {
+        let mut block = Block::default();
+        for child in node.children().filter(Node::is_element) {
+            let name = name(child)?;
+            match child.tag_name().name() {
+                "field" => {
+                    let type_name = attribute(child, "type")?.to_owned();
+                    let kind = self
+                        .field_kind(&type_name)
+                        .with_context(|| format!("Field '{name}'"))?;
+                    let presence = Presence::parse(child.attribute("presence"))?;
+                    let length = match (presence, kind) {
+                        (Presence::Constant, _) => 0,
+                        (_, FieldKind::Encoded(t)) => t.size(),
+                        (_, FieldKind::Enum(t)) => t.encoding.size(),
+                        (_, FieldKind::Set(t)) => t.encoding.size(),
+                    };
+                    let type_since = match kind {
+                        FieldKind::Encoded(t) => t.since_version,
+                        FieldKind::Enum(t) => t.since_version,
+                        FieldKind::Set(t) => t.since_version,
+                    };
+                    block.fields.push(Field {
+                        name,
+                        id: number(child, "id")?,
+                        type_name,
+                        presence,
+                        value_ref: child
+                            .attribute("valueRef")
+                            .map(|value| value.split_once('.').map_or(value, |(_, v)| v))
+                            .map(str::to_owned),
+                        offset: block.block_length,
+                        length,
+                        since_version: optional_number(child, "sinceVersion")?.max(type_since),
+                        exponent: mbx(child, "exponent"),
+                        json_path: mbx(child, "jsonPath"),
+                        json_default_value: mbx(child, "jsonDefaultValue"),
+                        json_omit_null: mbx(child, "jsonOmitNull").as_deref() == Some("true"),
+                    });
+                    block.block_length += length;
+                }
+                "group" => {
+                    let dimension_type = child
+                        .attribute("dimensionType")
+                        .unwrap_or("groupSizeEncoding")
+                        .to_owned();
+                    self.composite(&dimension_type)?;
+                    block.groups.push(Group {
+                        name,
+                        id: number(child, "id")?,
+                        dimension_type,
+                        since_version: optional_number(child, "sinceVersion")?,
+                        json_path: mbx(child, "jsonPath"),
+                        json_omit_null: mbx(child, "jsonOmitNull").as_deref() == Some("true"),
+                        block: self.block(child)?,
+                    });
+                }
+                "data" => {
+                    let type_name = attribute(child, "type")?.to_owned();
+                    self.composite(&type_name)?;
+                    block.data.push(Data {
+                        name,
+                        id: number(child, "id")?,
+                        type_name,
+                        since_version: optional_number(child, "sinceVersion")?,
+                        json_path: mbx(child, "jsonPath"),
+                    });
+                }
+                other => bail!("Unsupported message member <{other}>"),
+            }
+        }
+        Ok(block)
+    }
+
+    pub fn field_kind(&self, type_name: &str) -> Result<FieldKind<'_>> {
+        if let Some(t) = self.encoded_types.iter().find(|t| t.name == type_name) {
+            return Ok(FieldKind::Encoded(t));
+        }
+        if let Some(t) = self.enums.iter().find(|t| t.name == type_name) {
+            return Ok(FieldKind::Enum(t));
+        }
+        if let Some(t) = self.sets.iter().find(|t| t.name == type_name) {
+            return Ok(FieldKind::Set(t));
+        }
+        Err(anyhow!("Unknown type '{type_name}'"))
+    }
+
+    pub fn composite(&self, name: &str) -> Result<&Composite> {
+        self.composites
+            .iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| anyhow!("Unknown composite '{name}'"))
+    }
+}
+
+fn encoded_type(node: Node) -> Result<EncodedType> {
+    Ok(EncodedType {
+        name: name(node)?,
+        primitive: Primitive::parse(attribute(node, "primitiveType")?)?,
+        length: match node.attribute("length") {
+            Some(length) => length.parse().context("Invalid length")?,
+            None => 1,
+        },
+        max_value: node.attribute("maxValue").map(str::to_owned),
+        character_encoding: node.attribute("characterEncoding").map(str::to_owned),
+        since_version: optional_number(node, "sinceVersion")?,
+    })
+}
+
+fn enum_type(node: Node) -> Result<EnumType> {
+    let values = node
+        .children()
+        .filter(|child| child.has_tag_name("validValue"))
+        .map(|child| {
+            Ok(EnumValue {
+                name: name(child)?,
+                value: text(child)?.parse().context("Invalid enum value")?,
+                since_version: optional_number(child, "sinceVersion")?,
+                json_value: mbx(child, "jsonValue"),
+            })
+        })
+        .collect::<Result<_>>()?;
+    Ok(EnumType {
+        name: name(node)?,
+        encoding: Primitive::parse(attribute(node, "encodingType")?)?,
+        since_version: optional_number(node, "sinceVersion")?,
+        values,
+    })
+}
+
+fn set_type(node: Node) -> Result<SetType> {
+    let choices = node
+        .children()
+        .filter(|child| child.has_tag_name("choice"))
+        .map(|child| {
+            Ok(SetChoice {
+                name: name(child)?,
+                bit: text(child)?.parse().context("Invalid choice bit")?,
+                json_value: mbx(child, "jsonValue"),
+            })
+        })
+        .collect::<Result<_>>()?;
+    Ok(SetType {
+        name: name(node)?,
+        encoding: Primitive::parse(attribute(node, "encodingType")?)?,
+        since_version: optional_number(node, "sinceVersion")?,
+        choices,
+    })
+}
+
+fn attribute<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str> {
+    node.attribute(name).ok_or_else(|| {
+        anyhow!(
+            "<{}> at byte {} has no '{name}' attribute",
+            node.tag_name().name(),
+            node.range().start
+        )
+    })
+}
+
+fn name(node: Node) -> Result<String> {
+    attribute(node, "name").map(str::to_owned)
+}
+
+fn text<'a>(node: Node<'a, '_>) -> Result<&'a str> {
+    node.text()
+        .map(str::trim)
+        .ok_or_else(|| anyhow!("<{}> has no value", node.tag_name().name()))
+}
+
+fn number(node: Node, name: &str) -> Result<u16> {
+    let value = attribute(node, name)?;
+    value
+        .parse()
+        .with_context(|| format!("Invalid {name} '{value}'"))
+}
+
+fn optional_number(node: Node, name: &str) -> Result<u16> {
+    match node.attribute(name) {
+        Some(_) => number(node, name),
+        None => Ok(0),
+    }
+}
+
+/// The quoted name opening the comment just before `node`, if any.
+fn event_type(node: Node) -> Option<String> {
+    let comment = std::iter::successors(node.prev_sibling(), Node::prev_sibling)
+        .find(|sibling| !sibling.is_text())
+        .filter(Node::is_comment)?;
+    let quoted = comment.text()?.trim().strip_prefix('"')?;
+    Some(quoted.split_once('"')?.0.to_owned())
+}
+
+fn mbx(node: Node, name: &str) -> Option<String> {
+    node.attribute((MBX, name)).map(str::to_owned)
+}
+
+/// Method and module names the way sbetool derives them from schema names,
+/// e.g. `Ticker24hFullResponse` becomes `ticker_24_hf_ull_response`: a word
+/// break is put before capitals and around digit runs, but one that would
+/// leave a single-letter word is moved one character along.
+pub fn to_snake(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::with_capacity(name.len() + 4);
+    let mut word_start = 0;
+    let mut pending = false;
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 {
+            let previous = chars[i - 1];
+            let boundary = c.is_ascii_uppercase()
+                || c.is_ascii_digit() != previous.is_ascii_digit()
+                || pending;
+            if boundary {
+                if i - word_start < 2 && !pending {
+                    pending = true;
+                } else {
+                    out.push('_');
+                    word_start = i;
+                    pending = false;
+                }
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    out
+}
+
+/// Upper camel case type name, e.g. `depthResponse` becomes `DepthResponse`.
+pub fn to_camel(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Name of a static in `meta_tables.rs`, e.g. `DEPTH_RESPONSE`.
+pub fn to_upper_snake(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    let mut previous: Option<char> = None;
+    for c in name.chars() {
+        if c.is_ascii_uppercase()
+            && previous.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit())
+        {
+            out.push('_');
+        }
+        out.push(c.to_ascii_uppercase());
+        previous = Some(c);
+    }
+    out
+}
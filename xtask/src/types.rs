@@ -0,0 +1,111 @@
+//! `<enum>` and `<set>` types, one module each.
+
+use crate::code::Code;
+use crate::schema::{to_camel, to_snake, EnumType, SetType};
+use crate::GeneratedFile;
+
+pub fn enum_file(e: &EnumType) -> GeneratedFile {
+    let name = to_camel(&e.name);
+    let raw = e.encoding.rust();
+    let literal = |value: u64| format!("{value:#x}_{raw}");
+    let null = literal(u64::MAX >> (64 - 8 * e.encoding.size()));
+
+    let mut code = Code::default();
+    code.line("#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]")
+        .line(r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#)
+        .line(format!("#[repr({raw})]"))
+        .open(format!("pub enum {name} {{"));
+    for value in &e.values {
+        code.line(format!("{} = {},", value.name, literal(value.value)));
+    }
+    code.line("#[default]")
+        .line(format!("NullVal = {null},"))
+        .close("}")
+        .open(format!("impl From<{raw}> for {name} {{"))
+        .line("#[inline]")
+        .open(format!("fn from(v: {raw}) -> Self {{"))
+        .open("match v {");
+    for value in &e.values {
+        code.line(format!("{} => Self::{},", literal(value.value), value.name));
+    }
+    code.line("_ => Self::NullVal,")
+        .close("}")
+        .close("}")
+        .close("}");
+
+    GeneratedFile {
+        name: format!("{}.rs", to_snake(&e.name)),
+        contents: code.finish(),
+    }
+}
+
+pub fn set_file(set: &SetType) -> GeneratedFile {
+    let name = to_camel(&set.name);
+    let raw = set.encoding.rust();
+
+    let mut code = Code::default();
+    code.line("#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]")
+        .line(r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#)
+        .line(format!("pub struct {name}(pub {raw});"))
+        .open(format!("impl {name} {{"))
+        .line("#[inline]")
+        .open(format!("pub fn new(value: {raw}) -> Self {{"))
+        .line(format!("{name}(value)"))
+        .close("}")
+        .blank()
+        .line("#[inline]")
+        .open("pub fn clear(&mut self) -> &mut Self {")
+        .line("self.0 = 0;")
+        .line("self")
+        .close("}");
+    for choice in &set.choices {
+        let method = to_snake(&choice.name);
+        let bit = choice.bit;
+        code.blank()
+            .line("#[inline]")
+            .open(format!("pub fn get_{method}(&self) -> bool {{"))
+            .line(format!("0 != self.0 & (1 << {bit})"))
+            .close("}")
+            .blank()
+            .line("#[inline]")
+            .open(format!(
+                "pub fn set_{method}(&mut self, value: bool) -> &mut Self {{"
+            ))
+            .open("self.0 = if value {")
+            .line(format!("self.0 | (1 << {bit})"))
+            .middle("} else {")
+            .line(format!("self.0 & !(1 << {bit})"))
+            .close("};")
+            .line("self")
+            .close("}");
+    }
+    let labels: String = set
+        .choices
+        .iter()
+        .map(|choice| format!("{}({})={{}}", to_snake(&choice.name), choice.bit))
+        .collect::<Vec<_>>()
+        .join(",");
+    let mut getters = String::new();
+    for choice in &set.choices {
+        getters.push_str(&format!("self.get_{}(),", to_snake(&choice.name)));
+    }
+    code.close("}")
+        .open(format!("impl From<{raw}> for {name} {{"))
+        .line("#[inline]")
+        .open(format!("fn from(v: {raw}) -> Self {{"))
+        .line(format!("{name}(v)"))
+        .close("}")
+        .close("}")
+        .open(format!("impl core::fmt::Debug for {name} {{"))
+        .line("#[inline]")
+        .open("fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {")
+        .line(format!(r#"write!(fmt, "{name}[{labels}]","#))
+        .line(format!("    {getters})"))
+        .close("}")
+        .close("}");
+
+    GeneratedFile {
+        name: format!("{}.rs", to_snake(&set.name)),
+        contents: code.finish(),
+    }
+}